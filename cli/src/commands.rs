@@ -21,12 +21,41 @@ use serde_json::to_string_pretty;
 pub(crate) enum Command {
     /// Send lbtc and receive btc lightning through a swap
     SendPayment {
-        /// Invoice which has to be paid
+        /// Invoice or bolt12 offer which has to be paid
         bolt11: String,
 
+        /// The amount to pay, in satoshi. Required for invoices without an amount
+        #[arg(short, long)]
+        amount_sat: Option<u64>,
+
         /// Delay for the send, in seconds
         #[arg(short, long)]
         delay: Option<u64>,
+
+        /// Optional key to safely retry the payment without paying twice
+        #[arg(short, long)]
+        idempotency_key: Option<String>,
+
+        /// Optional note to the recipient, only when paying a bolt12 offer
+        #[arg(long)]
+        payer_note: Option<String>,
+    },
+    /// Send lbtc directly to a Liquid address or BIP21 URI
+    SendLiquidPayment {
+        /// Liquid address or BIP21 URI to send to
+        destination: String,
+
+        /// The amount to send, in satoshi. Required if the destination has no amount
+        #[arg(short, long)]
+        amount_sat: Option<u64>,
+
+        /// Whether or not to send all funds
+        #[clap(name = "drain", short = 'd', long = "drain")]
+        drain: bool,
+
+        /// Optional id of the Liquid asset to send, if other than L-BTC
+        #[arg(long)]
+        asset_id: Option<String>,
     },
     /// Fetch the current limits for Send and Receive payments
     FetchLightningLimits,
@@ -37,12 +66,20 @@ pub(crate) enum Command {
         /// Btc onchain address to send to
         address: String,
 
-        /// Amount that will be received, in satoshi
+        /// Amount that will be received, in satoshi. Ignored when draining
         receiver_amount_sat: u64,
 
         // The optional fee rate to use, in satoshi/vbyte
         #[clap(short = 'f', long = "fee_rate")]
         sat_per_vbyte: Option<u32>,
+
+        /// Whether or not to send all funds
+        #[clap(name = "drain", short = 'd', long = "drain")]
+        drain: bool,
+
+        /// Optional key to safely retry the payment without paying twice
+        #[clap(short = 'i', long = "idempotency_key")]
+        idempotency_key: Option<String>,
     },
     /// Receive lbtc and send btc through a swap
     ReceivePayment {
@@ -53,10 +90,35 @@ pub(crate) enum Command {
         #[clap(short = 'd', long = "description")]
         description: Option<String>,
     },
+    /// Receive lbtc directly to a Liquid address
+    ReceiveLiquidPayment {
+        /// Optional amount to request, in satoshi
+        #[clap(short = 'a', long = "amount_sat")]
+        amount_sat: Option<u64>,
+
+        /// Optional description for the payment
+        #[clap(short = 'd', long = "description")]
+        description: Option<String>,
+
+        /// Optional id of the Liquid asset to receive, if other than L-BTC
+        #[arg(long)]
+        asset_id: Option<String>,
+    },
     /// Receive lbtc and send btc onchain through a swap
     ReceiveOnchainPayment {
-        /// Amount the payer will send, in satoshi
-        payer_amount_sat: u64,
+        /// Amount the payer will send, in satoshi. If not set, any amount within the limits can be sent
+        #[arg(long)]
+        payer_amount_sat: Option<u64>,
+    },
+    /// Get the fees proposed for an onchain receive where the payer sent a different amount
+    FetchPaymentProposedFees {
+        /// The id of the swap waiting for fee acceptance
+        swap_id: String,
+    },
+    /// Accept the fees proposed for an onchain receive where the payer sent a different amount
+    AcceptPaymentProposedFees {
+        /// The id of the swap waiting for fee acceptance
+        swap_id: String,
     },
     /// Generates an URL to buy bitcoin from a 3rd party provider
     BuyBitcoin {
@@ -82,6 +144,101 @@ pub(crate) enum Command {
         /// Optional offset in payments
         #[clap(short = 'o', long = "offset")]
         offset: Option<u32>,
+
+        /// Optional metadata filters, either `key` or `key=value`. Can be repeated.
+        #[clap(short = 'm', long = "metadata")]
+        metadata: Vec<String>,
+
+        /// Optional payment states to filter by, e.g. `Pending`. Can be repeated.
+        #[clap(long = "state")]
+        states: Vec<PaymentState>,
+
+        /// Optional payment kinds to filter by: submarine, reverse, chain or direct. Can be repeated.
+        #[clap(long = "kind")]
+        kinds: Vec<PaymentKind>,
+
+        /// The optional minimum amount, in satoshi
+        #[clap(long = "from_amount")]
+        from_amount_sat: Option<u64>,
+
+        /// The optional maximum amount, in satoshi
+        #[clap(long = "to_amount")]
+        to_amount_sat: Option<u64>,
+
+        /// The optional Liquid asset id to filter by
+        #[clap(long = "asset_id")]
+        asset_id: Option<String>,
+
+        /// Optional text to search for in the description or invoice
+        #[clap(short = 's', long = "search")]
+        search: Option<String>,
+
+        /// Optional cursor of the page to list, as returned in `next_cursor`
+        #[clap(short = 'c', long = "cursor")]
+        cursor: Option<String>,
+    },
+    /// Get a payment by one of its identifiers
+    GetPayment {
+        /// The swap id of the payment
+        #[clap(long = "swap_id")]
+        swap_id: Option<String>,
+
+        /// The bolt11 invoice of the payment
+        #[clap(long = "invoice")]
+        invoice: Option<String>,
+
+        /// The payment hash of the invoice of the payment
+        #[clap(long = "payment_hash")]
+        payment_hash: Option<String>,
+
+        /// Any tx id of the payment, e.g. a swap lockup, claim or refund tx id
+        #[clap(long = "tx_id")]
+        tx_id: Option<String>,
+
+        /// The Liquid address of a direct Liquid payment
+        #[clap(long = "destination")]
+        destination: Option<String>,
+    },
+    /// Export the payments to a CSV or JSON file
+    ExportPayments {
+        /// Path of the file to write
+        export_path: String,
+
+        /// The file format: csv or json
+        #[clap(short = 'f', long = "format", default_value = "csv")]
+        format: ExportFormat,
+
+        /// The optional fiat currency to value the payments in, e.g. USD
+        #[clap(short = 'c', long = "currency")]
+        fiat_currency: Option<String>,
+
+        /// The optional from unix timestamp
+        #[clap(long = "from")]
+        from_timestamp: Option<i64>,
+
+        /// The optional to unix timestamp
+        #[clap(long = "to")]
+        to_timestamp: Option<i64>,
+    },
+    /// Set a metadata value on a payment, e.g. a note or a label
+    SetPaymentMetadata {
+        /// The tx id or swap id of the payment
+        payment_id: String,
+        key: String,
+        value: String,
+    },
+    /// Get the metadata of a payment
+    GetPaymentMetadata {
+        /// The tx id or swap id of the payment
+        payment_id: String,
+    },
+    /// Delete metadata from a payment
+    DeletePaymentMetadata {
+        /// The tx id or swap id of the payment
+        payment_id: String,
+
+        /// The key to delete. If not set, all the payment metadata is deleted
+        key: Option<String>,
     },
     /// List refundable chain swaps
     ListRefundables,
@@ -103,6 +260,13 @@ pub(crate) enum Command {
         // Fee rate to use, in satoshi/vbyte
         sat_per_vbyte: u32,
     },
+    /// Replace a broadcast refund transaction with one paying a higher fee rate
+    BumpRefundFee {
+        // Swap address of the lockup
+        swap_address: String,
+        // New fee rate to use, in satoshi/vbyte
+        sat_per_vbyte: u32,
+    },
     /// Rescan onchain swaps
     RescanOnchainSwaps,
     /// Get the balance and general info of the current instance
@@ -130,6 +294,11 @@ pub(crate) enum Command {
         /// Generic input (URL, LNURL, BIP-21 BTC Address, LN invoice, etc)
         input: String,
     },
+    /// Parse a bolt12 offer to get its metadata
+    ParseOffer {
+        /// The bolt12 offer
+        offer: String,
+    },
     /// Pay using LNURL
     LnurlPay {
         /// LN Address or LNURL-pay endpoint
@@ -236,9 +405,20 @@ pub(crate) async fn handle_command(
             let limits = sdk.fetch_onchain_limits().await?;
             command_result!(limits)
         }
-        Command::SendPayment { bolt11, delay } => {
+        Command::SendPayment {
+            bolt11,
+            amount_sat,
+            delay,
+            idempotency_key,
+            payer_note,
+        } => {
             let prepare_response = sdk
-                .prepare_send_payment(&PrepareSendRequest { invoice: bolt11 })
+                .prepare_send_payment(&PrepareSendRequest {
+                    invoice: bolt11,
+                    amount_sat,
+                    idempotency_key,
+                    payer_note,
+                })
                 .await?;
 
             wait_confirmation!(
@@ -263,22 +443,51 @@ pub(crate) async fn handle_command(
                 command_result!(response)
             }
         }
+        Command::SendLiquidPayment {
+            destination,
+            amount_sat,
+            drain,
+            asset_id,
+        } => {
+            let prepare_res = sdk
+                .prepare_send_liquid(&PrepareSendLiquidRequest {
+                    destination,
+                    amount_sat,
+                    drain: Some(drain),
+                    asset_id,
+                })
+                .await?;
+
+            wait_confirmation!(
+                format!(
+                    "Amount: {} sat, fees: {} sat. Are the fees acceptable? (y/N) ",
+                    prepare_res.receiver_amount_sat, prepare_res.fees_sat
+                ),
+                "Payment send halted"
+            );
+
+            let response = sdk.send_liquid(&prepare_res).await?;
+            command_result!(response)
+        }
         Command::SendOnchainPayment {
             address,
             receiver_amount_sat,
             sat_per_vbyte,
+            drain,
+            idempotency_key,
         } => {
             let prepare_res = sdk
                 .prepare_pay_onchain(&PreparePayOnchainRequest {
                     receiver_amount_sat,
                     sat_per_vbyte,
+                    drain: Some(drain),
                 })
                 .await?;
 
             wait_confirmation!(
                 format!(
-                    "Fees: {} sat (incl claim fee: {} sat). Are the fees acceptable? (y/N) ",
-                    prepare_res.total_fees_sat, prepare_res.claim_fees_sat
+                    "Amount: {} sat, fees: {} sat (incl claim fee: {} sat). Are the fees acceptable? (y/N) ",
+                    prepare_res.receiver_amount_sat, prepare_res.total_fees_sat, prepare_res.claim_fees_sat
                 ),
                 "Payment send halted"
             );
@@ -287,20 +496,46 @@ pub(crate) async fn handle_command(
                 .pay_onchain(&PayOnchainRequest {
                     address,
                     prepare_res,
+                    idempotency_key,
                 })
                 .await?;
             command_result!(response)
         }
+        Command::ReceiveLiquidPayment {
+            amount_sat,
+            description,
+            asset_id,
+        } => {
+            let response = sdk
+                .receive_liquid(&ReceiveLiquidRequest {
+                    amount_sat,
+                    description,
+                    asset_id,
+                })
+                .await?;
+            let bip21 = response.bip21.clone();
+
+            let mut result = command_result!(response);
+            result.push('\n');
+            result.push_str(&build_qr_text(&bip21));
+            result
+        }
         Command::ReceiveOnchainPayment { payer_amount_sat } => {
             let prepare_res = sdk
                 .prepare_receive_onchain(&PrepareReceiveOnchainRequest { payer_amount_sat })
                 .await?;
 
             wait_confirmation!(
-                format!(
-                    "Fees: {} sat. Are the fees acceptable? (y/N) ",
-                    prepare_res.fees_sat
-                ),
+                match prepare_res.payer_amount_sat {
+                    Some(_) => format!(
+                        "Fees: {} sat. Are the fees acceptable? (y/N) ",
+                        prepare_res.fees_sat
+                    ),
+                    None => format!(
+                        "Fees: {} sat + {}% of the amount paid. Are the fees acceptable? (y/N) ",
+                        prepare_res.fees_sat, prepare_res.swapper_feerate
+                    ),
+                },
                 "Payment receive halted"
             );
 
@@ -312,6 +547,29 @@ pub(crate) async fn handle_command(
             result.push_str(&build_qr_text(&bip21));
             result
         }
+        Command::FetchPaymentProposedFees { swap_id } => {
+            let res = sdk
+                .fetch_payment_proposed_fees(&FetchPaymentProposedFeesRequest { swap_id })
+                .await?;
+            command_result!(res)
+        }
+        Command::AcceptPaymentProposedFees { swap_id } => {
+            let res = sdk
+                .fetch_payment_proposed_fees(&FetchPaymentProposedFeesRequest { swap_id })
+                .await?;
+
+            wait_confirmation!(
+                format!(
+                    "Payer sent: {} sat, receive: {} sat, fees: {} sat. Are the fees acceptable? (y/N) ",
+                    res.payer_amount_sat, res.receiver_amount_sat, res.fees_sat
+                ),
+                "Proposed fees not accepted"
+            );
+
+            sdk.accept_payment_proposed_fees(&AcceptPaymentProposedFeesRequest { response: res })
+                .await?;
+            command_result!("Proposed fees accepted successfully")
+        }
         Command::BuyBitcoin {
             provider,
             amount_sat,
@@ -351,17 +609,110 @@ pub(crate) async fn handle_command(
             to_timestamp,
             limit,
             offset,
+            metadata,
+            states,
+            kinds,
+            from_amount_sat,
+            to_amount_sat,
+            asset_id,
+            search,
+            cursor,
         } => {
-            let payments = sdk
-                .list_payments(&ListPaymentsRequest {
+            let metadata_filters = metadata
+                .into_iter()
+                .map(|filter| match filter.split_once('=') {
+                    Some((key, value)) => PaymentMetadataFilter {
+                        key: key.to_string(),
+                        value: Some(value.to_string()),
+                    },
+                    None => PaymentMetadataFilter {
+                        key: filter,
+                        value: None,
+                    },
+                })
+                .collect::<Vec<_>>();
+            let res = sdk
+                .list_payments_page(&ListPaymentsRequest {
                     filters: None,
                     from_timestamp,
                     to_timestamp,
                     limit,
                     offset,
+                    metadata_filters: Some(metadata_filters),
+                    states: (!states.is_empty()).then_some(states),
+                    kinds: (!kinds.is_empty()).then_some(kinds),
+                    from_amount_sat,
+                    to_amount_sat,
+                    asset_id,
+                    search,
+                    cursor,
                 })
                 .await?;
-            command_result!(payments)
+            command_result!(res)
+        }
+        Command::GetPayment {
+            swap_id,
+            invoice,
+            payment_hash,
+            tx_id,
+            destination,
+        } => {
+            let lookup = if let Some(swap_id) = swap_id {
+                PaymentLookup::SwapId { swap_id }
+            } else if let Some(invoice) = invoice {
+                PaymentLookup::Invoice { invoice }
+            } else if let Some(payment_hash) = payment_hash {
+                PaymentLookup::PaymentHash { payment_hash }
+            } else if let Some(tx_id) = tx_id {
+                PaymentLookup::TxId { tx_id }
+            } else if let Some(address) = destination {
+                PaymentLookup::Destination { address }
+            } else {
+                return Err(anyhow::anyhow!(
+                    "One of swap_id, invoice, payment_hash, tx_id or destination is required"
+                ));
+            };
+            let payment = sdk.get_payment(&lookup).await?;
+            command_result!(payment)
+        }
+        Command::ExportPayments {
+            export_path,
+            format,
+            fiat_currency,
+            from_timestamp,
+            to_timestamp,
+        } => {
+            sdk.export_payments(&ExportPaymentsRequest {
+                export_path,
+                format,
+                fiat_currency,
+                from_timestamp,
+                to_timestamp,
+            })
+            .await?;
+            command_result!("Payments exported successfully")
+        }
+        Command::SetPaymentMetadata {
+            payment_id,
+            key,
+            value,
+        } => {
+            sdk.set_payment_metadata(&SetPaymentMetadataRequest {
+                payment_id,
+                key,
+                value,
+            })
+            .await?;
+            command_result!("Payment metadata set successfully")
+        }
+        Command::GetPaymentMetadata { payment_id } => {
+            let metadata = sdk.get_payment_metadata(&payment_id).await?;
+            command_result!(metadata)
+        }
+        Command::DeletePaymentMetadata { payment_id, key } => {
+            sdk.delete_payment_metadata(&DeletePaymentMetadataRequest { payment_id, key })
+                .await?;
+            command_result!("Payment metadata deleted successfully")
         }
         Command::ListRefundables => {
             let refundables = sdk.list_refundables().await?;
@@ -395,6 +746,18 @@ pub(crate) async fn handle_command(
                 .await?;
             command_result!(res)
         }
+        Command::BumpRefundFee {
+            swap_address,
+            sat_per_vbyte,
+        } => {
+            let res = sdk
+                .bump_refund_fee(&BumpRefundFeeRequest {
+                    swap_address,
+                    sat_per_vbyte,
+                })
+                .await?;
+            command_result!(res)
+        }
         Command::RescanOnchainSwaps => {
             sdk.rescan_onchain_swaps().await?;
             command_result!("Rescanned successfully")
@@ -427,6 +790,10 @@ pub(crate) async fn handle_command(
             let res = LiquidSdk::parse(&input).await?;
             command_result!(res)
         }
+        Command::ParseOffer { offer } => {
+            let res = LiquidSdk::parse_offer(&offer)?;
+            command_result!(res)
+        }
         Command::LnurlPay {
             lnurl,
             validate_success_url,
@@ -21,6 +21,22 @@ pub(crate) struct Args {
 
     #[clap(short, long, value_parser = parse_network_arg)]
     pub(crate) network: Option<LiquidNetwork>,
+
+    /// Connect in watch-only mode with this CT descriptor, instead of the stored mnemonic
+    #[clap(short, long)]
+    pub(crate) watch_only_descriptor: Option<String>,
+
+    /// Fetch the chain data from the Esplora APIs instead of the Electrum servers
+    #[clap(long)]
+    pub(crate) esplora: bool,
+
+    /// Connect through this SOCKS5 proxy, e.g. `socks5h://127.0.0.1:9050` for Tor
+    #[clap(long)]
+    pub(crate) proxy_url: Option<String>,
+
+    /// Automatically refund failed incoming chain swaps to this BTC address
+    #[clap(long)]
+    pub(crate) auto_refund_address: Option<String>,
 }
 
 fn parse_network_arg(s: &str) -> Result<LiquidNetwork, String> {
@@ -69,13 +85,25 @@ async fn main() -> Result<()> {
         info!("No history found");
     }
 
-    let mnemonic = persistence.get_or_create_mnemonic()?;
+    let mnemonic = match args.watch_only_descriptor {
+        Some(_) => None,
+        None => Some(persistence.get_or_create_mnemonic()?.to_string()),
+    };
     let network = args.network.unwrap_or(LiquidNetwork::Testnet);
     let mut config = LiquidSdk::default_config(network);
     config.working_dir = data_dir_str;
+    if args.esplora {
+        config.chain_backend = ChainBackend::Esplora;
+    }
+    config.proxy_url = args.proxy_url;
+    config.auto_refund = args.auto_refund_address.map(|address| AutoRefundPolicy {
+        destination: RefundDestination::Address { address },
+        fee_rate: RefundFeeRate::HalfHour,
+    });
     let sdk = LiquidSdk::connect(ConnectRequest {
-        mnemonic: mnemonic.to_string(),
+        mnemonic,
         config,
+        watch_only_descriptor: args.watch_only_descriptor,
     })
     .await?;
     let listener_id = sdk
@@ -85,6 +113,7 @@ async fn main() -> Result<()> {
     let cli_prompt = match network {
         LiquidNetwork::Mainnet => "breez-liquid-cli [mainnet]> ",
         LiquidNetwork::Testnet => "breez-liquid-cli [testnet]> ",
+        LiquidNetwork::Regtest => "breez-liquid-cli [regtest]> ",
     };
 
     loop {
@@ -16,6 +16,8 @@ typedef struct _Dart_Handle* Dart_Handle;
 
 #define ESTIMATED_BTC_CLAIM_TX_VSIZE 111
 
+#define DEFAULT_AMOUNTLESS_FEE_TOLERANCE_SAT 0
+
 #define STANDARD_FEE_RATE_SAT_PER_VBYTE 0.1
 
 #define LOWBALL_FEE_RATE_SAT_PER_VBYTE 0.01
@@ -42,10 +44,26 @@ typedef struct wire_cst_list_prim_u_8_strict {
   int32_t len;
 } wire_cst_list_prim_u_8_strict;
 
+typedef struct wire_cst_fetch_payment_proposed_fees_response {
+  struct wire_cst_list_prim_u_8_strict *swap_id;
+  uint64_t fees_sat;
+  uint64_t payer_amount_sat;
+  uint64_t receiver_amount_sat;
+} wire_cst_fetch_payment_proposed_fees_response;
+
+typedef struct wire_cst_accept_payment_proposed_fees_request {
+  struct wire_cst_fetch_payment_proposed_fees_response response;
+} wire_cst_accept_payment_proposed_fees_request;
+
 typedef struct wire_cst_backup_request {
   struct wire_cst_list_prim_u_8_strict *backup_path;
 } wire_cst_backup_request;
 
+typedef struct wire_cst_bump_refund_fee_request {
+  struct wire_cst_list_prim_u_8_strict *swap_address;
+  uint32_t sat_per_vbyte;
+} wire_cst_bump_refund_fee_request;
+
 typedef struct wire_cst_prepare_buy_bitcoin_response {
   int32_t provider;
   uint64_t amount_sat;
@@ -57,17 +75,95 @@ typedef struct wire_cst_buy_bitcoin_request {
   struct wire_cst_list_prim_u_8_strict *redirect_url;
 } wire_cst_buy_bitcoin_request;
 
+typedef struct wire_cst_delete_payment_metadata_request {
+  struct wire_cst_list_prim_u_8_strict *payment_id;
+  struct wire_cst_list_prim_u_8_strict *key;
+} wire_cst_delete_payment_metadata_request;
+
+typedef struct wire_cst_export_payments_request {
+  struct wire_cst_list_prim_u_8_strict *export_path;
+  int32_t format;
+  struct wire_cst_list_prim_u_8_strict *fiat_currency;
+  int64_t *from_timestamp;
+  int64_t *to_timestamp;
+} wire_cst_export_payments_request;
+
+typedef struct wire_cst_fetch_payment_proposed_fees_request {
+  struct wire_cst_list_prim_u_8_strict *swap_id;
+} wire_cst_fetch_payment_proposed_fees_request;
+
+typedef struct wire_cst_PaymentLookup_SwapId {
+  struct wire_cst_list_prim_u_8_strict *swap_id;
+} wire_cst_PaymentLookup_SwapId;
+
+typedef struct wire_cst_PaymentLookup_Invoice {
+  struct wire_cst_list_prim_u_8_strict *invoice;
+} wire_cst_PaymentLookup_Invoice;
+
+typedef struct wire_cst_PaymentLookup_PaymentHash {
+  struct wire_cst_list_prim_u_8_strict *payment_hash;
+} wire_cst_PaymentLookup_PaymentHash;
+
+typedef struct wire_cst_PaymentLookup_TxId {
+  struct wire_cst_list_prim_u_8_strict *tx_id;
+} wire_cst_PaymentLookup_TxId;
+
+typedef struct wire_cst_PaymentLookup_Destination {
+  struct wire_cst_list_prim_u_8_strict *address;
+} wire_cst_PaymentLookup_Destination;
+
+typedef union PaymentLookupKind {
+  struct wire_cst_PaymentLookup_SwapId SwapId;
+  struct wire_cst_PaymentLookup_Invoice Invoice;
+  struct wire_cst_PaymentLookup_PaymentHash PaymentHash;
+  struct wire_cst_PaymentLookup_TxId TxId;
+  struct wire_cst_PaymentLookup_Destination Destination;
+} PaymentLookupKind;
+
+typedef struct wire_cst_payment_lookup {
+  int32_t tag;
+  union PaymentLookupKind kind;
+} wire_cst_payment_lookup;
+
 typedef struct wire_cst_list_payment_type {
   int32_t *ptr;
   int32_t len;
 } wire_cst_list_payment_type;
 
+typedef struct wire_cst_payment_metadata_filter {
+  struct wire_cst_list_prim_u_8_strict *key;
+  struct wire_cst_list_prim_u_8_strict *value;
+} wire_cst_payment_metadata_filter;
+
+typedef struct wire_cst_list_payment_metadata_filter {
+  struct wire_cst_payment_metadata_filter *ptr;
+  int32_t len;
+} wire_cst_list_payment_metadata_filter;
+
+typedef struct wire_cst_list_payment_state {
+  int32_t *ptr;
+  int32_t len;
+} wire_cst_list_payment_state;
+
+typedef struct wire_cst_list_payment_kind {
+  int32_t *ptr;
+  int32_t len;
+} wire_cst_list_payment_kind;
+
 typedef struct wire_cst_list_payments_request {
   struct wire_cst_list_payment_type *filters;
   int64_t *from_timestamp;
   int64_t *to_timestamp;
   uint32_t *offset;
   uint32_t *limit;
+  struct wire_cst_list_payment_metadata_filter *metadata_filters;
+  struct wire_cst_list_payment_state *states;
+  struct wire_cst_list_payment_kind *kinds;
+  uint64_t *from_amount_sat;
+  uint64_t *to_amount_sat;
+  struct wire_cst_list_prim_u_8_strict *asset_id;
+  struct wire_cst_list_prim_u_8_strict *search;
+  struct wire_cst_list_prim_u_8_strict *cursor;
 } wire_cst_list_payments_request;
 
 typedef struct wire_cst_ln_url_auth_request_data {
@@ -115,11 +211,13 @@ typedef struct wire_cst_prepare_pay_onchain_response {
   uint64_t receiver_amount_sat;
   uint64_t claim_fees_sat;
   uint64_t total_fees_sat;
+  bool drain;
 } wire_cst_prepare_pay_onchain_response;
 
 typedef struct wire_cst_pay_onchain_request {
   struct wire_cst_list_prim_u_8_strict *address;
   struct wire_cst_prepare_pay_onchain_response prepare_res;
+  struct wire_cst_list_prim_u_8_strict *idempotency_key;
 } wire_cst_pay_onchain_request;
 
 typedef struct wire_cst_prepare_buy_bitcoin_request {
@@ -130,10 +228,11 @@ typedef struct wire_cst_prepare_buy_bitcoin_request {
 typedef struct wire_cst_prepare_pay_onchain_request {
   uint64_t receiver_amount_sat;
   uint32_t *sat_per_vbyte;
+  bool *drain;
 } wire_cst_prepare_pay_onchain_request;
 
 typedef struct wire_cst_prepare_receive_onchain_request {
-  uint64_t payer_amount_sat;
+  uint64_t *payer_amount_sat;
 } wire_cst_prepare_receive_onchain_request;
 
 typedef struct wire_cst_prepare_receive_payment_request {
@@ -146,13 +245,30 @@ typedef struct wire_cst_prepare_refund_request {
   uint32_t sat_per_vbyte;
 } wire_cst_prepare_refund_request;
 
+typedef struct wire_cst_prepare_send_liquid_request {
+  struct wire_cst_list_prim_u_8_strict *destination;
+  uint64_t *amount_sat;
+  bool *drain;
+  struct wire_cst_list_prim_u_8_strict *asset_id;
+} wire_cst_prepare_send_liquid_request;
+
 typedef struct wire_cst_prepare_send_request {
   struct wire_cst_list_prim_u_8_strict *invoice;
+  uint64_t *amount_sat;
+  struct wire_cst_list_prim_u_8_strict *idempotency_key;
+  struct wire_cst_list_prim_u_8_strict *payer_note;
 } wire_cst_prepare_send_request;
 
+typedef struct wire_cst_receive_liquid_request {
+  uint64_t *amount_sat;
+  struct wire_cst_list_prim_u_8_strict *description;
+  struct wire_cst_list_prim_u_8_strict *asset_id;
+} wire_cst_receive_liquid_request;
+
 typedef struct wire_cst_prepare_receive_onchain_response {
-  uint64_t payer_amount_sat;
+  uint64_t *payer_amount_sat;
   uint64_t fees_sat;
+  double swapper_feerate;
 } wire_cst_prepare_receive_onchain_response;
 
 typedef struct wire_cst_prepare_receive_payment_response {
@@ -175,15 +291,54 @@ typedef struct wire_cst_restore_request {
   struct wire_cst_list_prim_u_8_strict *backup_path;
 } wire_cst_restore_request;
 
+typedef struct wire_cst_prepare_send_liquid_response {
+  struct wire_cst_list_prim_u_8_strict *address;
+  uint64_t receiver_amount_sat;
+  uint64_t fees_sat;
+  struct wire_cst_list_prim_u_8_strict *description;
+  bool drain;
+  struct wire_cst_list_prim_u_8_strict *asset_id;
+} wire_cst_prepare_send_liquid_response;
+
 typedef struct wire_cst_prepare_send_response {
   struct wire_cst_list_prim_u_8_strict *invoice;
+  struct wire_cst_list_prim_u_8_strict *offer;
+  uint64_t receiver_amount_sat;
   uint64_t fees_sat;
+  struct wire_cst_list_prim_u_8_strict *idempotency_key;
 } wire_cst_prepare_send_response;
 
+typedef struct wire_cst_set_payment_metadata_request {
+  struct wire_cst_list_prim_u_8_strict *payment_id;
+  struct wire_cst_list_prim_u_8_strict *key;
+  struct wire_cst_list_prim_u_8_strict *value;
+} wire_cst_set_payment_metadata_request;
+
 typedef struct wire_cst_binding_event_listener {
   struct wire_cst_list_prim_u_8_strict *stream;
 } wire_cst_binding_event_listener;
 
+typedef struct wire_cst_payment_metadata {
+  struct wire_cst_list_prim_u_8_strict *key;
+  struct wire_cst_list_prim_u_8_strict *value;
+} wire_cst_payment_metadata;
+
+typedef struct wire_cst_list_payment_metadata {
+  struct wire_cst_payment_metadata *ptr;
+  int32_t len;
+} wire_cst_list_payment_metadata;
+
+typedef struct wire_cst_payment_fiat_rate {
+  struct wire_cst_list_prim_u_8_strict *coin;
+  double value;
+  uint32_t created_at;
+} wire_cst_payment_fiat_rate;
+
+typedef struct wire_cst_list_payment_fiat_rate {
+  struct wire_cst_payment_fiat_rate *ptr;
+  int32_t len;
+} wire_cst_list_payment_fiat_rate;
+
 typedef struct wire_cst_payment {
   struct wire_cst_list_prim_u_8_strict *tx_id;
   struct wire_cst_list_prim_u_8_strict *swap_id;
@@ -193,10 +348,14 @@ typedef struct wire_cst_payment {
   struct wire_cst_list_prim_u_8_strict *preimage;
   struct wire_cst_list_prim_u_8_strict *bolt11;
   struct wire_cst_list_prim_u_8_strict *description;
+  struct wire_cst_list_prim_u_8_strict *destination;
+  struct wire_cst_list_prim_u_8_strict *asset_id;
   struct wire_cst_list_prim_u_8_strict *refund_tx_id;
   uint64_t *refund_tx_amount_sat;
   int32_t payment_type;
   int32_t status;
+  struct wire_cst_list_payment_metadata *metadata;
+  struct wire_cst_list_payment_fiat_rate *fiat_rates;
 } wire_cst_payment;
 
 typedef struct wire_cst_SdkEvent_PaymentFailed {
@@ -223,6 +382,20 @@ typedef struct wire_cst_SdkEvent_PaymentWaitingConfirmation {
   struct wire_cst_payment *details;
 } wire_cst_SdkEvent_PaymentWaitingConfirmation;
 
+typedef struct wire_cst_auto_refund_details {
+  struct wire_cst_list_prim_u_8_strict *swap_address;
+  struct wire_cst_list_prim_u_8_strict *refund_address;
+  struct wire_cst_list_prim_u_8_strict *refund_tx_id;
+} wire_cst_auto_refund_details;
+
+typedef struct wire_cst_SdkEvent_SwapAutoRefunded {
+  struct wire_cst_auto_refund_details *details;
+} wire_cst_SdkEvent_SwapAutoRefunded;
+
+typedef struct wire_cst_SdkEvent_SwapWaitingFeeAcceptance {
+  struct wire_cst_list_prim_u_8_strict *swap_id;
+} wire_cst_SdkEvent_SwapWaitingFeeAcceptance;
+
 typedef union SdkEventKind {
   struct wire_cst_SdkEvent_PaymentFailed PaymentFailed;
   struct wire_cst_SdkEvent_PaymentPending PaymentPending;
@@ -230,6 +403,8 @@ typedef union SdkEventKind {
   struct wire_cst_SdkEvent_PaymentRefundPending PaymentRefundPending;
   struct wire_cst_SdkEvent_PaymentSucceeded PaymentSucceeded;
   struct wire_cst_SdkEvent_PaymentWaitingConfirmation PaymentWaitingConfirmation;
+  struct wire_cst_SdkEvent_SwapAutoRefunded SwapAutoRefunded;
+  struct wire_cst_SdkEvent_SwapWaitingFeeAcceptance SwapWaitingFeeAcceptance;
 } SdkEventKind;
 
 typedef struct wire_cst_sdk_event {
@@ -237,22 +412,97 @@ typedef struct wire_cst_sdk_event {
   union SdkEventKind kind;
 } wire_cst_sdk_event;
 
+typedef struct wire_cst_asset_metadata {
+  struct wire_cst_list_prim_u_8_strict *asset_id;
+  struct wire_cst_list_prim_u_8_strict *name;
+  struct wire_cst_list_prim_u_8_strict *ticker;
+  uint8_t precision;
+} wire_cst_asset_metadata;
+
+typedef struct wire_cst_list_asset_metadata {
+  struct wire_cst_asset_metadata *ptr;
+  int32_t len;
+} wire_cst_list_asset_metadata;
+
+typedef struct wire_cst_list_String {
+  struct wire_cst_list_prim_u_8_strict **ptr;
+  int32_t len;
+} wire_cst_list_String;
+
+typedef struct wire_cst_RefundDestination_Address {
+  struct wire_cst_list_prim_u_8_strict *address;
+} wire_cst_RefundDestination_Address;
+
+typedef struct wire_cst_RefundDestination_Xpub {
+  struct wire_cst_list_prim_u_8_strict *xpub;
+} wire_cst_RefundDestination_Xpub;
+
+typedef union RefundDestinationKind {
+  struct wire_cst_RefundDestination_Address Address;
+  struct wire_cst_RefundDestination_Xpub Xpub;
+} RefundDestinationKind;
+
+typedef struct wire_cst_refund_destination {
+  int32_t tag;
+  union RefundDestinationKind kind;
+} wire_cst_refund_destination;
+
+typedef struct wire_cst_auto_refund_policy {
+  struct wire_cst_refund_destination destination;
+  int32_t fee_rate;
+} wire_cst_auto_refund_policy;
+
 typedef struct wire_cst_config {
   struct wire_cst_list_prim_u_8_strict *liquid_electrum_url;
   struct wire_cst_list_prim_u_8_strict *bitcoin_electrum_url;
   struct wire_cst_list_prim_u_8_strict *mempoolspace_url;
+  struct wire_cst_list_prim_u_8_strict *liquid_esplora_url;
+  struct wire_cst_list_prim_u_8_strict *bitcoin_esplora_url;
+  int32_t chain_backend;
+  struct wire_cst_list_prim_u_8_strict *breez_server_url;
+  struct wire_cst_list_prim_u_8_strict *boltz_url;
+  struct wire_cst_list_prim_u_8_strict *referral_id;
   struct wire_cst_list_prim_u_8_strict *working_dir;
   int32_t network;
   uint64_t payment_timeout_sec;
   uint32_t zero_conf_min_fee_rate_msat;
   uint64_t *zero_conf_max_amount_sat;
+  struct wire_cst_list_asset_metadata *asset_metadata;
+  struct wire_cst_list_String *fiat_currencies;
+  struct wire_cst_list_prim_u_8_strict *proxy_url;
+  struct wire_cst_auto_refund_policy *auto_refund;
+  uint64_t *amountless_fee_tolerance_sat;
 } wire_cst_config;
 
 typedef struct wire_cst_connect_request {
   struct wire_cst_list_prim_u_8_strict *mnemonic;
   struct wire_cst_config config;
+  struct wire_cst_list_prim_u_8_strict *watch_only_descriptor;
 } wire_cst_connect_request;
 
+typedef struct wire_cst_SignerResult_String {
+  struct wire_cst_list_prim_u_8_strict *value;
+} wire_cst_SignerResult_String;
+
+typedef struct wire_cst_SignerResult_Bytes {
+  struct wire_cst_list_prim_u_8_strict *value;
+} wire_cst_SignerResult_Bytes;
+
+typedef struct wire_cst_SignerResult_Error {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_SignerResult_Error;
+
+typedef union SignerResultKind {
+  struct wire_cst_SignerResult_String String;
+  struct wire_cst_SignerResult_Bytes Bytes;
+  struct wire_cst_SignerResult_Error Error;
+} SignerResultKind;
+
+typedef struct wire_cst_signer_result {
+  int32_t tag;
+  union SignerResultKind kind;
+} wire_cst_signer_result;
+
 typedef struct wire_cst_aes_success_action_data_decrypted {
   struct wire_cst_list_prim_u_8_strict *description;
   struct wire_cst_list_prim_u_8_strict *plaintext;
@@ -381,6 +631,19 @@ typedef struct wire_cst_symbol {
   uint32_t *position;
 } wire_cst_symbol;
 
+typedef struct wire_cst_asset_balance {
+  struct wire_cst_list_prim_u_8_strict *asset_id;
+  uint64_t balance;
+  uint64_t pending_send;
+  uint64_t pending_receive;
+  struct wire_cst_asset_metadata *asset_metadata;
+} wire_cst_asset_balance;
+
+typedef struct wire_cst_list_asset_balance {
+  struct wire_cst_asset_balance *ptr;
+  int32_t len;
+} wire_cst_list_asset_balance;
+
 typedef struct wire_cst_localized_name {
   struct wire_cst_list_prim_u_8_strict *locale;
   struct wire_cst_list_prim_u_8_strict *name;
@@ -448,11 +711,31 @@ typedef struct wire_cst_list_refundable_swap {
   int32_t len;
 } wire_cst_list_refundable_swap;
 
+typedef struct wire_cst_balance_details {
+  uint64_t confirmed_sat;
+  uint64_t unconfirmed_receive_sat;
+  uint64_t unconfirmed_send_sat;
+  uint64_t pending_send_swaps_sat;
+  uint64_t pending_receive_swaps_sat;
+  uint64_t pending_refund_sat;
+  uint64_t refundable_btc_sat;
+  uint64_t pending_btc_refund_sat;
+} wire_cst_balance_details;
+
+typedef struct wire_cst_bump_refund_fee_response {
+  struct wire_cst_list_prim_u_8_strict *refund_tx_id;
+  struct wire_cst_list_prim_u_8_strict *replaced_tx_id;
+  uint64_t tx_fee_sat;
+  uint64_t fee_delta_sat;
+} wire_cst_bump_refund_fee_response;
+
 typedef struct wire_cst_get_info_response {
   uint64_t balance_sat;
   uint64_t pending_send_sat;
   uint64_t pending_receive_sat;
   struct wire_cst_list_prim_u_8_strict *pubkey;
+  struct wire_cst_list_asset_balance *asset_balances;
+  struct wire_cst_balance_details balance_details;
 } wire_cst_get_info_response;
 
 typedef struct wire_cst_InputType_BitcoinAddress {
@@ -514,6 +797,20 @@ typedef struct wire_cst_lightning_payment_limits_response {
   struct wire_cst_limits receive;
 } wire_cst_lightning_payment_limits_response;
 
+typedef struct wire_cst_list_payments_response {
+  struct wire_cst_list_payment *payments;
+  struct wire_cst_list_prim_u_8_strict *next_cursor;
+} wire_cst_list_payments_response;
+
+typedef struct wire_cst_ln_offer {
+  struct wire_cst_list_prim_u_8_strict *offer;
+  struct wire_cst_list_String *chains;
+  uint64_t *amount_sat;
+  struct wire_cst_list_prim_u_8_strict *description;
+  uint64_t *absolute_expiry;
+  struct wire_cst_list_prim_u_8_strict *issuer;
+} wire_cst_ln_offer;
+
 typedef struct wire_cst_LnUrlAuthError_Generic {
   struct wire_cst_list_prim_u_8_strict *err;
 } wire_cst_LnUrlAuthError_Generic;
@@ -707,6 +1004,10 @@ typedef struct wire_cst_onchain_payment_limits_response {
   struct wire_cst_limits receive;
 } wire_cst_onchain_payment_limits_response;
 
+typedef struct wire_cst_PaymentError_AmountMissing {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_PaymentError_AmountMissing;
+
 typedef struct wire_cst_PaymentError_Generic {
   struct wire_cst_list_prim_u_8_strict *err;
 } wire_cst_PaymentError_Generic;
@@ -715,6 +1016,10 @@ typedef struct wire_cst_PaymentError_InvalidInvoice {
   struct wire_cst_list_prim_u_8_strict *err;
 } wire_cst_PaymentError_InvalidInvoice;
 
+typedef struct wire_cst_PaymentError_InvalidDestination {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_PaymentError_InvalidDestination;
+
 typedef struct wire_cst_PaymentError_LwkError {
   struct wire_cst_list_prim_u_8_strict *err;
 } wire_cst_PaymentError_LwkError;
@@ -737,8 +1042,10 @@ typedef struct wire_cst_PaymentError_SignerError {
 } wire_cst_PaymentError_SignerError;
 
 typedef union PaymentErrorKind {
+  struct wire_cst_PaymentError_AmountMissing AmountMissing;
   struct wire_cst_PaymentError_Generic Generic;
   struct wire_cst_PaymentError_InvalidInvoice InvalidInvoice;
+  struct wire_cst_PaymentError_InvalidDestination InvalidDestination;
   struct wire_cst_PaymentError_LwkError LwkError;
   struct wire_cst_PaymentError_ReceiveError ReceiveError;
   struct wire_cst_PaymentError_Refunded Refunded;
@@ -757,6 +1064,11 @@ typedef struct wire_cst_prepare_refund_response {
   struct wire_cst_list_prim_u_8_strict *refund_tx_id;
 } wire_cst_prepare_refund_response;
 
+typedef struct wire_cst_receive_liquid_response {
+  struct wire_cst_list_prim_u_8_strict *address;
+  struct wire_cst_list_prim_u_8_strict *bip21;
+} wire_cst_receive_liquid_response;
+
 typedef struct wire_cst_receive_onchain_response {
   struct wire_cst_list_prim_u_8_strict *address;
   struct wire_cst_list_prim_u_8_strict *bip21;
@@ -801,6 +1113,45 @@ typedef struct wire_cst_send_payment_response {
   struct wire_cst_payment payment;
 } wire_cst_send_payment_response;
 
+typedef struct wire_cst_SignerMethod_DeriveXpub {
+  struct wire_cst_list_prim_u_8_strict *derivation_path;
+} wire_cst_SignerMethod_DeriveXpub;
+
+typedef struct wire_cst_SignerMethod_SignPset {
+  struct wire_cst_list_prim_u_8_strict *pset;
+} wire_cst_SignerMethod_SignPset;
+
+typedef struct wire_cst_SignerMethod_SignEcdsa {
+  struct wire_cst_list_prim_u_8_strict *msg;
+  struct wire_cst_list_prim_u_8_strict *derivation_path;
+} wire_cst_SignerMethod_SignEcdsa;
+
+typedef struct wire_cst_SignerMethod_HmacSha256 {
+  struct wire_cst_list_prim_u_8_strict *msg;
+  struct wire_cst_list_prim_u_8_strict *derivation_path;
+} wire_cst_SignerMethod_HmacSha256;
+
+typedef union SignerMethodKind {
+  struct wire_cst_SignerMethod_DeriveXpub DeriveXpub;
+  struct wire_cst_SignerMethod_SignPset SignPset;
+  struct wire_cst_SignerMethod_SignEcdsa SignEcdsa;
+  struct wire_cst_SignerMethod_HmacSha256 HmacSha256;
+} SignerMethodKind;
+
+typedef struct wire_cst_signer_method {
+  int32_t tag;
+  union SignerMethodKind kind;
+} wire_cst_signer_method;
+
+typedef struct wire_cst_signer_request {
+  uint32_t id;
+  struct wire_cst_signer_method method;
+} wire_cst_signer_request;
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_accept_payment_proposed_fees(int64_t port_,
+                                                                                              uintptr_t that,
+                                                                                              struct wire_cst_accept_payment_proposed_fees_request *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_add_event_listener(int64_t port_,
                                                                                     uintptr_t that,
                                                                                     struct wire_cst_list_prim_u_8_strict *listener);
@@ -808,15 +1159,27 @@ void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_add_event_liste
 WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_backup(uintptr_t that,
                                                                                         struct wire_cst_backup_request *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_bump_refund_fee(int64_t port_,
+                                                                                 uintptr_t that,
+                                                                                 struct wire_cst_bump_refund_fee_request *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_buy_bitcoin(int64_t port_,
                                                                              uintptr_t that,
                                                                              struct wire_cst_buy_bitcoin_request *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_delete_payment_metadata(int64_t port_,
+                                                                                         uintptr_t that,
+                                                                                         struct wire_cst_delete_payment_metadata_request *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_disconnect(int64_t port_,
                                                                             uintptr_t that);
 
 WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache(uintptr_t that);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_payments(int64_t port_,
+                                                                                 uintptr_t that,
+                                                                                 struct wire_cst_export_payments_request *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_fiat_rates(int64_t port_,
                                                                                   uintptr_t that);
 
@@ -826,9 +1189,21 @@ void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_lightning
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_onchain_limits(int64_t port_,
                                                                                       uintptr_t that);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_payment_proposed_fees(int64_t port_,
+                                                                                             uintptr_t that,
+                                                                                             struct wire_cst_fetch_payment_proposed_fees_request *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_info(int64_t port_,
                                                                           uintptr_t that);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment(int64_t port_,
+                                                                             uintptr_t that,
+                                                                             struct wire_cst_payment_lookup *req);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment_metadata(int64_t port_,
+                                                                                      uintptr_t that,
+                                                                                      struct wire_cst_list_prim_u_8_strict *payment_id);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_fiat_currencies(int64_t port_,
                                                                                       uintptr_t that);
 
@@ -836,6 +1211,10 @@ void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments(i
                                                                                uintptr_t that,
                                                                                struct wire_cst_list_payments_request *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments_page(int64_t port_,
+                                                                                    uintptr_t that,
+                                                                                    struct wire_cst_list_payments_request *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_refundables(int64_t port_,
                                                                                   uintptr_t that);
 
@@ -875,10 +1254,18 @@ void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_refund(
                                                                                 uintptr_t that,
                                                                                 struct wire_cst_prepare_refund_request *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_liquid(int64_t port_,
+                                                                                     uintptr_t that,
+                                                                                     struct wire_cst_prepare_send_liquid_request *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_payment(int64_t port_,
                                                                                       uintptr_t that,
                                                                                       struct wire_cst_prepare_send_request *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_liquid(int64_t port_,
+                                                                                uintptr_t that,
+                                                                                struct wire_cst_receive_liquid_request *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_onchain(int64_t port_,
                                                                                  uintptr_t that,
                                                                                  struct wire_cst_prepare_receive_onchain_response *req);
@@ -900,10 +1287,18 @@ void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_rescan_onchain_
 WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_restore(uintptr_t that,
                                                                                          struct wire_cst_restore_request *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_liquid(int64_t port_,
+                                                                             uintptr_t that,
+                                                                             struct wire_cst_prepare_send_liquid_response *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_payment(int64_t port_,
                                                                               uintptr_t that,
                                                                               struct wire_cst_prepare_send_response *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_set_payment_metadata(int64_t port_,
+                                                                                      uintptr_t that,
+                                                                                      struct wire_cst_set_payment_metadata_request *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sync(int64_t port_,
                                                                       uintptr_t that);
 
@@ -917,6 +1312,9 @@ void frbgen_breez_liquid_wire__crate__bindings__breez_log_stream(int64_t port_,
 void frbgen_breez_liquid_wire__crate__bindings__connect(int64_t port_,
                                                         struct wire_cst_connect_request *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__connect_with_signer(int64_t port_,
+                                                                    struct wire_cst_connect_request *req);
+
 WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__default_config(int32_t network);
 
 void frbgen_breez_liquid_wire__crate__bindings__parse(int64_t port_,
@@ -924,14 +1322,30 @@ void frbgen_breez_liquid_wire__crate__bindings__parse(int64_t port_,
 
 WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__parse_invoice(struct wire_cst_list_prim_u_8_strict *input);
 
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__parse_offer(struct wire_cst_list_prim_u_8_strict *input);
+
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__respond_to_signer_request(uint32_t id,
+                                                                                          struct wire_cst_signer_result *result);
+
+void frbgen_breez_liquid_wire__crate__bindings__signer_request_stream(int64_t port_,
+                                                                      struct wire_cst_list_prim_u_8_strict *s);
+
 void frbgen_breez_liquid_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(const void *ptr);
 
 void frbgen_breez_liquid_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(const void *ptr);
 
+struct wire_cst_accept_payment_proposed_fees_request *frbgen_breez_liquid_cst_new_box_autoadd_accept_payment_proposed_fees_request(void);
+
 struct wire_cst_aes_success_action_data_decrypted *frbgen_breez_liquid_cst_new_box_autoadd_aes_success_action_data_decrypted(void);
 
 struct wire_cst_aes_success_action_data_result *frbgen_breez_liquid_cst_new_box_autoadd_aes_success_action_data_result(void);
 
+struct wire_cst_asset_metadata *frbgen_breez_liquid_cst_new_box_autoadd_asset_metadata(void);
+
+struct wire_cst_auto_refund_details *frbgen_breez_liquid_cst_new_box_autoadd_auto_refund_details(void);
+
+struct wire_cst_auto_refund_policy *frbgen_breez_liquid_cst_new_box_autoadd_auto_refund_policy(void);
+
 struct wire_cst_backup_request *frbgen_breez_liquid_cst_new_box_autoadd_backup_request(void);
 
 struct wire_cst_binding_event_listener *frbgen_breez_liquid_cst_new_box_autoadd_binding_event_listener(void);
@@ -940,10 +1354,18 @@ struct wire_cst_bitcoin_address_data *frbgen_breez_liquid_cst_new_box_autoadd_bi
 
 bool *frbgen_breez_liquid_cst_new_box_autoadd_bool(bool value);
 
+struct wire_cst_bump_refund_fee_request *frbgen_breez_liquid_cst_new_box_autoadd_bump_refund_fee_request(void);
+
 struct wire_cst_buy_bitcoin_request *frbgen_breez_liquid_cst_new_box_autoadd_buy_bitcoin_request(void);
 
 struct wire_cst_connect_request *frbgen_breez_liquid_cst_new_box_autoadd_connect_request(void);
 
+struct wire_cst_delete_payment_metadata_request *frbgen_breez_liquid_cst_new_box_autoadd_delete_payment_metadata_request(void);
+
+struct wire_cst_export_payments_request *frbgen_breez_liquid_cst_new_box_autoadd_export_payments_request(void);
+
+struct wire_cst_fetch_payment_proposed_fees_request *frbgen_breez_liquid_cst_new_box_autoadd_fetch_payment_proposed_fees_request(void);
+
 int64_t *frbgen_breez_liquid_cst_new_box_autoadd_i_64(int64_t value);
 
 struct wire_cst_list_payments_request *frbgen_breez_liquid_cst_new_box_autoadd_list_payments_request(void);
@@ -974,6 +1396,8 @@ struct wire_cst_pay_onchain_request *frbgen_breez_liquid_cst_new_box_autoadd_pay
 
 struct wire_cst_payment *frbgen_breez_liquid_cst_new_box_autoadd_payment(void);
 
+struct wire_cst_payment_lookup *frbgen_breez_liquid_cst_new_box_autoadd_payment_lookup(void);
+
 struct wire_cst_prepare_buy_bitcoin_request *frbgen_breez_liquid_cst_new_box_autoadd_prepare_buy_bitcoin_request(void);
 
 struct wire_cst_prepare_pay_onchain_request *frbgen_breez_liquid_cst_new_box_autoadd_prepare_pay_onchain_request(void);
@@ -986,10 +1410,16 @@ struct wire_cst_prepare_receive_payment_request *frbgen_breez_liquid_cst_new_box
 
 struct wire_cst_prepare_refund_request *frbgen_breez_liquid_cst_new_box_autoadd_prepare_refund_request(void);
 
+struct wire_cst_prepare_send_liquid_request *frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_liquid_request(void);
+
+struct wire_cst_prepare_send_liquid_response *frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_liquid_response(void);
+
 struct wire_cst_prepare_send_request *frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_request(void);
 
 struct wire_cst_prepare_send_response *frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_response(void);
 
+struct wire_cst_receive_liquid_request *frbgen_breez_liquid_cst_new_box_autoadd_receive_liquid_request(void);
+
 struct wire_cst_receive_payment_request *frbgen_breez_liquid_cst_new_box_autoadd_receive_payment_request(void);
 
 struct wire_cst_refund_request *frbgen_breez_liquid_cst_new_box_autoadd_refund_request(void);
@@ -998,6 +1428,10 @@ struct wire_cst_restore_request *frbgen_breez_liquid_cst_new_box_autoadd_restore
 
 struct wire_cst_sdk_event *frbgen_breez_liquid_cst_new_box_autoadd_sdk_event(void);
 
+struct wire_cst_set_payment_metadata_request *frbgen_breez_liquid_cst_new_box_autoadd_set_payment_metadata_request(void);
+
+struct wire_cst_signer_result *frbgen_breez_liquid_cst_new_box_autoadd_signer_result(void);
+
 struct wire_cst_success_action_processed *frbgen_breez_liquid_cst_new_box_autoadd_success_action_processed(void);
 
 struct wire_cst_symbol *frbgen_breez_liquid_cst_new_box_autoadd_symbol(void);
@@ -1008,6 +1442,12 @@ uint64_t *frbgen_breez_liquid_cst_new_box_autoadd_u_64(uint64_t value);
 
 struct wire_cst_url_success_action_data *frbgen_breez_liquid_cst_new_box_autoadd_url_success_action_data(void);
 
+struct wire_cst_list_String *frbgen_breez_liquid_cst_new_list_String(int32_t len);
+
+struct wire_cst_list_asset_balance *frbgen_breez_liquid_cst_new_list_asset_balance(int32_t len);
+
+struct wire_cst_list_asset_metadata *frbgen_breez_liquid_cst_new_list_asset_metadata(int32_t len);
+
 struct wire_cst_list_fiat_currency *frbgen_breez_liquid_cst_new_list_fiat_currency(int32_t len);
 
 struct wire_cst_list_locale_overrides *frbgen_breez_liquid_cst_new_list_locale_overrides(int32_t len);
@@ -1016,6 +1456,16 @@ struct wire_cst_list_localized_name *frbgen_breez_liquid_cst_new_list_localized_
 
 struct wire_cst_list_payment *frbgen_breez_liquid_cst_new_list_payment(int32_t len);
 
+struct wire_cst_list_payment_fiat_rate *frbgen_breez_liquid_cst_new_list_payment_fiat_rate(int32_t len);
+
+struct wire_cst_list_payment_kind *frbgen_breez_liquid_cst_new_list_payment_kind(int32_t len);
+
+struct wire_cst_list_payment_metadata *frbgen_breez_liquid_cst_new_list_payment_metadata(int32_t len);
+
+struct wire_cst_list_payment_metadata_filter *frbgen_breez_liquid_cst_new_list_payment_metadata_filter(int32_t len);
+
+struct wire_cst_list_payment_state *frbgen_breez_liquid_cst_new_list_payment_state(int32_t len);
+
 struct wire_cst_list_payment_type *frbgen_breez_liquid_cst_new_list_payment_type(int32_t len);
 
 struct wire_cst_list_prim_u_8_strict *frbgen_breez_liquid_cst_new_list_prim_u_8_strict(int32_t len);
@@ -1029,14 +1479,22 @@ struct wire_cst_list_route_hint *frbgen_breez_liquid_cst_new_list_route_hint(int
 struct wire_cst_list_route_hint_hop *frbgen_breez_liquid_cst_new_list_route_hint_hop(int32_t len);
 static int64_t dummy_method_to_enforce_bundling(void) {
     int64_t dummy_var = 0;
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_accept_payment_proposed_fees_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_aes_success_action_data_decrypted);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_aes_success_action_data_result);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_asset_metadata);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_auto_refund_details);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_auto_refund_policy);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_backup_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_binding_event_listener);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_bitcoin_address_data);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_bool);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_bump_refund_fee_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_buy_bitcoin_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_connect_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_delete_payment_metadata_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_export_payments_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_fetch_payment_proposed_fees_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_i_64);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_list_payments_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_ln_invoice);
@@ -1052,27 +1510,41 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_message_success_action_data);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_pay_onchain_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_payment_lookup);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_buy_bitcoin_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_pay_onchain_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_onchain_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_onchain_response);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_payment_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_refund_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_liquid_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_liquid_response);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_response);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_receive_liquid_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_receive_payment_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_refund_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_restore_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_sdk_event);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_set_payment_metadata_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_signer_result);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_success_action_processed);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_symbol);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_u_32);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_u_64);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_url_success_action_data);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_String);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_asset_balance);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_asset_metadata);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_fiat_currency);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_locale_overrides);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_localized_name);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_payment_fiat_rate);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_payment_kind);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_payment_metadata);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_payment_metadata_filter);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_payment_state);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_payment_type);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_prim_u_8_strict);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_rate);
@@ -1081,17 +1553,25 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_route_hint_hop);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_accept_payment_proposed_fees);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_add_event_listener);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_backup);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_bump_refund_fee);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_buy_bitcoin);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_delete_payment_metadata);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_disconnect);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_payments);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_fiat_rates);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limits);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_onchain_limits);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_payment_proposed_fees);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_info);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment_metadata);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_fiat_currencies);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments_page);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_refundables);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_auth);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_pay);
@@ -1102,21 +1582,29 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_receive_onchain);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_refund);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_liquid);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_liquid);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_onchain);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_payment);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_recommended_fees);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refund);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_rescan_onchain_swaps);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_restore);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_liquid);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_set_payment_metadata);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__binding_event_listener_on_event);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__breez_log_stream);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__connect);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__connect_with_signer);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__default_config);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__parse);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__parse_invoice);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__parse_offer);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__respond_to_signer_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__signer_request_stream);
     dummy_var ^= ((int64_t) (void*) store_dart_post_cobject);
     return dummy_var;
 }
@@ -18,6 +18,19 @@ RCT_EXTERN_METHOD(
     reject: (RCTPromiseRejectBlock)reject
 )
 
+RCT_EXTERN_METHOD(
+    connectWithSigner: (NSDictionary*)req
+    resolve: (RCTPromiseResolveBlock)resolve
+    reject: (RCTPromiseRejectBlock)reject
+)
+
+RCT_EXTERN_METHOD(
+    respondToSignerRequest: (NSInteger)id
+    result: (NSDictionary*)result
+    resolve: (RCTPromiseResolveBlock)resolve
+    reject: (RCTPromiseRejectBlock)reject
+)
+
 RCT_EXTERN_METHOD(
     addEventListener: (RCTPromiseResolveBlock)resolve
     reject: (RCTPromiseRejectBlock)reject
@@ -7,9 +7,10 @@ class RNBreezSDKLiquid: RCTEventEmitter {
     
     public static var emitter: RCTEventEmitter!
     public static var hasListeners: Bool = false
-    public static var supportedEvents: [String] = ["breezSdkLiquidLog"]
+    public static var supportedEvents: [String] = ["breezSdkLiquidLog", "breezSdkLiquidSignerRequest"]
 
     private var bindingLiquidSdk: BindingLiquidSdk!
+    private var signer: BreezSDKLiquidSigner?
 
 
     static var breezSdkLiquidDirectory: URL {
@@ -105,6 +106,43 @@ class RNBreezSDKLiquid: RCTEventEmitter {
             rejectErr(err: err, reject: reject)
         }
     }
+
+    @objc(connectWithSigner:resolve:reject:)
+    func connectWithSigner(_ req:[String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) -> Void {
+        if bindingLiquidSdk != nil {
+            reject("Generic", "Already initialized", nil)
+            return
+        }
+
+        // The signer blocks until respondToSignerRequest is called on the method queue,
+        // so the connection is made off it
+        DispatchQueue.global().async {
+            do {
+                var connectRequest = try BreezSDKLiquidMapper.asConnectRequest(connectRequest: req)
+                try self.ensureWorkingDir(workingDir: connectRequest.config.workingDir)
+
+                let signer = BreezSDKLiquidSigner()
+                self.signer = signer
+                self.bindingLiquidSdk = try BreezSDKLiquid.connectWithSigner(req: connectRequest, signer: signer)
+                resolve(["status": "ok"])
+            } catch let err {
+                self.rejectErr(err: err, reject: reject)
+            }
+        }
+    }
+
+    @objc(respondToSignerRequest:result:resolve:reject:)
+    func respondToSignerRequest(_ id: Int, result: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) -> Void {
+        do {
+            guard let signer = signer else {
+                throw SdkError.Generic(message: "Not connected with a signer")
+            }
+            try signer.respond(id: id, result: result)
+            resolve(["status": "ok"])
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
  
     @objc(addEventListener:reject:)
     func addEventListener(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
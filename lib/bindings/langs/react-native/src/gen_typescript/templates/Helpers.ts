@@ -3,11 +3,67 @@ export type EventListener = (e: SdkEvent) => void
 
 export type Logger = (logEntry: LogEntry) => void
 
+export interface Signer {
+    xpub: () => Promise<string>
+    deriveXpub: (derivationPath: string) => Promise<string>
+    slip77MasterBlindingKey: () => Promise<string>
+    signPset: (pset: string) => Promise<string>
+    signEcdsa: (msg: number[], derivationPath: string) => Promise<number[]>
+    hmacSha256: (msg: number[], derivationPath: string) => Promise<number[]>
+}
+
+interface SignerRequest {
+    id: number
+    method: keyof Signer
+    derivationPath: string
+    pset: string
+    msg: number[]
+}
+
+export const connect = async (req: ConnectRequest): Promise<void> => {
+    const response = await BreezSDKLiquid.connect(req)
+    return response
+}
 export const connect = async (req: ConnectRequest): Promise<void> => {
     const response = await BreezSDKLiquid.connect(req)
     return response
 }
 
+const callSigner = async (signer: Signer, req: SignerRequest): Promise<string | number[]> => {
+    switch (req.method) {
+        case "xpub":
+            return await signer.xpub()
+        case "deriveXpub":
+            return await signer.deriveXpub(req.derivationPath)
+        case "slip77MasterBlindingKey":
+            return await signer.slip77MasterBlindingKey()
+        case "signPset":
+            return await signer.signPset(req.pset)
+        case "signEcdsa":
+            return await signer.signEcdsa(req.msg, req.derivationPath)
+        case "hmacSha256":
+            return await signer.hmacSha256(req.msg, req.derivationPath)
+    }
+}
+
+export const connectWithSigner = async (req: ConnectRequest, signer: Signer): Promise<void> => {
+    const subscription = BreezSDKLiquidEmitter.addListener("breezSdkLiquidSignerRequest", async (signerRequest: SignerRequest) => {
+        try {
+            const value = await callSigner(signer, signerRequest)
+            await BreezSDKLiquid.respondToSignerRequest(signerRequest.id, { value })
+        } catch (e) {
+            await BreezSDKLiquid.respondToSignerRequest(signerRequest.id, { error: `${e}` })
+        }
+    })
+
+    try {
+        await BreezSDKLiquid.connectWithSigner(req)
+    } catch (e) {
+        subscription.remove()
+        throw e
+    }
+}
+
 export const addEventListener = async (listener: EventListener): Promise<string> => {
     const response = await BreezSDKLiquid.addEventListener()
     BreezSDKLiquidEmitter.addListener(`event-${response}`, listener)
@@ -59,6 +59,77 @@ pub fn set_logger(logger: Box<dyn Logger>) -> Result<(), SdkError> {
     Ok(())
 }
 
+/// The wallet signer implemented by the app, see [breez_sdk_liquid::signer::Signer]
+pub trait Signer: Send + Sync {
+    fn xpub(&self) -> Result<String, SignerError>;
+    fn derive_xpub(&self, derivation_path: String) -> Result<String, SignerError>;
+    fn slip77_master_blinding_key(&self) -> Result<String, SignerError>;
+    fn sign_pset(&self, pset: String) -> Result<String, SignerError>;
+    fn sign_ecdsa(&self, msg: Vec<u8>, derivation_path: String) -> Result<Vec<u8>, SignerError>;
+    fn hmac_sha256(&self, msg: Vec<u8>, derivation_path: String) -> Result<Vec<u8>, SignerError>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SignerError {
+    #[error("Signer error: {err}")]
+    Generic { err: String },
+}
+
+impl From<uniffi::UnexpectedUniFFICallbackError> for SignerError {
+    fn from(err: uniffi::UnexpectedUniFFICallbackError) -> Self {
+        SignerError::Generic { err: err.reason }
+    }
+}
+
+impl From<SignerError> for breez_sdk_liquid::error::SignerError {
+    fn from(err: SignerError) -> Self {
+        match err {
+            SignerError::Generic { err } => breez_sdk_liquid::error::SignerError::Generic { err },
+        }
+    }
+}
+
+struct UniffiBindingSigner {
+    signer: Box<dyn Signer>,
+}
+
+impl breez_sdk_liquid::signer::Signer for UniffiBindingSigner {
+    fn xpub(&self) -> Result<String, breez_sdk_liquid::error::SignerError> {
+        Ok(self.signer.xpub()?)
+    }
+
+    fn derive_xpub(
+        &self,
+        derivation_path: String,
+    ) -> Result<String, breez_sdk_liquid::error::SignerError> {
+        Ok(self.signer.derive_xpub(derivation_path)?)
+    }
+
+    fn slip77_master_blinding_key(&self) -> Result<String, breez_sdk_liquid::error::SignerError> {
+        Ok(self.signer.slip77_master_blinding_key()?)
+    }
+
+    fn sign_pset(&self, pset: String) -> Result<String, breez_sdk_liquid::error::SignerError> {
+        Ok(self.signer.sign_pset(pset)?)
+    }
+
+    fn sign_ecdsa(
+        &self,
+        msg: Vec<u8>,
+        derivation_path: String,
+    ) -> Result<Vec<u8>, breez_sdk_liquid::error::SignerError> {
+        Ok(self.signer.sign_ecdsa(msg, derivation_path)?)
+    }
+
+    fn hmac_sha256(
+        &self,
+        msg: Vec<u8>,
+        derivation_path: String,
+    ) -> Result<Vec<u8>, breez_sdk_liquid::error::SignerError> {
+        Ok(self.signer.hmac_sha256(msg, derivation_path)?)
+    }
+}
+
 pub fn connect(req: ConnectRequest) -> Result<Arc<BindingLiquidSdk>, SdkError> {
     rt().block_on(async {
         let sdk = LiquidSdk::connect(req).await?;
@@ -66,6 +137,17 @@ pub fn connect(req: ConnectRequest) -> Result<Arc<BindingLiquidSdk>, SdkError> {
     })
 }
 
+pub fn connect_with_signer(
+    req: ConnectRequest,
+    signer: Box<dyn Signer>,
+) -> Result<Arc<BindingLiquidSdk>, SdkError> {
+    rt().block_on(async {
+        let signer = Box::new(UniffiBindingSigner { signer });
+        let sdk = LiquidSdk::connect_with_signer(req, signer).await?;
+        Ok(Arc::from(BindingLiquidSdk { sdk }))
+    })
+}
+
 pub fn default_config(network: LiquidNetwork) -> Config {
     LiquidSdk::default_config(network)
 }
@@ -76,6 +158,9 @@ pub fn parse(input: String) -> Result<InputType, PaymentError> {
 pub fn parse_invoice(input: String) -> Result<LNInvoice, PaymentError> {
     LiquidSdk::parse_invoice(&input)
 }
+pub fn parse_offer(input: String) -> Result<LNOffer, PaymentError> {
+    LiquidSdk::parse_offer(&input)
+}
 
 pub struct BindingLiquidSdk {
     sdk: Arc<LiquidSdk>,
@@ -108,6 +193,20 @@ impl BindingLiquidSdk {
         rt().block_on(self.sdk.send_payment(&req))
     }
 
+    pub fn prepare_send_liquid(
+        &self,
+        req: PrepareSendLiquidRequest,
+    ) -> Result<PrepareSendLiquidResponse, PaymentError> {
+        rt().block_on(self.sdk.prepare_send_liquid(&req))
+    }
+
+    pub fn send_liquid(
+        &self,
+        req: PrepareSendLiquidResponse,
+    ) -> Result<SendPaymentResponse, PaymentError> {
+        rt().block_on(self.sdk.send_liquid(&req))
+    }
+
     pub fn prepare_receive_payment(
         &self,
         req: PrepareReceivePaymentRequest,
@@ -122,6 +221,13 @@ impl BindingLiquidSdk {
         rt().block_on(self.sdk.receive_payment(&req))
     }
 
+    pub fn receive_liquid(
+        &self,
+        req: ReceiveLiquidRequest,
+    ) -> Result<ReceiveLiquidResponse, PaymentError> {
+        rt().block_on(self.sdk.receive_liquid(&req))
+    }
+
     pub fn fetch_lightning_limits(&self) -> Result<LightningPaymentLimitsResponse, PaymentError> {
         rt().block_on(self.sdk.fetch_lightning_limits())
     }
@@ -155,6 +261,20 @@ impl BindingLiquidSdk {
         rt().block_on(self.sdk.receive_onchain(&req))
     }
 
+    pub fn fetch_payment_proposed_fees(
+        &self,
+        req: FetchPaymentProposedFeesRequest,
+    ) -> Result<FetchPaymentProposedFeesResponse, PaymentError> {
+        rt().block_on(self.sdk.fetch_payment_proposed_fees(&req))
+    }
+
+    pub fn accept_payment_proposed_fees(
+        &self,
+        req: AcceptPaymentProposedFeesRequest,
+    ) -> Result<(), PaymentError> {
+        rt().block_on(self.sdk.accept_payment_proposed_fees(&req))
+    }
+
     pub fn prepare_buy_bitcoin(
         &self,
         req: PrepareBuyBitcoinRequest,
@@ -170,6 +290,39 @@ impl BindingLiquidSdk {
         rt().block_on(self.sdk.list_payments(&req))
     }
 
+    pub fn export_payments(&self, req: ExportPaymentsRequest) -> Result<(), PaymentError> {
+        rt().block_on(self.sdk.export_payments(&req))
+    }
+
+    pub fn list_payments_page(
+        &self,
+        req: ListPaymentsRequest,
+    ) -> Result<ListPaymentsResponse, PaymentError> {
+        rt().block_on(self.sdk.list_payments_page(&req))
+    }
+
+    pub fn get_payment(&self, req: PaymentLookup) -> Result<Option<Payment>, PaymentError> {
+        rt().block_on(self.sdk.get_payment(&req))
+    }
+
+    pub fn set_payment_metadata(&self, req: SetPaymentMetadataRequest) -> Result<(), PaymentError> {
+        rt().block_on(self.sdk.set_payment_metadata(&req))
+    }
+
+    pub fn get_payment_metadata(
+        &self,
+        payment_id: String,
+    ) -> Result<Vec<PaymentMetadata>, PaymentError> {
+        rt().block_on(self.sdk.get_payment_metadata(&payment_id))
+    }
+
+    pub fn delete_payment_metadata(
+        &self,
+        req: DeletePaymentMetadataRequest,
+    ) -> Result<(), PaymentError> {
+        rt().block_on(self.sdk.delete_payment_metadata(&req))
+    }
+
     pub fn lnurl_pay(&self, req: LnUrlPayRequest) -> Result<LnUrlPayResult, LnUrlPayError> {
         rt().block_on(self.sdk.lnurl_pay(req)).map_err(Into::into)
     }
@@ -209,6 +362,13 @@ impl BindingLiquidSdk {
         rt().block_on(self.sdk.refund(&req))
     }
 
+    pub fn bump_refund_fee(
+        &self,
+        req: BumpRefundFeeRequest,
+    ) -> Result<BumpRefundFeeResponse, PaymentError> {
+        rt().block_on(self.sdk.bump_refund_fee(&req))
+    }
+
     pub fn rescan_onchain_swaps(&self) -> SdkResult<()> {
         rt().block_on(self.sdk.rescan_onchain_swaps())
     }
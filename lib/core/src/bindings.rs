@@ -1,6 +1,8 @@
 //! Dart / flutter bindings
 
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{mpsc, Arc, Mutex, MutexGuard, OnceLock};
 
 use anyhow::Result;
 use flutter_rust_bridge::frb;
@@ -13,7 +15,7 @@ pub use sdk_common::prelude::{
     RouteHintHop, SuccessActionProcessed, Symbol, UrlSuccessActionData,
 };
 
-use crate::{error::*, frb_generated::StreamSink, model::*, sdk::LiquidSdk};
+use crate::{error::*, frb_generated::StreamSink, model::*, sdk::LiquidSdk, signer::Signer};
 
 pub struct BindingEventListener {
     pub stream: StreamSink<SdkEvent>,
@@ -53,6 +55,102 @@ impl log::Log for DartBindingLogger {
     fn flush(&self) {}
 }
 
+/// The signer of [connect_with_signer], set by [signer_request_stream]
+static DART_SIGNER: OnceLock<DartBindingSigner> = OnceLock::new();
+
+/// Forwards each [Signer] call to the app as a [SignerRequest], then blocks until the app
+/// answers it with [respond_to_signer_request]
+struct DartBindingSigner {
+    requests: StreamSink<SignerRequest>,
+    next_id: AtomicU32,
+    pending: Mutex<HashMap<u32, mpsc::Sender<SignerResult>>>,
+}
+
+impl DartBindingSigner {
+    fn request(&self, method: SignerMethod) -> Result<SignerResult, SignerError> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let (result_tx, result_rx) = mpsc::channel();
+        self.pending()?.insert(id, result_tx);
+        if self.requests.add(SignerRequest { id, method }).is_err() {
+            self.pending()?.remove(&id);
+            return Err(SignerError::Generic {
+                err: "Failed to send the signer request".to_string(),
+            });
+        }
+        match result_rx.recv() {
+            Ok(SignerResult::Error { err }) => Err(SignerError::Generic { err }),
+            Ok(result) => Ok(result),
+            Err(_) => Err(SignerError::Generic {
+                err: format!("Signer request {id} was dropped without a result"),
+            }),
+        }
+    }
+
+    fn request_string(&self, method: SignerMethod) -> Result<String, SignerError> {
+        match self.request(method)? {
+            SignerResult::String { value } => Ok(value),
+            result => Err(SignerError::Generic {
+                err: format!("Expected a string signer result, got {result:?}"),
+            }),
+        }
+    }
+
+    fn request_bytes(&self, method: SignerMethod) -> Result<Vec<u8>, SignerError> {
+        match self.request(method)? {
+            SignerResult::Bytes { value } => Ok(value),
+            result => Err(SignerError::Generic {
+                err: format!("Expected a bytes signer result, got {result:?}"),
+            }),
+        }
+    }
+
+    fn respond(&self, id: u32, result: SignerResult) -> Result<(), SignerError> {
+        let result_tx = self.pending()?.remove(&id).ok_or(SignerError::Generic {
+            err: format!("No pending signer request {id}"),
+        })?;
+        let _ = result_tx.send(result);
+        Ok(())
+    }
+
+    fn pending(&self) -> Result<MutexGuard<HashMap<u32, mpsc::Sender<SignerResult>>>, SignerError> {
+        self.pending.lock().map_err(|_| SignerError::Generic {
+            err: "Failed to lock the pending signer requests".to_string(),
+        })
+    }
+}
+
+impl Signer for &'static DartBindingSigner {
+    fn xpub(&self) -> Result<String, SignerError> {
+        self.request_string(SignerMethod::Xpub)
+    }
+
+    fn derive_xpub(&self, derivation_path: String) -> Result<String, SignerError> {
+        self.request_string(SignerMethod::DeriveXpub { derivation_path })
+    }
+
+    fn slip77_master_blinding_key(&self) -> Result<String, SignerError> {
+        self.request_string(SignerMethod::Slip77MasterBlindingKey)
+    }
+
+    fn sign_pset(&self, pset: String) -> Result<String, SignerError> {
+        self.request_string(SignerMethod::SignPset { pset })
+    }
+
+    fn sign_ecdsa(&self, msg: Vec<u8>, derivation_path: String) -> Result<Vec<u8>, SignerError> {
+        self.request_bytes(SignerMethod::SignEcdsa {
+            msg,
+            derivation_path,
+        })
+    }
+
+    fn hmac_sha256(&self, msg: Vec<u8>, derivation_path: String) -> Result<Vec<u8>, SignerError> {
+        self.request_bytes(SignerMethod::HmacSha256 {
+            msg,
+            derivation_path,
+        })
+    }
+}
+
 pub async fn connect(req: ConnectRequest) -> Result<BindingLiquidSdk, SdkError> {
     let ln_sdk = LiquidSdk::connect(req).await?;
     Ok(BindingLiquidSdk { sdk: ln_sdk })
@@ -66,6 +164,44 @@ pub fn breez_log_stream(s: StreamSink<LogEntry>) -> Result<()> {
     Ok(())
 }
 
+/// Connects with a signer implemented by the app, see [LiquidSdk::connect_with_signer].
+///
+/// A Dart object cannot implement the Rust signer trait, so unlike in the Uniffi bindings the
+/// signer is not passed as an argument. Instead, the app answers the requests of the
+/// [signer_request_stream] with [respond_to_signer_request], which has to be set up first.
+pub async fn connect_with_signer(req: ConnectRequest) -> Result<BindingLiquidSdk, SdkError> {
+    let signer = DART_SIGNER.get().ok_or(SdkError::Generic {
+        err: "The signer request stream has to be created first".into(),
+    })?;
+    let ln_sdk = LiquidSdk::connect_with_signer(req, Box::new(signer)).await?;
+    Ok(BindingLiquidSdk { sdk: ln_sdk })
+}
+
+/// The requests to the signer of `connect_with_signer`. If used, this must be called before
+/// `connect_with_signer`. It can only be called once.
+pub fn signer_request_stream(s: StreamSink<SignerRequest>) -> Result<()> {
+    let signer = DartBindingSigner {
+        requests: s,
+        next_id: AtomicU32::new(0),
+        pending: Mutex::new(HashMap::new()),
+    };
+    DART_SIGNER.set(signer).map_err(|_| SdkError::Generic {
+        err: "Signer request stream already created".into(),
+    })?;
+    Ok(())
+}
+
+/// Answers the [SignerRequest] with the given `id`
+#[frb(sync)]
+pub fn respond_to_signer_request(id: u32, result: SignerResult) -> Result<(), SdkError> {
+    let signer = DART_SIGNER.get().ok_or(SdkError::Generic {
+        err: "The signer request stream was not created".into(),
+    })?;
+    signer
+        .respond(id, result)
+        .map_err(|e| SdkError::Generic { err: e.to_string() })
+}
+
 #[frb(sync)]
 pub fn default_config(network: LiquidNetwork) -> Config {
     LiquidSdk::default_config(network)
@@ -80,6 +216,11 @@ pub fn parse_invoice(input: String) -> Result<LNInvoice, PaymentError> {
     LiquidSdk::parse_invoice(&input)
 }
 
+#[frb(sync)]
+pub fn parse_offer(input: String) -> Result<LNOffer, PaymentError> {
+    LiquidSdk::parse_offer(&input)
+}
+
 pub struct BindingLiquidSdk {
     sdk: Arc<LiquidSdk>,
 }
@@ -112,6 +253,20 @@ impl BindingLiquidSdk {
         self.sdk.send_payment(&req).await
     }
 
+    pub async fn prepare_send_liquid(
+        &self,
+        req: PrepareSendLiquidRequest,
+    ) -> Result<PrepareSendLiquidResponse, PaymentError> {
+        self.sdk.prepare_send_liquid(&req).await
+    }
+
+    pub async fn send_liquid(
+        &self,
+        req: PrepareSendLiquidResponse,
+    ) -> Result<SendPaymentResponse, PaymentError> {
+        self.sdk.send_liquid(&req).await
+    }
+
     pub async fn prepare_receive_payment(
         &self,
         req: PrepareReceivePaymentRequest,
@@ -126,6 +281,13 @@ impl BindingLiquidSdk {
         self.sdk.receive_payment(&req).await
     }
 
+    pub async fn receive_liquid(
+        &self,
+        req: ReceiveLiquidRequest,
+    ) -> Result<ReceiveLiquidResponse, PaymentError> {
+        self.sdk.receive_liquid(&req).await
+    }
+
     pub async fn fetch_lightning_limits(
         &self,
     ) -> Result<LightningPaymentLimitsResponse, PaymentError> {
@@ -164,6 +326,20 @@ impl BindingLiquidSdk {
         self.sdk.receive_onchain(&req).await
     }
 
+    pub async fn fetch_payment_proposed_fees(
+        &self,
+        req: FetchPaymentProposedFeesRequest,
+    ) -> Result<FetchPaymentProposedFeesResponse, PaymentError> {
+        self.sdk.fetch_payment_proposed_fees(&req).await
+    }
+
+    pub async fn accept_payment_proposed_fees(
+        &self,
+        req: AcceptPaymentProposedFeesRequest,
+    ) -> Result<(), PaymentError> {
+        self.sdk.accept_payment_proposed_fees(&req).await
+    }
+
     pub async fn prepare_buy_bitcoin(
         &self,
         req: PrepareBuyBitcoinRequest,
@@ -182,6 +358,42 @@ impl BindingLiquidSdk {
         self.sdk.list_payments(&req).await
     }
 
+    pub async fn export_payments(&self, req: ExportPaymentsRequest) -> Result<(), PaymentError> {
+        self.sdk.export_payments(&req).await
+    }
+
+    pub async fn list_payments_page(
+        &self,
+        req: ListPaymentsRequest,
+    ) -> Result<ListPaymentsResponse, PaymentError> {
+        self.sdk.list_payments_page(&req).await
+    }
+
+    pub async fn get_payment(&self, req: PaymentLookup) -> Result<Option<Payment>, PaymentError> {
+        self.sdk.get_payment(&req).await
+    }
+
+    pub async fn set_payment_metadata(
+        &self,
+        req: SetPaymentMetadataRequest,
+    ) -> Result<(), PaymentError> {
+        self.sdk.set_payment_metadata(&req).await
+    }
+
+    pub async fn get_payment_metadata(
+        &self,
+        payment_id: String,
+    ) -> Result<Vec<PaymentMetadata>, PaymentError> {
+        self.sdk.get_payment_metadata(&payment_id).await
+    }
+
+    pub async fn delete_payment_metadata(
+        &self,
+        req: DeletePaymentMetadataRequest,
+    ) -> Result<(), PaymentError> {
+        self.sdk.delete_payment_metadata(&req).await
+    }
+
     pub async fn lnurl_pay(
         &self,
         req: LnUrlPayRequest,
@@ -234,6 +446,13 @@ impl BindingLiquidSdk {
         self.sdk.refund(&req).await
     }
 
+    pub async fn bump_refund_fee(
+        &self,
+        req: BumpRefundFeeRequest,
+    ) -> Result<BumpRefundFeeResponse, PaymentError> {
+        self.sdk.bump_refund_fee(&req).await
+    }
+
     pub async fn rescan_onchain_swaps(&self) -> Result<(), SdkError> {
         self.sdk.rescan_onchain_swaps().await
     }
@@ -0,0 +1,324 @@
+use std::str::FromStr;
+
+use bech32::FromBase32;
+use boltz_client::Bolt11Invoice;
+use lightning::bitcoin::{blockdata::constants::ChainHash, Network};
+use lightning::offers::invoice::Bolt12Invoice;
+use lightning::offers::offer::{Amount, Offer};
+
+use crate::ensure_sdk;
+use crate::error::PaymentError;
+use crate::model::{LNOffer, LiquidNetwork};
+
+/// The human readable part of bech32 encoded BOLT12 invoices
+const BOLT12_INVOICE_HRP: &str = "lni";
+
+/// The Lightning chain BOLT12 offers and invoices have to be on, to be paid on the given network
+pub(crate) fn chain_hash(network: LiquidNetwork) -> ChainHash {
+    ChainHash::using_genesis_block(match network {
+        LiquidNetwork::Mainnet => Network::Bitcoin,
+        LiquidNetwork::Testnet => Network::Testnet,
+        LiquidNetwork::Regtest => Network::Regtest,
+    })
+}
+
+/// The human readable part of bech32 encoded BOLT12 offers
+const BOLT12_OFFER_HRP: &str = "lno";
+
+/// Whether the input is a BOLT12 offer, rather than a BOLT11 invoice
+pub(crate) fn is_offer(input: &str) -> bool {
+    input.trim().to_lowercase().starts_with(BOLT12_OFFER_HRP)
+}
+
+pub(crate) fn parse_offer(offer: &str) -> Result<Offer, PaymentError> {
+    Offer::from_str(offer.trim()).map_err(|e| PaymentError::InvalidInvoice {
+        err: format!("Invalid BOLT12 offer: {e:?}"),
+    })
+}
+
+/// Parses the offer and checks that it can be paid on the given network
+pub(crate) fn validate_offer(offer: &str, network: LiquidNetwork) -> Result<Offer, PaymentError> {
+    let offer = parse_offer(offer)?;
+    ensure_sdk!(
+        offer.supports_chain(chain_hash(network)),
+        PaymentError::InvalidInvoice {
+            err: "Offer cannot be paid on the current network".to_string()
+        }
+    );
+    ensure_sdk!(
+        !offer.is_expired(),
+        PaymentError::InvalidInvoice {
+            err: "Offer has expired".to_string()
+        }
+    );
+    Ok(offer)
+}
+
+/// Resolves the amount to pay for the given offer. Offers without an amount require a
+/// caller-supplied amount, while offers with an amount only accept a matching one. As payments
+/// are made in whole sats, offers for an amount with a fraction of a sat are rejected.
+pub(crate) fn get_receiver_amount_sat(
+    offer: &Offer,
+    amount_sat: Option<u64>,
+) -> Result<u64, PaymentError> {
+    let offer_amount_sat = match offer.amount() {
+        Some(Amount::Bitcoin { amount_msats }) => {
+            ensure_sdk!(
+                amount_msats % 1000 == 0,
+                PaymentError::InvalidInvoice {
+                    err: format!(
+                        "Offer amount of {amount_msats} msat is not a whole number of sats"
+                    )
+                }
+            );
+            Some(amount_msats / 1000)
+        }
+        Some(Amount::Currency { .. }) => {
+            return Err(PaymentError::InvalidInvoice {
+                err: "Offers with an amount in a fiat currency are not supported".to_string(),
+            })
+        }
+        None => None,
+    };
+    match (offer_amount_sat, amount_sat) {
+        (Some(offer_amount_sat), None) => Ok(offer_amount_sat),
+        (Some(offer_amount_sat), Some(amount_sat)) => {
+            ensure_sdk!(
+                offer_amount_sat == amount_sat,
+                PaymentError::InvalidInvoice {
+                    err: "The specified amount does not match the offer amount".to_string()
+                }
+            );
+            Ok(amount_sat)
+        }
+        (None, Some(amount_sat)) => {
+            ensure_sdk!(amount_sat > 0, PaymentError::AmountOutOfRange);
+            Ok(amount_sat)
+        }
+        (None, None) => Err(PaymentError::AmountMissing {
+            err: "An amount is required to pay an offer without an amount".to_string(),
+        }),
+    }
+}
+
+/// Parses a BOLT12 invoice, as returned by the swapper in its bech32 encoding
+pub(crate) fn parse_invoice(invoice: &str) -> Result<Bolt12Invoice, PaymentError> {
+    let invalid_invoice = |err: String| PaymentError::InvalidInvoice { err };
+    let (hrp, data) = bech32::decode_without_checksum(invoice.trim())
+        .map_err(|e| invalid_invoice(format!("Invalid BOLT12 invoice encoding: {e}")))?;
+    ensure_sdk!(
+        hrp == BOLT12_INVOICE_HRP,
+        invalid_invoice(format!("Unexpected BOLT12 invoice prefix: {hrp}"))
+    );
+    let bytes = Vec::<u8>::from_base32(&data).map_err(|e| invalid_invoice(e.to_string()))?;
+    Bolt12Invoice::try_from(bytes)
+        .map_err(|e| invalid_invoice(format!("Invalid BOLT12 invoice: {e:?}")))
+}
+
+/// Parses an invoice fetched for the offer and checks that it was signed by the offer node, is for
+/// the expected amount, on the given network and not yet expired
+pub(crate) fn validate_invoice(
+    invoice: &str,
+    offer: &Offer,
+    amount_sat: u64,
+    network: LiquidNetwork,
+) -> Result<Bolt12Invoice, PaymentError> {
+    let invoice = parse_invoice(invoice)?;
+    ensure_sdk!(
+        invoice.chain() == chain_hash(network),
+        PaymentError::InvalidInvoice {
+            err: "Invoice cannot be paid on the current network".to_string()
+        }
+    );
+    ensure_sdk!(
+        invoice.signing_pubkey() == offer.signing_pubkey()
+            && invoice.description().to_string() == offer.description().to_string(),
+        PaymentError::InvalidInvoice {
+            err: "Invoice does not match the offer".to_string()
+        }
+    );
+    ensure_sdk!(
+        invoice.amount_msats() == amount_sat * 1000,
+        PaymentError::InvalidInvoice {
+            err: format!(
+                "Invoice amount of {} msat does not match the expected {amount_sat} sat",
+                invoice.amount_msats()
+            )
+        }
+    );
+    ensure_sdk!(
+        !invoice.is_expired(),
+        PaymentError::InvalidInvoice {
+            err: "Invoice has expired".to_string()
+        }
+    );
+    Ok(invoice)
+}
+
+pub(crate) fn get_payer_note(invoice: &Bolt12Invoice) -> Option<String> {
+    invoice
+        .payer_note()
+        .map(|payer_note| payer_note.to_string())
+}
+
+/// Whether the invoice paid by a send swap is a BOLT12 invoice, rather than a BOLT11 one
+pub(crate) fn is_invoice(invoice: &str) -> bool {
+    invoice
+        .trim()
+        .to_lowercase()
+        .starts_with(BOLT12_INVOICE_HRP)
+}
+
+/// The payment hash of a BOLT11 or BOLT12 invoice
+pub(crate) fn get_payment_hash(invoice: &str) -> Result<String, PaymentError> {
+    match is_invoice(invoice) {
+        true => Ok(hex::encode(parse_invoice(invoice)?.payment_hash().0)),
+        false => Bolt11Invoice::from_str(invoice.trim())
+            .map(|invoice| invoice.payment_hash().to_string())
+            .map_err(|e| PaymentError::InvalidInvoice { err: e.to_string() }),
+    }
+}
+
+pub(crate) fn get_invoice_description(invoice: &str) -> Option<String> {
+    parse_invoice(invoice)
+        .ok()
+        .map(|invoice| invoice.description().to_string())
+        .filter(|description| !description.is_empty())
+}
+
+impl From<&Offer> for LNOffer {
+    fn from(offer: &Offer) -> Self {
+        LNOffer {
+            offer: offer.to_string(),
+            chains: offer.chains().iter().map(ToString::to_string).collect(),
+            amount_sat: match offer.amount() {
+                Some(Amount::Bitcoin { amount_msats }) => Some(amount_msats / 1000),
+                _ => None,
+            },
+            description: Some(offer.description().to_string())
+                .filter(|description| !description.is_empty()),
+            absolute_expiry: offer.absolute_expiry().map(|expiry| expiry.as_secs()),
+            issuer: offer.issuer().map(|issuer| issuer.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use lightning::bitcoin::secp256k1::{PublicKey, Secp256k1, SecretKey};
+    use lightning::offers::offer::OfferBuilder;
+
+    use crate::error::PaymentError;
+    use crate::model::LiquidNetwork;
+    use crate::test_utils::persist::new_invoice;
+    use crate::test_utils::swapper::{TEST_BOLT12_INVOICE, TEST_BOLT12_OFFER};
+
+    use super::{
+        get_payer_note, get_payment_hash, get_receiver_amount_sat, is_invoice, is_offer,
+        parse_invoice, parse_offer, validate_invoice, validate_offer,
+    };
+
+    /// An offer for the same amount and description as [TEST_BOLT12_OFFER], by another node
+    const TEST_OTHER_NODE_BOLT12_OFFER: &str = "lno1qgsyxjtl6luzd9t3pr62xr7eemp6awnejusgf6gw45q75vcfqqqqqqqgqsp04uyqpgg5ymmvwscnygr5v4ehggr0venx2uskyypfdt5utvu2m4zjzf24l8ks88ev8uhm5ehfanfawmfgw34s457ltfg";
+
+    #[test]
+    fn test_bolt12_detection() {
+        let bolt11 = new_invoice(None).to_string();
+        assert!(!is_offer(&bolt11));
+        assert!(!is_invoice(&bolt11));
+        assert!(is_offer(" LNO1PQPS7SJQPGT "));
+        assert!(is_invoice("lni1qqgsyqcyq5rqwzqfq"));
+    }
+
+    #[test]
+    fn test_bolt12_parse_errors() {
+        assert!(matches!(
+            parse_offer("lno1invalid"),
+            Err(PaymentError::InvalidInvoice { .. })
+        ));
+        assert!(matches!(
+            parse_invoice("lno1qqgsyqcyq5rqwzqfq"),
+            Err(PaymentError::InvalidInvoice { .. })
+        ));
+        assert!(matches!(
+            get_payment_hash("lni1invalid"),
+            Err(PaymentError::InvalidInvoice { .. })
+        ));
+    }
+
+    #[test]
+    fn test_validate_invoice() -> anyhow::Result<()> {
+        let offer = validate_offer(TEST_BOLT12_OFFER, LiquidNetwork::Testnet)?;
+        let invoice =
+            validate_invoice(TEST_BOLT12_INVOICE, &offer, 50_000, LiquidNetwork::Testnet)?;
+        assert_eq!(get_payer_note(&invoice), Some("Thanks".to_string()));
+        assert_eq!(
+            get_payment_hash(TEST_BOLT12_INVOICE)?,
+            "da51749fe31c7decc11bab9f4429327ba7cd29676570a6236eba21f9002f17b2"
+        );
+
+        // The invoice has to be for the expected amount and network
+        assert!(matches!(
+            validate_invoice(TEST_BOLT12_INVOICE, &offer, 10_000, LiquidNetwork::Testnet),
+            Err(PaymentError::InvalidInvoice { .. })
+        ));
+        assert!(matches!(
+            validate_invoice(TEST_BOLT12_INVOICE, &offer, 50_000, LiquidNetwork::Mainnet),
+            Err(PaymentError::InvalidInvoice { .. })
+        ));
+        assert!(matches!(
+            validate_offer(TEST_BOLT12_OFFER, LiquidNetwork::Mainnet),
+            Err(PaymentError::InvalidInvoice { .. })
+        ));
+
+        // The invoice has to be signed by the offer node, even if the other offer fields match
+        let other_offer = validate_offer(TEST_OTHER_NODE_BOLT12_OFFER, LiquidNetwork::Testnet)?;
+        assert!(matches!(
+            validate_invoice(
+                TEST_BOLT12_INVOICE,
+                &other_offer,
+                50_000,
+                LiquidNetwork::Testnet
+            ),
+            Err(PaymentError::InvalidInvoice { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_receiver_amount_sat() -> anyhow::Result<()> {
+        let offer = validate_offer(TEST_BOLT12_OFFER, LiquidNetwork::Testnet)?;
+        assert_eq!(get_receiver_amount_sat(&offer, None)?, 50_000);
+        assert_eq!(get_receiver_amount_sat(&offer, Some(50_000))?, 50_000);
+        assert!(matches!(
+            get_receiver_amount_sat(&offer, Some(10_000)),
+            Err(PaymentError::InvalidInvoice { .. })
+        ));
+
+        // An amount with a fraction of a sat cannot be paid
+        let signing_pubkey =
+            PublicKey::from_secret_key(&Secp256k1::new(), &SecretKey::from_slice(&[1; 32])?);
+        let offer = OfferBuilder::new("Coffee".to_string(), signing_pubkey)
+            .amount_msats(50_000_500)
+            .build()
+            .map_err(|e| anyhow!("{e:?}"))?;
+        for amount_sat in [None, Some(50_000), Some(50_001)] {
+            assert!(matches!(
+                get_receiver_amount_sat(&offer, amount_sat),
+                Err(PaymentError::InvalidInvoice { .. })
+            ));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bolt11_payment_hash() -> anyhow::Result<()> {
+        let invoice = new_invoice(None);
+        assert_eq!(
+            get_payment_hash(&invoice.to_string())?,
+            invoice.payment_hash().to_string()
+        );
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use std::{thread, time::Duration};
+use std::{collections::HashSet, thread, time::Duration};
 
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
@@ -7,34 +7,43 @@ use electrum_client::{Client, ElectrumApi, GetBalanceRes, HeaderNotification};
 use log::info;
 use lwk_wollet::{
     bitcoin::{
+        block::Header,
         consensus::{deserialize, serialize},
-        Script, Transaction, Txid,
+        OutPoint, Script, Transaction, TxOut, Txid,
     },
-    hashes::{sha256, Hash},
-    ElectrumOptions, ElectrumUrl, Error, History,
+    ElectrumOptions, History,
 };
-use sdk_common::prelude::get_parse_and_log_response;
+use serde::Deserialize;
 
-use crate::model::{Config, RecommendedFees};
+use crate::{
+    chain::{
+        electrum_client, esplora_broadcast, esplora_script_hash, esplora_script_history,
+        esplora_tx_hex, get_with_retry, http_client, EsploraScriptInfo,
+    },
+    model::{Config, RecommendedFees},
+};
 
 /// Trait implemented by types that can fetch data from a blockchain data source.
 #[allow(dead_code)]
 #[async_trait]
 pub trait BitcoinChainService: Send + Sync {
     /// Get the blockchain latest block
-    fn tip(&mut self) -> Result<HeaderNotification>;
+    async fn tip(&mut self) -> Result<HeaderNotification>;
 
     /// Broadcast a transaction
-    fn broadcast(&self, tx: &Transaction) -> Result<Txid>;
+    async fn broadcast(&self, tx: &Transaction) -> Result<Txid>;
 
     /// Get a list of transactions
-    fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>>;
+    async fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>>;
 
     /// Get the transactions involved for a script
-    fn get_script_history(&self, script: &Script) -> Result<Vec<History>>;
+    async fn get_script_history(&self, script: &Script) -> Result<Vec<History>>;
 
     /// Return the confirmed and unconfirmed balances of a script hash
-    fn script_get_balance(&self, script: &Script) -> Result<GetBalanceRes>;
+    async fn script_get_balance(&self, script: &Script) -> Result<GetBalanceRes>;
+
+    /// Get the output paying to the script that no tx in the script history spends
+    async fn get_script_utxo(&self, script: &Script) -> Result<(OutPoint, TxOut)>;
 
     /// Verify that a transaction appears in the address script history
     async fn verify_tx(
@@ -51,61 +60,34 @@ pub trait BitcoinChainService: Send + Sync {
 
 pub(crate) struct HybridBitcoinChainService {
     client: Client,
+    http_client: reqwest::Client,
     tip: HeaderNotification,
     config: Config,
 }
 impl HybridBitcoinChainService {
-    pub fn new(config: Config) -> Result<Self, Error> {
+    pub fn new(config: Config) -> Result<Self> {
         Self::with_options(config, ElectrumOptions::default())
     }
 
-    /// Creates an Electrum client specifying non default options like timeout
-    pub fn with_options(config: Config, options: ElectrumOptions) -> Result<Self, Error> {
-        let url = ElectrumUrl::new(&config.bitcoin_electrum_url, true, true);
-        let client = url.build_client(&options)?;
+    /// Creates an Electrum client specifying non default options like timeout. If a
+    /// [Config::proxy_url] is set, the client connects through it instead.
+    pub fn with_options(config: Config, options: ElectrumOptions) -> Result<Self> {
+        let client = electrum_client(&config, &config.bitcoin_electrum_url, &options)?;
         let header = client.block_headers_subscribe_raw()?;
         let tip: HeaderNotification = header.try_into()?;
 
         Ok(Self {
             client,
+            http_client: http_client(&config)?,
             tip,
             config,
         })
     }
-
-    async fn get_script_history_with_retry(
-        &self,
-        script: &Script,
-        retries: u64,
-    ) -> Result<Vec<History>> {
-        let script_hash = sha256::Hash::hash(script.as_bytes())
-            .to_byte_array()
-            .to_hex();
-        info!("Fetching script history for {}", script_hash);
-        let mut script_history = vec![];
-
-        let mut retry = 0;
-        while retry <= retries {
-            script_history = self.get_script_history(script)?;
-            match script_history.is_empty() {
-                true => {
-                    retry += 1;
-                    info!(
-                        "Script history for {} got zero transactions, retrying in {} seconds...",
-                        script_hash, retry
-                    );
-                    thread::sleep(Duration::from_secs(retry));
-                }
-                false => break,
-            }
-        }
-        Ok(script_history)
-    }
 }
 
 #[async_trait]
 impl BitcoinChainService for HybridBitcoinChainService {
-    fn tip(&mut self) -> Result<HeaderNotification> {
+    async fn tip(&mut self) -> Result<HeaderNotification> {
         let mut maybe_popped_header = None;
         while let Some(header) = self.client.block_headers_pop_raw()? {
             maybe_popped_header = Some(header)
@@ -131,12 +113,12 @@ impl BitcoinChainService for HybridBitcoinChainService {
         Ok(self.tip.clone())
     }
 
-    fn broadcast(&self, tx: &Transaction) -> Result<Txid> {
+    async fn broadcast(&self, tx: &Transaction) -> Result<Txid> {
         let txid = self.client.transaction_broadcast_raw(&serialize(tx))?;
         Ok(Txid::from_raw_hash(txid.to_raw_hash()))
     }
 
-    fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>> {
+    async fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>> {
         let mut result = vec![];
         for tx in self.client.batch_transaction_get_raw(txids)? {
             let tx: Transaction = deserialize(&tx)?;
@@ -145,7 +127,7 @@ impl BitcoinChainService for HybridBitcoinChainService {
         Ok(result)
     }
 
-    fn get_script_history(&self, script: &Script) -> Result<Vec<History>> {
+    async fn get_script_history(&self, script: &Script) -> Result<Vec<History>> {
         Ok(self
             .client
             .script_get_history(script)?
@@ -154,7 +136,7 @@ impl BitcoinChainService for HybridBitcoinChainService {
             .collect())
     }
 
-    fn script_get_balance(&self, script: &Script) -> Result<GetBalanceRes> {
+    async fn script_get_balance(&self, script: &Script) -> Result<GetBalanceRes> {
         Ok(self.client.script_get_balance(script)?)
     }
 
@@ -165,45 +147,231 @@ impl BitcoinChainService for HybridBitcoinChainService {
         tx_hex: &str,
         verify_confirmation: bool,
     ) -> Result<Transaction> {
-        let script_pubkey = address.script_pubkey();
-        let script = script_pubkey.as_script();
-
-        let script_history = self.get_script_history_with_retry(script, 5).await?;
-        let lockup_tx_history = script_history.iter().find(|h| h.txid.to_hex().eq(tx_id));
-
-        match lockup_tx_history {
-            Some(history) => {
-                info!("Bitcoin transaction found, verifying transaction content...");
-                let tx: Transaction = deserialize(&hex::decode(tx_hex)?)?;
-                if !tx.txid().to_hex().eq(&history.txid.to_hex()) {
-                    return Err(anyhow!(
-                        "Bitcoin transaction id and hex do not match: {} vs {}",
-                        tx_id,
-                        tx.txid().to_hex()
-                    ));
-                }
+        verify_tx_in_script_history(self, address, tx_id, tx_hex, verify_confirmation).await
+    }
 
-                if verify_confirmation && history.height <= 0 {
-                    return Err(anyhow!(
-                        "Bitcoin transaction was not confirmed, txid={} waiting for confirmation",
-                        tx_id,
-                    ));
-                }
-                Ok(tx)
-            }
-            None => Err(anyhow!(
-                "Bitcoin transaction was not found, txid={} waiting for broadcast",
-                tx_id,
-            )),
-        }
+    async fn get_script_utxo(&self, script: &Script) -> Result<(OutPoint, TxOut)> {
+        get_script_utxo_from_history(self, script).await
     }
 
     async fn recommended_fees(&self) -> Result<RecommendedFees> {
-        get_parse_and_log_response(
-            &format!("{}/v1/fees/recommended", self.config.mempoolspace_url),
-            true,
+        get_recommended_fees(&self.http_client, &self.config).await
+    }
+}
+
+#[derive(Deserialize)]
+struct EsploraBlock {
+    height: usize,
+}
+
+/// A [BitcoinChainService] only using the Esplora REST API, see [Config::bitcoin_esplora_url]
+pub(crate) struct EsploraBitcoinChainService {
+    http_client: reqwest::Client,
+    config: Config,
+}
+
+impl EsploraBitcoinChainService {
+    pub(crate) fn new(config: Config) -> Result<Self> {
+        Ok(Self {
+            http_client: http_client(&config)?,
+            config,
+        })
+    }
+
+    fn esplora_url(&self) -> &str {
+        &self.config.bitcoin_esplora_url
+    }
+}
+
+#[async_trait]
+impl BitcoinChainService for EsploraBitcoinChainService {
+    async fn tip(&mut self) -> Result<HeaderNotification> {
+        let response = get_with_retry(
+            &self.http_client,
+            &format!("{}/blocks/tip/hash", self.esplora_url()),
+            3,
+        )
+        .await?;
+        let tip_hash = response.text().await?.trim().to_string();
+        let block: EsploraBlock = get_with_retry(
+            &self.http_client,
+            &format!("{}/block/{tip_hash}", self.esplora_url()),
+            3,
+        )
+        .await?
+        .json()
+        .await?;
+        let header_hex = get_with_retry(
+            &self.http_client,
+            &format!("{}/block/{tip_hash}/header", self.esplora_url()),
+            3,
+        )
+        .await?
+        .text()
+        .await?;
+        let header: Header = deserialize(&hex::decode(header_hex.trim())?)?;
+        Ok(HeaderNotification {
+            height: block.height,
+            header,
+        })
+    }
+
+    async fn broadcast(&self, tx: &Transaction) -> Result<Txid> {
+        let tx_hex = serialize(tx).to_hex();
+        info!("Broadcasting Bitcoin tx: {tx_hex}");
+        let txid = esplora_broadcast(&self.http_client, self.esplora_url(), tx_hex, None).await?;
+        Ok(txid.parse()?)
+    }
+
+    async fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>> {
+        let mut result = vec![];
+        for txid in txids {
+            let tx_hex =
+                esplora_tx_hex(&self.http_client, self.esplora_url(), &txid.to_string()).await?;
+            let tx: Transaction = deserialize(&hex::decode(tx_hex.trim())?)?;
+            result.push(tx);
+        }
+        Ok(result)
+    }
+
+    async fn get_script_history(&self, script: &Script) -> Result<Vec<History>> {
+        esplora_script_history(&self.http_client, self.esplora_url(), script.as_bytes()).await
+    }
+
+    async fn script_get_balance(&self, script: &Script) -> Result<GetBalanceRes> {
+        let script_hash = esplora_script_hash(script.as_bytes());
+        let script_info: EsploraScriptInfo = get_with_retry(
+            &self.http_client,
+            &format!("{}/scripthash/{script_hash}", self.esplora_url()),
+            3,
         )
-        .await
-        .map_err(Into::into)
+        .await?
+        .json()
+        .await?;
+        Ok(script_info.into())
+    }
+
+    async fn verify_tx(
+        &self,
+        address: &Address,
+        tx_id: &str,
+        tx_hex: &str,
+        verify_confirmation: bool,
+    ) -> Result<Transaction> {
+        verify_tx_in_script_history(self, address, tx_id, tx_hex, verify_confirmation).await
+    }
+
+    async fn get_script_utxo(&self, script: &Script) -> Result<(OutPoint, TxOut)> {
+        get_script_utxo_from_history(self, script).await
+    }
+
+    async fn recommended_fees(&self) -> Result<RecommendedFees> {
+        get_recommended_fees(&self.http_client, &self.config).await
+    }
+}
+
+async fn get_recommended_fees(
+    client: &reqwest::Client,
+    config: &Config,
+) -> Result<RecommendedFees> {
+    let url = format!("{}/v1/fees/recommended", config.mempoolspace_url);
+    let response = get_with_retry(client, &url, 3).await?;
+    Ok(response.json().await?)
+}
+
+async fn get_script_utxo_from_history<S: BitcoinChainService + ?Sized>(
+    chain_service: &S,
+    script: &Script,
+) -> Result<(OutPoint, TxOut)> {
+    let txids: Vec<Txid> = chain_service
+        .get_script_history(script)
+        .await?
+        .iter()
+        .map(|h| Txid::from_raw_hash(h.txid.to_raw_hash()))
+        .collect();
+    let txs = chain_service.get_transactions(&txids).await?;
+    let spent_outpoints: HashSet<OutPoint> = txs
+        .iter()
+        .flat_map(|tx| tx.input.iter().map(|input| input.previous_output))
+        .collect();
+    txs.iter()
+        .flat_map(|tx| {
+            let txid = tx.txid();
+            tx.output
+                .iter()
+                .enumerate()
+                .map(move |(vout, txout)| (OutPoint::new(txid, vout as u32), txout))
+        })
+        .find(|(outpoint, txout)| {
+            txout.script_pubkey.as_script() == script && !spent_outpoints.contains(outpoint)
+        })
+        .map(|(outpoint, txout)| (outpoint, txout.clone()))
+        .ok_or(anyhow!("No unspent output found for script"))
+}
+
+async fn get_script_history_with_retry<S: BitcoinChainService + ?Sized>(
+    chain_service: &S,
+    script: &Script,
+    retries: u64,
+) -> Result<Vec<History>> {
+    let script_hash = esplora_script_hash(script.as_bytes());
+    info!("Fetching script history for {}", script_hash);
+    let mut script_history = vec![];
+
+    let mut retry = 0;
+    while retry <= retries {
+        script_history = chain_service.get_script_history(script).await?;
+        match script_history.is_empty() {
+            true => {
+                retry += 1;
+                info!(
+                    "Script history for {} got zero transactions, retrying in {} seconds...",
+                    script_hash, retry
+                );
+                thread::sleep(Duration::from_secs(retry));
+            }
+            false => break,
+        }
+    }
+    Ok(script_history)
+}
+
+async fn verify_tx_in_script_history<S: BitcoinChainService + ?Sized>(
+    chain_service: &S,
+    address: &Address,
+    tx_id: &str,
+    tx_hex: &str,
+    verify_confirmation: bool,
+) -> Result<Transaction> {
+    let script_pubkey = address.script_pubkey();
+    let script = script_pubkey.as_script();
+
+    let script_history = get_script_history_with_retry(chain_service, script, 5).await?;
+    let lockup_tx_history = script_history.iter().find(|h| h.txid.to_hex().eq(tx_id));
+
+    match lockup_tx_history {
+        Some(history) => {
+            info!("Bitcoin transaction found, verifying transaction content...");
+            let tx: Transaction = deserialize(&hex::decode(tx_hex)?)?;
+            if !tx.txid().to_hex().eq(&history.txid.to_hex()) {
+                return Err(anyhow!(
+                    "Bitcoin transaction id and hex do not match: {} vs {}",
+                    tx_id,
+                    tx.txid().to_hex()
+                ));
+            }
+
+            if verify_confirmation && history.height <= 0 {
+                return Err(anyhow!(
+                    "Bitcoin transaction was not confirmed, txid={} waiting for confirmation",
+                    tx_id,
+                ));
+            }
+            Ok(tx)
+        }
+        None => Err(anyhow!(
+            "Bitcoin transaction was not found, txid={} waiting for broadcast",
+            tx_id,
+        )),
     }
 }
@@ -1,25 +1,34 @@
-use std::{str::FromStr, thread, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+    thread,
+    time::Duration,
+};
 
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use boltz_client::ToHex;
-use log::info;
+use electrum_client::{ElectrumApi, ScriptStatus};
+use log::{debug, info};
 use lwk_wollet::elements::hex::FromHex;
 use lwk_wollet::{
-    elements::{pset::serialize::Serialize, Address, BlockHash, Script, Transaction, Txid},
-    hashes::{sha256, Hash},
-    BlockchainBackend, ElectrumClient, ElectrumUrl, History,
+    bitcoin,
+    elements::{
+        encode::deserialize, pset::serialize::Serialize, Address, BlockHash, BlockHeader, OutPoint,
+        Script, Transaction, TxOut, Txid,
+    },
+    BlockchainBackend, ElectrumOptions, History,
 };
-use reqwest::Response;
-use serde::Deserialize;
 
 use crate::{
+    chain::{
+        electrum_client, esplora_broadcast, esplora_script_hash, esplora_script_history,
+        esplora_tip_height, esplora_tx_hex, http_client,
+    },
     model::{Config, LiquidNetwork},
     utils,
 };
 
-const LIQUID_ESPLORA_URL: &str = "https://lq1.breez.technology/liquid/api";
-
 #[async_trait]
 pub trait LiquidChainService: Send + Sync {
     /// Get the blockchain latest block
@@ -34,6 +43,9 @@ pub trait LiquidChainService: Send + Sync {
     /// Get the transactions involved in a list of scripts including lowball
     async fn get_script_history(&self, scripts: &Script) -> Result<Vec<History>>;
 
+    /// Get the output paying to the script that no tx in the script history spends
+    async fn get_script_utxo(&self, script: &Script) -> Result<(OutPoint, TxOut)>;
+
     /// Verify that a transaction appears in the address script history
     async fn verify_tx(
         &self,
@@ -42,111 +54,113 @@ pub trait LiquidChainService: Send + Sync {
         tx_hex: &str,
         verify_confirmation: bool,
     ) -> Result<Transaction>;
+
+    /// Check for new blocks and for status changes of the given scripts since the last call.
+    /// Scripts not seen before are watched from now on.
+    async fn poll_changes(&mut self, scripts: &[Script]) -> Result<ChainChanges>;
 }
 
-#[derive(Deserialize)]
-struct EsploraTx {
-    txid: Txid,
-    status: Status,
+/// The changes found by [LiquidChainService::poll_changes]
+#[derive(Debug, Default)]
+pub struct ChainChanges {
+    /// Whether a new block was found
+    pub new_block: bool,
+    /// The scripts with a new status, e.g. because of a new or confirmed tx
+    pub changed_scripts: Vec<Script>,
+    /// Whether the chain service cannot tell which scripts changed, so any of them may have
+    pub is_unknown: bool,
 }
 
-#[derive(Deserialize)]
-struct Status {
-    block_height: Option<i32>,
-    block_hash: Option<BlockHash>,
+impl ChainChanges {
+    /// Whether the whole wallet has to be scanned, rather than only the changed scripts: if the
+    /// changed scripts are unknown, or if a new block may have confirmed an unconfirmed tx
+    pub(crate) fn needs_full_scan(&self, has_unconfirmed_txs: bool) -> bool {
+        self.is_unknown || (self.new_block && has_unconfirmed_txs)
+    }
 }
 
 pub(crate) struct HybridLiquidChainService {
-    electrum_client: ElectrumClient,
+    electrum_client: electrum_client::Client,
+    http_client: reqwest::Client,
+    esplora_url: String,
     network: LiquidNetwork,
+    config: Config,
+    /// The Electrum client holding the header and script subscriptions, connected on first use
+    subscription_client: Option<electrum_client::Client>,
+    /// The last known status of the subscribed scripts
+    script_statuses: HashMap<Script, Option<ScriptStatus>>,
 }
 
 impl HybridLiquidChainService {
     pub(crate) fn new(config: Config) -> Result<Self> {
-        let electrum_client =
-            ElectrumClient::new(&ElectrumUrl::new(&config.liquid_electrum_url, true, true))?;
+        let electrum_client = electrum_client(
+            &config,
+            &config.liquid_electrum_url,
+            &ElectrumOptions::default(),
+        )?;
         Ok(Self {
             electrum_client,
+            http_client: http_client(&config)?,
+            esplora_url: config.liquid_esplora_url.clone(),
             network: config.network,
+            config,
+            subscription_client: None,
+            script_statuses: HashMap::new(),
         })
     }
-
-    async fn get_script_history_with_retry(
-        &self,
-        script: &Script,
-        retries: u64,
-    ) -> Result<Vec<History>> {
-        let script_hash = sha256::Hash::hash(script.as_bytes())
-            .to_byte_array()
-            .to_hex();
-        info!("Fetching script history for {}", script_hash);
-        let mut script_history = vec![];
-
-        let mut retry = 0;
-        while retry <= retries {
-            script_history = self.get_script_history(script).await?;
-            match script_history.is_empty() {
-                true => {
-                    retry += 1;
-                    info!("Script history for {script_hash} is empty, retrying in 1 second... ({retry} of {retries})");
-                    // Waiting 1s between retries, so we detect the new tx as soon as possible
-                    thread::sleep(Duration::from_secs(1));
-                }
-                false => break,
-            }
-        }
-        Ok(script_history)
-    }
 }
 
 #[async_trait]
 impl LiquidChainService for HybridLiquidChainService {
     async fn tip(&mut self) -> Result<u32> {
-        Ok(self.electrum_client.tip()?.height)
+        Ok(self.electrum_client.block_headers_subscribe_raw()?.height as u32)
     }
 
     async fn broadcast(&self, tx: &Transaction, swap_id: Option<&str>) -> Result<Txid> {
         match self.network {
             LiquidNetwork::Mainnet => {
-                let tx_bytes = tx.serialize();
-                info!("Broadcasting Liquid tx: {}", tx_bytes.to_hex());
-                let client = reqwest::Client::new();
-                let response = client
-                    .post(format!("{LIQUID_ESPLORA_URL}/tx"))
-                    .header("Swap-ID", swap_id.unwrap_or_default())
-                    .body(tx_bytes.to_hex())
-                    .send()
+                let tx_hex = tx.serialize().to_hex();
+                info!("Broadcasting Liquid tx: {tx_hex}");
+                let txid = esplora_broadcast(&self.http_client, &self.esplora_url, tx_hex, swap_id)
                     .await?;
-                let txid = Txid::from_str(&response.text().await?)?;
-                Ok(txid)
+                Ok(Txid::from_str(&txid)?)
+            }
+            LiquidNetwork::Testnet | LiquidNetwork::Regtest => {
+                let txid = self
+                    .electrum_client
+                    .transaction_broadcast_raw(&tx.serialize())?;
+                Ok(Txid::from_raw_hash(txid.to_raw_hash()))
             }
-            LiquidNetwork::Testnet => Ok(self.electrum_client.broadcast(tx)?),
         }
     }
 
     async fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>> {
-        Ok(self.electrum_client.get_transactions(txids)?)
+        let txids: Vec<bitcoin::Txid> = txids
+            .iter()
+            .map(|txid| bitcoin::Txid::from_raw_hash(txid.to_raw_hash()))
+            .collect();
+        let mut result = vec![];
+        for tx in self.electrum_client.batch_transaction_get_raw(&txids)? {
+            let tx: Transaction = deserialize(&tx)?;
+            result.push(tx);
+        }
+        Ok(result)
     }
 
     async fn get_script_history(&self, script: &Script) -> Result<Vec<History>> {
         match self.network {
             LiquidNetwork::Mainnet => {
-                let script = lwk_wollet::elements::bitcoin::Script::from_bytes(script.as_bytes());
-                let script_hash = sha256::Hash::hash(script.as_bytes())
-                    .to_byte_array()
-                    .to_hex();
-                let url = format!("{}/scripthash/{}/txs", LIQUID_ESPLORA_URL, script_hash);
-                // TODO must handle paging -> https://github.com/blockstream/esplora/blob/master/API.md#addresses
-                let response = get_with_retry(&url, 3).await?;
-                let json: Vec<EsploraTx> = response.json().await?;
-
-                let history: Vec<History> = json.into_iter().map(Into::into).collect();
-                Ok(history)
+                esplora_script_history(&self.http_client, &self.esplora_url, script.as_bytes())
+                    .await
             }
-            LiquidNetwork::Testnet => {
-                let mut history_vec = self.electrum_client.get_scripts_history(&[script])?;
-                let h = history_vec.pop();
-                Ok(h.unwrap_or(vec![]))
+            LiquidNetwork::Testnet | LiquidNetwork::Regtest => {
+                let script = bitcoin::Script::from_bytes(script.as_bytes());
+                Ok(self
+                    .electrum_client
+                    .script_get_history(script)?
+                    .into_iter()
+                    .map(Into::into)
+                    .collect())
             }
         }
     }
@@ -158,71 +172,537 @@ impl LiquidChainService for HybridLiquidChainService {
         tx_hex: &str,
         verify_confirmation: bool,
     ) -> Result<Transaction> {
-        let script = Script::from_hex(
-            hex::encode(address.to_unconfidential().script_pubkey().as_bytes()).as_str(),
-        )
-        .map_err(|e| anyhow!("Failed to get script from address {e:?}"))?;
-
-        let script_history = self.get_script_history_with_retry(&script, 30).await?;
-        let lockup_tx_history = script_history.iter().find(|h| h.txid.to_hex().eq(tx_id));
-
-        match lockup_tx_history {
-            Some(history) => {
-                info!("Liquid transaction found, verifying transaction content...");
-                let tx: Transaction = utils::deserialize_tx_hex(tx_hex)?;
-                if !tx.txid().to_hex().eq(&history.txid.to_hex()) {
-                    return Err(anyhow!(
-                        "Liquid transaction id and hex do not match: {} vs {}",
-                        tx_id,
-                        tx.txid().to_hex()
-                    ));
-                }
-
-                if verify_confirmation && history.height <= 0 {
-                    return Err(anyhow!(
-                        "Liquid transaction was not confirmed, txid={} waiting for confirmation",
-                        tx_id,
-                    ));
-                }
-                Ok(tx)
+        verify_tx_in_script_history(self, address, tx_id, tx_hex, verify_confirmation).await
+    }
+
+    async fn get_script_utxo(&self, script: &Script) -> Result<(OutPoint, TxOut)> {
+        get_script_utxo_from_history(self, script).await
+    }
+
+    async fn poll_changes(&mut self, scripts: &[Script]) -> Result<ChainChanges> {
+        let config = &self.config;
+        let changes = poll_subscriptions_reconnecting(
+            &mut self.subscription_client,
+            &mut self.script_statuses,
+            scripts,
+            || {
+                let client = electrum_client(
+                    config,
+                    &config.liquid_electrum_url,
+                    &ElectrumOptions::default(),
+                )?;
+                client.block_headers_subscribe_raw()?;
+                Ok(client)
+            },
+        )?;
+        debug!("Polled chain changes: {changes:?}");
+        Ok(changes)
+    }
+}
+
+/// A [LiquidChainService] only using the Esplora REST API, see [Config::liquid_esplora_url]
+pub(crate) struct EsploraLiquidChainService {
+    http_client: reqwest::Client,
+    esplora_url: String,
+}
+
+impl EsploraLiquidChainService {
+    pub(crate) fn new(config: Config) -> Result<Self> {
+        Ok(Self {
+            http_client: http_client(&config)?,
+            esplora_url: config.liquid_esplora_url,
+        })
+    }
+}
+
+#[async_trait]
+impl LiquidChainService for EsploraLiquidChainService {
+    async fn tip(&mut self) -> Result<u32> {
+        esplora_tip_height(&self.http_client, &self.esplora_url).await
+    }
+
+    async fn broadcast(&self, tx: &Transaction, swap_id: Option<&str>) -> Result<Txid> {
+        let tx_hex = tx.serialize().to_hex();
+        info!("Broadcasting Liquid tx: {tx_hex}");
+        let txid = esplora_broadcast(&self.http_client, &self.esplora_url, tx_hex, swap_id).await?;
+        Ok(Txid::from_str(&txid)?)
+    }
+
+    async fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>> {
+        let mut txs = vec![];
+        for txid in txids {
+            let tx_hex =
+                esplora_tx_hex(&self.http_client, &self.esplora_url, &txid.to_string()).await?;
+            txs.push(utils::deserialize_tx_hex(&tx_hex)?);
+        }
+        Ok(txs)
+    }
+
+    async fn get_script_history(&self, script: &Script) -> Result<Vec<History>> {
+        esplora_script_history(&self.http_client, &self.esplora_url, script.as_bytes()).await
+    }
+
+    async fn verify_tx(
+        &self,
+        address: &Address,
+        tx_id: &str,
+        tx_hex: &str,
+        verify_confirmation: bool,
+    ) -> Result<Transaction> {
+        verify_tx_in_script_history(self, address, tx_id, tx_hex, verify_confirmation).await
+    }
+
+    async fn get_script_utxo(&self, script: &Script) -> Result<(OutPoint, TxOut)> {
+        get_script_utxo_from_history(self, script).await
+    }
+
+    async fn poll_changes(&mut self, _scripts: &[Script]) -> Result<ChainChanges> {
+        // Esplora has no subscriptions, so any script may have changed
+        Ok(ChainChanges {
+            new_block: true,
+            changed_scripts: vec![],
+            is_unknown: true,
+        })
+    }
+}
+
+/// A [BlockchainBackend] to scan the wallet with, over the Electrum client of [electrum_client].
+/// Unlike the LWK clients, it can connect through the [Config::proxy_url].
+pub(crate) struct ElectrumBlockchainBackend {
+    client: electrum_client::Client,
+}
+
+impl ElectrumBlockchainBackend {
+    pub(crate) fn new(config: &Config) -> Result<Self> {
+        Ok(Self {
+            client: electrum_client(
+                config,
+                &config.liquid_electrum_url,
+                &ElectrumOptions::default(),
+            )?,
+        })
+    }
+}
+
+impl BlockchainBackend for ElectrumBlockchainBackend {
+    fn tip(&mut self) -> Result<BlockHeader, lwk_wollet::Error> {
+        let header = self.client.block_headers_subscribe_raw()?;
+        Ok(deserialize(&header.header)?)
+    }
+
+    fn broadcast(&self, tx: &Transaction) -> Result<Txid, lwk_wollet::Error> {
+        let txid = self.client.transaction_broadcast_raw(&tx.serialize())?;
+        Ok(Txid::from_raw_hash(txid.to_raw_hash()))
+    }
+
+    fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>, lwk_wollet::Error> {
+        let txids: Vec<bitcoin::Txid> = txids
+            .iter()
+            .map(|txid| bitcoin::Txid::from_raw_hash(txid.to_raw_hash()))
+            .collect();
+        let mut result = vec![];
+        for tx in self.client.batch_transaction_get_raw(&txids)? {
+            let tx: Transaction = deserialize(&tx)?;
+            result.push(tx);
+        }
+        Ok(result)
+    }
+
+    fn get_headers(
+        &self,
+        heights: &[u32],
+        _height_blockhash: &HashMap<u32, BlockHash>,
+    ) -> Result<Vec<BlockHeader>, lwk_wollet::Error> {
+        let mut result = vec![];
+        for header in self.client.batch_block_header_raw(heights)? {
+            let header: BlockHeader = deserialize(&header)?;
+            result.push(header);
+        }
+        Ok(result)
+    }
+
+    fn get_scripts_history(
+        &self,
+        scripts: &[&Script],
+    ) -> Result<Vec<Vec<History>>, lwk_wollet::Error> {
+        let scripts: Vec<&bitcoin::Script> = scripts
+            .iter()
+            .map(|script| bitcoin::Script::from_bytes(script.as_bytes()))
+            .collect();
+        Ok(self
+            .client
+            .batch_script_get_history(scripts.iter().copied())?
+            .into_iter()
+            .map(|history| history.into_iter().map(Into::into).collect())
+            .collect())
+    }
+}
+
+/// The Electrum calls used to poll the header and script subscriptions
+trait SubscriptionClient {
+    type ScriptStatus: PartialEq;
+
+    /// Pops the next new block header notification, returning whether there was any
+    fn pop_block_header(&self) -> Result<bool>;
+
+    /// Pops the next status notification of a subscribed script, if any
+    fn pop_script_status(&self, script: &Script) -> Result<Option<Self::ScriptStatus>>;
+
+    /// Subscribes to the status changes of the script, returning its current status
+    fn subscribe_script(&self, script: &Script) -> Result<Option<Self::ScriptStatus>>;
+}
+
+impl SubscriptionClient for electrum_client::Client {
+    type ScriptStatus = ScriptStatus;
+
+    fn pop_block_header(&self) -> Result<bool> {
+        Ok(self.block_headers_pop_raw()?.is_some())
+    }
+
+    fn pop_script_status(&self, script: &Script) -> Result<Option<ScriptStatus>> {
+        let bitcoin_script = lwk_wollet::bitcoin::Script::from_bytes(script.as_bytes());
+        Ok(self.script_pop(bitcoin_script)?)
+    }
+
+    fn subscribe_script(&self, script: &Script) -> Result<Option<ScriptStatus>> {
+        let bitcoin_script = lwk_wollet::bitcoin::Script::from_bytes(script.as_bytes());
+        Ok(self.script_subscribe(bitcoin_script)?)
+    }
+}
+
+/// Polls the subscriptions of the client, first connecting a new one if there is none
+fn poll_subscriptions_reconnecting<C: SubscriptionClient>(
+    subscription_client: &mut Option<C>,
+    script_statuses: &mut HashMap<Script, Option<C::ScriptStatus>>,
+    scripts: &[Script],
+    connect: impl FnOnce() -> Result<C>,
+) -> Result<ChainChanges> {
+    let client = match subscription_client.take() {
+        Some(client) => client,
+        None => {
+            let client = connect()?;
+            // Subscriptions do not carry over to a new client
+            script_statuses.clear();
+            client
+        }
+    };
+
+    // On errors the client is dropped, so that the next poll reconnects and subscribes again
+    let changes = poll_subscriptions(&client, script_statuses, scripts)?;
+    *subscription_client = Some(client);
+    Ok(changes)
+}
+
+fn poll_subscriptions<C: SubscriptionClient>(
+    client: &C,
+    script_statuses: &mut HashMap<Script, Option<C::ScriptStatus>>,
+    scripts: &[Script],
+) -> Result<ChainChanges> {
+    let mut changes = ChainChanges::default();
+    while client.pop_block_header()? {
+        changes.new_block = true;
+    }
+    for (script, status) in script_statuses.iter_mut() {
+        let mut is_changed = false;
+        while let Some(new_status) = client.pop_script_status(script)? {
+            if status.as_ref() != Some(&new_status) {
+                *status = Some(new_status);
+                is_changed = true;
+            }
+        }
+        if is_changed {
+            changes.changed_scripts.push(script.clone());
+        }
+    }
+    for script in scripts {
+        if script_statuses.contains_key(script) {
+            continue;
+        }
+        let status = client.subscribe_script(script)?;
+        // A script with history may have been paid to before it was watched
+        if status.is_some() {
+            changes.changed_scripts.push(script.clone());
+        }
+        script_statuses.insert(script.clone(), status);
+    }
+    Ok(changes)
+}
+
+async fn get_script_utxo_from_history<S: LiquidChainService + ?Sized>(
+    chain_service: &S,
+    script: &Script,
+) -> Result<(OutPoint, TxOut)> {
+    let txids: Vec<Txid> = chain_service
+        .get_script_history(script)
+        .await?
+        .iter()
+        .map(|h| h.txid)
+        .collect();
+    let txs = chain_service.get_transactions(&txids).await?;
+    let spent_outpoints: HashSet<OutPoint> = txs
+        .iter()
+        .flat_map(|tx| tx.input.iter().map(|input| input.previous_output))
+        .collect();
+    txs.iter()
+        .flat_map(|tx| {
+            let txid = tx.txid();
+            tx.output
+                .iter()
+                .enumerate()
+                .map(move |(vout, txout)| (OutPoint::new(txid, vout as u32), txout))
+        })
+        .find(|(outpoint, txout)| {
+            txout.script_pubkey == *script && !spent_outpoints.contains(outpoint)
+        })
+        .map(|(outpoint, txout)| (outpoint, txout.clone()))
+        .ok_or(anyhow!("No unspent output found for script"))
+}
+
+async fn get_script_history_with_retry<S: LiquidChainService + ?Sized>(
+    chain_service: &S,
+    script: &Script,
+    retries: u64,
+) -> Result<Vec<History>> {
+    let script_hash = esplora_script_hash(script.as_bytes());
+    info!("Fetching script history for {}", script_hash);
+    let mut script_history = vec![];
+
+    let mut retry = 0;
+    while retry <= retries {
+        script_history = chain_service.get_script_history(script).await?;
+        match script_history.is_empty() {
+            true => {
+                retry += 1;
+                info!("Script history for {script_hash} is empty, retrying in 1 second... ({retry} of {retries})");
+                // Waiting 1s between retries, so we detect the new tx as soon as possible
+                thread::sleep(Duration::from_secs(1));
             }
-            None => Err(anyhow!(
-                "Liquid transaction was not found, txid={} waiting for broadcast",
-                tx_id,
-            )),
+            false => break,
         }
     }
+    Ok(script_history)
 }
 
-async fn get_with_retry(url: &str, retries: usize) -> Result<Response> {
-    let mut attempt = 0;
-    loop {
-        info!("liquid chain service get_with_retry for url {url}");
-        let response = reqwest::get(url).await?;
-        attempt += 1;
-        // 429 Too many requests
-        // 503 Service Temporarily Unavailable
-        if response.status() == 429 || response.status() == 503 {
-            if attempt >= retries {
-                return Err(anyhow!("Too many retry".to_string()));
+async fn verify_tx_in_script_history<S: LiquidChainService + ?Sized>(
+    chain_service: &S,
+    address: &Address,
+    tx_id: &str,
+    tx_hex: &str,
+    verify_confirmation: bool,
+) -> Result<Transaction> {
+    let script = Script::from_hex(
+        hex::encode(address.to_unconfidential().script_pubkey().as_bytes()).as_str(),
+    )
+    .map_err(|e| anyhow!("Failed to get script from address {e:?}"))?;
+
+    let script_history = get_script_history_with_retry(chain_service, &script, 30).await?;
+    let lockup_tx_history = script_history.iter().find(|h| h.txid.to_hex().eq(tx_id));
+
+    match lockup_tx_history {
+        Some(history) => {
+            info!("Liquid transaction found, verifying transaction content...");
+            let tx: Transaction = utils::deserialize_tx_hex(tx_hex)?;
+            if !tx.txid().to_hex().eq(&history.txid.to_hex()) {
+                return Err(anyhow!(
+                    "Liquid transaction id and hex do not match: {} vs {}",
+                    tx_id,
+                    tx.txid().to_hex()
+                ));
             }
-            let secs = 1 << attempt;
 
-            thread::sleep(Duration::from_secs(secs));
-        } else {
-            return Ok(response);
+            if verify_confirmation && history.height <= 0 {
+                return Err(anyhow!(
+                    "Liquid transaction was not confirmed, txid={} waiting for confirmation",
+                    tx_id,
+                ));
+            }
+            Ok(tx)
         }
+        None => Err(anyhow!(
+            "Liquid transaction was not found, txid={} waiting for broadcast",
+            tx_id,
+        )),
     }
 }
 
-impl From<EsploraTx> for History {
-    fn from(value: EsploraTx) -> Self {
-        let status = value.status;
-        History {
-            txid: value.txid,
-            height: status.block_height.unwrap_or_default(),
-            block_hash: status.block_hash,
-            block_timestamp: None,
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::{Cell, RefCell},
+        collections::{HashMap, VecDeque},
+    };
+
+    use anyhow::{anyhow, Result};
+    use lwk_wollet::elements::Script;
+
+    use super::{
+        poll_subscriptions, poll_subscriptions_reconnecting, ChainChanges, SubscriptionClient,
+    };
+
+    #[derive(Default)]
+    struct MockSubscriptionClient {
+        /// The number of pending new block header notifications
+        block_headers: Cell<usize>,
+        /// The pending status notifications, by script
+        script_notifications: RefCell<HashMap<Script, VecDeque<u8>>>,
+        /// The status returned when subscribing, by script
+        script_statuses: HashMap<Script, u8>,
+        subscribed_scripts: RefCell<Vec<Script>>,
+        is_disconnected: bool,
+    }
+
+    impl MockSubscriptionClient {
+        fn notify_script(&self, script: &Script, status: u8) {
+            self.script_notifications
+                .borrow_mut()
+                .entry(script.clone())
+                .or_default()
+                .push_back(status);
+        }
+    }
+
+    impl SubscriptionClient for MockSubscriptionClient {
+        type ScriptStatus = u8;
+
+        fn pop_block_header(&self) -> Result<bool> {
+            if self.is_disconnected {
+                return Err(anyhow!("Disconnected"));
+            }
+            let block_headers = self.block_headers.get();
+            self.block_headers.set(block_headers.saturating_sub(1));
+            Ok(block_headers > 0)
+        }
+
+        fn pop_script_status(&self, script: &Script) -> Result<Option<u8>> {
+            Ok(self
+                .script_notifications
+                .borrow_mut()
+                .get_mut(script)
+                .and_then(VecDeque::pop_front))
         }
+
+        fn subscribe_script(&self, script: &Script) -> Result<Option<u8>> {
+            self.subscribed_scripts.borrow_mut().push(script.clone());
+            Ok(self.script_statuses.get(script).copied())
+        }
+    }
+
+    #[test]
+    fn test_poll_subscriptions() -> Result<()> {
+        let used_script = Script::from(vec![0x51]);
+        let unused_script = Script::from(vec![0x52]);
+        let client = MockSubscriptionClient {
+            script_statuses: HashMap::from([(used_script.clone(), 1)]),
+            ..Default::default()
+        };
+        let mut script_statuses = HashMap::new();
+
+        // New scripts are subscribed to, and reported as changed if they already have history
+        let changes = poll_subscriptions(
+            &client,
+            &mut script_statuses,
+            &[used_script.clone(), unused_script.clone()],
+        )?;
+        assert!(!changes.new_block);
+        assert_eq!(changes.changed_scripts, vec![used_script.clone()]);
+        assert_eq!(client.subscribed_scripts.borrow().len(), 2);
+
+        // Only the scripts notified with a different status are reported as changed
+        client.block_headers.set(2);
+        client.notify_script(&used_script, 1);
+        client.notify_script(&unused_script, 2);
+        let changes = poll_subscriptions(
+            &client,
+            &mut script_statuses,
+            &[used_script.clone(), unused_script.clone()],
+        )?;
+        assert!(changes.new_block);
+        assert_eq!(changes.changed_scripts, vec![unused_script.clone()]);
+        assert_eq!(script_statuses.get(&unused_script), Some(&Some(2)));
+        assert_eq!(client.block_headers.get(), 0);
+
+        // Watched scripts are not subscribed to again
+        let changes = poll_subscriptions(&client, &mut script_statuses, &[used_script])?;
+        assert!(!changes.new_block);
+        assert!(changes.changed_scripts.is_empty());
+        assert_eq!(client.subscribed_scripts.borrow().len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_poll_subscriptions_reconnecting() -> Result<()> {
+        let script = Script::from(vec![0x51]);
+        let stale_script = Script::from(vec![0x52]);
+        let new_client = || MockSubscriptionClient {
+            script_statuses: HashMap::from([(script.clone(), 1)]),
+            ..Default::default()
+        };
+        let mut script_statuses =
+            HashMap::from([(script.clone(), Some(1)), (stale_script.clone(), Some(2))]);
+
+        // A new client starts with no subscriptions, so the known statuses are cleared and the
+        // scripts subscribed to again
+        let mut subscription_client = None;
+        let changes = poll_subscriptions_reconnecting(
+            &mut subscription_client,
+            &mut script_statuses,
+            &[script.clone()],
+            || Ok(new_client()),
+        )?;
+        assert_eq!(changes.changed_scripts, vec![script.clone()]);
+        assert_eq!(script_statuses, HashMap::from([(script.clone(), Some(1))]));
+        assert!(subscription_client.is_some());
+
+        // A connected client is kept
+        let changes = poll_subscriptions_reconnecting(
+            &mut subscription_client,
+            &mut script_statuses,
+            &[script.clone()],
+            || Err(anyhow!("Unexpected reconnect")),
+        )?;
+        assert!(changes.changed_scripts.is_empty());
+
+        // A failing client is dropped, so that the next poll reconnects
+        let mut subscription_client = Some(MockSubscriptionClient {
+            is_disconnected: true,
+            ..Default::default()
+        });
+        assert!(poll_subscriptions_reconnecting(
+            &mut subscription_client,
+            &mut script_statuses,
+            &[script.clone()],
+            || Err(anyhow!("Unexpected reconnect")),
+        )
+        .is_err());
+        assert!(subscription_client.is_none());
+        let changes = poll_subscriptions_reconnecting(
+            &mut subscription_client,
+            &mut script_statuses,
+            &[script.clone()],
+            || Ok(new_client()),
+        )?;
+        assert_eq!(changes.changed_scripts, vec![script]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_needs_full_scan() {
+        let changes = ChainChanges {
+            new_block: true,
+            changed_scripts: vec![Script::from(vec![0x51])],
+            is_unknown: false,
+        };
+        // A new block only matters if it may have confirmed a wallet tx
+        assert!(changes.needs_full_scan(true));
+        assert!(!changes.needs_full_scan(false));
+
+        let changes = ChainChanges {
+            is_unknown: true,
+            ..Default::default()
+        };
+        assert!(changes.needs_full_scan(false));
+        assert!(!ChainChanges::default().needs_full_scan(true));
     }
 }
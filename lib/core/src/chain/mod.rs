@@ -1,2 +1,264 @@
 pub(crate) mod bitcoin;
 pub(crate) mod liquid;
+
+use std::{thread, time::Duration};
+
+use anyhow::{anyhow, Result};
+use boltz_client::ToHex;
+use electrum_client::{ConfigBuilder, GetBalanceRes, Socks5Config};
+use log::info;
+use lwk_wollet::{
+    elements::{BlockHash, Txid},
+    hashes::{sha256, Hash},
+    ElectrumOptions, ElectrumUrl, History,
+};
+use reqwest::{Client, Proxy, Response};
+use serde::Deserialize;
+
+use crate::model::Config;
+
+#[derive(Deserialize)]
+struct EsploraTx {
+    txid: Txid,
+    status: Status,
+}
+
+#[derive(Deserialize)]
+struct Status {
+    block_height: Option<i32>,
+    block_hash: Option<BlockHash>,
+}
+
+impl From<EsploraTx> for History {
+    fn from(value: EsploraTx) -> Self {
+        let status = value.status;
+        History {
+            txid: value.txid,
+            height: status.block_height.unwrap_or_default(),
+            block_hash: status.block_hash,
+            block_timestamp: None,
+        }
+    }
+}
+
+/// The totals of the outputs funding and spending a script, as returned by Esplora
+#[derive(Deserialize)]
+struct EsploraScriptStats {
+    funded_txo_sum: u64,
+    spent_txo_sum: u64,
+}
+
+impl EsploraScriptStats {
+    fn balance(&self) -> i64 {
+        self.funded_txo_sum as i64 - self.spent_txo_sum as i64
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct EsploraScriptInfo {
+    chain_stats: EsploraScriptStats,
+    mempool_stats: EsploraScriptStats,
+}
+
+impl From<EsploraScriptInfo> for GetBalanceRes {
+    fn from(value: EsploraScriptInfo) -> Self {
+        GetBalanceRes {
+            confirmed: value.chain_stats.balance().max(0) as u64,
+            unconfirmed: value.mempool_stats.balance(),
+        }
+    }
+}
+
+/// Builds a raw Electrum client for the server at `url`. If a [Config::proxy_url] is set, the
+/// client connects through it.
+pub(crate) fn electrum_client(
+    config: &Config,
+    url: &str,
+    options: &ElectrumOptions,
+) -> Result<electrum_client::Client> {
+    let client = match config.socks5_proxy_addr()? {
+        Some(proxy_addr) => {
+            let scheme = match config.electrum_tls() {
+                true => "ssl",
+                false => "tcp",
+            };
+            let electrum_config = ConfigBuilder::new()
+                .socks5(Some(Socks5Config::new(proxy_addr)))
+                .validate_domain(config.electrum_tls())
+                .build();
+            electrum_client::Client::from_config(&format!("{scheme}://{url}"), electrum_config)?
+        }
+        None => ElectrumUrl::new(url, config.electrum_tls(), config.electrum_tls())
+            .build_client(options)?,
+    };
+    Ok(client)
+}
+
+/// Builds the HTTP client of the Esplora APIs, going through the [Config::proxy_url] if set
+pub(crate) fn http_client(config: &Config) -> Result<Client> {
+    let mut builder = Client::builder();
+    if let Some(proxy_url) = &config.proxy_url {
+        // Validates the proxy URL
+        config.socks5_proxy_addr()?;
+        builder = builder.proxy(Proxy::all(proxy_url)?);
+    }
+    Ok(builder.build()?)
+}
+
+/// The script hash used by the Esplora `/scripthash` endpoints
+pub(crate) fn esplora_script_hash(script: &[u8]) -> String {
+    sha256::Hash::hash(script).to_byte_array().to_hex()
+}
+
+/// Fetches the transactions involving a script from an Esplora API
+pub(crate) async fn esplora_script_history(
+    client: &Client,
+    esplora_url: &str,
+    script: &[u8],
+) -> Result<Vec<History>> {
+    let script_hash = esplora_script_hash(script);
+    let url = format!("{esplora_url}/scripthash/{script_hash}/txs");
+    // TODO must handle paging -> https://github.com/blockstream/esplora/blob/master/API.md#addresses
+    let response = get_with_retry(client, &url, 3).await?;
+    let json: Vec<EsploraTx> = response.json().await?;
+
+    let history: Vec<History> = json.into_iter().map(Into::into).collect();
+    Ok(history)
+}
+
+/// Fetches the height of the chain tip from an Esplora API
+pub(crate) async fn esplora_tip_height(client: &Client, esplora_url: &str) -> Result<u32> {
+    let response = get_with_retry(client, &format!("{esplora_url}/blocks/tip/height"), 3).await?;
+    Ok(response.text().await?.trim().parse()?)
+}
+
+/// Fetches the raw hex of a transaction from an Esplora API
+pub(crate) async fn esplora_tx_hex(
+    client: &Client,
+    esplora_url: &str,
+    txid: &str,
+) -> Result<String> {
+    let response = get_with_retry(client, &format!("{esplora_url}/tx/{txid}/hex"), 3).await?;
+    if !response.status().is_success() {
+        return Err(anyhow!("Transaction {txid} not found"));
+    }
+    Ok(response.text().await?)
+}
+
+/// Broadcasts the raw hex of a transaction through an Esplora API, returning the txid
+pub(crate) async fn esplora_broadcast(
+    client: &Client,
+    esplora_url: &str,
+    tx_hex: String,
+    swap_id: Option<&str>,
+) -> Result<String> {
+    let response = client
+        .post(format!("{esplora_url}/tx"))
+        .header("Swap-ID", swap_id.unwrap_or_default())
+        .body(tx_hex)
+        .send()
+        .await?;
+    let status = response.status();
+    let text = response.text().await?;
+    match status.is_success() {
+        true => Ok(text),
+        false => Err(anyhow!("Failed to broadcast transaction: {text}")),
+    }
+}
+
+pub(crate) async fn get_with_retry(client: &Client, url: &str, retries: usize) -> Result<Response> {
+    let mut attempt = 0;
+    loop {
+        info!("chain service get_with_retry for url {url}");
+        let response = client.get(url).send().await?;
+        attempt += 1;
+        // 429 Too many requests
+        // 503 Service Temporarily Unavailable
+        if response.status() == 429 || response.status() == 503 {
+            if attempt >= retries {
+                return Err(anyhow!("Too many retry".to_string()));
+            }
+            let secs = 1 << attempt;
+
+            thread::sleep(Duration::from_secs(secs));
+        } else {
+            return Ok(response);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use electrum_client::GetBalanceRes;
+    use lwk_wollet::History;
+
+    use super::{EsploraScriptInfo, EsploraTx};
+
+    const TEST_TXID: &str = "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b";
+    const TEST_BLOCK_HASH: &str =
+        "000000000000000000024bead8df69990852c202db0e0097c1a12ea637d7e96d";
+
+    #[test]
+    fn test_esplora_tx_into_history() -> Result<()> {
+        let confirmed_tx: EsploraTx = serde_json::from_str(&format!(
+            r#"{{
+                "txid": "{TEST_TXID}",
+                "version": 2,
+                "status": {{
+                    "confirmed": true,
+                    "block_height": 840000,
+                    "block_hash": "{TEST_BLOCK_HASH}",
+                    "block_time": 1713571767
+                }}
+            }}"#
+        ))?;
+        let history: History = confirmed_tx.into();
+        assert_eq!(history.txid.to_string(), TEST_TXID);
+        assert_eq!(history.height, 840000);
+        assert_eq!(
+            history.block_hash.map(|hash| hash.to_string()),
+            Some(TEST_BLOCK_HASH.to_string())
+        );
+
+        // Mempool txs have no block and are at height 0, as with Electrum
+        let mempool_tx: EsploraTx = serde_json::from_str(&format!(
+            r#"{{"txid": "{TEST_TXID}", "version": 2, "status": {{"confirmed": false}}}}"#
+        ))?;
+        let history: History = mempool_tx.into();
+        assert_eq!(history.txid.to_string(), TEST_TXID);
+        assert_eq!(history.height, 0);
+        assert!(history.block_hash.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_esplora_script_info_into_balance() -> Result<()> {
+        let script_info: EsploraScriptInfo = serde_json::from_str(
+            r#"{
+                "address": "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu",
+                "chain_stats": {
+                    "funded_txo_count": 2,
+                    "funded_txo_sum": 30000,
+                    "spent_txo_count": 1,
+                    "spent_txo_sum": 10000,
+                    "tx_count": 3
+                },
+                "mempool_stats": {
+                    "funded_txo_count": 1,
+                    "funded_txo_sum": 5000,
+                    "spent_txo_count": 1,
+                    "spent_txo_sum": 20000,
+                    "tx_count": 2
+                }
+            }"#,
+        )?;
+        let balance: GetBalanceRes = script_info.into();
+        assert_eq!(balance.confirmed, 20000);
+        // Spending confirmed outputs in the mempool makes the unconfirmed balance negative
+        assert_eq!(balance.unconfirmed, -15000);
+
+        Ok(())
+    }
+}
@@ -2,21 +2,31 @@ use std::time::Duration;
 use std::{str::FromStr, sync::Arc};
 
 use anyhow::{anyhow, Result};
+use boltz_client::bitcoin::Txid;
 use boltz_client::swaps::boltz::{self, SwapUpdateTxDetails};
 use boltz_client::swaps::{boltz::ChainSwapStates, boltz::CreateChainResponse};
-use boltz_client::Secp256k1;
+use boltz_client::{Address, Secp256k1};
 use log::{debug, error, info, warn};
+use lwk_wollet::bitcoin::{
+    self,
+    bip32::{ChildNumber, Xpub},
+};
 use lwk_wollet::elements::Transaction;
 use tokio::sync::{broadcast, watch, Mutex};
 use tokio::time::MissedTickBehavior;
 
 use crate::chain::bitcoin::BitcoinChainService;
 use crate::chain::liquid::LiquidChainService;
+use crate::ensure_sdk;
 use crate::error::{SdkError, SdkResult};
 use crate::model::PaymentState::{
-    Complete, Created, Failed, Pending, RefundPending, Refundable, TimedOut,
+    Complete, Created, Failed, Pending, RefundPending, Refundable, TimedOut, WaitingFeeAcceptance,
+};
+use crate::model::{
+    AutoRefundDetails, BumpRefundFeeResponse, ChainSwap, Config, Direction,
+    FetchPaymentProposedFeesResponse, LiquidNetwork, PaymentTxData, PaymentType, RefundDestination,
+    SdkEvent, SwapScriptV2, SwapTransaction, Utxo,
 };
-use crate::model::{ChainSwap, Config, Direction, PaymentTxData, PaymentType};
 use crate::sdk::CHAIN_SWAP_MONITORING_PERIOD_BITCOIN_BLOCKS;
 use crate::swapper::Swapper;
 use crate::utils;
@@ -25,6 +35,9 @@ use crate::{error::PaymentError, model::PaymentState, persist::Persister};
 
 // Estimates based on https://github.com/BoltzExchange/boltz-backend/blob/ee4c77be1fcb9bb2b45703c542ad67f7efbf218d/lib/rates/FeeProvider.ts#L78
 pub const ESTIMATED_BTC_CLAIM_TX_VSIZE: u64 = 111;
+pub const DEFAULT_AMOUNTLESS_FEE_TOLERANCE_SAT: u64 = 0;
+/// The size of the Taproot control block revealing a leaf of the swap tree, which has two leaves
+const SWAP_TREE_CONTROL_BLOCK_SIZE: usize = 33 + 32;
 
 pub(crate) struct ChainSwapStateHandler {
     config: Config,
@@ -34,6 +47,7 @@ pub(crate) struct ChainSwapStateHandler {
     liquid_chain_service: Arc<Mutex<dyn LiquidChainService>>,
     bitcoin_chain_service: Arc<Mutex<dyn BitcoinChainService>>,
     subscription_notifier: broadcast::Sender<String>,
+    event_notifier: broadcast::Sender<SdkEvent>,
 }
 
 impl ChainSwapStateHandler {
@@ -46,6 +60,7 @@ impl ChainSwapStateHandler {
         bitcoin_chain_service: Arc<Mutex<dyn BitcoinChainService>>,
     ) -> Result<Self> {
         let (subscription_notifier, _) = broadcast::channel::<String>(30);
+        let (event_notifier, _) = broadcast::channel::<SdkEvent>(30);
         Ok(Self {
             config,
             onchain_wallet,
@@ -54,6 +69,7 @@ impl ChainSwapStateHandler {
             liquid_chain_service,
             bitcoin_chain_service,
             subscription_notifier,
+            event_notifier,
         })
     }
 
@@ -83,6 +99,11 @@ impl ChainSwapStateHandler {
         self.subscription_notifier.subscribe()
     }
 
+    /// Subscribes to the events not tied to a payment, like [SdkEvent::SwapAutoRefunded]
+    pub(crate) fn subscribe_events(&self) -> broadcast::Receiver<SdkEvent> {
+        self.event_notifier.subscribe()
+    }
+
     /// Handles status updates from Boltz for Chain swaps
     pub(crate) async fn on_new_status(&self, update: &boltz::Update) -> Result<()> {
         let id = &update.id;
@@ -98,7 +119,7 @@ impl ChainSwapStateHandler {
     }
 
     pub(crate) async fn rescan_incoming_chain_swaps(&self) -> Result<()> {
-        let current_height = self.bitcoin_chain_service.lock().await.tip()?.height as u32;
+        let current_height = self.bitcoin_chain_service.lock().await.tip().await?.height as u32;
         let chain_swaps: Vec<ChainSwap> = self
             .persister
             .list_chain_swaps()?
@@ -115,9 +136,126 @@ impl ChainSwapStateHandler {
                 error!("Error rescanning Chain Swap {}: {e:?}", swap.id);
             }
         }
+        if self.config.auto_refund.is_some() {
+            self.auto_refund_incoming_swaps().await?;
+        }
+        Ok(())
+    }
+
+    /// Refunds the refundable incoming swaps as set in the [Config::auto_refund] policy
+    async fn auto_refund_incoming_swaps(&self) -> Result<()> {
+        let Some(policy) = self.config.auto_refund.clone() else {
+            return Ok(());
+        };
+        let refundable_swaps: Vec<ChainSwap> = self
+            .persister
+            .list_refundable_chain_swaps()?
+            .into_iter()
+            // The swaps waiting for fee acceptance are left for the user to accept or decline
+            .filter(|s| s.direction == Direction::Incoming && s.state == Refundable)
+            .collect();
+        if refundable_swaps.is_empty() {
+            return Ok(());
+        }
+
+        let fees = self
+            .bitcoin_chain_service
+            .lock()
+            .await
+            .recommended_fees()
+            .await?;
+        let sat_per_vbyte = policy.fee_rate.sat_per_vbyte(&fees);
+        info!(
+            "Auto refunding {} Chain Swap(s) at {sat_per_vbyte} sat/vB",
+            refundable_swaps.len()
+        );
+        for swap in refundable_swaps {
+            let (refund_address, reserved_index) =
+                self.reserve_auto_refund_address(&policy.destination)?;
+            match self
+                .refund_incoming_swap(&swap.lockup_address, &refund_address, sat_per_vbyte)
+                .await
+            {
+                Ok(refund_tx_id) => {
+                    let _ = self.event_notifier.send(SdkEvent::SwapAutoRefunded {
+                        details: AutoRefundDetails {
+                            swap_address: swap.lockup_address,
+                            refund_address,
+                            refund_tx_id,
+                        },
+                    });
+                }
+                // The non-cooperative refund fails until the swap lock time has elapsed, so it
+                // is retried on the next rescan
+                Err(e) => {
+                    warn!("Could not auto refund Chain Swap {}: {e:?}", swap.id);
+                    // The address was not paid to, so it is released for the next refund
+                    if let Some(index) = reserved_index {
+                        self.persister.set_auto_refund_address_index(index)?;
+                    }
+                }
+            }
+        }
         Ok(())
     }
 
+    /// The Bitcoin address the next automatic refund is sent to. For an xpub destination, the
+    /// address index is advanced before the refund is broadcast, so the address is never reused
+    /// even if the SDK stops right after. The reserved index is returned to release it in case
+    /// the refund fails.
+    fn reserve_auto_refund_address(
+        &self,
+        destination: &RefundDestination,
+    ) -> Result<(String, Option<u32>)> {
+        match destination {
+            RefundDestination::Address { address } => Ok((address.clone(), None)),
+            RefundDestination::Xpub { xpub } => {
+                let index = self.persister.get_auto_refund_address_index()?;
+                let address = derive_refund_address(xpub, index, self.config.network)?;
+                self.persister.set_auto_refund_address_index(index + 1)?;
+                Ok((address, Some(index)))
+            }
+        }
+    }
+
+    /// Looks up the user lockup tx of an incoming swap in the history of its lockup script
+    async fn fetch_user_lockup_tx_id(&self, swap: &ChainSwap) -> Result<Option<String>> {
+        let swap_script = swap.get_lockup_swap_script()?.as_bitcoin_script()?;
+        let script_pubkey = swap_script
+            .to_address(self.config.network.as_bitcoin_chain())
+            .map_err(|e| anyhow!("Error getting script address: {e:?}"))?
+            .script_pubkey();
+        let history = self
+            .bitcoin_chain_service
+            .lock()
+            .await
+            .get_script_history(script_pubkey.as_script())
+            .await?;
+        Ok(history.first().map(|h| h.txid.to_string()))
+    }
+
+    /// Looks up the amount locked up by the user of an incoming swap in the balance of its lockup
+    /// script. This can differ from [ChainSwap::payer_amount_sat], which is 0 for amountless
+    /// swaps. A refund being broadcast only spends the lockup in the mempool, so for
+    /// [RefundPending] swaps the confirmed balance is the amount being refunded.
+    pub(crate) async fn fetch_user_lockup_amount_sat(&self, swap: &ChainSwap) -> Result<u64> {
+        let swap_script = swap.get_lockup_swap_script()?.as_bitcoin_script()?;
+        let script_pubkey = swap_script
+            .to_address(self.config.network.as_bitcoin_chain())
+            .map_err(|e| anyhow!("Error getting script address: {e:?}"))?
+            .script_pubkey();
+        let script_balance = self
+            .bitcoin_chain_service
+            .lock()
+            .await
+            .script_get_balance(script_pubkey.as_script())
+            .await?;
+        Ok(match swap.state {
+            RefundPending => script_balance.confirmed,
+            _ => (script_balance.confirmed as i64 + script_balance.unconfirmed).max(0) as u64,
+        })
+    }
+
     async fn rescan_incoming_chain_swap(
         &self,
         swap: &ChainSwap,
@@ -138,7 +276,8 @@ impl ChainSwapStateHandler {
                 .bitcoin_chain_service
                 .lock()
                 .await
-                .script_get_balance(script_pubkey.as_script())?;
+                .script_get_balance(script_pubkey.as_script())
+                .await?;
             info!(
                 "Chain Swap {} has {} confirmed and {} unconfirmed sats",
                 swap.id, script_balance.confirmed, script_balance.unconfirmed
@@ -276,6 +415,66 @@ impl ChainSwapStateHandler {
                 Ok(())
             }
 
+            // The user lockup amount differs from the agreed one, or no amount was agreed. If the
+            // swapper proposes a new quote for it, the user can accept it instead of refunding.
+            // Amountless swaps accept it automatically if the fees are acceptable.
+            ChainSwapStates::TransactionLockupFailed if swap.refund_tx_id.is_none() => {
+                // The user lockup may not have been announced, e.g. for an amountless swap
+                let user_lockup_tx_id = match swap.user_lockup_tx_id.clone() {
+                    Some(user_lockup_tx_id) => user_lockup_tx_id,
+                    None => match self.fetch_user_lockup_tx_id(swap).await? {
+                        Some(user_lockup_tx_id) => user_lockup_tx_id,
+                        None => {
+                            info!("Chain Swap {id} user lockup tx was never broadcast. Resolving payment as failed.");
+                            self.update_swap_info(id, Failed, None, None, None, None)
+                                .await?;
+                            return Ok(());
+                        }
+                    },
+                };
+                match self.swapper.get_chain_swap_quote(id) {
+                    Ok(server_lockup_amount_sat) => {
+                        info!("Chain Swap {id} has a new quote of {server_lockup_amount_sat} sats. Waiting for fee acceptance.");
+                        self.update_swap_info(
+                            id,
+                            WaitingFeeAcceptance,
+                            None,
+                            Some(&user_lockup_tx_id),
+                            None,
+                            None,
+                        )
+                        .await?;
+                        if swap.get_boltz_create_response()?.lockup_details.amount == 0 {
+                            match self.auto_accept_proposed_fees(swap).await {
+                                Ok(true) => return Ok(()),
+                                Ok(false) => {}
+                                Err(e) => warn!(
+                                    "Could not accept the proposed fees of amountless Chain Swap {id}: {e:?}"
+                                ),
+                            }
+                        }
+                        let _ = self
+                            .event_notifier
+                            .send(SdkEvent::SwapWaitingFeeAcceptance {
+                                swap_id: id.clone(),
+                            });
+                    }
+                    Err(e) => {
+                        warn!("Could not get a new quote for Chain Swap {id}: {e:?}. Setting the swap to refundable.");
+                        self.update_swap_info(
+                            id,
+                            Refundable,
+                            None,
+                            Some(&user_lockup_tx_id),
+                            None,
+                            None,
+                        )
+                        .await?;
+                    }
+                }
+                Ok(())
+            }
+
             // If swap state is unrecoverable, either:
             // 1. The transaction failed
             // 2. Lockup failed (too little funds were sent)
@@ -334,7 +533,7 @@ impl ChainSwapStateHandler {
                     // Create the user lockup tx
                     (_, None) => {
                         let create_response = swap.get_boltz_create_response()?;
-                        let user_lockup_tx = self.lockup_funds(id, &create_response).await?;
+                        let user_lockup_tx = self.lockup_funds(&swap, &create_response).await?;
                         let lockup_tx_id = user_lockup_tx.txid().to_string();
                         let lockup_tx_fees_sat: u64 = user_lockup_tx.all_fees().values().sum();
 
@@ -348,6 +547,7 @@ impl ChainSwapStateHandler {
                             fees_sat: lockup_tx_fees_sat + swap.claim_fees_sat,
                             payment_type: PaymentType::Send,
                             is_confirmed: false,
+                            asset_id: None,
                         })?;
 
                         self.update_swap_info(id, Pending, None, Some(&lockup_tx_id), None, None)
@@ -501,9 +701,10 @@ impl ChainSwapStateHandler {
 
     async fn lockup_funds(
         &self,
-        swap_id: &str,
+        swap: &ChainSwap,
         create_response: &CreateChainResponse,
     ) -> Result<Transaction, PaymentError> {
+        let swap_id = &swap.id;
         let lockup_details = create_response.lockup_details.clone();
 
         debug!(
@@ -511,14 +712,28 @@ impl ChainSwapStateHandler {
             lockup_details.amount, lockup_details.lockup_address
         );
 
-        let lockup_tx = self
-            .onchain_wallet
-            .build_tx(
-                None,
-                &lockup_details.lockup_address,
-                lockup_details.amount as u64,
-            )
-            .await?;
+        let lockup_tx = match swap.drain {
+            // The swap was created to drain the wallet, so there is no room left for change.
+            // The drain tx is only built if it locks up exactly the expected amount.
+            true => {
+                self.onchain_wallet
+                    .build_drain_tx(
+                        None,
+                        &lockup_details.lockup_address,
+                        Some(lockup_details.amount as u64),
+                    )
+                    .await?
+            }
+            false => {
+                self.onchain_wallet
+                    .build_tx(
+                        None,
+                        &lockup_details.lockup_address,
+                        lockup_details.amount as u64,
+                    )
+                    .await?
+            }
+        };
 
         let lockup_tx_id = self
             .liquid_chain_service
@@ -577,7 +792,20 @@ impl ChainSwapStateHandler {
 
     async fn claim(&self, chain_swap: &ChainSwap) -> Result<(), PaymentError> {
         debug!("Initiating claim for Chain Swap {}", &chain_swap.id);
-        let claim_tx_id = self.swapper.claim_chain_swap(chain_swap)?;
+        let claim_utxo = self
+            .fetch_swap_script_utxo(chain_swap.get_claim_swap_script()?)
+            .await?;
+        let user_lockup_utxo = self
+            .fetch_swap_script_utxo(chain_swap.get_lockup_swap_script()?)
+            .await?;
+        let claim_tx =
+            self.swapper
+                .create_chain_swap_claim(chain_swap, claim_utxo, user_lockup_utxo)?;
+        let claim_tx_id = self.broadcast_swap_tx(&chain_swap.id, &claim_tx).await?;
+        info!(
+            "Successfully broadcast claim tx {claim_tx_id} for Chain Swap {}",
+            chain_swap.id
+        );
 
         if chain_swap.direction == Direction::Incoming {
             // We insert a pseudo-claim-tx in case LWK fails to pick up the new mempool tx for a while
@@ -589,6 +817,7 @@ impl ChainSwapStateHandler {
                 fees_sat: 0,
                 payment_type: PaymentType::Receive,
                 is_confirmed: false,
+                asset_id: None,
             })?;
         }
 
@@ -604,7 +833,7 @@ impl ChainSwapStateHandler {
         Ok(())
     }
 
-    pub fn prepare_refund(
+    pub async fn prepare_refund(
         &self,
         lockup_address: &str,
         output_address: &str,
@@ -622,9 +851,15 @@ impl ChainSwapStateHandler {
                 swap.id
             );
         }
-        let (tx_vsize, tx_fee_sat) =
-            self.swapper
-                .prepare_chain_swap_refund(&swap, output_address, sat_per_vbyte as f32)?;
+        let lockup_utxo = self
+            .fetch_swap_script_utxo(swap.get_lockup_swap_script()?)
+            .await?;
+        let (tx_vsize, tx_fee_sat) = self.swapper.prepare_chain_swap_refund(
+            &swap,
+            output_address,
+            sat_per_vbyte as f32,
+            &lockup_utxo,
+        )?;
         Ok((tx_vsize, tx_fee_sat, swap.refund_tx_id))
     }
 
@@ -647,25 +882,18 @@ impl ChainSwapStateHandler {
             );
         }
 
-        let (_, broadcast_fees_sat) =
-            self.swapper
-                .prepare_chain_swap_refund(&swap, output_address, sat_per_vbyte as f32)?;
-        let refund_res =
-            self.swapper
-                .refund_chain_swap_cooperative(&swap, output_address, broadcast_fees_sat);
-        let refund_tx_id = match refund_res {
-            Ok(res) => Ok(res),
-            Err(e) => {
-                warn!("Cooperative refund failed: {:?}", e);
-                let current_height = self.bitcoin_chain_service.lock().await.tip()?.height as u32;
-                self.swapper.refund_chain_swap_non_cooperative(
-                    &swap,
-                    broadcast_fees_sat,
-                    output_address,
-                    current_height,
-                )
-            }
-        }?;
+        let lockup_utxo = self
+            .fetch_swap_script_utxo(swap.get_lockup_swap_script()?)
+            .await?;
+        let (_, broadcast_fees_sat) = self.swapper.prepare_chain_swap_refund(
+            &swap,
+            output_address,
+            sat_per_vbyte as f32,
+            &lockup_utxo,
+        )?;
+        let refund_tx_id = self
+            .refund_lockup_utxo(&swap, lockup_utxo, output_address, broadcast_fees_sat)
+            .await?;
 
         info!(
             "Broadcast refund tx for Chain Swap {}. Tx id: {refund_tx_id}",
@@ -683,6 +911,277 @@ impl ChainSwapStateHandler {
         Ok(refund_tx_id)
     }
 
+    /// Replaces the broadcast refund tx of an incoming swap with one paying a higher fee rate,
+    /// spending the same lockup output to the same address.
+    pub(crate) async fn bump_refund_fee(
+        &self,
+        lockup_address: &str,
+        sat_per_vbyte: u32,
+    ) -> Result<BumpRefundFeeResponse, PaymentError> {
+        let swap = self
+            .persister
+            .fetch_chain_swap_by_lockup_address(lockup_address)?
+            .ok_or(PaymentError::Generic {
+                err: format!("Swap {} not found", lockup_address),
+            })?;
+        ensure_sdk!(
+            swap.direction == Direction::Incoming && swap.state == RefundPending,
+            PaymentError::Generic {
+                err: format!("Chain Swap {} has no pending refund to bump", swap.id),
+            }
+        );
+        let replaced_tx_id = swap.refund_tx_id.clone().ok_or(PaymentError::Generic {
+            err: format!("Chain Swap {} has no refund tx", swap.id),
+        })?;
+
+        let refund_script_size = swap
+            .get_boltz_create_response()?
+            .lockup_details
+            .swap_tree
+            .refund_leaf
+            .output
+            .len()
+            / 2;
+        let (lockup_utxo, output_address, replaced_tx_fee_sat, tx_vsizes) = {
+            let bitcoin_chain_service = self.bitcoin_chain_service.lock().await;
+            let replaced_tx = bitcoin_chain_service
+                .get_transactions(&[Txid::from_str(&replaced_tx_id)?])
+                .await?
+                .pop()
+                .ok_or(PaymentError::Generic {
+                    err: format!("Refund tx {replaced_tx_id} not found"),
+                })?;
+            let lockup_outpoint = replaced_tx
+                .input
+                .first()
+                .ok_or(PaymentError::Generic {
+                    err: format!("Refund tx {replaced_tx_id} has no inputs"),
+                })?
+                .previous_output;
+            let lockup_txout = bitcoin_chain_service
+                .get_transactions(&[lockup_outpoint.txid])
+                .await?
+                .pop()
+                .and_then(|tx| tx.output.get(lockup_outpoint.vout as usize).cloned())
+                .ok_or(PaymentError::Generic {
+                    err: format!("Lockup output {lockup_outpoint} not found"),
+                })?;
+
+            let swap_address = swap
+                .get_lockup_swap_script()?
+                .as_bitcoin_script()?
+                .to_address(self.config.network.as_bitcoin_chain())
+                .map_err(|e| anyhow!("Error getting script address: {e:?}"))?;
+            ensure_sdk!(
+                lockup_txout.script_pubkey == swap_address.script_pubkey(),
+                PaymentError::Generic {
+                    err: format!("Refund tx {replaced_tx_id} does not spend the swap lockup"),
+                }
+            );
+            let refund_txout = replaced_tx.output.first().ok_or(PaymentError::Generic {
+                err: format!("Refund tx {replaced_tx_id} has no outputs"),
+            })?;
+            let output_address =
+                Address::from_script(&refund_txout.script_pubkey, *swap_address.network())
+                    .map_err(|e| anyhow!("Error getting refund address: {e:?}"))?;
+
+            let outputs_sat: u64 = replaced_tx.output.iter().map(|o| o.value.to_sat()).sum();
+            let replaced_tx_fee_sat = lockup_txout.value.to_sat().saturating_sub(outputs_sat);
+            (
+                Utxo::Bitcoin((lockup_outpoint, lockup_txout)),
+                output_address.to_string(),
+                replaced_tx_fee_sat,
+                get_refund_tx_vsizes(&replaced_tx, refund_script_size)?,
+            )
+        };
+
+        // The replacement has to pay at least the min relay fee rate for its own size on top of
+        // the replaced fee
+        let get_tx_fee_sat = |tx_vsize: u64| -> Result<u64, PaymentError> {
+            let tx_fee_sat = tx_vsize * sat_per_vbyte as u64;
+            ensure_sdk!(
+                tx_fee_sat >= replaced_tx_fee_sat + tx_vsize,
+                PaymentError::Generic {
+                    err: format!(
+                        "The fee of {tx_fee_sat} sat is too low to replace the refund tx paying {replaced_tx_fee_sat} sat"
+                    ),
+                }
+            );
+            Ok(tx_fee_sat)
+        };
+
+        // Each path pays for its own size, as the non-cooperative script path spend is larger
+        let (cooperative_tx_vsize, non_cooperative_tx_vsize) = tx_vsizes;
+        let refund_res = match get_tx_fee_sat(cooperative_tx_vsize).and_then(|tx_fee_sat| {
+            self.swapper
+                .create_chain_swap_refund_cooperative(
+                    &swap,
+                    lockup_utxo.clone(),
+                    &output_address,
+                    tx_fee_sat,
+                )
+                .map(|refund_tx| (refund_tx, tx_fee_sat))
+        }) {
+            Ok((refund_tx, tx_fee_sat)) => self
+                .broadcast_swap_tx(&swap.id, &refund_tx)
+                .await
+                .map(|refund_tx_id| (refund_tx_id, tx_fee_sat)),
+            Err(e) => Err(e),
+        };
+        let (refund_tx_id, tx_fee_sat) = match refund_res {
+            Ok(res) => res,
+            Err(e) => {
+                warn!("Cooperative refund replacement failed: {:?}", e);
+                let tx_fee_sat = get_tx_fee_sat(non_cooperative_tx_vsize)?;
+                let current_height =
+                    self.bitcoin_chain_service.lock().await.tip().await?.height as u32;
+                let refund_tx = self.swapper.create_chain_swap_refund_non_cooperative(
+                    &swap,
+                    lockup_utxo,
+                    tx_fee_sat,
+                    &output_address,
+                    current_height,
+                )?;
+                let refund_tx_id = self.broadcast_swap_tx(&swap.id, &refund_tx).await?;
+                (refund_tx_id, tx_fee_sat)
+            }
+        };
+
+        info!(
+            "Broadcast replacement refund tx for Chain Swap {}. Tx id: {refund_tx_id}, replaced tx id: {replaced_tx_id}",
+            swap.id
+        );
+        self.persister
+            .update_chain_swap_refund_tx_id(&swap.id, &refund_tx_id)?;
+        Ok(BumpRefundFeeResponse {
+            refund_tx_id,
+            replaced_tx_id,
+            tx_fee_sat,
+            fee_delta_sat: tx_fee_sat - replaced_tx_fee_sat,
+        })
+    }
+
+    /// Gets the fees proposed by the swapper for the amount actually locked up by the payer
+    pub(crate) async fn fetch_payment_proposed_fees(
+        &self,
+        swap_id: &str,
+    ) -> Result<FetchPaymentProposedFeesResponse, PaymentError> {
+        let swap =
+            self.persister
+                .fetch_chain_swap_by_id(swap_id)?
+                .ok_or(PaymentError::Generic {
+                    err: format!("Chain Swap not found {swap_id}"),
+                })?;
+        ensure_sdk!(
+            swap.direction == Direction::Incoming && swap.state == WaitingFeeAcceptance,
+            PaymentError::Generic {
+                err: format!("Chain Swap {swap_id} is not waiting for fee acceptance"),
+            }
+        );
+
+        let server_lockup_amount_sat = self.swapper.get_chain_swap_quote(swap_id)?;
+        let receiver_amount_sat = server_lockup_amount_sat.saturating_sub(swap.claim_fees_sat);
+        ensure_sdk!(
+            receiver_amount_sat > 0,
+            PaymentError::Generic {
+                err: format!("The new quote of Chain Swap {swap_id} does not cover the claim fees"),
+            }
+        );
+
+        let swap_script = swap.get_lockup_swap_script()?.as_bitcoin_script()?;
+        let script_pubkey = swap_script
+            .to_address(self.config.network.as_bitcoin_chain())
+            .map_err(|e| anyhow!("Error getting script address: {e:?}"))?
+            .script_pubkey();
+        let script_balance = self
+            .bitcoin_chain_service
+            .lock()
+            .await
+            .script_get_balance(script_pubkey.as_script())
+            .await?;
+        let payer_amount_sat =
+            (script_balance.confirmed as i64 + script_balance.unconfirmed) as u64;
+        ensure_sdk!(
+            payer_amount_sat > receiver_amount_sat,
+            PaymentError::Generic {
+                err: format!("Unexpected user lockup amount of {payer_amount_sat} sats for Chain Swap {swap_id}"),
+            }
+        );
+
+        Ok(FetchPaymentProposedFeesResponse {
+            swap_id: swap_id.to_string(),
+            fees_sat: payer_amount_sat - receiver_amount_sat,
+            payer_amount_sat,
+            receiver_amount_sat,
+        })
+    }
+
+    /// Accepts the fees proposed by the swapper, so that it locks up the new amount and the swap
+    /// continues as usual
+    pub(crate) async fn accept_payment_proposed_fees(
+        &self,
+        proposed_fees: &FetchPaymentProposedFeesResponse,
+    ) -> Result<(), PaymentError> {
+        let swap_id = &proposed_fees.swap_id;
+        let current_fees = self.fetch_payment_proposed_fees(swap_id).await?;
+        ensure_sdk!(
+            current_fees == *proposed_fees,
+            PaymentError::InvalidOrExpiredFees
+        );
+
+        let swap =
+            self.persister
+                .fetch_chain_swap_by_id(swap_id)?
+                .ok_or(PaymentError::Generic {
+                    err: format!("Chain Swap not found {swap_id}"),
+                })?;
+        self.swapper.accept_chain_swap_quote(
+            swap_id,
+            proposed_fees.receiver_amount_sat + swap.claim_fees_sat,
+        )?;
+        info!("Accepted the proposed fees for Chain Swap {swap_id}: {proposed_fees:?}");
+
+        self.persister.update_chain_swap_amounts(
+            swap_id,
+            proposed_fees.payer_amount_sat,
+            proposed_fees.receiver_amount_sat,
+        )?;
+        self.update_swap_info(swap_id, Pending, None, None, None, None)
+            .await
+    }
+
+    /// Accepts the fees proposed for an amountless swap if the amount paid is within the limits
+    /// of the pair and the fees exceed those of the pair by at most
+    /// [crate::model::Config::amountless_fee_tolerance_sat]. Returns whether they were accepted.
+    async fn auto_accept_proposed_fees(&self, swap: &ChainSwap) -> Result<bool, PaymentError> {
+        let swap_id = &swap.id;
+        let proposed_fees = self.fetch_payment_proposed_fees(swap_id).await?;
+        let pair = self
+            .swapper
+            .get_chain_pair(Direction::Incoming)?
+            .ok_or(PaymentError::PairsNotFound)?;
+
+        let payer_amount_sat = proposed_fees.payer_amount_sat;
+        if let Err(e) = pair.limits.within(payer_amount_sat) {
+            info!("Amountless Chain Swap {swap_id} was paid {payer_amount_sat} sats, out of the limits: {e:?}");
+            return Ok(false);
+        }
+
+        let expected_fees_sat =
+            pair.fees.boltz(payer_amount_sat) + pair.fees.server() + swap.claim_fees_sat;
+        let max_fees_sat = expected_fees_sat + self.config.amountless_fee_tolerance_sat();
+        if proposed_fees.fees_sat > max_fees_sat {
+            info!(
+                "Amountless Chain Swap {swap_id} has proposed fees of {} sats, above the maximum of {max_fees_sat} sats",
+                proposed_fees.fees_sat
+            );
+            return Ok(false);
+        }
+
+        self.accept_payment_proposed_fees(&proposed_fees).await?;
+        Ok(true)
+    }
+
     pub(crate) async fn refund_outgoing_swap(
         &self,
         swap: &ChainSwap,
@@ -696,27 +1195,18 @@ impl ChainSwapStateHandler {
             }),
             None => {
                 let output_address = self.onchain_wallet.next_unused_address().await?.to_string();
-                let (_, broadcast_fees_sat) =
-                    self.swapper
-                        .prepare_chain_swap_refund(swap, &output_address, 0.1)?;
-                let refund_res = self.swapper.refund_chain_swap_cooperative(
+                let lockup_utxo = self
+                    .fetch_swap_script_utxo(swap.get_lockup_swap_script()?)
+                    .await?;
+                let (_, broadcast_fees_sat) = self.swapper.prepare_chain_swap_refund(
                     swap,
                     &output_address,
-                    broadcast_fees_sat,
-                );
-                let refund_tx_id = match refund_res {
-                    Ok(res) => Ok(res),
-                    Err(e) => {
-                        warn!("Cooperative refund failed: {:?}", e);
-                        let current_height = self.liquid_chain_service.lock().await.tip().await?;
-                        self.swapper.refund_chain_swap_non_cooperative(
-                            swap,
-                            broadcast_fees_sat,
-                            &output_address,
-                            current_height,
-                        )
-                    }
-                }?;
+                    0.1,
+                    &lockup_utxo,
+                )?;
+                let refund_tx_id = self
+                    .refund_lockup_utxo(swap, lockup_utxo, &output_address, broadcast_fees_sat)
+                    .await?;
 
                 info!(
                     "Broadcast refund tx for Chain Swap {}. Tx id: {refund_tx_id}",
@@ -736,6 +1226,108 @@ impl ChainSwapStateHandler {
         }
     }
 
+    /// Refunds the swap lockup output cooperatively or, if the swapper does not cooperate, via
+    /// the refund script path once the lock time has elapsed
+    async fn refund_lockup_utxo(
+        &self,
+        swap: &ChainSwap,
+        lockup_utxo: Utxo,
+        output_address: &str,
+        broadcast_fees_sat: u64,
+    ) -> Result<String, PaymentError> {
+        let refund_res = match self.swapper.create_chain_swap_refund_cooperative(
+            swap,
+            lockup_utxo.clone(),
+            output_address,
+            broadcast_fees_sat,
+        ) {
+            Ok(refund_tx) => self.broadcast_swap_tx(&swap.id, &refund_tx).await,
+            Err(e) => Err(e),
+        };
+        match refund_res {
+            Ok(refund_tx_id) => Ok(refund_tx_id),
+            Err(e) => {
+                warn!("Cooperative refund failed: {:?}", e);
+                let current_height = match swap.direction {
+                    Direction::Incoming => {
+                        self.bitcoin_chain_service.lock().await.tip().await?.height as u32
+                    }
+                    Direction::Outgoing => self.liquid_chain_service.lock().await.tip().await?,
+                };
+                let refund_tx = self.swapper.create_chain_swap_refund_non_cooperative(
+                    swap,
+                    lockup_utxo,
+                    broadcast_fees_sat,
+                    output_address,
+                    current_height,
+                )?;
+                self.broadcast_swap_tx(&swap.id, &refund_tx).await
+            }
+        }
+    }
+
+    /// Looks up the unspent output of the swap script on its chain
+    async fn fetch_swap_script_utxo(
+        &self,
+        swap_script: SwapScriptV2,
+    ) -> Result<Utxo, PaymentError> {
+        let utxo = match swap_script {
+            SwapScriptV2::Bitcoin(swap_script) => {
+                let script_pubkey = swap_script
+                    .to_address(self.config.network.as_bitcoin_chain())
+                    .map_err(|e| anyhow!("Error getting script address: {e:?}"))?
+                    .script_pubkey();
+                Utxo::Bitcoin(
+                    self.bitcoin_chain_service
+                        .lock()
+                        .await
+                        .get_script_utxo(script_pubkey.as_script())
+                        .await?,
+                )
+            }
+            SwapScriptV2::Liquid(swap_script) => {
+                let script_pubkey = swap_script
+                    .to_address(self.config.network.into())
+                    .map_err(|e| anyhow!("Error getting script address: {e:?}"))?
+                    .to_unconfidential()
+                    .script_pubkey();
+                Utxo::Liquid(Box::new(
+                    self.liquid_chain_service
+                        .lock()
+                        .await
+                        .get_script_utxo(&script_pubkey)
+                        .await?,
+                ))
+            }
+        };
+        Ok(utxo)
+    }
+
+    /// Broadcasts a swap tx through the chain service of its chain
+    async fn broadcast_swap_tx(
+        &self,
+        swap_id: &str,
+        tx: &SwapTransaction,
+    ) -> Result<String, PaymentError> {
+        let txid = match tx {
+            SwapTransaction::Bitcoin(tx) => self
+                .bitcoin_chain_service
+                .lock()
+                .await
+                .broadcast(tx)
+                .await?
+                .to_string(),
+            SwapTransaction::Liquid(tx) => self
+                .liquid_chain_service
+                .lock()
+                .await
+                .broadcast(tx, Some(swap_id))
+                .await?
+                .to_string(),
+        };
+        Ok(txid)
+    }
+
     fn validate_state_transition(
         from_state: PaymentState,
         to_state: PaymentState,
@@ -745,7 +1337,7 @@ impl ChainSwapStateHandler {
                 err: "Cannot transition to Created state".to_string(),
             }),
 
-            (Created | Pending, Pending) => Ok(()),
+            (Created | Pending | WaitingFeeAcceptance, Pending) => Ok(()),
             (_, Pending) => Err(PaymentError::Generic {
                 err: format!("Cannot transition from {from_state:?} to Pending state"),
             }),
@@ -760,16 +1352,24 @@ impl ChainSwapStateHandler {
                 err: format!("Cannot transition from {from_state:?} to TimedOut state"),
             }),
 
-            (Created | Pending | RefundPending | Failed | Complete, Refundable) => Ok(()),
+            (
+                Created | Pending | RefundPending | Failed | Complete | WaitingFeeAcceptance,
+                Refundable,
+            ) => Ok(()),
             (_, Refundable) => Err(PaymentError::Generic {
                 err: format!("Cannot transition from {from_state:?} to Refundable state"),
             }),
 
-            (Pending | Refundable, RefundPending) => Ok(()),
+            (Pending | Refundable | WaitingFeeAcceptance, RefundPending) => Ok(()),
             (_, RefundPending) => Err(PaymentError::Generic {
                 err: format!("Cannot transition from {from_state:?} to RefundPending state"),
             }),
 
+            (Created | Pending | WaitingFeeAcceptance, WaitingFeeAcceptance) => Ok(()),
+            (_, WaitingFeeAcceptance) => Err(PaymentError::Generic {
+                err: format!("Cannot transition from {from_state:?} to WaitingFeeAcceptance state"),
+            }),
+
             (Complete, Failed) => Err(PaymentError::Generic {
                 err: format!("Cannot transition from {from_state:?} to Failed state"),
             }),
@@ -803,7 +1403,8 @@ impl ChainSwapStateHandler {
         verify_confirmation: bool,
     ) -> Result<()> {
         let swap_script = chain_swap.get_claim_swap_script()?;
-        let claim_details = chain_swap.get_boltz_create_response()?.claim_details;
+        // The server lockup amount changes if new fees were accepted for the swap
+        let server_lockup_amount_sat = chain_swap.receiver_amount_sat + chain_swap.claim_fees_sat;
         // Verify transaction
         let liquid_swap_script = swap_script.as_liquid_script()?;
         let address = liquid_swap_script
@@ -837,10 +1438,9 @@ impl ChainSwapStateHandler {
                 .unblind(&secp, liquid_swap_script.blinding_key.secret_key())?
                 .value;
         }
-        if value < claim_details.amount as u64 {
+        if value < server_lockup_amount_sat {
             return Err(anyhow!(
-                "Transaction value {value} sats is less than {} sats",
-                claim_details.amount
+                "Transaction value {value} sats is less than {server_lockup_amount_sat} sats"
             ));
         }
         Ok(())
@@ -892,26 +1492,422 @@ impl ChainSwapStateHandler {
     }
 }
 
+/// The P2WPKH address at `xpub/0/index`, i.e. on the external chain of the account
+fn derive_refund_address(xpub: &str, index: u32, network: LiquidNetwork) -> Result<String> {
+    let public_key = Xpub::from_str(xpub)?
+        .derive_pub(
+            &bitcoin::secp256k1::Secp256k1::verification_only(),
+            &[
+                ChildNumber::from_normal_idx(0)?,
+                ChildNumber::from_normal_idx(index)?,
+            ],
+        )?
+        .to_pub();
+    Ok(bitcoin::Address::p2wpkh(&public_key, network.as_bitcoin_network())?.to_string())
+}
+
+/// Gets the vsizes of a refund tx spending the lockup via the key path, i.e. cooperatively, and
+/// via the refund script path, i.e. non-cooperatively. They are derived from the replaced refund
+/// tx, which may have used either path, as the replacement has the same inputs and outputs.
+fn get_refund_tx_vsizes(
+    replaced_tx: &boltz_client::bitcoin::Transaction,
+    refund_script_size: usize,
+) -> Result<(u64, u64)> {
+    let witness_item_weight = |item_size: usize| -> u64 {
+        let size_prefix_weight = match item_size {
+            0..=0xfc => 1,
+            0xfd..=0xffff => 3,
+            _ => 5,
+        };
+        size_prefix_weight + item_size as u64
+    };
+    let witness = &replaced_tx
+        .input
+        .first()
+        .ok_or(anyhow!("Refund tx has no inputs"))?
+        .witness;
+
+    // Both paths have the signature as first witness item, which the script path follows with
+    // the refund script and the control block
+    let script_path_items_weight: u64 = witness
+        .iter()
+        .skip(1)
+        .map(|item| witness_item_weight(item.len()))
+        .sum();
+    let key_path_weight = replaced_tx.weight().to_wu() - script_path_items_weight;
+    let script_path_weight = key_path_weight
+        + witness_item_weight(refund_script_size)
+        + witness_item_weight(SWAP_TREE_CONTROL_BLOCK_SIZE);
+    Ok(((key_path_weight + 3) / 4, (script_path_weight + 3) / 4))
+}
+
 #[cfg(test)]
 mod tests {
     use std::{
         collections::{HashMap, HashSet},
+        str::FromStr,
         sync::Arc,
     };
 
     use anyhow::Result;
+    use boltz_client::{bitcoin, boltz};
+    use lwk_wollet::{elements, History};
+    use tokio::sync::Mutex;
 
     use crate::{
         model::{
-            Direction,
+            AutoRefundDetails, AutoRefundPolicy, ChainSwap, Config, Direction, LiquidNetwork,
             PaymentState::{self, *},
+            RecommendedFees, RefundDestination, RefundFeeRate, SdkEvent,
         },
+        swapper::Swapper,
         test_utils::{
-            chain_swap::{new_chain_swap, new_chain_swap_state_handler},
+            chain::MockBitcoinChainService,
+            chain_swap::{
+                new_chain_swap, new_chain_swap_state_handler,
+                new_chain_swap_state_handler_with_mocks, TEST_BITCOIN_TX,
+            },
             persist::new_persister,
+            swapper::MockSwapper,
         },
     };
 
+    use super::{derive_refund_address, get_refund_tx_vsizes, SWAP_TREE_CONTROL_BLOCK_SIZE};
+
+    /// The BIP84 test vector account xpub, of the `abandon ... about` mnemonic
+    const TEST_XPUB: &str = "xpub6CatWdiZiodmUeTDp8LT5or8nmbKNcuyvz7WyksVFkKB4RHwCD3XyuvPEbvqAQY3rAPshWcMLoP2fMFMKHPJ4ZeZXYVUhLv1VMrjPC7PW6V";
+
+    #[test]
+    fn test_derive_refund_address() -> Result<()> {
+        let test_cases = [
+            (
+                0,
+                LiquidNetwork::Mainnet,
+                "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu",
+            ),
+            (
+                1,
+                LiquidNetwork::Mainnet,
+                "bc1qnjg0jd8228aq7egyzacy8cys3knf9xvrerkf9g",
+            ),
+            (
+                0,
+                LiquidNetwork::Testnet,
+                "tb1qcr8te4kr609gcawutmrza0j4xv80jy8zmfp6l0",
+            ),
+            (
+                1,
+                LiquidNetwork::Regtest,
+                "bcrt1qnjg0jd8228aq7egyzacy8cys3knf9xvr3v5hfj",
+            ),
+        ];
+        for (index, network, expected_address) in test_cases {
+            assert_eq!(
+                derive_refund_address(TEST_XPUB, index, network)?,
+                expected_address
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_auto_refund_incoming_swaps() -> Result<()> {
+        let (_temp_dir, storage) = new_persister()?;
+        let storage = Arc::new(storage);
+
+        let mut config = Config::testnet();
+        config.auto_refund = Some(AutoRefundPolicy {
+            destination: RefundDestination::Xpub {
+                xpub: TEST_XPUB.to_string(),
+            },
+            fee_rate: RefundFeeRate::Economy,
+        });
+        let swapper = Arc::new(MockSwapper::new());
+        let bitcoin_chain_service = Arc::new(Mutex::new(MockBitcoinChainService::new()));
+        bitcoin_chain_service
+            .lock()
+            .await
+            .set_recommended_fees(RecommendedFees {
+                fastest_fee: 10,
+                half_hour_fee: 8,
+                hour_fee: 6,
+                economy_fee: 4,
+                minimum_fee: 2,
+            });
+        let chain_swap_state_handler = new_chain_swap_state_handler_with_mocks(
+            config,
+            storage.clone(),
+            swapper.clone(),
+            bitcoin_chain_service.clone(),
+        )?;
+        let mut events = chain_swap_state_handler.subscribe_events();
+
+        let mut locked_swap = new_chain_swap(Direction::Incoming, Some(Refundable), false, None);
+        locked_swap.lockup_address = "tb1q-locked-swap".to_string();
+        storage.insert_chain_swap(&locked_swap)?;
+        let mut expired_swap = new_chain_swap(Direction::Incoming, Some(Refundable), false, None);
+        expired_swap.lockup_address = "tb1q-expired-swap".to_string();
+        storage.insert_chain_swap(&expired_swap)?;
+        let first_address = derive_refund_address(TEST_XPUB, 0, LiquidNetwork::Testnet)?;
+        let second_address = derive_refund_address(TEST_XPUB, 1, LiquidNetwork::Testnet)?;
+
+        // The failing refund is skipped, and the address it reserved is used by the next one
+        swapper.set_failing_refunds(&[&locked_swap.id]);
+        chain_swap_state_handler
+            .auto_refund_incoming_swaps()
+            .await?;
+        assert_eq!(storage.get_auto_refund_address_index()?, 1);
+        let refund_tx_id = bitcoin_chain_service
+            .lock()
+            .await
+            .broadcast_txs
+            .lock()
+            .unwrap()
+            .pop()
+            .unwrap()
+            .txid()
+            .to_string();
+        assert_eq!(
+            events.try_recv()?,
+            SdkEvent::SwapAutoRefunded {
+                details: AutoRefundDetails {
+                    swap_address: expired_swap.lockup_address.clone(),
+                    refund_address: first_address,
+                    refund_tx_id: refund_tx_id.clone(),
+                },
+            }
+        );
+        assert!(events.try_recv().is_err());
+        let expired_swap = storage.fetch_chain_swap_by_id(&expired_swap.id)?.unwrap();
+        assert_eq!(expired_swap.state, RefundPending);
+        assert_eq!(expired_swap.refund_tx_id, Some(refund_tx_id));
+        let locked_swap = storage.fetch_chain_swap_by_id(&locked_swap.id)?.unwrap();
+        assert_eq!(locked_swap.state, Refundable);
+
+        // The skipped refund is retried on the next run
+        swapper.set_failing_refunds(&[]);
+        chain_swap_state_handler
+            .auto_refund_incoming_swaps()
+            .await?;
+        assert_eq!(storage.get_auto_refund_address_index()?, 2);
+        let refund_tx_id = bitcoin_chain_service
+            .lock()
+            .await
+            .broadcast_txs
+            .lock()
+            .unwrap()
+            .pop()
+            .unwrap()
+            .txid()
+            .to_string();
+        assert_eq!(
+            events.try_recv()?,
+            SdkEvent::SwapAutoRefunded {
+                details: AutoRefundDetails {
+                    swap_address: locked_swap.lockup_address.clone(),
+                    refund_address: second_address,
+                    refund_tx_id: refund_tx_id.clone(),
+                },
+            }
+        );
+        assert!(events.try_recv().is_err());
+        let locked_swap = storage.fetch_chain_swap_by_id(&locked_swap.id)?.unwrap();
+        assert_eq!(locked_swap.state, RefundPending);
+        assert_eq!(locked_swap.refund_tx_id, Some(refund_tx_id));
+
+        // Without refundable swaps, no address is reserved
+        chain_swap_state_handler
+            .auto_refund_incoming_swaps()
+            .await?;
+        assert_eq!(storage.get_auto_refund_address_index()?, 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_auto_accept_proposed_fees() -> Result<()> {
+        let (_temp_dir, storage) = new_persister()?;
+        let storage = Arc::new(storage);
+
+        let mut config = Config::testnet();
+        config.amountless_fee_tolerance_sat = Some(100);
+        let swapper = Arc::new(MockSwapper::new());
+        let bitcoin_chain_service = Arc::new(Mutex::new(MockBitcoinChainService::new()));
+        let payer_amount_sat = 30_000;
+        bitcoin_chain_service
+            .lock()
+            .await
+            .set_script_balance(payer_amount_sat, 0);
+        let chain_swap_state_handler = new_chain_swap_state_handler_with_mocks(
+            config,
+            storage.clone(),
+            swapper.clone(),
+            bitcoin_chain_service,
+        )?;
+
+        let swap = new_chain_swap(
+            Direction::Incoming,
+            Some(WaitingFeeAcceptance),
+            false,
+            Some("user-lockup-tx-id".to_string()),
+        );
+        storage.insert_chain_swap(&swap)?;
+        let pair = swapper.get_chain_pair(Direction::Incoming)?.unwrap();
+        let max_fees_sat =
+            pair.fees.boltz(payer_amount_sat) + pair.fees.server() + swap.claim_fees_sat + 100;
+        // The quote for which the payer pays the given fees
+        let set_proposed_fees = |fees_sat: u64| {
+            swapper.set_chain_swap_quote(payer_amount_sat - fees_sat + swap.claim_fees_sat)
+        };
+
+        // Fees above the tolerance are left for the user to accept
+        set_proposed_fees(max_fees_sat + 1);
+        assert!(
+            !chain_swap_state_handler
+                .auto_accept_proposed_fees(&swap)
+                .await?
+        );
+
+        // So are amounts out of the pair limits
+        set_proposed_fees(max_fees_sat);
+        swapper.set_chain_pair_limits(payer_amount_sat + 1, u64::MAX);
+        assert!(
+            !chain_swap_state_handler
+                .auto_accept_proposed_fees(&swap)
+                .await?
+        );
+        swapper.set_chain_pair_limits(0, payer_amount_sat - 1);
+        assert!(
+            !chain_swap_state_handler
+                .auto_accept_proposed_fees(&swap)
+                .await?
+        );
+        assert!(swapper
+            .accepted_chain_swap_quotes
+            .lock()
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            storage.fetch_chain_swap_by_id(&swap.id)?.unwrap().state,
+            WaitingFeeAcceptance
+        );
+
+        // Fees at the tolerance boundary are accepted
+        swapper.set_chain_pair_limits(0, payer_amount_sat);
+        assert!(
+            chain_swap_state_handler
+                .auto_accept_proposed_fees(&swap)
+                .await?
+        );
+        let receiver_amount_sat = payer_amount_sat - max_fees_sat;
+        assert_eq!(
+            *swapper.accepted_chain_swap_quotes.lock().unwrap(),
+            vec![(swap.id.clone(), receiver_amount_sat + swap.claim_fees_sat)]
+        );
+        let swap = storage.fetch_chain_swap_by_id(&swap.id)?.unwrap();
+        assert_eq!(swap.state, Pending);
+        assert_eq!(swap.payer_amount_sat, payer_amount_sat);
+        assert_eq!(swap.receiver_amount_sat, receiver_amount_sat);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_lockup_failed_finds_user_lockup() -> Result<()> {
+        let (_temp_dir, storage) = new_persister()?;
+        let storage = Arc::new(storage);
+
+        let swapper = Arc::new(MockSwapper::new());
+        swapper.set_chain_swap_quote(18_000);
+        let bitcoin_chain_service = Arc::new(Mutex::new(MockBitcoinChainService::new()));
+        let chain_swap_state_handler = new_chain_swap_state_handler_with_mocks(
+            Config::testnet(),
+            storage.clone(),
+            swapper,
+            bitcoin_chain_service.clone(),
+        )?;
+
+        // The user lockups were never announced
+        let new_lockup_failed_swap = || -> Result<(ChainSwap, boltz::Update)> {
+            let swap = new_chain_swap(Direction::Incoming, Some(Created), false, None);
+            storage.insert_chain_swap(&swap)?;
+            let update = boltz::Update {
+                id: swap.id.clone(),
+                status: "transaction.lockupFailed".to_string(),
+                transaction: None,
+                zero_conf_rejected: None,
+            };
+            Ok((swap, update))
+        };
+
+        // Without a lockup in the script history, nothing was paid
+        let (swap, update) = new_lockup_failed_swap()?;
+        chain_swap_state_handler.on_new_status(&update).await?;
+        assert_eq!(
+            storage.fetch_chain_swap_by_id(&swap.id)?.unwrap().state,
+            Failed
+        );
+
+        let (swap, update) = new_lockup_failed_swap()?;
+        let user_lockup_tx_id = TEST_BITCOIN_TX.txid().to_string();
+        bitcoin_chain_service
+            .lock()
+            .await
+            .set_history(vec![History {
+                txid: elements::Txid::from_str(&user_lockup_tx_id)?,
+                height: 0,
+                block_hash: None,
+                block_timestamp: None,
+            }]);
+        chain_swap_state_handler.on_new_status(&update).await?;
+        let swap = storage.fetch_chain_swap_by_id(&swap.id)?.unwrap();
+        assert_eq!(swap.state, WaitingFeeAcceptance);
+        assert_eq!(swap.user_lockup_tx_id, Some(user_lockup_tx_id));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_refund_tx_vsizes() -> Result<()> {
+        let new_refund_tx = |witness: &[Vec<u8>]| bitcoin::Transaction {
+            version: bitcoin::transaction::Version::TWO,
+            lock_time: bitcoin::absolute::LockTime::ZERO,
+            input: vec![bitcoin::TxIn {
+                previous_output: bitcoin::OutPoint::null(),
+                script_sig: bitcoin::ScriptBuf::new(),
+                sequence: bitcoin::Sequence::ENABLE_RBF_NO_LOCKTIME,
+                witness: bitcoin::Witness::from_slice(witness),
+            }],
+            output: vec![bitcoin::TxOut {
+                value: bitcoin::Amount::from_sat(10_000),
+                // A P2WPKH output script
+                script_pubkey: bitcoin::ScriptBuf::from(vec![0; 22]),
+            }],
+        };
+        let refund_script_size = 40;
+        let key_path_tx = new_refund_tx(&[vec![1; 64]]);
+        let script_path_tx = new_refund_tx(&[
+            vec![1; 64],
+            vec![2; refund_script_size],
+            vec![3; SWAP_TREE_CONTROL_BLOCK_SIZE],
+        ]);
+
+        // The sizes of both paths are the same, whichever path the replaced tx used
+        let expected_vsizes = (key_path_tx.vsize() as u64, script_path_tx.vsize() as u64);
+        assert!(expected_vsizes.0 < expected_vsizes.1);
+        assert_eq!(
+            get_refund_tx_vsizes(&key_path_tx, refund_script_size)?,
+            expected_vsizes
+        );
+        assert_eq!(
+            get_refund_tx_vsizes(&script_path_tx, refund_script_size)?,
+            expected_vsizes
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_chain_swap_state_transitions() -> Result<()> {
         let (_temp_dir, storage) = new_persister()?;
@@ -920,21 +1916,52 @@ mod tests {
         let chain_swap_state_handler = new_chain_swap_state_handler(storage.clone())?;
 
         // Test valid combinations of states
-        let all_states = HashSet::from([Created, Pending, Complete, TimedOut, Failed]);
+        let all_states = HashSet::from([
+            Created,
+            Pending,
+            Complete,
+            TimedOut,
+            Failed,
+            WaitingFeeAcceptance,
+        ]);
         let valid_combinations = HashMap::from([
             (
                 Created,
-                HashSet::from([Pending, Complete, TimedOut, Refundable, Failed]),
+                HashSet::from([
+                    Pending,
+                    Complete,
+                    TimedOut,
+                    Refundable,
+                    Failed,
+                    WaitingFeeAcceptance,
+                ]),
             ),
             (
                 Pending,
-                HashSet::from([Pending, Complete, Refundable, RefundPending, Failed]),
+                HashSet::from([
+                    Pending,
+                    Complete,
+                    Refundable,
+                    RefundPending,
+                    Failed,
+                    WaitingFeeAcceptance,
+                ]),
             ),
             (TimedOut, HashSet::from([Failed])),
             (Complete, HashSet::from([Refundable])),
             (Refundable, HashSet::from([RefundPending, Failed])),
             (RefundPending, HashSet::from([Refundable, Complete, Failed])),
             (Failed, HashSet::from([Failed, Refundable])),
+            (
+                WaitingFeeAcceptance,
+                HashSet::from([
+                    Pending,
+                    Refundable,
+                    RefundPending,
+                    Failed,
+                    WaitingFeeAcceptance,
+                ]),
+            ),
         ]);
 
         for (first_state, allowed_states) in valid_combinations.iter() {
@@ -70,6 +70,9 @@ pub enum PaymentError {
     #[error("Amount is out of range")]
     AmountOutOfRange,
 
+    #[error("Amount is missing: {err}")]
+    AmountMissing { err: String },
+
     #[error("Generic error: {err}")]
     Generic { err: String },
 
@@ -82,6 +85,9 @@ pub enum PaymentError {
     #[error("The specified invoice is not valid: {err}")]
     InvalidInvoice { err: String },
 
+    #[error("The specified destination is not valid: {err}")]
+    InvalidDestination { err: String },
+
     #[error("The generated preimage is not valid")]
     InvalidPreimage,
 
@@ -111,6 +117,9 @@ pub enum PaymentError {
 
     #[error("Could not sign the transaction: {err}")]
     SignerError { err: String },
+
+    #[error("Not supported in watch-only mode")]
+    WatchOnly,
 }
 impl PaymentError {
     pub(crate) fn receive_error(err: &str) -> Self {
@@ -184,6 +193,37 @@ impl From<crate::bitcoin::util::bip32::Error> for PaymentError {
     }
 }
 
+/// Error returned by a [crate::signer::Signer]
+#[derive(Debug, thiserror::Error)]
+pub enum SignerError {
+    #[error("Signer error: {err}")]
+    Generic { err: String },
+}
+
+impl From<lwk_signer::SignerError> for SignerError {
+    fn from(err: lwk_signer::SignerError) -> Self {
+        SignerError::Generic {
+            err: format!("{err:?}"),
+        }
+    }
+}
+
+impl From<crate::bitcoin::util::bip32::Error> for SignerError {
+    fn from(err: crate::bitcoin::util::bip32::Error) -> Self {
+        SignerError::Generic {
+            err: err.to_string(),
+        }
+    }
+}
+
+impl From<SignerError> for PaymentError {
+    fn from(err: SignerError) -> Self {
+        Self::SignerError {
+            err: err.to_string(),
+        }
+    }
+}
+
 impl From<PaymentError> for LnUrlAuthError {
     fn from(value: PaymentError) -> Self {
         Self::Generic {
@@ -0,0 +1,293 @@
+use std::{fs, path::Path};
+
+use anyhow::Result;
+use chrono::{TimeZone, Utc};
+use serde::Serialize;
+
+use crate::model::{ExportFormat, Payment, PaymentState, PaymentType};
+
+const SATS_PER_BTC: f64 = 100_000_000.0;
+
+const CSV_HEADER: [&str; 21] = [
+    "timestamp",
+    "date",
+    "payment_type",
+    "status",
+    "amount_sat",
+    "fees_sat",
+    "network_fees_sat",
+    "swap_fees_sat",
+    "asset_id",
+    "swap_id",
+    "tx_id",
+    "refund_tx_id",
+    "refund_tx_amount_sat",
+    "description",
+    "bolt11",
+    "destination",
+    "fiat_currency",
+    "fiat_rate",
+    "fiat_rate_source",
+    "fiat_amount",
+    "fiat_fees",
+];
+
+/// Where the fiat rate a payment is valued at comes from
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum FiatRateSource {
+    /// The rate recorded at the time of the payment, see [Payment::fiat_rates]
+    Snapshot,
+    /// The rate at the time of the export, for payments without a recorded rate
+    Current,
+}
+
+impl FiatRateSource {
+    fn as_str(&self) -> &'static str {
+        match self {
+            FiatRateSource::Snapshot => "snapshot",
+            FiatRateSource::Current => "current",
+        }
+    }
+}
+
+/// A payment as written to the export file, see [crate::sdk::LiquidSdk::export_payments]
+#[derive(Debug, Serialize)]
+pub(crate) struct PaymentExportRecord {
+    timestamp: u32,
+    /// The [Self::timestamp] as an RFC 3339 UTC date
+    date: String,
+    payment_type: PaymentType,
+    status: PaymentState,
+    amount_sat: u64,
+    fees_sat: u64,
+    /// The part of [Self::fees_sat] paid for the wallet onchain tx
+    network_fees_sat: u64,
+    /// The part of [Self::fees_sat] paid to the swapper, including its onchain tx fees
+    swap_fees_sat: u64,
+    asset_id: Option<String>,
+    swap_id: Option<String>,
+    tx_id: Option<String>,
+    refund_tx_id: Option<String>,
+    refund_tx_amount_sat: Option<u64>,
+    description: String,
+    bolt11: Option<String>,
+    destination: Option<String>,
+    fiat_currency: Option<String>,
+    /// The BTC price in [Self::fiat_currency] the payment is valued at
+    fiat_rate: Option<f64>,
+    fiat_rate_source: Option<FiatRateSource>,
+    /// The fiat value of [Self::amount_sat]. Not set for asset payments.
+    fiat_amount: Option<f64>,
+    fiat_fees: Option<f64>,
+}
+
+impl PaymentExportRecord {
+    /// Builds the record of a payment, given the fee of its wallet tx and the optional fiat
+    /// currency, rate and rate source to value it in
+    pub(crate) fn new(
+        payment: Payment,
+        tx_fees_sat: Option<u64>,
+        fiat_rate: Option<(&str, f64, FiatRateSource)>,
+    ) -> Self {
+        let network_fees_sat = match (&payment.swap_id, payment.payment_type) {
+            (Some(_), _) => tx_fees_sat.unwrap_or(0).min(payment.fees_sat),
+            (None, PaymentType::Send) => payment.fees_sat,
+            (None, PaymentType::Receive) => 0,
+        };
+        let to_fiat = |amount_sat: u64| {
+            fiat_rate.map(|(_, rate, _)| {
+                (amount_sat as f64 / SATS_PER_BTC * rate * 100.0).round() / 100.0
+            })
+        };
+
+        PaymentExportRecord {
+            timestamp: payment.timestamp,
+            date: Utc
+                .timestamp_opt(payment.timestamp as i64, 0)
+                .single()
+                .map(|date| date.to_rfc3339())
+                .unwrap_or_default(),
+            payment_type: payment.payment_type,
+            status: payment.status,
+            fiat_amount: match payment.asset_id {
+                Some(_) => None,
+                None => to_fiat(payment.amount_sat),
+            },
+            fiat_fees: to_fiat(payment.fees_sat),
+            amount_sat: payment.amount_sat,
+            fees_sat: payment.fees_sat,
+            network_fees_sat,
+            swap_fees_sat: payment.fees_sat - network_fees_sat,
+            asset_id: payment.asset_id,
+            swap_id: payment.swap_id,
+            tx_id: payment.tx_id,
+            refund_tx_id: payment.refund_tx_id,
+            refund_tx_amount_sat: payment.refund_tx_amount_sat,
+            description: payment.description,
+            bolt11: payment.bolt11,
+            destination: payment.destination,
+            fiat_currency: fiat_rate.map(|(currency, _, _)| currency.to_string()),
+            fiat_rate: fiat_rate.map(|(_, rate, _)| rate),
+            fiat_rate_source: fiat_rate.map(|(_, _, source)| source),
+        }
+    }
+
+    fn to_csv_row(&self) -> String {
+        let opt = |value: Option<String>| value.unwrap_or_default();
+        [
+            self.timestamp.to_string(),
+            self.date.clone(),
+            self.payment_type.to_string(),
+            format!("{:?}", self.status),
+            self.amount_sat.to_string(),
+            self.fees_sat.to_string(),
+            self.network_fees_sat.to_string(),
+            self.swap_fees_sat.to_string(),
+            opt(self.asset_id.clone()),
+            opt(self.swap_id.clone()),
+            opt(self.tx_id.clone()),
+            opt(self.refund_tx_id.clone()),
+            opt(self.refund_tx_amount_sat.map(|amount| amount.to_string())),
+            self.description.clone(),
+            opt(self.bolt11.clone()),
+            opt(self.destination.clone()),
+            opt(self.fiat_currency.clone()),
+            opt(self.fiat_rate.map(|rate| rate.to_string())),
+            opt(self
+                .fiat_rate_source
+                .map(|source| source.as_str().to_string())),
+            opt(self.fiat_amount.map(|amount| format!("{amount:.2}"))),
+            opt(self.fiat_fees.map(|fees| format!("{fees:.2}"))),
+        ]
+        .iter()
+        .map(|field| escape_csv_field(field))
+        .collect::<Vec<_>>()
+        .join(",")
+    }
+}
+
+/// Quotes a CSV field if it contains a separator, a quote or a line break. Fields that a
+/// spreadsheet would evaluate as a formula are prefixed with `'`.
+fn escape_csv_field(field: &str) -> String {
+    let field = match field.starts_with(['=', '+', '-', '@']) {
+        true => format!("'{field}"),
+        false => field.to_string(),
+    };
+    match field.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field,
+    }
+}
+
+pub(crate) fn to_csv(records: &[PaymentExportRecord]) -> String {
+    let mut csv = CSV_HEADER.join(",");
+    csv.push('\n');
+    for record in records {
+        csv.push_str(&record.to_csv_row());
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Writes the records to the file at the given path, replacing any existing file
+pub(crate) fn write_payments(
+    records: &[PaymentExportRecord],
+    format: ExportFormat,
+    path: &Path,
+) -> Result<()> {
+    let contents = match format {
+        ExportFormat::Csv => to_csv(records),
+        ExportFormat::Json => serde_json::to_string_pretty(records)?,
+    };
+    fs::write(path, contents)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::model::{Payment, PaymentState, PaymentType};
+
+    use super::{escape_csv_field, to_csv, FiatRateSource, PaymentExportRecord, CSV_HEADER};
+
+    fn new_payment(swap_id: Option<&str>, description: &str) -> Payment {
+        Payment {
+            tx_id: Some("tx_id".to_string()),
+            swap_id: swap_id.map(str::to_string),
+            timestamp: 1_700_000_000,
+            amount_sat: 50_000,
+            fees_sat: 300,
+            preimage: None,
+            bolt11: None,
+            description: description.to_string(),
+            destination: None,
+            asset_id: None,
+            refund_tx_id: None,
+            refund_tx_amount_sat: None,
+            payment_type: PaymentType::Send,
+            status: PaymentState::Complete,
+            metadata: vec![],
+            fiat_rates: vec![],
+        }
+    }
+
+    #[test]
+    fn test_payment_export_record() -> Result<()> {
+        let record = PaymentExportRecord::new(
+            new_payment(Some("swap_id"), "Coffee"),
+            Some(100),
+            Some(("USD", 60_000.0, FiatRateSource::Snapshot)),
+        );
+        assert_eq!(record.network_fees_sat, 100);
+        assert_eq!(record.swap_fees_sat, 200);
+        assert_eq!(record.fiat_amount, Some(30.0));
+        assert_eq!(record.fiat_fees, Some(0.18));
+        assert_eq!(record.fiat_rate_source, Some(FiatRateSource::Snapshot));
+        assert_eq!(record.date, "2023-11-14T22:13:20+00:00");
+
+        let record = PaymentExportRecord::new(new_payment(None, "Coffee"), Some(300), None);
+        assert_eq!(record.network_fees_sat, 300);
+        assert_eq!(record.swap_fees_sat, 0);
+        assert_eq!(record.fiat_amount, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_csv() -> Result<()> {
+        let records = vec![PaymentExportRecord::new(
+            new_payment(None, "Coffee, \"large\""),
+            None,
+            None,
+        )];
+        let csv = to_csv(&records);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], CSV_HEADER.join(","));
+        assert!(lines[1].contains(",\"Coffee, \"\"large\"\"\","));
+
+        let records = vec![PaymentExportRecord::new(
+            new_payment(None, "Coffee"),
+            None,
+            Some(("USD", 60_000.0, FiatRateSource::Current)),
+        )];
+        assert!(to_csv(&records).contains(",USD,60000,current,30.00,0.18"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_escape_csv_field() {
+        assert_eq!(escape_csv_field("Coffee"), "Coffee");
+        assert_eq!(escape_csv_field("=1+2"), "'=1+2");
+        assert_eq!(escape_csv_field("+1"), "'+1");
+        assert_eq!(escape_csv_field("-1"), "'-1");
+        assert_eq!(escape_csv_field("@SUM(A1)"), "'@SUM(A1)");
+        assert_eq!(
+            escape_csv_field("=HYPERLINK(\"x\", \"y\")"),
+            "\"'=HYPERLINK(\"\"x\"\", \"\"y\"\")\""
+        );
+    }
+}
@@ -71,6 +71,18 @@ impl CstDecode<StreamSink<crate::model::SdkEvent, flutter_rust_bridge::for_gener
         StreamSink::deserialize(raw)
     }
 }
+impl
+    CstDecode<StreamSink<crate::model::SignerRequest, flutter_rust_bridge::for_generated::DcoCodec>>
+    for *mut wire_cst_list_prim_u_8_strict
+{
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(
+        self,
+    ) -> StreamSink<crate::model::SignerRequest, flutter_rust_bridge::for_generated::DcoCodec> {
+        let raw: String = self.cst_decode();
+        StreamSink::deserialize(raw)
+    }
+}
 impl CstDecode<String> for *mut wire_cst_list_prim_u_8_strict {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> String {
@@ -78,6 +90,16 @@ impl CstDecode<String> for *mut wire_cst_list_prim_u_8_strict {
         String::from_utf8(vec).unwrap()
     }
 }
+impl CstDecode<crate::model::AcceptPaymentProposedFeesRequest>
+    for wire_cst_accept_payment_proposed_fees_request
+{
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::AcceptPaymentProposedFeesRequest {
+        crate::model::AcceptPaymentProposedFeesRequest {
+            response: self.response.cst_decode(),
+        }
+    }
+}
 impl CstDecode<crate::bindings::AesSuccessActionDataDecrypted>
     for wire_cst_aes_success_action_data_decrypted
 {
@@ -111,6 +133,48 @@ impl CstDecode<crate::bindings::AesSuccessActionDataResult>
         }
     }
 }
+impl CstDecode<crate::model::AssetBalance> for wire_cst_asset_balance {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::AssetBalance {
+        crate::model::AssetBalance {
+            asset_id: self.asset_id.cst_decode(),
+            balance: self.balance.cst_decode(),
+            pending_send: self.pending_send.cst_decode(),
+            pending_receive: self.pending_receive.cst_decode(),
+            asset_metadata: self.asset_metadata.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::AssetMetadata> for wire_cst_asset_metadata {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::AssetMetadata {
+        crate::model::AssetMetadata {
+            asset_id: self.asset_id.cst_decode(),
+            name: self.name.cst_decode(),
+            ticker: self.ticker.cst_decode(),
+            precision: self.precision.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::AutoRefundDetails> for wire_cst_auto_refund_details {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::AutoRefundDetails {
+        crate::model::AutoRefundDetails {
+            swap_address: self.swap_address.cst_decode(),
+            refund_address: self.refund_address.cst_decode(),
+            refund_tx_id: self.refund_tx_id.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::AutoRefundPolicy> for wire_cst_auto_refund_policy {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::AutoRefundPolicy {
+        crate::model::AutoRefundPolicy {
+            destination: self.destination.cst_decode(),
+            fee_rate: self.fee_rate.cst_decode(),
+        }
+    }
+}
 impl CstDecode<crate::model::BackupRequest> for wire_cst_backup_request {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::model::BackupRequest {
@@ -119,6 +183,21 @@ impl CstDecode<crate::model::BackupRequest> for wire_cst_backup_request {
         }
     }
 }
+impl CstDecode<crate::model::BalanceDetails> for wire_cst_balance_details {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::BalanceDetails {
+        crate::model::BalanceDetails {
+            confirmed_sat: self.confirmed_sat.cst_decode(),
+            unconfirmed_receive_sat: self.unconfirmed_receive_sat.cst_decode(),
+            unconfirmed_send_sat: self.unconfirmed_send_sat.cst_decode(),
+            pending_send_swaps_sat: self.pending_send_swaps_sat.cst_decode(),
+            pending_receive_swaps_sat: self.pending_receive_swaps_sat.cst_decode(),
+            pending_refund_sat: self.pending_refund_sat.cst_decode(),
+            refundable_btc_sat: self.refundable_btc_sat.cst_decode(),
+            pending_btc_refund_sat: self.pending_btc_refund_sat.cst_decode(),
+        }
+    }
+}
 impl CstDecode<crate::bindings::BindingEventListener> for wire_cst_binding_event_listener {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::bindings::BindingEventListener {
@@ -139,6 +218,15 @@ impl CstDecode<crate::bindings::BitcoinAddressData> for wire_cst_bitcoin_address
         }
     }
 }
+impl CstDecode<crate::model::AcceptPaymentProposedFeesRequest>
+    for *mut wire_cst_accept_payment_proposed_fees_request
+{
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::AcceptPaymentProposedFeesRequest {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::AcceptPaymentProposedFeesRequest>::cst_decode(*wrap).into()
+    }
+}
 impl CstDecode<crate::bindings::AesSuccessActionDataDecrypted>
     for *mut wire_cst_aes_success_action_data_decrypted
 {
@@ -157,6 +245,27 @@ impl CstDecode<crate::bindings::AesSuccessActionDataResult>
         CstDecode::<crate::bindings::AesSuccessActionDataResult>::cst_decode(*wrap).into()
     }
 }
+impl CstDecode<crate::model::AssetMetadata> for *mut wire_cst_asset_metadata {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::AssetMetadata {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::AssetMetadata>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<crate::model::AutoRefundDetails> for *mut wire_cst_auto_refund_details {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::AutoRefundDetails {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::AutoRefundDetails>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<crate::model::AutoRefundPolicy> for *mut wire_cst_auto_refund_policy {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::AutoRefundPolicy {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::AutoRefundPolicy>::cst_decode(*wrap).into()
+    }
+}
 impl CstDecode<crate::model::BackupRequest> for *mut wire_cst_backup_request {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::model::BackupRequest {
@@ -184,6 +293,13 @@ impl CstDecode<bool> for *mut bool {
         unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
     }
 }
+impl CstDecode<crate::model::BumpRefundFeeRequest> for *mut wire_cst_bump_refund_fee_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::BumpRefundFeeRequest {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::BumpRefundFeeRequest>::cst_decode(*wrap).into()
+    }
+}
 impl CstDecode<crate::model::BuyBitcoinRequest> for *mut wire_cst_buy_bitcoin_request {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::model::BuyBitcoinRequest {
@@ -198,6 +314,31 @@ impl CstDecode<crate::model::ConnectRequest> for *mut wire_cst_connect_request {
         CstDecode::<crate::model::ConnectRequest>::cst_decode(*wrap).into()
     }
 }
+impl CstDecode<crate::model::DeletePaymentMetadataRequest>
+    for *mut wire_cst_delete_payment_metadata_request
+{
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::DeletePaymentMetadataRequest {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::DeletePaymentMetadataRequest>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<crate::model::ExportPaymentsRequest> for *mut wire_cst_export_payments_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::ExportPaymentsRequest {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::ExportPaymentsRequest>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<crate::model::FetchPaymentProposedFeesRequest>
+    for *mut wire_cst_fetch_payment_proposed_fees_request
+{
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::FetchPaymentProposedFeesRequest {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::FetchPaymentProposedFeesRequest>::cst_decode(*wrap).into()
+    }
+}
 impl CstDecode<i64> for *mut i64 {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> i64 {
@@ -308,6 +449,13 @@ impl CstDecode<crate::model::Payment> for *mut wire_cst_payment {
         CstDecode::<crate::model::Payment>::cst_decode(*wrap).into()
     }
 }
+impl CstDecode<crate::model::PaymentLookup> for *mut wire_cst_payment_lookup {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PaymentLookup {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::PaymentLookup>::cst_decode(*wrap).into()
+    }
+}
 impl CstDecode<crate::model::PrepareBuyBitcoinRequest>
     for *mut wire_cst_prepare_buy_bitcoin_request
 {
@@ -360,6 +508,24 @@ impl CstDecode<crate::model::PrepareRefundRequest> for *mut wire_cst_prepare_ref
         CstDecode::<crate::model::PrepareRefundRequest>::cst_decode(*wrap).into()
     }
 }
+impl CstDecode<crate::model::PrepareSendLiquidRequest>
+    for *mut wire_cst_prepare_send_liquid_request
+{
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PrepareSendLiquidRequest {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::PrepareSendLiquidRequest>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<crate::model::PrepareSendLiquidResponse>
+    for *mut wire_cst_prepare_send_liquid_response
+{
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PrepareSendLiquidResponse {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::PrepareSendLiquidResponse>::cst_decode(*wrap).into()
+    }
+}
 impl CstDecode<crate::model::PrepareSendRequest> for *mut wire_cst_prepare_send_request {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::model::PrepareSendRequest {
@@ -374,6 +540,13 @@ impl CstDecode<crate::model::PrepareSendResponse> for *mut wire_cst_prepare_send
         CstDecode::<crate::model::PrepareSendResponse>::cst_decode(*wrap).into()
     }
 }
+impl CstDecode<crate::model::ReceiveLiquidRequest> for *mut wire_cst_receive_liquid_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::ReceiveLiquidRequest {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::ReceiveLiquidRequest>::cst_decode(*wrap).into()
+    }
+}
 impl CstDecode<crate::model::ReceivePaymentRequest> for *mut wire_cst_receive_payment_request {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::model::ReceivePaymentRequest {
@@ -402,6 +575,22 @@ impl CstDecode<crate::model::SdkEvent> for *mut wire_cst_sdk_event {
         CstDecode::<crate::model::SdkEvent>::cst_decode(*wrap).into()
     }
 }
+impl CstDecode<crate::model::SetPaymentMetadataRequest>
+    for *mut wire_cst_set_payment_metadata_request
+{
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::SetPaymentMetadataRequest {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::SetPaymentMetadataRequest>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<crate::model::SignerResult> for *mut wire_cst_signer_result {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::SignerResult {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::SignerResult>::cst_decode(*wrap).into()
+    }
+}
 impl CstDecode<crate::bindings::SuccessActionProcessed> for *mut wire_cst_success_action_processed {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::bindings::SuccessActionProcessed {
@@ -435,6 +624,26 @@ impl CstDecode<crate::bindings::UrlSuccessActionData> for *mut wire_cst_url_succ
         CstDecode::<crate::bindings::UrlSuccessActionData>::cst_decode(*wrap).into()
     }
 }
+impl CstDecode<crate::model::BumpRefundFeeRequest> for wire_cst_bump_refund_fee_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::BumpRefundFeeRequest {
+        crate::model::BumpRefundFeeRequest {
+            swap_address: self.swap_address.cst_decode(),
+            sat_per_vbyte: self.sat_per_vbyte.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::BumpRefundFeeResponse> for wire_cst_bump_refund_fee_response {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::BumpRefundFeeResponse {
+        crate::model::BumpRefundFeeResponse {
+            refund_tx_id: self.refund_tx_id.cst_decode(),
+            replaced_tx_id: self.replaced_tx_id.cst_decode(),
+            tx_fee_sat: self.tx_fee_sat.cst_decode(),
+            fee_delta_sat: self.fee_delta_sat.cst_decode(),
+        }
+    }
+}
 impl CstDecode<crate::model::BuyBitcoinRequest> for wire_cst_buy_bitcoin_request {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::model::BuyBitcoinRequest {
@@ -451,11 +660,22 @@ impl CstDecode<crate::model::Config> for wire_cst_config {
             liquid_electrum_url: self.liquid_electrum_url.cst_decode(),
             bitcoin_electrum_url: self.bitcoin_electrum_url.cst_decode(),
             mempoolspace_url: self.mempoolspace_url.cst_decode(),
+            liquid_esplora_url: self.liquid_esplora_url.cst_decode(),
+            bitcoin_esplora_url: self.bitcoin_esplora_url.cst_decode(),
+            chain_backend: self.chain_backend.cst_decode(),
+            breez_server_url: self.breez_server_url.cst_decode(),
+            boltz_url: self.boltz_url.cst_decode(),
+            referral_id: self.referral_id.cst_decode(),
             working_dir: self.working_dir.cst_decode(),
             network: self.network.cst_decode(),
             payment_timeout_sec: self.payment_timeout_sec.cst_decode(),
             zero_conf_min_fee_rate_msat: self.zero_conf_min_fee_rate_msat.cst_decode(),
             zero_conf_max_amount_sat: self.zero_conf_max_amount_sat.cst_decode(),
+            asset_metadata: self.asset_metadata.cst_decode(),
+            fiat_currencies: self.fiat_currencies.cst_decode(),
+            proxy_url: self.proxy_url.cst_decode(),
+            auto_refund: self.auto_refund.cst_decode(),
+            amountless_fee_tolerance_sat: self.amountless_fee_tolerance_sat.cst_decode(),
         }
     }
 }
@@ -465,6 +685,7 @@ impl CstDecode<crate::model::ConnectRequest> for wire_cst_connect_request {
         crate::model::ConnectRequest {
             mnemonic: self.mnemonic.cst_decode(),
             config: self.config.cst_decode(),
+            watch_only_descriptor: self.watch_only_descriptor.cst_decode(),
         }
     }
 }
@@ -482,6 +703,52 @@ impl CstDecode<crate::bindings::CurrencyInfo> for wire_cst_currency_info {
         }
     }
 }
+impl CstDecode<crate::model::DeletePaymentMetadataRequest>
+    for wire_cst_delete_payment_metadata_request
+{
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::DeletePaymentMetadataRequest {
+        crate::model::DeletePaymentMetadataRequest {
+            payment_id: self.payment_id.cst_decode(),
+            key: self.key.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::ExportPaymentsRequest> for wire_cst_export_payments_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::ExportPaymentsRequest {
+        crate::model::ExportPaymentsRequest {
+            export_path: self.export_path.cst_decode(),
+            format: self.format.cst_decode(),
+            fiat_currency: self.fiat_currency.cst_decode(),
+            from_timestamp: self.from_timestamp.cst_decode(),
+            to_timestamp: self.to_timestamp.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::FetchPaymentProposedFeesRequest>
+    for wire_cst_fetch_payment_proposed_fees_request
+{
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::FetchPaymentProposedFeesRequest {
+        crate::model::FetchPaymentProposedFeesRequest {
+            swap_id: self.swap_id.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::FetchPaymentProposedFeesResponse>
+    for wire_cst_fetch_payment_proposed_fees_response
+{
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::FetchPaymentProposedFeesResponse {
+        crate::model::FetchPaymentProposedFeesResponse {
+            swap_id: self.swap_id.cst_decode(),
+            fees_sat: self.fees_sat.cst_decode(),
+            payer_amount_sat: self.payer_amount_sat.cst_decode(),
+            receiver_amount_sat: self.receiver_amount_sat.cst_decode(),
+        }
+    }
+}
 impl CstDecode<crate::bindings::FiatCurrency> for wire_cst_fiat_currency {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::bindings::FiatCurrency {
@@ -499,6 +766,8 @@ impl CstDecode<crate::model::GetInfoResponse> for wire_cst_get_info_response {
             pending_send_sat: self.pending_send_sat.cst_decode(),
             pending_receive_sat: self.pending_receive_sat.cst_decode(),
             pubkey: self.pubkey.cst_decode(),
+            asset_balances: self.asset_balances.cst_decode(),
+            balance_details: self.balance_details.cst_decode(),
         }
     }
 }
@@ -579,6 +848,36 @@ impl CstDecode<crate::model::Limits> for wire_cst_limits {
         }
     }
 }
+impl CstDecode<Vec<String>> for *mut wire_cst_list_String {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> Vec<String> {
+        let vec = unsafe {
+            let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+            flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(CstDecode::cst_decode).collect()
+    }
+}
+impl CstDecode<Vec<crate::model::AssetBalance>> for *mut wire_cst_list_asset_balance {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> Vec<crate::model::AssetBalance> {
+        let vec = unsafe {
+            let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+            flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(CstDecode::cst_decode).collect()
+    }
+}
+impl CstDecode<Vec<crate::model::AssetMetadata>> for *mut wire_cst_list_asset_metadata {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> Vec<crate::model::AssetMetadata> {
+        let vec = unsafe {
+            let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+            flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(CstDecode::cst_decode).collect()
+    }
+}
 impl CstDecode<Vec<crate::bindings::FiatCurrency>> for *mut wire_cst_list_fiat_currency {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> Vec<crate::bindings::FiatCurrency> {
@@ -619,6 +918,58 @@ impl CstDecode<Vec<crate::model::Payment>> for *mut wire_cst_list_payment {
         vec.into_iter().map(CstDecode::cst_decode).collect()
     }
 }
+impl CstDecode<Vec<crate::model::PaymentFiatRate>> for *mut wire_cst_list_payment_fiat_rate {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> Vec<crate::model::PaymentFiatRate> {
+        let vec = unsafe {
+            let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+            flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(CstDecode::cst_decode).collect()
+    }
+}
+impl CstDecode<Vec<crate::model::PaymentKind>> for *mut wire_cst_list_payment_kind {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> Vec<crate::model::PaymentKind> {
+        let vec = unsafe {
+            let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+            flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(CstDecode::cst_decode).collect()
+    }
+}
+impl CstDecode<Vec<crate::model::PaymentMetadata>> for *mut wire_cst_list_payment_metadata {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> Vec<crate::model::PaymentMetadata> {
+        let vec = unsafe {
+            let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+            flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(CstDecode::cst_decode).collect()
+    }
+}
+impl CstDecode<Vec<crate::model::PaymentMetadataFilter>>
+    for *mut wire_cst_list_payment_metadata_filter
+{
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> Vec<crate::model::PaymentMetadataFilter> {
+        let vec = unsafe {
+            let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+            flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(CstDecode::cst_decode).collect()
+    }
+}
+impl CstDecode<Vec<crate::model::PaymentState>> for *mut wire_cst_list_payment_state {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> Vec<crate::model::PaymentState> {
+        let vec = unsafe {
+            let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+            flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(CstDecode::cst_decode).collect()
+    }
+}
 impl CstDecode<Vec<crate::model::PaymentType>> for *mut wire_cst_list_payment_type {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> Vec<crate::model::PaymentType> {
@@ -638,6 +989,23 @@ impl CstDecode<crate::model::ListPaymentsRequest> for wire_cst_list_payments_req
             to_timestamp: self.to_timestamp.cst_decode(),
             offset: self.offset.cst_decode(),
             limit: self.limit.cst_decode(),
+            metadata_filters: self.metadata_filters.cst_decode(),
+            states: self.states.cst_decode(),
+            kinds: self.kinds.cst_decode(),
+            from_amount_sat: self.from_amount_sat.cst_decode(),
+            to_amount_sat: self.to_amount_sat.cst_decode(),
+            asset_id: self.asset_id.cst_decode(),
+            search: self.search.cst_decode(),
+            cursor: self.cursor.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::ListPaymentsResponse> for wire_cst_list_payments_response {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::ListPaymentsResponse {
+        crate::model::ListPaymentsResponse {
+            payments: self.payments.cst_decode(),
+            next_cursor: self.next_cursor.cst_decode(),
         }
     }
 }
@@ -709,6 +1077,19 @@ impl CstDecode<crate::bindings::LNInvoice> for wire_cst_ln_invoice {
         }
     }
 }
+impl CstDecode<crate::model::LNOffer> for wire_cst_ln_offer {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::LNOffer {
+        crate::model::LNOffer {
+            offer: self.offer.cst_decode(),
+            chains: self.chains.cst_decode(),
+            amount_sat: self.amount_sat.cst_decode(),
+            description: self.description.cst_decode(),
+            absolute_expiry: self.absolute_expiry.cst_decode(),
+            issuer: self.issuer.cst_decode(),
+        }
+    }
+}
 impl CstDecode<crate::bindings::duplicates::LnUrlAuthError> for wire_cst_ln_url_auth_error {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::bindings::duplicates::LnUrlAuthError {
@@ -1077,6 +1458,7 @@ impl CstDecode<crate::model::PayOnchainRequest> for wire_cst_pay_onchain_request
         crate::model::PayOnchainRequest {
             address: self.address.cst_decode(),
             prepare_res: self.prepare_res.cst_decode(),
+            idempotency_key: self.idempotency_key.cst_decode(),
         }
     }
 }
@@ -1092,10 +1474,14 @@ impl CstDecode<crate::model::Payment> for wire_cst_payment {
             preimage: self.preimage.cst_decode(),
             bolt11: self.bolt11.cst_decode(),
             description: self.description.cst_decode(),
+            destination: self.destination.cst_decode(),
+            asset_id: self.asset_id.cst_decode(),
             refund_tx_id: self.refund_tx_id.cst_decode(),
             refund_tx_amount_sat: self.refund_tx_amount_sat.cst_decode(),
             payment_type: self.payment_type.cst_decode(),
             status: self.status.cst_decode(),
+            metadata: self.metadata.cst_decode(),
+            fiat_rates: self.fiat_rates.cst_decode(),
         }
     }
 }
@@ -1108,94 +1494,175 @@ impl CstDecode<crate::error::PaymentError> for wire_cst_payment_error {
             2 => crate::error::PaymentError::PaymentInProgress,
             3 => crate::error::PaymentError::AmountOutOfRange,
             4 => {
+                let ans = unsafe { self.kind.AmountMissing };
+                crate::error::PaymentError::AmountMissing {
+                    err: ans.err.cst_decode(),
+                }
+            }
+            5 => {
                 let ans = unsafe { self.kind.Generic };
                 crate::error::PaymentError::Generic {
                     err: ans.err.cst_decode(),
                 }
             }
-            5 => crate::error::PaymentError::InvalidOrExpiredFees,
-            6 => crate::error::PaymentError::InsufficientFunds,
-            7 => {
+            6 => crate::error::PaymentError::InvalidOrExpiredFees,
+            7 => crate::error::PaymentError::InsufficientFunds,
+            8 => {
                 let ans = unsafe { self.kind.InvalidInvoice };
                 crate::error::PaymentError::InvalidInvoice {
                     err: ans.err.cst_decode(),
                 }
             }
-            8 => crate::error::PaymentError::InvalidPreimage,
             9 => {
+                let ans = unsafe { self.kind.InvalidDestination };
+                crate::error::PaymentError::InvalidDestination {
+                    err: ans.err.cst_decode(),
+                }
+            }
+            10 => crate::error::PaymentError::InvalidPreimage,
+            11 => {
                 let ans = unsafe { self.kind.LwkError };
                 crate::error::PaymentError::LwkError {
                     err: ans.err.cst_decode(),
                 }
             }
-            10 => crate::error::PaymentError::PairsNotFound,
-            11 => crate::error::PaymentError::PaymentTimeout,
-            12 => crate::error::PaymentError::PersistError,
-            13 => {
+            12 => crate::error::PaymentError::PairsNotFound,
+            13 => crate::error::PaymentError::PaymentTimeout,
+            14 => crate::error::PaymentError::PersistError,
+            15 => {
                 let ans = unsafe { self.kind.ReceiveError };
                 crate::error::PaymentError::ReceiveError {
                     err: ans.err.cst_decode(),
                 }
             }
-            14 => {
+            16 => {
                 let ans = unsafe { self.kind.Refunded };
                 crate::error::PaymentError::Refunded {
                     err: ans.err.cst_decode(),
                     refund_tx_id: ans.refund_tx_id.cst_decode(),
                 }
             }
-            15 => crate::error::PaymentError::SelfTransferNotSupported,
-            16 => {
+            17 => crate::error::PaymentError::SelfTransferNotSupported,
+            18 => {
                 let ans = unsafe { self.kind.SendError };
                 crate::error::PaymentError::SendError {
                     err: ans.err.cst_decode(),
                 }
             }
-            17 => {
+            19 => {
                 let ans = unsafe { self.kind.SignerError };
                 crate::error::PaymentError::SignerError {
                     err: ans.err.cst_decode(),
                 }
             }
+            20 => crate::error::PaymentError::WatchOnly,
             _ => unreachable!(),
         }
     }
 }
-impl CstDecode<crate::model::PrepareBuyBitcoinRequest> for wire_cst_prepare_buy_bitcoin_request {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> crate::model::PrepareBuyBitcoinRequest {
-        crate::model::PrepareBuyBitcoinRequest {
-            provider: self.provider.cst_decode(),
-            amount_sat: self.amount_sat.cst_decode(),
-        }
-    }
-}
-impl CstDecode<crate::model::PrepareBuyBitcoinResponse> for wire_cst_prepare_buy_bitcoin_response {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> crate::model::PrepareBuyBitcoinResponse {
-        crate::model::PrepareBuyBitcoinResponse {
-            provider: self.provider.cst_decode(),
-            amount_sat: self.amount_sat.cst_decode(),
-            fees_sat: self.fees_sat.cst_decode(),
-        }
-    }
-}
-impl CstDecode<crate::model::PreparePayOnchainRequest> for wire_cst_prepare_pay_onchain_request {
+impl CstDecode<crate::model::PaymentFiatRate> for wire_cst_payment_fiat_rate {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> crate::model::PreparePayOnchainRequest {
-        crate::model::PreparePayOnchainRequest {
-            receiver_amount_sat: self.receiver_amount_sat.cst_decode(),
-            sat_per_vbyte: self.sat_per_vbyte.cst_decode(),
+    fn cst_decode(self) -> crate::model::PaymentFiatRate {
+        crate::model::PaymentFiatRate {
+            coin: self.coin.cst_decode(),
+            value: self.value.cst_decode(),
+            created_at: self.created_at.cst_decode(),
         }
     }
 }
-impl CstDecode<crate::model::PreparePayOnchainResponse> for wire_cst_prepare_pay_onchain_response {
+impl CstDecode<crate::model::PaymentLookup> for wire_cst_payment_lookup {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> crate::model::PreparePayOnchainResponse {
-        crate::model::PreparePayOnchainResponse {
-            receiver_amount_sat: self.receiver_amount_sat.cst_decode(),
+    fn cst_decode(self) -> crate::model::PaymentLookup {
+        match self.tag {
+            0 => {
+                let ans = unsafe { self.kind.SwapId };
+                crate::model::PaymentLookup::SwapId {
+                    swap_id: ans.swap_id.cst_decode(),
+                }
+            }
+            1 => {
+                let ans = unsafe { self.kind.Invoice };
+                crate::model::PaymentLookup::Invoice {
+                    invoice: ans.invoice.cst_decode(),
+                }
+            }
+            2 => {
+                let ans = unsafe { self.kind.PaymentHash };
+                crate::model::PaymentLookup::PaymentHash {
+                    payment_hash: ans.payment_hash.cst_decode(),
+                }
+            }
+            3 => {
+                let ans = unsafe { self.kind.TxId };
+                crate::model::PaymentLookup::TxId {
+                    tx_id: ans.tx_id.cst_decode(),
+                }
+            }
+            4 => {
+                let ans = unsafe { self.kind.Destination };
+                crate::model::PaymentLookup::Destination {
+                    address: ans.address.cst_decode(),
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl CstDecode<crate::model::PaymentMetadata> for wire_cst_payment_metadata {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PaymentMetadata {
+        crate::model::PaymentMetadata {
+            key: self.key.cst_decode(),
+            value: self.value.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::PaymentMetadataFilter> for wire_cst_payment_metadata_filter {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PaymentMetadataFilter {
+        crate::model::PaymentMetadataFilter {
+            key: self.key.cst_decode(),
+            value: self.value.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::PrepareBuyBitcoinRequest> for wire_cst_prepare_buy_bitcoin_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PrepareBuyBitcoinRequest {
+        crate::model::PrepareBuyBitcoinRequest {
+            provider: self.provider.cst_decode(),
+            amount_sat: self.amount_sat.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::PrepareBuyBitcoinResponse> for wire_cst_prepare_buy_bitcoin_response {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PrepareBuyBitcoinResponse {
+        crate::model::PrepareBuyBitcoinResponse {
+            provider: self.provider.cst_decode(),
+            amount_sat: self.amount_sat.cst_decode(),
+            fees_sat: self.fees_sat.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::PreparePayOnchainRequest> for wire_cst_prepare_pay_onchain_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PreparePayOnchainRequest {
+        crate::model::PreparePayOnchainRequest {
+            receiver_amount_sat: self.receiver_amount_sat.cst_decode(),
+            sat_per_vbyte: self.sat_per_vbyte.cst_decode(),
+            drain: self.drain.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::PreparePayOnchainResponse> for wire_cst_prepare_pay_onchain_response {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PreparePayOnchainResponse {
+        crate::model::PreparePayOnchainResponse {
+            receiver_amount_sat: self.receiver_amount_sat.cst_decode(),
             claim_fees_sat: self.claim_fees_sat.cst_decode(),
             total_fees_sat: self.total_fees_sat.cst_decode(),
+            drain: self.drain.cst_decode(),
         }
     }
 }
@@ -1217,6 +1684,7 @@ impl CstDecode<crate::model::PrepareReceiveOnchainResponse>
         crate::model::PrepareReceiveOnchainResponse {
             payer_amount_sat: self.payer_amount_sat.cst_decode(),
             fees_sat: self.fees_sat.cst_decode(),
+            swapper_feerate: self.swapper_feerate.cst_decode(),
         }
     }
 }
@@ -1261,11 +1729,38 @@ impl CstDecode<crate::model::PrepareRefundResponse> for wire_cst_prepare_refund_
         }
     }
 }
+impl CstDecode<crate::model::PrepareSendLiquidRequest> for wire_cst_prepare_send_liquid_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PrepareSendLiquidRequest {
+        crate::model::PrepareSendLiquidRequest {
+            destination: self.destination.cst_decode(),
+            amount_sat: self.amount_sat.cst_decode(),
+            drain: self.drain.cst_decode(),
+            asset_id: self.asset_id.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::PrepareSendLiquidResponse> for wire_cst_prepare_send_liquid_response {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PrepareSendLiquidResponse {
+        crate::model::PrepareSendLiquidResponse {
+            address: self.address.cst_decode(),
+            receiver_amount_sat: self.receiver_amount_sat.cst_decode(),
+            fees_sat: self.fees_sat.cst_decode(),
+            description: self.description.cst_decode(),
+            drain: self.drain.cst_decode(),
+            asset_id: self.asset_id.cst_decode(),
+        }
+    }
+}
 impl CstDecode<crate::model::PrepareSendRequest> for wire_cst_prepare_send_request {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::model::PrepareSendRequest {
         crate::model::PrepareSendRequest {
             invoice: self.invoice.cst_decode(),
+            amount_sat: self.amount_sat.cst_decode(),
+            idempotency_key: self.idempotency_key.cst_decode(),
+            payer_note: self.payer_note.cst_decode(),
         }
     }
 }
@@ -1274,7 +1769,10 @@ impl CstDecode<crate::model::PrepareSendResponse> for wire_cst_prepare_send_resp
     fn cst_decode(self) -> crate::model::PrepareSendResponse {
         crate::model::PrepareSendResponse {
             invoice: self.invoice.cst_decode(),
+            offer: self.offer.cst_decode(),
+            receiver_amount_sat: self.receiver_amount_sat.cst_decode(),
             fees_sat: self.fees_sat.cst_decode(),
+            idempotency_key: self.idempotency_key.cst_decode(),
         }
     }
 }
@@ -1287,6 +1785,25 @@ impl CstDecode<crate::bindings::Rate> for wire_cst_rate {
         }
     }
 }
+impl CstDecode<crate::model::ReceiveLiquidRequest> for wire_cst_receive_liquid_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::ReceiveLiquidRequest {
+        crate::model::ReceiveLiquidRequest {
+            amount_sat: self.amount_sat.cst_decode(),
+            description: self.description.cst_decode(),
+            asset_id: self.asset_id.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::ReceiveLiquidResponse> for wire_cst_receive_liquid_response {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::ReceiveLiquidResponse {
+        crate::model::ReceiveLiquidResponse {
+            address: self.address.cst_decode(),
+            bip21: self.bip21.cst_decode(),
+        }
+    }
+}
 impl CstDecode<crate::model::ReceiveOnchainResponse> for wire_cst_receive_onchain_response {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::model::ReceiveOnchainResponse {
@@ -1326,6 +1843,26 @@ impl CstDecode<crate::model::RecommendedFees> for wire_cst_recommended_fees {
         }
     }
 }
+impl CstDecode<crate::model::RefundDestination> for wire_cst_refund_destination {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::RefundDestination {
+        match self.tag {
+            0 => {
+                let ans = unsafe { self.kind.Address };
+                crate::model::RefundDestination::Address {
+                    address: ans.address.cst_decode(),
+                }
+            }
+            1 => {
+                let ans = unsafe { self.kind.Xpub };
+                crate::model::RefundDestination::Xpub {
+                    xpub: ans.xpub.cst_decode(),
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+}
 impl CstDecode<crate::model::RefundRequest> for wire_cst_refund_request {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::model::RefundRequest {
@@ -1446,7 +1983,19 @@ impl CstDecode<crate::model::SdkEvent> for wire_cst_sdk_event {
                     details: ans.details.cst_decode(),
                 }
             }
-            6 => crate::model::SdkEvent::Synced,
+            6 => {
+                let ans = unsafe { self.kind.SwapAutoRefunded };
+                crate::model::SdkEvent::SwapAutoRefunded {
+                    details: ans.details.cst_decode(),
+                }
+            }
+            7 => {
+                let ans = unsafe { self.kind.SwapWaitingFeeAcceptance };
+                crate::model::SdkEvent::SwapWaitingFeeAcceptance {
+                    swap_id: ans.swap_id.cst_decode(),
+                }
+            }
+            8 => crate::model::SdkEvent::Synced,
             _ => unreachable!(),
         }
     }
@@ -1459,6 +2008,87 @@ impl CstDecode<crate::model::SendPaymentResponse> for wire_cst_send_payment_resp
         }
     }
 }
+impl CstDecode<crate::model::SetPaymentMetadataRequest> for wire_cst_set_payment_metadata_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::SetPaymentMetadataRequest {
+        crate::model::SetPaymentMetadataRequest {
+            payment_id: self.payment_id.cst_decode(),
+            key: self.key.cst_decode(),
+            value: self.value.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::SignerMethod> for wire_cst_signer_method {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::SignerMethod {
+        match self.tag {
+            0 => crate::model::SignerMethod::Xpub,
+            1 => {
+                let ans = unsafe { self.kind.DeriveXpub };
+                crate::model::SignerMethod::DeriveXpub {
+                    derivation_path: ans.derivation_path.cst_decode(),
+                }
+            }
+            2 => crate::model::SignerMethod::Slip77MasterBlindingKey,
+            3 => {
+                let ans = unsafe { self.kind.SignPset };
+                crate::model::SignerMethod::SignPset {
+                    pset: ans.pset.cst_decode(),
+                }
+            }
+            4 => {
+                let ans = unsafe { self.kind.SignEcdsa };
+                crate::model::SignerMethod::SignEcdsa {
+                    msg: ans.msg.cst_decode(),
+                    derivation_path: ans.derivation_path.cst_decode(),
+                }
+            }
+            5 => {
+                let ans = unsafe { self.kind.HmacSha256 };
+                crate::model::SignerMethod::HmacSha256 {
+                    msg: ans.msg.cst_decode(),
+                    derivation_path: ans.derivation_path.cst_decode(),
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl CstDecode<crate::model::SignerRequest> for wire_cst_signer_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::SignerRequest {
+        crate::model::SignerRequest {
+            id: self.id.cst_decode(),
+            method: self.method.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::SignerResult> for wire_cst_signer_result {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::SignerResult {
+        match self.tag {
+            0 => {
+                let ans = unsafe { self.kind.String };
+                crate::model::SignerResult::String {
+                    value: ans.value.cst_decode(),
+                }
+            }
+            1 => {
+                let ans = unsafe { self.kind.Bytes };
+                crate::model::SignerResult::Bytes {
+                    value: ans.value.cst_decode(),
+                }
+            }
+            2 => {
+                let ans = unsafe { self.kind.Error };
+                crate::model::SignerResult::Error {
+                    err: ans.err.cst_decode(),
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+}
 impl CstDecode<crate::bindings::SuccessActionProcessed> for wire_cst_success_action_processed {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::bindings::SuccessActionProcessed {
@@ -1506,6 +2136,18 @@ impl CstDecode<crate::bindings::UrlSuccessActionData> for wire_cst_url_success_a
         }
     }
 }
+impl NewWithNullPtr for wire_cst_accept_payment_proposed_fees_request {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            response: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_accept_payment_proposed_fees_request {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
 impl NewWithNullPtr for wire_cst_aes_success_action_data_decrypted {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -1532,6 +2174,64 @@ impl Default for wire_cst_aes_success_action_data_result {
         Self::new_with_null_ptr()
     }
 }
+impl NewWithNullPtr for wire_cst_asset_balance {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            asset_id: core::ptr::null_mut(),
+            balance: Default::default(),
+            pending_send: Default::default(),
+            pending_receive: Default::default(),
+            asset_metadata: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_asset_balance {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_asset_metadata {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            asset_id: core::ptr::null_mut(),
+            name: core::ptr::null_mut(),
+            ticker: core::ptr::null_mut(),
+            precision: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_asset_metadata {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_auto_refund_details {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            swap_address: core::ptr::null_mut(),
+            refund_address: core::ptr::null_mut(),
+            refund_tx_id: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_auto_refund_details {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_auto_refund_policy {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            destination: Default::default(),
+            fee_rate: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_auto_refund_policy {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
 impl NewWithNullPtr for wire_cst_backup_request {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -1544,6 +2244,25 @@ impl Default for wire_cst_backup_request {
         Self::new_with_null_ptr()
     }
 }
+impl NewWithNullPtr for wire_cst_balance_details {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            confirmed_sat: Default::default(),
+            unconfirmed_receive_sat: Default::default(),
+            unconfirmed_send_sat: Default::default(),
+            pending_send_swaps_sat: Default::default(),
+            pending_receive_swaps_sat: Default::default(),
+            pending_refund_sat: Default::default(),
+            refundable_btc_sat: Default::default(),
+            pending_btc_refund_sat: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_balance_details {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
 impl NewWithNullPtr for wire_cst_binding_event_listener {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -1572,6 +2291,34 @@ impl Default for wire_cst_bitcoin_address_data {
         Self::new_with_null_ptr()
     }
 }
+impl NewWithNullPtr for wire_cst_bump_refund_fee_request {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            swap_address: core::ptr::null_mut(),
+            sat_per_vbyte: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_bump_refund_fee_request {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_bump_refund_fee_response {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            refund_tx_id: core::ptr::null_mut(),
+            replaced_tx_id: core::ptr::null_mut(),
+            tx_fee_sat: Default::default(),
+            fee_delta_sat: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_bump_refund_fee_response {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
 impl NewWithNullPtr for wire_cst_buy_bitcoin_request {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -1591,11 +2338,22 @@ impl NewWithNullPtr for wire_cst_config {
             liquid_electrum_url: core::ptr::null_mut(),
             bitcoin_electrum_url: core::ptr::null_mut(),
             mempoolspace_url: core::ptr::null_mut(),
+            liquid_esplora_url: core::ptr::null_mut(),
+            bitcoin_esplora_url: core::ptr::null_mut(),
+            chain_backend: Default::default(),
+            breez_server_url: core::ptr::null_mut(),
+            boltz_url: core::ptr::null_mut(),
+            referral_id: core::ptr::null_mut(),
             working_dir: core::ptr::null_mut(),
             network: Default::default(),
             payment_timeout_sec: Default::default(),
             zero_conf_min_fee_rate_msat: Default::default(),
             zero_conf_max_amount_sat: core::ptr::null_mut(),
+            asset_metadata: core::ptr::null_mut(),
+            fiat_currencies: core::ptr::null_mut(),
+            proxy_url: core::ptr::null_mut(),
+            auto_refund: core::ptr::null_mut(),
+            amountless_fee_tolerance_sat: core::ptr::null_mut(),
         }
     }
 }
@@ -1609,6 +2367,7 @@ impl NewWithNullPtr for wire_cst_connect_request {
         Self {
             mnemonic: core::ptr::null_mut(),
             config: Default::default(),
+            watch_only_descriptor: core::ptr::null_mut(),
         }
     }
 }
@@ -1635,6 +2394,62 @@ impl Default for wire_cst_currency_info {
         Self::new_with_null_ptr()
     }
 }
+impl NewWithNullPtr for wire_cst_delete_payment_metadata_request {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            payment_id: core::ptr::null_mut(),
+            key: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_delete_payment_metadata_request {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_export_payments_request {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            export_path: core::ptr::null_mut(),
+            format: Default::default(),
+            fiat_currency: core::ptr::null_mut(),
+            from_timestamp: core::ptr::null_mut(),
+            to_timestamp: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_export_payments_request {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_fetch_payment_proposed_fees_request {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            swap_id: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_fetch_payment_proposed_fees_request {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_fetch_payment_proposed_fees_response {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            swap_id: core::ptr::null_mut(),
+            fees_sat: Default::default(),
+            payer_amount_sat: Default::default(),
+            receiver_amount_sat: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_fetch_payment_proposed_fees_response {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
 impl NewWithNullPtr for wire_cst_fiat_currency {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -1655,6 +2470,8 @@ impl NewWithNullPtr for wire_cst_get_info_response {
             pending_send_sat: Default::default(),
             pending_receive_sat: Default::default(),
             pubkey: core::ptr::null_mut(),
+            asset_balances: core::ptr::null_mut(),
+            balance_details: Default::default(),
         }
     }
 }
@@ -1711,6 +2528,14 @@ impl NewWithNullPtr for wire_cst_list_payments_request {
             to_timestamp: core::ptr::null_mut(),
             offset: core::ptr::null_mut(),
             limit: core::ptr::null_mut(),
+            metadata_filters: core::ptr::null_mut(),
+            states: core::ptr::null_mut(),
+            kinds: core::ptr::null_mut(),
+            from_amount_sat: core::ptr::null_mut(),
+            to_amount_sat: core::ptr::null_mut(),
+            asset_id: core::ptr::null_mut(),
+            search: core::ptr::null_mut(),
+            cursor: core::ptr::null_mut(),
         }
     }
 }
@@ -1719,6 +2544,19 @@ impl Default for wire_cst_list_payments_request {
         Self::new_with_null_ptr()
     }
 }
+impl NewWithNullPtr for wire_cst_list_payments_response {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            payments: core::ptr::null_mut(),
+            next_cursor: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_list_payments_response {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
 impl NewWithNullPtr for wire_cst_ln_invoice {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -1742,6 +2580,23 @@ impl Default for wire_cst_ln_invoice {
         Self::new_with_null_ptr()
     }
 }
+impl NewWithNullPtr for wire_cst_ln_offer {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            offer: core::ptr::null_mut(),
+            chains: core::ptr::null_mut(),
+            amount_sat: core::ptr::null_mut(),
+            description: core::ptr::null_mut(),
+            absolute_expiry: core::ptr::null_mut(),
+            issuer: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_ln_offer {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
 impl NewWithNullPtr for wire_cst_ln_url_auth_error {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -2021,6 +2876,7 @@ impl NewWithNullPtr for wire_cst_pay_onchain_request {
         Self {
             address: core::ptr::null_mut(),
             prepare_res: Default::default(),
+            idempotency_key: core::ptr::null_mut(),
         }
     }
 }
@@ -2040,10 +2896,14 @@ impl NewWithNullPtr for wire_cst_payment {
             preimage: core::ptr::null_mut(),
             bolt11: core::ptr::null_mut(),
             description: core::ptr::null_mut(),
+            destination: core::ptr::null_mut(),
+            asset_id: core::ptr::null_mut(),
             refund_tx_id: core::ptr::null_mut(),
             refund_tx_amount_sat: core::ptr::null_mut(),
             payment_type: Default::default(),
             status: Default::default(),
+            metadata: core::ptr::null_mut(),
+            fiat_rates: core::ptr::null_mut(),
         }
     }
 }
@@ -2065,6 +2925,59 @@ impl Default for wire_cst_payment_error {
         Self::new_with_null_ptr()
     }
 }
+impl NewWithNullPtr for wire_cst_payment_fiat_rate {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            coin: core::ptr::null_mut(),
+            value: Default::default(),
+            created_at: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_payment_fiat_rate {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_payment_lookup {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            tag: -1,
+            kind: PaymentLookupKind { nil__: () },
+        }
+    }
+}
+impl Default for wire_cst_payment_lookup {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_payment_metadata {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            key: core::ptr::null_mut(),
+            value: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_payment_metadata {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_payment_metadata_filter {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            key: core::ptr::null_mut(),
+            value: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_payment_metadata_filter {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
 impl NewWithNullPtr for wire_cst_prepare_buy_bitcoin_request {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -2097,6 +3010,7 @@ impl NewWithNullPtr for wire_cst_prepare_pay_onchain_request {
         Self {
             receiver_amount_sat: Default::default(),
             sat_per_vbyte: core::ptr::null_mut(),
+            drain: core::ptr::null_mut(),
         }
     }
 }
@@ -2111,6 +3025,7 @@ impl NewWithNullPtr for wire_cst_prepare_pay_onchain_response {
             receiver_amount_sat: Default::default(),
             claim_fees_sat: Default::default(),
             total_fees_sat: Default::default(),
+            drain: Default::default(),
         }
     }
 }
@@ -2122,7 +3037,7 @@ impl Default for wire_cst_prepare_pay_onchain_response {
 impl NewWithNullPtr for wire_cst_prepare_receive_onchain_request {
     fn new_with_null_ptr() -> Self {
         Self {
-            payer_amount_sat: Default::default(),
+            payer_amount_sat: core::ptr::null_mut(),
         }
     }
 }
@@ -2134,8 +3049,9 @@ impl Default for wire_cst_prepare_receive_onchain_request {
 impl NewWithNullPtr for wire_cst_prepare_receive_onchain_response {
     fn new_with_null_ptr() -> Self {
         Self {
-            payer_amount_sat: Default::default(),
+            payer_amount_sat: core::ptr::null_mut(),
             fees_sat: Default::default(),
+            swapper_feerate: Default::default(),
         }
     }
 }
@@ -2197,10 +3113,45 @@ impl Default for wire_cst_prepare_refund_response {
         Self::new_with_null_ptr()
     }
 }
+impl NewWithNullPtr for wire_cst_prepare_send_liquid_request {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            destination: core::ptr::null_mut(),
+            amount_sat: core::ptr::null_mut(),
+            drain: core::ptr::null_mut(),
+            asset_id: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_prepare_send_liquid_request {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_prepare_send_liquid_response {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            address: core::ptr::null_mut(),
+            receiver_amount_sat: Default::default(),
+            fees_sat: Default::default(),
+            description: core::ptr::null_mut(),
+            drain: Default::default(),
+            asset_id: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_prepare_send_liquid_response {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
 impl NewWithNullPtr for wire_cst_prepare_send_request {
     fn new_with_null_ptr() -> Self {
         Self {
             invoice: core::ptr::null_mut(),
+            amount_sat: core::ptr::null_mut(),
+            idempotency_key: core::ptr::null_mut(),
+            payer_note: core::ptr::null_mut(),
         }
     }
 }
@@ -2213,7 +3164,10 @@ impl NewWithNullPtr for wire_cst_prepare_send_response {
     fn new_with_null_ptr() -> Self {
         Self {
             invoice: core::ptr::null_mut(),
+            offer: core::ptr::null_mut(),
+            receiver_amount_sat: Default::default(),
             fees_sat: Default::default(),
+            idempotency_key: core::ptr::null_mut(),
         }
     }
 }
@@ -2235,6 +3189,33 @@ impl Default for wire_cst_rate {
         Self::new_with_null_ptr()
     }
 }
+impl NewWithNullPtr for wire_cst_receive_liquid_request {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            amount_sat: core::ptr::null_mut(),
+            description: core::ptr::null_mut(),
+            asset_id: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_receive_liquid_request {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_receive_liquid_response {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            address: core::ptr::null_mut(),
+            bip21: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_receive_liquid_response {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
 impl NewWithNullPtr for wire_cst_receive_onchain_response {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -2290,6 +3271,19 @@ impl Default for wire_cst_recommended_fees {
         Self::new_with_null_ptr()
     }
 }
+impl NewWithNullPtr for wire_cst_refund_destination {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            tag: -1,
+            kind: RefundDestinationKind { nil__: () },
+        }
+    }
+}
+impl Default for wire_cst_refund_destination {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
 impl NewWithNullPtr for wire_cst_refund_request {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -2393,19 +3387,72 @@ impl NewWithNullPtr for wire_cst_sdk_event {
         }
     }
 }
-impl Default for wire_cst_sdk_event {
+impl Default for wire_cst_sdk_event {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_send_payment_response {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            payment: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_send_payment_response {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_set_payment_metadata_request {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            payment_id: core::ptr::null_mut(),
+            key: core::ptr::null_mut(),
+            value: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_set_payment_metadata_request {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_signer_method {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            tag: -1,
+            kind: SignerMethodKind { nil__: () },
+        }
+    }
+}
+impl Default for wire_cst_signer_method {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_signer_request {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            id: Default::default(),
+            method: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_signer_request {
     fn default() -> Self {
         Self::new_with_null_ptr()
     }
 }
-impl NewWithNullPtr for wire_cst_send_payment_response {
+impl NewWithNullPtr for wire_cst_signer_result {
     fn new_with_null_ptr() -> Self {
         Self {
-            payment: Default::default(),
+            tag: -1,
+            kind: SignerResultKind { nil__: () },
         }
     }
 }
-impl Default for wire_cst_send_payment_response {
+impl Default for wire_cst_signer_result {
     fn default() -> Self {
         Self::new_with_null_ptr()
     }
@@ -2453,6 +3500,15 @@ impl Default for wire_cst_url_success_action_data {
     }
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_accept_payment_proposed_fees(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_accept_payment_proposed_fees_request,
+) {
+    wire__crate__bindings__BindingLiquidSdk_accept_payment_proposed_fees_impl(port_, that, req)
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_add_event_listener(
     port_: i64,
@@ -2470,6 +3526,15 @@ pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_ba
     wire__crate__bindings__BindingLiquidSdk_backup_impl(that, req)
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_bump_refund_fee(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_bump_refund_fee_request,
+) {
+    wire__crate__bindings__BindingLiquidSdk_bump_refund_fee_impl(port_, that, req)
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_buy_bitcoin(
     port_: i64,
@@ -2479,6 +3544,15 @@ pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_bu
     wire__crate__bindings__BindingLiquidSdk_buy_bitcoin_impl(port_, that, req)
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_delete_payment_metadata(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_delete_payment_metadata_request,
+) {
+    wire__crate__bindings__BindingLiquidSdk_delete_payment_metadata_impl(port_, that, req)
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_disconnect(
     port_: i64,
@@ -2494,6 +3568,15 @@ pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_em
     wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache_impl(that)
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_payments(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_export_payments_request,
+) {
+    wire__crate__bindings__BindingLiquidSdk_export_payments_impl(port_, that, req)
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_fiat_rates(
     port_: i64,
@@ -2518,6 +3601,15 @@ pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fe
     wire__crate__bindings__BindingLiquidSdk_fetch_onchain_limits_impl(port_, that)
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_payment_proposed_fees(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_fetch_payment_proposed_fees_request,
+) {
+    wire__crate__bindings__BindingLiquidSdk_fetch_payment_proposed_fees_impl(port_, that, req)
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_info(
     port_: i64,
@@ -2526,6 +3618,24 @@ pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_ge
     wire__crate__bindings__BindingLiquidSdk_get_info_impl(port_, that)
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_payment_lookup,
+) {
+    wire__crate__bindings__BindingLiquidSdk_get_payment_impl(port_, that, req)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment_metadata(
+    port_: i64,
+    that: usize,
+    payment_id: *mut wire_cst_list_prim_u_8_strict,
+) {
+    wire__crate__bindings__BindingLiquidSdk_get_payment_metadata_impl(port_, that, payment_id)
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_fiat_currencies(
     port_: i64,
@@ -2543,6 +3653,15 @@ pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_li
     wire__crate__bindings__BindingLiquidSdk_list_payments_impl(port_, that, req)
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments_page(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_list_payments_request,
+) {
+    wire__crate__bindings__BindingLiquidSdk_list_payments_page_impl(port_, that, req)
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_refundables(
     port_: i64,
@@ -2632,6 +3751,15 @@ pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_pr
     wire__crate__bindings__BindingLiquidSdk_prepare_refund_impl(port_, that, req)
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_liquid(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_prepare_send_liquid_request,
+) {
+    wire__crate__bindings__BindingLiquidSdk_prepare_send_liquid_impl(port_, that, req)
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_payment(
     port_: i64,
@@ -2641,6 +3769,15 @@ pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_pr
     wire__crate__bindings__BindingLiquidSdk_prepare_send_payment_impl(port_, that, req)
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_liquid(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_receive_liquid_request,
+) {
+    wire__crate__bindings__BindingLiquidSdk_receive_liquid_impl(port_, that, req)
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_onchain(
     port_: i64,
@@ -2692,6 +3829,15 @@ pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_re
     wire__crate__bindings__BindingLiquidSdk_restore_impl(that, req)
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_liquid(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_prepare_send_liquid_response,
+) {
+    wire__crate__bindings__BindingLiquidSdk_send_liquid_impl(port_, that, req)
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_payment(
     port_: i64,
@@ -2701,6 +3847,15 @@ pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_se
     wire__crate__bindings__BindingLiquidSdk_send_payment_impl(port_, that, req)
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_set_payment_metadata(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_set_payment_metadata_request,
+) {
+    wire__crate__bindings__BindingLiquidSdk_set_payment_metadata_impl(port_, that, req)
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sync(
     port_: i64,
@@ -2734,6 +3889,14 @@ pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__connect(
     wire__crate__bindings__connect_impl(port_, req)
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__connect_with_signer(
+    port_: i64,
+    req: *mut wire_cst_connect_request,
+) {
+    wire__crate__bindings__connect_with_signer_impl(port_, req)
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__default_config(
     network: i32,
@@ -2756,6 +3919,29 @@ pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__parse_invoice(
     wire__crate__bindings__parse_invoice_impl(input)
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__parse_offer(
+    input: *mut wire_cst_list_prim_u_8_strict,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    wire__crate__bindings__parse_offer_impl(input)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__respond_to_signer_request(
+    id: u32,
+    result: *mut wire_cst_signer_result,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    wire__crate__bindings__respond_to_signer_request_impl(id, result)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__signer_request_stream(
+    port_: i64,
+    s: *mut wire_cst_list_prim_u_8_strict,
+) {
+    wire__crate__bindings__signer_request_stream_impl(port_, s)
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
     ptr: *const std::ffi::c_void,
@@ -2774,6 +3960,14 @@ pub extern "C" fn frbgen_breez_liquid_rust_arc_decrement_strong_count_RustOpaque
     }
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_accept_payment_proposed_fees_request(
+) -> *mut wire_cst_accept_payment_proposed_fees_request {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_accept_payment_proposed_fees_request::new_with_null_ptr(),
+    )
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_aes_success_action_data_decrypted(
 ) -> *mut wire_cst_aes_success_action_data_decrypted {
@@ -2790,6 +3984,30 @@ pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_aes_success_action_dat
     )
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_asset_metadata(
+) -> *mut wire_cst_asset_metadata {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_asset_metadata::new_with_null_ptr(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_auto_refund_details(
+) -> *mut wire_cst_auto_refund_details {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_auto_refund_details::new_with_null_ptr(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_auto_refund_policy(
+) -> *mut wire_cst_auto_refund_policy {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_auto_refund_policy::new_with_null_ptr(),
+    )
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_backup_request(
 ) -> *mut wire_cst_backup_request {
@@ -2819,6 +4037,14 @@ pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_bool(value: bool) -> *
     flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_bump_refund_fee_request(
+) -> *mut wire_cst_bump_refund_fee_request {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_bump_refund_fee_request::new_with_null_ptr(),
+    )
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_buy_bitcoin_request(
 ) -> *mut wire_cst_buy_bitcoin_request {
@@ -2835,6 +4061,30 @@ pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_connect_request(
     )
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_delete_payment_metadata_request(
+) -> *mut wire_cst_delete_payment_metadata_request {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_delete_payment_metadata_request::new_with_null_ptr(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_export_payments_request(
+) -> *mut wire_cst_export_payments_request {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_export_payments_request::new_with_null_ptr(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_fetch_payment_proposed_fees_request(
+) -> *mut wire_cst_fetch_payment_proposed_fees_request {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_fetch_payment_proposed_fees_request::new_with_null_ptr(),
+    )
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_i_64(value: i64) -> *mut i64 {
     flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
@@ -2946,6 +4196,14 @@ pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_payment() -> *mut wire
     flutter_rust_bridge::for_generated::new_leak_box_ptr(wire_cst_payment::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_payment_lookup(
+) -> *mut wire_cst_payment_lookup {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_payment_lookup::new_with_null_ptr(),
+    )
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_prepare_buy_bitcoin_request(
 ) -> *mut wire_cst_prepare_buy_bitcoin_request {
@@ -2994,6 +4252,22 @@ pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_prepare_refund_request
     )
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_liquid_request(
+) -> *mut wire_cst_prepare_send_liquid_request {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_prepare_send_liquid_request::new_with_null_ptr(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_liquid_response(
+) -> *mut wire_cst_prepare_send_liquid_response {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_prepare_send_liquid_response::new_with_null_ptr(),
+    )
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_request(
 ) -> *mut wire_cst_prepare_send_request {
@@ -3010,6 +4284,14 @@ pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_response(
     )
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_receive_liquid_request(
+) -> *mut wire_cst_receive_liquid_request {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_receive_liquid_request::new_with_null_ptr(),
+    )
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_receive_payment_request(
 ) -> *mut wire_cst_receive_payment_request {
@@ -3039,6 +4321,20 @@ pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_sdk_event() -> *mut wi
     flutter_rust_bridge::for_generated::new_leak_box_ptr(wire_cst_sdk_event::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_set_payment_metadata_request(
+) -> *mut wire_cst_set_payment_metadata_request {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_set_payment_metadata_request::new_with_null_ptr(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_signer_result(
+) -> *mut wire_cst_signer_result {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(wire_cst_signer_result::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_success_action_processed(
 ) -> *mut wire_cst_success_action_processed {
@@ -3071,26 +4367,128 @@ pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_url_success_action_dat
 }
 
 #[no_mangle]
-pub extern "C" fn frbgen_breez_liquid_cst_new_list_fiat_currency(
+pub extern "C" fn frbgen_breez_liquid_cst_new_list_String(len: i32) -> *mut wire_cst_list_String {
+    let wrap = wire_cst_list_String {
+        ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(core::ptr::null_mut(), len),
+        len,
+    };
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_list_asset_balance(
+    len: i32,
+) -> *mut wire_cst_list_asset_balance {
+    let wrap = wire_cst_list_asset_balance {
+        ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+            <wire_cst_asset_balance>::new_with_null_ptr(),
+            len,
+        ),
+        len,
+    };
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_list_asset_metadata(
+    len: i32,
+) -> *mut wire_cst_list_asset_metadata {
+    let wrap = wire_cst_list_asset_metadata {
+        ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+            <wire_cst_asset_metadata>::new_with_null_ptr(),
+            len,
+        ),
+        len,
+    };
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_list_fiat_currency(
+    len: i32,
+) -> *mut wire_cst_list_fiat_currency {
+    let wrap = wire_cst_list_fiat_currency {
+        ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+            <wire_cst_fiat_currency>::new_with_null_ptr(),
+            len,
+        ),
+        len,
+    };
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_list_locale_overrides(
+    len: i32,
+) -> *mut wire_cst_list_locale_overrides {
+    let wrap = wire_cst_list_locale_overrides {
+        ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+            <wire_cst_locale_overrides>::new_with_null_ptr(),
+            len,
+        ),
+        len,
+    };
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_list_localized_name(
+    len: i32,
+) -> *mut wire_cst_list_localized_name {
+    let wrap = wire_cst_list_localized_name {
+        ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+            <wire_cst_localized_name>::new_with_null_ptr(),
+            len,
+        ),
+        len,
+    };
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_list_payment(len: i32) -> *mut wire_cst_list_payment {
+    let wrap = wire_cst_list_payment {
+        ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+            <wire_cst_payment>::new_with_null_ptr(),
+            len,
+        ),
+        len,
+    };
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_list_payment_fiat_rate(
+    len: i32,
+) -> *mut wire_cst_list_payment_fiat_rate {
+    let wrap = wire_cst_list_payment_fiat_rate {
+        ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+            <wire_cst_payment_fiat_rate>::new_with_null_ptr(),
+            len,
+        ),
+        len,
+    };
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_list_payment_kind(
     len: i32,
-) -> *mut wire_cst_list_fiat_currency {
-    let wrap = wire_cst_list_fiat_currency {
-        ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
-            <wire_cst_fiat_currency>::new_with_null_ptr(),
-            len,
-        ),
+) -> *mut wire_cst_list_payment_kind {
+    let wrap = wire_cst_list_payment_kind {
+        ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(Default::default(), len),
         len,
     };
     flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
 }
 
 #[no_mangle]
-pub extern "C" fn frbgen_breez_liquid_cst_new_list_locale_overrides(
+pub extern "C" fn frbgen_breez_liquid_cst_new_list_payment_metadata(
     len: i32,
-) -> *mut wire_cst_list_locale_overrides {
-    let wrap = wire_cst_list_locale_overrides {
+) -> *mut wire_cst_list_payment_metadata {
+    let wrap = wire_cst_list_payment_metadata {
         ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
-            <wire_cst_locale_overrides>::new_with_null_ptr(),
+            <wire_cst_payment_metadata>::new_with_null_ptr(),
             len,
         ),
         len,
@@ -3099,12 +4497,12 @@ pub extern "C" fn frbgen_breez_liquid_cst_new_list_locale_overrides(
 }
 
 #[no_mangle]
-pub extern "C" fn frbgen_breez_liquid_cst_new_list_localized_name(
+pub extern "C" fn frbgen_breez_liquid_cst_new_list_payment_metadata_filter(
     len: i32,
-) -> *mut wire_cst_list_localized_name {
-    let wrap = wire_cst_list_localized_name {
+) -> *mut wire_cst_list_payment_metadata_filter {
+    let wrap = wire_cst_list_payment_metadata_filter {
         ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
-            <wire_cst_localized_name>::new_with_null_ptr(),
+            <wire_cst_payment_metadata_filter>::new_with_null_ptr(),
             len,
         ),
         len,
@@ -3113,12 +4511,11 @@ pub extern "C" fn frbgen_breez_liquid_cst_new_list_localized_name(
 }
 
 #[no_mangle]
-pub extern "C" fn frbgen_breez_liquid_cst_new_list_payment(len: i32) -> *mut wire_cst_list_payment {
-    let wrap = wire_cst_list_payment {
-        ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
-            <wire_cst_payment>::new_with_null_ptr(),
-            len,
-        ),
+pub extern "C" fn frbgen_breez_liquid_cst_new_list_payment_state(
+    len: i32,
+) -> *mut wire_cst_list_payment_state {
+    let wrap = wire_cst_list_payment_state {
+        ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(Default::default(), len),
         len,
     };
     flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
@@ -3200,6 +4597,11 @@ pub extern "C" fn frbgen_breez_liquid_cst_new_list_route_hint_hop(
     flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
 }
 
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_accept_payment_proposed_fees_request {
+    response: wire_cst_fetch_payment_proposed_fees_response,
+}
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub struct wire_cst_aes_success_action_data_decrypted {
@@ -3231,11 +4633,53 @@ pub struct wire_cst_AesSuccessActionDataResult_ErrorStatus {
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct wire_cst_asset_balance {
+    asset_id: *mut wire_cst_list_prim_u_8_strict,
+    balance: u64,
+    pending_send: u64,
+    pending_receive: u64,
+    asset_metadata: *mut wire_cst_asset_metadata,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_asset_metadata {
+    asset_id: *mut wire_cst_list_prim_u_8_strict,
+    name: *mut wire_cst_list_prim_u_8_strict,
+    ticker: *mut wire_cst_list_prim_u_8_strict,
+    precision: u8,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_auto_refund_details {
+    swap_address: *mut wire_cst_list_prim_u_8_strict,
+    refund_address: *mut wire_cst_list_prim_u_8_strict,
+    refund_tx_id: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_auto_refund_policy {
+    destination: wire_cst_refund_destination,
+    fee_rate: i32,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct wire_cst_backup_request {
     backup_path: *mut wire_cst_list_prim_u_8_strict,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct wire_cst_balance_details {
+    confirmed_sat: u64,
+    unconfirmed_receive_sat: u64,
+    unconfirmed_send_sat: u64,
+    pending_send_swaps_sat: u64,
+    pending_receive_swaps_sat: u64,
+    pending_refund_sat: u64,
+    refundable_btc_sat: u64,
+    pending_btc_refund_sat: u64,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct wire_cst_binding_event_listener {
     stream: *mut wire_cst_list_prim_u_8_strict,
 }
@@ -3250,6 +4694,20 @@ pub struct wire_cst_bitcoin_address_data {
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct wire_cst_bump_refund_fee_request {
+    swap_address: *mut wire_cst_list_prim_u_8_strict,
+    sat_per_vbyte: u32,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_bump_refund_fee_response {
+    refund_tx_id: *mut wire_cst_list_prim_u_8_strict,
+    replaced_tx_id: *mut wire_cst_list_prim_u_8_strict,
+    tx_fee_sat: u64,
+    fee_delta_sat: u64,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct wire_cst_buy_bitcoin_request {
     prepare_res: wire_cst_prepare_buy_bitcoin_response,
     redirect_url: *mut wire_cst_list_prim_u_8_strict,
@@ -3260,17 +4718,29 @@ pub struct wire_cst_config {
     liquid_electrum_url: *mut wire_cst_list_prim_u_8_strict,
     bitcoin_electrum_url: *mut wire_cst_list_prim_u_8_strict,
     mempoolspace_url: *mut wire_cst_list_prim_u_8_strict,
+    liquid_esplora_url: *mut wire_cst_list_prim_u_8_strict,
+    bitcoin_esplora_url: *mut wire_cst_list_prim_u_8_strict,
+    chain_backend: i32,
+    breez_server_url: *mut wire_cst_list_prim_u_8_strict,
+    boltz_url: *mut wire_cst_list_prim_u_8_strict,
+    referral_id: *mut wire_cst_list_prim_u_8_strict,
     working_dir: *mut wire_cst_list_prim_u_8_strict,
     network: i32,
     payment_timeout_sec: u64,
     zero_conf_min_fee_rate_msat: u32,
     zero_conf_max_amount_sat: *mut u64,
+    asset_metadata: *mut wire_cst_list_asset_metadata,
+    fiat_currencies: *mut wire_cst_list_String,
+    proxy_url: *mut wire_cst_list_prim_u_8_strict,
+    auto_refund: *mut wire_cst_auto_refund_policy,
+    amountless_fee_tolerance_sat: *mut u64,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub struct wire_cst_connect_request {
     mnemonic: *mut wire_cst_list_prim_u_8_strict,
     config: wire_cst_config,
+    watch_only_descriptor: *mut wire_cst_list_prim_u_8_strict,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -3285,6 +4755,34 @@ pub struct wire_cst_currency_info {
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct wire_cst_delete_payment_metadata_request {
+    payment_id: *mut wire_cst_list_prim_u_8_strict,
+    key: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_export_payments_request {
+    export_path: *mut wire_cst_list_prim_u_8_strict,
+    format: i32,
+    fiat_currency: *mut wire_cst_list_prim_u_8_strict,
+    from_timestamp: *mut i64,
+    to_timestamp: *mut i64,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_fetch_payment_proposed_fees_request {
+    swap_id: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_fetch_payment_proposed_fees_response {
+    swap_id: *mut wire_cst_list_prim_u_8_strict,
+    fees_sat: u64,
+    payer_amount_sat: u64,
+    receiver_amount_sat: u64,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct wire_cst_fiat_currency {
     id: *mut wire_cst_list_prim_u_8_strict,
     info: wire_cst_currency_info,
@@ -3296,6 +4794,8 @@ pub struct wire_cst_get_info_response {
     pending_send_sat: u64,
     pending_receive_sat: u64,
     pubkey: *mut wire_cst_list_prim_u_8_strict,
+    asset_balances: *mut wire_cst_list_asset_balance,
+    balance_details: wire_cst_balance_details,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -3371,6 +4871,24 @@ pub struct wire_cst_limits {
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct wire_cst_list_String {
+    ptr: *mut *mut wire_cst_list_prim_u_8_strict,
+    len: i32,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_list_asset_balance {
+    ptr: *mut wire_cst_asset_balance,
+    len: i32,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_list_asset_metadata {
+    ptr: *mut wire_cst_asset_metadata,
+    len: i32,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct wire_cst_list_fiat_currency {
     ptr: *mut wire_cst_fiat_currency,
     len: i32,
@@ -3395,6 +4913,36 @@ pub struct wire_cst_list_payment {
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct wire_cst_list_payment_fiat_rate {
+    ptr: *mut wire_cst_payment_fiat_rate,
+    len: i32,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_list_payment_kind {
+    ptr: *mut i32,
+    len: i32,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_list_payment_metadata {
+    ptr: *mut wire_cst_payment_metadata,
+    len: i32,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_list_payment_metadata_filter {
+    ptr: *mut wire_cst_payment_metadata_filter,
+    len: i32,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_list_payment_state {
+    ptr: *mut i32,
+    len: i32,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct wire_cst_list_payment_type {
     ptr: *mut i32,
     len: i32,
@@ -3407,6 +4955,20 @@ pub struct wire_cst_list_payments_request {
     to_timestamp: *mut i64,
     offset: *mut u32,
     limit: *mut u32,
+    metadata_filters: *mut wire_cst_list_payment_metadata_filter,
+    states: *mut wire_cst_list_payment_state,
+    kinds: *mut wire_cst_list_payment_kind,
+    from_amount_sat: *mut u64,
+    to_amount_sat: *mut u64,
+    asset_id: *mut wire_cst_list_prim_u_8_strict,
+    search: *mut wire_cst_list_prim_u_8_strict,
+    cursor: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_list_payments_response {
+    payments: *mut wire_cst_list_payment,
+    next_cursor: *mut wire_cst_list_prim_u_8_strict,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -3456,6 +5018,16 @@ pub struct wire_cst_ln_invoice {
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct wire_cst_ln_offer {
+    offer: *mut wire_cst_list_prim_u_8_strict,
+    chains: *mut wire_cst_list_String,
+    amount_sat: *mut u64,
+    description: *mut wire_cst_list_prim_u_8_strict,
+    absolute_expiry: *mut u64,
+    issuer: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct wire_cst_ln_url_auth_error {
     tag: i32,
     kind: LnUrlAuthErrorKind,
@@ -3785,6 +5357,7 @@ pub struct wire_cst_onchain_payment_limits_response {
 pub struct wire_cst_pay_onchain_request {
     address: *mut wire_cst_list_prim_u_8_strict,
     prepare_res: wire_cst_prepare_pay_onchain_response,
+    idempotency_key: *mut wire_cst_list_prim_u_8_strict,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -3797,10 +5370,14 @@ pub struct wire_cst_payment {
     preimage: *mut wire_cst_list_prim_u_8_strict,
     bolt11: *mut wire_cst_list_prim_u_8_strict,
     description: *mut wire_cst_list_prim_u_8_strict,
+    destination: *mut wire_cst_list_prim_u_8_strict,
+    asset_id: *mut wire_cst_list_prim_u_8_strict,
     refund_tx_id: *mut wire_cst_list_prim_u_8_strict,
     refund_tx_amount_sat: *mut u64,
     payment_type: i32,
     status: i32,
+    metadata: *mut wire_cst_list_payment_metadata,
+    fiat_rates: *mut wire_cst_list_payment_fiat_rate,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -3811,8 +5388,10 @@ pub struct wire_cst_payment_error {
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub union PaymentErrorKind {
+    AmountMissing: wire_cst_PaymentError_AmountMissing,
     Generic: wire_cst_PaymentError_Generic,
     InvalidInvoice: wire_cst_PaymentError_InvalidInvoice,
+    InvalidDestination: wire_cst_PaymentError_InvalidDestination,
     LwkError: wire_cst_PaymentError_LwkError,
     ReceiveError: wire_cst_PaymentError_ReceiveError,
     Refunded: wire_cst_PaymentError_Refunded,
@@ -3822,6 +5401,11 @@ pub union PaymentErrorKind {
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct wire_cst_PaymentError_AmountMissing {
+    err: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct wire_cst_PaymentError_Generic {
     err: *mut wire_cst_list_prim_u_8_strict,
 }
@@ -3832,6 +5416,11 @@ pub struct wire_cst_PaymentError_InvalidInvoice {
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct wire_cst_PaymentError_InvalidDestination {
+    err: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct wire_cst_PaymentError_LwkError {
     err: *mut wire_cst_list_prim_u_8_strict,
 }
@@ -3858,6 +5447,66 @@ pub struct wire_cst_PaymentError_SignerError {
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct wire_cst_payment_fiat_rate {
+    coin: *mut wire_cst_list_prim_u_8_strict,
+    value: f64,
+    created_at: u32,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_payment_lookup {
+    tag: i32,
+    kind: PaymentLookupKind,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union PaymentLookupKind {
+    SwapId: wire_cst_PaymentLookup_SwapId,
+    Invoice: wire_cst_PaymentLookup_Invoice,
+    PaymentHash: wire_cst_PaymentLookup_PaymentHash,
+    TxId: wire_cst_PaymentLookup_TxId,
+    Destination: wire_cst_PaymentLookup_Destination,
+    nil__: (),
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_PaymentLookup_SwapId {
+    swap_id: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_PaymentLookup_Invoice {
+    invoice: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_PaymentLookup_PaymentHash {
+    payment_hash: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_PaymentLookup_TxId {
+    tx_id: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_PaymentLookup_Destination {
+    address: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_payment_metadata {
+    key: *mut wire_cst_list_prim_u_8_strict,
+    value: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_payment_metadata_filter {
+    key: *mut wire_cst_list_prim_u_8_strict,
+    value: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct wire_cst_prepare_buy_bitcoin_request {
     provider: i32,
     amount_sat: u64,
@@ -3874,6 +5523,7 @@ pub struct wire_cst_prepare_buy_bitcoin_response {
 pub struct wire_cst_prepare_pay_onchain_request {
     receiver_amount_sat: u64,
     sat_per_vbyte: *mut u32,
+    drain: *mut bool,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -3881,17 +5531,19 @@ pub struct wire_cst_prepare_pay_onchain_response {
     receiver_amount_sat: u64,
     claim_fees_sat: u64,
     total_fees_sat: u64,
+    drain: bool,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub struct wire_cst_prepare_receive_onchain_request {
-    payer_amount_sat: u64,
+    payer_amount_sat: *mut u64,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub struct wire_cst_prepare_receive_onchain_response {
-    payer_amount_sat: u64,
+    payer_amount_sat: *mut u64,
     fees_sat: u64,
+    swapper_feerate: f64,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -3920,14 +5572,38 @@ pub struct wire_cst_prepare_refund_response {
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct wire_cst_prepare_send_liquid_request {
+    destination: *mut wire_cst_list_prim_u_8_strict,
+    amount_sat: *mut u64,
+    drain: *mut bool,
+    asset_id: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_prepare_send_liquid_response {
+    address: *mut wire_cst_list_prim_u_8_strict,
+    receiver_amount_sat: u64,
+    fees_sat: u64,
+    description: *mut wire_cst_list_prim_u_8_strict,
+    drain: bool,
+    asset_id: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct wire_cst_prepare_send_request {
     invoice: *mut wire_cst_list_prim_u_8_strict,
+    amount_sat: *mut u64,
+    idempotency_key: *mut wire_cst_list_prim_u_8_strict,
+    payer_note: *mut wire_cst_list_prim_u_8_strict,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub struct wire_cst_prepare_send_response {
     invoice: *mut wire_cst_list_prim_u_8_strict,
+    offer: *mut wire_cst_list_prim_u_8_strict,
+    receiver_amount_sat: u64,
     fees_sat: u64,
+    idempotency_key: *mut wire_cst_list_prim_u_8_strict,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -3937,6 +5613,19 @@ pub struct wire_cst_rate {
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct wire_cst_receive_liquid_request {
+    amount_sat: *mut u64,
+    description: *mut wire_cst_list_prim_u_8_strict,
+    asset_id: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_receive_liquid_response {
+    address: *mut wire_cst_list_prim_u_8_strict,
+    bip21: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct wire_cst_receive_onchain_response {
     address: *mut wire_cst_list_prim_u_8_strict,
     bip21: *mut wire_cst_list_prim_u_8_strict,
@@ -3964,6 +5653,29 @@ pub struct wire_cst_recommended_fees {
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct wire_cst_refund_destination {
+    tag: i32,
+    kind: RefundDestinationKind,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union RefundDestinationKind {
+    Address: wire_cst_RefundDestination_Address,
+    Xpub: wire_cst_RefundDestination_Xpub,
+    nil__: (),
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_RefundDestination_Address {
+    address: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_RefundDestination_Xpub {
+    xpub: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct wire_cst_refund_request {
     swap_address: *mut wire_cst_list_prim_u_8_strict,
     refund_address: *mut wire_cst_list_prim_u_8_strict,
@@ -4040,6 +5752,8 @@ pub union SdkEventKind {
     PaymentRefundPending: wire_cst_SdkEvent_PaymentRefundPending,
     PaymentSucceeded: wire_cst_SdkEvent_PaymentSucceeded,
     PaymentWaitingConfirmation: wire_cst_SdkEvent_PaymentWaitingConfirmation,
+    SwapAutoRefunded: wire_cst_SdkEvent_SwapAutoRefunded,
+    SwapWaitingFeeAcceptance: wire_cst_SdkEvent_SwapWaitingFeeAcceptance,
     nil__: (),
 }
 #[repr(C)]
@@ -4074,11 +5788,100 @@ pub struct wire_cst_SdkEvent_PaymentWaitingConfirmation {
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct wire_cst_SdkEvent_SwapAutoRefunded {
+    details: *mut wire_cst_auto_refund_details,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_SdkEvent_SwapWaitingFeeAcceptance {
+    swap_id: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct wire_cst_send_payment_response {
     payment: wire_cst_payment,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct wire_cst_set_payment_metadata_request {
+    payment_id: *mut wire_cst_list_prim_u_8_strict,
+    key: *mut wire_cst_list_prim_u_8_strict,
+    value: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_signer_method {
+    tag: i32,
+    kind: SignerMethodKind,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union SignerMethodKind {
+    DeriveXpub: wire_cst_SignerMethod_DeriveXpub,
+    SignPset: wire_cst_SignerMethod_SignPset,
+    SignEcdsa: wire_cst_SignerMethod_SignEcdsa,
+    HmacSha256: wire_cst_SignerMethod_HmacSha256,
+    nil__: (),
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_SignerMethod_DeriveXpub {
+    derivation_path: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_SignerMethod_SignPset {
+    pset: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_SignerMethod_SignEcdsa {
+    msg: *mut wire_cst_list_prim_u_8_strict,
+    derivation_path: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_SignerMethod_HmacSha256 {
+    msg: *mut wire_cst_list_prim_u_8_strict,
+    derivation_path: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_signer_request {
+    id: u32,
+    method: wire_cst_signer_method,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_signer_result {
+    tag: i32,
+    kind: SignerResultKind,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union SignerResultKind {
+    String: wire_cst_SignerResult_String,
+    Bytes: wire_cst_SignerResult_Bytes,
+    Error: wire_cst_SignerResult_Error,
+    nil__: (),
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_SignerResult_String {
+    value: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_SignerResult_Bytes {
+    value: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_SignerResult_Error {
+    err: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct wire_cst_success_action_processed {
     tag: i32,
     kind: SuccessActionProcessedKind,
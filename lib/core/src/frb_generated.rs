@@ -39,7 +39,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueNom,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.0.0";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -119028624;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -601581800;
 
 // Section: executor
 
@@ -47,6 +47,56 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
+fn wire__crate__bindings__BindingLiquidSdk_accept_payment_proposed_fees_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::AcceptPaymentProposedFeesRequest>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_accept_payment_proposed_fees",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, crate::error::PaymentError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::bindings::BindingLiquidSdk::accept_payment_proposed_fees(
+                                &*api_that_guard,
+                                api_req,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__bindings__BindingLiquidSdk_add_event_listener_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
@@ -135,16 +185,16 @@ fn wire__crate__bindings__BindingLiquidSdk_backup_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_buy_bitcoin_impl(
+fn wire__crate__bindings__BindingLiquidSdk_bump_refund_fee_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
-    req: impl CstDecode<crate::model::BuyBitcoinRequest>,
+    req: impl CstDecode<crate::model::BumpRefundFeeRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_buy_bitcoin",
+            debug_name: "BindingLiquidSdk_bump_refund_fee",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -171,7 +221,7 @@ fn wire__crate__bindings__BindingLiquidSdk_buy_bitcoin_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::bindings::BindingLiquidSdk::buy_bitcoin(
+                        let output_ok = crate::bindings::BindingLiquidSdk::bump_refund_fee(
                             &*api_that_guard,
                             api_req,
                         )
@@ -184,22 +234,24 @@ fn wire__crate__bindings__BindingLiquidSdk_buy_bitcoin_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_disconnect_impl(
+fn wire__crate__bindings__BindingLiquidSdk_buy_bitcoin_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
+    req: impl CstDecode<crate::model::BuyBitcoinRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_disconnect",
+            debug_name: "BindingLiquidSdk_buy_bitcoin",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
             move |context| async move {
-                transform_result_dco::<_, _, crate::error::SdkError>(
+                transform_result_dco::<_, _, crate::error::PaymentError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -218,8 +270,11 @@ fn wire__crate__bindings__BindingLiquidSdk_disconnect_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::bindings::BindingLiquidSdk::disconnect(&*api_that_guard).await?;
+                        let output_ok = crate::bindings::BindingLiquidSdk::buy_bitcoin(
+                            &*api_that_guard,
+                            api_req,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -228,57 +283,24 @@ fn wire__crate__bindings__BindingLiquidSdk_disconnect_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache_impl(
-    that: impl CstDecode<
-        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
-    >,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_empty_wallet_cache",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let api_that = that.cst_decode();
-            transform_result_dco::<_, _, crate::error::SdkError>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok =
-                    crate::bindings::BindingLiquidSdk::empty_wallet_cache(&*api_that_guard)?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__bindings__BindingLiquidSdk_fetch_fiat_rates_impl(
+fn wire__crate__bindings__BindingLiquidSdk_delete_payment_metadata_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
+    req: impl CstDecode<crate::model::DeletePaymentMetadataRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_fetch_fiat_rates",
+            debug_name: "BindingLiquidSdk_delete_payment_metadata",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
             move |context| async move {
-                transform_result_dco::<_, _, crate::error::SdkError>(
+                transform_result_dco::<_, _, crate::error::PaymentError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -297,9 +319,11 @@ fn wire__crate__bindings__BindingLiquidSdk_fetch_fiat_rates_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::bindings::BindingLiquidSdk::fetch_fiat_rates(&*api_that_guard)
-                                .await?;
+                        let output_ok = crate::bindings::BindingLiquidSdk::delete_payment_metadata(
+                            &*api_that_guard,
+                            api_req,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -308,7 +332,7 @@ fn wire__crate__bindings__BindingLiquidSdk_fetch_fiat_rates_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limits_impl(
+fn wire__crate__bindings__BindingLiquidSdk_disconnect_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
@@ -316,14 +340,14 @@ fn wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limits_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_fetch_lightning_limits",
+            debug_name: "BindingLiquidSdk_disconnect",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_that = that.cst_decode();
             move |context| async move {
-                transform_result_dco::<_, _, crate::error::PaymentError>(
+                transform_result_dco::<_, _, crate::error::SdkError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -342,10 +366,8 @@ fn wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limits_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::bindings::BindingLiquidSdk::fetch_lightning_limits(
-                            &*api_that_guard,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::bindings::BindingLiquidSdk::disconnect(&*api_that_guard).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -354,20 +376,57 @@ fn wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limits_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_fetch_onchain_limits_impl(
+fn wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache_impl(
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_empty_wallet_cache",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            transform_result_dco::<_, _, crate::error::SdkError>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok =
+                    crate::bindings::BindingLiquidSdk::empty_wallet_cache(&*api_that_guard)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_export_payments_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
+    req: impl CstDecode<crate::model::ExportPaymentsRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_fetch_onchain_limits",
+            debug_name: "BindingLiquidSdk_export_payments",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
             move |context| async move {
                 transform_result_dco::<_, _, crate::error::PaymentError>(
                     (move || async move {
@@ -388,8 +447,9 @@ fn wire__crate__bindings__BindingLiquidSdk_fetch_onchain_limits_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::bindings::BindingLiquidSdk::fetch_onchain_limits(
+                        let output_ok = crate::bindings::BindingLiquidSdk::export_payments(
                             &*api_that_guard,
+                            api_req,
                         )
                         .await?;
                         Ok(output_ok)
@@ -400,7 +460,7 @@ fn wire__crate__bindings__BindingLiquidSdk_fetch_onchain_limits_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_get_info_impl(
+fn wire__crate__bindings__BindingLiquidSdk_fetch_fiat_rates_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
@@ -408,7 +468,7 @@ fn wire__crate__bindings__BindingLiquidSdk_get_info_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_get_info",
+            debug_name: "BindingLiquidSdk_fetch_fiat_rates",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -435,7 +495,8 @@ fn wire__crate__bindings__BindingLiquidSdk_get_info_impl(
                         }
                         let api_that_guard = api_that_guard.unwrap();
                         let output_ok =
-                            crate::bindings::BindingLiquidSdk::get_info(&*api_that_guard).await?;
+                            crate::bindings::BindingLiquidSdk::fetch_fiat_rates(&*api_that_guard)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -444,7 +505,7 @@ fn wire__crate__bindings__BindingLiquidSdk_get_info_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_list_fiat_currencies_impl(
+fn wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limits_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
@@ -452,14 +513,14 @@ fn wire__crate__bindings__BindingLiquidSdk_list_fiat_currencies_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_list_fiat_currencies",
+            debug_name: "BindingLiquidSdk_fetch_lightning_limits",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_that = that.cst_decode();
             move |context| async move {
-                transform_result_dco::<_, _, crate::error::SdkError>(
+                transform_result_dco::<_, _, crate::error::PaymentError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -478,7 +539,7 @@ fn wire__crate__bindings__BindingLiquidSdk_list_fiat_currencies_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::bindings::BindingLiquidSdk::list_fiat_currencies(
+                        let output_ok = crate::bindings::BindingLiquidSdk::fetch_lightning_limits(
                             &*api_that_guard,
                         )
                         .await?;
@@ -490,22 +551,20 @@ fn wire__crate__bindings__BindingLiquidSdk_list_fiat_currencies_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_list_payments_impl(
+fn wire__crate__bindings__BindingLiquidSdk_fetch_onchain_limits_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
-    req: impl CstDecode<crate::model::ListPaymentsRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_list_payments",
+            debug_name: "BindingLiquidSdk_fetch_onchain_limits",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_that = that.cst_decode();
-            let api_req = req.cst_decode();
             move |context| async move {
                 transform_result_dco::<_, _, crate::error::PaymentError>(
                     (move || async move {
@@ -526,9 +585,8 @@ fn wire__crate__bindings__BindingLiquidSdk_list_payments_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::bindings::BindingLiquidSdk::list_payments(
+                        let output_ok = crate::bindings::BindingLiquidSdk::fetch_onchain_limits(
                             &*api_that_guard,
-                            api_req,
                         )
                         .await?;
                         Ok(output_ok)
@@ -539,22 +597,24 @@ fn wire__crate__bindings__BindingLiquidSdk_list_payments_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_list_refundables_impl(
+fn wire__crate__bindings__BindingLiquidSdk_fetch_payment_proposed_fees_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
+    req: impl CstDecode<crate::model::FetchPaymentProposedFeesRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_list_refundables",
+            debug_name: "BindingLiquidSdk_fetch_payment_proposed_fees",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
             move |context| async move {
-                transform_result_dco::<_, _, crate::error::SdkError>(
+                transform_result_dco::<_, _, crate::error::PaymentError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -574,8 +634,11 @@ fn wire__crate__bindings__BindingLiquidSdk_list_refundables_impl(
                         }
                         let api_that_guard = api_that_guard.unwrap();
                         let output_ok =
-                            crate::bindings::BindingLiquidSdk::list_refundables(&*api_that_guard)
-                                .await?;
+                            crate::bindings::BindingLiquidSdk::fetch_payment_proposed_fees(
+                                &*api_that_guard,
+                                api_req,
+                            )
+                            .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -584,24 +647,22 @@ fn wire__crate__bindings__BindingLiquidSdk_list_refundables_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_lnurl_auth_impl(
+fn wire__crate__bindings__BindingLiquidSdk_get_info_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
-    req_data: impl CstDecode<crate::bindings::LnUrlAuthRequestData>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_lnurl_auth",
+            debug_name: "BindingLiquidSdk_get_info",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_that = that.cst_decode();
-            let api_req_data = req_data.cst_decode();
             move |context| async move {
-                transform_result_dco::<_, _, crate::bindings::duplicates::LnUrlAuthError>(
+                transform_result_dco::<_, _, crate::error::SdkError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -620,11 +681,8 @@ fn wire__crate__bindings__BindingLiquidSdk_lnurl_auth_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::bindings::BindingLiquidSdk::lnurl_auth(
-                            &*api_that_guard,
-                            api_req_data,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::bindings::BindingLiquidSdk::get_info(&*api_that_guard).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -633,16 +691,16 @@ fn wire__crate__bindings__BindingLiquidSdk_lnurl_auth_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_lnurl_pay_impl(
+fn wire__crate__bindings__BindingLiquidSdk_get_payment_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
-    req: impl CstDecode<crate::bindings::LnUrlPayRequest>,
+    req: impl CstDecode<crate::model::PaymentLookup>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_lnurl_pay",
+            debug_name: "BindingLiquidSdk_get_payment",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -650,7 +708,7 @@ fn wire__crate__bindings__BindingLiquidSdk_lnurl_pay_impl(
             let api_that = that.cst_decode();
             let api_req = req.cst_decode();
             move |context| async move {
-                transform_result_dco::<_, _, crate::bindings::duplicates::LnUrlPayError>(
+                transform_result_dco::<_, _, crate::error::PaymentError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -669,9 +727,11 @@ fn wire__crate__bindings__BindingLiquidSdk_lnurl_pay_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::bindings::BindingLiquidSdk::lnurl_pay(&*api_that_guard, api_req)
-                                .await?;
+                        let output_ok = crate::bindings::BindingLiquidSdk::get_payment(
+                            &*api_that_guard,
+                            api_req,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -680,24 +740,24 @@ fn wire__crate__bindings__BindingLiquidSdk_lnurl_pay_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw_impl(
+fn wire__crate__bindings__BindingLiquidSdk_get_payment_metadata_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
-    req: impl CstDecode<crate::bindings::LnUrlWithdrawRequest>,
+    payment_id: impl CstDecode<String>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_lnurl_withdraw",
+            debug_name: "BindingLiquidSdk_get_payment_metadata",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_that = that.cst_decode();
-            let api_req = req.cst_decode();
+            let api_payment_id = payment_id.cst_decode();
             move |context| async move {
-                transform_result_dco::<_, _, crate::bindings::duplicates::LnUrlWithdrawError>(
+                transform_result_dco::<_, _, crate::error::PaymentError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -716,9 +776,9 @@ fn wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::bindings::BindingLiquidSdk::lnurl_withdraw(
+                        let output_ok = crate::bindings::BindingLiquidSdk::get_payment_metadata(
                             &*api_that_guard,
-                            api_req,
+                            api_payment_id,
                         )
                         .await?;
                         Ok(output_ok)
@@ -729,24 +789,22 @@ fn wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_pay_onchain_impl(
+fn wire__crate__bindings__BindingLiquidSdk_list_fiat_currencies_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
-    req: impl CstDecode<crate::model::PayOnchainRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_pay_onchain",
+            debug_name: "BindingLiquidSdk_list_fiat_currencies",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_that = that.cst_decode();
-            let api_req = req.cst_decode();
             move |context| async move {
-                transform_result_dco::<_, _, crate::error::PaymentError>(
+                transform_result_dco::<_, _, crate::error::SdkError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -765,9 +823,8 @@ fn wire__crate__bindings__BindingLiquidSdk_pay_onchain_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::bindings::BindingLiquidSdk::pay_onchain(
+                        let output_ok = crate::bindings::BindingLiquidSdk::list_fiat_currencies(
                             &*api_that_guard,
-                            api_req,
                         )
                         .await?;
                         Ok(output_ok)
@@ -778,16 +835,16 @@ fn wire__crate__bindings__BindingLiquidSdk_pay_onchain_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_prepare_buy_bitcoin_impl(
+fn wire__crate__bindings__BindingLiquidSdk_list_payments_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
-    req: impl CstDecode<crate::model::PrepareBuyBitcoinRequest>,
+    req: impl CstDecode<crate::model::ListPaymentsRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_prepare_buy_bitcoin",
+            debug_name: "BindingLiquidSdk_list_payments",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -814,7 +871,7 @@ fn wire__crate__bindings__BindingLiquidSdk_prepare_buy_bitcoin_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::bindings::BindingLiquidSdk::prepare_buy_bitcoin(
+                        let output_ok = crate::bindings::BindingLiquidSdk::list_payments(
                             &*api_that_guard,
                             api_req,
                         )
@@ -827,16 +884,16 @@ fn wire__crate__bindings__BindingLiquidSdk_prepare_buy_bitcoin_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchain_impl(
+fn wire__crate__bindings__BindingLiquidSdk_list_payments_page_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
-    req: impl CstDecode<crate::model::PreparePayOnchainRequest>,
+    req: impl CstDecode<crate::model::ListPaymentsRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_prepare_pay_onchain",
+            debug_name: "BindingLiquidSdk_list_payments_page",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -863,7 +920,7 @@ fn wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchain_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::bindings::BindingLiquidSdk::prepare_pay_onchain(
+                        let output_ok = crate::bindings::BindingLiquidSdk::list_payments_page(
                             &*api_that_guard,
                             api_req,
                         )
@@ -876,24 +933,22 @@ fn wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchain_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_prepare_receive_onchain_impl(
+fn wire__crate__bindings__BindingLiquidSdk_list_refundables_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
-    req: impl CstDecode<crate::model::PrepareReceiveOnchainRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_prepare_receive_onchain",
+            debug_name: "BindingLiquidSdk_list_refundables",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_that = that.cst_decode();
-            let api_req = req.cst_decode();
             move |context| async move {
-                transform_result_dco::<_, _, crate::error::PaymentError>(
+                transform_result_dco::<_, _, crate::error::SdkError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -912,11 +967,9 @@ fn wire__crate__bindings__BindingLiquidSdk_prepare_receive_onchain_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::bindings::BindingLiquidSdk::prepare_receive_onchain(
-                            &*api_that_guard,
-                            api_req,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::bindings::BindingLiquidSdk::list_refundables(&*api_that_guard)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -925,24 +978,24 @@ fn wire__crate__bindings__BindingLiquidSdk_prepare_receive_onchain_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment_impl(
+fn wire__crate__bindings__BindingLiquidSdk_lnurl_auth_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
-    req: impl CstDecode<crate::model::PrepareReceivePaymentRequest>,
+    req_data: impl CstDecode<crate::bindings::LnUrlAuthRequestData>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_prepare_receive_payment",
+            debug_name: "BindingLiquidSdk_lnurl_auth",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_that = that.cst_decode();
-            let api_req = req.cst_decode();
+            let api_req_data = req_data.cst_decode();
             move |context| async move {
-                transform_result_dco::<_, _, crate::error::PaymentError>(
+                transform_result_dco::<_, _, crate::bindings::duplicates::LnUrlAuthError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -961,9 +1014,9 @@ fn wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::bindings::BindingLiquidSdk::prepare_receive_payment(
+                        let output_ok = crate::bindings::BindingLiquidSdk::lnurl_auth(
                             &*api_that_guard,
-                            api_req,
+                            api_req_data,
                         )
                         .await?;
                         Ok(output_ok)
@@ -974,16 +1027,16 @@ fn wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_prepare_refund_impl(
+fn wire__crate__bindings__BindingLiquidSdk_lnurl_pay_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
-    req: impl CstDecode<crate::model::PrepareRefundRequest>,
+    req: impl CstDecode<crate::bindings::LnUrlPayRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_prepare_refund",
+            debug_name: "BindingLiquidSdk_lnurl_pay",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -991,7 +1044,7 @@ fn wire__crate__bindings__BindingLiquidSdk_prepare_refund_impl(
             let api_that = that.cst_decode();
             let api_req = req.cst_decode();
             move |context| async move {
-                transform_result_dco::<_, _, crate::error::SdkError>(
+                transform_result_dco::<_, _, crate::bindings::duplicates::LnUrlPayError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -1010,11 +1063,9 @@ fn wire__crate__bindings__BindingLiquidSdk_prepare_refund_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::bindings::BindingLiquidSdk::prepare_refund(
-                            &*api_that_guard,
-                            api_req,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::bindings::BindingLiquidSdk::lnurl_pay(&*api_that_guard, api_req)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1023,16 +1074,16 @@ fn wire__crate__bindings__BindingLiquidSdk_prepare_refund_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_prepare_send_payment_impl(
+fn wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
-    req: impl CstDecode<crate::model::PrepareSendRequest>,
+    req: impl CstDecode<crate::bindings::LnUrlWithdrawRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_prepare_send_payment",
+            debug_name: "BindingLiquidSdk_lnurl_withdraw",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1040,7 +1091,7 @@ fn wire__crate__bindings__BindingLiquidSdk_prepare_send_payment_impl(
             let api_that = that.cst_decode();
             let api_req = req.cst_decode();
             move |context| async move {
-                transform_result_dco::<_, _, crate::error::PaymentError>(
+                transform_result_dco::<_, _, crate::bindings::duplicates::LnUrlWithdrawError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -1059,7 +1110,7 @@ fn wire__crate__bindings__BindingLiquidSdk_prepare_send_payment_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::bindings::BindingLiquidSdk::prepare_send_payment(
+                        let output_ok = crate::bindings::BindingLiquidSdk::lnurl_withdraw(
                             &*api_that_guard,
                             api_req,
                         )
@@ -1072,16 +1123,16 @@ fn wire__crate__bindings__BindingLiquidSdk_prepare_send_payment_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_receive_onchain_impl(
+fn wire__crate__bindings__BindingLiquidSdk_pay_onchain_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
-    req: impl CstDecode<crate::model::PrepareReceiveOnchainResponse>,
+    req: impl CstDecode<crate::model::PayOnchainRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_receive_onchain",
+            debug_name: "BindingLiquidSdk_pay_onchain",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1108,7 +1159,7 @@ fn wire__crate__bindings__BindingLiquidSdk_receive_onchain_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::bindings::BindingLiquidSdk::receive_onchain(
+                        let output_ok = crate::bindings::BindingLiquidSdk::pay_onchain(
                             &*api_that_guard,
                             api_req,
                         )
@@ -1121,16 +1172,16 @@ fn wire__crate__bindings__BindingLiquidSdk_receive_onchain_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_receive_payment_impl(
+fn wire__crate__bindings__BindingLiquidSdk_prepare_buy_bitcoin_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
-    req: impl CstDecode<crate::model::ReceivePaymentRequest>,
+    req: impl CstDecode<crate::model::PrepareBuyBitcoinRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_receive_payment",
+            debug_name: "BindingLiquidSdk_prepare_buy_bitcoin",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1157,7 +1208,7 @@ fn wire__crate__bindings__BindingLiquidSdk_receive_payment_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::bindings::BindingLiquidSdk::receive_payment(
+                        let output_ok = crate::bindings::BindingLiquidSdk::prepare_buy_bitcoin(
                             &*api_that_guard,
                             api_req,
                         )
@@ -1170,22 +1221,24 @@ fn wire__crate__bindings__BindingLiquidSdk_receive_payment_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_recommended_fees_impl(
+fn wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchain_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
+    req: impl CstDecode<crate::model::PreparePayOnchainRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_recommended_fees",
+            debug_name: "BindingLiquidSdk_prepare_pay_onchain",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
             move |context| async move {
-                transform_result_dco::<_, _, crate::error::SdkError>(
+                transform_result_dco::<_, _, crate::error::PaymentError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -1204,9 +1257,11 @@ fn wire__crate__bindings__BindingLiquidSdk_recommended_fees_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::bindings::BindingLiquidSdk::recommended_fees(&*api_that_guard)
-                                .await?;
+                        let output_ok = crate::bindings::BindingLiquidSdk::prepare_pay_onchain(
+                            &*api_that_guard,
+                            api_req,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1215,16 +1270,16 @@ fn wire__crate__bindings__BindingLiquidSdk_recommended_fees_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_refund_impl(
+fn wire__crate__bindings__BindingLiquidSdk_prepare_receive_onchain_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
-    req: impl CstDecode<crate::model::RefundRequest>,
+    req: impl CstDecode<crate::model::PrepareReceiveOnchainRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_refund",
+            debug_name: "BindingLiquidSdk_prepare_receive_onchain",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1251,9 +1306,11 @@ fn wire__crate__bindings__BindingLiquidSdk_refund_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::bindings::BindingLiquidSdk::refund(&*api_that_guard, api_req)
-                                .await?;
+                        let output_ok = crate::bindings::BindingLiquidSdk::prepare_receive_onchain(
+                            &*api_that_guard,
+                            api_req,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1262,22 +1319,24 @@ fn wire__crate__bindings__BindingLiquidSdk_refund_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_rescan_onchain_swaps_impl(
+fn wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
+    req: impl CstDecode<crate::model::PrepareReceivePaymentRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_rescan_onchain_swaps",
+            debug_name: "BindingLiquidSdk_prepare_receive_payment",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
             move |context| async move {
-                transform_result_dco::<_, _, crate::error::SdkError>(
+                transform_result_dco::<_, _, crate::error::PaymentError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -1296,8 +1355,9 @@ fn wire__crate__bindings__BindingLiquidSdk_rescan_onchain_swaps_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::bindings::BindingLiquidSdk::rescan_onchain_swaps(
+                        let output_ok = crate::bindings::BindingLiquidSdk::prepare_receive_payment(
                             &*api_that_guard,
+                            api_req,
                         )
                         .await?;
                         Ok(output_ok)
@@ -1308,53 +1368,65 @@ fn wire__crate__bindings__BindingLiquidSdk_rescan_onchain_swaps_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_restore_impl(
+fn wire__crate__bindings__BindingLiquidSdk_prepare_refund_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
-    req: impl CstDecode<crate::model::RestoreRequest>,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+    req: impl CstDecode<crate::model::PrepareRefundRequest>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_restore",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "BindingLiquidSdk_prepare_refund",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_that = that.cst_decode();
             let api_req = req.cst_decode();
-            transform_result_dco::<_, _, crate::error::SdkError>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok =
-                    crate::bindings::BindingLiquidSdk::restore(&*api_that_guard, api_req)?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_dco::<_, _, crate::error::SdkError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::bindings::BindingLiquidSdk::prepare_refund(
+                            &*api_that_guard,
+                            api_req,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_send_payment_impl(
+fn wire__crate__bindings__BindingLiquidSdk_prepare_send_liquid_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
-    req: impl CstDecode<crate::model::PrepareSendResponse>,
+    req: impl CstDecode<crate::model::PrepareSendLiquidRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_send_payment",
+            debug_name: "BindingLiquidSdk_prepare_send_liquid",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1381,7 +1453,7 @@ fn wire__crate__bindings__BindingLiquidSdk_send_payment_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok = crate::bindings::BindingLiquidSdk::send_payment(
+                        let output_ok = crate::bindings::BindingLiquidSdk::prepare_send_liquid(
                             &*api_that_guard,
                             api_req,
                         )
@@ -1394,22 +1466,24 @@ fn wire__crate__bindings__BindingLiquidSdk_send_payment_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_sync_impl(
+fn wire__crate__bindings__BindingLiquidSdk_prepare_send_payment_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
+    req: impl CstDecode<crate::model::PrepareSendRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_sync",
+            debug_name: "BindingLiquidSdk_prepare_send_payment",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
             move |context| async move {
-                transform_result_dco::<_, _, crate::error::SdkError>(
+                transform_result_dco::<_, _, crate::error::PaymentError>(
                     (move || async move {
                         let mut api_that_guard = None;
                         let decode_indices_ =
@@ -1428,8 +1502,11 @@ fn wire__crate__bindings__BindingLiquidSdk_sync_impl(
                             }
                         }
                         let api_that_guard = api_that_guard.unwrap();
-                        let output_ok =
-                            crate::bindings::BindingLiquidSdk::sync(&*api_that_guard).await?;
+                        let output_ok = crate::bindings::BindingLiquidSdk::prepare_send_payment(
+                            &*api_that_guard,
+                            api_req,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -1438,51 +1515,564 @@ fn wire__crate__bindings__BindingLiquidSdk_sync_impl(
         },
     )
 }
-fn wire__crate__bindings__binding_event_listener_on_event_impl(
+fn wire__crate__bindings__BindingLiquidSdk_receive_liquid_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    that: impl CstDecode<crate::bindings::BindingEventListener>,
-    e: impl CstDecode<crate::model::SdkEvent>,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::ReceiveLiquidRequest>,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "binding_event_listener_on_event",
+            debug_name: "BindingLiquidSdk_receive_liquid",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_that = that.cst_decode();
-            let api_e = e.cst_decode();
-            move |context| {
-                transform_result_dco::<_, _, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::bindings::BindingEventListener::on_event(&api_that, api_e);
-                    })?;
-                    Ok(output_ok)
-                })())
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, crate::error::PaymentError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::bindings::BindingLiquidSdk::receive_liquid(
+                            &*api_that_guard,
+                            api_req,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__bindings__breez_log_stream_impl(
+fn wire__crate__bindings__BindingLiquidSdk_receive_onchain_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    s: impl CstDecode<StreamSink<crate::model::LogEntry, flutter_rust_bridge::for_generated::DcoCodec>>,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::PrepareReceiveOnchainResponse>,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "breez_log_stream",
+            debug_name: "BindingLiquidSdk_receive_onchain",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_s = s.cst_decode();
-            move |context| {
-                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::bindings::breez_log_stream(api_s)?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, crate::error::PaymentError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::bindings::BindingLiquidSdk::receive_onchain(
+                            &*api_that_guard,
+                            api_req,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_receive_payment_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::ReceivePaymentRequest>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_receive_payment",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, crate::error::PaymentError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::bindings::BindingLiquidSdk::receive_payment(
+                            &*api_that_guard,
+                            api_req,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_recommended_fees_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_recommended_fees",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, crate::error::SdkError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::bindings::BindingLiquidSdk::recommended_fees(&*api_that_guard)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_refund_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::RefundRequest>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_refund",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, crate::error::PaymentError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::bindings::BindingLiquidSdk::refund(&*api_that_guard, api_req)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_rescan_onchain_swaps_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_rescan_onchain_swaps",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, crate::error::SdkError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::bindings::BindingLiquidSdk::rescan_onchain_swaps(
+                            &*api_that_guard,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_restore_impl(
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::RestoreRequest>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_restore",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            transform_result_dco::<_, _, crate::error::SdkError>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok =
+                    crate::bindings::BindingLiquidSdk::restore(&*api_that_guard, api_req)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_send_liquid_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::PrepareSendLiquidResponse>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_send_liquid",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, crate::error::PaymentError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::bindings::BindingLiquidSdk::send_liquid(
+                            &*api_that_guard,
+                            api_req,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_send_payment_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::PrepareSendResponse>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_send_payment",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, crate::error::PaymentError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::bindings::BindingLiquidSdk::send_payment(
+                            &*api_that_guard,
+                            api_req,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_set_payment_metadata_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::SetPaymentMetadataRequest>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_set_payment_metadata",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, crate::error::PaymentError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::bindings::BindingLiquidSdk::set_payment_metadata(
+                            &*api_that_guard,
+                            api_req,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_sync_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_sync",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, crate::error::SdkError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::bindings::BindingLiquidSdk::sync(&*api_that_guard).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__binding_event_listener_on_event_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<crate::bindings::BindingEventListener>,
+    e: impl CstDecode<crate::model::SdkEvent>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "binding_event_listener_on_event",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_e = e.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::bindings::BindingEventListener::on_event(&api_that, api_e);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__bindings__breez_log_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    s: impl CstDecode<StreamSink<crate::model::LogEntry, flutter_rust_bridge::for_generated::DcoCodec>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "breez_log_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_s = s.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::bindings::breez_log_stream(api_s)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
         },
     )
 }
@@ -1510,6 +2100,30 @@ fn wire__crate__bindings__connect_impl(
         },
     )
 }
+fn wire__crate__bindings__connect_with_signer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    req: impl CstDecode<crate::model::ConnectRequest>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "connect_with_signer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco::<_, _, crate::error::SdkError>(
+                    (move || async move {
+                        let output_ok = crate::bindings::connect_with_signer(api_req).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__bindings__default_config_impl(
     network: impl CstDecode<crate::model::LiquidNetwork>,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
@@ -1570,6 +2184,69 @@ fn wire__crate__bindings__parse_invoice_impl(
         },
     )
 }
+fn wire__crate__bindings__parse_offer_impl(
+    input: impl CstDecode<String>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_offer",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_input = input.cst_decode();
+            transform_result_dco::<_, _, crate::error::PaymentError>((move || {
+                let output_ok = crate::bindings::parse_offer(api_input)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__bindings__respond_to_signer_request_impl(
+    id: impl CstDecode<u32>,
+    result: impl CstDecode<crate::model::SignerResult>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "respond_to_signer_request",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_id = id.cst_decode();
+            let api_result = result.cst_decode();
+            transform_result_dco::<_, _, crate::error::SdkError>((move || {
+                let output_ok = crate::bindings::respond_to_signer_request(api_id, api_result)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__bindings__signer_request_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    s: impl CstDecode<
+        StreamSink<crate::model::SignerRequest, flutter_rust_bridge::for_generated::DcoCodec>,
+    >,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "signer_request_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_s = s.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::bindings::signer_request_stream(api_s)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
 
 // Section: static_checks
 
@@ -1780,6 +2457,26 @@ impl CstDecode<crate::model::BuyBitcoinProvider> for i32 {
         }
     }
 }
+impl CstDecode<crate::model::ChainBackend> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::ChainBackend {
+        match self {
+            0 => crate::model::ChainBackend::Electrum,
+            1 => crate::model::ChainBackend::Esplora,
+            _ => unreachable!("Invalid variant for ChainBackend: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::model::ExportFormat> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::ExportFormat {
+        match self {
+            0 => crate::model::ExportFormat::Csv,
+            1 => crate::model::ExportFormat::Json,
+            _ => unreachable!("Invalid variant for ExportFormat: {}", self),
+        }
+    }
+}
 impl CstDecode<f64> for f64 {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> f64 {
@@ -1804,6 +2501,7 @@ impl CstDecode<crate::model::LiquidNetwork> for i32 {
         match self {
             0 => crate::model::LiquidNetwork::Mainnet,
             1 => crate::model::LiquidNetwork::Testnet,
+            2 => crate::model::LiquidNetwork::Regtest,
             _ => unreachable!("Invalid variant for LiquidNetwork: {}", self),
         }
     }
@@ -1820,6 +2518,18 @@ impl CstDecode<crate::bindings::Network> for i32 {
         }
     }
 }
+impl CstDecode<crate::model::PaymentKind> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PaymentKind {
+        match self {
+            0 => crate::model::PaymentKind::Submarine,
+            1 => crate::model::PaymentKind::Reverse,
+            2 => crate::model::PaymentKind::Chain,
+            3 => crate::model::PaymentKind::Direct,
+            _ => unreachable!("Invalid variant for PaymentKind: {}", self),
+        }
+    }
+}
 impl CstDecode<crate::model::PaymentState> for i32 {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::model::PaymentState {
@@ -1831,6 +2541,7 @@ impl CstDecode<crate::model::PaymentState> for i32 {
             4 => crate::model::PaymentState::TimedOut,
             5 => crate::model::PaymentState::Refundable,
             6 => crate::model::PaymentState::RefundPending,
+            7 => crate::model::PaymentState::WaitingFeeAcceptance,
             _ => unreachable!("Invalid variant for PaymentState: {}", self),
         }
     }
@@ -1845,6 +2556,19 @@ impl CstDecode<crate::model::PaymentType> for i32 {
         }
     }
 }
+impl CstDecode<crate::model::RefundFeeRate> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::RefundFeeRate {
+        match self {
+            0 => crate::model::RefundFeeRate::Fastest,
+            1 => crate::model::RefundFeeRate::HalfHour,
+            2 => crate::model::RefundFeeRate::Hour,
+            3 => crate::model::RefundFeeRate::Economy,
+            4 => crate::model::RefundFeeRate::Minimum,
+            _ => unreachable!("Invalid variant for RefundFeeRate: {}", self),
+        }
+    }
+}
 impl CstDecode<u16> for u16 {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> u16 {
@@ -1923,6 +2647,16 @@ impl SseDecode
     }
 }
 
+impl SseDecode
+    for StreamSink<crate::model::SignerRequest, flutter_rust_bridge::for_generated::DcoCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
 impl SseDecode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1931,6 +2665,17 @@ impl SseDecode for String {
     }
 }
 
+impl SseDecode for crate::model::AcceptPaymentProposedFeesRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_response =
+            <crate::model::FetchPaymentProposedFeesResponse>::sse_decode(deserializer);
+        return crate::model::AcceptPaymentProposedFeesRequest {
+            response: var_response,
+        };
+    }
+}
+
 impl SseDecode for crate::bindings::AesSuccessActionDataDecrypted {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1966,12 +2711,96 @@ impl SseDecode for crate::bindings::AesSuccessActionDataResult {
     }
 }
 
+impl SseDecode for crate::model::AssetBalance {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_assetId = <String>::sse_decode(deserializer);
+        let mut var_balance = <u64>::sse_decode(deserializer);
+        let mut var_pendingSend = <u64>::sse_decode(deserializer);
+        let mut var_pendingReceive = <u64>::sse_decode(deserializer);
+        let mut var_assetMetadata = <Option<crate::model::AssetMetadata>>::sse_decode(deserializer);
+        return crate::model::AssetBalance {
+            asset_id: var_assetId,
+            balance: var_balance,
+            pending_send: var_pendingSend,
+            pending_receive: var_pendingReceive,
+            asset_metadata: var_assetMetadata,
+        };
+    }
+}
+
+impl SseDecode for crate::model::AssetMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_assetId = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_ticker = <String>::sse_decode(deserializer);
+        let mut var_precision = <u8>::sse_decode(deserializer);
+        return crate::model::AssetMetadata {
+            asset_id: var_assetId,
+            name: var_name,
+            ticker: var_ticker,
+            precision: var_precision,
+        };
+    }
+}
+
+impl SseDecode for crate::model::AutoRefundDetails {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_swapAddress = <String>::sse_decode(deserializer);
+        let mut var_refundAddress = <String>::sse_decode(deserializer);
+        let mut var_refundTxId = <String>::sse_decode(deserializer);
+        return crate::model::AutoRefundDetails {
+            swap_address: var_swapAddress,
+            refund_address: var_refundAddress,
+            refund_tx_id: var_refundTxId,
+        };
+    }
+}
+
+impl SseDecode for crate::model::AutoRefundPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_destination = <crate::model::RefundDestination>::sse_decode(deserializer);
+        let mut var_feeRate = <crate::model::RefundFeeRate>::sse_decode(deserializer);
+        return crate::model::AutoRefundPolicy {
+            destination: var_destination,
+            fee_rate: var_feeRate,
+        };
+    }
+}
+
 impl SseDecode for crate::model::BackupRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_backupPath = <Option<String>>::sse_decode(deserializer);
-        return crate::model::BackupRequest {
-            backup_path: var_backupPath,
+        let mut var_backupPath = <Option<String>>::sse_decode(deserializer);
+        return crate::model::BackupRequest {
+            backup_path: var_backupPath,
+        };
+    }
+}
+
+impl SseDecode for crate::model::BalanceDetails {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_confirmedSat = <u64>::sse_decode(deserializer);
+        let mut var_unconfirmedReceiveSat = <u64>::sse_decode(deserializer);
+        let mut var_unconfirmedSendSat = <u64>::sse_decode(deserializer);
+        let mut var_pendingSendSwapsSat = <u64>::sse_decode(deserializer);
+        let mut var_pendingReceiveSwapsSat = <u64>::sse_decode(deserializer);
+        let mut var_pendingRefundSat = <u64>::sse_decode(deserializer);
+        let mut var_refundableBtcSat = <u64>::sse_decode(deserializer);
+        let mut var_pendingBtcRefundSat = <u64>::sse_decode(deserializer);
+        return crate::model::BalanceDetails {
+            confirmed_sat: var_confirmedSat,
+            unconfirmed_receive_sat: var_unconfirmedReceiveSat,
+            unconfirmed_send_sat: var_unconfirmedSendSat,
+            pending_send_swaps_sat: var_pendingSendSwapsSat,
+            pending_receive_swaps_sat: var_pendingReceiveSwapsSat,
+            pending_refund_sat: var_pendingRefundSat,
+            refundable_btc_sat: var_refundableBtcSat,
+            pending_btc_refund_sat: var_pendingBtcRefundSat,
         };
     }
 }
@@ -2012,6 +2841,34 @@ impl SseDecode for bool {
     }
 }
 
+impl SseDecode for crate::model::BumpRefundFeeRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_swapAddress = <String>::sse_decode(deserializer);
+        let mut var_satPerVbyte = <u32>::sse_decode(deserializer);
+        return crate::model::BumpRefundFeeRequest {
+            swap_address: var_swapAddress,
+            sat_per_vbyte: var_satPerVbyte,
+        };
+    }
+}
+
+impl SseDecode for crate::model::BumpRefundFeeResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_refundTxId = <String>::sse_decode(deserializer);
+        let mut var_replacedTxId = <String>::sse_decode(deserializer);
+        let mut var_txFeeSat = <u64>::sse_decode(deserializer);
+        let mut var_feeDeltaSat = <u64>::sse_decode(deserializer);
+        return crate::model::BumpRefundFeeResponse {
+            refund_tx_id: var_refundTxId,
+            replaced_tx_id: var_replacedTxId,
+            tx_fee_sat: var_txFeeSat,
+            fee_delta_sat: var_feeDeltaSat,
+        };
+    }
+}
+
 impl SseDecode for crate::model::BuyBitcoinProvider {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2036,26 +2893,61 @@ impl SseDecode for crate::model::BuyBitcoinRequest {
     }
 }
 
+impl SseDecode for crate::model::ChainBackend {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::model::ChainBackend::Electrum,
+            1 => crate::model::ChainBackend::Esplora,
+            _ => unreachable!("Invalid variant for ChainBackend: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for crate::model::Config {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_liquidElectrumUrl = <String>::sse_decode(deserializer);
         let mut var_bitcoinElectrumUrl = <String>::sse_decode(deserializer);
         let mut var_mempoolspaceUrl = <String>::sse_decode(deserializer);
+        let mut var_liquidEsploraUrl = <String>::sse_decode(deserializer);
+        let mut var_bitcoinEsploraUrl = <String>::sse_decode(deserializer);
+        let mut var_chainBackend = <crate::model::ChainBackend>::sse_decode(deserializer);
+        let mut var_breezServerUrl = <String>::sse_decode(deserializer);
+        let mut var_boltzUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_referralId = <Option<String>>::sse_decode(deserializer);
         let mut var_workingDir = <String>::sse_decode(deserializer);
         let mut var_network = <crate::model::LiquidNetwork>::sse_decode(deserializer);
         let mut var_paymentTimeoutSec = <u64>::sse_decode(deserializer);
         let mut var_zeroConfMinFeeRateMsat = <u32>::sse_decode(deserializer);
         let mut var_zeroConfMaxAmountSat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_assetMetadata =
+            <Option<Vec<crate::model::AssetMetadata>>>::sse_decode(deserializer);
+        let mut var_fiatCurrencies = <Option<Vec<String>>>::sse_decode(deserializer);
+        let mut var_proxyUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_autoRefund = <Option<crate::model::AutoRefundPolicy>>::sse_decode(deserializer);
+        let mut var_amountlessFeeToleranceSat = <Option<u64>>::sse_decode(deserializer);
         return crate::model::Config {
             liquid_electrum_url: var_liquidElectrumUrl,
             bitcoin_electrum_url: var_bitcoinElectrumUrl,
             mempoolspace_url: var_mempoolspaceUrl,
+            liquid_esplora_url: var_liquidEsploraUrl,
+            bitcoin_esplora_url: var_bitcoinEsploraUrl,
+            chain_backend: var_chainBackend,
+            breez_server_url: var_breezServerUrl,
+            boltz_url: var_boltzUrl,
+            referral_id: var_referralId,
             working_dir: var_workingDir,
             network: var_network,
             payment_timeout_sec: var_paymentTimeoutSec,
             zero_conf_min_fee_rate_msat: var_zeroConfMinFeeRateMsat,
             zero_conf_max_amount_sat: var_zeroConfMaxAmountSat,
+            asset_metadata: var_assetMetadata,
+            fiat_currencies: var_fiatCurrencies,
+            proxy_url: var_proxyUrl,
+            auto_refund: var_autoRefund,
+            amountless_fee_tolerance_sat: var_amountlessFeeToleranceSat,
         };
     }
 }
@@ -2063,11 +2955,13 @@ impl SseDecode for crate::model::Config {
 impl SseDecode for crate::model::ConnectRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_mnemonic = <String>::sse_decode(deserializer);
+        let mut var_mnemonic = <Option<String>>::sse_decode(deserializer);
         let mut var_config = <crate::model::Config>::sse_decode(deserializer);
+        let mut var_watchOnlyDescriptor = <Option<String>>::sse_decode(deserializer);
         return crate::model::ConnectRequest {
             mnemonic: var_mnemonic,
             config: var_config,
+            watch_only_descriptor: var_watchOnlyDescriptor,
         };
     }
 }
@@ -2095,6 +2989,48 @@ impl SseDecode for crate::bindings::CurrencyInfo {
     }
 }
 
+impl SseDecode for crate::model::DeletePaymentMetadataRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_paymentId = <String>::sse_decode(deserializer);
+        let mut var_key = <Option<String>>::sse_decode(deserializer);
+        return crate::model::DeletePaymentMetadataRequest {
+            payment_id: var_paymentId,
+            key: var_key,
+        };
+    }
+}
+
+impl SseDecode for crate::model::ExportFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::model::ExportFormat::Csv,
+            1 => crate::model::ExportFormat::Json,
+            _ => unreachable!("Invalid variant for ExportFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::model::ExportPaymentsRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_exportPath = <String>::sse_decode(deserializer);
+        let mut var_format = <crate::model::ExportFormat>::sse_decode(deserializer);
+        let mut var_fiatCurrency = <Option<String>>::sse_decode(deserializer);
+        let mut var_fromTimestamp = <Option<i64>>::sse_decode(deserializer);
+        let mut var_toTimestamp = <Option<i64>>::sse_decode(deserializer);
+        return crate::model::ExportPaymentsRequest {
+            export_path: var_exportPath,
+            format: var_format,
+            fiat_currency: var_fiatCurrency,
+            from_timestamp: var_fromTimestamp,
+            to_timestamp: var_toTimestamp,
+        };
+    }
+}
+
 impl SseDecode for f64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2102,6 +3038,32 @@ impl SseDecode for f64 {
     }
 }
 
+impl SseDecode for crate::model::FetchPaymentProposedFeesRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_swapId = <String>::sse_decode(deserializer);
+        return crate::model::FetchPaymentProposedFeesRequest {
+            swap_id: var_swapId,
+        };
+    }
+}
+
+impl SseDecode for crate::model::FetchPaymentProposedFeesResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_swapId = <String>::sse_decode(deserializer);
+        let mut var_feesSat = <u64>::sse_decode(deserializer);
+        let mut var_payerAmountSat = <u64>::sse_decode(deserializer);
+        let mut var_receiverAmountSat = <u64>::sse_decode(deserializer);
+        return crate::model::FetchPaymentProposedFeesResponse {
+            swap_id: var_swapId,
+            fees_sat: var_feesSat,
+            payer_amount_sat: var_payerAmountSat,
+            receiver_amount_sat: var_receiverAmountSat,
+        };
+    }
+}
+
 impl SseDecode for crate::bindings::FiatCurrency {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2121,11 +3083,15 @@ impl SseDecode for crate::model::GetInfoResponse {
         let mut var_pendingSendSat = <u64>::sse_decode(deserializer);
         let mut var_pendingReceiveSat = <u64>::sse_decode(deserializer);
         let mut var_pubkey = <String>::sse_decode(deserializer);
+        let mut var_assetBalances = <Vec<crate::model::AssetBalance>>::sse_decode(deserializer);
+        let mut var_balanceDetails = <crate::model::BalanceDetails>::sse_decode(deserializer);
         return crate::model::GetInfoResponse {
             balance_sat: var_balanceSat,
             pending_send_sat: var_pendingSendSat,
             pending_receive_sat: var_pendingReceiveSat,
             pubkey: var_pubkey,
+            asset_balances: var_assetBalances,
+            balance_details: var_balanceDetails,
         };
     }
 }
@@ -2230,11 +3196,48 @@ impl SseDecode for crate::model::LiquidNetwork {
         return match inner {
             0 => crate::model::LiquidNetwork::Mainnet,
             1 => crate::model::LiquidNetwork::Testnet,
+            2 => crate::model::LiquidNetwork::Regtest,
             _ => unreachable!("Invalid variant for LiquidNetwork: {}", inner),
         };
     }
 }
 
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::model::AssetBalance> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::AssetBalance>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::model::AssetMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::AssetMetadata>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::bindings::FiatCurrency> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2283,6 +3286,68 @@ impl SseDecode for Vec<crate::model::Payment> {
     }
 }
 
+impl SseDecode for Vec<crate::model::PaymentFiatRate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::PaymentFiatRate>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::model::PaymentKind> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::PaymentKind>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::model::PaymentMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::PaymentMetadata>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::model::PaymentMetadataFilter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::PaymentMetadataFilter>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::model::PaymentState> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::PaymentState>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::model::PaymentType> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2303,12 +3368,41 @@ impl SseDecode for crate::model::ListPaymentsRequest {
         let mut var_toTimestamp = <Option<i64>>::sse_decode(deserializer);
         let mut var_offset = <Option<u32>>::sse_decode(deserializer);
         let mut var_limit = <Option<u32>>::sse_decode(deserializer);
+        let mut var_metadataFilters =
+            <Option<Vec<crate::model::PaymentMetadataFilter>>>::sse_decode(deserializer);
+        let mut var_states = <Option<Vec<crate::model::PaymentState>>>::sse_decode(deserializer);
+        let mut var_kinds = <Option<Vec<crate::model::PaymentKind>>>::sse_decode(deserializer);
+        let mut var_fromAmountSat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_toAmountSat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_assetId = <Option<String>>::sse_decode(deserializer);
+        let mut var_search = <Option<String>>::sse_decode(deserializer);
+        let mut var_cursor = <Option<String>>::sse_decode(deserializer);
         return crate::model::ListPaymentsRequest {
             filters: var_filters,
             from_timestamp: var_fromTimestamp,
             to_timestamp: var_toTimestamp,
             offset: var_offset,
             limit: var_limit,
+            metadata_filters: var_metadataFilters,
+            states: var_states,
+            kinds: var_kinds,
+            from_amount_sat: var_fromAmountSat,
+            to_amount_sat: var_toAmountSat,
+            asset_id: var_assetId,
+            search: var_search,
+            cursor: var_cursor,
+        };
+    }
+}
+
+impl SseDecode for crate::model::ListPaymentsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_payments = <Vec<crate::model::Payment>>::sse_decode(deserializer);
+        let mut var_nextCursor = <Option<String>>::sse_decode(deserializer);
+        return crate::model::ListPaymentsResponse {
+            payments: var_payments,
+            next_cursor: var_nextCursor,
         };
     }
 }
@@ -2405,6 +3499,26 @@ impl SseDecode for crate::bindings::LNInvoice {
     }
 }
 
+impl SseDecode for crate::model::LNOffer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_offer = <String>::sse_decode(deserializer);
+        let mut var_chains = <Vec<String>>::sse_decode(deserializer);
+        let mut var_amountSat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_absoluteExpiry = <Option<u64>>::sse_decode(deserializer);
+        let mut var_issuer = <Option<String>>::sse_decode(deserializer);
+        return crate::model::LNOffer {
+            offer: var_offer,
+            chains: var_chains,
+            amount_sat: var_amountSat,
+            description: var_description,
+            absolute_expiry: var_absoluteExpiry,
+            issuer: var_issuer,
+        };
+    }
+}
+
 impl SseDecode for crate::bindings::duplicates::LnUrlAuthError {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2816,91 +3930,181 @@ impl SseDecode for crate::bindings::Network {
     }
 }
 
-impl SseDecode for crate::model::OnchainPaymentLimitsResponse {
+impl SseDecode for crate::model::OnchainPaymentLimitsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_send = <crate::model::Limits>::sse_decode(deserializer);
+        let mut var_receive = <crate::model::Limits>::sse_decode(deserializer);
+        return crate::model::OnchainPaymentLimitsResponse {
+            send: var_send,
+            receive: var_receive,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::model::AssetMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::model::AssetMetadata>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::model::AutoRefundPolicy> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::model::AutoRefundPolicy>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::model::Payment> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::model::Payment>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::bindings::SuccessActionProcessed> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::bindings::SuccessActionProcessed>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::bindings::Symbol> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_send = <crate::model::Limits>::sse_decode(deserializer);
-        let mut var_receive = <crate::model::Limits>::sse_decode(deserializer);
-        return crate::model::OnchainPaymentLimitsResponse {
-            send: var_send,
-            receive: var_receive,
-        };
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::bindings::Symbol>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseDecode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
+            return Some(<u32>::sse_decode(deserializer));
         } else {
             return None;
         }
     }
 }
 
-impl SseDecode for Option<bool> {
+impl SseDecode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         if (<bool>::sse_decode(deserializer)) {
-            return Some(<bool>::sse_decode(deserializer));
+            return Some(<u64>::sse_decode(deserializer));
         } else {
             return None;
         }
     }
 }
 
-impl SseDecode for Option<i64> {
+impl SseDecode for Option<Vec<String>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         if (<bool>::sse_decode(deserializer)) {
-            return Some(<i64>::sse_decode(deserializer));
+            return Some(<Vec<String>>::sse_decode(deserializer));
         } else {
             return None;
         }
     }
 }
 
-impl SseDecode for Option<crate::bindings::SuccessActionProcessed> {
+impl SseDecode for Option<Vec<crate::model::AssetMetadata>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::bindings::SuccessActionProcessed>::sse_decode(
-                deserializer,
-            ));
+            return Some(<Vec<crate::model::AssetMetadata>>::sse_decode(deserializer));
         } else {
             return None;
         }
     }
 }
 
-impl SseDecode for Option<crate::bindings::Symbol> {
+impl SseDecode for Option<Vec<crate::model::PaymentKind>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::bindings::Symbol>::sse_decode(deserializer));
+            return Some(<Vec<crate::model::PaymentKind>>::sse_decode(deserializer));
         } else {
             return None;
         }
     }
 }
 
-impl SseDecode for Option<u32> {
+impl SseDecode for Option<Vec<crate::model::PaymentMetadataFilter>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         if (<bool>::sse_decode(deserializer)) {
-            return Some(<u32>::sse_decode(deserializer));
+            return Some(<Vec<crate::model::PaymentMetadataFilter>>::sse_decode(
+                deserializer,
+            ));
         } else {
             return None;
         }
     }
 }
 
-impl SseDecode for Option<u64> {
+impl SseDecode for Option<Vec<crate::model::PaymentState>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         if (<bool>::sse_decode(deserializer)) {
-            return Some(<u64>::sse_decode(deserializer));
+            return Some(<Vec<crate::model::PaymentState>>::sse_decode(deserializer));
         } else {
             return None;
         }
@@ -2924,9 +4128,11 @@ impl SseDecode for crate::model::PayOnchainRequest {
         let mut var_address = <String>::sse_decode(deserializer);
         let mut var_prepareRes =
             <crate::model::PreparePayOnchainResponse>::sse_decode(deserializer);
+        let mut var_idempotencyKey = <Option<String>>::sse_decode(deserializer);
         return crate::model::PayOnchainRequest {
             address: var_address,
             prepare_res: var_prepareRes,
+            idempotency_key: var_idempotencyKey,
         };
     }
 }
@@ -2942,10 +4148,14 @@ impl SseDecode for crate::model::Payment {
         let mut var_preimage = <Option<String>>::sse_decode(deserializer);
         let mut var_bolt11 = <Option<String>>::sse_decode(deserializer);
         let mut var_description = <String>::sse_decode(deserializer);
+        let mut var_destination = <Option<String>>::sse_decode(deserializer);
+        let mut var_assetId = <Option<String>>::sse_decode(deserializer);
         let mut var_refundTxId = <Option<String>>::sse_decode(deserializer);
         let mut var_refundTxAmountSat = <Option<u64>>::sse_decode(deserializer);
         let mut var_paymentType = <crate::model::PaymentType>::sse_decode(deserializer);
         let mut var_status = <crate::model::PaymentState>::sse_decode(deserializer);
+        let mut var_metadata = <Vec<crate::model::PaymentMetadata>>::sse_decode(deserializer);
+        let mut var_fiatRates = <Vec<crate::model::PaymentFiatRate>>::sse_decode(deserializer);
         return crate::model::Payment {
             tx_id: var_txId,
             swap_id: var_swapId,
@@ -2955,10 +4165,14 @@ impl SseDecode for crate::model::Payment {
             preimage: var_preimage,
             bolt11: var_bolt11,
             description: var_description,
+            destination: var_destination,
+            asset_id: var_assetId,
             refund_tx_id: var_refundTxId,
             refund_tx_amount_sat: var_refundTxAmountSat,
             payment_type: var_paymentType,
             status: var_status,
+            metadata: var_metadata,
+            fiat_rates: var_fiatRates,
         };
     }
 }
@@ -2982,39 +4196,47 @@ impl SseDecode for crate::error::PaymentError {
             }
             4 => {
                 let mut var_err = <String>::sse_decode(deserializer);
-                return crate::error::PaymentError::Generic { err: var_err };
+                return crate::error::PaymentError::AmountMissing { err: var_err };
             }
             5 => {
-                return crate::error::PaymentError::InvalidOrExpiredFees;
+                let mut var_err = <String>::sse_decode(deserializer);
+                return crate::error::PaymentError::Generic { err: var_err };
             }
             6 => {
-                return crate::error::PaymentError::InsufficientFunds;
+                return crate::error::PaymentError::InvalidOrExpiredFees;
             }
             7 => {
+                return crate::error::PaymentError::InsufficientFunds;
+            }
+            8 => {
                 let mut var_err = <String>::sse_decode(deserializer);
                 return crate::error::PaymentError::InvalidInvoice { err: var_err };
             }
-            8 => {
+            9 => {
+                let mut var_err = <String>::sse_decode(deserializer);
+                return crate::error::PaymentError::InvalidDestination { err: var_err };
+            }
+            10 => {
                 return crate::error::PaymentError::InvalidPreimage;
             }
-            9 => {
+            11 => {
                 let mut var_err = <String>::sse_decode(deserializer);
                 return crate::error::PaymentError::LwkError { err: var_err };
             }
-            10 => {
+            12 => {
                 return crate::error::PaymentError::PairsNotFound;
             }
-            11 => {
+            13 => {
                 return crate::error::PaymentError::PaymentTimeout;
             }
-            12 => {
+            14 => {
                 return crate::error::PaymentError::PersistError;
             }
-            13 => {
+            15 => {
                 let mut var_err = <String>::sse_decode(deserializer);
                 return crate::error::PaymentError::ReceiveError { err: var_err };
             }
-            14 => {
+            16 => {
                 let mut var_err = <String>::sse_decode(deserializer);
                 let mut var_refundTxId = <String>::sse_decode(deserializer);
                 return crate::error::PaymentError::Refunded {
@@ -3022,17 +4244,88 @@ impl SseDecode for crate::error::PaymentError {
                     refund_tx_id: var_refundTxId,
                 };
             }
-            15 => {
+            17 => {
                 return crate::error::PaymentError::SelfTransferNotSupported;
             }
-            16 => {
+            18 => {
                 let mut var_err = <String>::sse_decode(deserializer);
                 return crate::error::PaymentError::SendError { err: var_err };
             }
-            17 => {
+            19 => {
                 let mut var_err = <String>::sse_decode(deserializer);
                 return crate::error::PaymentError::SignerError { err: var_err };
             }
+            20 => {
+                return crate::error::PaymentError::WatchOnly;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::model::PaymentFiatRate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_coin = <String>::sse_decode(deserializer);
+        let mut var_value = <f64>::sse_decode(deserializer);
+        let mut var_createdAt = <u32>::sse_decode(deserializer);
+        return crate::model::PaymentFiatRate {
+            coin: var_coin,
+            value: var_value,
+            created_at: var_createdAt,
+        };
+    }
+}
+
+impl SseDecode for crate::model::PaymentKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::model::PaymentKind::Submarine,
+            1 => crate::model::PaymentKind::Reverse,
+            2 => crate::model::PaymentKind::Chain,
+            3 => crate::model::PaymentKind::Direct,
+            _ => unreachable!("Invalid variant for PaymentKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::model::PaymentLookup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_swapId = <String>::sse_decode(deserializer);
+                return crate::model::PaymentLookup::SwapId {
+                    swap_id: var_swapId,
+                };
+            }
+            1 => {
+                let mut var_invoice = <String>::sse_decode(deserializer);
+                return crate::model::PaymentLookup::Invoice {
+                    invoice: var_invoice,
+                };
+            }
+            2 => {
+                let mut var_paymentHash = <String>::sse_decode(deserializer);
+                return crate::model::PaymentLookup::PaymentHash {
+                    payment_hash: var_paymentHash,
+                };
+            }
+            3 => {
+                let mut var_txId = <String>::sse_decode(deserializer);
+                return crate::model::PaymentLookup::TxId { tx_id: var_txId };
+            }
+            4 => {
+                let mut var_address = <String>::sse_decode(deserializer);
+                return crate::model::PaymentLookup::Destination {
+                    address: var_address,
+                };
+            }
             _ => {
                 unimplemented!("");
             }
@@ -3040,6 +4333,30 @@ impl SseDecode for crate::error::PaymentError {
     }
 }
 
+impl SseDecode for crate::model::PaymentMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        return crate::model::PaymentMetadata {
+            key: var_key,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for crate::model::PaymentMetadataFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_value = <Option<String>>::sse_decode(deserializer);
+        return crate::model::PaymentMetadataFilter {
+            key: var_key,
+            value: var_value,
+        };
+    }
+}
+
 impl SseDecode for crate::model::PaymentState {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3052,6 +4369,7 @@ impl SseDecode for crate::model::PaymentState {
             4 => crate::model::PaymentState::TimedOut,
             5 => crate::model::PaymentState::Refundable,
             6 => crate::model::PaymentState::RefundPending,
+            7 => crate::model::PaymentState::WaitingFeeAcceptance,
             _ => unreachable!("Invalid variant for PaymentState: {}", inner),
         };
     }
@@ -3100,9 +4418,11 @@ impl SseDecode for crate::model::PreparePayOnchainRequest {
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_receiverAmountSat = <u64>::sse_decode(deserializer);
         let mut var_satPerVbyte = <Option<u32>>::sse_decode(deserializer);
+        let mut var_drain = <Option<bool>>::sse_decode(deserializer);
         return crate::model::PreparePayOnchainRequest {
             receiver_amount_sat: var_receiverAmountSat,
             sat_per_vbyte: var_satPerVbyte,
+            drain: var_drain,
         };
     }
 }
@@ -3113,10 +4433,12 @@ impl SseDecode for crate::model::PreparePayOnchainResponse {
         let mut var_receiverAmountSat = <u64>::sse_decode(deserializer);
         let mut var_claimFeesSat = <u64>::sse_decode(deserializer);
         let mut var_totalFeesSat = <u64>::sse_decode(deserializer);
+        let mut var_drain = <bool>::sse_decode(deserializer);
         return crate::model::PreparePayOnchainResponse {
             receiver_amount_sat: var_receiverAmountSat,
             claim_fees_sat: var_claimFeesSat,
             total_fees_sat: var_totalFeesSat,
+            drain: var_drain,
         };
     }
 }
@@ -3124,7 +4446,7 @@ impl SseDecode for crate::model::PreparePayOnchainResponse {
 impl SseDecode for crate::model::PrepareReceiveOnchainRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_payerAmountSat = <u64>::sse_decode(deserializer);
+        let mut var_payerAmountSat = <Option<u64>>::sse_decode(deserializer);
         return crate::model::PrepareReceiveOnchainRequest {
             payer_amount_sat: var_payerAmountSat,
         };
@@ -3134,11 +4456,13 @@ impl SseDecode for crate::model::PrepareReceiveOnchainRequest {
 impl SseDecode for crate::model::PrepareReceiveOnchainResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_payerAmountSat = <u64>::sse_decode(deserializer);
+        let mut var_payerAmountSat = <Option<u64>>::sse_decode(deserializer);
         let mut var_feesSat = <u64>::sse_decode(deserializer);
+        let mut var_swapperFeerate = <f64>::sse_decode(deserializer);
         return crate::model::PrepareReceiveOnchainResponse {
             payer_amount_sat: var_payerAmountSat,
             fees_sat: var_feesSat,
+            swapper_feerate: var_swapperFeerate,
         };
     }
 }
@@ -3193,12 +4517,54 @@ impl SseDecode for crate::model::PrepareRefundResponse {
     }
 }
 
+impl SseDecode for crate::model::PrepareSendLiquidRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_destination = <String>::sse_decode(deserializer);
+        let mut var_amountSat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_drain = <Option<bool>>::sse_decode(deserializer);
+        let mut var_assetId = <Option<String>>::sse_decode(deserializer);
+        return crate::model::PrepareSendLiquidRequest {
+            destination: var_destination,
+            amount_sat: var_amountSat,
+            drain: var_drain,
+            asset_id: var_assetId,
+        };
+    }
+}
+
+impl SseDecode for crate::model::PrepareSendLiquidResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_receiverAmountSat = <u64>::sse_decode(deserializer);
+        let mut var_feesSat = <u64>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_drain = <bool>::sse_decode(deserializer);
+        let mut var_assetId = <Option<String>>::sse_decode(deserializer);
+        return crate::model::PrepareSendLiquidResponse {
+            address: var_address,
+            receiver_amount_sat: var_receiverAmountSat,
+            fees_sat: var_feesSat,
+            description: var_description,
+            drain: var_drain,
+            asset_id: var_assetId,
+        };
+    }
+}
+
 impl SseDecode for crate::model::PrepareSendRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_invoice = <String>::sse_decode(deserializer);
+        let mut var_amountSat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_idempotencyKey = <Option<String>>::sse_decode(deserializer);
+        let mut var_payerNote = <Option<String>>::sse_decode(deserializer);
         return crate::model::PrepareSendRequest {
             invoice: var_invoice,
+            amount_sat: var_amountSat,
+            idempotency_key: var_idempotencyKey,
+            payer_note: var_payerNote,
         };
     }
 }
@@ -3207,10 +4573,16 @@ impl SseDecode for crate::model::PrepareSendResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_invoice = <String>::sse_decode(deserializer);
+        let mut var_offer = <Option<String>>::sse_decode(deserializer);
+        let mut var_receiverAmountSat = <u64>::sse_decode(deserializer);
         let mut var_feesSat = <u64>::sse_decode(deserializer);
+        let mut var_idempotencyKey = <Option<String>>::sse_decode(deserializer);
         return crate::model::PrepareSendResponse {
             invoice: var_invoice,
+            offer: var_offer,
+            receiver_amount_sat: var_receiverAmountSat,
             fees_sat: var_feesSat,
+            idempotency_key: var_idempotencyKey,
         };
     }
 }
@@ -3227,6 +4599,32 @@ impl SseDecode for crate::bindings::Rate {
     }
 }
 
+impl SseDecode for crate::model::ReceiveLiquidRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_amountSat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_assetId = <Option<String>>::sse_decode(deserializer);
+        return crate::model::ReceiveLiquidRequest {
+            amount_sat: var_amountSat,
+            description: var_description,
+            asset_id: var_assetId,
+        };
+    }
+}
+
+impl SseDecode for crate::model::ReceiveLiquidResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_bip21 = <String>::sse_decode(deserializer);
+        return crate::model::ReceiveLiquidResponse {
+            address: var_address,
+            bip21: var_bip21,
+        };
+    }
+}
+
 impl SseDecode for crate::model::ReceiveOnchainResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3282,6 +4680,43 @@ impl SseDecode for crate::model::RecommendedFees {
     }
 }
 
+impl SseDecode for crate::model::RefundDestination {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_address = <String>::sse_decode(deserializer);
+                return crate::model::RefundDestination::Address {
+                    address: var_address,
+                };
+            }
+            1 => {
+                let mut var_xpub = <String>::sse_decode(deserializer);
+                return crate::model::RefundDestination::Xpub { xpub: var_xpub };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::model::RefundFeeRate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::model::RefundFeeRate::Fastest,
+            1 => crate::model::RefundFeeRate::HalfHour,
+            2 => crate::model::RefundFeeRate::Hour,
+            3 => crate::model::RefundFeeRate::Economy,
+            4 => crate::model::RefundFeeRate::Minimum,
+            _ => unreachable!("Invalid variant for RefundFeeRate: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for crate::model::RefundRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3416,20 +4851,100 @@ impl SseDecode for crate::model::SdkEvent {
                 };
             }
             4 => {
-                let mut var_details = <crate::model::Payment>::sse_decode(deserializer);
-                return crate::model::SdkEvent::PaymentSucceeded {
-                    details: var_details,
+                let mut var_details = <crate::model::Payment>::sse_decode(deserializer);
+                return crate::model::SdkEvent::PaymentSucceeded {
+                    details: var_details,
+                };
+            }
+            5 => {
+                let mut var_details = <crate::model::Payment>::sse_decode(deserializer);
+                return crate::model::SdkEvent::PaymentWaitingConfirmation {
+                    details: var_details,
+                };
+            }
+            6 => {
+                let mut var_details = <crate::model::AutoRefundDetails>::sse_decode(deserializer);
+                return crate::model::SdkEvent::SwapAutoRefunded {
+                    details: var_details,
+                };
+            }
+            7 => {
+                let mut var_swapId = <String>::sse_decode(deserializer);
+                return crate::model::SdkEvent::SwapWaitingFeeAcceptance {
+                    swap_id: var_swapId,
+                };
+            }
+            8 => {
+                return crate::model::SdkEvent::Synced;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::model::SendPaymentResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_payment = <crate::model::Payment>::sse_decode(deserializer);
+        return crate::model::SendPaymentResponse {
+            payment: var_payment,
+        };
+    }
+}
+
+impl SseDecode for crate::model::SetPaymentMetadataRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_paymentId = <String>::sse_decode(deserializer);
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        return crate::model::SetPaymentMetadataRequest {
+            payment_id: var_paymentId,
+            key: var_key,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for crate::model::SignerMethod {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::model::SignerMethod::Xpub;
+            }
+            1 => {
+                let mut var_derivationPath = <String>::sse_decode(deserializer);
+                return crate::model::SignerMethod::DeriveXpub {
+                    derivation_path: var_derivationPath,
+                };
+            }
+            2 => {
+                return crate::model::SignerMethod::Slip77MasterBlindingKey;
+            }
+            3 => {
+                let mut var_pset = <String>::sse_decode(deserializer);
+                return crate::model::SignerMethod::SignPset { pset: var_pset };
+            }
+            4 => {
+                let mut var_msg = <Vec<u8>>::sse_decode(deserializer);
+                let mut var_derivationPath = <String>::sse_decode(deserializer);
+                return crate::model::SignerMethod::SignEcdsa {
+                    msg: var_msg,
+                    derivation_path: var_derivationPath,
                 };
             }
             5 => {
-                let mut var_details = <crate::model::Payment>::sse_decode(deserializer);
-                return crate::model::SdkEvent::PaymentWaitingConfirmation {
-                    details: var_details,
+                let mut var_msg = <Vec<u8>>::sse_decode(deserializer);
+                let mut var_derivationPath = <String>::sse_decode(deserializer);
+                return crate::model::SignerMethod::HmacSha256 {
+                    msg: var_msg,
+                    derivation_path: var_derivationPath,
                 };
             }
-            6 => {
-                return crate::model::SdkEvent::Synced;
-            }
             _ => {
                 unimplemented!("");
             }
@@ -3437,16 +4952,42 @@ impl SseDecode for crate::model::SdkEvent {
     }
 }
 
-impl SseDecode for crate::model::SendPaymentResponse {
+impl SseDecode for crate::model::SignerRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_payment = <crate::model::Payment>::sse_decode(deserializer);
-        return crate::model::SendPaymentResponse {
-            payment: var_payment,
+        let mut var_id = <u32>::sse_decode(deserializer);
+        let mut var_method = <crate::model::SignerMethod>::sse_decode(deserializer);
+        return crate::model::SignerRequest {
+            id: var_id,
+            method: var_method,
         };
     }
 }
 
+impl SseDecode for crate::model::SignerResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_value = <String>::sse_decode(deserializer);
+                return crate::model::SignerResult::String { value: var_value };
+            }
+            1 => {
+                let mut var_value = <Vec<u8>>::sse_decode(deserializer);
+                return crate::model::SignerResult::Bytes { value: var_value };
+            }
+            2 => {
+                let mut var_err = <String>::sse_decode(deserializer);
+                return crate::model::SignerResult::Error { err: var_err };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseDecode for crate::bindings::SuccessActionProcessed {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3586,6 +5127,23 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<BindingLiquidSdk>> for Binding
     }
 }
 
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::AcceptPaymentProposedFeesRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.response.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::AcceptPaymentProposedFeesRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::AcceptPaymentProposedFeesRequest>
+    for crate::model::AcceptPaymentProposedFeesRequest
+{
+    fn into_into_dart(self) -> crate::model::AcceptPaymentProposedFeesRequest {
+        self
+    }
+}
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::bindings::AesSuccessActionDataDecrypted> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
@@ -3635,6 +5193,88 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::bindings::AesSuccessAct
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::AssetBalance {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.asset_id.into_into_dart().into_dart(),
+            self.balance.into_into_dart().into_dart(),
+            self.pending_send.into_into_dart().into_dart(),
+            self.pending_receive.into_into_dart().into_dart(),
+            self.asset_metadata.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::AssetBalance {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::AssetBalance> for crate::model::AssetBalance {
+    fn into_into_dart(self) -> crate::model::AssetBalance {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::AssetMetadata {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.asset_id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.ticker.into_into_dart().into_dart(),
+            self.precision.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::AssetMetadata {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::AssetMetadata>
+    for crate::model::AssetMetadata
+{
+    fn into_into_dart(self) -> crate::model::AssetMetadata {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::AutoRefundDetails {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.swap_address.into_into_dart().into_dart(),
+            self.refund_address.into_into_dart().into_dart(),
+            self.refund_tx_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::AutoRefundDetails
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::AutoRefundDetails>
+    for crate::model::AutoRefundDetails
+{
+    fn into_into_dart(self) -> crate::model::AutoRefundDetails {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::AutoRefundPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.destination.into_into_dart().into_dart(),
+            self.fee_rate.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::AutoRefundPolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::AutoRefundPolicy>
+    for crate::model::AutoRefundPolicy
+{
+    fn into_into_dart(self) -> crate::model::AutoRefundPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::model::BackupRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [self.backup_path.into_into_dart().into_dart()].into_dart()
@@ -3649,6 +5289,30 @@ impl flutter_rust_bridge::IntoIntoDart<crate::model::BackupRequest>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::BalanceDetails {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.confirmed_sat.into_into_dart().into_dart(),
+            self.unconfirmed_receive_sat.into_into_dart().into_dart(),
+            self.unconfirmed_send_sat.into_into_dart().into_dart(),
+            self.pending_send_swaps_sat.into_into_dart().into_dart(),
+            self.pending_receive_swaps_sat.into_into_dart().into_dart(),
+            self.pending_refund_sat.into_into_dart().into_dart(),
+            self.refundable_btc_sat.into_into_dart().into_dart(),
+            self.pending_btc_refund_sat.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::BalanceDetails {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::BalanceDetails>
+    for crate::model::BalanceDetails
+{
+    fn into_into_dart(self) -> crate::model::BalanceDetails {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::bindings::BindingEventListener {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [self.stream.into_into_dart().into_dart()].into_dart()
@@ -3690,6 +5354,50 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::bindings::BitcoinAddres
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::BumpRefundFeeRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.swap_address.into_into_dart().into_dart(),
+            self.sat_per_vbyte.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::BumpRefundFeeRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::BumpRefundFeeRequest>
+    for crate::model::BumpRefundFeeRequest
+{
+    fn into_into_dart(self) -> crate::model::BumpRefundFeeRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::BumpRefundFeeResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.refund_tx_id.into_into_dart().into_dart(),
+            self.replaced_tx_id.into_into_dart().into_dart(),
+            self.tx_fee_sat.into_into_dart().into_dart(),
+            self.fee_delta_sat.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::BumpRefundFeeResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::BumpRefundFeeResponse>
+    for crate::model::BumpRefundFeeResponse
+{
+    fn into_into_dart(self) -> crate::model::BumpRefundFeeResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::model::BuyBitcoinProvider {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -3731,12 +5439,34 @@ impl flutter_rust_bridge::IntoIntoDart<crate::model::BuyBitcoinRequest>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::ChainBackend {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Electrum => 0.into_dart(),
+            Self::Esplora => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::ChainBackend {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::ChainBackend> for crate::model::ChainBackend {
+    fn into_into_dart(self) -> crate::model::ChainBackend {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::model::Config {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.liquid_electrum_url.into_into_dart().into_dart(),
             self.bitcoin_electrum_url.into_into_dart().into_dart(),
             self.mempoolspace_url.into_into_dart().into_dart(),
+            self.liquid_esplora_url.into_into_dart().into_dart(),
+            self.bitcoin_esplora_url.into_into_dart().into_dart(),
+            self.chain_backend.into_into_dart().into_dart(),
+            self.breez_server_url.into_into_dart().into_dart(),
+            self.boltz_url.into_into_dart().into_dart(),
+            self.referral_id.into_into_dart().into_dart(),
             self.working_dir.into_into_dart().into_dart(),
             self.network.into_into_dart().into_dart(),
             self.payment_timeout_sec.into_into_dart().into_dart(),
@@ -3744,6 +5474,13 @@ impl flutter_rust_bridge::IntoDart for crate::model::Config {
                 .into_into_dart()
                 .into_dart(),
             self.zero_conf_max_amount_sat.into_into_dart().into_dart(),
+            self.asset_metadata.into_into_dart().into_dart(),
+            self.fiat_currencies.into_into_dart().into_dart(),
+            self.proxy_url.into_into_dart().into_dart(),
+            self.auto_refund.into_into_dart().into_dart(),
+            self.amountless_fee_tolerance_sat
+                .into_into_dart()
+                .into_dart(),
         ]
         .into_dart()
     }
@@ -3760,6 +5497,7 @@ impl flutter_rust_bridge::IntoDart for crate::model::ConnectRequest {
         [
             self.mnemonic.into_into_dart().into_dart(),
             self.config.into_into_dart().into_dart(),
+            self.watch_only_descriptor.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -3799,6 +5537,107 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::bindings::CurrencyInfo>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::DeletePaymentMetadataRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.payment_id.into_into_dart().into_dart(),
+            self.key.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::DeletePaymentMetadataRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::DeletePaymentMetadataRequest>
+    for crate::model::DeletePaymentMetadataRequest
+{
+    fn into_into_dart(self) -> crate::model::DeletePaymentMetadataRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::ExportFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Csv => 0.into_dart(),
+            Self::Json => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::ExportFormat {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::ExportFormat> for crate::model::ExportFormat {
+    fn into_into_dart(self) -> crate::model::ExportFormat {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::ExportPaymentsRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.export_path.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.fiat_currency.into_into_dart().into_dart(),
+            self.from_timestamp.into_into_dart().into_dart(),
+            self.to_timestamp.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::ExportPaymentsRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::ExportPaymentsRequest>
+    for crate::model::ExportPaymentsRequest
+{
+    fn into_into_dart(self) -> crate::model::ExportPaymentsRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::FetchPaymentProposedFeesRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.swap_id.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::FetchPaymentProposedFeesRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::FetchPaymentProposedFeesRequest>
+    for crate::model::FetchPaymentProposedFeesRequest
+{
+    fn into_into_dart(self) -> crate::model::FetchPaymentProposedFeesRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::FetchPaymentProposedFeesResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.swap_id.into_into_dart().into_dart(),
+            self.fees_sat.into_into_dart().into_dart(),
+            self.payer_amount_sat.into_into_dart().into_dart(),
+            self.receiver_amount_sat.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::FetchPaymentProposedFeesResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::FetchPaymentProposedFeesResponse>
+    for crate::model::FetchPaymentProposedFeesResponse
+{
+    fn into_into_dart(self) -> crate::model::FetchPaymentProposedFeesResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::bindings::FiatCurrency> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -3827,6 +5666,8 @@ impl flutter_rust_bridge::IntoDart for crate::model::GetInfoResponse {
             self.pending_send_sat.into_into_dart().into_dart(),
             self.pending_receive_sat.into_into_dart().into_dart(),
             self.pubkey.into_into_dart().into_dart(),
+            self.asset_balances.into_into_dart().into_dart(),
+            self.balance_details.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -3928,6 +5769,7 @@ impl flutter_rust_bridge::IntoDart for crate::model::LiquidNetwork {
         match self {
             Self::Mainnet => 0.into_dart(),
             Self::Testnet => 1.into_dart(),
+            Self::Regtest => 2.into_dart(),
             _ => unreachable!(),
         }
     }
@@ -3949,6 +5791,14 @@ impl flutter_rust_bridge::IntoDart for crate::model::ListPaymentsRequest {
             self.to_timestamp.into_into_dart().into_dart(),
             self.offset.into_into_dart().into_dart(),
             self.limit.into_into_dart().into_dart(),
+            self.metadata_filters.into_into_dart().into_dart(),
+            self.states.into_into_dart().into_dart(),
+            self.kinds.into_into_dart().into_dart(),
+            self.from_amount_sat.into_into_dart().into_dart(),
+            self.to_amount_sat.into_into_dart().into_dart(),
+            self.asset_id.into_into_dart().into_dart(),
+            self.search.into_into_dart().into_dart(),
+            self.cursor.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -3965,6 +5815,27 @@ impl flutter_rust_bridge::IntoIntoDart<crate::model::ListPaymentsRequest>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::ListPaymentsResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.payments.into_into_dart().into_dart(),
+            self.next_cursor.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::ListPaymentsResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::ListPaymentsResponse>
+    for crate::model::ListPaymentsResponse
+{
+    fn into_into_dart(self) -> crate::model::ListPaymentsResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::bindings::LNInvoice> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -3987,15 +5858,35 @@ impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::bindings::LNInvoice> {
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<crate::bindings::LNInvoice>
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::bindings::LNInvoice>>
-    for crate::bindings::LNInvoice
-{
-    fn into_into_dart(self) -> FrbWrapper<crate::bindings::LNInvoice> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<crate::bindings::LNInvoice>
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::bindings::LNInvoice>>
+    for crate::bindings::LNInvoice
+{
+    fn into_into_dart(self) -> FrbWrapper<crate::bindings::LNInvoice> {
+        self.into()
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::LNOffer {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.offer.into_into_dart().into_dart(),
+            self.chains.into_into_dart().into_dart(),
+            self.amount_sat.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.absolute_expiry.into_into_dart().into_dart(),
+            self.issuer.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::LNOffer {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::LNOffer> for crate::model::LNOffer {
+    fn into_into_dart(self) -> crate::model::LNOffer {
+        self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4530,6 +6421,7 @@ impl flutter_rust_bridge::IntoDart for crate::model::PayOnchainRequest {
         [
             self.address.into_into_dart().into_dart(),
             self.prepare_res.into_into_dart().into_dart(),
+            self.idempotency_key.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -4557,10 +6449,14 @@ impl flutter_rust_bridge::IntoDart for crate::model::Payment {
             self.preimage.into_into_dart().into_dart(),
             self.bolt11.into_into_dart().into_dart(),
             self.description.into_into_dart().into_dart(),
+            self.destination.into_into_dart().into_dart(),
+            self.asset_id.into_into_dart().into_dart(),
             self.refund_tx_id.into_into_dart().into_dart(),
             self.refund_tx_amount_sat.into_into_dart().into_dart(),
             self.payment_type.into_into_dart().into_dart(),
             self.status.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.fiat_rates.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -4579,37 +6475,44 @@ impl flutter_rust_bridge::IntoDart for crate::error::PaymentError {
             crate::error::PaymentError::AlreadyPaid => [1.into_dart()].into_dart(),
             crate::error::PaymentError::PaymentInProgress => [2.into_dart()].into_dart(),
             crate::error::PaymentError::AmountOutOfRange => [3.into_dart()].into_dart(),
-            crate::error::PaymentError::Generic { err } => {
+            crate::error::PaymentError::AmountMissing { err } => {
                 [4.into_dart(), err.into_into_dart().into_dart()].into_dart()
             }
-            crate::error::PaymentError::InvalidOrExpiredFees => [5.into_dart()].into_dart(),
-            crate::error::PaymentError::InsufficientFunds => [6.into_dart()].into_dart(),
+            crate::error::PaymentError::Generic { err } => {
+                [5.into_dart(), err.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::PaymentError::InvalidOrExpiredFees => [6.into_dart()].into_dart(),
+            crate::error::PaymentError::InsufficientFunds => [7.into_dart()].into_dart(),
             crate::error::PaymentError::InvalidInvoice { err } => {
-                [7.into_dart(), err.into_into_dart().into_dart()].into_dart()
+                [8.into_dart(), err.into_into_dart().into_dart()].into_dart()
             }
-            crate::error::PaymentError::InvalidPreimage => [8.into_dart()].into_dart(),
-            crate::error::PaymentError::LwkError { err } => {
+            crate::error::PaymentError::InvalidDestination { err } => {
                 [9.into_dart(), err.into_into_dart().into_dart()].into_dart()
             }
-            crate::error::PaymentError::PairsNotFound => [10.into_dart()].into_dart(),
-            crate::error::PaymentError::PaymentTimeout => [11.into_dart()].into_dart(),
-            crate::error::PaymentError::PersistError => [12.into_dart()].into_dart(),
+            crate::error::PaymentError::InvalidPreimage => [10.into_dart()].into_dart(),
+            crate::error::PaymentError::LwkError { err } => {
+                [11.into_dart(), err.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::PaymentError::PairsNotFound => [12.into_dart()].into_dart(),
+            crate::error::PaymentError::PaymentTimeout => [13.into_dart()].into_dart(),
+            crate::error::PaymentError::PersistError => [14.into_dart()].into_dart(),
             crate::error::PaymentError::ReceiveError { err } => {
-                [13.into_dart(), err.into_into_dart().into_dart()].into_dart()
+                [15.into_dart(), err.into_into_dart().into_dart()].into_dart()
             }
             crate::error::PaymentError::Refunded { err, refund_tx_id } => [
-                14.into_dart(),
+                16.into_dart(),
                 err.into_into_dart().into_dart(),
                 refund_tx_id.into_into_dart().into_dart(),
             ]
             .into_dart(),
-            crate::error::PaymentError::SelfTransferNotSupported => [15.into_dart()].into_dart(),
+            crate::error::PaymentError::SelfTransferNotSupported => [17.into_dart()].into_dart(),
             crate::error::PaymentError::SendError { err } => {
-                [16.into_dart(), err.into_into_dart().into_dart()].into_dart()
+                [18.into_dart(), err.into_into_dart().into_dart()].into_dart()
             }
             crate::error::PaymentError::SignerError { err } => {
-                [17.into_dart(), err.into_into_dart().into_dart()].into_dart()
+                [19.into_dart(), err.into_into_dart().into_dart()].into_dart()
             }
+            crate::error::PaymentError::WatchOnly => [20.into_dart()].into_dart(),
             _ => {
                 unimplemented!("");
             }
@@ -4623,6 +6526,115 @@ impl flutter_rust_bridge::IntoIntoDart<crate::error::PaymentError> for crate::er
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::PaymentFiatRate {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.coin.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::PaymentFiatRate {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PaymentFiatRate>
+    for crate::model::PaymentFiatRate
+{
+    fn into_into_dart(self) -> crate::model::PaymentFiatRate {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::PaymentKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Submarine => 0.into_dart(),
+            Self::Reverse => 1.into_dart(),
+            Self::Chain => 2.into_dart(),
+            Self::Direct => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::PaymentKind {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PaymentKind> for crate::model::PaymentKind {
+    fn into_into_dart(self) -> crate::model::PaymentKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::PaymentLookup {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::model::PaymentLookup::SwapId { swap_id } => {
+                [0.into_dart(), swap_id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::model::PaymentLookup::Invoice { invoice } => {
+                [1.into_dart(), invoice.into_into_dart().into_dart()].into_dart()
+            }
+            crate::model::PaymentLookup::PaymentHash { payment_hash } => {
+                [2.into_dart(), payment_hash.into_into_dart().into_dart()].into_dart()
+            }
+            crate::model::PaymentLookup::TxId { tx_id } => {
+                [3.into_dart(), tx_id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::model::PaymentLookup::Destination { address } => {
+                [4.into_dart(), address.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::PaymentLookup {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PaymentLookup>
+    for crate::model::PaymentLookup
+{
+    fn into_into_dart(self) -> crate::model::PaymentLookup {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::PaymentMetadata {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::PaymentMetadata {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PaymentMetadata>
+    for crate::model::PaymentMetadata
+{
+    fn into_into_dart(self) -> crate::model::PaymentMetadata {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::PaymentMetadataFilter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::PaymentMetadataFilter
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PaymentMetadataFilter>
+    for crate::model::PaymentMetadataFilter
+{
+    fn into_into_dart(self) -> crate::model::PaymentMetadataFilter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::model::PaymentState {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -4633,6 +6645,7 @@ impl flutter_rust_bridge::IntoDart for crate::model::PaymentState {
             Self::TimedOut => 4.into_dart(),
             Self::Refundable => 5.into_dart(),
             Self::RefundPending => 6.into_dart(),
+            Self::WaitingFeeAcceptance => 7.into_dart(),
             _ => unreachable!(),
         }
     }
@@ -4708,6 +6721,7 @@ impl flutter_rust_bridge::IntoDart for crate::model::PreparePayOnchainRequest {
         [
             self.receiver_amount_sat.into_into_dart().into_dart(),
             self.sat_per_vbyte.into_into_dart().into_dart(),
+            self.drain.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -4730,6 +6744,7 @@ impl flutter_rust_bridge::IntoDart for crate::model::PreparePayOnchainResponse {
             self.receiver_amount_sat.into_into_dart().into_dart(),
             self.claim_fees_sat.into_into_dart().into_dart(),
             self.total_fees_sat.into_into_dart().into_dart(),
+            self.drain.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -4768,6 +6783,7 @@ impl flutter_rust_bridge::IntoDart for crate::model::PrepareReceiveOnchainRespon
         [
             self.payer_amount_sat.into_into_dart().into_dart(),
             self.fees_sat.into_into_dart().into_dart(),
+            self.swapper_feerate.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -4866,9 +6882,63 @@ impl flutter_rust_bridge::IntoIntoDart<crate::model::PrepareRefundResponse>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::PrepareSendLiquidRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.destination.into_into_dart().into_dart(),
+            self.amount_sat.into_into_dart().into_dart(),
+            self.drain.into_into_dart().into_dart(),
+            self.asset_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::PrepareSendLiquidRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PrepareSendLiquidRequest>
+    for crate::model::PrepareSendLiquidRequest
+{
+    fn into_into_dart(self) -> crate::model::PrepareSendLiquidRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::PrepareSendLiquidResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.address.into_into_dart().into_dart(),
+            self.receiver_amount_sat.into_into_dart().into_dart(),
+            self.fees_sat.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.drain.into_into_dart().into_dart(),
+            self.asset_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::PrepareSendLiquidResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PrepareSendLiquidResponse>
+    for crate::model::PrepareSendLiquidResponse
+{
+    fn into_into_dart(self) -> crate::model::PrepareSendLiquidResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::model::PrepareSendRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.invoice.into_into_dart().into_dart()].into_dart()
+        [
+            self.invoice.into_into_dart().into_dart(),
+            self.amount_sat.into_into_dart().into_dart(),
+            self.idempotency_key.into_into_dart().into_dart(),
+            self.payer_note.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
@@ -4887,7 +6957,10 @@ impl flutter_rust_bridge::IntoDart for crate::model::PrepareSendResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.invoice.into_into_dart().into_dart(),
+            self.offer.into_into_dart().into_dart(),
+            self.receiver_amount_sat.into_into_dart().into_dart(),
             self.fees_sat.into_into_dart().into_dart(),
+            self.idempotency_key.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -4925,6 +6998,49 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<crate::bindings::Rate>>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::ReceiveLiquidRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.amount_sat.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.asset_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::ReceiveLiquidRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::ReceiveLiquidRequest>
+    for crate::model::ReceiveLiquidRequest
+{
+    fn into_into_dart(self) -> crate::model::ReceiveLiquidRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::ReceiveLiquidResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.address.into_into_dart().into_dart(),
+            self.bip21.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::ReceiveLiquidResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::ReceiveLiquidResponse>
+    for crate::model::ReceiveLiquidResponse
+{
+    fn into_into_dart(self) -> crate::model::ReceiveLiquidResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::model::ReceiveOnchainResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -5009,6 +7125,54 @@ impl flutter_rust_bridge::IntoIntoDart<crate::model::RecommendedFees>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::RefundDestination {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::model::RefundDestination::Address { address } => {
+                [0.into_dart(), address.into_into_dart().into_dart()].into_dart()
+            }
+            crate::model::RefundDestination::Xpub { xpub } => {
+                [1.into_dart(), xpub.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::RefundDestination
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::RefundDestination>
+    for crate::model::RefundDestination
+{
+    fn into_into_dart(self) -> crate::model::RefundDestination {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::RefundFeeRate {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Fastest => 0.into_dart(),
+            Self::HalfHour => 1.into_dart(),
+            Self::Hour => 2.into_dart(),
+            Self::Economy => 3.into_dart(),
+            Self::Minimum => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::RefundFeeRate {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::RefundFeeRate>
+    for crate::model::RefundFeeRate
+{
+    fn into_into_dart(self) -> crate::model::RefundFeeRate {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::model::RefundRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -5166,7 +7330,13 @@ impl flutter_rust_bridge::IntoDart for crate::model::SdkEvent {
             crate::model::SdkEvent::PaymentWaitingConfirmation { details } => {
                 [5.into_dart(), details.into_into_dart().into_dart()].into_dart()
             }
-            crate::model::SdkEvent::Synced => [6.into_dart()].into_dart(),
+            crate::model::SdkEvent::SwapAutoRefunded { details } => {
+                [6.into_dart(), details.into_into_dart().into_dart()].into_dart()
+            }
+            crate::model::SdkEvent::SwapWaitingFeeAcceptance { swap_id } => {
+                [7.into_dart(), swap_id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::model::SdkEvent::Synced => [8.into_dart()].into_dart(),
             _ => {
                 unimplemented!("");
             }
@@ -5197,6 +7367,113 @@ impl flutter_rust_bridge::IntoIntoDart<crate::model::SendPaymentResponse>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::SetPaymentMetadataRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.payment_id.into_into_dart().into_dart(),
+            self.key.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::SetPaymentMetadataRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::SetPaymentMetadataRequest>
+    for crate::model::SetPaymentMetadataRequest
+{
+    fn into_into_dart(self) -> crate::model::SetPaymentMetadataRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::SignerMethod {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::model::SignerMethod::Xpub => [0.into_dart()].into_dart(),
+            crate::model::SignerMethod::DeriveXpub { derivation_path } => {
+                [1.into_dart(), derivation_path.into_into_dart().into_dart()].into_dart()
+            }
+            crate::model::SignerMethod::Slip77MasterBlindingKey => [2.into_dart()].into_dart(),
+            crate::model::SignerMethod::SignPset { pset } => {
+                [3.into_dart(), pset.into_into_dart().into_dart()].into_dart()
+            }
+            crate::model::SignerMethod::SignEcdsa {
+                msg,
+                derivation_path,
+            } => [
+                4.into_dart(),
+                msg.into_into_dart().into_dart(),
+                derivation_path.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::model::SignerMethod::HmacSha256 {
+                msg,
+                derivation_path,
+            } => [
+                5.into_dart(),
+                msg.into_into_dart().into_dart(),
+                derivation_path.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::SignerMethod {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::SignerMethod> for crate::model::SignerMethod {
+    fn into_into_dart(self) -> crate::model::SignerMethod {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::SignerRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.method.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::SignerRequest {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::SignerRequest>
+    for crate::model::SignerRequest
+{
+    fn into_into_dart(self) -> crate::model::SignerRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::SignerResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::model::SignerResult::String { value } => {
+                [0.into_dart(), value.into_into_dart().into_dart()].into_dart()
+            }
+            crate::model::SignerResult::Bytes { value } => {
+                [1.into_dart(), value.into_into_dart().into_dart()].into_dart()
+            }
+            crate::model::SignerResult::Error { err } => {
+                [2.into_dart(), err.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::SignerResult {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::SignerResult> for crate::model::SignerResult {
+    fn into_into_dart(self) -> crate::model::SignerResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for FrbWrapper<crate::bindings::SuccessActionProcessed> {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self.0 {
@@ -5298,7 +7575,16 @@ impl SseEncode
 }
 
 impl SseEncode
-    for StreamSink<crate::model::LogEntry, flutter_rust_bridge::for_generated::DcoCodec>
+    for StreamSink<crate::model::LogEntry, flutter_rust_bridge::for_generated::DcoCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<crate::model::SdkEvent, flutter_rust_bridge::for_generated::DcoCodec>
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5307,7 +7593,7 @@ impl SseEncode
 }
 
 impl SseEncode
-    for StreamSink<crate::model::SdkEvent, flutter_rust_bridge::for_generated::DcoCodec>
+    for StreamSink<crate::model::SignerRequest, flutter_rust_bridge::for_generated::DcoCodec>
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5322,6 +7608,13 @@ impl SseEncode for String {
     }
 }
 
+impl SseEncode for crate::model::AcceptPaymentProposedFeesRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::model::FetchPaymentProposedFeesResponse>::sse_encode(self.response, serializer);
+    }
+}
+
 impl SseEncode for crate::bindings::AesSuccessActionDataDecrypted {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5349,6 +7642,44 @@ impl SseEncode for crate::bindings::AesSuccessActionDataResult {
     }
 }
 
+impl SseEncode for crate::model::AssetBalance {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.asset_id, serializer);
+        <u64>::sse_encode(self.balance, serializer);
+        <u64>::sse_encode(self.pending_send, serializer);
+        <u64>::sse_encode(self.pending_receive, serializer);
+        <Option<crate::model::AssetMetadata>>::sse_encode(self.asset_metadata, serializer);
+    }
+}
+
+impl SseEncode for crate::model::AssetMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.asset_id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.ticker, serializer);
+        <u8>::sse_encode(self.precision, serializer);
+    }
+}
+
+impl SseEncode for crate::model::AutoRefundDetails {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.swap_address, serializer);
+        <String>::sse_encode(self.refund_address, serializer);
+        <String>::sse_encode(self.refund_tx_id, serializer);
+    }
+}
+
+impl SseEncode for crate::model::AutoRefundPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::model::RefundDestination>::sse_encode(self.destination, serializer);
+        <crate::model::RefundFeeRate>::sse_encode(self.fee_rate, serializer);
+    }
+}
+
 impl SseEncode for crate::model::BackupRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5356,6 +7687,20 @@ impl SseEncode for crate::model::BackupRequest {
     }
 }
 
+impl SseEncode for crate::model::BalanceDetails {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.confirmed_sat, serializer);
+        <u64>::sse_encode(self.unconfirmed_receive_sat, serializer);
+        <u64>::sse_encode(self.unconfirmed_send_sat, serializer);
+        <u64>::sse_encode(self.pending_send_swaps_sat, serializer);
+        <u64>::sse_encode(self.pending_receive_swaps_sat, serializer);
+        <u64>::sse_encode(self.pending_refund_sat, serializer);
+        <u64>::sse_encode(self.refundable_btc_sat, serializer);
+        <u64>::sse_encode(self.pending_btc_refund_sat, serializer);
+    }
+}
+
 impl SseEncode for crate::bindings::BindingEventListener {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5381,6 +7726,24 @@ impl SseEncode for bool {
     }
 }
 
+impl SseEncode for crate::model::BumpRefundFeeRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.swap_address, serializer);
+        <u32>::sse_encode(self.sat_per_vbyte, serializer);
+    }
+}
+
+impl SseEncode for crate::model::BumpRefundFeeResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.refund_tx_id, serializer);
+        <String>::sse_encode(self.replaced_tx_id, serializer);
+        <u64>::sse_encode(self.tx_fee_sat, serializer);
+        <u64>::sse_encode(self.fee_delta_sat, serializer);
+    }
+}
+
 impl SseEncode for crate::model::BuyBitcoinProvider {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5404,25 +7767,53 @@ impl SseEncode for crate::model::BuyBitcoinRequest {
     }
 }
 
+impl SseEncode for crate::model::ChainBackend {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::model::ChainBackend::Electrum => 0,
+                crate::model::ChainBackend::Esplora => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for crate::model::Config {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.liquid_electrum_url, serializer);
         <String>::sse_encode(self.bitcoin_electrum_url, serializer);
         <String>::sse_encode(self.mempoolspace_url, serializer);
+        <String>::sse_encode(self.liquid_esplora_url, serializer);
+        <String>::sse_encode(self.bitcoin_esplora_url, serializer);
+        <crate::model::ChainBackend>::sse_encode(self.chain_backend, serializer);
+        <String>::sse_encode(self.breez_server_url, serializer);
+        <Option<String>>::sse_encode(self.boltz_url, serializer);
+        <Option<String>>::sse_encode(self.referral_id, serializer);
         <String>::sse_encode(self.working_dir, serializer);
         <crate::model::LiquidNetwork>::sse_encode(self.network, serializer);
         <u64>::sse_encode(self.payment_timeout_sec, serializer);
         <u32>::sse_encode(self.zero_conf_min_fee_rate_msat, serializer);
         <Option<u64>>::sse_encode(self.zero_conf_max_amount_sat, serializer);
+        <Option<Vec<crate::model::AssetMetadata>>>::sse_encode(self.asset_metadata, serializer);
+        <Option<Vec<String>>>::sse_encode(self.fiat_currencies, serializer);
+        <Option<String>>::sse_encode(self.proxy_url, serializer);
+        <Option<crate::model::AutoRefundPolicy>>::sse_encode(self.auto_refund, serializer);
+        <Option<u64>>::sse_encode(self.amountless_fee_tolerance_sat, serializer);
     }
 }
 
 impl SseEncode for crate::model::ConnectRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.mnemonic, serializer);
+        <Option<String>>::sse_encode(self.mnemonic, serializer);
         <crate::model::Config>::sse_encode(self.config, serializer);
+        <Option<String>>::sse_encode(self.watch_only_descriptor, serializer);
     }
 }
 
@@ -5439,6 +7830,41 @@ impl SseEncode for crate::bindings::CurrencyInfo {
     }
 }
 
+impl SseEncode for crate::model::DeletePaymentMetadataRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.payment_id, serializer);
+        <Option<String>>::sse_encode(self.key, serializer);
+    }
+}
+
+impl SseEncode for crate::model::ExportFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::model::ExportFormat::Csv => 0,
+                crate::model::ExportFormat::Json => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::model::ExportPaymentsRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.export_path, serializer);
+        <crate::model::ExportFormat>::sse_encode(self.format, serializer);
+        <Option<String>>::sse_encode(self.fiat_currency, serializer);
+        <Option<i64>>::sse_encode(self.from_timestamp, serializer);
+        <Option<i64>>::sse_encode(self.to_timestamp, serializer);
+    }
+}
+
 impl SseEncode for f64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5446,6 +7872,23 @@ impl SseEncode for f64 {
     }
 }
 
+impl SseEncode for crate::model::FetchPaymentProposedFeesRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.swap_id, serializer);
+    }
+}
+
+impl SseEncode for crate::model::FetchPaymentProposedFeesResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.swap_id, serializer);
+        <u64>::sse_encode(self.fees_sat, serializer);
+        <u64>::sse_encode(self.payer_amount_sat, serializer);
+        <u64>::sse_encode(self.receiver_amount_sat, serializer);
+    }
+}
+
 impl SseEncode for crate::bindings::FiatCurrency {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5461,6 +7904,8 @@ impl SseEncode for crate::model::GetInfoResponse {
         <u64>::sse_encode(self.pending_send_sat, serializer);
         <u64>::sse_encode(self.pending_receive_sat, serializer);
         <String>::sse_encode(self.pubkey, serializer);
+        <Vec<crate::model::AssetBalance>>::sse_encode(self.asset_balances, serializer);
+        <crate::model::BalanceDetails>::sse_encode(self.balance_details, serializer);
     }
 }
 
@@ -5545,6 +7990,7 @@ impl SseEncode for crate::model::LiquidNetwork {
             match self {
                 crate::model::LiquidNetwork::Mainnet => 0,
                 crate::model::LiquidNetwork::Testnet => 1,
+                crate::model::LiquidNetwork::Regtest => 2,
                 _ => {
                     unimplemented!("");
                 }
@@ -5554,6 +8000,36 @@ impl SseEncode for crate::model::LiquidNetwork {
     }
 }
 
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::model::AssetBalance> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::model::AssetBalance>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::model::AssetMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::model::AssetMetadata>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::bindings::FiatCurrency> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5594,6 +8070,56 @@ impl SseEncode for Vec<crate::model::Payment> {
     }
 }
 
+impl SseEncode for Vec<crate::model::PaymentFiatRate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::model::PaymentFiatRate>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::model::PaymentKind> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::model::PaymentKind>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::model::PaymentMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::model::PaymentMetadata>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::model::PaymentMetadataFilter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::model::PaymentMetadataFilter>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::model::PaymentState> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::model::PaymentState>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::model::PaymentType> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5612,6 +8138,25 @@ impl SseEncode for crate::model::ListPaymentsRequest {
         <Option<i64>>::sse_encode(self.to_timestamp, serializer);
         <Option<u32>>::sse_encode(self.offset, serializer);
         <Option<u32>>::sse_encode(self.limit, serializer);
+        <Option<Vec<crate::model::PaymentMetadataFilter>>>::sse_encode(
+            self.metadata_filters,
+            serializer,
+        );
+        <Option<Vec<crate::model::PaymentState>>>::sse_encode(self.states, serializer);
+        <Option<Vec<crate::model::PaymentKind>>>::sse_encode(self.kinds, serializer);
+        <Option<u64>>::sse_encode(self.from_amount_sat, serializer);
+        <Option<u64>>::sse_encode(self.to_amount_sat, serializer);
+        <Option<String>>::sse_encode(self.asset_id, serializer);
+        <Option<String>>::sse_encode(self.search, serializer);
+        <Option<String>>::sse_encode(self.cursor, serializer);
+    }
+}
+
+impl SseEncode for crate::model::ListPaymentsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::model::Payment>>::sse_encode(self.payments, serializer);
+        <Option<String>>::sse_encode(self.next_cursor, serializer);
     }
 }
 
@@ -5683,6 +8228,18 @@ impl SseEncode for crate::bindings::LNInvoice {
     }
 }
 
+impl SseEncode for crate::model::LNOffer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.offer, serializer);
+        <Vec<String>>::sse_encode(self.chains, serializer);
+        <Option<u64>>::sse_encode(self.amount_sat, serializer);
+        <Option<String>>::sse_encode(self.description, serializer);
+        <Option<u64>>::sse_encode(self.absolute_expiry, serializer);
+        <Option<String>>::sse_encode(self.issuer, serializer);
+    }
+}
+
 impl SseEncode for crate::bindings::duplicates::LnUrlAuthError {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5973,113 +8530,193 @@ impl SseEncode for crate::bindings::LocalizedName {
     }
 }
 
-impl SseEncode for crate::model::LogEntry {
+impl SseEncode for crate::model::LogEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.line, serializer);
+        <String>::sse_encode(self.level, serializer);
+    }
+}
+
+impl SseEncode for crate::bindings::MessageSuccessActionData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.message, serializer);
+    }
+}
+
+impl SseEncode for crate::bindings::Network {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::bindings::Network::Bitcoin => 0,
+                crate::bindings::Network::Testnet => 1,
+                crate::bindings::Network::Signet => 2,
+                crate::bindings::Network::Regtest => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::model::OnchainPaymentLimitsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::model::Limits>::sse_encode(self.send, serializer);
+        <crate::model::Limits>::sse_encode(self.receive, serializer);
+    }
+}
+
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::model::AssetMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::model::AssetMetadata>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::model::AutoRefundPolicy> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::model::AutoRefundPolicy>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.line, serializer);
-        <String>::sse_encode(self.level, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i64>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::bindings::MessageSuccessActionData {
+impl SseEncode for Option<crate::model::Payment> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.message, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::model::Payment>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::bindings::Network {
+impl SseEncode for Option<crate::bindings::SuccessActionProcessed> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(
-            match self {
-                crate::bindings::Network::Bitcoin => 0,
-                crate::bindings::Network::Testnet => 1,
-                crate::bindings::Network::Signet => 2,
-                crate::bindings::Network::Regtest => 3,
-                _ => {
-                    unimplemented!("");
-                }
-            },
-            serializer,
-        );
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::bindings::SuccessActionProcessed>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::model::OnchainPaymentLimitsResponse {
+impl SseEncode for Option<crate::bindings::Symbol> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <crate::model::Limits>::sse_encode(self.send, serializer);
-        <crate::model::Limits>::sse_encode(self.receive, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::bindings::Symbol>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for Option<String> {
+impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
+            <u32>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<bool> {
+impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <bool>::sse_encode(value, serializer);
+            <u64>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<i64> {
+impl SseEncode for Option<Vec<String>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <i64>::sse_encode(value, serializer);
+            <Vec<String>>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<crate::bindings::SuccessActionProcessed> {
+impl SseEncode for Option<Vec<crate::model::AssetMetadata>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <crate::bindings::SuccessActionProcessed>::sse_encode(value, serializer);
+            <Vec<crate::model::AssetMetadata>>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<crate::bindings::Symbol> {
+impl SseEncode for Option<Vec<crate::model::PaymentKind>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <crate::bindings::Symbol>::sse_encode(value, serializer);
+            <Vec<crate::model::PaymentKind>>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<u32> {
+impl SseEncode for Option<Vec<crate::model::PaymentMetadataFilter>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <u32>::sse_encode(value, serializer);
+            <Vec<crate::model::PaymentMetadataFilter>>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<u64> {
+impl SseEncode for Option<Vec<crate::model::PaymentState>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <u64>::sse_encode(value, serializer);
+            <Vec<crate::model::PaymentState>>::sse_encode(value, serializer);
         }
     }
 }
@@ -6099,6 +8736,7 @@ impl SseEncode for crate::model::PayOnchainRequest {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.address, serializer);
         <crate::model::PreparePayOnchainResponse>::sse_encode(self.prepare_res, serializer);
+        <Option<String>>::sse_encode(self.idempotency_key, serializer);
     }
 }
 
@@ -6113,10 +8751,14 @@ impl SseEncode for crate::model::Payment {
         <Option<String>>::sse_encode(self.preimage, serializer);
         <Option<String>>::sse_encode(self.bolt11, serializer);
         <String>::sse_encode(self.description, serializer);
+        <Option<String>>::sse_encode(self.destination, serializer);
+        <Option<String>>::sse_encode(self.asset_id, serializer);
         <Option<String>>::sse_encode(self.refund_tx_id, serializer);
         <Option<u64>>::sse_encode(self.refund_tx_amount_sat, serializer);
         <crate::model::PaymentType>::sse_encode(self.payment_type, serializer);
         <crate::model::PaymentState>::sse_encode(self.status, serializer);
+        <Vec<crate::model::PaymentMetadata>>::sse_encode(self.metadata, serializer);
+        <Vec<crate::model::PaymentFiatRate>>::sse_encode(self.fiat_rates, serializer);
     }
 }
 
@@ -6136,56 +8778,125 @@ impl SseEncode for crate::error::PaymentError {
             crate::error::PaymentError::AmountOutOfRange => {
                 <i32>::sse_encode(3, serializer);
             }
-            crate::error::PaymentError::Generic { err } => {
+            crate::error::PaymentError::AmountMissing { err } => {
                 <i32>::sse_encode(4, serializer);
                 <String>::sse_encode(err, serializer);
             }
-            crate::error::PaymentError::InvalidOrExpiredFees => {
+            crate::error::PaymentError::Generic { err } => {
                 <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(err, serializer);
             }
-            crate::error::PaymentError::InsufficientFunds => {
+            crate::error::PaymentError::InvalidOrExpiredFees => {
                 <i32>::sse_encode(6, serializer);
             }
-            crate::error::PaymentError::InvalidInvoice { err } => {
+            crate::error::PaymentError::InsufficientFunds => {
                 <i32>::sse_encode(7, serializer);
+            }
+            crate::error::PaymentError::InvalidInvoice { err } => {
+                <i32>::sse_encode(8, serializer);
+                <String>::sse_encode(err, serializer);
+            }
+            crate::error::PaymentError::InvalidDestination { err } => {
+                <i32>::sse_encode(9, serializer);
                 <String>::sse_encode(err, serializer);
             }
             crate::error::PaymentError::InvalidPreimage => {
-                <i32>::sse_encode(8, serializer);
+                <i32>::sse_encode(10, serializer);
             }
             crate::error::PaymentError::LwkError { err } => {
-                <i32>::sse_encode(9, serializer);
+                <i32>::sse_encode(11, serializer);
                 <String>::sse_encode(err, serializer);
             }
             crate::error::PaymentError::PairsNotFound => {
-                <i32>::sse_encode(10, serializer);
+                <i32>::sse_encode(12, serializer);
             }
             crate::error::PaymentError::PaymentTimeout => {
-                <i32>::sse_encode(11, serializer);
+                <i32>::sse_encode(13, serializer);
             }
             crate::error::PaymentError::PersistError => {
-                <i32>::sse_encode(12, serializer);
+                <i32>::sse_encode(14, serializer);
             }
             crate::error::PaymentError::ReceiveError { err } => {
-                <i32>::sse_encode(13, serializer);
+                <i32>::sse_encode(15, serializer);
                 <String>::sse_encode(err, serializer);
             }
             crate::error::PaymentError::Refunded { err, refund_tx_id } => {
-                <i32>::sse_encode(14, serializer);
+                <i32>::sse_encode(16, serializer);
                 <String>::sse_encode(err, serializer);
                 <String>::sse_encode(refund_tx_id, serializer);
             }
             crate::error::PaymentError::SelfTransferNotSupported => {
-                <i32>::sse_encode(15, serializer);
+                <i32>::sse_encode(17, serializer);
             }
             crate::error::PaymentError::SendError { err } => {
-                <i32>::sse_encode(16, serializer);
+                <i32>::sse_encode(18, serializer);
                 <String>::sse_encode(err, serializer);
             }
             crate::error::PaymentError::SignerError { err } => {
-                <i32>::sse_encode(17, serializer);
+                <i32>::sse_encode(19, serializer);
                 <String>::sse_encode(err, serializer);
             }
+            crate::error::PaymentError::WatchOnly => {
+                <i32>::sse_encode(20, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::model::PaymentFiatRate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.coin, serializer);
+        <f64>::sse_encode(self.value, serializer);
+        <u32>::sse_encode(self.created_at, serializer);
+    }
+}
+
+impl SseEncode for crate::model::PaymentKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::model::PaymentKind::Submarine => 0,
+                crate::model::PaymentKind::Reverse => 1,
+                crate::model::PaymentKind::Chain => 2,
+                crate::model::PaymentKind::Direct => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::model::PaymentLookup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::model::PaymentLookup::SwapId { swap_id } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(swap_id, serializer);
+            }
+            crate::model::PaymentLookup::Invoice { invoice } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(invoice, serializer);
+            }
+            crate::model::PaymentLookup::PaymentHash { payment_hash } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(payment_hash, serializer);
+            }
+            crate::model::PaymentLookup::TxId { tx_id } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(tx_id, serializer);
+            }
+            crate::model::PaymentLookup::Destination { address } => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(address, serializer);
+            }
             _ => {
                 unimplemented!("");
             }
@@ -6193,6 +8904,22 @@ impl SseEncode for crate::error::PaymentError {
     }
 }
 
+impl SseEncode for crate::model::PaymentMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.key, serializer);
+        <String>::sse_encode(self.value, serializer);
+    }
+}
+
+impl SseEncode for crate::model::PaymentMetadataFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.key, serializer);
+        <Option<String>>::sse_encode(self.value, serializer);
+    }
+}
+
 impl SseEncode for crate::model::PaymentState {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6205,6 +8932,7 @@ impl SseEncode for crate::model::PaymentState {
                 crate::model::PaymentState::TimedOut => 4,
                 crate::model::PaymentState::Refundable => 5,
                 crate::model::PaymentState::RefundPending => 6,
+                crate::model::PaymentState::WaitingFeeAcceptance => 7,
                 _ => {
                     unimplemented!("");
                 }
@@ -6252,6 +8980,7 @@ impl SseEncode for crate::model::PreparePayOnchainRequest {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <u64>::sse_encode(self.receiver_amount_sat, serializer);
         <Option<u32>>::sse_encode(self.sat_per_vbyte, serializer);
+        <Option<bool>>::sse_encode(self.drain, serializer);
     }
 }
 
@@ -6261,21 +8990,23 @@ impl SseEncode for crate::model::PreparePayOnchainResponse {
         <u64>::sse_encode(self.receiver_amount_sat, serializer);
         <u64>::sse_encode(self.claim_fees_sat, serializer);
         <u64>::sse_encode(self.total_fees_sat, serializer);
+        <bool>::sse_encode(self.drain, serializer);
     }
 }
 
 impl SseEncode for crate::model::PrepareReceiveOnchainRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u64>::sse_encode(self.payer_amount_sat, serializer);
+        <Option<u64>>::sse_encode(self.payer_amount_sat, serializer);
     }
 }
 
 impl SseEncode for crate::model::PrepareReceiveOnchainResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u64>::sse_encode(self.payer_amount_sat, serializer);
+        <Option<u64>>::sse_encode(self.payer_amount_sat, serializer);
         <u64>::sse_encode(self.fees_sat, serializer);
+        <f64>::sse_encode(self.swapper_feerate, serializer);
     }
 }
 
@@ -6312,10 +9043,35 @@ impl SseEncode for crate::model::PrepareRefundResponse {
     }
 }
 
+impl SseEncode for crate::model::PrepareSendLiquidRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.destination, serializer);
+        <Option<u64>>::sse_encode(self.amount_sat, serializer);
+        <Option<bool>>::sse_encode(self.drain, serializer);
+        <Option<String>>::sse_encode(self.asset_id, serializer);
+    }
+}
+
+impl SseEncode for crate::model::PrepareSendLiquidResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.address, serializer);
+        <u64>::sse_encode(self.receiver_amount_sat, serializer);
+        <u64>::sse_encode(self.fees_sat, serializer);
+        <Option<String>>::sse_encode(self.description, serializer);
+        <bool>::sse_encode(self.drain, serializer);
+        <Option<String>>::sse_encode(self.asset_id, serializer);
+    }
+}
+
 impl SseEncode for crate::model::PrepareSendRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.invoice, serializer);
+        <Option<u64>>::sse_encode(self.amount_sat, serializer);
+        <Option<String>>::sse_encode(self.idempotency_key, serializer);
+        <Option<String>>::sse_encode(self.payer_note, serializer);
     }
 }
 
@@ -6323,7 +9079,10 @@ impl SseEncode for crate::model::PrepareSendResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.invoice, serializer);
+        <Option<String>>::sse_encode(self.offer, serializer);
+        <u64>::sse_encode(self.receiver_amount_sat, serializer);
         <u64>::sse_encode(self.fees_sat, serializer);
+        <Option<String>>::sse_encode(self.idempotency_key, serializer);
     }
 }
 
@@ -6335,6 +9094,23 @@ impl SseEncode for crate::bindings::Rate {
     }
 }
 
+impl SseEncode for crate::model::ReceiveLiquidRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u64>>::sse_encode(self.amount_sat, serializer);
+        <Option<String>>::sse_encode(self.description, serializer);
+        <Option<String>>::sse_encode(self.asset_id, serializer);
+    }
+}
+
+impl SseEncode for crate::model::ReceiveLiquidResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.address, serializer);
+        <String>::sse_encode(self.bip21, serializer);
+    }
+}
+
 impl SseEncode for crate::model::ReceiveOnchainResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6370,6 +9146,44 @@ impl SseEncode for crate::model::RecommendedFees {
     }
 }
 
+impl SseEncode for crate::model::RefundDestination {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::model::RefundDestination::Address { address } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(address, serializer);
+            }
+            crate::model::RefundDestination::Xpub { xpub } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(xpub, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::model::RefundFeeRate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::model::RefundFeeRate::Fastest => 0,
+                crate::model::RefundFeeRate::HalfHour => 1,
+                crate::model::RefundFeeRate::Hour => 2,
+                crate::model::RefundFeeRate::Economy => 3,
+                crate::model::RefundFeeRate::Minimum => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for crate::model::RefundRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -6475,8 +9289,16 @@ impl SseEncode for crate::model::SdkEvent {
                 <i32>::sse_encode(5, serializer);
                 <crate::model::Payment>::sse_encode(details, serializer);
             }
-            crate::model::SdkEvent::Synced => {
+            crate::model::SdkEvent::SwapAutoRefunded { details } => {
                 <i32>::sse_encode(6, serializer);
+                <crate::model::AutoRefundDetails>::sse_encode(details, serializer);
+            }
+            crate::model::SdkEvent::SwapWaitingFeeAcceptance { swap_id } => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(swap_id, serializer);
+            }
+            crate::model::SdkEvent::Synced => {
+                <i32>::sse_encode(8, serializer);
             }
             _ => {
                 unimplemented!("");
@@ -6492,6 +9314,87 @@ impl SseEncode for crate::model::SendPaymentResponse {
     }
 }
 
+impl SseEncode for crate::model::SetPaymentMetadataRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.payment_id, serializer);
+        <String>::sse_encode(self.key, serializer);
+        <String>::sse_encode(self.value, serializer);
+    }
+}
+
+impl SseEncode for crate::model::SignerMethod {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::model::SignerMethod::Xpub => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::model::SignerMethod::DeriveXpub { derivation_path } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(derivation_path, serializer);
+            }
+            crate::model::SignerMethod::Slip77MasterBlindingKey => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::model::SignerMethod::SignPset { pset } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(pset, serializer);
+            }
+            crate::model::SignerMethod::SignEcdsa {
+                msg,
+                derivation_path,
+            } => {
+                <i32>::sse_encode(4, serializer);
+                <Vec<u8>>::sse_encode(msg, serializer);
+                <String>::sse_encode(derivation_path, serializer);
+            }
+            crate::model::SignerMethod::HmacSha256 {
+                msg,
+                derivation_path,
+            } => {
+                <i32>::sse_encode(5, serializer);
+                <Vec<u8>>::sse_encode(msg, serializer);
+                <String>::sse_encode(derivation_path, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::model::SignerRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.id, serializer);
+        <crate::model::SignerMethod>::sse_encode(self.method, serializer);
+    }
+}
+
+impl SseEncode for crate::model::SignerResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::model::SignerResult::String { value } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(value, serializer);
+            }
+            crate::model::SignerResult::Bytes { value } => {
+                <i32>::sse_encode(1, serializer);
+                <Vec<u8>>::sse_encode(value, serializer);
+            }
+            crate::model::SignerResult::Error { err } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(err, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseEncode for crate::bindings::SuccessActionProcessed {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -28,8 +28,9 @@
 //! config.working_dir = "path to an existing directory".into();
 //!
 //! let connect_request = ConnectRequest {
-//!     mnemonic: mnemonic.to_string(),
+//!     mnemonic: Some(mnemonic.to_string()),
 //!     config,
+//!     watch_only_descriptor: None,
 //! };
 //! let sdk = sdk::LiquidSdk::connect(connect_request).await?;
 //!
@@ -64,6 +65,9 @@
 //! let prepare_send_response = sdk
 //!     .prepare_send_payment(&PrepareSendRequest {
 //!         invoice: "...".to_string(),
+//!         amount_sat: None,
+//!         idempotency_key: None,
+//!         payer_note: None,
 //!     })
 //!     .await?;
 //!
@@ -103,16 +107,41 @@
 //!
 //! Now your SDK is ready to be used.
 //!
+//! ### Watch-only mode
+//!
+//! Setting the [model::ConnectRequest] `watch_only_descriptor` instead of the mnemonic connects the
+//! SDK to a wallet without its keys. Payments, balances and sync are available, while calls that
+//! sign or create swaps return [error::PaymentError::WatchOnly].
+//!
+//! ### External signer
+//!
+//! By default the wallet keys are derived from the mnemonic and held by the SDK. To keep them in an
+//! external signer instead, implement [signer::Signer] and connect with
+//! [sdk::LiquidSdk::connect_with_signer]. See its docs for how the signer is implemented in each
+//! of the bindings.
+//!
 //! ### Sending a Lightning payment
 //!
 //! * [sdk::LiquidSdk::prepare_send_payment] to check fees
 //! * [sdk::LiquidSdk::send_payment] to pay an invoice
 //!
+//! ### Sending a Liquid payment
+//!
+//! * [sdk::LiquidSdk::prepare_send_liquid] to check fees
+//! * [sdk::LiquidSdk::send_liquid] to pay to a Liquid address or BIP21 URI
+//!
+//! Issued Liquid assets (e.g. USDt) can be sent by setting the `asset_id` of the request. Their
+//! balances are part of [sdk::LiquidSdk::get_info].
+//!
 //! ### Receiving a Lightning payment
 //!
 //! * [sdk::LiquidSdk::prepare_receive_payment] to check fees
 //! * [sdk::LiquidSdk::receive_payment] to generate an invoice
 //!
+//! ### Receiving a Liquid payment
+//!
+//! * [sdk::LiquidSdk::receive_liquid] to generate a Liquid address and BIP21 URI
+//!
 //! ### Sending an onchain payment
 //!
 //! * [sdk::LiquidSdk::prepare_pay_onchain] to check fees
@@ -136,6 +165,22 @@
 //!
 //! * [sdk::LiquidSdk::list_fiat_currencies] to get the supported fiat currencies
 //! * [sdk::LiquidSdk::fetch_fiat_rates] to get the current exchange rates
+//! * [model::Config::fiat_currencies] to record the exchange rates at the time of each payment,
+//!   see [model::Payment::fiat_rates]
+//!
+//! ### Looking up payments
+//!
+//! * [sdk::LiquidSdk::list_payments] to list the payments, optionally filtered
+//! * [sdk::LiquidSdk::list_payments_page] to list the payments page by page, using a cursor
+//! * [sdk::LiquidSdk::get_payment] to find a payment by swap id, invoice, payment hash, tx id or
+//!   destination address
+//! * [sdk::LiquidSdk::export_payments] to export the payments to a CSV or JSON file, valued in fiat
+//!
+//! ### Payment metadata
+//!
+//! * [sdk::LiquidSdk::set_payment_metadata] to attach a note, label or custom tag to a payment
+//! * [sdk::LiquidSdk::get_payment_metadata] and [sdk::LiquidSdk::delete_payment_metadata] to manage it
+//! * [model::ListPaymentsRequest] `metadata_filters` to list payments by their metadata
 
 //! ### Utilities
 //!
@@ -159,11 +204,13 @@
 
 #[cfg(feature = "frb")]
 pub(crate) mod bindings;
+pub(crate) mod bolt12;
 pub(crate) mod buy;
 pub(crate) mod chain;
 pub(crate) mod chain_swap;
 pub mod error;
 pub(crate) mod event;
+pub(crate) mod export;
 #[cfg(feature = "frb")]
 pub(crate) mod frb_generated;
 pub mod logger;
@@ -172,6 +219,7 @@ pub mod persist;
 pub(crate) mod receive_swap;
 pub mod sdk;
 pub(crate) mod send_swap;
+pub mod signer;
 pub(crate) mod swapper;
 pub(crate) mod test_utils;
 pub(crate) mod utils;
@@ -1,23 +1,31 @@
 use std::path::PathBuf;
+use std::str::FromStr;
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, ensure, Result};
 
 use boltz_client::{
+    boltz::{BOLTZ_MAINNET_URL_V2, BOLTZ_TESTNET_URL_V2},
     network::Chain,
     swaps::boltz::{
         CreateChainResponse, CreateReverseResponse, CreateSubmarineResponse, Leaf, Side, SwapTree,
+        SwapTxKind,
     },
-    ToHex,
 };
-use boltz_client::{BtcSwapScript, BtcSwapTx, Keypair, LBtcSwapScript, LBtcSwapTx};
-use lwk_signer::SwSigner;
+use boltz_client::{
+    BtcSwapScript, BtcSwapTx, ElementsAddress, Keypair, LBtcSwapScript, LBtcSwapTx,
+};
+use lwk_wollet::bitcoin::{self, Amount, Denomination};
+use lwk_wollet::elements::{self, AddressParams, AssetId};
 use lwk_wollet::ElementsNetwork;
 use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSqlOutput, ValueRef};
 use rusqlite::ToSql;
 use sdk_common::prelude::*;
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
+use url::Url;
 
+use crate::chain_swap::DEFAULT_AMOUNTLESS_FEE_TOLERANCE_SAT;
+use crate::ensure_sdk;
 use crate::error::{PaymentError, SdkResult};
 use crate::receive_swap::{
     DEFAULT_ZERO_CONF_MAX_SAT, DEFAULT_ZERO_CONF_MIN_FEE_RATE_MAINNET,
@@ -28,6 +36,12 @@ use crate::utils;
 pub const STANDARD_FEE_RATE_SAT_PER_VBYTE: f32 = 0.1;
 pub const LOWBALL_FEE_RATE_SAT_PER_VBYTE: f32 = 0.01;
 
+/// The Boltz API of a local regtest backend
+const BOLTZ_REGTEST_URL_V2: &str = "http://localhost:9001/v2";
+/// The policy asset of a local `elementsd` in the default `elementsregtest` chain
+const REGTEST_POLICY_ASSET_ID: &str =
+    "5ac9f65c0efcc4775e0baec4ec03abdde22473cd3cf33c0419ca290e0751b225";
+
 /// Configuration for the Liquid SDK
 #[derive(Clone, Debug, Serialize)]
 pub struct Config {
@@ -35,6 +49,22 @@ pub struct Config {
     pub bitcoin_electrum_url: String,
     /// The mempool.space API URL, has to be in the format: `https://mempool.space/api`
     pub mempoolspace_url: String,
+    /// The Liquid Esplora API URL, used on mainnet to broadcast txs and fetch script histories.
+    /// Has to be in the format: `https://blockstream.info/liquid/api`
+    pub liquid_esplora_url: String,
+    /// The Bitcoin Esplora API URL, used with [ChainBackend::Esplora].
+    /// Has to be in the format: `https://blockstream.info/api`
+    pub bitcoin_esplora_url: String,
+    /// Whether the chain data is fetched from the Electrum or the Esplora servers
+    pub chain_backend: ChainBackend,
+    /// The Breez server URL, used to look up the swapper proxy and to buy Bitcoin
+    pub breez_server_url: String,
+    /// The optional Boltz API URL, e.g. of a self-hosted instance. If not set, the Boltz swapper
+    /// proxy from the Breez server is used on mainnet, or else the public Boltz instance.
+    pub boltz_url: Option<String>,
+    /// The optional referral id sent to Boltz when creating swaps. If not set, the one of the
+    /// swapper proxy is used, if any.
+    pub referral_id: Option<String>,
     /// Directory in which all SDK files (DB, log, cache) are stored.
     ///
     /// Prefix can be a relative or absolute path to this directory.
@@ -47,6 +77,24 @@ pub struct Config {
     /// Maximum amount in satoshi to accept zero-conf payments with
     /// Defaults to [crate::receive_swap::DEFAULT_ZERO_CONF_MAX_SAT]
     pub zero_conf_max_amount_sat: Option<u64>,
+    /// Metadata of additional Liquid assets, on top of the built-in registry of well-known
+    /// assets. See [AssetMetadata::registry]
+    pub asset_metadata: Option<Vec<AssetMetadata>>,
+    /// The fiat currencies, e.g. `USD`, to record the exchange rates of whenever a payment is
+    /// created or first seen. See [Payment::fiat_rates]
+    pub fiat_currencies: Option<Vec<String>>,
+    /// The optional SOCKS5 proxy URL, e.g. `socks5h://127.0.0.1:9050` for a local Tor daemon.
+    /// All the chain, Boltz and fee estimate requests go through it. The only client without a
+    /// proxy hook is the Breez server gRPC client, so the fiat rates, buying Bitcoin and the
+    /// swapper proxy lookup fail instead of connecting directly.
+    pub proxy_url: Option<String>,
+    /// The optional policy to refund failed incoming chain swaps automatically. If not set, they
+    /// stay refundable until [crate::sdk::LiquidSdk::refund] is called.
+    pub auto_refund: Option<AutoRefundPolicy>,
+    /// The most, in satoshi, by which the fees proposed for an amountless incoming chain swap may
+    /// exceed the current fees of the swapper before they are no longer accepted automatically.
+    /// Defaults to [crate::chain_swap::DEFAULT_AMOUNTLESS_FEE_TOLERANCE_SAT]
+    pub amountless_fee_tolerance_sat: Option<u64>,
 }
 
 impl Config {
@@ -55,11 +103,22 @@ impl Config {
             liquid_electrum_url: "blockstream.info:995".to_string(),
             bitcoin_electrum_url: "blockstream.info:700".to_string(),
             mempoolspace_url: "https://mempool.space/api".to_string(),
+            liquid_esplora_url: "https://lq1.breez.technology/liquid/api".to_string(),
+            bitcoin_esplora_url: "https://blockstream.info/api".to_string(),
+            chain_backend: ChainBackend::Electrum,
+            breez_server_url: PRODUCTION_BREEZSERVER_URL.to_string(),
+            boltz_url: None,
+            referral_id: None,
             working_dir: ".".to_string(),
             network: LiquidNetwork::Mainnet,
             payment_timeout_sec: 15,
             zero_conf_min_fee_rate_msat: DEFAULT_ZERO_CONF_MIN_FEE_RATE_MAINNET,
             zero_conf_max_amount_sat: None,
+            asset_metadata: None,
+            fiat_currencies: None,
+            proxy_url: None,
+            auto_refund: None,
+            amountless_fee_tolerance_sat: None,
         }
     }
 
@@ -68,21 +127,94 @@ impl Config {
             liquid_electrum_url: "blockstream.info:465".to_string(),
             bitcoin_electrum_url: "blockstream.info:993".to_string(),
             mempoolspace_url: "https://mempool.space/testnet/api".to_string(),
+            liquid_esplora_url: "https://blockstream.info/liquidtestnet/api".to_string(),
+            bitcoin_esplora_url: "https://blockstream.info/testnet/api".to_string(),
+            chain_backend: ChainBackend::Electrum,
+            breez_server_url: PRODUCTION_BREEZSERVER_URL.to_string(),
+            boltz_url: None,
+            referral_id: None,
             working_dir: ".".to_string(),
             network: LiquidNetwork::Testnet,
             payment_timeout_sec: 15,
             zero_conf_min_fee_rate_msat: DEFAULT_ZERO_CONF_MIN_FEE_RATE_TESTNET,
             zero_conf_max_amount_sat: None,
+            asset_metadata: None,
+            fiat_currencies: None,
+            proxy_url: None,
+            auto_refund: None,
+            amountless_fee_tolerance_sat: None,
         }
     }
 
-    pub(crate) fn get_wallet_working_dir(&self, signer: &SwSigner) -> anyhow::Result<String> {
+    /// The default config for a local regtest setup, with `elementsd`, `bitcoind`, their Electrum
+    /// and Esplora servers and a Boltz backend
+    pub fn regtest() -> Self {
+        Config {
+            liquid_electrum_url: "localhost:19002".to_string(),
+            bitcoin_electrum_url: "localhost:19001".to_string(),
+            mempoolspace_url: "http://localhost:3003/api".to_string(),
+            liquid_esplora_url: "http://localhost:4003/api".to_string(),
+            bitcoin_esplora_url: "http://localhost:4002/api".to_string(),
+            chain_backend: ChainBackend::Electrum,
+            breez_server_url: PRODUCTION_BREEZSERVER_URL.to_string(),
+            boltz_url: Some(BOLTZ_REGTEST_URL_V2.to_string()),
+            referral_id: None,
+            working_dir: ".".to_string(),
+            network: LiquidNetwork::Regtest,
+            payment_timeout_sec: 15,
+            zero_conf_min_fee_rate_msat: DEFAULT_ZERO_CONF_MIN_FEE_RATE_TESTNET,
+            zero_conf_max_amount_sat: None,
+            asset_metadata: None,
+            fiat_currencies: None,
+            proxy_url: None,
+            auto_refund: None,
+            amountless_fee_tolerance_sat: None,
+        }
+    }
+
+    /// The configured Boltz API URL, or else the public Boltz instance of the network
+    pub(crate) fn get_boltz_url(&self) -> String {
+        self.boltz_url.clone().unwrap_or(
+            match self.network {
+                LiquidNetwork::Mainnet => BOLTZ_MAINNET_URL_V2,
+                LiquidNetwork::Testnet => BOLTZ_TESTNET_URL_V2,
+                LiquidNetwork::Regtest => BOLTZ_REGTEST_URL_V2,
+            }
+            .to_string(),
+        )
+    }
+
+    /// Whether to connect to the Electrum servers over TLS. Local regtest servers do not use it.
+    pub(crate) fn electrum_tls(&self) -> bool {
+        self.network != LiquidNetwork::Regtest
+    }
+
+    /// The `host:port` of the configured SOCKS5 proxy, if any
+    pub(crate) fn socks5_proxy_addr(&self) -> anyhow::Result<Option<String>> {
+        let Some(proxy_url) = &self.proxy_url else {
+            return Ok(None);
+        };
+        let url = Url::parse(proxy_url)?;
+        if !matches!(url.scheme(), "socks5" | "socks5h") {
+            return Err(anyhow!(
+                "Unsupported proxy scheme {}, expected socks5 or socks5h",
+                url.scheme()
+            ));
+        }
+        match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => Ok(Some(format!("{host}:{port}"))),
+            _ => Err(anyhow!("Proxy URL {proxy_url} is missing the host or port")),
+        }
+    }
+
+    pub(crate) fn get_wallet_working_dir(&self, wallet_id: &str) -> anyhow::Result<String> {
         Ok(PathBuf::from(self.working_dir.clone())
             .join(match self.network {
                 LiquidNetwork::Mainnet => "mainnet",
                 LiquidNetwork::Testnet => "testnet",
+                LiquidNetwork::Regtest => "regtest",
             })
-            .join(signer.fingerprint().to_hex())
+            .join(wallet_id)
             .to_str()
             .ok_or(anyhow::anyhow!(
                 "Could not get retrieve current wallet directory"
@@ -95,11 +227,158 @@ impl Config {
             .unwrap_or(DEFAULT_ZERO_CONF_MAX_SAT)
     }
 
+    pub fn amountless_fee_tolerance_sat(&self) -> u64 {
+        self.amountless_fee_tolerance_sat
+            .unwrap_or(DEFAULT_AMOUNTLESS_FEE_TOLERANCE_SAT)
+    }
+
     pub(crate) fn lowball_fee_rate_msat_per_vbyte(&self) -> Option<f32> {
         match self.network {
             LiquidNetwork::Mainnet => Some(LOWBALL_FEE_RATE_SAT_PER_VBYTE * 1000.0),
-            LiquidNetwork::Testnet => None,
+            LiquidNetwork::Testnet | LiquidNetwork::Regtest => None,
+        }
+    }
+
+    /// Looks up the metadata of an asset, first in [Config::asset_metadata] and then in the
+    /// built-in registry
+    pub(crate) fn get_asset_metadata(&self, asset_id: &str) -> Option<AssetMetadata> {
+        self.asset_metadata
+            .iter()
+            .flatten()
+            .cloned()
+            .chain(AssetMetadata::registry(self.network))
+            .find(|metadata| metadata.asset_id == asset_id)
+    }
+}
+
+/// Metadata of a Liquid asset, used to identify and display asset amounts
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AssetMetadata {
+    /// The asset id in hex format
+    pub asset_id: String,
+    pub name: String,
+    pub ticker: String,
+    /// The number of decimal places of the asset. Amounts are always expressed in base units,
+    /// so for example an amount of 150 of an asset with precision 2 is displayed as 1.50
+    pub precision: u8,
+}
+
+impl AssetMetadata {
+    /// The built-in registry of well-known assets on the given network
+    pub(crate) fn registry(network: LiquidNetwork) -> Vec<AssetMetadata> {
+        let usdt_asset_id = match network {
+            LiquidNetwork::Mainnet => {
+                Some("ce091c998b83c78bb71a632313ba3760f1763d9cfcffae02258ffa9865a37bd2")
+            }
+            LiquidNetwork::Testnet => {
+                Some("b612eb46313a2cd6ebabd8b7a8eed5696e29898b87a43bff41c94f51acef9d73")
+            }
+            LiquidNetwork::Regtest => None,
+        };
+        let mut registry = vec![AssetMetadata {
+            asset_id: ElementsNetwork::from(network).policy_asset().to_string(),
+            name: "Bitcoin".to_string(),
+            ticker: "L-BTC".to_string(),
+            precision: 8,
+        }];
+        if let Some(usdt_asset_id) = usdt_asset_id {
+            registry.push(AssetMetadata {
+                asset_id: usdt_asset_id.to_string(),
+                name: "Tether USD".to_string(),
+                ticker: "USDt".to_string(),
+                precision: 8,
+            });
         }
+        registry
+    }
+
+    /// BIP21 amounts are parsed with 8 decimals, so this is the factor between them and the
+    /// asset base units
+    fn bip21_amount_factor(&self) -> u64 {
+        10u64.pow(8u32.saturating_sub(self.precision.into()))
+    }
+
+    /// Converts an amount parsed from a BIP21 URI (see [LiquidBip21::amount_sat]) to base units
+    pub(crate) fn amount_from_bip21(&self, bip21_amount: u64) -> Result<u64, PaymentError> {
+        let factor = self.bip21_amount_factor();
+        ensure_sdk!(
+            bip21_amount % factor == 0,
+            PaymentError::InvalidDestination {
+                err: format!(
+                    "Amount has more than {} decimals, the precision of asset {}",
+                    self.precision, self.ticker
+                )
+            }
+        );
+        Ok(bip21_amount / factor)
+    }
+
+    /// Converts an amount in base units to the amount set in a BIP21 URI
+    pub(crate) fn amount_to_bip21(&self, amount: u64) -> u64 {
+        amount * self.bip21_amount_factor()
+    }
+}
+
+/// The source of the chain data of the Liquid SDK
+#[derive(Debug, Copy, Clone, PartialEq, Serialize)]
+pub enum ChainBackend {
+    /// The Electrum servers, see [Config::liquid_electrum_url] and [Config::bitcoin_electrum_url]
+    Electrum,
+    /// The Esplora REST APIs, see [Config::liquid_esplora_url] and [Config::bitcoin_esplora_url].
+    /// Only needs outbound HTTPS.
+    Esplora,
+}
+
+/// The policy to automatically refund failed incoming chain swaps, see [Config::auto_refund]
+#[derive(Clone, Debug, Serialize)]
+pub struct AutoRefundPolicy {
+    /// Where to send the refunds to
+    pub destination: RefundDestination,
+    /// The fee rate at which to broadcast the refunds
+    pub fee_rate: RefundFeeRate,
+}
+
+/// The destination of the automatic refunds of an [AutoRefundPolicy]
+#[derive(Clone, Debug, Serialize)]
+pub enum RefundDestination {
+    /// A fixed Bitcoin address
+    Address { address: String },
+    /// A Bitcoin account xpub. Each refund is sent to the next P2WPKH address of its external
+    /// chain, i.e. `xpub/0/i`.
+    Xpub { xpub: String },
+}
+
+/// The details of a refund broadcast by an [AutoRefundPolicy], see [SdkEvent::SwapAutoRefunded]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AutoRefundDetails {
+    /// The address where the swap funds were locked up
+    pub swap_address: String,
+    /// The address the swap funds were refunded to
+    pub refund_address: String,
+    pub refund_tx_id: String,
+}
+
+/// The [RecommendedFees] fee rate an [AutoRefundPolicy] refunds at
+#[derive(Debug, Copy, Clone, PartialEq, Serialize)]
+pub enum RefundFeeRate {
+    Fastest,
+    HalfHour,
+    Hour,
+    Economy,
+    Minimum,
+}
+
+impl RefundFeeRate {
+    /// The fee rate in sat/vB picked from the recommended fees
+    pub(crate) fn sat_per_vbyte(&self, fees: &RecommendedFees) -> u32 {
+        let fee_rate = match self {
+            RefundFeeRate::Fastest => fees.fastest_fee,
+            RefundFeeRate::HalfHour => fees.half_hour_fee,
+            RefundFeeRate::Hour => fees.hour_fee,
+            RefundFeeRate::Economy => fees.economy_fee,
+            RefundFeeRate::Minimum => fees.minimum_fee,
+        };
+        fee_rate as u32
     }
 }
 
@@ -111,12 +390,31 @@ pub enum LiquidNetwork {
     Mainnet,
     /// Testnet Bitcoin and Liquid chains
     Testnet,
+    /// Local regtest Bitcoin and Liquid chains, e.g. for integration tests. See [Config::regtest]
+    Regtest,
 }
 impl LiquidNetwork {
     pub fn as_bitcoin_chain(&self) -> Chain {
         match self {
             LiquidNetwork::Mainnet => Chain::Bitcoin,
             LiquidNetwork::Testnet => Chain::BitcoinTestnet,
+            LiquidNetwork::Regtest => Chain::BitcoinRegtest,
+        }
+    }
+
+    pub(crate) fn as_bitcoin_network(&self) -> bitcoin::Network {
+        match self {
+            LiquidNetwork::Mainnet => bitcoin::Network::Bitcoin,
+            LiquidNetwork::Testnet => bitcoin::Network::Testnet,
+            LiquidNetwork::Regtest => bitcoin::Network::Regtest,
+        }
+    }
+
+    pub(crate) fn address_params(&self) -> &'static AddressParams {
+        match self {
+            LiquidNetwork::Mainnet => &AddressParams::LIQUID,
+            LiquidNetwork::Testnet => &AddressParams::LIQUID_TESTNET,
+            LiquidNetwork::Regtest => &AddressParams::ELEMENTS,
         }
     }
 }
@@ -126,6 +424,10 @@ impl From<LiquidNetwork> for ElementsNetwork {
         match value {
             LiquidNetwork::Mainnet => ElementsNetwork::Liquid,
             LiquidNetwork::Testnet => ElementsNetwork::LiquidTestnet,
+            LiquidNetwork::Regtest => ElementsNetwork::ElementsRegtest {
+                policy_asset: AssetId::from_str(REGTEST_POLICY_ASSET_ID)
+                    .expect("Expecting a valid asset id"),
+            },
         }
     }
 }
@@ -135,6 +437,7 @@ impl From<LiquidNetwork> for Chain {
         match value {
             LiquidNetwork::Mainnet => Chain::Liquid,
             LiquidNetwork::Testnet => Chain::LiquidTestnet,
+            LiquidNetwork::Regtest => Chain::LiquidRegtest,
         }
     }
 }
@@ -146,6 +449,7 @@ impl TryFrom<&str> for LiquidNetwork {
         match value.to_lowercase().as_str() {
             "mainnet" => Ok(LiquidNetwork::Mainnet),
             "testnet" => Ok(LiquidNetwork::Testnet),
+            "regtest" => Ok(LiquidNetwork::Regtest),
             _ => Err(anyhow!("Invalid network")),
         }
     }
@@ -156,6 +460,7 @@ impl From<LiquidNetwork> for sdk_common::prelude::Network {
         match value {
             LiquidNetwork::Mainnet => Self::Bitcoin,
             LiquidNetwork::Testnet => Self::Testnet,
+            LiquidNetwork::Regtest => Self::Regtest,
         }
     }
 }
@@ -165,6 +470,7 @@ impl From<LiquidNetwork> for sdk_common::bitcoin::Network {
         match value {
             LiquidNetwork::Mainnet => Self::Bitcoin,
             LiquidNetwork::Testnet => Self::Testnet,
+            LiquidNetwork::Regtest => Self::Regtest,
         }
     }
 }
@@ -184,14 +490,21 @@ pub enum SdkEvent {
     PaymentRefundPending { details: Payment },
     PaymentSucceeded { details: Payment },
     PaymentWaitingConfirmation { details: Payment },
+    SwapAutoRefunded { details: AutoRefundDetails },
+    SwapWaitingFeeAcceptance { swap_id: String },
     Synced,
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::connect].
 #[derive(Debug, Serialize)]
 pub struct ConnectRequest {
-    pub mnemonic: String,
+    /// The wallet mnemonic. Required unless `watch_only_descriptor` is set.
+    pub mnemonic: Option<String>,
     pub config: Config,
+    /// A CT descriptor of the wallet, as parsed by [lwk_wollet::WolletDescriptor]. If set instead
+    /// of the mnemonic, the SDK connects in watch-only mode: payments, balances and sync work,
+    /// while any call that needs to sign or create a swap fails with [PaymentError::WatchOnly].
+    pub watch_only_descriptor: Option<String>,
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::prepare_receive_payment].
@@ -250,27 +563,250 @@ pub struct OnchainPaymentLimitsResponse {
 /// An argument when calling [crate::sdk::LiquidSdk::prepare_send_payment].
 #[derive(Debug, Serialize, Clone)]
 pub struct PrepareSendRequest {
+    /// The bolt11 invoice or bolt12 offer to pay
     pub invoice: String,
+    /// The amount to pay, in satoshi. Required if the invoice or offer has no amount, otherwise
+    /// it must either be omitted or match its amount.
+    pub amount_sat: Option<u64>,
+    /// An optional key identifying the payment. See [PrepareSendResponse::idempotency_key]
+    pub idempotency_key: Option<String>,
+    /// An optional note to the recipient, only supported when paying a bolt12 offer
+    pub payer_note: Option<String>,
 }
 
 /// Returned when calling [crate::sdk::LiquidSdk::prepare_send_payment].
 #[derive(Debug, Serialize, Clone)]
 pub struct PrepareSendResponse {
+    /// The invoice to pay. When paying a bolt12 offer, the bolt12 invoice fetched for it.
     pub invoice: String,
+    /// The bolt12 offer being paid, if any
+    pub offer: Option<String>,
+    /// The amount the recipient will receive, in satoshi
+    pub receiver_amount_sat: u64,
     pub fees_sat: u64,
+    /// If set, calling [crate::sdk::LiquidSdk::send_payment] again with the same key returns the
+    /// payment started by the first call, instead of paying again
+    pub idempotency_key: Option<String>,
 }
 
-/// Returned when calling [crate::sdk::LiquidSdk::send_payment].
+/// A parsed bolt12 offer. See [crate::sdk::LiquidSdk::parse_offer]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct LNOffer {
+    /// The offer as a bech32 string
+    pub offer: String,
+    /// The hashes of the chains the offer can be paid on
+    pub chains: Vec<String>,
+    /// The amount to pay, in satoshi. If not set, the payer chooses the amount.
+    pub amount_sat: Option<u64>,
+    pub description: Option<String>,
+    /// The UNIX timestamp in seconds after which the offer can no longer be paid
+    pub absolute_expiry: Option<u64>,
+    pub issuer: Option<String>,
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::send_payment] or [crate::sdk::LiquidSdk::send_liquid].
 #[derive(Debug, Serialize)]
 pub struct SendPaymentResponse {
     pub payment: Payment,
 }
 
+/// An argument when calling [crate::sdk::LiquidSdk::prepare_send_liquid].
+#[derive(Debug, Serialize, Clone)]
+pub struct PrepareSendLiquidRequest {
+    /// A confidential Liquid address or a `liquidnetwork:` / `liquidtestnet:` BIP21 URI
+    pub destination: String,
+    /// The amount to send, in satoshi. Required if the destination has no amount and `drain`
+    /// is not set, otherwise it must either be omitted or match the destination amount.
+    pub amount_sat: Option<u64>,
+    /// If set, the whole wallet balance is sent and the receiver amount is the balance minus fees
+    pub drain: Option<bool>,
+    /// The id of the Liquid asset to send. Defaults to the asset of the destination, or L-BTC.
+    /// If set, `amount_sat` is in the asset base units and `drain` is not supported.
+    pub asset_id: Option<String>,
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::prepare_send_liquid].
+#[derive(Debug, Serialize, Clone)]
+pub struct PrepareSendLiquidResponse {
+    /// The Liquid address the funds will be sent to
+    pub address: String,
+    /// The amount received, in the asset base units if `asset_id` is set
+    pub receiver_amount_sat: u64,
+    /// The onchain fees, always paid in L-BTC
+    pub fees_sat: u64,
+    /// The description of the payment, taken from the BIP21 message or label
+    pub description: Option<String>,
+    /// Whether the whole wallet balance is sent
+    pub drain: bool,
+    /// The id of the Liquid asset sent, if other than L-BTC
+    pub asset_id: Option<String>,
+}
+
+/// A Liquid address, optionally wrapped in a BIP21 URI carrying the payment details
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct LiquidBip21 {
+    pub(crate) address: String,
+    /// The amount, parsed with 8 decimals. For assets with a different precision, see
+    /// [AssetMetadata::amount_from_bip21]
+    pub(crate) amount_sat: Option<u64>,
+    pub(crate) asset_id: Option<String>,
+    pub(crate) label: Option<String>,
+    pub(crate) message: Option<String>,
+}
+
+impl LiquidBip21 {
+    pub(crate) fn uri_scheme(network: LiquidNetwork) -> &'static str {
+        match network {
+            LiquidNetwork::Mainnet => "liquidnetwork",
+            LiquidNetwork::Testnet | LiquidNetwork::Regtest => "liquidtestnet",
+        }
+    }
+
+    /// Parses either a plain Liquid address or a BIP21 URI, and validates it against the network
+    pub(crate) fn parse(input: &str, network: LiquidNetwork) -> Result<Self, PaymentError> {
+        let input = input.trim();
+        let res = match input.split_once(':') {
+            Some((scheme, _)) => {
+                ensure_sdk!(
+                    scheme.eq_ignore_ascii_case(Self::uri_scheme(network)),
+                    PaymentError::InvalidDestination {
+                        err: format!("Unsupported URI scheme: {scheme}")
+                    }
+                );
+                let uri = Url::parse(input).map_err(|e| PaymentError::InvalidDestination {
+                    err: format!("Invalid BIP21 URI: {e}"),
+                })?;
+
+                let mut res = Self {
+                    address: uri.path().to_string(),
+                    ..Default::default()
+                };
+                for (key, value) in uri.query_pairs() {
+                    match key.as_ref() {
+                        "amount" => {
+                            res.amount_sat = Some(
+                                Amount::from_str_in(&value, Denomination::Bitcoin)
+                                    .map_err(|e| PaymentError::InvalidDestination {
+                                        err: format!("Invalid BIP21 amount: {e}"),
+                                    })?
+                                    .to_sat(),
+                            )
+                        }
+                        "assetid" => res.asset_id = Some(value.to_string()),
+                        "label" => res.label = Some(value.to_string()),
+                        "message" => res.message = Some(value.to_string()),
+                        key if key.starts_with("req-") => {
+                            return Err(PaymentError::InvalidDestination {
+                                err: format!("Unsupported required BIP21 parameter: {key}"),
+                            })
+                        }
+                        _ => {}
+                    }
+                }
+                res
+            }
+            None => Self {
+                address: input.to_string(),
+                ..Default::default()
+            },
+        };
+
+        let address = ElementsAddress::from_str(&res.address).map_err(|e| {
+            PaymentError::InvalidDestination {
+                err: format!("Invalid Liquid address: {e}"),
+            }
+        })?;
+        ensure_sdk!(
+            address.params == network.address_params(),
+            PaymentError::InvalidDestination {
+                err: "Address cannot be used on the current network".to_string()
+            }
+        );
+        ensure_sdk!(
+            address.is_blinded(),
+            PaymentError::InvalidDestination {
+                err: "Address is not confidential".to_string()
+            }
+        );
+
+        if let Some(asset_id) = &res.asset_id {
+            AssetId::from_str(asset_id).map_err(|e| PaymentError::InvalidDestination {
+                err: format!("Invalid asset id {asset_id}: {e}"),
+            })?;
+        }
+
+        Ok(res)
+    }
+
+    /// Builds the BIP21 URI for this address and its payment details
+    pub(crate) fn to_uri(&self, network: LiquidNetwork) -> String {
+        let mut query = url::form_urlencoded::Serializer::new(String::new());
+        if let Some(amount_sat) = self.amount_sat {
+            query.append_pair(
+                "amount",
+                &Amount::from_sat(amount_sat).to_string_in(Denomination::Bitcoin),
+            );
+        }
+        if let Some(asset_id) = &self.asset_id {
+            query.append_pair("assetid", asset_id);
+        }
+        if let Some(label) = &self.label {
+            query.append_pair("label", label);
+        }
+        if let Some(message) = &self.message {
+            query.append_pair("message", message);
+        }
+
+        let scheme = Self::uri_scheme(network);
+        let query = query.finish();
+        match query.is_empty() {
+            true => format!("{scheme}:{}", self.address),
+            false => format!("{scheme}:{}?{query}", self.address),
+        }
+    }
+
+    /// The payment description, preferring the message over the label
+    pub(crate) fn description(&self) -> Option<String> {
+        self.message.clone().or(self.label.clone())
+    }
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::receive_liquid].
+#[derive(Debug, Serialize, Clone, Default)]
+pub struct ReceiveLiquidRequest {
+    /// The optional amount to request, in satoshi
+    pub amount_sat: Option<u64>,
+    /// The optional description, shown to the payer and kept on the resulting payment
+    pub description: Option<String>,
+    /// The id of the Liquid asset to request. If set, `amount_sat` is in the asset base units.
+    pub asset_id: Option<String>,
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::receive_liquid].
+#[derive(Debug, Serialize)]
+pub struct ReceiveLiquidResponse {
+    pub address: String,
+    /// A `liquidnetwork:` / `liquidtestnet:` BIP21 URI with the requested amount and description
+    pub bip21: String,
+}
+
+/// A Liquid address handed out by [crate::sdk::LiquidSdk::receive_liquid], used to match incoming txs
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct LiquidReceiveAddress {
+    pub(crate) address: String,
+    pub(crate) amount_sat: Option<u64>,
+    pub(crate) description: Option<String>,
+    pub(crate) created_at: u32,
+}
+
 /// An argument when calling [crate::sdk::LiquidSdk::prepare_pay_onchain].
 #[derive(Debug, Serialize, Clone)]
 pub struct PreparePayOnchainRequest {
+    /// The amount to be received. Ignored if `drain` is set.
     pub receiver_amount_sat: u64,
     pub sat_per_vbyte: Option<u32>,
+    /// If set, the whole wallet balance is sent and the receiver amount is the balance minus fees
+    pub drain: Option<bool>,
 }
 
 /// Returned when calling [crate::sdk::LiquidSdk::prepare_pay_onchain].
@@ -279,6 +815,8 @@ pub struct PreparePayOnchainResponse {
     pub receiver_amount_sat: u64,
     pub claim_fees_sat: u64,
     pub total_fees_sat: u64,
+    /// Whether the whole wallet balance is sent
+    pub drain: bool,
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::pay_onchain].
@@ -286,19 +824,28 @@ pub struct PreparePayOnchainResponse {
 pub struct PayOnchainRequest {
     pub address: String,
     pub prepare_res: PreparePayOnchainResponse,
+    /// If set, calling [crate::sdk::LiquidSdk::pay_onchain] again with the same key returns the
+    /// payment started by the first call, instead of paying again
+    pub idempotency_key: Option<String>,
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::prepare_receive_onchain].
 #[derive(Debug, Serialize, Clone)]
 pub struct PrepareReceiveOnchainRequest {
-    pub payer_amount_sat: u64,
+    /// The amount to be paid by the payer. If not set, any amount within the swapper limits can
+    /// be paid, and the swap continues once the fees for the actual amount are accepted.
+    pub payer_amount_sat: Option<u64>,
 }
 
 /// Returned when calling [crate::sdk::LiquidSdk::prepare_receive_onchain].
 #[derive(Debug, Serialize, Clone)]
 pub struct PrepareReceiveOnchainResponse {
-    pub payer_amount_sat: u64,
+    pub payer_amount_sat: Option<u64>,
+    /// The total fees. If no amount is set, only the fixed part of them, on top of which the
+    /// swapper takes `swapper_feerate` percent of the amount paid.
     pub fees_sat: u64,
+    /// The percentage of the amount paid taken by the swapper
+    pub swapper_feerate: f64,
 }
 
 /// Returned when calling [crate::sdk::LiquidSdk::receive_onchain].
@@ -344,6 +891,53 @@ pub struct RefundResponse {
     pub refund_tx_id: String,
 }
 
+/// An argument when calling [crate::sdk::LiquidSdk::bump_refund_fee].
+#[derive(Debug, Serialize)]
+pub struct BumpRefundFeeRequest {
+    /// The address where the swap funds are locked up
+    pub swap_address: String,
+    /// The new fee rate in sat/vB for the refund transaction
+    pub sat_per_vbyte: u32,
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::bump_refund_fee].
+#[derive(Debug, Serialize)]
+pub struct BumpRefundFeeResponse {
+    /// The id of the replacement refund transaction
+    pub refund_tx_id: String,
+    /// The id of the refund transaction that was replaced
+    pub replaced_tx_id: String,
+    /// The absolute fee of the replacement refund transaction
+    pub tx_fee_sat: u64,
+    /// How much more the replacement refund transaction pays in fees
+    pub fee_delta_sat: u64,
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::fetch_payment_proposed_fees].
+#[derive(Debug, Serialize)]
+pub struct FetchPaymentProposedFeesRequest {
+    /// The id of the swap, as emitted in [SdkEvent::SwapWaitingFeeAcceptance]
+    pub swap_id: String,
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::fetch_payment_proposed_fees].
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct FetchPaymentProposedFeesResponse {
+    pub swap_id: String,
+    /// The fees of the swap for the amount actually sent by the payer
+    pub fees_sat: u64,
+    /// The amount actually sent by the payer
+    pub payer_amount_sat: u64,
+    /// The amount that will be received if the fees are accepted
+    pub receiver_amount_sat: u64,
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::accept_payment_proposed_fees].
+#[derive(Debug, Serialize)]
+pub struct AcceptPaymentProposedFeesRequest {
+    pub response: FetchPaymentProposedFeesResponse,
+}
+
 /// Returned when calling [crate::sdk::LiquidSdk::get_info].
 #[derive(Debug, Serialize)]
 pub struct GetInfoResponse {
@@ -354,6 +948,46 @@ pub struct GetInfoResponse {
     /// Incoming amount that is pending from ongoing Receive swaps
     pub pending_receive_sat: u64,
     pub pubkey: String,
+    /// Balances of the Liquid assets other than L-BTC held by the wallet
+    pub asset_balances: Vec<AssetBalance>,
+    /// A breakdown of the balance, explaining why `balance_sat` may be lower than expected
+    pub balance_details: BalanceDetails,
+}
+
+/// A breakdown of the wallet balance, returned as part of [GetInfoResponse]
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct BalanceDetails {
+    /// Confirmed L-BTC balance, before subtracting the pending sends
+    pub confirmed_sat: u64,
+    /// L-BTC received in direct Liquid txs that are not yet confirmed
+    pub unconfirmed_receive_sat: u64,
+    /// L-BTC sent in direct Liquid txs that are not yet confirmed
+    pub unconfirmed_send_sat: u64,
+    /// L-BTC locked up in ongoing Send swaps and outgoing chain swaps
+    pub pending_send_swaps_sat: u64,
+    /// L-BTC incoming from ongoing Receive swaps and incoming chain swaps, not yet confirmed
+    pub pending_receive_swaps_sat: u64,
+    /// L-BTC being refunded from failed Send swaps and outgoing chain swaps
+    pub pending_refund_sat: u64,
+    /// BTC locked up in failed incoming chain swaps, which has to be refunded manually.
+    /// See [crate::sdk::LiquidSdk::list_refundables]
+    pub refundable_btc_sat: u64,
+    /// BTC being refunded from failed incoming chain swaps
+    pub pending_btc_refund_sat: u64,
+}
+
+/// The balance of a Liquid asset other than L-BTC, returned as part of [GetInfoResponse]
+#[derive(Clone, Debug, Serialize)]
+pub struct AssetBalance {
+    pub asset_id: String,
+    /// Usable balance in the asset base units. This is the confirmed balance minus pending sends.
+    pub balance: u64,
+    /// Outgoing amount in the asset base units that is not yet confirmed
+    pub pending_send: u64,
+    /// Incoming amount in the asset base units that is not yet confirmed
+    pub pending_receive: u64,
+    /// The asset metadata, if the asset is known. See [Config::asset_metadata]
+    pub asset_metadata: Option<AssetMetadata>,
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::backup].
@@ -366,6 +1000,28 @@ pub struct BackupRequest {
     pub backup_path: Option<String>,
 }
 
+/// The file format of [ExportPaymentsRequest]
+#[derive(Clone, Copy, Debug, EnumString, Eq, PartialEq, Serialize)]
+#[strum(serialize_all = "lowercase")]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::export_payments].
+#[derive(Debug, Serialize)]
+pub struct ExportPaymentsRequest {
+    /// Path of the file to export the payments to. Any existing file is replaced.
+    pub export_path: String,
+    pub format: ExportFormat,
+    /// The optional code of the fiat currency to value the payments in, e.g. `USD`
+    pub fiat_currency: Option<String>,
+    /// Epoch time, in seconds
+    pub from_timestamp: Option<i64>,
+    /// Epoch time, in seconds
+    pub to_timestamp: Option<i64>,
+}
+
 /// An argument when calling [crate::sdk::LiquidSdk::restore].
 #[derive(Debug, Serialize)]
 pub struct RestoreRequest {
@@ -382,6 +1038,109 @@ pub struct ListPaymentsRequest {
     pub to_timestamp: Option<i64>,
     pub offset: Option<u32>,
     pub limit: Option<u32>,
+    /// Only payments matching all of these metadata filters are listed
+    pub metadata_filters: Option<Vec<PaymentMetadataFilter>>,
+    /// Only payments in any of these states are listed
+    pub states: Option<Vec<PaymentState>>,
+    /// Only payments of any of these kinds are listed
+    pub kinds: Option<Vec<PaymentKind>>,
+    /// The minimum payment amount, inclusive. See [Payment::amount_sat]
+    pub from_amount_sat: Option<u64>,
+    /// The maximum payment amount, inclusive. See [Payment::amount_sat]
+    pub to_amount_sat: Option<u64>,
+    /// Only payments of this Liquid asset are listed. Set it to the L-BTC asset id to exclude
+    /// the payments of other assets.
+    pub asset_id: Option<String>,
+    /// Only payments whose description or bolt11 invoice contain this text are listed. The search
+    /// is case-insensitive.
+    pub search: Option<String>,
+    /// Only payments after this cursor are listed. Set it to the [ListPaymentsResponse::next_cursor]
+    /// of the previous page to get the next one. Cannot be combined with `offset`, and only used
+    /// by [crate::sdk::LiquidSdk::list_payments_page].
+    pub cursor: Option<String>,
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::list_payments_page].
+#[derive(Debug, Serialize)]
+pub struct ListPaymentsResponse {
+    pub payments: Vec<Payment>,
+    /// The opaque cursor of the next page, set only if this page is full. See
+    /// [ListPaymentsRequest::cursor]
+    pub next_cursor: Option<String>,
+}
+
+/// How a [Payment] is made, used to filter [ListPaymentsRequest]
+#[derive(Clone, Copy, Debug, EnumString, Eq, PartialEq, Serialize)]
+#[strum(serialize_all = "lowercase")]
+pub enum PaymentKind {
+    /// A Lightning payment sent via a submarine swap
+    Submarine,
+    /// A Lightning payment received via a reverse swap
+    Reverse,
+    /// A Bitcoin payment sent or received via a chain swap
+    Chain,
+    /// A Liquid payment without any swap
+    Direct,
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::get_payment].
+#[derive(Clone, Debug)]
+pub enum PaymentLookup {
+    /// The id of the swap of a Lightning or onchain payment
+    SwapId { swap_id: String },
+    /// The bolt11 invoice paid or received via a swap
+    Invoice { invoice: String },
+    /// The payment hash of the bolt11 invoice paid or received via a swap
+    PaymentHash { payment_hash: String },
+    /// Any tx of the payment, e.g. the lockup, claim or refund tx of a swap
+    TxId { tx_id: String },
+    /// The Liquid address a direct Liquid payment was sent to or received on
+    Destination { address: String },
+}
+
+/// A filter on the [Payment::metadata], used in [ListPaymentsRequest]
+#[derive(Clone, Debug)]
+pub struct PaymentMetadataFilter {
+    pub key: String,
+    /// If set, the metadata value has to match it. Otherwise the key only has to be present.
+    pub value: Option<String>,
+}
+
+/// A user-defined key-value pair attached to a [Payment], such as a note, a label or an
+/// external order id
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PaymentMetadata {
+    pub key: String,
+    pub value: String,
+}
+
+/// A snapshot of the exchange rate of a fiat currency, recorded for a [Payment]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PaymentFiatRate {
+    /// The fiat currency code, e.g. `USD`
+    pub coin: String,
+    /// The BTC price in the fiat currency
+    pub value: f64,
+    /// Epoch time, in seconds, when the rate was recorded
+    pub created_at: u32,
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::set_payment_metadata].
+#[derive(Debug, Serialize)]
+pub struct SetPaymentMetadataRequest {
+    /// The tx id or swap id of the payment
+    pub payment_id: String,
+    pub key: String,
+    pub value: String,
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::delete_payment_metadata].
+#[derive(Debug, Serialize)]
+pub struct DeletePaymentMetadataRequest {
+    /// The tx id or swap id of the payment
+    pub payment_id: String,
+    /// The key to delete. If not set, all the metadata of the payment is deleted.
+    pub key: Option<String>,
 }
 
 // A swap enum variant
@@ -441,6 +1200,83 @@ impl SwapTxV2 {
             _ => Err(anyhow!("Invalid chain")),
         }
     }
+
+    /// Creates a tx of the given kind spending the swap script output `utxo` to `output_address`
+    pub(crate) fn new(
+        kind: SwapTxKind,
+        swap_script: SwapScriptV2,
+        output_address: &str,
+        utxo: Utxo,
+        network: LiquidNetwork,
+    ) -> Result<Self> {
+        let swap_tx = match (swap_script, utxo) {
+            (SwapScriptV2::Bitcoin(swap_script), Utxo::Bitcoin(utxo)) => {
+                let output_address = bitcoin::Address::from_str(output_address)?
+                    .require_network(network.as_bitcoin_network())?;
+                SwapTxV2::Bitcoin(BtcSwapTx {
+                    kind,
+                    swap_script,
+                    output_address,
+                    utxo,
+                })
+            }
+            (SwapScriptV2::Liquid(swap_script), Utxo::Liquid(utxo)) => {
+                let output_address = ElementsAddress::from_str(output_address)?;
+                ensure!(
+                    output_address.params == network.address_params(),
+                    "Address {output_address} cannot be used on the {network:?} network"
+                );
+                let (funding_outpoint, funding_utxo) = *utxo;
+                SwapTxV2::Liquid(LBtcSwapTx {
+                    kind,
+                    swap_script,
+                    output_address,
+                    funding_outpoint,
+                    funding_utxo,
+                    genesis_hash: utils::liquid_genesis_hash(network)?,
+                })
+            }
+            _ => return Err(anyhow!("Invalid chain")),
+        };
+        Ok(swap_tx)
+    }
+}
+
+/// An unspent output of a swap script, as found on its chain
+#[derive(Clone, Debug)]
+pub(crate) enum Utxo {
+    Bitcoin((bitcoin::OutPoint, bitcoin::TxOut)),
+    Liquid(Box<(elements::OutPoint, elements::TxOut)>),
+}
+impl Utxo {
+    pub(crate) fn as_bitcoin(&self) -> Result<(bitcoin::OutPoint, bitcoin::TxOut)> {
+        match self {
+            Utxo::Bitcoin(utxo) => Ok(utxo.clone()),
+            _ => Err(anyhow!("Invalid chain")),
+        }
+    }
+
+    pub(crate) fn as_liquid(&self) -> Result<(elements::OutPoint, elements::TxOut)> {
+        match self {
+            Utxo::Liquid(utxo) => Ok(*utxo.clone()),
+            _ => Err(anyhow!("Invalid chain")),
+        }
+    }
+}
+
+/// A signed swap tx, which is broadcast through the chain service of its chain
+#[derive(Clone, Debug)]
+pub(crate) enum SwapTransaction {
+    Bitcoin(bitcoin::Transaction),
+    Liquid(elements::Transaction),
+}
+impl SwapTransaction {
+    pub(crate) fn as_liquid(&self) -> Result<elements::Transaction> {
+        match self {
+            SwapTransaction::Liquid(tx) => Ok(tx.clone()),
+            _ => Err(anyhow!("Invalid chain")),
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Serialize)]
@@ -494,6 +1330,11 @@ pub(crate) struct ChainSwap {
     pub(crate) state: PaymentState,
     pub(crate) claim_private_key: String,
     pub(crate) refund_private_key: String,
+    /// The idempotency key of the [crate::sdk::LiquidSdk::pay_onchain] call that created the swap
+    pub(crate) idempotency_key: Option<String>,
+    /// Whether the swap was prepared to send the whole wallet balance, so that its lockup tx
+    /// leaves no change
+    pub(crate) drain: bool,
 }
 impl ChainSwap {
     pub(crate) fn get_claim_keypair(&self) -> SdkResult<Keypair> {
@@ -592,6 +1433,12 @@ pub(crate) struct SendSwap {
     pub(crate) created_at: u32,
     pub(crate) state: PaymentState,
     pub(crate) refund_private_key: String,
+    /// The idempotency key of the [crate::sdk::LiquidSdk::send_payment] call that created the swap
+    pub(crate) idempotency_key: Option<String>,
+    /// The BOLT12 offer the invoice was fetched for, if paying an offer
+    pub(crate) bolt12_offer: Option<String>,
+    /// The payer note included in the invoice fetched for the BOLT12 offer
+    pub(crate) payer_note: Option<String>,
 }
 impl SendSwap {
     pub(crate) fn get_refund_keypair(&self) -> Result<Keypair, PaymentError> {
@@ -764,7 +1611,7 @@ impl From<ChainSwap> for RefundableSwap {
 }
 
 /// The payment state of an individual payment.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Hash)]
+#[derive(Clone, Copy, Debug, EnumString, Eq, PartialEq, Serialize, Hash)]
 pub enum PaymentState {
     Created = 0,
 
@@ -829,6 +1676,15 @@ pub enum PaymentState {
     ///
     /// When the refund tx is broadcast, `refund_tx_id` is set in the swap.
     RefundPending = 6,
+
+    /// ## Incoming Chain Swaps
+    ///
+    /// This is the status when the user lockup amount differs from the agreed one and the swapper
+    /// proposed new fees for it. They can be accepted by calling
+    /// [crate::sdk::LiquidSdk::fetch_payment_proposed_fees] then
+    /// [crate::sdk::LiquidSdk::accept_payment_proposed_fees], otherwise the swap becomes
+    /// refundable once it expires.
+    WaitingFeeAcceptance = 7,
 }
 impl ToSql for PaymentState {
     fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
@@ -846,6 +1702,7 @@ impl FromSql for PaymentState {
                 4 => Ok(PaymentState::TimedOut),
                 5 => Ok(PaymentState::Refundable),
                 6 => Ok(PaymentState::RefundPending),
+                7 => Ok(PaymentState::WaitingFeeAcceptance),
                 _ => Err(FromSqlError::OutOfRange(i)),
             },
             _ => Err(FromSqlError::InvalidType),
@@ -918,15 +1775,34 @@ pub struct PaymentTxData {
     /// The onchain tx amount.
     ///
     /// In case of an outbound payment (Send), this is the payer amount. Otherwise it's the receiver amount.
+    /// For asset txs, this is the asset amount in base units.
     pub amount_sat: u64,
 
-    /// The onchain fees of this tx
+    /// The onchain fees of this tx, always paid in L-BTC
     pub fees_sat: u64,
 
     pub payment_type: PaymentType,
 
     /// Onchain tx status
     pub is_confirmed: bool,
+
+    /// The id of the Liquid asset transferred, if other than L-BTC
+    pub asset_id: Option<String>,
+}
+
+/// Additional details of a direct Liquid payment, which are not part of the onchain tx
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PaymentTxDetails {
+    pub tx_id: String,
+
+    /// The Liquid address the funds were sent to
+    pub destination: Option<String>,
+
+    pub description: Option<String>,
+
+    /// The idempotency key of the [crate::sdk::LiquidSdk::send_payment] call that made the
+    /// payment, if paid directly to the MRH address of the invoice
+    pub idempotency_key: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -975,6 +1851,7 @@ pub struct Payment {
     /// The payment amount, which corresponds to the onchain tx amount.
     ///
     /// In case of an outbound payment (Send), this is the payer amount. Otherwise it's the receiver amount.
+    /// For asset payments, this is the asset amount in base units.
     pub amount_sat: u64,
 
     /// Represents the fees paid by this wallet for this payment.
@@ -990,6 +1867,8 @@ pub struct Payment {
     /// If no swap is associated with this payment:
     /// - for Send payments, this is the onchain tx fee
     /// - for Receive payments, this is zero
+    ///
+    /// Fees are always paid in L-BTC, also for asset payments.
     pub fees_sat: u64,
 
     /// In case of a Send swap, this is the preimage of the paid invoice (proof of payment).
@@ -1003,6 +1882,12 @@ pub struct Payment {
     /// Represents the invoice description
     pub description: String,
 
+    /// For a direct Liquid payment, this is the Liquid address the funds were sent to
+    pub destination: Option<String>,
+
+    /// The id of the Liquid asset transferred, if other than L-BTC. Swaps are always in L-BTC.
+    pub asset_id: Option<String>,
+
     /// For a Send swap which was refunded, this is the refund tx id
     pub refund_tx_id: Option<String>,
 
@@ -1018,6 +1903,13 @@ pub struct Payment {
     ///
     /// If the tx has an associated swap, this is determined by the swap status (pending or complete).
     pub status: PaymentState,
+
+    /// The user-defined metadata of the payment, see [crate::sdk::LiquidSdk::set_payment_metadata]
+    pub metadata: Vec<PaymentMetadata>,
+
+    /// The exchange rates of the [Config::fiat_currencies] at the time the payment was created
+    /// or first seen, sorted by currency
+    pub fiat_rates: Vec<PaymentFiatRate>,
 }
 impl Payment {
     pub(crate) fn from_pending_swap(swap: PaymentSwapData, payment_type: PaymentType) -> Payment {
@@ -1035,14 +1927,22 @@ impl Payment {
             preimage: swap.preimage,
             bolt11: swap.bolt11,
             description: swap.description,
+            destination: None,
+            asset_id: None,
             refund_tx_id: swap.refund_tx_id,
             refund_tx_amount_sat: swap.refund_tx_amount_sat,
             payment_type,
             status: swap.status,
+            metadata: vec![],
+            fiat_rates: vec![],
         }
     }
 
-    pub(crate) fn from_tx_data(tx: PaymentTxData, swap: Option<PaymentSwapData>) -> Payment {
+    pub(crate) fn from_tx_data(
+        tx: PaymentTxData,
+        swap: Option<PaymentSwapData>,
+        details: Option<PaymentTxDetails>,
+    ) -> Payment {
         Payment {
             tx_id: Some(tx.tx_id),
             swap_id: swap.as_ref().map(|s| s.swap_id.clone()),
@@ -1063,7 +1963,10 @@ impl Payment {
             description: swap
                 .as_ref()
                 .map(|s| s.description.clone())
+                .or(details.as_ref().and_then(|d| d.description.clone()))
                 .unwrap_or("Liquid transfer".to_string()),
+            destination: details.and_then(|d| d.destination),
+            asset_id: tx.asset_id,
             refund_tx_id: swap.as_ref().and_then(|s| s.refund_tx_id.clone()),
             refund_tx_amount_sat: swap.as_ref().and_then(|s| s.refund_tx_amount_sat),
             payment_type: tx.payment_type,
@@ -1074,6 +1977,8 @@ impl Payment {
                     false => PaymentState::Pending,
                 },
             },
+            metadata: vec![],
+            fiat_rates: vec![],
         }
     }
 }
@@ -1129,6 +2034,44 @@ pub struct LogEntry {
     pub level: String,
 }
 
+/// A call to the external signer of the Dart bindings, which is implemented by the app. It is
+/// answered by passing the same `id` to `respond_to_signer_request`, see `connect_with_signer`.
+#[derive(Clone, Debug)]
+pub struct SignerRequest {
+    pub id: u32,
+    pub method: SignerMethod,
+}
+
+/// The [crate::signer::Signer] method called by a [SignerRequest], along with its arguments
+#[derive(Clone, Debug)]
+pub enum SignerMethod {
+    Xpub,
+    DeriveXpub {
+        derivation_path: String,
+    },
+    Slip77MasterBlindingKey,
+    SignPset {
+        pset: String,
+    },
+    SignEcdsa {
+        msg: Vec<u8>,
+        derivation_path: String,
+    },
+    HmacSha256 {
+        msg: Vec<u8>,
+        derivation_path: String,
+    },
+}
+
+/// The result of a [SignerRequest]. The xpub, blinding key and PSET methods return a string,
+/// while the ECDSA and HMAC methods return bytes.
+#[derive(Clone, Debug)]
+pub enum SignerResult {
+    String { value: String },
+    Bytes { value: Vec<u8> },
+    Error { err: String },
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 struct InternalLeaf {
     pub output: String,
@@ -1198,28 +2141,100 @@ pub struct LnUrlPaySuccessData {
 }
 
 #[macro_export]
-macro_rules! get_invoice_amount {
+macro_rules! get_invoice_description {
     ($invoice:expr) => {
-        $invoice
-            .parse::<Bolt11Invoice>()
-            .expect("Expecting valid invoice")
-            .amount_milli_satoshis()
-            .expect("Expecting valid amount")
-            / 1000
+        match $crate::bolt12::is_invoice(&$invoice) {
+            true => $crate::bolt12::get_invoice_description(&$invoice),
+            false => match $invoice
+                .trim()
+                .parse::<Bolt11Invoice>()
+                .expect("Expecting valid invoice")
+                .description()
+            {
+                Bolt11InvoiceDescription::Direct(msg) => Some(msg.to_string()),
+                Bolt11InvoiceDescription::Hash(_) => None,
+            },
+        }
     };
 }
 
-#[macro_export]
-macro_rules! get_invoice_description {
-    ($invoice:expr) => {
-        match $invoice
-            .trim()
-            .parse::<Bolt11Invoice>()
-            .expect("Expecting valid invoice")
-            .description()
-        {
-            Bolt11InvoiceDescription::Direct(msg) => Some(msg.to_string()),
-            Bolt11InvoiceDescription::Hash(_) => None,
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::error::PaymentError;
+
+    use super::{AssetMetadata, Config, LiquidBip21, LiquidNetwork};
+
+    const TEST_ADDRESS: &str = "tlq1pq0wqu32e2xacxeyps22x8gjre4qk3u6r70pj4r62hzczxeyz8x3yxucrpn79zy28plc4x37aaf33kwt6dz2nn6gtkya6h02mwpzy4eh69zzexq7cf5y5";
+    const TEST_ASSET_ID: &str = "144c654344aa716d6f3abcc1ca90e5641e4e2a7f633bc09fe3baf64585819a49";
+
+    #[test]
+    fn test_parse_liquid_bip21() -> Result<()> {
+        let bip21 = LiquidBip21::parse(TEST_ADDRESS, LiquidNetwork::Testnet)?;
+        assert_eq!(bip21.address, TEST_ADDRESS);
+        assert_eq!(bip21.amount_sat, None);
+
+        let uri = format!(
+            "liquidtestnet:{TEST_ADDRESS}?amount=0.00001149&assetid={TEST_ASSET_ID}&label=Coffee&message=Two%20cups"
+        );
+        let bip21 = LiquidBip21::parse(&uri, LiquidNetwork::Testnet)?;
+        assert_eq!(bip21.address, TEST_ADDRESS);
+        assert_eq!(bip21.amount_sat, Some(1149));
+        assert_eq!(bip21.asset_id.as_deref(), Some(TEST_ASSET_ID));
+        assert_eq!(bip21.description(), Some("Two cups".to_string()));
+
+        // The URI can be rebuilt from the parsed data
+        let rebuilt = LiquidBip21::parse(
+            &bip21.to_uri(LiquidNetwork::Testnet),
+            LiquidNetwork::Testnet,
+        )?;
+        assert_eq!(rebuilt, bip21);
+
+        // Wrong network, scheme or asset
+        for input in [
+            format!("liquidnetwork:{TEST_ADDRESS}"),
+            format!("bitcoin:{TEST_ADDRESS}"),
+            format!("liquidtestnet:{TEST_ADDRESS}?assetid=usdt"),
+            format!("liquidtestnet:{TEST_ADDRESS}?req-unknown=1"),
+        ] {
+            assert!(matches!(
+                LiquidBip21::parse(&input, LiquidNetwork::Testnet),
+                Err(PaymentError::InvalidDestination { .. })
+            ));
         }
-    };
+        assert!(LiquidBip21::parse(TEST_ADDRESS, LiquidNetwork::Mainnet).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_asset_metadata() -> Result<()> {
+        let mut config = Config::testnet();
+        let usdt = config
+            .get_asset_metadata("b612eb46313a2cd6ebabd8b7a8eed5696e29898b87a43bff41c94f51acef9d73")
+            .expect("Expecting USDt in the built-in registry");
+        assert_eq!(usdt.ticker, "USDt");
+        assert_eq!(usdt.amount_from_bip21(1_500_000_000)?, 1_500_000_000);
+
+        let test_asset = AssetMetadata {
+            asset_id: "00".repeat(32),
+            name: "Test asset".to_string(),
+            ticker: "TEST".to_string(),
+            precision: 2,
+        };
+        config.asset_metadata = Some(vec![test_asset.clone()]);
+        assert_eq!(
+            config.get_asset_metadata(&"00".repeat(32)),
+            Some(test_asset.clone())
+        );
+        assert!(config.get_asset_metadata(&"11".repeat(32)).is_none());
+
+        // 1.50 TEST is parsed from a BIP21 URI as 150_000_000
+        assert_eq!(test_asset.amount_from_bip21(150_000_000)?, 150);
+        assert_eq!(test_asset.amount_to_bip21(150), 150_000_000);
+        assert!(test_asset.amount_from_bip21(150_000_001).is_err());
+
+        Ok(())
+    }
 }
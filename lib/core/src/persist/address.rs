@@ -0,0 +1,112 @@
+use anyhow::Result;
+use rusqlite::{params, Connection, Row};
+
+use super::Persister;
+use crate::model::LiquidReceiveAddress;
+
+impl Persister {
+    pub(crate) fn insert_or_update_liquid_receive_address(
+        &self,
+        receive_address: &LiquidReceiveAddress,
+    ) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute(
+            "INSERT OR REPLACE INTO liquid_receive_addresses (
+                address,
+                amount_sat,
+                description,
+                created_at
+            )
+            VALUES (?, ?, ?, ?)",
+            (
+                &receive_address.address,
+                receive_address.amount_sat,
+                &receive_address.description,
+                receive_address.created_at,
+            ),
+        )?;
+
+        Ok(())
+    }
+
+    fn list_liquid_receive_addresses_query(where_clauses: Vec<String>) -> String {
+        let mut where_clause_str = String::new();
+        if !where_clauses.is_empty() {
+            where_clause_str = String::from("WHERE ");
+            where_clause_str.push_str(where_clauses.join(" AND ").as_str());
+        }
+
+        format!(
+            "
+            SELECT
+                address,
+                amount_sat,
+                description,
+                created_at
+            FROM liquid_receive_addresses
+            {where_clause_str}
+            ORDER BY created_at
+        "
+        )
+    }
+
+    fn sql_row_to_liquid_receive_address(row: &Row) -> rusqlite::Result<LiquidReceiveAddress> {
+        Ok(LiquidReceiveAddress {
+            address: row.get(0)?,
+            amount_sat: row.get(1)?,
+            description: row.get(2)?,
+            created_at: row.get(3)?,
+        })
+    }
+
+    pub(crate) fn list_liquid_receive_addresses(&self) -> Result<Vec<LiquidReceiveAddress>> {
+        let con: Connection = self.get_connection()?;
+        let query = Self::list_liquid_receive_addresses_query(vec![]);
+        let receive_addresses = con
+            .prepare(&query)?
+            .query_map(params![], Self::sql_row_to_liquid_receive_address)?
+            .map(|i| i.unwrap())
+            .collect();
+
+        Ok(receive_addresses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::{model::LiquidReceiveAddress, test_utils::persist::new_persister, utils};
+
+    #[test]
+    fn test_list_liquid_receive_addresses() -> Result<()> {
+        let (_temp_dir, storage) = new_persister()?;
+
+        let receive_address = LiquidReceiveAddress {
+            address: "tlq1-address".to_string(),
+            amount_sat: Some(1000),
+            description: Some("Coffee".to_string()),
+            created_at: utils::now(),
+        };
+        storage.insert_or_update_liquid_receive_address(&receive_address)?;
+        assert_eq!(
+            storage.list_liquid_receive_addresses()?,
+            vec![receive_address.clone()]
+        );
+
+        // Every request has its own address, so an earlier request is kept
+        let other_receive_address = LiquidReceiveAddress {
+            address: "tlq1-other-address".to_string(),
+            amount_sat: None,
+            description: Some("Tea".to_string()),
+            created_at: receive_address.created_at + 1,
+        };
+        storage.insert_or_update_liquid_receive_address(&other_receive_address)?;
+        assert_eq!(
+            storage.list_liquid_receive_addresses()?,
+            vec![receive_address, other_receive_address]
+        );
+
+        Ok(())
+    }
+}
@@ -5,6 +5,8 @@ use super::Persister;
 
 const KEY_SWAPPER_PROXY_URL: &str = "swapper_proxy_url";
 const KEY_IS_FIRST_SYNC_COMPLETE: &str = "is_first_sync_complete";
+const KEY_AUTO_REFUND_ADDRESS_INDEX: &str = "auto_refund_address_index";
+const KEY_LIQUID_RECEIVE_ADDRESS_INDEX: &str = "liquid_receive_address_index";
 
 impl Persister {
     pub fn get_cached_item(&self, key: &str) -> Result<Option<String>> {
@@ -52,6 +54,30 @@ impl Persister {
         self.get_cached_item(KEY_IS_FIRST_SYNC_COMPLETE)
             .map(|maybe_str| maybe_str.and_then(|val_str| bool::from_str(&val_str).ok()))
     }
+
+    pub fn set_auto_refund_address_index(&self, index: u32) -> Result<()> {
+        self.update_cached_item(KEY_AUTO_REFUND_ADDRESS_INDEX, index.to_string())
+    }
+
+    /// The index of the next xpub address to refund to, see [crate::model::RefundDestination]
+    pub fn get_auto_refund_address_index(&self) -> Result<u32> {
+        Ok(self
+            .get_cached_item(KEY_AUTO_REFUND_ADDRESS_INDEX)?
+            .and_then(|val_str| u32::from_str(&val_str).ok())
+            .unwrap_or_default())
+    }
+
+    pub fn set_liquid_receive_address_index(&self, index: u32) -> Result<()> {
+        self.update_cached_item(KEY_LIQUID_RECEIVE_ADDRESS_INDEX, index.to_string())
+    }
+
+    /// The lowest wallet address index [crate::sdk::LiquidSdk::receive_liquid] can hand out next
+    pub fn get_liquid_receive_address_index(&self) -> Result<u32> {
+        Ok(self
+            .get_cached_item(KEY_LIQUID_RECEIVE_ADDRESS_INDEX)?
+            .and_then(|val_str| u32::from_str(&val_str).ok())
+            .unwrap_or_default())
+    }
 }
 
 #[cfg(test)]
@@ -74,4 +100,26 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_auto_refund_address_index() -> Result<()> {
+        let (_temp_dir, persister) = new_persister()?;
+
+        assert_eq!(persister.get_auto_refund_address_index()?, 0);
+        persister.set_auto_refund_address_index(3)?;
+        assert_eq!(persister.get_auto_refund_address_index()?, 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_liquid_receive_address_index() -> Result<()> {
+        let (_temp_dir, persister) = new_persister()?;
+
+        assert_eq!(persister.get_liquid_receive_address_index()?, 0);
+        persister.set_liquid_receive_address_index(5)?;
+        assert_eq!(persister.get_liquid_receive_address_index()?, 5);
+
+        Ok(())
+    }
 }
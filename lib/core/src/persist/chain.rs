@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use anyhow::Result;
 use boltz_client::swaps::boltz::{ChainSwapDetails, CreateChainResponse};
-use rusqlite::{named_params, params, Connection, Row};
+use rusqlite::{named_params, params, Connection, OptionalExtension, Row};
 use serde::{Deserialize, Serialize};
 
 use crate::ensure_sdk;
@@ -62,7 +62,9 @@ impl Persister {
                 server_lockup_tx_id = :server_lockup_tx_id,
                 user_lockup_tx_id = :user_lockup_tx_id,
                 claim_tx_id = :claim_tx_id,
-                refund_tx_id = :refund_tx_id
+                refund_tx_id = :refund_tx_id,
+                idempotency_key = :idempotency_key,
+                drain = :drain
             WHERE
                 id = :id",
             named_params! {
@@ -72,6 +74,8 @@ impl Persister {
                 ":user_lockup_tx_id": &chain_swap.user_lockup_tx_id,
                 ":claim_tx_id": &chain_swap.claim_tx_id,
                 ":refund_tx_id": &chain_swap.refund_tx_id,
+                ":idempotency_key": &chain_swap.idempotency_key,
+                ":drain": &chain_swap.drain,
             },
         )?;
 
@@ -107,7 +111,9 @@ impl Persister {
                 claim_tx_id,
                 refund_tx_id,
                 created_at,
-                state
+                state,
+                idempotency_key,
+                drain
             FROM chain_swaps
             {where_clause_str}
             ORDER BY created_at
@@ -120,7 +126,7 @@ impl Persister {
         let query = Self::list_chain_swaps_query(vec!["id = ?1".to_string()]);
         let res = con.query_row(&query, [id], Self::sql_row_to_chain_swap);
 
-        Ok(res.ok())
+        Ok(res.optional()?)
     }
 
     pub(crate) fn fetch_chain_swap_by_lockup_address(
@@ -134,6 +140,28 @@ impl Persister {
         Ok(res.ok())
     }
 
+    /// Looks up a Chain Swap by any of its lockup, claim or refund tx ids
+    pub(crate) fn fetch_chain_swap_by_tx_id(&self, tx_id: &str) -> Result<Option<ChainSwap>> {
+        let con: Connection = self.get_connection()?;
+        let query = Self::list_chain_swaps_query(vec![
+            "?1 IN (user_lockup_tx_id, server_lockup_tx_id, claim_tx_id, refund_tx_id)".to_string(),
+        ]);
+        let res = con.query_row(&query, [tx_id], Self::sql_row_to_chain_swap);
+
+        Ok(res.optional()?)
+    }
+
+    pub(crate) fn fetch_chain_swap_by_idempotency_key(
+        &self,
+        idempotency_key: &str,
+    ) -> Result<Option<ChainSwap>> {
+        let con: Connection = self.get_connection()?;
+        let query = Self::list_chain_swaps_query(vec!["idempotency_key = ?1".to_string()]);
+        let res = con.query_row(&query, [idempotency_key], Self::sql_row_to_chain_swap);
+
+        Ok(res.optional()?)
+    }
+
     fn sql_row_to_chain_swap(row: &Row) -> rusqlite::Result<ChainSwap> {
         Ok(ChainSwap {
             id: row.get(0)?,
@@ -156,6 +184,8 @@ impl Persister {
             refund_tx_id: row.get(17)?,
             created_at: row.get(18)?,
             state: row.get(19)?,
+            idempotency_key: row.get(20)?,
+            drain: row.get(21)?,
         })
     }
 
@@ -188,7 +218,14 @@ impl Persister {
     }
 
     pub(crate) fn list_ongoing_chain_swaps(&self, con: &Connection) -> Result<Vec<ChainSwap>> {
-        self.list_chain_swaps_by_state(con, vec![PaymentState::Created, PaymentState::Pending])
+        self.list_chain_swaps_by_state(
+            con,
+            vec![
+                PaymentState::Created,
+                PaymentState::Pending,
+                PaymentState::WaitingFeeAcceptance,
+            ],
+        )
     }
 
     pub(crate) fn list_pending_chain_swaps(&self) -> Result<Vec<ChainSwap>> {
@@ -199,9 +236,19 @@ impl Persister {
         )
     }
 
+    /// Swaps whose lockup can be refunded, including those waiting for fee acceptance, as their
+    /// proposed fees can be declined by refunding instead
     pub(crate) fn list_refundable_chain_swaps(&self) -> Result<Vec<ChainSwap>> {
         let con: Connection = self.get_connection()?;
-        self.list_chain_swaps_by_state(&con, vec![PaymentState::Refundable])
+        self.list_chain_swaps_by_state(
+            &con,
+            vec![PaymentState::Refundable, PaymentState::WaitingFeeAcceptance],
+        )
+    }
+
+    pub(crate) fn list_refund_pending_chain_swaps(&self) -> Result<Vec<ChainSwap>> {
+        let con: Connection = self.get_connection()?;
+        self.list_chain_swaps_by_state(&con, vec![PaymentState::RefundPending])
     }
 
     /// Pending Chain swaps, indexed by refund tx id
@@ -242,6 +289,54 @@ impl Persister {
         Ok(())
     }
 
+    /// Sets the amounts of a Chain Swap after new fees were accepted for it
+    pub(crate) fn update_chain_swap_amounts(
+        &self,
+        swap_id: &str,
+        payer_amount_sat: u64,
+        receiver_amount_sat: u64,
+    ) -> Result<(), PaymentError> {
+        let con: Connection = self.get_connection()?;
+        con.execute(
+            "UPDATE chain_swaps
+            SET
+                payer_amount_sat = :payer_amount_sat,
+                receiver_amount_sat = :receiver_amount_sat
+            WHERE
+                id = :id",
+            named_params! {
+                ":id": swap_id,
+                ":payer_amount_sat": payer_amount_sat,
+                ":receiver_amount_sat": receiver_amount_sat,
+            },
+        )
+        .map_err(|_| PaymentError::PersistError)?;
+        Ok(())
+    }
+
+    /// Replaces the refund tx id of a Chain Swap, e.g. after its refund tx was fee bumped.
+    /// Unlike [Persister::try_handle_chain_swap_update], this overwrites any existing value.
+    pub(crate) fn update_chain_swap_refund_tx_id(
+        &self,
+        swap_id: &str,
+        refund_tx_id: &str,
+    ) -> Result<(), PaymentError> {
+        let con: Connection = self.get_connection()?;
+        con.execute(
+            "UPDATE chain_swaps
+            SET
+                refund_tx_id = :refund_tx_id
+            WHERE
+                id = :id",
+            named_params! {
+                ":id": swap_id,
+                ":refund_tx_id": refund_tx_id,
+            },
+        )
+        .map_err(|_| PaymentError::PersistError)?;
+        Ok(())
+    }
+
     pub(crate) fn try_handle_chain_swap_update(
         &self,
         swap_id: &str,
@@ -0,0 +1,97 @@
+use anyhow::Result;
+use rusqlite::params;
+
+use super::Persister;
+use crate::model::PaymentFiatRate;
+
+impl Persister {
+    /// Records the fiat rates of a payment. Rates already recorded for a currency are kept, so
+    /// the first snapshot of a payment is the one reported.
+    pub(crate) fn insert_payment_fiat_rates(
+        &self,
+        payment_id: &str,
+        rates: &[PaymentFiatRate],
+    ) -> Result<()> {
+        let mut con = self.get_connection()?;
+        let tx = con.transaction()?;
+        for rate in rates {
+            tx.execute(
+                "INSERT OR IGNORE INTO payment_fiat_rates (payment_id, coin, value, created_at)
+                VALUES (?1, ?2, ?3, ?4)",
+                (payment_id, &rate.coin, rate.value, rate.created_at),
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    pub(crate) fn list_payment_fiat_rates(&self, payment_id: &str) -> Result<Vec<PaymentFiatRate>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(
+            "SELECT coin, value, created_at FROM payment_fiat_rates
+            WHERE payment_id = ?1
+            ORDER BY coin",
+        )?;
+        let rates = stmt
+            .query_map(params![payment_id], |row| {
+                Ok(PaymentFiatRate {
+                    coin: row.get(0)?,
+                    value: row.get(1)?,
+                    created_at: row.get(2)?,
+                })
+            })?
+            .map(|i| i.unwrap())
+            .collect();
+        Ok(rates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::{
+        model::{PaymentFiatRate, PaymentType},
+        test_utils::persist::{new_payment_tx_data, new_persister},
+    };
+
+    #[test]
+    fn test_payment_fiat_rates() -> Result<()> {
+        let (_temp_dir, storage) = new_persister()?;
+
+        let payment_tx_data = new_payment_tx_data(PaymentType::Receive);
+        storage.insert_or_update_payment(payment_tx_data.clone())?;
+        let payment_id = payment_tx_data.tx_id.as_str();
+
+        let rates = vec![
+            PaymentFiatRate {
+                coin: "EUR".to_string(),
+                value: 55_000.0,
+                created_at: 1_700_000_000,
+            },
+            PaymentFiatRate {
+                coin: "USD".to_string(),
+                value: 60_000.0,
+                created_at: 1_700_000_000,
+            },
+        ];
+        storage.insert_payment_fiat_rates(payment_id, &rates)?;
+        assert_eq!(storage.list_payment_fiat_rates(payment_id)?, rates);
+
+        // A later snapshot does not replace the rates at the time of the payment
+        storage.insert_payment_fiat_rates(
+            payment_id,
+            &[PaymentFiatRate {
+                coin: "USD".to_string(),
+                value: 70_000.0,
+                created_at: 1_800_000_000,
+            }],
+        )?;
+        let payment = storage
+            .get_payment(payment_id.to_string())?
+            .expect("Expecting payment");
+        assert_eq!(payment.fiat_rates, rates);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+use anyhow::Result;
+use rusqlite::params;
+
+use super::Persister;
+use crate::model::PaymentMetadata;
+
+impl Persister {
+    pub(crate) fn set_payment_metadata(
+        &self,
+        payment_id: &str,
+        key: &str,
+        value: &str,
+    ) -> Result<()> {
+        self.get_connection()?.execute(
+            "INSERT OR REPLACE INTO payment_metadata (payment_id, key, value) VALUES (?1, ?2, ?3)",
+            (payment_id, key, value),
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn list_payment_metadata(&self, payment_id: &str) -> Result<Vec<PaymentMetadata>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(
+            "SELECT key, value FROM payment_metadata WHERE payment_id = ?1 ORDER BY key",
+        )?;
+        let metadata = stmt
+            .query_map(params![payment_id], |row| {
+                Ok(PaymentMetadata {
+                    key: row.get(0)?,
+                    value: row.get(1)?,
+                })
+            })?
+            .map(|i| i.unwrap())
+            .collect();
+        Ok(metadata)
+    }
+
+    /// Deletes the metadata `key` of a payment, or all its metadata if `key` is not set
+    pub(crate) fn delete_payment_metadata(
+        &self,
+        payment_id: &str,
+        key: Option<&str>,
+    ) -> Result<()> {
+        let con = self.get_connection()?;
+        match key {
+            Some(key) => con.execute(
+                "DELETE FROM payment_metadata WHERE payment_id = ?1 AND key = ?2",
+                (payment_id, key),
+            )?,
+            None => con.execute(
+                "DELETE FROM payment_metadata WHERE payment_id = ?1",
+                [payment_id],
+            )?,
+        };
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::{
+        model::{ListPaymentsRequest, PaymentMetadata, PaymentMetadataFilter, PaymentType},
+        test_utils::persist::{new_payment_tx_data, new_persister},
+    };
+
+    #[test]
+    fn test_payment_metadata() -> Result<()> {
+        let (_temp_dir, storage) = new_persister()?;
+
+        let payment_tx_data = new_payment_tx_data(PaymentType::Send);
+        storage.insert_or_update_payment(payment_tx_data.clone())?;
+        let payment_id = payment_tx_data.tx_id.as_str();
+
+        storage.set_payment_metadata(payment_id, "note", "Coffee")?;
+        storage.set_payment_metadata(payment_id, "order_id", "123")?;
+        // Setting an existing key replaces its value
+        storage.set_payment_metadata(payment_id, "note", "Tea")?;
+        let metadata = vec![
+            PaymentMetadata {
+                key: "note".to_string(),
+                value: "Tea".to_string(),
+            },
+            PaymentMetadata {
+                key: "order_id".to_string(),
+                value: "123".to_string(),
+            },
+        ];
+        assert_eq!(storage.list_payment_metadata(payment_id)?, metadata);
+        let payment = storage
+            .get_payment(payment_id.to_string())?
+            .expect("Expecting payment");
+        assert_eq!(payment.metadata, metadata);
+
+        let list_with_filter = |key: &str, value: Option<&str>| {
+            storage.get_payments(&ListPaymentsRequest {
+                metadata_filters: Some(vec![PaymentMetadataFilter {
+                    key: key.to_string(),
+                    value: value.map(str::to_string),
+                }]),
+                ..Default::default()
+            })
+        };
+        assert_eq!(list_with_filter("note", None)?.len(), 1);
+        assert_eq!(list_with_filter("note", Some("Tea"))?.len(), 1);
+        assert!(list_with_filter("note", Some("Coffee"))?.is_empty());
+        assert!(list_with_filter("label", None)?.is_empty());
+
+        storage.delete_payment_metadata(payment_id, Some("note"))?;
+        assert_eq!(
+            storage.list_payment_metadata(payment_id)?,
+            metadata[1..].to_vec()
+        );
+        storage.delete_payment_metadata(payment_id, None)?;
+        assert!(storage.list_payment_metadata(payment_id)?.is_empty());
+
+        Ok(())
+    }
+}
@@ -65,5 +65,54 @@ pub(crate) fn current_migrations() -> Vec<&'static str> {
         ALTER TABLE send_swaps ADD COLUMN description TEXT;
         ALTER TABLE chain_swaps ADD COLUMN description TEXT;
         ",
+        "CREATE TABLE IF NOT EXISTS payment_details (
+            tx_id TEXT NOT NULL PRIMARY KEY,
+            destination TEXT,
+            description TEXT
+        ) STRICT;",
+        "CREATE TABLE IF NOT EXISTS liquid_receive_addresses (
+            address TEXT NOT NULL PRIMARY KEY,
+            amount_sat INTEGER,
+            description TEXT,
+            created_at INTEGER NOT NULL
+        ) STRICT;",
+        "ALTER TABLE payment_tx_data ADD COLUMN asset_id TEXT;",
+        "CREATE TABLE IF NOT EXISTS payment_metadata (
+            payment_id TEXT NOT NULL,
+            key TEXT NOT NULL,
+            value TEXT NOT NULL,
+            PRIMARY KEY (payment_id, key)
+        ) STRICT;",
+        "
+        ALTER TABLE send_swaps ADD COLUMN idempotency_key TEXT;
+        ALTER TABLE chain_swaps ADD COLUMN idempotency_key TEXT;
+        ALTER TABLE payment_details ADD COLUMN idempotency_key TEXT;
+        CREATE UNIQUE INDEX IF NOT EXISTS send_swaps_idempotency_key ON send_swaps(idempotency_key);
+        CREATE UNIQUE INDEX IF NOT EXISTS chain_swaps_idempotency_key ON chain_swaps(idempotency_key);
+        CREATE UNIQUE INDEX IF NOT EXISTS payment_details_idempotency_key ON payment_details(idempotency_key);
+        ",
+        "
+        ALTER TABLE payment_tx_data ADD COLUMN first_seen_at INTEGER;
+        UPDATE payment_tx_data
+        SET first_seen_at = COALESCE(timestamp, CAST(strftime('%s', 'now') AS INTEGER));
+        ",
+        "CREATE TABLE IF NOT EXISTS payment_fiat_rates (
+            payment_id TEXT NOT NULL,
+            coin TEXT NOT NULL,
+            value REAL NOT NULL,
+            created_at INTEGER NOT NULL,
+            PRIMARY KEY (payment_id, coin)
+        ) STRICT;",
+        "
+        ALTER TABLE send_swaps ADD COLUMN bolt12_offer TEXT;
+        ALTER TABLE send_swaps ADD COLUMN payer_note TEXT;
+        ",
+        "
+        ALTER TABLE send_swaps ADD COLUMN payment_hash TEXT;
+        ALTER TABLE receive_swaps ADD COLUMN payment_hash TEXT;
+        CREATE INDEX IF NOT EXISTS send_swaps_payment_hash ON send_swaps(payment_hash);
+        CREATE INDEX IF NOT EXISTS receive_swaps_payment_hash ON receive_swaps(payment_hash);
+        ",
+        "ALTER TABLE chain_swaps ADD COLUMN drain INTEGER NOT NULL DEFAULT 0;",
     ]
 }
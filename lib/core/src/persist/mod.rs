@@ -1,23 +1,47 @@
+mod address;
 mod backup;
 mod cache;
 pub(crate) mod chain;
+mod fiat;
+mod metadata;
 mod migrations;
 pub(crate) mod receive;
 pub(crate) mod send;
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::{fs::create_dir_all, path::PathBuf, str::FromStr};
 
+use crate::bolt12;
 use crate::lightning_invoice::{Bolt11Invoice, Bolt11InvoiceDescription};
 use crate::model::*;
 use crate::{get_invoice_description, utils};
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, ensure, Result};
+use lwk_wollet::ElementsNetwork;
 use migrations::current_migrations;
-use rusqlite::{params, Connection, OptionalExtension, Row};
+use rusqlite::types::Type;
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension, Row};
 use rusqlite_migration::{Migrations, M};
 
 const DEFAULT_DB_FILENAME: &str = "storage.sql";
 
+/// The id of a row in the payments query: its swap id, or else its tx id
+const PAYMENT_ID_EXPR: &str = "COALESCE(rs.id, ss.id, cs.id, ptx.tx_id)";
+/// The sort key of a row in the payments query: its swap creation time, or else the time its tx
+/// was first seen. Unlike the tx timestamp, it does not change when a pending payment confirms.
+const PAYMENT_SORT_KEY_EXPR: &str =
+    "COALESCE(rs.created_at, ss.created_at, cs.created_at, ptx.first_seen_at)";
+/// The order of [Persister::get_payments]: by tx timestamp, or else by swap creation time
+const PAYMENT_TIMESTAMP_ORDER: &str =
+    "COALESCE(ptx.timestamp, rs.created_at, ss.created_at, cs.created_at) DESC";
+/// The amount of a row in the payments query, as in [Payment::amount_sat]: the tx amount, or else
+/// the receiver amount of an incoming swap or the payer amount of an outgoing one
+const PAYMENT_AMOUNT_SAT_EXPR: &str = "COALESCE(
+    ptx.amount_sat,
+    rs.receiver_amount_sat,
+    ss.payer_amount_sat,
+    CASE cs.direction WHEN 0 THEN cs.receiver_amount_sat ELSE cs.payer_amount_sat END -- 0 is Incoming
+)";
+
 pub(crate) struct Persister {
     main_db_dir: PathBuf,
     network: LiquidNetwork,
@@ -67,36 +91,159 @@ impl Persister {
         let migrations = Migrations::new(current_migrations().into_iter().map(M::up).collect());
         let mut conn = self.get_connection()?;
         migrations.to_latest(&mut conn)?;
+        Self::backfill_swap_payment_hashes(&conn)?;
+        Ok(())
+    }
+
+    /// Sets the payment hash of the swaps created before it was stored on insert
+    fn backfill_swap_payment_hashes(con: &Connection) -> Result<()> {
+        for table in ["send_swaps", "receive_swaps"] {
+            let invoices: Vec<(String, String)> = con
+                .prepare(&format!(
+                    "SELECT id, invoice FROM {table} WHERE payment_hash IS NULL"
+                ))?
+                .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+                .collect::<Result<_, _>>()?;
+            for (id, invoice) in invoices {
+                if let Ok(payment_hash) = bolt12::get_payment_hash(&invoice) {
+                    con.execute(
+                        &format!("UPDATE {table} SET payment_hash = ?1 WHERE id = ?2"),
+                        params![payment_hash, id],
+                    )?;
+                }
+            }
+        }
         Ok(())
     }
 
     pub(crate) fn fetch_swap_by_id(&self, id: &str) -> Result<Swap> {
-        match self.fetch_send_swap_by_id(id) {
-            Ok(Some(send_swap)) => Ok(Swap::Send(send_swap)),
-            _ => match self.fetch_receive_swap_by_id(id) {
-                Ok(Some(receive_swap)) => Ok(Swap::Receive(receive_swap)),
-                _ => match self.fetch_chain_swap_by_id(id) {
-                    Ok(Some(chain_swap)) => Ok(Swap::Chain(chain_swap)),
-                    _ => Err(anyhow!("Could not find Swap {id}")),
-                },
-            },
+        self.fetch_swap_by_id_if_exists(id)?
+            .ok_or(anyhow!("Could not find Swap {id}"))
+    }
+
+    fn fetch_swap_by_id_if_exists(&self, id: &str) -> Result<Option<Swap>> {
+        if let Some(send_swap) = self.fetch_send_swap_by_id(id)? {
+            return Ok(Some(Swap::Send(send_swap)));
+        }
+        if let Some(receive_swap) = self.fetch_receive_swap_by_id(id)? {
+            return Ok(Some(Swap::Receive(receive_swap)));
         }
+        Ok(self.fetch_chain_swap_by_id(id)?.map(Swap::Chain))
+    }
+
+    pub(crate) fn fetch_swap_by_invoice(&self, invoice: &str) -> Result<Option<Swap>> {
+        Ok(match self.fetch_send_swap_by_invoice(invoice)? {
+            Some(send_swap) => Some(Swap::Send(send_swap)),
+            None => self
+                .fetch_receive_swap_by_invoice(invoice)?
+                .map(Swap::Receive),
+        })
+    }
+
+    /// Looks up the Send or Receive Swap of the invoice with the given payment hash
+    pub(crate) fn fetch_swap_by_payment_hash(&self, payment_hash: &str) -> Result<Option<Swap>> {
+        let payment_hash = payment_hash.to_lowercase();
+        Ok(match self.fetch_send_swap_by_payment_hash(&payment_hash)? {
+            Some(send_swap) => Some(Swap::Send(send_swap)),
+            None => self
+                .fetch_receive_swap_by_payment_hash(&payment_hash)?
+                .map(Swap::Receive),
+        })
+    }
+
+    /// Looks up a swap by any of its lockup, claim or refund tx ids
+    pub(crate) fn fetch_swap_by_tx_id(&self, tx_id: &str) -> Result<Option<Swap>> {
+        Ok(match self.fetch_send_swap_by_tx_id(tx_id)? {
+            Some(send_swap) => Some(Swap::Send(send_swap)),
+            None => match self.fetch_receive_swap_by_tx_id(tx_id)? {
+                Some(receive_swap) => Some(Swap::Receive(receive_swap)),
+                None => self.fetch_chain_swap_by_tx_id(tx_id)?.map(Swap::Chain),
+            },
+        })
     }
 
     pub(crate) fn insert_or_update_payment(&self, ptx: PaymentTxData) -> Result<()> {
         let mut con = self.get_connection()?;
 
         let tx = con.transaction()?;
-        tx.execute(
-            "INSERT OR REPLACE INTO payment_tx_data (
+        Self::insert_or_update_payment_tx_data(&tx, ptx)?;
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    /// Inserts a payment made without a swap, before its tx is broadcast. The details are only
+    /// inserted if their idempotency key is not yet used, otherwise nothing is stored.
+    ///
+    /// Returns `false` if the idempotency key was already used.
+    pub(crate) fn insert_payment_with_details(
+        &self,
+        ptx: PaymentTxData,
+        details: PaymentTxDetails,
+    ) -> Result<bool> {
+        let mut con = self.get_connection()?;
+
+        let tx = con.transaction()?;
+        Self::insert_or_update_payment_tx_data(&tx, ptx)?;
+        let inserted = tx.execute(
+            "INSERT INTO payment_details (
+           tx_id,
+           destination,
+           description,
+           idempotency_key
+        )
+        VALUES (?, ?, ?, ?)
+        ON CONFLICT (idempotency_key) DO NOTHING
+        ",
+            (
+                details.tx_id,
+                details.destination,
+                details.description,
+                details.idempotency_key,
+            ),
+        )?;
+        // The tx data is rolled back when the transaction is dropped
+        if inserted == 0 {
+            return Ok(false);
+        }
+        tx.commit()?;
+
+        Ok(true)
+    }
+
+    /// Removes a payment inserted by [Persister::insert_payment_with_details], if its tx could not
+    /// be broadcast
+    pub(crate) fn delete_payment_with_details(&self, tx_id: &str) -> Result<()> {
+        let mut con = self.get_connection()?;
+
+        let tx = con.transaction()?;
+        tx.execute("DELETE FROM payment_details WHERE tx_id = ?1", [tx_id])?;
+        tx.execute("DELETE FROM payment_tx_data WHERE tx_id = ?1", [tx_id])?;
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    fn insert_or_update_payment_tx_data(con: &Connection, ptx: PaymentTxData) -> Result<()> {
+        con.execute(
+            "INSERT INTO payment_tx_data (
            tx_id,
            timestamp,
            amount_sat,
            fees_sat,
            payment_type,
-           is_confirmed
+           is_confirmed,
+           asset_id,
+           first_seen_at
         )
-        VALUES (?, ?, ?, ?, ?, ?)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        ON CONFLICT (tx_id) DO UPDATE SET
+           timestamp = excluded.timestamp,
+           amount_sat = excluded.amount_sat,
+           fees_sat = excluded.fees_sat,
+           payment_type = excluded.payment_type,
+           is_confirmed = excluded.is_confirmed,
+           asset_id = excluded.asset_id
         ",
             (
                 ptx.tx_id,
@@ -105,9 +252,52 @@ impl Persister {
                 ptx.fees_sat,
                 ptx.payment_type,
                 ptx.is_confirmed,
+                ptx.asset_id,
+                ptx.timestamp.unwrap_or(utils::now()),
+            ),
+        )?;
+
+        Ok(())
+    }
+
+    /// The fees of the wallet txs, by tx id
+    pub(crate) fn list_payment_tx_fees(&self) -> Result<HashMap<String, u64>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare("SELECT tx_id, fees_sat FROM payment_tx_data")?;
+        let tx_fees = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<HashMap<String, u64>, _>>()?;
+        Ok(tx_fees)
+    }
+
+    /// Whether the wallet txs are confirmed, by tx id
+    pub(crate) fn list_payment_tx_confirmations(&self) -> Result<HashMap<String, bool>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare("SELECT tx_id, is_confirmed FROM payment_tx_data")?;
+        let tx_confirmations = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<HashMap<String, bool>, _>>()?;
+        Ok(tx_confirmations)
+    }
+
+    pub(crate) fn insert_or_update_payment_details(&self, details: PaymentTxDetails) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute(
+            "INSERT OR REPLACE INTO payment_details (
+           tx_id,
+           destination,
+           description,
+           idempotency_key
+        )
+        VALUES (?, ?, ?, ?)
+        ",
+            (
+                details.tx_id,
+                details.destination,
+                details.description,
+                details.idempotency_key,
             ),
         )?;
-        tx.commit()?;
 
         Ok(())
     }
@@ -140,6 +330,7 @@ impl Persister {
     fn select_payment_query(
         &self,
         where_clause: Option<&str>,
+        order_by: &str,
         offset: Option<u32>,
         limit: Option<u32>,
     ) -> String {
@@ -177,7 +368,25 @@ impl Persister {
                 cs.payer_amount_sat,
                 cs.receiver_amount_sat,
                 cs.state,
-                rtx.amount_sat
+                rtx.amount_sat,
+                pd.destination,
+                pd.description,
+                ptx.asset_id,
+                (
+                    SELECT json_group_array(json_object('key', pm.key, 'value', pm.value))
+                    FROM payment_metadata AS pm
+                    WHERE pm.payment_id = {PAYMENT_ID_EXPR}
+                ),
+                pd.idempotency_key,
+                {PAYMENT_SORT_KEY_EXPR},
+                {PAYMENT_ID_EXPR},
+                (
+                    SELECT json_group_array(
+                        json_object('coin', pfr.coin, 'value', pfr.value, 'created_at', pfr.created_at)
+                    )
+                    FROM payment_fiat_rates AS pfr
+                    WHERE pfr.payment_id = {PAYMENT_ID_EXPR}
+                )
             FROM payment_tx_data AS ptx          -- Payment tx (each tx results in a Payment)
             FULL JOIN (
                 SELECT * FROM receive_swaps
@@ -190,17 +399,19 @@ impl Persister {
                 ON ptx.tx_id in (cs.user_lockup_tx_id, cs.claim_tx_id)
             LEFT JOIN payment_tx_data AS rtx     -- Refund tx data
                 ON rtx.tx_id in (ss.refund_tx_id, cs.refund_tx_id)
+            LEFT JOIN payment_details AS pd      -- Additional payment details
+                ON pd.tx_id = ptx.tx_id
             WHERE                                -- Filter out refund txs from Send Swaps
                 ptx.tx_id NOT IN (SELECT refund_tx_id FROM send_swaps WHERE refund_tx_id NOT NULL)
             AND                                  -- Filter out refund txs from Chain Swaps
                 ptx.tx_id NOT IN (SELECT refund_tx_id FROM chain_swaps WHERE refund_tx_id NOT NULL)
             AND {}
-            ORDER BY                             -- Order by tx timestamp or swap creation time
-                COALESCE(ptx.timestamp, rs.created_at, ss.created_at, cs.created_at) DESC
+            ORDER BY {}                          -- See get_payments and get_payments_page
             LIMIT {}
             OFFSET {}
             ",
             where_clause.unwrap_or("true"),
+            order_by,
             limit.unwrap_or(u32::MAX),
             offset.unwrap_or(0),
         )
@@ -216,6 +427,7 @@ impl Persister {
                 fees_sat: row.get(3)?,
                 payment_type: row.get(4)?,
                 is_confirmed: row.get(5)?,
+                asset_id: row.get(34)?,
             }),
             _ => None,
         };
@@ -250,6 +462,31 @@ impl Persister {
 
         let maybe_swap_refund_tx_amount_sat: Option<u64> = row.get(31)?;
 
+        let maybe_details_destination: Option<String> = row.get(32)?;
+        let maybe_details_description: Option<String> = row.get(33)?;
+        let maybe_details_idempotency_key: Option<String> = row.get(36)?;
+        let details = tx.as_ref().map(|tx| PaymentTxDetails {
+            tx_id: tx.tx_id.clone(),
+            destination: maybe_details_destination,
+            description: maybe_details_description,
+            idempotency_key: maybe_details_idempotency_key,
+        });
+
+        let maybe_metadata_json: Option<String> = row.get(35)?;
+        let metadata: Vec<PaymentMetadata> = match maybe_metadata_json {
+            Some(metadata_json) => serde_json::from_str(&metadata_json)
+                .map_err(|e| rusqlite::Error::FromSqlConversionFailure(35, Type::Text, e.into()))?,
+            None => vec![],
+        };
+
+        let maybe_fiat_rates_json: Option<String> = row.get(39)?;
+        let mut fiat_rates: Vec<PaymentFiatRate> = match maybe_fiat_rates_json {
+            Some(fiat_rates_json) => serde_json::from_str(&fiat_rates_json)
+                .map_err(|e| rusqlite::Error::FromSqlConversionFailure(39, Type::Text, e.into()))?,
+            None => vec![],
+        };
+        fiat_rates.sort_by(|a, b| a.coin.cmp(&b.coin));
+
         let (swap, payment_type) = match maybe_receive_swap_id {
             Some(receive_swap_id) => (
                 Some(PaymentSwapData {
@@ -314,28 +551,220 @@ impl Persister {
             },
         };
 
-        match (tx, swap.clone()) {
-            (None, None) => Err(maybe_tx_tx_id.err().unwrap()),
-            (None, Some(swap)) => Ok(Payment::from_pending_swap(swap, payment_type)),
-            (Some(tx), None) => Ok(Payment::from_tx_data(tx, None)),
-            (Some(tx), Some(swap)) => Ok(Payment::from_tx_data(tx, Some(swap))),
-        }
+        let payment = match (tx, swap.clone()) {
+            (None, None) => return Err(maybe_tx_tx_id.err().unwrap()),
+            (None, Some(swap)) => Payment::from_pending_swap(swap, payment_type),
+            (Some(tx), None) => Payment::from_tx_data(tx, None, details),
+            (Some(tx), Some(swap)) => Payment::from_tx_data(tx, Some(swap), details),
+        };
+        Ok(Payment {
+            metadata,
+            fiat_rates,
+            ..payment
+        })
     }
 
     pub fn get_payment(&self, id: String) -> Result<Option<Payment>> {
         Ok(self
             .get_connection()?
             .query_row(
-                &self.select_payment_query(Some("ptx.tx_id = ?1"), None, None),
+                &self.select_payment_query(
+                    Some("ptx.tx_id = ?1"),
+                    PAYMENT_TIMESTAMP_ORDER,
+                    None,
+                    None,
+                ),
                 params![id],
                 |row| self.sql_row_to_payment(row),
             )
             .optional()?)
     }
 
+    /// Looks up a payment by either its tx id or its swap id
+    pub(crate) fn get_payment_by_tx_or_swap_id(&self, id: &str) -> Result<Option<Payment>> {
+        Ok(self
+            .get_connection()?
+            .query_row(
+                &self.select_payment_query(
+                    Some("(ptx.tx_id = ?1 OR COALESCE(rs.id, ss.id, cs.id) = ?1)"),
+                    PAYMENT_TIMESTAMP_ORDER,
+                    None,
+                    None,
+                ),
+                params![id],
+                |row| self.sql_row_to_payment(row),
+            )
+            .optional()?)
+    }
+
+    /// Looks up a payment by any of the identifiers in [PaymentLookup]
+    pub(crate) fn get_payment_by_lookup(&self, lookup: &PaymentLookup) -> Result<Option<Payment>> {
+        let maybe_swap = match lookup {
+            PaymentLookup::SwapId { swap_id } => self.fetch_swap_by_id_if_exists(swap_id)?,
+            PaymentLookup::Invoice { invoice } => self.fetch_swap_by_invoice(invoice)?,
+            PaymentLookup::PaymentHash { payment_hash } => {
+                self.fetch_swap_by_payment_hash(payment_hash)?
+            }
+            PaymentLookup::TxId { tx_id } => match self.get_payment(tx_id.clone())? {
+                Some(payment) => return Ok(Some(payment)),
+                // Refund txs, as well as swap txs not sent from or to this wallet, are not
+                // payments of their own
+                None => self.fetch_swap_by_tx_id(tx_id)?,
+            },
+            PaymentLookup::Destination { address } => {
+                return Ok(self
+                    .get_connection()?
+                    .query_row(
+                        &self.select_payment_query(
+                            Some("pd.destination = ?1"),
+                            PAYMENT_TIMESTAMP_ORDER,
+                            None,
+                            None,
+                        ),
+                        params![address],
+                        |row| self.sql_row_to_payment(row),
+                    )
+                    .optional()?)
+            }
+        };
+
+        match maybe_swap {
+            Some(swap) => match self.get_payment_by_tx_or_swap_id(&swap.id())? {
+                Some(payment) => Ok(Some(payment)),
+                None => Ok(Some(self.get_pending_swap_payment(swap)?)),
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Builds the payment of a swap without a tx yet, which is not part of the payments query
+    fn get_pending_swap_payment(&self, swap: Swap) -> Result<Payment> {
+        let (swap_data, payment_type) = match swap {
+            Swap::Send(swap) => (
+                PaymentSwapData {
+                    swap_id: swap.id,
+                    created_at: swap.created_at,
+                    preimage: swap.preimage,
+                    description: swap
+                        .description
+                        .or_else(|| get_invoice_description!(swap.invoice))
+                        .unwrap_or("Lightning payment".to_string()),
+                    bolt11: Some(swap.invoice),
+                    payer_amount_sat: swap.payer_amount_sat,
+                    receiver_amount_sat: swap.receiver_amount_sat,
+                    refund_tx_id: swap.refund_tx_id,
+                    refund_tx_amount_sat: None,
+                    status: swap.state,
+                },
+                PaymentType::Send,
+            ),
+            Swap::Receive(swap) => (
+                PaymentSwapData {
+                    swap_id: swap.id,
+                    created_at: swap.created_at,
+                    preimage: None,
+                    description: swap
+                        .description
+                        .or_else(|| get_invoice_description!(swap.invoice))
+                        .unwrap_or("Lightning payment".to_string()),
+                    bolt11: Some(swap.invoice),
+                    payer_amount_sat: swap.payer_amount_sat,
+                    receiver_amount_sat: swap.receiver_amount_sat,
+                    refund_tx_id: None,
+                    refund_tx_amount_sat: None,
+                    status: swap.state,
+                },
+                PaymentType::Receive,
+            ),
+            Swap::Chain(swap) => (
+                PaymentSwapData {
+                    swap_id: swap.id,
+                    created_at: swap.created_at,
+                    preimage: Some(swap.preimage),
+                    description: swap.description.unwrap_or("Bitcoin transfer".to_string()),
+                    bolt11: None,
+                    payer_amount_sat: swap.payer_amount_sat,
+                    receiver_amount_sat: swap.receiver_amount_sat,
+                    refund_tx_id: swap.refund_tx_id,
+                    refund_tx_amount_sat: None,
+                    status: swap.state,
+                },
+                swap.direction.into(),
+            ),
+        };
+
+        let metadata = self.list_payment_metadata(&swap_data.swap_id)?;
+        let fiat_rates = self.list_payment_fiat_rates(&swap_data.swap_id)?;
+        Ok(Payment {
+            metadata,
+            fiat_rates,
+            ..Payment::from_pending_swap(swap_data, payment_type)
+        })
+    }
+
+    /// Looks up a direct onchain payment by the idempotency key it was made with
+    pub(crate) fn get_payment_by_idempotency_key(
+        &self,
+        idempotency_key: &str,
+    ) -> Result<Option<Payment>> {
+        Ok(self
+            .get_connection()?
+            .query_row(
+                &self.select_payment_query(
+                    Some("pd.idempotency_key = ?1"),
+                    PAYMENT_TIMESTAMP_ORDER,
+                    None,
+                    None,
+                ),
+                params![idempotency_key],
+                |row| self.sql_row_to_payment(row),
+            )
+            .optional()?)
+    }
+
     pub fn get_payments(&self, req: &ListPaymentsRequest) -> Result<Vec<Payment>> {
-        let where_clause =
-            filter_to_where_clause(req.filters.clone(), req.from_timestamp, req.to_timestamp);
+        // The cursor points into the order of the pages, which differs from this one
+        ensure!(
+            req.cursor.is_none(),
+            "A cursor can only be used to list a page of payments"
+        );
+        Ok(self
+            .get_payments_with_cursors(req, PAYMENT_TIMESTAMP_ORDER)?
+            .into_iter()
+            .map(|(payment, _)| payment)
+            .collect())
+    }
+
+    /// Lists a page of payments. The returned cursor is set only if the page is full, in which
+    /// case the next page may have more payments.
+    ///
+    /// Unlike [Persister::get_payments], the payments are ordered by their sort key, which does
+    /// not change when a pending payment confirms, so that the cursor keeps its position.
+    pub(crate) fn get_payments_page(
+        &self,
+        req: &ListPaymentsRequest,
+    ) -> Result<ListPaymentsResponse> {
+        let order_by = format!("{PAYMENT_SORT_KEY_EXPR} DESC, {PAYMENT_ID_EXPR} DESC");
+        let payments = self.get_payments_with_cursors(req, &order_by)?;
+        let next_cursor = match req.limit {
+            Some(limit) if payments.len() as u64 >= limit as u64 => {
+                payments.last().map(|(_, cursor)| cursor.encode())
+            }
+            _ => None,
+        };
+        Ok(ListPaymentsResponse {
+            payments: payments.into_iter().map(|(payment, _)| payment).collect(),
+            next_cursor,
+        })
+    }
+
+    /// Lists the payments in the given order, along with the cursor pointing at each of them
+    fn get_payments_with_cursors(
+        &self,
+        req: &ListPaymentsRequest,
+        order_by: &str,
+    ) -> Result<Vec<(Payment, PaymentCursor)>> {
+        let (where_clause, where_params) = filter_to_where_clause(req, self.network)?;
         let maybe_where_clause = match where_clause.is_empty() {
             false => Some(where_clause.as_str()),
             true => None,
@@ -343,31 +772,72 @@ impl Persister {
 
         // Assumes there is no swap chaining (send swap lockup tx = receive swap claim tx)
         let con = self.get_connection()?;
-        let mut stmt =
-            con.prepare(&self.select_payment_query(maybe_where_clause, req.offset, req.limit))?;
-        let payments: Vec<Payment> = stmt
-            .query_map(params![], |row| self.sql_row_to_payment(row))?
+        let mut stmt = con.prepare(&self.select_payment_query(
+            maybe_where_clause,
+            order_by,
+            req.offset,
+            req.limit,
+        ))?;
+        let payments: Vec<(Payment, PaymentCursor)> = stmt
+            .query_map(params_from_iter(where_params), |row| {
+                let cursor = PaymentCursor {
+                    sort_key: row.get(37)?,
+                    payment_id: row.get(38)?,
+                };
+                Ok((self.sql_row_to_payment(row)?, cursor))
+            })?
             .map(|i| i.unwrap())
             .collect();
         Ok(payments)
     }
 }
 
+/// The position of a payment in the order of [Persister::get_payments_page], see
+/// [ListPaymentsRequest::cursor]
+#[derive(Debug, PartialEq)]
+struct PaymentCursor {
+    sort_key: u32,
+    payment_id: String,
+}
+
+impl PaymentCursor {
+    fn encode(&self) -> String {
+        hex::encode(format!("{}:{}", self.sort_key, self.payment_id))
+    }
+
+    fn decode(cursor: &str) -> Result<Self> {
+        let decoded = hex::decode(cursor)
+            .ok()
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .ok_or(anyhow!("Invalid cursor {cursor}"))?;
+        let (sort_key, payment_id) = decoded
+            .split_once(':')
+            .ok_or(anyhow!("Invalid cursor {cursor}"))?;
+        Ok(PaymentCursor {
+            sort_key: sort_key
+                .parse()
+                .map_err(|_| anyhow!("Invalid cursor {cursor}"))?,
+            payment_id: payment_id.to_string(),
+        })
+    }
+}
+
+/// Builds the WHERE clause of the payments query, along with the params it binds
 fn filter_to_where_clause(
-    type_filters: Option<Vec<PaymentType>>,
-    from_timestamp: Option<i64>,
-    to_timestamp: Option<i64>,
-) -> String {
+    req: &ListPaymentsRequest,
+    network: LiquidNetwork,
+) -> Result<(String, Vec<String>)> {
     let mut where_clause: Vec<String> = Vec::new();
+    let mut where_params: Vec<String> = Vec::new();
 
-    if let Some(t) = from_timestamp {
+    if let Some(t) = req.from_timestamp {
         where_clause.push(format!("coalesce(ptx.timestamp, rs.created_at) >= {t}"));
     };
-    if let Some(t) = to_timestamp {
+    if let Some(t) = req.to_timestamp {
         where_clause.push(format!("coalesce(ptx.timestamp, rs.created_at) <= {t}"));
     };
 
-    if let Some(filters) = type_filters {
+    if let Some(filters) = req.filters.clone() {
         if !filters.is_empty() {
             let mut type_filter_clause: HashSet<PaymentType> = HashSet::new();
             for type_filter in filters {
@@ -392,12 +862,123 @@ fn filter_to_where_clause(
         }
     }
 
-    where_clause.join(" and ")
+    if let Some(states) = &req.states {
+        // The state of the swap, or else the onchain tx status. See [Payment::status]
+        where_clause.push(format!(
+            "COALESCE(
+                rs.state,
+                ss.state,
+                cs.state,
+                CASE WHEN ptx.is_confirmed THEN {} ELSE {} END
+            ) in ({})",
+            PaymentState::Complete as i8,
+            PaymentState::Pending as i8,
+            states
+                .iter()
+                .map(|s| format!("{}", *s as i8))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+
+    if let Some(kinds) = &req.kinds {
+        let kind_clauses: Vec<&str> = kinds
+            .iter()
+            .map(|kind| match kind {
+                PaymentKind::Submarine => "ss.id IS NOT NULL",
+                PaymentKind::Reverse => "rs.id IS NOT NULL",
+                PaymentKind::Chain => "cs.id IS NOT NULL",
+                PaymentKind::Direct => "COALESCE(rs.id, ss.id, cs.id) IS NULL",
+            })
+            .collect();
+        where_clause.push(match kind_clauses.is_empty() {
+            true => "false".to_string(),
+            false => format!("({})", kind_clauses.join(" OR ")),
+        });
+    }
+
+    if let Some(amount_sat) = req.from_amount_sat {
+        where_clause.push(format!("{PAYMENT_AMOUNT_SAT_EXPR} >= {amount_sat}"));
+    }
+    if let Some(amount_sat) = req.to_amount_sat {
+        where_clause.push(format!("{PAYMENT_AMOUNT_SAT_EXPR} <= {amount_sat}"));
+    }
+
+    if let Some(asset_id) = &req.asset_id {
+        // L-BTC payments are stored without an asset id
+        match *asset_id == ElementsNetwork::from(network).policy_asset().to_string() {
+            true => where_clause.push("ptx.asset_id IS NULL".to_string()),
+            false => {
+                where_clause.push("ptx.asset_id = ?".to_string());
+                where_params.push(asset_id.clone());
+            }
+        }
+    }
+
+    if let Some(search) = req.search.as_ref().filter(|search| !search.is_empty()) {
+        let pattern = format!(
+            "%{}%",
+            search
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_")
+        );
+        where_clause.push(
+            "(
+                COALESCE(rs.description, ss.description, cs.description, pd.description)
+                    LIKE ? ESCAPE '\\'
+                OR COALESCE(rs.invoice, ss.invoice) LIKE ? ESCAPE '\\'
+            )"
+            .to_string(),
+        );
+        where_params.push(pattern.clone());
+        where_params.push(pattern);
+    }
+
+    for filter in req.metadata_filters.clone().unwrap_or_default() {
+        let value_clause = match filter.value {
+            Some(value) => {
+                where_params.push(filter.key);
+                where_params.push(value);
+                "pm.key = ? AND pm.value = ?"
+            }
+            None => {
+                where_params.push(filter.key);
+                "pm.key = ?"
+            }
+        };
+        where_clause.push(format!(
+            "EXISTS (
+                SELECT 1 FROM payment_metadata AS pm
+                WHERE pm.payment_id = {PAYMENT_ID_EXPR} AND {value_clause}
+            )"
+        ));
+    }
+
+    // Only the payments after the cursor in the query order
+    if let Some(cursor) = &req.cursor {
+        ensure!(
+            req.offset.is_none(),
+            "A cursor cannot be combined with an offset"
+        );
+        let cursor = PaymentCursor::decode(cursor)?;
+        where_clause.push(format!(
+            "(
+                {PAYMENT_SORT_KEY_EXPR} < {sort_key}
+                OR ({PAYMENT_SORT_KEY_EXPR} = {sort_key} AND {PAYMENT_ID_EXPR} < ?)
+            )",
+            sort_key = cursor.sort_key
+        ));
+        where_params.push(cursor.payment_id);
+    }
+
+    Ok((where_clause.join(" and "), where_params))
 }
 
 #[cfg(test)]
 mod tests {
     use anyhow::Result;
+    use std::str::FromStr;
 
     use crate::{
         prelude::ListPaymentsRequest,
@@ -406,7 +987,10 @@ mod tests {
         },
     };
 
-    use super::{PaymentState, PaymentType};
+    use super::{
+        Bolt11Invoice, ElementsNetwork, PaymentKind, PaymentLookup, PaymentState, PaymentTxDetails,
+        PaymentType, Swap,
+    };
 
     #[test]
     fn test_get_payments() -> Result<()> {
@@ -426,6 +1010,402 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_list_payment_tx_confirmations() -> Result<()> {
+        let (_temp_dir, storage) = new_persister()?;
+
+        let mut payment_tx_data = new_payment_tx_data(PaymentType::Receive);
+        payment_tx_data.is_confirmed = false;
+        storage.insert_or_update_payment(payment_tx_data.clone())?;
+        assert_eq!(
+            storage
+                .list_payment_tx_confirmations()?
+                .get(&payment_tx_data.tx_id),
+            Some(&false)
+        );
+
+        payment_tx_data.is_confirmed = true;
+        storage.insert_or_update_payment(payment_tx_data.clone())?;
+        assert_eq!(
+            storage
+                .list_payment_tx_confirmations()?
+                .get(&payment_tx_data.tx_id),
+            Some(&true)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_payments_with_filters() -> Result<()> {
+        let (_temp_dir, storage) = new_persister()?;
+
+        let mut send_tx_data = new_payment_tx_data(PaymentType::Send);
+        send_tx_data.amount_sat = 1_000;
+        send_tx_data.is_confirmed = true;
+        storage.insert_or_update_payment(send_tx_data.clone())?;
+        storage.insert_or_update_payment_details(PaymentTxDetails {
+            tx_id: send_tx_data.tx_id.clone(),
+            destination: None,
+            description: Some("Coffee_beans".to_string()),
+            idempotency_key: None,
+        })?;
+
+        let asset_id = "b612eb46313a2cd6ebabd8b7a8eed5696e29898b87a43bff41c94f51acef9d73";
+        let mut receive_tx_data = new_payment_tx_data(PaymentType::Receive);
+        receive_tx_data.amount_sat = 5_000;
+        receive_tx_data.asset_id = Some(asset_id.to_string());
+        storage.insert_or_update_payment(receive_tx_data.clone())?;
+
+        let list_tx_ids = |req: ListPaymentsRequest| -> Result<Vec<Option<String>>> {
+            Ok(storage
+                .get_payments(&req)?
+                .into_iter()
+                .map(|payment| payment.tx_id)
+                .collect())
+        };
+        let send_tx_id = vec![Some(send_tx_data.tx_id)];
+        let receive_tx_id = vec![Some(receive_tx_data.tx_id)];
+
+        let req = ListPaymentsRequest {
+            states: Some(vec![PaymentState::Complete]),
+            ..Default::default()
+        };
+        assert_eq!(list_tx_ids(req)?, send_tx_id);
+
+        let req = ListPaymentsRequest {
+            from_amount_sat: Some(2_000),
+            ..Default::default()
+        };
+        assert_eq!(list_tx_ids(req)?, receive_tx_id);
+
+        let req = ListPaymentsRequest {
+            kinds: Some(vec![PaymentKind::Direct]),
+            ..Default::default()
+        };
+        assert_eq!(list_tx_ids(req)?.len(), 2);
+        let req = ListPaymentsRequest {
+            kinds: Some(vec![PaymentKind::Submarine, PaymentKind::Chain]),
+            ..Default::default()
+        };
+        assert!(list_tx_ids(req)?.is_empty());
+
+        let req = ListPaymentsRequest {
+            asset_id: Some(asset_id.to_string()),
+            ..Default::default()
+        };
+        assert_eq!(list_tx_ids(req)?, receive_tx_id);
+        let req = ListPaymentsRequest {
+            asset_id: Some(ElementsNetwork::LiquidTestnet.policy_asset().to_string()),
+            ..Default::default()
+        };
+        assert_eq!(list_tx_ids(req)?, send_tx_id);
+
+        // The search is case-insensitive and matches wildcard characters literally
+        for (search, expected) in [("coffee_", send_tx_id.clone()), ("coffee%", vec![])] {
+            let req = ListPaymentsRequest {
+                search: Some(search.to_string()),
+                ..Default::default()
+            };
+            assert_eq!(list_tx_ids(req)?, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_payments_with_amount_filters() -> Result<()> {
+        let (_temp_dir, storage) = new_persister()?;
+
+        // A pending send swap, with its lockup tx
+        let mut lockup_tx_data = new_payment_tx_data(PaymentType::Send);
+        lockup_tx_data.amount_sat = 1149;
+        storage.insert_or_update_payment(lockup_tx_data.clone())?;
+        let mut send_swap = new_send_swap(Some(PaymentState::Pending));
+        send_swap.lockup_tx_id = Some(lockup_tx_data.tx_id.clone());
+        storage.insert_send_swap(&send_swap)?;
+
+        let list_swap_ids = |from_amount_sat, to_amount_sat| -> Result<Vec<Option<String>>> {
+            Ok(storage
+                .get_payments(&ListPaymentsRequest {
+                    from_amount_sat,
+                    to_amount_sat,
+                    ..Default::default()
+                })?
+                .into_iter()
+                .map(|payment| payment.swap_id)
+                .collect())
+        };
+        let payment = storage
+            .get_payment_by_tx_or_swap_id(&send_swap.id)?
+            .expect("Expecting payment");
+        assert_eq!(payment.amount_sat, send_swap.payer_amount_sat);
+
+        // The filters match the payment amount, not the swap receiver amount
+        let swap_id = vec![Some(send_swap.id)];
+        assert_eq!(list_swap_ids(Some(1149), Some(1149))?, swap_id);
+        assert_eq!(list_swap_ids(Some(1001), None)?, swap_id);
+        assert!(list_swap_ids(Some(1150), None)?.is_empty());
+        assert!(list_swap_ids(None, Some(1148))?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_payments_page() -> Result<()> {
+        let (_temp_dir, storage) = new_persister()?;
+
+        let mut payments_tx_data = vec![];
+        for timestamp in [100, 200, 300] {
+            let mut payment_tx_data = new_payment_tx_data(PaymentType::Receive);
+            payment_tx_data.timestamp = Some(timestamp);
+            storage.insert_or_update_payment(payment_tx_data.clone())?;
+            payments_tx_data.push(payment_tx_data);
+        }
+
+        let req = ListPaymentsRequest {
+            limit: Some(2),
+            ..Default::default()
+        };
+        let first_page = storage.get_payments_page(&req)?;
+        assert_eq!(
+            first_page
+                .payments
+                .iter()
+                .map(|payment| payment.tx_id.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                Some(payments_tx_data[2].tx_id.clone()),
+                Some(payments_tx_data[1].tx_id.clone())
+            ]
+        );
+        assert!(first_page.next_cursor.is_some());
+
+        // Neither a new payment nor a changed tx timestamp shift the next page
+        storage.insert_or_update_payment(new_payment_tx_data(PaymentType::Send))?;
+        let mut confirmed_tx_data = payments_tx_data[0].clone();
+        confirmed_tx_data.timestamp = Some(400);
+        confirmed_tx_data.is_confirmed = true;
+        storage.insert_or_update_payment(confirmed_tx_data)?;
+
+        let second_page_cursor = first_page.next_cursor.clone();
+        let second_page = storage.get_payments_page(&ListPaymentsRequest {
+            cursor: first_page.next_cursor,
+            ..req
+        })?;
+        assert_eq!(second_page.payments.len(), 1);
+        assert_eq!(
+            second_page.payments[0].tx_id,
+            Some(payments_tx_data[0].tx_id.clone())
+        );
+        assert!(second_page.next_cursor.is_none());
+
+        // Unlike the pages, the full list is ordered by tx timestamp and takes no cursor
+        assert_eq!(
+            storage.get_payments(&ListPaymentsRequest::default())?[0].tx_id,
+            Some(payments_tx_data[0].tx_id.clone())
+        );
+        assert!(storage
+            .get_payments(&ListPaymentsRequest {
+                cursor: second_page_cursor.clone(),
+                ..Default::default()
+            })
+            .is_err());
+
+        assert!(storage
+            .get_payments_page(&ListPaymentsRequest {
+                cursor: Some("invalid".to_string()),
+                ..Default::default()
+            })
+            .is_err());
+
+        // The cursor already skips the previous pages, so it cannot be combined with an offset
+        assert!(storage
+            .get_payments_page(&ListPaymentsRequest {
+                limit: Some(2),
+                offset: Some(1),
+                cursor: second_page_cursor,
+                ..Default::default()
+            })
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_payment_with_details() -> Result<()> {
+        let (_temp_dir, storage) = new_persister()?;
+
+        let payment_tx_data = new_payment_tx_data(PaymentType::Send);
+        storage.insert_or_update_payment(payment_tx_data.clone())?;
+
+        let payment = storage
+            .get_payment(payment_tx_data.tx_id.clone())?
+            .expect("Expecting payment");
+        assert_eq!(payment.description, "Liquid transfer");
+        assert_eq!(payment.destination, None);
+
+        storage.insert_or_update_payment_details(PaymentTxDetails {
+            tx_id: payment_tx_data.tx_id.clone(),
+            destination: Some("tlq1-address".to_string()),
+            description: Some("Coffee".to_string()),
+            idempotency_key: None,
+        })?;
+
+        let payment = storage
+            .get_payment(payment_tx_data.tx_id)?
+            .expect("Expecting payment");
+        assert_eq!(payment.description, "Coffee");
+        assert_eq!(payment.destination, Some("tlq1-address".to_string()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_payment_with_details() -> Result<()> {
+        let (_temp_dir, storage) = new_persister()?;
+
+        let details = |tx_id: &str| PaymentTxDetails {
+            tx_id: tx_id.to_string(),
+            destination: Some("tlq1-address".to_string()),
+            description: None,
+            idempotency_key: Some("key".to_string()),
+        };
+        let payment_tx_data = new_payment_tx_data(PaymentType::Send);
+        assert!(storage.insert_payment_with_details(
+            payment_tx_data.clone(),
+            details(&payment_tx_data.tx_id)
+        )?);
+        assert_eq!(
+            storage
+                .get_payment_by_idempotency_key("key")?
+                .and_then(|payment| payment.tx_id),
+            Some(payment_tx_data.tx_id.clone())
+        );
+
+        // A payment with the same idempotency key is not stored
+        let other_tx_data = new_payment_tx_data(PaymentType::Send);
+        assert!(!storage
+            .insert_payment_with_details(other_tx_data.clone(), details(&other_tx_data.tx_id))?);
+        assert!(storage.get_payment(other_tx_data.tx_id)?.is_none());
+
+        // Once deleted, the idempotency key can be used again
+        storage.delete_payment_with_details(&payment_tx_data.tx_id)?;
+        assert!(storage
+            .get_payment(payment_tx_data.tx_id.clone())?
+            .is_none());
+        assert!(storage.get_payment_by_idempotency_key("key")?.is_none());
+        assert!(storage.insert_payment_with_details(
+            payment_tx_data.clone(),
+            details(&payment_tx_data.tx_id)
+        )?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_payment_by_lookup() -> Result<()> {
+        let (_temp_dir, storage) = new_persister()?;
+
+        // A pending swap without any tx yet
+        let send_swap = new_send_swap(None);
+        storage.insert_send_swap(&send_swap)?;
+        let payment_hash = Bolt11Invoice::from_str(&send_swap.invoice)?
+            .payment_hash()
+            .to_string();
+        for lookup in [
+            PaymentLookup::SwapId {
+                swap_id: send_swap.id.clone(),
+            },
+            PaymentLookup::Invoice {
+                invoice: send_swap.invoice.clone(),
+            },
+            PaymentLookup::PaymentHash { payment_hash },
+        ] {
+            let payment = storage
+                .get_payment_by_lookup(&lookup)?
+                .expect("Expecting payment");
+            assert_eq!(payment.swap_id, Some(send_swap.id.clone()));
+            assert_eq!(payment.tx_id, None);
+        }
+
+        let payment_tx_data = new_payment_tx_data(PaymentType::Send);
+        storage.insert_or_update_payment(payment_tx_data.clone())?;
+        storage.insert_or_update_payment_details(PaymentTxDetails {
+            tx_id: payment_tx_data.tx_id.clone(),
+            destination: Some("tlq1-address".to_string()),
+            description: None,
+            idempotency_key: None,
+        })?;
+        for lookup in [
+            PaymentLookup::TxId {
+                tx_id: payment_tx_data.tx_id.clone(),
+            },
+            PaymentLookup::Destination {
+                address: "tlq1-address".to_string(),
+            },
+        ] {
+            let payment = storage
+                .get_payment_by_lookup(&lookup)?
+                .expect("Expecting payment");
+            assert_eq!(payment.tx_id, Some(payment_tx_data.tx_id.clone()));
+        }
+
+        assert!(storage
+            .get_payment_by_lookup(&PaymentLookup::SwapId {
+                swap_id: "unknown".to_string()
+            })?
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_swap_by_payment_hash() -> Result<()> {
+        let (_temp_dir, storage) = new_persister()?;
+
+        let send_swap = new_send_swap(None);
+        storage.insert_send_swap(&send_swap)?;
+        let receive_swap = new_receive_swap(None);
+        storage.insert_receive_swap(&receive_swap)?;
+
+        // The payment hash is stored on insert and matched regardless of its case
+        let send_payment_hash = Bolt11Invoice::from_str(&send_swap.invoice)?
+            .payment_hash()
+            .to_string();
+        let Some(Swap::Send(swap)) =
+            storage.fetch_swap_by_payment_hash(&send_payment_hash.to_uppercase())?
+        else {
+            panic!("Expecting send swap");
+        };
+        assert_eq!(swap.id, send_swap.id);
+
+        // Swaps stored before the column existed are backfilled on migration
+        let receive_payment_hash = Bolt11Invoice::from_str(&receive_swap.invoice)?
+            .payment_hash()
+            .to_string();
+        storage.get_connection()?.execute(
+            "UPDATE receive_swaps SET payment_hash = NULL WHERE id = ?1",
+            [&receive_swap.id],
+        )?;
+        assert!(storage
+            .fetch_swap_by_payment_hash(&receive_payment_hash)?
+            .is_none());
+        storage.init()?;
+        let Some(Swap::Receive(swap)) =
+            storage.fetch_swap_by_payment_hash(&receive_payment_hash)?
+        else {
+            panic!("Expecting receive swap");
+        };
+        assert_eq!(swap.id, receive_swap.id);
+
+        assert!(storage
+            .fetch_swap_by_payment_hash(&"00".repeat(32))?
+            .is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_list_ongoing_swaps() -> Result<()> {
         let (_temp_dir, storage) = new_persister()?;
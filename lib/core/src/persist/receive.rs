@@ -2,9 +2,10 @@ use std::collections::HashMap;
 
 use anyhow::Result;
 use boltz_client::swaps::boltz::CreateReverseResponse;
-use rusqlite::{named_params, params, Connection, Row};
+use rusqlite::{named_params, params, Connection, OptionalExtension, Row};
 use serde::{Deserialize, Serialize};
 
+use crate::bolt12;
 use crate::ensure_sdk;
 use crate::error::PaymentError;
 use crate::model::*;
@@ -28,9 +29,10 @@ impl Persister {
                 created_at,
                 claim_fees_sat,
                 claim_tx_id,
-                state
+                state,
+                payment_hash
             )
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
         )?;
         _ = stmt.execute((
             &receive_swap.id,
@@ -45,6 +47,7 @@ impl Persister {
             &receive_swap.claim_fees_sat,
             &receive_swap.claim_tx_id,
             &receive_swap.state,
+            bolt12::get_payment_hash(&receive_swap.invoice).ok(),
         ))?;
 
         Ok(())
@@ -84,7 +87,7 @@ impl Persister {
         let query = Self::list_receive_swaps_query(vec!["id = ?1".to_string()]);
         let res = con.query_row(&query, [id], Self::sql_row_to_receive_swap);
 
-        Ok(res.ok())
+        Ok(res.optional()?)
     }
 
     pub(crate) fn fetch_receive_swap_by_invoice(
@@ -95,7 +98,29 @@ impl Persister {
         let query = Self::list_receive_swaps_query(vec!["invoice= ?1".to_string()]);
         let res = con.query_row(&query, [invoice], Self::sql_row_to_receive_swap);
 
-        Ok(res.ok())
+        Ok(res.optional()?)
+    }
+
+    /// Looks up a Receive Swap by the lowercase hex payment hash of its invoice
+    pub(crate) fn fetch_receive_swap_by_payment_hash(
+        &self,
+        payment_hash: &str,
+    ) -> Result<Option<ReceiveSwap>> {
+        let con: Connection = self.get_connection()?;
+        let query = Self::list_receive_swaps_query(vec!["payment_hash = ?1".to_string()]);
+        let res = con.query_row(&query, [payment_hash], Self::sql_row_to_receive_swap);
+
+        Ok(res.optional()?)
+    }
+
+    /// Looks up a Receive Swap by its lockup or claim tx id
+    pub(crate) fn fetch_receive_swap_by_tx_id(&self, tx_id: &str) -> Result<Option<ReceiveSwap>> {
+        let con: Connection = self.get_connection()?;
+        let query =
+            Self::list_receive_swaps_query(vec!["?1 IN (lockup_tx_id, claim_tx_id)".to_string()]);
+        let res = con.query_row(&query, [tx_id], Self::sql_row_to_receive_swap);
+
+        Ok(res.optional()?)
     }
 
     fn sql_row_to_receive_swap(row: &Row) -> rusqlite::Result<ReceiveSwap> {
@@ -2,9 +2,10 @@ use std::collections::HashMap;
 
 use anyhow::Result;
 use boltz_client::swaps::boltz::CreateSubmarineResponse;
-use rusqlite::{named_params, params, Connection, Row};
+use rusqlite::{named_params, params, Connection, OptionalExtension, Row};
 use serde::{Deserialize, Serialize};
 
+use crate::bolt12;
 use crate::ensure_sdk;
 use crate::error::PaymentError;
 use crate::model::*;
@@ -27,9 +28,13 @@ impl Persister {
                 lockup_tx_id,
                 refund_tx_id,
                 created_at,
-                state
+                state,
+                idempotency_key,
+                bolt12_offer,
+                payer_note,
+                payment_hash
             )
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
         )?;
         _ = stmt.execute((
             &send_swap.id,
@@ -43,6 +48,10 @@ impl Persister {
             &send_swap.refund_tx_id,
             &send_swap.created_at,
             &send_swap.state,
+            &send_swap.idempotency_key,
+            &send_swap.bolt12_offer,
+            &send_swap.payer_note,
+            bolt12::get_payment_hash(&send_swap.invoice).ok(),
         ))?;
 
         Ok(())
@@ -92,7 +101,10 @@ impl Persister {
                 lockup_tx_id,
                 refund_tx_id,
                 created_at,
-                state
+                state,
+                idempotency_key,
+                bolt12_offer,
+                payer_note
             FROM send_swaps
             {where_clause_str}
             ORDER BY created_at
@@ -105,7 +117,7 @@ impl Persister {
         let query = Self::list_send_swaps_query(vec!["id = ?1".to_string()]);
         let res = con.query_row(&query, [id], Self::sql_row_to_send_swap);
 
-        Ok(res.ok())
+        Ok(res.optional()?)
     }
 
     pub(crate) fn fetch_send_swap_by_invoice(&self, invoice: &str) -> Result<Option<SendSwap>> {
@@ -113,7 +125,40 @@ impl Persister {
         let query = Self::list_send_swaps_query(vec!["invoice= ?1".to_string()]);
         let res = con.query_row(&query, [invoice], Self::sql_row_to_send_swap);
 
-        Ok(res.ok())
+        Ok(res.optional()?)
+    }
+
+    /// Looks up a Send Swap by the lowercase hex payment hash of its invoice
+    pub(crate) fn fetch_send_swap_by_payment_hash(
+        &self,
+        payment_hash: &str,
+    ) -> Result<Option<SendSwap>> {
+        let con: Connection = self.get_connection()?;
+        let query = Self::list_send_swaps_query(vec!["payment_hash = ?1".to_string()]);
+        let res = con.query_row(&query, [payment_hash], Self::sql_row_to_send_swap);
+
+        Ok(res.optional()?)
+    }
+
+    /// Looks up a Send Swap by its lockup or refund tx id
+    pub(crate) fn fetch_send_swap_by_tx_id(&self, tx_id: &str) -> Result<Option<SendSwap>> {
+        let con: Connection = self.get_connection()?;
+        let query =
+            Self::list_send_swaps_query(vec!["?1 IN (lockup_tx_id, refund_tx_id)".to_string()]);
+        let res = con.query_row(&query, [tx_id], Self::sql_row_to_send_swap);
+
+        Ok(res.optional()?)
+    }
+
+    pub(crate) fn fetch_send_swap_by_idempotency_key(
+        &self,
+        idempotency_key: &str,
+    ) -> Result<Option<SendSwap>> {
+        let con: Connection = self.get_connection()?;
+        let query = Self::list_send_swaps_query(vec!["idempotency_key = ?1".to_string()]);
+        let res = con.query_row(&query, [idempotency_key], Self::sql_row_to_send_swap);
+
+        Ok(res.optional()?)
     }
 
     fn sql_row_to_send_swap(row: &Row) -> rusqlite::Result<SendSwap> {
@@ -130,6 +175,9 @@ impl Persister {
             refund_tx_id: row.get(9)?,
             created_at: row.get(10)?,
             state: row.get(11)?,
+            idempotency_key: row.get(12)?,
+            bolt12_offer: row.get(13)?,
+            payer_note: row.get(14)?,
         })
     }
 
@@ -291,7 +339,8 @@ mod tests {
     #[test]
     fn test_fetch_send_swap() -> Result<()> {
         let (_temp_dir, storage) = new_persister()?;
-        let send_swap = new_send_swap(None);
+        let mut send_swap = new_send_swap(None);
+        send_swap.idempotency_key = Some("idempotency-key".to_string());
 
         storage.insert_send_swap(&send_swap)?;
         // Fetch swap by id
@@ -300,6 +349,16 @@ mod tests {
         assert!(storage
             .fetch_send_swap_by_invoice(&send_swap.invoice)
             .is_ok());
+        // Fetch swap by idempotency key
+        assert_eq!(
+            storage
+                .fetch_send_swap_by_idempotency_key("idempotency-key")?
+                .map(|swap| swap.id),
+            Some(send_swap.id)
+        );
+        assert!(storage
+            .fetch_send_swap_by_idempotency_key("other-key")?
+            .is_none());
 
         Ok(())
     }
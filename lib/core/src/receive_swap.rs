@@ -8,9 +8,9 @@ use tokio::sync::{broadcast, Mutex};
 
 use crate::chain::liquid::LiquidChainService;
 use crate::model::PaymentState::{
-    Complete, Created, Failed, Pending, RefundPending, Refundable, TimedOut,
+    Complete, Created, Failed, Pending, RefundPending, Refundable, TimedOut, WaitingFeeAcceptance,
 };
-use crate::model::{Config, PaymentTxData, PaymentType, ReceiveSwap};
+use crate::model::{Config, PaymentTxData, PaymentType, ReceiveSwap, Utxo};
 use crate::{ensure_sdk, utils};
 use crate::{
     error::PaymentError, model::PaymentState, persist::Persister, swapper::Swapper,
@@ -250,9 +250,20 @@ impl ReceiveSwapStateHandler {
         );
         let swap_id = &ongoing_receive_swap.id;
         let claim_address = self.onchain_wallet.next_unused_address().await?.to_string();
+        let lockup_utxo = self.fetch_lockup_utxo(ongoing_receive_swap).await?;
+        let claim_tx = self.swapper.create_receive_swap_claim(
+            ongoing_receive_swap,
+            claim_address,
+            lockup_utxo,
+        )?;
         let claim_tx_id = self
-            .swapper
-            .claim_receive_swap(ongoing_receive_swap, claim_address)?;
+            .liquid_chain_service
+            .lock()
+            .await
+            .broadcast(&claim_tx, Some(swap_id))
+            .await?
+            .to_string();
+        info!("Successfully broadcast claim tx {claim_tx_id} for Receive Swap {swap_id}");
 
         // We insert a pseudo-claim-tx in case LWK fails to pick up the new mempool tx for a while
         // This makes the tx known to the SDK (get_info, list_payments) instantly
@@ -263,6 +274,7 @@ impl ReceiveSwapStateHandler {
             fees_sat: 0,
             payment_type: PaymentType::Receive,
             is_confirmed: false,
+            asset_id: None,
         })?;
 
         self.update_swap_info(swap_id, Pending, Some(&claim_tx_id), None)
@@ -271,6 +283,22 @@ impl ReceiveSwapStateHandler {
         Ok(())
     }
 
+    async fn fetch_lockup_utxo(&self, swap: &ReceiveSwap) -> Result<Utxo, PaymentError> {
+        let script_pubkey = swap
+            .get_swap_script()?
+            .to_address(self.config.network.into())
+            .map_err(|e| anyhow!("Error getting script address: {e:?}"))?
+            .to_unconfidential()
+            .script_pubkey();
+        let lockup_utxo = self
+            .liquid_chain_service
+            .lock()
+            .await
+            .get_script_utxo(&script_pubkey)
+            .await?;
+        Ok(Utxo::Liquid(Box::new(lockup_utxo)))
+    }
+
     fn validate_state_transition(
         from_state: PaymentState,
         to_state: PaymentState,
@@ -303,6 +331,10 @@ impl ReceiveSwapStateHandler {
                 err: format!("Cannot transition from {from_state:?} to RefundPending state"),
             }),
 
+            (_, WaitingFeeAcceptance) => Err(PaymentError::Generic {
+                err: format!("Cannot transition from {from_state:?} to WaitingFeeAcceptance state"),
+            }),
+
             (Complete, Failed) => Err(PaymentError::Generic {
                 err: format!("Cannot transition from {from_state:?} to Failed state"),
             }),
@@ -1,23 +1,25 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::time::Instant;
 use std::{fs, path::PathBuf, str::FromStr, sync::Arc, time::Duration};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use boltz_client::{swaps::boltz::*, util::secrets::Preimage};
 use boltz_client::{LockTime, ToHex};
 use buy::{BuyBitcoinApi, BuyBitcoinService};
-use chain::bitcoin::HybridBitcoinChainService;
-use chain::liquid::{HybridLiquidChainService, LiquidChainService};
+use chain::bitcoin::{EsploraBitcoinChainService, HybridBitcoinChainService};
+use chain::liquid::{
+    ChainChanges, EsploraLiquidChainService, HybridLiquidChainService, LiquidChainService,
+};
 use chain_swap::ESTIMATED_BTC_CLAIM_TX_VSIZE;
 use futures_util::stream::select_all;
 use futures_util::StreamExt;
-use log::{debug, error, info};
+use log::{debug, error, info, warn};
 use lwk_wollet::bitcoin::hex::DisplayHex;
 use lwk_wollet::hashes::{sha256, Hash};
 use lwk_wollet::secp256k1::ThirtyTwoByteHash;
 use lwk_wollet::{elements, ElementsNetwork};
-use sdk_common::bitcoin::secp256k1::Secp256k1;
+use sdk_common::bitcoin::secp256k1::Message;
 use sdk_common::bitcoin::util::bip32::ChildNumber;
 use sdk_common::ensure_sdk;
 use sdk_common::prelude::{FiatAPI, FiatCurrency, LnUrlPayError, LnUrlWithdrawError, Rate};
@@ -26,13 +28,16 @@ use tokio::time::MissedTickBehavior;
 use tokio_stream::wrappers::BroadcastStream;
 use url::Url;
 
+use crate::bolt12;
 use crate::chain::bitcoin::BitcoinChainService;
 use crate::chain_swap::ChainSwapStateHandler;
 use crate::error::SdkError;
+use crate::export::{FiatRateSource, PaymentExportRecord};
 use crate::lightning_invoice::{Bolt11Invoice, Bolt11InvoiceDescription};
 use crate::model::PaymentState::*;
 use crate::receive_swap::ReceiveSwapStateHandler;
 use crate::send_swap::SendSwapStateHandler;
+use crate::signer::{SdkSigner, Signer};
 use crate::swapper::{BoltzSwapper, ReconnectHandler, Swapper, SwapperStatusStream};
 use crate::wallet::{LiquidOnchainWallet, OnchainWallet};
 use crate::{
@@ -46,6 +51,12 @@ use crate::{
 pub const DEFAULT_DATA_DIR: &str = ".data";
 /// Number of blocks to monitor a swap after its timeout block height
 pub const CHAIN_SWAP_MONITORING_PERIOD_BITCOIN_BLOCKS: u32 = 4320;
+/// Maximum age of a tx first seen during sync for which the current fiat rates are recorded, so
+/// that old txs brought in by a restore are not valued at today's rates
+const FIAT_RATES_SNAPSHOT_MAX_TX_AGE_SEC: u32 = 60 * 60;
+/// Interval after which the background sync does a full scan, even if no chain change was seen.
+/// Covers notifications missed e.g. while the Electrum client was reconnecting.
+const BACKGROUND_FULL_SCAN_INTERVAL_SEC: u64 = 10 * 60;
 
 pub struct LiquidSdk {
     pub(crate) config: Config,
@@ -75,18 +86,69 @@ impl LiquidSdk {
     /// * `req` - the [ConnectRequest] containing:
     ///     * `mnemonic` - the Liquid wallet mnemonic
     ///     * `config` - the SDK [Config]
+    ///     * `watch_only_descriptor` - the CT descriptor to connect in watch-only mode, instead of
+    ///       the mnemonic
     pub async fn connect(req: ConnectRequest) -> Result<Arc<LiquidSdk>> {
-        let maybe_swapper_proxy_url =
-            match BreezServer::new("https://bs1.breez.technology:443".into(), None) {
+        let signer: Option<Arc<dyn Signer>> = match req.mnemonic {
+            Some(mnemonic) => Some(Arc::new(SdkSigner::new(&mnemonic, req.config.network)?)),
+            None => None,
+        };
+        Self::connect_inner(req.config, signer, req.watch_only_descriptor).await
+    }
+
+    /// Initializes the SDK services with an external [Signer] holding the wallet keys, instead
+    /// of a mnemonic, and starts the background tasks.
+    ///
+    /// The signer is a separate argument rather than a [ConnectRequest] field, as the bindings
+    /// cannot pass an app-implemented callback within a request struct. It is exposed in each of
+    /// them as follows:
+    /// * Uniffi - a `Signer` callback interface, passed to `connect_with_signer`
+    /// * Dart - `connect_with_signer`, after listening to `signer_request_stream` and answering
+    ///   each request with `respond_to_signer_request`, as a Dart object cannot implement it
+    /// * React Native - `connectWithSigner`, taking a `Signer` object whose calls are relayed to
+    ///   it by the native module
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [ConnectRequest] containing the SDK [Config]. Neither `mnemonic` nor
+    ///   `watch_only_descriptor` can be set.
+    /// * `signer` - the [Signer] used to sign txs and derive keys
+    pub async fn connect_with_signer(
+        req: ConnectRequest,
+        signer: Box<dyn Signer>,
+    ) -> Result<Arc<LiquidSdk>> {
+        ensure_sdk!(
+            req.mnemonic.is_none() && req.watch_only_descriptor.is_none(),
+            anyhow!("A mnemonic or watch-only descriptor cannot be used along with a signer")
+        );
+        Self::connect_inner(req.config, Some(Arc::from(signer)), None).await
+    }
+
+    async fn connect_inner(
+        config: Config,
+        signer: Option<Arc<dyn Signer>>,
+        watch_only_descriptor: Option<String>,
+    ) -> Result<Arc<LiquidSdk>> {
+        // The swapper proxy is not needed if a Boltz instance is configured. The Breez server
+        // client has no proxy hook, so it is not fetched through the configured proxy either.
+        let maybe_swapper_proxy_url = match (&config.boltz_url, &config.proxy_url) {
+            (Some(_), _) | (_, Some(_)) => None,
+            (None, None) => match BreezServer::new(config.breez_server_url.clone(), None) {
                 Ok(breez_server) => breez_server
                     .fetch_boltz_swapper_urls()
                     .await
                     .ok()
                     .and_then(|swapper_urls| swapper_urls.first().cloned()),
                 Err(_) => None,
-            };
+            },
+        };
 
-        let sdk = LiquidSdk::new(req.config, maybe_swapper_proxy_url, req.mnemonic)?;
+        let sdk = LiquidSdk::new(
+            config,
+            maybe_swapper_proxy_url,
+            signer,
+            watch_only_descriptor,
+        )?;
         sdk.start().await?;
 
         Ok(sdk)
@@ -95,14 +157,25 @@ impl LiquidSdk {
     fn new(
         config: Config,
         swapper_proxy_url: Option<String>,
-        mnemonic: String,
+        signer: Option<Arc<dyn Signer>>,
+        watch_only_descriptor: Option<String>,
     ) -> Result<Arc<Self>> {
         fs::create_dir_all(&config.working_dir)?;
 
-        let onchain_wallet = Arc::new(LiquidOnchainWallet::new(mnemonic, config.clone())?);
+        let onchain_wallet = Arc::new(match (signer, watch_only_descriptor) {
+            (Some(signer), None) => LiquidOnchainWallet::new(signer, config.clone())?,
+            (None, Some(descriptor)) => {
+                LiquidOnchainWallet::new_watch_only(descriptor, config.clone())?
+            }
+            _ => {
+                return Err(anyhow!(
+                    "Either a mnemonic or a watch-only descriptor has to be provided"
+                ))
+            }
+        });
 
         let persister = Arc::new(Persister::new(
-            &config.get_wallet_working_dir(&onchain_wallet.lwk_signer)?,
+            &config.get_wallet_working_dir(&onchain_wallet.wallet_id)?,
             config.network,
         )?);
         persister.init()?;
@@ -114,13 +187,22 @@ impl LiquidSdk {
             persister.set_swapper_proxy_url(swapper_proxy_url)?;
         }
         let cached_swapper_proxy_url = persister.get_swapper_proxy_url()?;
-        let swapper = Arc::new(BoltzSwapper::new(config.clone(), cached_swapper_proxy_url));
+        let swapper = Arc::new(BoltzSwapper::new(config.clone(), cached_swapper_proxy_url)?);
         let status_stream = Arc::<dyn SwapperStatusStream>::from(swapper.create_status_stream());
 
-        let liquid_chain_service =
-            Arc::new(Mutex::new(HybridLiquidChainService::new(config.clone())?));
-        let bitcoin_chain_service =
-            Arc::new(Mutex::new(HybridBitcoinChainService::new(config.clone())?));
+        let (liquid_chain_service, bitcoin_chain_service): (
+            Arc<Mutex<dyn LiquidChainService>>,
+            Arc<Mutex<dyn BitcoinChainService>>,
+        ) = match config.chain_backend {
+            ChainBackend::Electrum => (
+                Arc::new(Mutex::new(HybridLiquidChainService::new(config.clone())?)),
+                Arc::new(Mutex::new(HybridBitcoinChainService::new(config.clone())?)),
+            ),
+            ChainBackend::Esplora => (
+                Arc::new(Mutex::new(EsploraLiquidChainService::new(config.clone())?)),
+                Arc::new(Mutex::new(EsploraBitcoinChainService::new(config.clone())?)),
+            ),
+        };
 
         let send_swap_state_handler = SendSwapStateHandler::new(
             config.clone(),
@@ -147,7 +229,7 @@ impl LiquidSdk {
             bitcoin_chain_service.clone(),
         )?);
 
-        let breez_server = Arc::new(BreezServer::new(PRODUCTION_BREEZSERVER_URL.into(), None)?);
+        let breez_server = Arc::new(BreezServer::new(config.breez_server_url.clone(), None)?);
 
         let buy_bitcoin_service =
             Arc::new(BuyBitcoinService::new(config.clone(), breez_server.clone()));
@@ -195,12 +277,28 @@ impl LiquidSdk {
     ///
     /// Internal method. Should only be used as part of [LiquidSdk::start].
     async fn start_background_tasks(self: &Arc<LiquidSdk>) -> SdkResult<()> {
-        // Periodically run sync() in the background
+        // Periodically check for chain changes in the background and sync if any is found. A
+        // full sync runs on startup and then every BACKGROUND_FULL_SCAN_INTERVAL_SEC.
         let sdk_clone = self.clone();
         let mut shutdown_rx_sync_loop = self.shutdown_receiver.clone();
         tokio::spawn(async move {
+            let mut last_full_sync: Option<Instant> = None;
             loop {
-                _ = sdk_clone.sync().await;
+                match last_full_sync {
+                    Some(last_full_sync)
+                        if last_full_sync.elapsed()
+                            < Duration::from_secs(BACKGROUND_FULL_SCAN_INTERVAL_SEC) =>
+                    {
+                        _ = sdk_clone.sync_on_chain_changes().await;
+                    }
+                    _ => {
+                        // Watch the wallet scripts before the scan, so that no later change is missed
+                        _ = sdk_clone.poll_chain_changes().await;
+                        if sdk_clone.sync().await.is_ok() {
+                            last_full_sync = Some(Instant::now());
+                        }
+                    }
+                }
 
                 tokio::select! {
                     _ = tokio::time::sleep(Duration::from_secs(30)) => {}
@@ -236,6 +334,29 @@ impl LiquidSdk {
         Ok(())
     }
 
+    /// Ensures the wallet can sign, as calls that sign or create swaps are not available in
+    /// watch-only mode
+    fn ensure_is_not_watch_only(&self) -> Result<(), PaymentError> {
+        ensure_sdk!(
+            !self.onchain_wallet.is_watch_only(),
+            PaymentError::WatchOnly
+        );
+        Ok(())
+    }
+
+    /// Ensures the Breez server, used for the fiat rates and to buy Bitcoin, is only called
+    /// without a [Config::proxy_url]. Its gRPC channel is built within [BreezServer], which
+    /// cannot be given a proxied connector.
+    fn ensure_breez_server_is_reachable(&self) -> Result<(), SdkError> {
+        ensure_sdk!(
+            self.config.proxy_url.is_none(),
+            SdkError::Generic {
+                err: "The Breez server cannot be reached through the configured proxy".to_string()
+            }
+        );
+        Ok(())
+    }
+
     /// Disconnects the [LiquidSdk] instance and stops the background tasks.
     pub async fn disconnect(&self) -> SdkResult<()> {
         self.ensure_is_started().await?;
@@ -255,6 +376,7 @@ impl LiquidSdk {
         tokio::spawn(async move {
             let mut shutdown_receiver = cloned.shutdown_receiver.clone();
             let mut updates_stream = cloned.status_stream.subscribe_swap_updates();
+            let mut chain_swap_events = cloned.chain_swap_state_handler.subscribe_events();
             let swaps_streams = vec![
                 cloned.send_swap_state_handler.subscribe_payment_updates(),
                 cloned
@@ -278,6 +400,14 @@ impl LiquidSdk {
                         }
                       }
                     }
+                    event = chain_swap_events.recv() => match event {
+                        Ok(event) => {
+                            if let Err(e) = cloned.notify_event_listeners(event).await {
+                                error!("Failed to emit chain swap event: {e:?}");
+                            }
+                        }
+                        Err(e) => error!("Failed to receive chain swap event: {e:?}"),
+                    },
                     update = updates_stream.recv() => match update {
                         Ok(update) => {
                             let id = &update.id;
@@ -355,7 +485,7 @@ impl LiquidSdk {
                 Direction::Incoming => {
                     let swap_script = chain_swap.get_lockup_swap_script()?.as_bitcoin_script()?;
                     let current_height =
-                        self.bitcoin_chain_service.lock().await.tip()?.height as u32;
+                        self.bitcoin_chain_service.lock().await.tip().await?.height as u32;
                     let locktime_from_height =
                         LockTime::from_height(current_height).map_err(|e| {
                             PaymentError::Generic {
@@ -525,6 +655,9 @@ impl LiquidSdk {
         let mut pending_receive_sat = 0;
         let mut confirmed_sent_sat = 0;
         let mut confirmed_received_sat = 0;
+        let mut balance_details = BalanceDetails::default();
+        // Per asset: (confirmed received, confirmed sent, pending send, pending receive)
+        let mut asset_totals: BTreeMap<String, (u64, u64, u64, u64)> = BTreeMap::new();
 
         for p in self
             .list_payments(&ListPaymentsRequest {
@@ -532,6 +665,25 @@ impl LiquidSdk {
             })
             .await?
         {
+            if let Some(asset_id) = &p.asset_id {
+                let totals = asset_totals.entry(asset_id.clone()).or_default();
+                let is_complete = p.status == Complete;
+                match (p.payment_type, is_complete) {
+                    (PaymentType::Send, true) => {
+                        totals.1 += p.amount_sat;
+                        confirmed_sent_sat += p.fees_sat;
+                    }
+                    (PaymentType::Send, false) => {
+                        totals.2 += p.amount_sat;
+                        pending_send_sat += p.fees_sat;
+                    }
+                    (PaymentType::Receive, true) => totals.0 += p.amount_sat,
+                    (PaymentType::Receive, false) => totals.3 += p.amount_sat,
+                }
+                continue;
+            }
+
+            let is_swap = p.swap_id.is_some();
             match p.payment_type {
                 PaymentType::Send => match p.status {
                     Complete => confirmed_sent_sat += p.amount_sat,
@@ -543,25 +695,98 @@ impl LiquidSdk {
                         Some(refund_tx_amount_sat) => {
                             confirmed_sent_sat += p.amount_sat;
                             pending_receive_sat += refund_tx_amount_sat;
+                            balance_details.pending_refund_sat += refund_tx_amount_sat;
+                        }
+                        None => {
+                            pending_send_sat += p.amount_sat;
+                            match is_swap {
+                                true => balance_details.pending_send_swaps_sat += p.amount_sat,
+                                false => balance_details.unconfirmed_send_sat += p.amount_sat,
+                            }
                         }
-                        None => pending_send_sat += p.amount_sat,
                     },
-                    Created => pending_send_sat += p.amount_sat,
-                    Refundable | RefundPending | TimedOut => {}
+                    // Only swaps are in the Created state
+                    Created => {
+                        pending_send_sat += p.amount_sat;
+                        balance_details.pending_send_swaps_sat += p.amount_sat;
+                    }
+                    RefundPending => {
+                        balance_details.pending_refund_sat +=
+                            p.refund_tx_amount_sat.unwrap_or(p.amount_sat)
+                    }
+                    Refundable | TimedOut | WaitingFeeAcceptance => {}
                 },
                 PaymentType::Receive => match p.status {
                     Complete => confirmed_received_sat += p.amount_sat,
-                    Pending => pending_receive_sat += p.amount_sat,
-                    Created | Refundable | RefundPending | Failed | TimedOut => {}
+                    Pending => {
+                        pending_receive_sat += p.amount_sat;
+                        match is_swap {
+                            true => balance_details.pending_receive_swaps_sat += p.amount_sat,
+                            false => balance_details.unconfirmed_receive_sat += p.amount_sat,
+                        }
+                    }
+                    Created | Refundable | RefundPending | Failed | TimedOut
+                    | WaitingFeeAcceptance => {}
                 },
             }
         }
+        balance_details.confirmed_sat = confirmed_received_sat.saturating_sub(confirmed_sent_sat);
+
+        // Incoming chain swaps are only listed as payments once claimed, so their BTC lockups
+        // are looked up separately
+        for swap in self
+            .persister
+            .list_refundable_chain_swaps()?
+            .into_iter()
+            .chain(self.persister.list_refund_pending_chain_swaps()?)
+            .filter(|swap| swap.direction == Direction::Incoming)
+        {
+            let lockup_amount_sat = self
+                .chain_swap_state_handler
+                .fetch_user_lockup_amount_sat(&swap)
+                .await
+                .unwrap_or_else(|e| {
+                    warn!(
+                        "Failed to fetch the lockup amount of Chain Swap {}: {e:?}",
+                        swap.id
+                    );
+                    swap.payer_amount_sat
+                });
+            match swap.state {
+                RefundPending => balance_details.pending_btc_refund_sat += lockup_amount_sat,
+                _ => balance_details.refundable_btc_sat += lockup_amount_sat,
+            }
+        }
+        balance_details.pending_receive_swaps_sat += self
+            .persister
+            .list_pending_chain_swaps()?
+            .iter()
+            .filter(|swap| {
+                swap.direction == Direction::Incoming
+                    && swap.state == Pending
+                    && swap.claim_tx_id.is_none()
+            })
+            .map(|swap| swap.receiver_amount_sat)
+            .sum::<u64>();
 
         Ok(GetInfoResponse {
             balance_sat: confirmed_received_sat - confirmed_sent_sat - pending_send_sat,
             pending_send_sat,
             pending_receive_sat,
             pubkey: self.onchain_wallet.pubkey(),
+            balance_details,
+            asset_balances: asset_totals
+                .into_iter()
+                .map(
+                    |(asset_id, (received, sent, pending_send, pending_receive))| AssetBalance {
+                        asset_metadata: self.config.get_asset_metadata(&asset_id),
+                        asset_id,
+                        balance: received.saturating_sub(sent + pending_send),
+                        pending_send,
+                        pending_receive,
+                    },
+                )
+                .collect(),
         })
     }
 
@@ -575,6 +800,7 @@ impl LiquidSdk {
         match (invoice.network().to_string().as_str(), self.config.network) {
             ("bitcoin", LiquidNetwork::Mainnet) => {}
             ("testnet", LiquidNetwork::Testnet) => {}
+            ("regtest", LiquidNetwork::Regtest) => {}
             _ => {
                 return Err(PaymentError::InvalidInvoice {
                     err: "Invoice cannot be paid on the current network".to_string(),
@@ -648,44 +874,138 @@ impl LiquidSdk {
             .sum())
     }
 
-    async fn estimate_lockup_tx_fee(&self, amount_sat: u64) -> Result<u64, PaymentError> {
+    /// Estimate the L-BTC onchain fee for sending an asset amount to the given destination address
+    async fn estimate_asset_tx_fee(
+        &self,
+        amount: u64,
+        address: &str,
+        asset_id: &str,
+    ) -> Result<u64, PaymentError> {
+        Ok(self
+            .onchain_wallet
+            .build_asset_tx(None, address, amount, asset_id)
+            .await?
+            .all_fees()
+            .values()
+            .sum())
+    }
+
+    /// Estimate the onchain fee for sending all funds to the given destination address
+    async fn estimate_drain_tx_fee(
+        &self,
+        address: &str,
+        fee_rate: Option<f32>,
+    ) -> Result<u64, PaymentError> {
+        Ok(self
+            .onchain_wallet
+            .build_drain_tx(fee_rate, address, None)
+            .await?
+            .all_fees()
+            .values()
+            .sum())
+    }
+
+    fn get_temp_p2tr_addr(&self) -> &'static str {
         // TODO Replace this with own address when LWK supports taproot
         //  https://github.com/Blockstream/lwk/issues/31
-        let temp_p2tr_addr = match self.config.network {
+        match self.config.network {
             LiquidNetwork::Mainnet => "lq1pqvzxvqhrf54dd4sny4cag7497pe38252qefk46t92frs7us8r80ja9ha8r5me09nn22m4tmdqp5p4wafq3s59cql3v9n45t5trwtxrmxfsyxjnstkctj",
-            LiquidNetwork::Testnet => "tlq1pq0wqu32e2xacxeyps22x8gjre4qk3u6r70pj4r62hzczxeyz8x3yxucrpn79zy28plc4x37aaf33kwt6dz2nn6gtkya6h02mwpzy4eh69zzexq7cf5y5"
-        };
+            LiquidNetwork::Testnet => "tlq1pq0wqu32e2xacxeyps22x8gjre4qk3u6r70pj4r62hzczxeyz8x3yxucrpn79zy28plc4x37aaf33kwt6dz2nn6gtkya6h02mwpzy4eh69zzexq7cf5y5",
+            LiquidNetwork::Regtest => "el1pq0wqu32e2xacxeyps22x8gjre4qk3u6r70pj4r62hzczxeyz8x3yxucrpn79zy28plc4x37aaf33kwt6dz2nn6gtkya6h02mwpzy4eh6txtavh70wdan"
+        }
+    }
 
+    async fn estimate_lockup_tx_fee(&self, amount_sat: u64) -> Result<u64, PaymentError> {
         self.estimate_onchain_tx_fee(
             amount_sat,
-            temp_p2tr_addr,
+            self.get_temp_p2tr_addr(),
             self.config.lowball_fee_rate_msat_per_vbyte(),
         )
         .await
     }
 
-    /// Prepares to pay a Lightning invoice via a submarine swap.
+    /// Estimate the fee of a lockup tx draining the wallet. This uses the same fee rate as the
+    /// chain swap lockup, so that the drained amount matches the amount agreed with the swapper.
+    async fn estimate_drain_lockup_tx_fee(&self) -> Result<u64, PaymentError> {
+        self.estimate_drain_tx_fee(self.get_temp_p2tr_addr(), None)
+            .await
+    }
+
+    /// Resolves the amount to pay for the given invoice. Amountless invoices require a
+    /// caller-supplied amount, while invoices with an amount only accept a matching one.
+    fn get_receiver_amount_sat(
+        invoice: &Bolt11Invoice,
+        amount_sat: Option<u64>,
+    ) -> Result<u64, PaymentError> {
+        match (invoice.amount_milli_satoshis(), amount_sat) {
+            (Some(invoice_amount_msat), None) => Ok(invoice_amount_msat / 1000),
+            (Some(invoice_amount_msat), Some(amount_sat)) => {
+                ensure_sdk!(
+                    invoice_amount_msat / 1000 == amount_sat,
+                    PaymentError::InvalidInvoice {
+                        err: "The specified amount does not match the invoice amount".to_string()
+                    }
+                );
+                Ok(amount_sat)
+            }
+            (None, Some(amount_sat)) => {
+                ensure_sdk!(amount_sat > 0, PaymentError::AmountOutOfRange);
+                Ok(amount_sat)
+            }
+            (None, None) => Err(PaymentError::AmountMissing {
+                err: "An amount is required to pay an amountless invoice".to_string(),
+            }),
+        }
+    }
+
+    /// Prepares to pay a Lightning invoice or offer via a submarine swap.
+    ///
+    /// When paying a bolt12 offer, the invoice to pay is fetched from the offer's issuer.
     ///
     /// # Arguments
     ///
     /// * `req` - the [PrepareSendRequest] containing:
-    ///     * `invoice` - the bolt11 Lightning invoice to pay
+    ///     * `invoice` - the bolt11 Lightning invoice or bolt12 offer to pay
+    ///     * `amount_sat` - the optional amount to pay, required if the invoice or offer has no amount
+    ///     * `idempotency_key` - the optional key identifying the payment, see [LiquidSdk::send_payment]
+    ///     * `payer_note` - the optional note to the recipient, only supported for bolt12 offers
     pub async fn prepare_send_payment(
         &self,
         req: &PrepareSendRequest,
     ) -> Result<PrepareSendResponse, PaymentError> {
         self.ensure_is_started().await?;
+        self.ensure_is_not_watch_only()?;
 
-        self.ensure_send_is_not_self_transfer(&req.invoice)?;
-        let invoice = self.validate_invoice(&req.invoice)?;
-
-        let receiver_amount_sat = invoice
-            .amount_milli_satoshis()
-            .ok_or(PaymentError::AmountOutOfRange)?
-            / 1000;
+        let (invoice, receiver_amount_sat, offer) = match bolt12::is_offer(&req.invoice) {
+            true => {
+                let (invoice, receiver_amount_sat) = self.fetch_bolt12_invoice(req)?;
+                (
+                    invoice,
+                    receiver_amount_sat,
+                    Some(req.invoice.trim().to_string()),
+                )
+            }
+            false => {
+                ensure_sdk!(
+                    req.payer_note.is_none(),
+                    PaymentError::Generic {
+                        err: "A payer note can only be sent when paying a bolt12 offer".to_string()
+                    }
+                );
+                self.ensure_send_is_not_self_transfer(&req.invoice)?;
+                let invoice = self.validate_invoice(&req.invoice)?;
+                let receiver_amount_sat = Self::get_receiver_amount_sat(&invoice, req.amount_sat)?;
+                (req.invoice.clone(), receiver_amount_sat, None)
+            }
+        };
         let lbtc_pair = self.validate_submarine_pairs(receiver_amount_sat)?;
 
-        let fees_sat = match self.swapper.check_for_mrh(&req.invoice)? {
+        // Magic routing hints are only found in bolt11 invoices
+        let mrh = match offer {
+            Some(_) => None,
+            None => self.swapper.check_for_mrh(&invoice)?,
+        };
+        let fees_sat = match mrh {
             Some((lbtc_address, _)) => {
                 self.estimate_onchain_tx_fee(receiver_amount_sat, &lbtc_address, None)
                     .await?
@@ -703,11 +1023,42 @@ impl LiquidSdk {
         );
 
         Ok(PrepareSendResponse {
-            invoice: req.invoice.clone(),
+            invoice,
+            offer,
+            receiver_amount_sat,
             fees_sat,
+            idempotency_key: req.idempotency_key.clone(),
         })
     }
 
+    /// Fetches an invoice for the bolt12 offer of the request, returning it with its amount
+    fn fetch_bolt12_invoice(
+        &self,
+        req: &PrepareSendRequest,
+    ) -> Result<(String, u64), PaymentError> {
+        let offer = bolt12::validate_offer(&req.invoice, self.config.network)?;
+        let receiver_amount_sat = bolt12::get_receiver_amount_sat(&offer, req.amount_sat)?;
+
+        let invoice_str = self.swapper.get_bolt12_invoice(
+            req.invoice.trim(),
+            receiver_amount_sat,
+            req.payer_note.clone(),
+        )?;
+        let invoice = bolt12::validate_invoice(
+            &invoice_str,
+            &offer,
+            receiver_amount_sat,
+            self.config.network,
+        )?;
+        ensure_sdk!(
+            bolt12::get_payer_note(&invoice) == req.payer_note,
+            PaymentError::InvalidInvoice {
+                err: "Invoice payer note does not match the requested one".to_string()
+            }
+        );
+        Ok((invoice_str, receiver_amount_sat))
+    }
+
     fn ensure_send_is_not_self_transfer(&self, invoice: &str) -> Result<(), PaymentError> {
         match self.persister.fetch_receive_swap_by_invoice(invoice)? {
             None => Ok(()),
@@ -726,6 +1077,9 @@ impl LiquidSdk {
     ///
     /// * `req` - The [PrepareSendResponse] from calling [LiquidSdk::prepare_send_payment]
     ///
+    /// If the `idempotency_key` of the request was already used by an earlier call, the payment
+    /// started by that call is returned instead of paying again.
+    ///
     /// # Errors
     ///
     /// * [PaymentError::PaymentTimeout] - if the payment could not be initiated in this time
@@ -734,18 +1088,43 @@ impl LiquidSdk {
         req: &PrepareSendResponse,
     ) -> Result<SendPaymentResponse, PaymentError> {
         self.ensure_is_started().await?;
+        self.ensure_is_not_watch_only()?;
 
-        self.ensure_send_is_not_self_transfer(&req.invoice)?;
-        self.validate_invoice(&req.invoice)?;
+        if let Some(idempotency_key) = &req.idempotency_key {
+            if let Some(payment) = self.get_send_payment_by_idempotency_key(idempotency_key, req)? {
+                info!("Found existing payment for idempotency key {idempotency_key}");
+                return Ok(SendPaymentResponse { payment });
+            }
+        }
 
-        let amount_sat = get_invoice_amount!(&req.invoice);
+        let amount_sat = match &req.offer {
+            Some(offer) => {
+                let offer = bolt12::validate_offer(offer, self.config.network)?;
+                bolt12::validate_invoice(
+                    &req.invoice,
+                    &offer,
+                    req.receiver_amount_sat,
+                    self.config.network,
+                )?;
+                req.receiver_amount_sat
+            }
+            None => {
+                self.ensure_send_is_not_self_transfer(&req.invoice)?;
+                let invoice = self.validate_invoice(&req.invoice)?;
+                Self::get_receiver_amount_sat(&invoice, Some(req.receiver_amount_sat))?
+            }
+        };
         let payer_amount_sat = amount_sat + req.fees_sat;
         ensure_sdk!(
             payer_amount_sat <= self.get_info().await?.balance_sat,
             PaymentError::InsufficientFunds
         );
 
-        match self.swapper.check_for_mrh(&req.invoice)? {
+        let mrh = match req.offer {
+            Some(_) => None,
+            None => self.swapper.check_for_mrh(&req.invoice)?,
+        };
+        match mrh {
             // If we find a valid MRH, extract the BIP21 amount and address, then pay via onchain tx
             Some((address, amount_btc)) => {
                 self.send_payment_via_mrh(req, &address, amount_btc).await
@@ -756,6 +1135,65 @@ impl LiquidSdk {
         }
     }
 
+    /// Looks up the payment started by an earlier [LiquidSdk::send_payment] call with the same
+    /// idempotency key.
+    ///
+    /// Returns `None` if there is no such payment, or if its swap was created but not yet funded.
+    /// In that case the swap is resumed by [LiquidSdk::send_payment_via_swap], which reuses the
+    /// swap of the invoice. Fails if the swap timed out before it was funded, as it can neither
+    /// be resumed nor replaced by another swap with the same key.
+    fn get_send_payment_by_idempotency_key(
+        &self,
+        idempotency_key: &str,
+        req: &PrepareSendResponse,
+    ) -> Result<Option<Payment>, PaymentError> {
+        match self
+            .persister
+            .fetch_send_swap_by_idempotency_key(idempotency_key)?
+        {
+            Some(swap) => {
+                ensure_sdk!(
+                    swap.invoice == req.invoice,
+                    PaymentError::Generic {
+                        err: "The idempotency key was already used for another invoice".to_string()
+                    }
+                );
+                match swap.state {
+                    Created => Ok(None),
+                    TimedOut => Err(PaymentError::Generic {
+                        err: format!(
+                            "Swap {} of the idempotency key timed out before it was funded, \
+                            use a new key to pay again",
+                            swap.id
+                        ),
+                    }),
+                    state => Ok(Some(self.get_idempotent_swap_payment(&swap.id, state)?)),
+                }
+            }
+            // Payments via MRH have no swap, so the key is kept with the payment details
+            None => Ok(self
+                .persister
+                .get_payment_by_idempotency_key(idempotency_key)?),
+        }
+    }
+
+    /// Returns the payment of a funded swap, started by an earlier call with the same
+    /// idempotency key
+    fn get_idempotent_swap_payment(
+        &self,
+        swap_id: &str,
+        state: PaymentState,
+    ) -> Result<Payment, PaymentError> {
+        match self.persister.get_payment_by_tx_or_swap_id(swap_id)? {
+            Some(payment) => Ok(payment),
+            // The lockup tx is not yet known to the wallet
+            None if state == Pending => Err(PaymentError::PaymentInProgress),
+            None => Err(PaymentError::Generic {
+                err: format!("Swap {swap_id} has no payment, its state is {state:?}"),
+            }),
+        }
+    }
+
     /// Performs a Send Payment by doing an onchain tx to the L-BTC address in the MRH.
     async fn send_payment_via_mrh(
         &self,
@@ -766,7 +1204,7 @@ impl LiquidSdk {
         let amount_sat: u64 = (amount_btc * 100_000_000.0) as u64;
         info!("Found MRH for L-BTC address {lbtc_address} and amount_sat {amount_sat}");
 
-        let receiver_amount_sat = get_invoice_amount!(req.invoice);
+        let receiver_amount_sat = req.receiver_amount_sat;
         let tx = self
             .onchain_wallet
             .build_tx(None, lbtc_address, receiver_amount_sat)
@@ -778,11 +1216,10 @@ impl LiquidSdk {
 
         let tx_id = tx.txid().to_string();
         let tx_hex = lwk_wollet::elements::encode::serialize(&tx).to_lower_hex_string();
-        self.swapper
-            .broadcast_tx(self.config.network.into(), &tx_hex)?;
 
         // We insert a pseudo-tx in case LWK fails to pick up the new mempool tx for a while
-        // This makes the tx known to the SDK (get_info, list_payments) instantly
+        // This makes the tx known to the SDK (get_info, list_payments) instantly.
+        // It is inserted before broadcasting, so that the idempotency key is taken atomically.
         let tx_data = PaymentTxData {
             tx_id: tx_id.clone(),
             timestamp: Some(utils::now()),
@@ -790,12 +1227,41 @@ impl LiquidSdk {
             fees_sat: onchain_fees_sat,
             payment_type: PaymentType::Send,
             is_confirmed: false,
+            asset_id: None,
         };
-        self.persister.insert_or_update_payment(tx_data.clone())?;
+        let details = PaymentTxDetails {
+            tx_id: tx_id.clone(),
+            destination: Some(lbtc_address.to_string()),
+            description: None,
+            idempotency_key: req.idempotency_key.clone(),
+        };
+        if !self
+            .persister
+            .insert_payment_with_details(tx_data.clone(), details.clone())?
+        {
+            // A concurrent call with the same idempotency key made the payment in the meantime
+            let idempotency_key = req.idempotency_key.clone().unwrap_or_default();
+            info!("Found existing payment for idempotency key {idempotency_key}");
+            return match self
+                .persister
+                .get_payment_by_idempotency_key(&idempotency_key)?
+            {
+                Some(payment) => Ok(SendPaymentResponse { payment }),
+                None => Err(PaymentError::PaymentInProgress),
+            };
+        }
+        if let Err(e) = self
+            .swapper
+            .broadcast_tx(self.config.network.into(), &tx_hex)
+        {
+            self.persister.delete_payment_with_details(&tx_id)?;
+            return Err(e);
+        }
+        self.snapshot_fiat_rates(vec![tx_id.clone()]);
         self.emit_payment_updated(Some(tx_id)).await?; // Emit Pending event
 
         Ok(SendPaymentResponse {
-            payment: Payment::from_tx_data(tx_data, None),
+            payment: Payment::from_tx_data(tx_data, None, Some(details)),
         })
     }
 
@@ -804,7 +1270,7 @@ impl LiquidSdk {
         &self,
         req: &PrepareSendResponse,
     ) -> Result<SendPaymentResponse, PaymentError> {
-        let receiver_amount_sat = get_invoice_amount!(req.invoice);
+        let receiver_amount_sat = req.receiver_amount_sat;
         let lbtc_pair = self.validate_submarine_pairs(receiver_amount_sat)?;
         let lockup_tx_fees_sat = self.estimate_lockup_tx_fee(receiver_amount_sat).await?;
         ensure_sdk!(
@@ -843,6 +1309,10 @@ impl LiquidSdk {
                 let create_response_json =
                     SendSwap::from_boltz_struct_to_json(&create_response, swap_id)?;
                 let description = get_invoice_description!(req.invoice);
+                let payer_note = match req.offer {
+                    Some(_) => bolt12::get_payer_note(&bolt12::parse_invoice(&req.invoice)?),
+                    None => None,
+                };
 
                 let payer_amount_sat = req.fees_sat + receiver_amount_sat;
                 let swap = SendSwap {
@@ -858,8 +1328,12 @@ impl LiquidSdk {
                     created_at: utils::now(),
                     state: PaymentState::Created,
                     refund_private_key: keypair.display_secret().to_string(),
+                    idempotency_key: req.idempotency_key.clone(),
+                    bolt12_offer: req.offer.clone(),
+                    payer_note,
                 };
                 self.persister.insert_send_swap(&swap)?;
+                self.snapshot_fiat_rates(vec![swap.id.clone()]);
                 swap
             }
         };
@@ -871,78 +1345,361 @@ impl LiquidSdk {
             .map(|payment| SendPaymentResponse { payment })
     }
 
-    /// Fetch the current payment limits for [LiquidSdk::send_payment] and [LiquidSdk::receive_payment].
-    pub async fn fetch_lightning_limits(
+    /// Prepares to pay directly to a Liquid address, without a swap.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [PrepareSendLiquidRequest] containing:
+    ///     * `destination` - the confidential Liquid address or `liquidnetwork:` BIP21 URI to pay to
+    ///     * `amount_sat` - the optional amount to send, required if the destination has no amount
+    ///     * `drain` - the optional flag to send all funds, leaving no change
+    ///     * `asset_id` - the optional id of the Liquid asset to send, if other than L-BTC
+    pub async fn prepare_send_liquid(
         &self,
-    ) -> Result<LightningPaymentLimitsResponse, PaymentError> {
+        req: &PrepareSendLiquidRequest,
+    ) -> Result<PrepareSendLiquidResponse, PaymentError> {
         self.ensure_is_started().await?;
+        self.ensure_is_not_watch_only()?;
+
+        let bip21 = LiquidBip21::parse(&req.destination, self.config.network)?;
+        let asset_id = self.resolve_asset_id(bip21.asset_id.as_deref(), req.asset_id.as_deref())?;
+        if req.drain.unwrap_or(false) {
+            ensure_sdk!(
+                asset_id.is_none(),
+                PaymentError::InvalidDestination {
+                    err: "Draining the wallet is only supported for L-BTC".to_string()
+                }
+            );
+            ensure_sdk!(
+                bip21.amount_sat.is_none() && req.amount_sat.is_none(),
+                PaymentError::InvalidDestination {
+                    err: "An amount cannot be specified when draining the wallet".to_string()
+                }
+            );
 
-        let submarine_pair = self
-            .swapper
-            .get_submarine_pairs()?
-            .ok_or(PaymentError::PairsNotFound)?;
-        let send_limits = submarine_pair.limits;
-
-        let reverse_pair = self
-            .swapper
-            .get_reverse_swap_pairs()?
-            .ok_or(PaymentError::PairsNotFound)?;
-        let receive_limits = reverse_pair.limits;
+            let fees_sat = self.estimate_drain_tx_fee(&bip21.address, None).await?;
+            let balance_sat = self.onchain_wallet.balance_sat().await?;
+            ensure_sdk!(balance_sat > fees_sat, PaymentError::InsufficientFunds);
+
+            return Ok(PrepareSendLiquidResponse {
+                description: bip21.description(),
+                address: bip21.address,
+                receiver_amount_sat: balance_sat - fees_sat,
+                fees_sat,
+                drain: true,
+                asset_id: None,
+            });
+        }
 
-        Ok(LightningPaymentLimitsResponse {
-            send: Limits {
-                min_sat: send_limits.minimal,
-                max_sat: send_limits.maximal,
-                max_zero_conf_sat: send_limits.maximal_zero_conf,
-            },
-            receive: Limits {
-                min_sat: receive_limits.minimal,
-                max_sat: receive_limits.maximal,
-                max_zero_conf_sat: self.config.zero_conf_max_amount_sat(),
+        let bip21_amount_sat = match (&asset_id, bip21.amount_sat) {
+            (Some(asset_id), Some(amount)) => match self.config.get_asset_metadata(asset_id) {
+                Some(asset_metadata) => Some(asset_metadata.amount_from_bip21(amount)?),
+                None => Some(amount),
             },
-        })
-    }
+            (_, amount) => amount,
+        };
+        let receiver_amount_sat = match (bip21_amount_sat, req.amount_sat) {
+            (Some(bip21_amount_sat), Some(amount_sat)) => {
+                ensure_sdk!(
+                    bip21_amount_sat == amount_sat,
+                    PaymentError::InvalidDestination {
+                        err: "The specified amount does not match the destination amount"
+                            .to_string()
+                    }
+                );
+                amount_sat
+            }
+            (Some(amount_sat), None) | (None, Some(amount_sat)) => amount_sat,
+            (None, None) => {
+                return Err(PaymentError::AmountMissing {
+                    err: "An amount is required to pay to a Liquid address".to_string(),
+                })
+            }
+        };
+        ensure_sdk!(receiver_amount_sat > 0, PaymentError::AmountOutOfRange);
+
+        if let Some(asset_id) = asset_id {
+            let fees_sat = self
+                .estimate_asset_tx_fee(receiver_amount_sat, &bip21.address, &asset_id)
+                .await?;
+            self.ensure_asset_send_funds(&asset_id, receiver_amount_sat, fees_sat)
+                .await?;
+
+            return Ok(PrepareSendLiquidResponse {
+                description: bip21.description(),
+                address: bip21.address,
+                receiver_amount_sat,
+                fees_sat,
+                drain: false,
+                asset_id: Some(asset_id),
+            });
+        }
 
-    /// Fetch the current payment limits for [LiquidSdk::pay_onchain] and [LiquidSdk::receive_onchain].
-    pub async fn fetch_onchain_limits(&self) -> Result<OnchainPaymentLimitsResponse, PaymentError> {
-        self.ensure_is_started().await?;
+        let fees_sat = self
+            .estimate_onchain_tx_fee(receiver_amount_sat, &bip21.address, None)
+            .await?;
 
-        let (pair_outgoing, pair_incoming) = self.swapper.get_chain_pairs()?;
-        let send_limits = pair_outgoing
-            .ok_or(PaymentError::PairsNotFound)
-            .map(|pair| pair.limits)?;
-        let receive_limits = pair_incoming
-            .ok_or(PaymentError::PairsNotFound)
-            .map(|pair| pair.limits)?;
+        let payer_amount_sat = receiver_amount_sat + fees_sat;
+        ensure_sdk!(
+            payer_amount_sat <= self.get_info().await?.balance_sat,
+            PaymentError::InsufficientFunds
+        );
 
-        Ok(OnchainPaymentLimitsResponse {
-            send: Limits {
-                min_sat: send_limits.minimal,
-                max_sat: send_limits.maximal,
-                max_zero_conf_sat: send_limits.maximal_zero_conf,
-            },
-            receive: Limits {
-                min_sat: receive_limits.minimal,
-                max_sat: receive_limits.maximal,
-                max_zero_conf_sat: receive_limits.maximal_zero_conf,
-            },
+        Ok(PrepareSendLiquidResponse {
+            description: bip21.description(),
+            address: bip21.address,
+            receiver_amount_sat,
+            fees_sat,
+            drain: false,
+            asset_id: None,
         })
     }
 
-    /// Prepares to pay to a Bitcoin address via a chain swap.
-    ///
-    /// # Arguments
-    ///
-    /// * `req` - the [PreparePayOnchainRequest] containing:
-    ///     * `receiver_amount_sat` - the amount in satoshi that will be received
-    ///     * `sat_per_vbyte` - the optional fee rate of the Bitcoin claim transaction. Defaults to the swapper estimated claim fee
-    pub async fn prepare_pay_onchain(
+    /// Resolves the asset of a direct Liquid payment from the destination and the request,
+    /// returning `None` for L-BTC
+    fn resolve_asset_id(
         &self,
-        req: &PreparePayOnchainRequest,
-    ) -> Result<PreparePayOnchainResponse, PaymentError> {
-        self.ensure_is_started().await?;
-
-        let receiver_amount_sat = req.receiver_amount_sat;
+        destination_asset_id: Option<&str>,
+        req_asset_id: Option<&str>,
+    ) -> Result<Option<String>, PaymentError> {
+        let asset_id = match (destination_asset_id, req_asset_id) {
+            (Some(destination_asset_id), Some(req_asset_id)) => {
+                ensure_sdk!(
+                    destination_asset_id == req_asset_id,
+                    PaymentError::InvalidDestination {
+                        err: "The specified asset does not match the destination asset".to_string()
+                    }
+                );
+                Some(req_asset_id)
+            }
+            (destination_asset_id, req_asset_id) => destination_asset_id.or(req_asset_id),
+        };
+
+        let policy_asset_id = ElementsNetwork::from(self.config.network)
+            .policy_asset()
+            .to_string();
+        match asset_id {
+            Some(asset_id) if asset_id != policy_asset_id => {
+                elements::AssetId::from_str(asset_id).map_err(|e| {
+                    PaymentError::InvalidDestination {
+                        err: format!("Invalid asset id {asset_id}: {e}"),
+                    }
+                })?;
+                Ok(Some(asset_id.to_string()))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Ensures the wallet holds enough of the asset to send, as well as enough L-BTC for the fees
+    async fn ensure_asset_send_funds(
+        &self,
+        asset_id: &str,
+        amount: u64,
+        fees_sat: u64,
+    ) -> Result<(), PaymentError> {
+        let info = self.get_info().await?;
+        let asset_balance = info
+            .asset_balances
+            .iter()
+            .find(|balance| balance.asset_id == asset_id)
+            .map(|balance| balance.balance)
+            .unwrap_or_default();
+        ensure_sdk!(
+            amount <= asset_balance && fees_sat <= info.balance_sat,
+            PaymentError::InsufficientFunds
+        );
+        Ok(())
+    }
+
+    /// Pays directly to a Liquid address, without a swap.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - The [PrepareSendLiquidResponse] from calling [LiquidSdk::prepare_send_liquid]
+    ///
+    /// # Errors
+    ///
+    /// * [PaymentError::InvalidOrExpiredFees] - if the fees of the built tx differ from the prepared ones
+    pub async fn send_liquid(
+        &self,
+        req: &PrepareSendLiquidResponse,
+    ) -> Result<SendPaymentResponse, PaymentError> {
+        self.ensure_is_started().await?;
+        self.ensure_is_not_watch_only()?;
+
+        let address = LiquidBip21::parse(&req.address, self.config.network)?.address;
+        let (payer_amount_sat, tx) = match &req.asset_id {
+            Some(asset_id) => {
+                self.ensure_asset_send_funds(asset_id, req.receiver_amount_sat, req.fees_sat)
+                    .await?;
+                let tx = self
+                    .onchain_wallet
+                    .build_asset_tx(None, &address, req.receiver_amount_sat, asset_id)
+                    .await?;
+                (req.receiver_amount_sat, tx)
+            }
+            None => {
+                let payer_amount_sat = req.receiver_amount_sat + req.fees_sat;
+                ensure_sdk!(
+                    payer_amount_sat <= self.get_info().await?.balance_sat,
+                    PaymentError::InsufficientFunds
+                );
+                let tx = match req.drain {
+                    true => {
+                        self.onchain_wallet
+                            .build_drain_tx(None, &address, Some(req.receiver_amount_sat))
+                            .await?
+                    }
+                    false => {
+                        self.onchain_wallet
+                            .build_tx(None, &address, req.receiver_amount_sat)
+                            .await?
+                    }
+                };
+                (payer_amount_sat, tx)
+            }
+        };
+        let onchain_fees_sat: u64 = tx.all_fees().values().sum();
+        ensure_sdk!(
+            onchain_fees_sat == req.fees_sat,
+            PaymentError::InvalidOrExpiredFees
+        );
+        info!("Built onchain Liquid tx with ID {}", tx.txid());
+
+        let tx_id = self
+            .liquid_chain_service
+            .lock()
+            .await
+            .broadcast(&tx, None)
+            .await?
+            .to_string();
+        info!("Successfully broadcast Liquid tx {tx_id} to {address}");
+
+        // We insert a pseudo-tx in case LWK fails to pick up the new mempool tx for a while
+        // This makes the tx known to the SDK (get_info, list_payments) instantly
+        let tx_data = PaymentTxData {
+            tx_id: tx_id.clone(),
+            timestamp: Some(utils::now()),
+            amount_sat: payer_amount_sat,
+            fees_sat: onchain_fees_sat,
+            payment_type: PaymentType::Send,
+            is_confirmed: false,
+            asset_id: req.asset_id.clone(),
+        };
+        let details = PaymentTxDetails {
+            tx_id: tx_id.clone(),
+            destination: Some(address),
+            description: req.description.clone(),
+            idempotency_key: None,
+        };
+        self.persister.insert_or_update_payment(tx_data.clone())?;
+        self.persister
+            .insert_or_update_payment_details(details.clone())?;
+        self.snapshot_fiat_rates(vec![tx_id.clone()]);
+        self.emit_payment_updated(Some(tx_id)).await?; // Emit Pending event
+
+        Ok(SendPaymentResponse {
+            payment: Payment::from_tx_data(tx_data, None, Some(details)),
+        })
+    }
+
+    /// Fetch the current payment limits for [LiquidSdk::send_payment] and [LiquidSdk::receive_payment].
+    pub async fn fetch_lightning_limits(
+        &self,
+    ) -> Result<LightningPaymentLimitsResponse, PaymentError> {
+        self.ensure_is_started().await?;
+
+        let submarine_pair = self
+            .swapper
+            .get_submarine_pairs()?
+            .ok_or(PaymentError::PairsNotFound)?;
+        let send_limits = submarine_pair.limits;
+
+        let reverse_pair = self
+            .swapper
+            .get_reverse_swap_pairs()?
+            .ok_or(PaymentError::PairsNotFound)?;
+        let receive_limits = reverse_pair.limits;
+
+        Ok(LightningPaymentLimitsResponse {
+            send: Limits {
+                min_sat: send_limits.minimal,
+                max_sat: send_limits.maximal,
+                max_zero_conf_sat: send_limits.maximal_zero_conf,
+            },
+            receive: Limits {
+                min_sat: receive_limits.minimal,
+                max_sat: receive_limits.maximal,
+                max_zero_conf_sat: self.config.zero_conf_max_amount_sat(),
+            },
+        })
+    }
+
+    /// Fetch the current payment limits for [LiquidSdk::pay_onchain] and [LiquidSdk::receive_onchain].
+    pub async fn fetch_onchain_limits(&self) -> Result<OnchainPaymentLimitsResponse, PaymentError> {
+        self.ensure_is_started().await?;
+
+        let (pair_outgoing, pair_incoming) = self.swapper.get_chain_pairs()?;
+        let send_limits = pair_outgoing
+            .ok_or(PaymentError::PairsNotFound)
+            .map(|pair| pair.limits)?;
+        let receive_limits = pair_incoming
+            .ok_or(PaymentError::PairsNotFound)
+            .map(|pair| pair.limits)?;
+
+        Ok(OnchainPaymentLimitsResponse {
+            send: Limits {
+                min_sat: send_limits.minimal,
+                max_sat: send_limits.maximal,
+                max_zero_conf_sat: send_limits.maximal_zero_conf,
+            },
+            receive: Limits {
+                min_sat: receive_limits.minimal,
+                max_sat: receive_limits.maximal,
+                max_zero_conf_sat: receive_limits.maximal_zero_conf,
+            },
+        })
+    }
+
+    /// Computes the largest server lockup amount the given user lockup amount can pay for,
+    /// after the swapper fees.
+    fn get_drain_server_lockup_amount_sat(pair: &ChainPair, user_lockup_amount_sat: u64) -> u64 {
+        let server_fees_sat = pair.fees.server();
+        let max_amount_sat = user_lockup_amount_sat.saturating_sub(server_fees_sat);
+
+        // Start from a lower bound and increase it while the user lockup amount still covers it
+        let mut server_lockup_amount_sat =
+            max_amount_sat.saturating_sub(pair.fees.boltz(max_amount_sat));
+        while server_lockup_amount_sat < max_amount_sat
+            && server_lockup_amount_sat + 1 + pair.fees.boltz(server_lockup_amount_sat + 1)
+                <= max_amount_sat
+        {
+            server_lockup_amount_sat += 1;
+        }
+        server_lockup_amount_sat
+    }
+
+    /// Prepares to pay to a Bitcoin address via a chain swap.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [PreparePayOnchainRequest] containing:
+    ///     * `receiver_amount_sat` - the amount in satoshi that will be received
+    ///     * `sat_per_vbyte` - the optional fee rate of the Bitcoin claim transaction. Defaults to the swapper estimated claim fee
+    ///     * `drain` - the optional flag to send all funds, in which case `receiver_amount_sat` is ignored
+    pub async fn prepare_pay_onchain(
+        &self,
+        req: &PreparePayOnchainRequest,
+    ) -> Result<PreparePayOnchainResponse, PaymentError> {
+        self.ensure_is_started().await?;
+        self.ensure_is_not_watch_only()?;
+
+        if req.drain.unwrap_or(false) {
+            return self.prepare_pay_onchain_drain(req.sat_per_vbyte).await;
+        }
+
+        let receiver_amount_sat = req.receiver_amount_sat;
         let pair = self.validate_chain_pairs(Direction::Outgoing, receiver_amount_sat)?;
         let claim_fees_sat = match req.sat_per_vbyte {
             Some(sat_per_vbyte) => ESTIMATED_BTC_CLAIM_TX_VSIZE * sat_per_vbyte as u64,
@@ -961,6 +1718,7 @@ impl LiquidSdk {
                 + lockup_fees_sat
                 + claim_fees_sat
                 + server_fees_sat,
+            drain: false,
         };
 
         let payer_amount_sat = res.receiver_amount_sat + res.total_fees_sat;
@@ -972,6 +1730,47 @@ impl LiquidSdk {
         Ok(res)
     }
 
+    /// Prepares a chain swap which sends the whole wallet balance, leaving no change
+    async fn prepare_pay_onchain_drain(
+        &self,
+        sat_per_vbyte: Option<u32>,
+    ) -> Result<PreparePayOnchainResponse, PaymentError> {
+        let pair = self
+            .swapper
+            .get_chain_pair(Direction::Outgoing)?
+            .ok_or(PaymentError::PairsNotFound)?;
+        let claim_fees_sat = match sat_per_vbyte {
+            Some(sat_per_vbyte) => ESTIMATED_BTC_CLAIM_TX_VSIZE * sat_per_vbyte as u64,
+            None => pair.fees.claim_estimate(),
+        };
+
+        // The balance of get_info also counts the pending payments, while the drain tx only
+        // spends the wallet UTXOs
+        let balance_sat = self.onchain_wallet.balance_sat().await?;
+        let lockup_fees_sat = self.estimate_drain_lockup_tx_fee().await?;
+        ensure_sdk!(
+            balance_sat > lockup_fees_sat,
+            PaymentError::InsufficientFunds
+        );
+        let user_lockup_amount_sat = balance_sat - lockup_fees_sat;
+
+        let server_lockup_amount_sat =
+            Self::get_drain_server_lockup_amount_sat(&pair, user_lockup_amount_sat);
+        ensure_sdk!(
+            server_lockup_amount_sat > claim_fees_sat,
+            PaymentError::AmountOutOfRange
+        );
+        let receiver_amount_sat = server_lockup_amount_sat - claim_fees_sat;
+        self.validate_chain_pairs(Direction::Outgoing, receiver_amount_sat)?;
+
+        Ok(PreparePayOnchainResponse {
+            receiver_amount_sat,
+            claim_fees_sat,
+            total_fees_sat: balance_sat - receiver_amount_sat,
+            drain: true,
+        })
+    }
+
     /// Pays to a Bitcoin address via a chain swap.
     ///
     /// Depending on [Config]'s `payment_timeout_sec`, this function will return:
@@ -984,6 +1783,8 @@ impl LiquidSdk {
     /// * `req` - the [PayOnchainRequest] containing:
     ///     * `address` - the Bitcoin address to pay to
     ///     * `prepare_res` - the [PreparePayOnchainResponse] from calling [LiquidSdk::prepare_pay_onchain]
+    ///     * `idempotency_key` - the optional key identifying the payment. If it was already used
+    ///       by an earlier call, the payment started by that call is returned instead of paying again.
     ///
     /// # Errors
     ///
@@ -993,30 +1794,79 @@ impl LiquidSdk {
         req: &PayOnchainRequest,
     ) -> Result<SendPaymentResponse, PaymentError> {
         self.ensure_is_started().await?;
+        self.ensure_is_not_watch_only()?;
+
+        if let Some(idempotency_key) = &req.idempotency_key {
+            if let Some(swap) = self
+                .persister
+                .fetch_chain_swap_by_idempotency_key(idempotency_key)?
+            {
+                info!(
+                    "Found existing Chain Swap {} for idempotency key {idempotency_key}",
+                    swap.id
+                );
+                ensure_sdk!(
+                    swap.claim_address == req.address,
+                    PaymentError::Generic {
+                        err: "The idempotency key was already used for another address".to_string()
+                    }
+                );
+                let payment = match swap.state {
+                    // The swap was created but not yet funded, so resume it
+                    Created => {
+                        self.status_stream.track_swap_id(&swap.id)?;
+                        let accept_zero_conf = swap.accept_zero_conf;
+                        self.wait_for_payment(Swap::Chain(swap), accept_zero_conf)
+                            .await?
+                    }
+                    state => self.get_idempotent_swap_payment(&swap.id, state)?,
+                };
+                return Ok(SendPaymentResponse { payment });
+            }
+        }
 
         let receiver_amount_sat = req.prepare_res.receiver_amount_sat;
         let pair = self.validate_chain_pairs(Direction::Outgoing, receiver_amount_sat)?;
         let claim_fees_sat = req.prepare_res.claim_fees_sat;
         let server_fees_sat = pair.fees.server();
         let server_lockup_amount_sat = receiver_amount_sat + claim_fees_sat;
-        let lockup_fees_sat = self
-            .estimate_lockup_tx_fee(server_lockup_amount_sat)
-            .await?;
 
-        ensure_sdk!(
-            req.prepare_res.total_fees_sat
-                == pair.fees.boltz(server_lockup_amount_sat)
-                    + lockup_fees_sat
-                    + claim_fees_sat
-                    + server_fees_sat,
-            PaymentError::InvalidOrExpiredFees
-        );
+        let (user_lock_amount, server_lock_amount) = match req.prepare_res.drain {
+            true => {
+                // When draining, the user lockup amount is fixed and the swapper derives the
+                // server lockup amount from it
+                let lockup_fees_sat = self.estimate_drain_lockup_tx_fee().await?;
+                let balance_sat = self.onchain_wallet.balance_sat().await?;
+                let user_lockup_amount_sat = balance_sat.saturating_sub(lockup_fees_sat);
+                ensure_sdk!(
+                    req.prepare_res.total_fees_sat + receiver_amount_sat == balance_sat
+                        && Self::get_drain_server_lockup_amount_sat(&pair, user_lockup_amount_sat)
+                            == server_lockup_amount_sat,
+                    PaymentError::InvalidOrExpiredFees
+                );
+                (Some(user_lockup_amount_sat as u32), None)
+            }
+            false => {
+                let lockup_fees_sat = self
+                    .estimate_lockup_tx_fee(server_lockup_amount_sat)
+                    .await?;
+                ensure_sdk!(
+                    req.prepare_res.total_fees_sat
+                        == pair.fees.boltz(server_lockup_amount_sat)
+                            + lockup_fees_sat
+                            + claim_fees_sat
+                            + server_fees_sat,
+                    PaymentError::InvalidOrExpiredFees
+                );
 
-        let payer_amount_sat = req.prepare_res.total_fees_sat + receiver_amount_sat;
-        ensure_sdk!(
-            payer_amount_sat <= self.get_info().await?.balance_sat,
-            PaymentError::InsufficientFunds
-        );
+                let payer_amount_sat = req.prepare_res.total_fees_sat + receiver_amount_sat;
+                ensure_sdk!(
+                    payer_amount_sat <= self.get_info().await?.balance_sat,
+                    PaymentError::InsufficientFunds
+                );
+                (None, Some(server_lockup_amount_sat as u32)) // TODO update our model
+            }
+        };
 
         let preimage = Preimage::new();
         let preimage_str = preimage.to_string().ok_or(PaymentError::InvalidPreimage)?;
@@ -1037,14 +1887,18 @@ impl LiquidSdk {
             preimage_hash: preimage.sha256,
             claim_public_key: Some(claim_public_key),
             refund_public_key: Some(refund_public_key),
-            user_lock_amount: None,
-            server_lock_amount: Some(server_lockup_amount_sat as u32), // TODO update our model
+            user_lock_amount,
+            server_lock_amount,
             pair_hash: Some(pair.hash),
             referral_id: None,
         })?;
 
         let swap_id = &create_response.id;
         let create_response_json = ChainSwap::from_boltz_struct_to_json(&create_response, swap_id)?;
+        ensure_sdk!(
+            create_response.claim_details.amount as u64 >= server_lockup_amount_sat,
+            PaymentError::InvalidOrExpiredFees
+        );
 
         let accept_zero_conf = server_lockup_amount_sat <= pair.limits.maximal_zero_conf;
         let payer_amount_sat = req.prepare_res.total_fees_sat + receiver_amount_sat;
@@ -1071,8 +1925,11 @@ impl LiquidSdk {
             refund_tx_id: None,
             created_at: utils::now(),
             state: PaymentState::Created,
+            idempotency_key: req.idempotency_key.clone(),
+            drain: req.prepare_res.drain,
         };
         self.persister.insert_chain_swap(&swap)?;
+        self.snapshot_fiat_rates(vec![swap.id.clone()]);
         self.status_stream.track_swap_id(&swap.id)?;
 
         self.wait_for_payment(Swap::Chain(swap), accept_zero_conf)
@@ -1146,6 +2003,7 @@ impl LiquidSdk {
         req: &PrepareReceivePaymentRequest,
     ) -> Result<PrepareReceivePaymentResponse, PaymentError> {
         self.ensure_is_started().await?;
+        self.ensure_is_not_watch_only()?;
         let reverse_pair = self
             .swapper
             .get_reverse_swap_pairs()?
@@ -1186,6 +2044,7 @@ impl LiquidSdk {
         req: &ReceivePaymentRequest,
     ) -> Result<ReceivePaymentResponse, PaymentError> {
         self.ensure_is_started().await?;
+        self.ensure_is_not_watch_only()?;
 
         let payer_amount_sat = req.prepare_res.payer_amount_sat;
         let fees_sat = req.prepare_res.fees_sat;
@@ -1292,6 +2151,7 @@ impl LiquidSdk {
                 state: PaymentState::Created,
             })
             .map_err(|_| PaymentError::PersistError)?;
+        self.snapshot_fiat_rates(vec![swap_id.clone()]);
         self.status_stream.track_swap_id(&swap_id)?;
 
         Ok(ReceivePaymentResponse {
@@ -1300,40 +2160,125 @@ impl LiquidSdk {
         })
     }
 
+    /// Generates a Liquid address to receive L-BTC or another Liquid asset directly, without a swap.
+    ///
+    /// The request is stored, so that the incoming tx is matched to it and keeps its description.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [ReceiveLiquidRequest] containing:
+    ///     * `amount_sat` - the optional amount to request, in satoshi or in the asset base units
+    ///     * `description` - the optional description, added to the BIP21 URI as label
+    ///     * `asset_id` - the optional id of the Liquid asset to request, if other than L-BTC
+    pub async fn receive_liquid(
+        &self,
+        req: &ReceiveLiquidRequest,
+    ) -> Result<ReceiveLiquidResponse, PaymentError> {
+        self.ensure_is_started().await?;
+
+        let asset_id = self.resolve_asset_id(None, req.asset_id.as_deref())?;
+        // Every request gets its own address, so that a later request does not replace it
+        let index = self.persister.get_liquid_receive_address_index()?;
+        let (address, index) = self.onchain_wallet.next_unused_address_from(index).await?;
+        self.persister.set_liquid_receive_address_index(index + 1)?;
+        let address = address.to_string();
+        self.persister
+            .insert_or_update_liquid_receive_address(&LiquidReceiveAddress {
+                address: address.clone(),
+                amount_sat: req.amount_sat,
+                description: req.description.clone(),
+                created_at: utils::now(),
+            })?;
+
+        let (amount_sat, asset_id) = match asset_id {
+            Some(asset_id) => (
+                req.amount_sat
+                    .map(|amount| match self.config.get_asset_metadata(&asset_id) {
+                        Some(asset_metadata) => asset_metadata.amount_to_bip21(amount),
+                        None => amount,
+                    }),
+                Some(asset_id),
+            ),
+            // The asset has to be specified whenever an amount is requested
+            None => (
+                req.amount_sat,
+                req.amount_sat.map(|_| {
+                    Into::<ElementsNetwork>::into(self.config.network)
+                        .policy_asset()
+                        .to_string()
+                }),
+            ),
+        };
+        let bip21 = LiquidBip21 {
+            address: address.clone(),
+            amount_sat,
+            asset_id,
+            label: req.description.clone(),
+            message: None,
+        }
+        .to_uri(self.config.network);
+
+        Ok(ReceiveLiquidResponse { address, bip21 })
+    }
+
     /// Prepares to receive from a Bitcoin transaction via a chain swap.
     ///
     /// # Arguments
     ///
     /// * `req` - the [PrepareReceiveOnchainRequest] containing:
-    ///     * `payer_amount_sat` - the amount in satoshi that will be paid by the payer
+    ///     * `payer_amount_sat` - the optional amount in satoshi that will be paid by the payer.
+    ///       If not set, the swap accepts any amount within the limits, see [LiquidSdk::fetch_onchain_limits]
     pub async fn prepare_receive_onchain(
         &self,
         req: &PrepareReceiveOnchainRequest,
     ) -> Result<PrepareReceiveOnchainResponse, PaymentError> {
         self.ensure_is_started().await?;
+        self.ensure_is_not_watch_only()?;
 
-        let payer_amount_sat = req.payer_amount_sat;
-        let pair = self.validate_chain_pairs(Direction::Incoming, payer_amount_sat)?;
-        let claim_fees_sat = pair.fees.claim_estimate();
-        let server_fees_sat = pair.fees.server();
+        let pair = self.get_incoming_chain_pair(req.payer_amount_sat)?;
+        let fixed_fees_sat = pair.fees.claim_estimate() + pair.fees.server();
+        let fees_sat = match req.payer_amount_sat {
+            Some(payer_amount_sat) => pair.fees.boltz(payer_amount_sat) + fixed_fees_sat,
+            None => fixed_fees_sat,
+        };
 
         Ok(PrepareReceiveOnchainResponse {
-            payer_amount_sat,
-            fees_sat: pair.fees.boltz(payer_amount_sat) + claim_fees_sat + server_fees_sat,
+            payer_amount_sat: req.payer_amount_sat,
+            fees_sat,
+            swapper_feerate: pair.fees.percentage,
         })
     }
 
+    /// Gets the incoming chain pair, validating the payer amount against it if one is set
+    fn get_incoming_chain_pair(
+        &self,
+        payer_amount_sat: Option<u64>,
+    ) -> Result<ChainPair, PaymentError> {
+        match payer_amount_sat {
+            Some(payer_amount_sat) => {
+                self.validate_chain_pairs(Direction::Incoming, payer_amount_sat)
+            }
+            None => self
+                .swapper
+                .get_chain_pair(Direction::Incoming)?
+                .ok_or(PaymentError::PairsNotFound),
+        }
+    }
+
     async fn create_chain_swap(
         &self,
-        payer_amount_sat: u64,
+        payer_amount_sat: Option<u64>,
         fees_sat: u64,
     ) -> Result<ChainSwap, PaymentError> {
-        let pair = self.validate_chain_pairs(Direction::Incoming, payer_amount_sat)?;
+        let pair = self.get_incoming_chain_pair(payer_amount_sat)?;
         let claim_fees_sat = pair.fees.claim_estimate();
         let server_fees_sat = pair.fees.server();
+        let boltz_fees_sat = payer_amount_sat
+            .map(|payer_amount_sat| pair.fees.boltz(payer_amount_sat))
+            .unwrap_or_default();
 
         ensure_sdk!(
-            fees_sat == pair.fees.boltz(payer_amount_sat) + claim_fees_sat + server_fees_sat,
+            fees_sat == boltz_fees_sat + claim_fees_sat + server_fees_sat,
             PaymentError::InvalidOrExpiredFees
         );
 
@@ -1356,7 +2301,7 @@ impl LiquidSdk {
             preimage_hash: preimage.sha256,
             claim_public_key: Some(claim_public_key),
             refund_public_key: Some(refund_public_key),
-            user_lock_amount: Some(payer_amount_sat as u32), // TODO update our model
+            user_lock_amount: payer_amount_sat.map(|amount| amount as u32), // TODO update our model
             server_lock_amount: None,
             pair_hash: Some(pair.hash),
             referral_id: None,
@@ -1366,8 +2311,15 @@ impl LiquidSdk {
         let create_response_json =
             ChainSwap::from_boltz_struct_to_json(&create_response, &swap_id)?;
 
-        let accept_zero_conf = payer_amount_sat <= pair.limits.maximal_zero_conf;
-        let receiver_amount_sat = payer_amount_sat - fees_sat;
+        // Amountless swaps only get their amounts once the fees for the paid amount are accepted
+        let (payer_amount_sat, receiver_amount_sat, accept_zero_conf) = match payer_amount_sat {
+            Some(payer_amount_sat) => (
+                payer_amount_sat,
+                payer_amount_sat - fees_sat,
+                payer_amount_sat <= pair.limits.maximal_zero_conf,
+            ),
+            None => (0, 0, false),
+        };
         let claim_address = self.onchain_wallet.next_unused_address().await?.to_string();
 
         let swap = ChainSwap {
@@ -1391,8 +2343,11 @@ impl LiquidSdk {
             refund_tx_id: None,
             created_at: utils::now(),
             state: PaymentState::Created,
+            idempotency_key: None,
+            drain: false,
         };
         self.persister.insert_chain_swap(&swap)?;
+        self.snapshot_fiat_rates(vec![swap.id.clone()]);
         self.status_stream.track_swap_id(&swap.id)?;
         Ok(swap)
     }
@@ -1413,6 +2368,7 @@ impl LiquidSdk {
         req: &PrepareReceiveOnchainResponse,
     ) -> Result<ReceiveOnchainResponse, PaymentError> {
         self.ensure_is_started().await?;
+        self.ensure_is_not_watch_only()?;
 
         let swap = self
             .create_chain_swap(req.payer_amount_sat, req.fees_sat)
@@ -1420,16 +2376,59 @@ impl LiquidSdk {
         let create_response = swap.get_boltz_create_response()?;
         let address = create_response.lockup_details.lockup_address;
 
-        let amount = create_response.lockup_details.amount as f64 / 100_000_000.0;
-        let bip21 = create_response.lockup_details.bip21.unwrap_or(format!(
-            "bitcoin:{address}?amount={amount}&label=Send%20to%20L-BTC%20address"
-        ));
+        let bip21 = create_response.lockup_details.bip21.unwrap_or_else(|| {
+            match create_response.lockup_details.amount {
+                0 => format!("bitcoin:{address}?label=Send%20to%20L-BTC%20address"),
+                amount_sat => {
+                    let amount = amount_sat as f64 / 100_000_000.0;
+                    format!("bitcoin:{address}?amount={amount}&label=Send%20to%20L-BTC%20address")
+                }
+            }
+        });
 
         Ok(ReceiveOnchainResponse { address, bip21 })
     }
 
+    /// Fetches the fees proposed for an incoming chain swap whose payer locked up a different
+    /// amount than agreed. Such swaps are announced with [SdkEvent::SwapWaitingFeeAcceptance].
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [FetchPaymentProposedFeesRequest] containing:
+    ///     * `swap_id` - the id of the swap waiting for fee acceptance
+    pub async fn fetch_payment_proposed_fees(
+        &self,
+        req: &FetchPaymentProposedFeesRequest,
+    ) -> Result<FetchPaymentProposedFeesResponse, PaymentError> {
+        self.chain_swap_state_handler
+            .fetch_payment_proposed_fees(&req.swap_id)
+            .await
+    }
+
+    /// Accepts the fees proposed for an incoming chain swap, so that it completes for the amount
+    /// the payer locked up instead of becoming refundable.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [AcceptPaymentProposedFeesRequest] containing:
+    ///     * `response` - the [FetchPaymentProposedFeesResponse] from calling
+    ///       [LiquidSdk::fetch_payment_proposed_fees]
+    pub async fn accept_payment_proposed_fees(
+        &self,
+        req: &AcceptPaymentProposedFeesRequest,
+    ) -> Result<(), PaymentError> {
+        self.ensure_is_not_watch_only()?;
+
+        self.chain_swap_state_handler
+            .accept_payment_proposed_fees(&req.response)
+            .await
+    }
+
     /// List all failed chain swaps that need to be refunded.
     /// They can be refunded by calling [LiquidSdk::prepare_refund] then [LiquidSdk::refund].
+    ///
+    /// This includes the swaps waiting for fee acceptance, see
+    /// [LiquidSdk::fetch_payment_proposed_fees]. Refunding them declines the proposed fees.
     pub async fn list_refundables(&self) -> SdkResult<Vec<RefundableSwap>> {
         Ok(self
             .persister
@@ -1451,11 +2450,10 @@ impl LiquidSdk {
         &self,
         req: &PrepareRefundRequest,
     ) -> SdkResult<PrepareRefundResponse> {
-        let (tx_vsize, tx_fee_sat, refund_tx_id) = self.chain_swap_state_handler.prepare_refund(
-            &req.swap_address,
-            &req.refund_address,
-            req.sat_per_vbyte,
-        )?;
+        let (tx_vsize, tx_fee_sat, refund_tx_id) = self
+            .chain_swap_state_handler
+            .prepare_refund(&req.swap_address, &req.refund_address, req.sat_per_vbyte)
+            .await?;
         Ok(PrepareRefundResponse {
             tx_vsize,
             tx_fee_sat,
@@ -1463,7 +2461,8 @@ impl LiquidSdk {
         })
     }
 
-    /// Refund a failed chain swap.
+    /// Refund a failed chain swap. To raise the fee of a refund that was already broadcast, use
+    /// [LiquidSdk::bump_refund_fee].
     ///
     /// # Arguments
     ///
@@ -1472,6 +2471,8 @@ impl LiquidSdk {
     ///     * `refund_address` - the Bitcoin address to refund to
     ///     * `sat_per_vbyte` - the fee rate at which to broadcast the refund transaction
     pub async fn refund(&self, req: &RefundRequest) -> Result<RefundResponse, PaymentError> {
+        self.ensure_is_not_watch_only()?;
+
         let refund_tx_id = self
             .chain_swap_state_handler
             .refund_incoming_swap(&req.swap_address, &req.refund_address, req.sat_per_vbyte)
@@ -1479,6 +2480,25 @@ impl LiquidSdk {
         Ok(RefundResponse { refund_tx_id })
     }
 
+    /// Replaces the broadcast refund transaction of a failed chain swap with one paying a higher
+    /// fee rate. The replacement spends the same swap lockup to the same refund address.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [BumpRefundFeeRequest] containing:
+    ///     * `swap_address` - the swap address of the refunded swap
+    ///     * `sat_per_vbyte` - the new fee rate at which to broadcast the refund transaction
+    pub async fn bump_refund_fee(
+        &self,
+        req: &BumpRefundFeeRequest,
+    ) -> Result<BumpRefundFeeResponse, PaymentError> {
+        self.ensure_is_not_watch_only()?;
+
+        self.chain_swap_state_handler
+            .bump_refund_fee(&req.swap_address, req.sat_per_vbyte)
+            .await
+    }
+
     /// Rescans all expired chain swaps created from calling [LiquidSdk::receive_onchain] within
     /// the monitoring period to check if there are any confirmed funds available to refund.
     pub async fn rescan_onchain_swaps(&self) -> SdkResult<()> {
@@ -1499,6 +2519,8 @@ impl LiquidSdk {
         &self,
         req: &PrepareBuyBitcoinRequest,
     ) -> Result<PrepareBuyBitcoinResponse, PaymentError> {
+        self.ensure_is_not_watch_only()?;
+
         if self.config.network != LiquidNetwork::Mainnet {
             return Err(PaymentError::Generic {
                 err: "Can only buy bitcoin on Mainnet".to_string(),
@@ -1507,12 +2529,12 @@ impl LiquidSdk {
 
         let res = self
             .prepare_receive_onchain(&PrepareReceiveOnchainRequest {
-                payer_amount_sat: req.amount_sat,
+                payer_amount_sat: Some(req.amount_sat),
             })
             .await?;
         Ok(PrepareBuyBitcoinResponse {
             provider: req.provider,
-            amount_sat: res.payer_amount_sat,
+            amount_sat: req.amount_sat,
             fees_sat: res.fees_sat,
         })
     }
@@ -1525,8 +2547,11 @@ impl LiquidSdk {
     ///     * `prepare_res` - the [PrepareBuyBitcoinResponse] from calling [LiquidSdk::prepare_buy_bitcoin]
     ///     * `redirect_url` - the optional redirect URL the provider should redirect to after purchase
     pub async fn buy_bitcoin(&self, req: &BuyBitcoinRequest) -> Result<String, PaymentError> {
+        self.ensure_is_not_watch_only()?;
+        self.ensure_breez_server_is_reachable()?;
+
         let swap = self
-            .create_chain_swap(req.prepare_res.amount_sat, req.prepare_res.fees_sat)
+            .create_chain_swap(Some(req.prepare_res.amount_sat), req.prepare_res.fees_sat)
             .await?;
 
         Ok(self
@@ -1535,18 +2560,87 @@ impl LiquidSdk {
             .await?)
     }
 
+    /// Checks for new blocks and changes to the wallet scripts since the last check. Scripts not
+    /// watched yet, e.g. of a newly used address, are watched from now on.
+    async fn poll_chain_changes(&self) -> Result<ChainChanges> {
+        let mut scripts: HashSet<elements::Script> =
+            self.onchain_wallet.scripts().await?.into_iter().collect();
+        // The addresses handed out by `receive_liquid` can be ahead of the next unused address
+        scripts.extend(
+            self.persister
+                .list_liquid_receive_addresses()?
+                .into_iter()
+                .filter_map(|receive_address| {
+                    elements::Address::from_str(&receive_address.address)
+                        .ok()
+                        .map(|address| address.script_pubkey())
+                }),
+        );
+        let scripts: Vec<elements::Script> = scripts.into_iter().collect();
+        self.liquid_chain_service
+            .lock()
+            .await
+            .poll_changes(&scripts)
+            .await
+    }
+
+    /// Syncs if the chain changed for the wallet since the last check. A new block found while
+    /// the wallet has unconfirmed txs requires a full sync, while for wallet scripts with a new
+    /// status only their txs are looked up.
+    async fn sync_on_chain_changes(&self) -> Result<()> {
+        let changes = self.poll_chain_changes().await?;
+        let wallet_txs = self.onchain_wallet.transactions().await?;
+        if changes.needs_full_scan(wallet_txs.iter().any(|tx| tx.height.is_none())) {
+            self.sync().await?;
+        } else if !changes.changed_scripts.is_empty() {
+            let known_tx_ids: HashSet<elements::Txid> =
+                wallet_txs.into_iter().map(|tx| tx.txid).collect();
+            self.sync_changed_scripts(&changes.changed_scripts, &known_tx_ids)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Applies the txs of the changed scripts not yet known to the wallet, then syncs the payments
+    /// without a full scan
+    async fn sync_changed_scripts(
+        &self,
+        scripts: &[elements::Script],
+        known_tx_ids: &HashSet<elements::Txid>,
+    ) -> Result<()> {
+        let mut new_tx_ids = vec![];
+        {
+            let liquid_chain_service = self.liquid_chain_service.lock().await;
+            for script in scripts {
+                for history in liquid_chain_service.get_script_history(script).await? {
+                    if !known_tx_ids.contains(&history.txid) && !new_tx_ids.contains(&history.txid)
+                    {
+                        new_tx_ids.push(history.txid);
+                    }
+                }
+            }
+            if !new_tx_ids.is_empty() {
+                for tx in liquid_chain_service.get_transactions(&new_tx_ids).await? {
+                    self.onchain_wallet.apply_tx(tx).await?;
+                }
+            }
+        }
+        info!(
+            "Applied {} new txs of {} changed scripts",
+            new_tx_ids.len(),
+            scripts.len()
+        );
+
+        self.sync_payments_with_chain_data(false).await?;
+        self.notify_event_listeners(SdkEvent::Synced).await?;
+        Ok(())
+    }
+
     /// This method fetches the chain tx data (onchain and mempool) using LWK. For every wallet tx,
     /// it inserts or updates a corresponding entry in our Payments table.
     async fn sync_payments_with_chain_data(&self, with_scan: bool) -> Result<()> {
-        let payments_before_sync: HashMap<String, Payment> = self
-            .list_payments(&ListPaymentsRequest::default())
-            .await?
-            .into_iter()
-            .filter_map(|payment| {
-                let tx_id = payment.tx_id.clone();
-                tx_id.map(|tx_id| (tx_id, payment))
-            })
-            .collect();
+        // Whether the txs known before this sync were confirmed, by tx id
+        let tx_confirmations_before_sync = self.persister.list_payment_tx_confirmations()?;
         if with_scan {
             self.onchain_wallet.full_scan().await?;
         }
@@ -1557,11 +2651,35 @@ impl LiquidSdk {
             self.persister.list_pending_send_swaps_by_refund_tx_id()?;
         let pending_chain_swaps_by_refund_tx_id =
             self.persister.list_pending_chain_swaps_by_refund_tx_id()?;
+        let liquid_receive_addresses_by_script: HashMap<elements::Script, LiquidReceiveAddress> =
+            self.persister
+                .list_liquid_receive_addresses()?
+                .into_iter()
+                .filter_map(|receive_address| {
+                    elements::Address::from_str(&receive_address.address)
+                        .ok()
+                        .map(|address| (address.script_pubkey(), receive_address))
+                })
+                .collect();
 
+        let policy_asset = ElementsNetwork::from(self.config.network).policy_asset();
+        let mut new_payment_tx_ids = vec![];
         for tx in self.onchain_wallet.transactions().await? {
             let tx_id = tx.txid.to_string();
             let is_tx_confirmed = tx.height.is_some();
-            let amount_sat = tx.balance.values().sum::<i64>();
+            // A tx transferring an asset other than L-BTC is an asset payment, in which case the
+            // L-BTC balance change only reflects the fees
+            let (asset_id, amount_sat) = match tx
+                .balance
+                .iter()
+                .find(|(asset_id, amount)| **asset_id != policy_asset && **amount != 0)
+            {
+                Some((asset_id, amount)) => (Some(asset_id.to_string()), *amount),
+                None => (
+                    None,
+                    tx.balance.get(&policy_asset).copied().unwrap_or_default(),
+                ),
+            };
 
             self.persister.insert_or_update_payment(PaymentTxData {
                 tx_id: tx_id.clone(),
@@ -1573,6 +2691,7 @@ impl LiquidSdk {
                     false => PaymentType::Send,
                 },
                 is_confirmed: is_tx_confirmed,
+                asset_id,
             })?;
 
             if let Some(swap) = pending_receive_swaps_by_claim_tx_id.get(&tx_id) {
@@ -1596,37 +2715,291 @@ impl LiquidSdk {
             } else {
                 // Payments that are not directly associated with a swap (e.g. direct onchain payments using MRH)
 
-                match payments_before_sync.get(&tx_id) {
-                    None => {
-                        // A completely new payment brought in by this sync, in mempool or confirmed
-                        // Covers events:
-                        // - onchain Receive Pending and Complete
-                        // - onchain Send Complete
-                        self.emit_payment_updated(Some(tx_id)).await?;
-                    }
-                    Some(payment_before_sync) => {
-                        if payment_before_sync.status == Pending && is_tx_confirmed {
-                            // A know payment that was in the mempool, but is now confirmed
-                            // Covers events: Send and Receive direct onchain payments transitioning to Complete
-                            self.emit_payment_updated(Some(tx_id)).await?;
-                        }
-                    }
-                }
+                // Incoming txs paying to an address from `receive_liquid` keep the request details
+                if amount_sat >= 0 {
+                    if let Some(receive_address) = tx.outputs.iter().flatten().find_map(|output| {
+                        liquid_receive_addresses_by_script.get(&output.script_pubkey)
+                    }) {
+                        self.persister
+                            .insert_or_update_payment_details(PaymentTxDetails {
+                                tx_id: tx_id.clone(),
+                                destination: Some(receive_address.address.clone()),
+                                description: receive_address.description.clone(),
+                                idempotency_key: None,
+                            })?;
+                    }
+                }
+
+                match tx_confirmations_before_sync.get(&tx_id) {
+                    None => {
+                        // A completely new payment brought in by this sync, in mempool or confirmed
+                        // Covers events:
+                        // - onchain Receive Pending and Complete
+                        // - onchain Send Complete
+                        if tx.timestamp.map_or(true, |timestamp| {
+                            utils::now().saturating_sub(timestamp)
+                                <= FIAT_RATES_SNAPSHOT_MAX_TX_AGE_SEC
+                        }) {
+                            new_payment_tx_ids.push(tx_id.clone());
+                        }
+                        self.emit_payment_updated(Some(tx_id)).await?;
+                    }
+                    Some(was_tx_confirmed) => {
+                        if !was_tx_confirmed && is_tx_confirmed {
+                            // A know payment that was in the mempool, but is now confirmed
+                            // Covers events: Send and Receive direct onchain payments transitioning to Complete
+                            self.emit_payment_updated(Some(tx_id)).await?;
+                        }
+                    }
+                }
+            }
+        }
+        self.snapshot_fiat_rates(new_payment_tx_ids);
+
+        Ok(())
+    }
+
+    /// Records the current exchange rates of the [Config::fiat_currencies] for new payments,
+    /// identified by their swap id or else their tx id. See [Payment::fiat_rates]
+    ///
+    /// Failing to fetch the rates must not fail the payment, so errors are only logged.
+    fn snapshot_fiat_rates(&self, payment_ids: Vec<String>) {
+        let currencies = match &self.config.fiat_currencies {
+            Some(currencies) if !currencies.is_empty() && !payment_ids.is_empty() => {
+                currencies.clone()
+            }
+            _ => return,
+        };
+        if let Err(e) = self.ensure_breez_server_is_reachable() {
+            warn!("Could not fetch fiat rates for payments {payment_ids:?}: {e}");
+            return;
+        }
+        let fiat_api = self.fiat_api.clone();
+        let persister = self.persister.clone();
+        // Fetched in the background, so that payments don't wait for the fiat rates API
+        tokio::spawn(async move {
+            let rates: Vec<PaymentFiatRate> = match fiat_api.fetch_fiat_rates().await {
+                Ok(rates) => rates
+                    .into_iter()
+                    .filter(|rate| {
+                        currencies
+                            .iter()
+                            .any(|currency| currency.eq_ignore_ascii_case(&rate.coin))
+                    })
+                    .map(|rate| PaymentFiatRate {
+                        coin: rate.coin,
+                        value: rate.value,
+                        created_at: utils::now(),
+                    })
+                    .collect(),
+                Err(e) => {
+                    warn!("Could not fetch fiat rates for payments {payment_ids:?}: {e}");
+                    return;
+                }
+            };
+            for payment_id in payment_ids {
+                if let Err(e) = persister.insert_payment_fiat_rates(&payment_id, &rates) {
+                    warn!("Could not record fiat rates for payment {payment_id}: {e}");
+                }
+            }
+        });
+    }
+
+    /// Lists the SDK payments in reverse chronological order, from newest to oldest.
+    /// The payments are determined based on onchain transactions and swaps.
+    pub async fn list_payments(
+        &self,
+        req: &ListPaymentsRequest,
+    ) -> Result<Vec<Payment>, PaymentError> {
+        self.ensure_is_started().await?;
+
+        Ok(self.persister.get_payments(req)?)
+    }
+
+    /// Exports the payments to a CSV or JSON file, e.g. for accounting.
+    ///
+    /// Each payment is exported with its swap and tx ids, its fees split into network and swap
+    /// fees, its status and its description. If a fiat currency is given, the L-BTC amounts and
+    /// the fees are also valued in it, at the rate recorded at the time of the payment (see
+    /// [Payment::fiat_rates]), or else at the current rate from [LiquidSdk::fetch_fiat_rates].
+    /// The `fiat_rate_source` of each payment tells which of the two rates it is valued at.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [ExportPaymentsRequest] containing:
+    ///     * `export_path` - the path of the file to write
+    ///     * `format` - the [ExportFormat] of the file
+    ///     * `fiat_currency` - the optional fiat currency code, e.g. `USD`
+    ///     * `from_timestamp` / `to_timestamp` - the optional time range of the payments
+    pub async fn export_payments(&self, req: &ExportPaymentsRequest) -> Result<(), PaymentError> {
+        self.ensure_is_started().await?;
+
+        let payments = self
+            .list_payments(&ListPaymentsRequest {
+                from_timestamp: req.from_timestamp,
+                to_timestamp: req.to_timestamp,
+                ..Default::default()
+            })
+            .await?;
+        let fiat_currency = req
+            .fiat_currency
+            .as_ref()
+            .map(|currency| currency.to_uppercase());
+        let get_snapshot_rate = |payment: &Payment, currency: &str| {
+            payment
+                .fiat_rates
+                .iter()
+                .find(|rate| rate.coin.eq_ignore_ascii_case(currency))
+                .map(|rate| rate.value)
+        };
+        // The current rate is only needed for the payments without a recorded rate
+        let current_fiat_rate = match &fiat_currency {
+            Some(currency)
+                if payments
+                    .iter()
+                    .any(|payment| get_snapshot_rate(payment, currency).is_none()) =>
+            {
+                let rate = self
+                    .fetch_fiat_rates()
+                    .await?
+                    .into_iter()
+                    .find(|rate| rate.coin.eq_ignore_ascii_case(currency))
+                    .ok_or(PaymentError::Generic {
+                        err: format!("No exchange rate found for {currency}"),
+                    })?;
+                Some(rate.value)
+            }
+            _ => None,
+        };
+
+        let tx_fees = self.persister.list_payment_tx_fees()?;
+        let records: Vec<PaymentExportRecord> = payments
+            .into_iter()
+            .map(|payment| {
+                let tx_fees_sat = payment
+                    .tx_id
+                    .as_ref()
+                    .and_then(|tx_id| tx_fees.get(tx_id))
+                    .copied();
+                let fiat_rate =
+                    fiat_currency.as_deref().and_then(|currency| {
+                        match get_snapshot_rate(&payment, currency) {
+                            Some(rate) => Some((currency, rate, FiatRateSource::Snapshot)),
+                            None => current_fiat_rate
+                                .map(|rate| (currency, rate, FiatRateSource::Current)),
+                        }
+                    });
+                PaymentExportRecord::new(payment, tx_fees_sat, fiat_rate)
+            })
+            .collect();
+        export::write_payments(&records, req.format, &PathBuf::from(&req.export_path))?;
+        info!("Exported {} payments to {}", records.len(), req.export_path);
+        Ok(())
+    }
+
+    /// Lists a page of the SDK payments, from newest to oldest.
+    ///
+    /// Unlike paging with an offset, paging with the returned cursor stays consistent while new
+    /// payments arrive or pending ones confirm, so no payment is listed twice or skipped. For that,
+    /// the payments are ordered by swap creation time, or else by the time their tx was first
+    /// seen, instead of by tx timestamp as in [LiquidSdk::list_payments].
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [ListPaymentsRequest], where:
+    ///     * `limit` - the page size
+    ///     * `cursor` - the [ListPaymentsResponse::next_cursor] of the previous page, or `None`
+    ///       for the first page. An `offset` cannot be set along with it.
+    pub async fn list_payments_page(
+        &self,
+        req: &ListPaymentsRequest,
+    ) -> Result<ListPaymentsResponse, PaymentError> {
+        self.ensure_is_started().await?;
+
+        Ok(self.persister.get_payments_page(req)?)
+    }
+
+    /// Looks up a single payment, including pending swaps which have no tx yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [PaymentLookup] identifying the payment, e.g. by swap id, invoice, payment
+    ///   hash, tx id or destination address
+    ///
+    /// Returns `None` if no payment matches.
+    pub async fn get_payment(&self, req: &PaymentLookup) -> Result<Option<Payment>, PaymentError> {
+        self.ensure_is_started().await?;
+
+        Ok(self.persister.get_payment_by_lookup(req)?)
+    }
+
+    /// Sets a user-defined metadata value on a payment, such as a note, a label or an external
+    /// order id. Any existing value of the same key is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [SetPaymentMetadataRequest] containing:
+    ///     * `payment_id` - the tx id or swap id of the payment
+    ///     * `key` - the metadata key, e.g. `note`
+    ///     * `value` - the metadata value
+    pub async fn set_payment_metadata(
+        &self,
+        req: &SetPaymentMetadataRequest,
+    ) -> Result<(), PaymentError> {
+        self.ensure_is_started().await?;
+        ensure_sdk!(
+            !req.key.is_empty(),
+            PaymentError::Generic {
+                err: "The metadata key cannot be empty".to_string()
             }
-        }
+        );
 
+        let payment_id = self.get_metadata_payment_id(&req.payment_id)?;
+        self.persister
+            .set_payment_metadata(&payment_id, &req.key, &req.value)?;
         Ok(())
     }
 
-    /// Lists the SDK payments in reverse chronological order, from newest to oldest.
-    /// The payments are determined based on onchain transactions and swaps.
-    pub async fn list_payments(
+    /// Returns the user-defined metadata of a payment, given its tx id or swap id
+    pub async fn get_payment_metadata(
         &self,
-        req: &ListPaymentsRequest,
-    ) -> Result<Vec<Payment>, PaymentError> {
+        payment_id: &str,
+    ) -> Result<Vec<PaymentMetadata>, PaymentError> {
         self.ensure_is_started().await?;
 
-        Ok(self.persister.get_payments(req)?)
+        let payment_id = self.get_metadata_payment_id(payment_id)?;
+        Ok(self.persister.list_payment_metadata(&payment_id)?)
+    }
+
+    /// Deletes user-defined metadata from a payment.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [DeletePaymentMetadataRequest] containing:
+    ///     * `payment_id` - the tx id or swap id of the payment
+    ///     * `key` - the optional metadata key to delete. If not set, all metadata is deleted.
+    pub async fn delete_payment_metadata(
+        &self,
+        req: &DeletePaymentMetadataRequest,
+    ) -> Result<(), PaymentError> {
+        self.ensure_is_started().await?;
+
+        let payment_id = self.get_metadata_payment_id(&req.payment_id)?;
+        self.persister
+            .delete_payment_metadata(&payment_id, req.key.as_deref())?;
+        Ok(())
+    }
+
+    /// Resolves the id the metadata of a payment is stored under: its swap id if it has a swap,
+    /// otherwise its tx id. This way the metadata of a swap is kept once its txs are known.
+    fn get_metadata_payment_id(&self, id: &str) -> Result<String, PaymentError> {
+        let payment =
+            self.persister
+                .get_payment_by_tx_or_swap_id(id)?
+                .ok_or(PaymentError::Generic {
+                    err: format!("Payment not found: {id}"),
+                })?;
+        Ok(payment.swap_id.or(payment.tx_id).unwrap_or(id.to_string()))
     }
 
     /// Empties the Liquid Wallet cache for the [Config::network].
@@ -1642,6 +3015,9 @@ impl LiquidSdk {
     }
 
     /// Synchronizes the local state with the mempool and onchain data.
+    ///
+    /// This always does a full scan of the wallet. The background sync started by
+    /// [LiquidSdk::connect] only syncs when the chain changed for the wallet.
     pub async fn sync(&self) -> SdkResult<()> {
         self.ensure_is_started().await?;
 
@@ -1726,6 +3102,9 @@ impl LiquidSdk {
                 let pay_req = self
                     .prepare_send_payment(&PrepareSendRequest {
                         invoice: cb.pr.clone(),
+                        amount_sat: None,
+                        idempotency_key: None,
+                        payer_note: None,
                     })
                     .await?;
 
@@ -1816,30 +3195,57 @@ impl LiquidSdk {
         &self,
         req_data: LnUrlAuthRequestData,
     ) -> Result<LnUrlCallbackStatus, LnUrlAuthError> {
-        // m/138'/0
-        let hashing_key = self.onchain_wallet.derive_bip32_key(vec![
-            ChildNumber::from_hardened_idx(138).map_err(Into::<LnUrlError>::into)?,
-            ChildNumber::from(0),
-        ])?;
-
-        let url =
+        let mut callback_url =
             Url::from_str(&req_data.url).map_err(|e| LnUrlError::InvalidUri(e.to_string()))?;
-
-        let derivation_path = get_derivation_path(hashing_key, url)?;
-        let linking_key = self.onchain_wallet.derive_bip32_key(derivation_path)?;
-        let linking_keys = linking_key.to_keypair(&Secp256k1::new());
-
-        Ok(perform_lnurl_auth(linking_keys, req_data).await?)
+        let domain = callback_url
+            .domain()
+            .ok_or(LnUrlError::InvalidUri(
+                "Could not determine domain".to_string(),
+            ))?
+            .to_string();
+
+        // The linking key is derived at m/138'/<long1>/<long2>/<long3>/<long4>, where the longs
+        // are the first 16 bytes of the HMAC of the domain, keyed with the hashing key at m/138'/0
+        let lnurl_auth_index =
+            ChildNumber::from_hardened_idx(138).map_err(Into::<LnUrlError>::into)?;
+        let hmac = self.onchain_wallet.hmac_sha256(
+            domain.as_bytes(),
+            vec![lnurl_auth_index, ChildNumber::from(0)],
+        )?;
+        let mut derivation_path = vec![lnurl_auth_index];
+        derivation_path.extend(hmac[..16].chunks_exact(4).map(|long| {
+            ChildNumber::from(u32::from_be_bytes([long[0], long[1], long[2], long[3]]))
+        }));
+        let linking_key = self
+            .onchain_wallet
+            .derive_bip32_pub_key(derivation_path.clone())?
+            .public_key;
+
+        let k1 = hex::decode(&req_data.k1)
+            .map_err(|e| LnUrlError::Generic(format!("Error decoding k1: {e}")))?;
+        let k1 = Message::from_slice(&k1)
+            .map_err(|e| LnUrlError::Generic(format!("Invalid k1: {e}")))?;
+        let sig = self.onchain_wallet.sign_ecdsa(&k1, derivation_path)?;
+
+        callback_url
+            .query_pairs_mut()
+            .append_pair("sig", &hex::encode(sig.serialize_der()))
+            .append_pair("key", &hex::encode(linking_key.serialize()));
+        Ok(get_parse_and_log_response(callback_url.as_ref(), false)
+            .await
+            .map_err(|e| LnUrlError::ServiceConnectivity(e.to_string()))?)
     }
 
     /// Fetch live rates of fiat currencies, sorted by name.
     pub async fn fetch_fiat_rates(&self) -> Result<Vec<Rate>, SdkError> {
+        self.ensure_breez_server_is_reachable()?;
         self.fiat_api.fetch_fiat_rates().await.map_err(Into::into)
     }
 
     /// List all supported fiat currencies for which there is a known exchange rate.
     /// List is sorted by the canonical name of the currency.
     pub async fn list_fiat_currencies(&self) -> Result<Vec<FiatCurrency>, SdkError> {
+        self.ensure_breez_server_is_reachable()?;
         self.fiat_api
             .list_fiat_currencies()
             .await
@@ -1861,6 +3267,7 @@ impl LiquidSdk {
         match network {
             LiquidNetwork::Mainnet => Config::mainnet(),
             LiquidNetwork::Testnet => Config::testnet(),
+            LiquidNetwork::Regtest => Config::regtest(),
         }
     }
 
@@ -1876,6 +3283,12 @@ impl LiquidSdk {
         parse_invoice(input).map_err(|e| PaymentError::InvalidInvoice { err: e.to_string() })
     }
 
+    /// Parses a string into an [LNOffer]. Offers are not recognized by [LiquidSdk::parse], but
+    /// can be paid with [LiquidSdk::prepare_send_payment] like invoices.
+    pub fn parse_offer(input: &str) -> Result<LNOffer, PaymentError> {
+        Ok((&bolt12::parse_offer(input)?).into())
+    }
+
     /// Configures a global SDK logger that will log to file and will forward log events to
     /// an optional application-specific logger.
     ///
@@ -1932,25 +3345,42 @@ impl ReconnectHandler for SwapperReconnectHandler {
 
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
+    use std::{
+        str::FromStr,
+        sync::{atomic::Ordering, Arc},
+    };
 
     use anyhow::{anyhow, Result};
     use boltz_client::{
+        bitcoin,
         boltz::{self, SwapUpdateTxDetails},
         swaps::boltz::{ChainSwapStates, RevSwapStates, SubSwapStates},
     };
-    use lwk_wollet::hashes::hex::DisplayHex;
+    use lwk_wollet::{hashes::hex::DisplayHex, History};
+    use tokio::sync::Mutex;
 
     use crate::{
-        model::{Direction, PaymentState, Swap},
+        chain::liquid::ChainChanges,
+        error::PaymentError,
+        model::{
+            AcceptPaymentProposedFeesRequest, BumpRefundFeeRequest, BuyBitcoinProvider, Direction,
+            FetchPaymentProposedFeesRequest, FetchPaymentProposedFeesResponse, PaymentState,
+            PaymentType, PrepareBuyBitcoinRequest, PreparePayOnchainRequest,
+            PrepareReceiveOnchainRequest, PrepareReceivePaymentRequest, PrepareSendLiquidRequest,
+            PrepareSendRequest, RefundRequest, Swap,
+        },
         sdk::LiquidSdk,
+        swapper::Swapper,
         test_utils::{
+            chain::{MockBitcoinChainService, MockLiquidChainService},
             chain_swap::{new_chain_swap, TEST_BITCOIN_TX},
-            persist::{new_persister, new_receive_swap, new_send_swap},
-            sdk::new_liquid_sdk,
+            persist::{
+                new_invoice, new_payment_tx_data, new_persister, new_receive_swap, new_send_swap,
+            },
+            sdk::{new_liquid_sdk, new_liquid_sdk_with_chain_services, new_liquid_sdk_with_wallet},
             status_stream::MockStatusStream,
-            swapper::MockSwapper,
-            wallet::TEST_LIQUID_TX,
+            swapper::{MockSwapper, TEST_BOLT12_INVOICE, TEST_BOLT12_OFFER},
+            wallet::{MockWallet, TEST_LIQUID_TX},
         },
     };
     use paste::paste;
@@ -2361,4 +3791,636 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_get_receiver_amount_sat() -> Result<()> {
+        // Invoices with an amount accept no amount or a matching one
+        let invoice = new_invoice(Some(50_000_000));
+        assert_eq!(LiquidSdk::get_receiver_amount_sat(&invoice, None)?, 50_000);
+        assert_eq!(
+            LiquidSdk::get_receiver_amount_sat(&invoice, Some(50_000))?,
+            50_000
+        );
+        assert!(matches!(
+            LiquidSdk::get_receiver_amount_sat(&invoice, Some(10_000)),
+            Err(PaymentError::InvalidInvoice { .. })
+        ));
+
+        // Amountless invoices require a non-zero amount
+        let invoice = new_invoice(None);
+        assert_eq!(
+            LiquidSdk::get_receiver_amount_sat(&invoice, Some(10_000))?,
+            10_000
+        );
+        assert!(matches!(
+            LiquidSdk::get_receiver_amount_sat(&invoice, Some(0)),
+            Err(PaymentError::AmountOutOfRange)
+        ));
+        assert!(matches!(
+            LiquidSdk::get_receiver_amount_sat(&invoice, None),
+            Err(PaymentError::AmountMissing { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_drain_server_lockup_amount_sat() -> Result<()> {
+        let pair = MockSwapper::new()
+            .get_chain_pair(Direction::Outgoing)?
+            .ok_or(anyhow!("Expecting chain pair"))?;
+        let server_fees_sat = pair.fees.server();
+
+        for user_lockup_amount_sat in [1_000, 25_000, 100_000, 123_456_789] {
+            let server_lockup_amount_sat =
+                LiquidSdk::get_drain_server_lockup_amount_sat(&pair, user_lockup_amount_sat);
+
+            // The user lockup amount covers the server lockup amount and fees...
+            assert!(
+                server_lockup_amount_sat
+                    + pair.fees.boltz(server_lockup_amount_sat)
+                    + server_fees_sat
+                    <= user_lockup_amount_sat
+            );
+            // ...but not a single sat more
+            assert!(
+                server_lockup_amount_sat
+                    + 1
+                    + pair.fees.boltz(server_lockup_amount_sat + 1)
+                    + server_fees_sat
+                    > user_lockup_amount_sat
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_info_balance_details() -> Result<()> {
+        let (_tmp_dir, persister) = new_persister()?;
+        let persister = Arc::new(persister);
+        // The BTC locked up in every incoming chain swap, regardless of its payer amount
+        let mut bitcoin_chain_service = MockBitcoinChainService::new();
+        bitcoin_chain_service.set_script_balance(25_000, 0);
+        let sdk = new_liquid_sdk_with_chain_services(
+            persister.clone(),
+            Arc::new(MockSwapper::default()),
+            Arc::new(MockStatusStream::new()),
+            Arc::new(Mutex::new(MockLiquidChainService::new())),
+            Arc::new(Mutex::new(bitcoin_chain_service)),
+            Arc::new(MockWallet::new()),
+        )?;
+
+        let insert_tx = |payment_type, amount_sat, is_confirmed| -> Result<String> {
+            let mut payment_tx_data = new_payment_tx_data(payment_type);
+            payment_tx_data.amount_sat = amount_sat;
+            payment_tx_data.is_confirmed = is_confirmed;
+            persister.insert_or_update_payment(payment_tx_data.clone())?;
+            Ok(payment_tx_data.tx_id)
+        };
+
+        // Confirmed direct Liquid payments
+        insert_tx(PaymentType::Receive, 100_000, true)?;
+        insert_tx(PaymentType::Send, 10_000, true)?;
+
+        // Unconfirmed direct Liquid payments
+        insert_tx(PaymentType::Send, 5_000, false)?;
+        insert_tx(PaymentType::Receive, 2_000, false)?;
+
+        // A pending Send swap, with its lockup tx
+        let mut send_swap = new_send_swap(Some(PaymentState::Pending));
+        send_swap.lockup_tx_id = Some(insert_tx(PaymentType::Send, 1_149, false)?);
+        persister.insert_send_swap(&send_swap)?;
+
+        // A pending Receive swap, with its claim tx not yet known to the wallet
+        let mut receive_swap = new_receive_swap(Some(PaymentState::Pending));
+        receive_swap.claim_tx_id = Some("claim_tx_id".to_string());
+        persister.insert_receive_swap(&receive_swap)?;
+
+        // A Send swap being refunded, with its confirmed lockup tx
+        let mut refund_pending_send_swap = new_send_swap(Some(PaymentState::RefundPending));
+        refund_pending_send_swap.lockup_tx_id = Some(insert_tx(PaymentType::Send, 1_149, true)?);
+        persister.insert_send_swap(&refund_pending_send_swap)?;
+
+        // Incoming chain swaps, which are not listed as payments before being claimed
+        for state in [
+            PaymentState::Pending,
+            PaymentState::Refundable,
+            PaymentState::RefundPending,
+        ] {
+            persister.insert_chain_swap(&new_chain_swap(
+                Direction::Incoming,
+                Some(state),
+                false,
+                None,
+            ))?;
+        }
+
+        let info = sdk.get_info().await?;
+        let balance_details = info.balance_details;
+        assert_eq!(balance_details.confirmed_sat, 90_000);
+        assert_eq!(balance_details.unconfirmed_send_sat, 5_000);
+        assert_eq!(balance_details.unconfirmed_receive_sat, 2_000);
+        assert_eq!(balance_details.pending_send_swaps_sat, 1_149);
+        assert_eq!(balance_details.pending_receive_swaps_sat, 587 + 17_592);
+        assert_eq!(balance_details.pending_refund_sat, 1_149);
+        assert_eq!(balance_details.refundable_btc_sat, 25_000);
+        assert_eq!(balance_details.pending_btc_refund_sat, 25_000);
+
+        // The L-BTC buckets add up to the balance and to the pending amounts
+        assert_eq!(
+            info.balance_sat,
+            balance_details.confirmed_sat
+                - balance_details.unconfirmed_send_sat
+                - balance_details.pending_send_swaps_sat
+        );
+        assert_eq!(
+            info.pending_send_sat,
+            balance_details.unconfirmed_send_sat + balance_details.pending_send_swaps_sat
+        );
+        assert_eq!(info.pending_receive_sat, 2_000 + 587);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sync_on_chain_changes() -> Result<()> {
+        let (_tmp_dir, persister) = new_persister()?;
+        let liquid_chain_service = Arc::new(Mutex::new(MockLiquidChainService::new()));
+        let onchain_wallet = Arc::new(MockWallet::new());
+        let sdk = new_liquid_sdk_with_chain_services(
+            Arc::new(persister),
+            Arc::new(MockSwapper::default()),
+            Arc::new(MockStatusStream::new()),
+            liquid_chain_service.clone(),
+            Arc::new(Mutex::new(MockBitcoinChainService::new())),
+            onchain_wallet.clone(),
+        )?;
+        let full_scans = || onchain_wallet.full_scans.load(Ordering::SeqCst);
+
+        // Without changes nothing is synced, nor for a new block if no wallet tx is unconfirmed
+        sdk.sync_on_chain_changes().await?;
+        liquid_chain_service
+            .lock()
+            .await
+            .set_chain_changes(ChainChanges {
+                new_block: true,
+                ..Default::default()
+            });
+        sdk.sync_on_chain_changes().await?;
+        assert_eq!(full_scans(), 0);
+
+        // For changed scripts, only their txs new to the wallet are applied, without a full scan
+        let tx = TEST_LIQUID_TX.clone();
+        liquid_chain_service
+            .lock()
+            .await
+            .set_chain_changes(ChainChanges {
+                changed_scripts: tx
+                    .output
+                    .iter()
+                    .map(|output| output.script_pubkey.clone())
+                    .collect(),
+                ..Default::default()
+            })
+            .set_history(vec![History {
+                txid: tx.txid(),
+                height: 0,
+                block_hash: None,
+                block_timestamp: None,
+            }])
+            .set_transactions(vec![tx.clone()]);
+        sdk.sync_on_chain_changes().await?;
+        assert_eq!(
+            *onchain_wallet.applied_tx_ids.lock().unwrap(),
+            vec![tx.txid()]
+        );
+        assert_eq!(full_scans(), 0);
+
+        // Changes the chain service cannot narrow down to scripts require a full scan
+        liquid_chain_service
+            .lock()
+            .await
+            .set_chain_changes(ChainChanges {
+                new_block: true,
+                is_unknown: true,
+                ..Default::default()
+            });
+        sdk.sync_on_chain_changes().await?;
+        assert_eq!(full_scans(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_bump_refund_fee() -> Result<()> {
+        let (_tmp_dir, persister) = new_persister()?;
+        let persister = Arc::new(persister);
+        let bitcoin_chain_service = Arc::new(Mutex::new(MockBitcoinChainService::new()));
+        let sdk = new_liquid_sdk_with_chain_services(
+            persister.clone(),
+            Arc::new(MockSwapper::default()),
+            Arc::new(MockStatusStream::new()),
+            Arc::new(Mutex::new(MockLiquidChainService::new())),
+            bitcoin_chain_service.clone(),
+            Arc::new(MockWallet::new()),
+        )?;
+
+        // An incoming swap refunded cooperatively, i.e. via the key path, paying 200 sat
+        let mut swap = new_chain_swap(
+            Direction::Incoming,
+            Some(PaymentState::RefundPending),
+            false,
+            None,
+        );
+        let lockup_script_pubkey = swap
+            .get_lockup_swap_script()?
+            .as_bitcoin_script()?
+            .to_address(sdk.config.network.as_bitcoin_chain())
+            .map_err(|e| anyhow!("Error getting script address: {e:?}"))?
+            .script_pubkey();
+        let lockup_tx = bitcoin::Transaction {
+            version: bitcoin::transaction::Version::TWO,
+            lock_time: bitcoin::absolute::LockTime::ZERO,
+            input: vec![],
+            output: vec![bitcoin::TxOut {
+                value: bitcoin::Amount::from_sat(swap.payer_amount_sat),
+                script_pubkey: lockup_script_pubkey,
+            }],
+        };
+        let refund_address =
+            bitcoin::Address::from_str("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx")?
+                .assume_checked();
+        let refund_tx = bitcoin::Transaction {
+            version: bitcoin::transaction::Version::TWO,
+            lock_time: bitcoin::absolute::LockTime::ZERO,
+            input: vec![bitcoin::TxIn {
+                previous_output: bitcoin::OutPoint::new(lockup_tx.txid(), 0),
+                script_sig: bitcoin::ScriptBuf::new(),
+                sequence: bitcoin::Sequence::ENABLE_RBF_NO_LOCKTIME,
+                witness: bitcoin::Witness::from_slice(&[[1; 64]]),
+            }],
+            output: vec![bitcoin::TxOut {
+                value: bitcoin::Amount::from_sat(swap.payer_amount_sat - 200),
+                script_pubkey: refund_address.script_pubkey(),
+            }],
+        };
+        let replaced_tx_id = refund_tx.txid().to_string();
+        swap.refund_tx_id = Some(replaced_tx_id.clone());
+        persister.insert_chain_swap(&swap)?;
+        bitcoin_chain_service
+            .lock()
+            .await
+            .set_transactions(vec![lockup_tx, refund_tx.clone()]);
+
+        // Neither path pays the replaced fee plus 1 sat/vbyte at a fee rate of 2 sat/vbyte
+        let mut req = BumpRefundFeeRequest {
+            swap_address: swap.lockup_address.clone(),
+            sat_per_vbyte: 2,
+        };
+        assert!(matches!(
+            sdk.bump_refund_fee(&req).await,
+            Err(PaymentError::Generic { .. })
+        ));
+        let fetch_refund_tx_id = || -> Result<Option<String>> {
+            Ok(persister
+                .fetch_chain_swap_by_id(&swap.id)?
+                .ok_or(anyhow!("Expecting chain swap"))?
+                .refund_tx_id)
+        };
+        assert_eq!(fetch_refund_tx_id()?, Some(replaced_tx_id.clone()));
+
+        // The cooperative replacement has the size of the replaced tx
+        req.sat_per_vbyte = 4;
+        let res = sdk.bump_refund_fee(&req).await?;
+        let tx_fee_sat = refund_tx.vsize() as u64 * 4;
+        assert_eq!(res.tx_fee_sat, tx_fee_sat);
+        assert_eq!(res.fee_delta_sat, tx_fee_sat - 200);
+        assert_eq!(res.replaced_tx_id, replaced_tx_id);
+        assert_eq!(fetch_refund_tx_id()?, Some(res.refund_tx_id.clone()));
+
+        // The replacement is broadcast through the chain service, spending the same lockup
+        let replacement_tx = bitcoin_chain_service
+            .lock()
+            .await
+            .broadcast_txs
+            .lock()
+            .unwrap()
+            .pop()
+            .ok_or(anyhow!("Expecting a broadcast replacement tx"))?;
+        assert_eq!(res.refund_tx_id, replacement_tx.txid().to_string());
+        assert_eq!(
+            replacement_tx.input[0].previous_output,
+            refund_tx.input[0].previous_output
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_payment_proposed_fees() -> Result<()> {
+        let (_tmp_dir, persister) = new_persister()?;
+        let persister = Arc::new(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let bitcoin_chain_service = Arc::new(Mutex::new(MockBitcoinChainService::new()));
+        let sdk = new_liquid_sdk_with_chain_services(
+            persister.clone(),
+            swapper.clone(),
+            Arc::new(MockStatusStream::new()),
+            Arc::new(Mutex::new(MockLiquidChainService::new())),
+            bitcoin_chain_service.clone(),
+            Arc::new(MockWallet::new()),
+        )?;
+
+        // The payer locked up 20,500 sats, for which the swapper quotes 20,144 sats
+        let swap = new_chain_swap(
+            Direction::Incoming,
+            Some(PaymentState::WaitingFeeAcceptance),
+            false,
+            Some("user-lockup-tx-id".to_string()),
+        );
+        persister.insert_chain_swap(&swap)?;
+        bitcoin_chain_service
+            .lock()
+            .await
+            .set_script_balance(20_000, 500);
+        swapper.set_chain_swap_quote(20_144);
+
+        // Only swaps waiting for fee acceptance have proposed fees
+        let pending_swap = new_chain_swap(
+            Direction::Incoming,
+            Some(PaymentState::Pending),
+            false,
+            None,
+        );
+        persister.insert_chain_swap(&pending_swap)?;
+        assert!(sdk
+            .fetch_payment_proposed_fees(&FetchPaymentProposedFeesRequest {
+                swap_id: pending_swap.id.clone(),
+            })
+            .await
+            .is_err());
+
+        let proposed_fees = sdk
+            .fetch_payment_proposed_fees(&FetchPaymentProposedFeesRequest {
+                swap_id: swap.id.clone(),
+            })
+            .await?;
+        assert_eq!(
+            proposed_fees,
+            FetchPaymentProposedFeesResponse {
+                swap_id: swap.id.clone(),
+                fees_sat: 500,
+                payer_amount_sat: 20_500,
+                receiver_amount_sat: 20_144 - swap.claim_fees_sat,
+            }
+        );
+
+        // Until accepted, the swap can be refunded instead
+        let refundables = sdk.list_refundables().await?;
+        assert_eq!(refundables.len(), 1);
+        assert_eq!(refundables[0].swap_address, swap.lockup_address);
+
+        // Fees that no longer match the quote are rejected
+        swapper.set_chain_swap_quote(20_044);
+        assert!(matches!(
+            sdk.accept_payment_proposed_fees(&AcceptPaymentProposedFeesRequest {
+                response: proposed_fees.clone(),
+            })
+            .await,
+            Err(PaymentError::InvalidOrExpiredFees)
+        ));
+        assert!(swapper
+            .accepted_chain_swap_quotes
+            .lock()
+            .unwrap()
+            .is_empty());
+
+        swapper.set_chain_swap_quote(20_144);
+        sdk.accept_payment_proposed_fees(&AcceptPaymentProposedFeesRequest {
+            response: proposed_fees.clone(),
+        })
+        .await?;
+        assert_eq!(
+            *swapper.accepted_chain_swap_quotes.lock().unwrap(),
+            vec![(swap.id.clone(), 20_144)]
+        );
+        let swap = persister.fetch_chain_swap_by_id(&swap.id)?.unwrap();
+        assert_eq!(swap.state, PaymentState::Pending);
+        assert_eq!(swap.payer_amount_sat, proposed_fees.payer_amount_sat);
+        assert_eq!(swap.receiver_amount_sat, proposed_fees.receiver_amount_sat);
+        assert!(sdk.list_refundables().await?.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_refund_waiting_fee_acceptance_swap() -> Result<()> {
+        let (_tmp_dir, persister) = new_persister()?;
+        let persister = Arc::new(persister);
+        let bitcoin_chain_service = Arc::new(Mutex::new(MockBitcoinChainService::new()));
+        let sdk = new_liquid_sdk_with_chain_services(
+            persister.clone(),
+            Arc::new(MockSwapper::default()),
+            Arc::new(MockStatusStream::new()),
+            Arc::new(Mutex::new(MockLiquidChainService::new())),
+            bitcoin_chain_service.clone(),
+            Arc::new(MockWallet::new()),
+        )?;
+
+        let swap = new_chain_swap(
+            Direction::Incoming,
+            Some(PaymentState::WaitingFeeAcceptance),
+            false,
+            Some("user-lockup-tx-id".to_string()),
+        );
+        persister.insert_chain_swap(&swap)?;
+
+        // Refunding declines the proposed fees
+        let res = sdk
+            .refund(&RefundRequest {
+                swap_address: swap.lockup_address.clone(),
+                refund_address: "tb1qcr8te4kr609gcawutmrza0j4xv80jy8zmfp6l0".to_string(),
+                sat_per_vbyte: 4,
+            })
+            .await?;
+        let refund_tx = bitcoin_chain_service
+            .lock()
+            .await
+            .broadcast_txs
+            .lock()
+            .unwrap()
+            .pop()
+            .ok_or(anyhow!("Expecting a broadcast refund tx"))?;
+        assert_eq!(res.refund_tx_id, refund_tx.txid().to_string());
+        let swap = persister.fetch_chain_swap_by_id(&swap.id)?.unwrap();
+        assert_eq!(swap.state, PaymentState::RefundPending);
+        assert_eq!(swap.refund_tx_id, Some(res.refund_tx_id));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_amountless_receive_onchain() -> Result<()> {
+        let (_tmp_dir, persister) = new_persister()?;
+        let persister = Arc::new(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let sdk = new_liquid_sdk(
+            persister.clone(),
+            swapper.clone(),
+            Arc::new(MockStatusStream::new()),
+        )?;
+        let pair = swapper.get_chain_pair(Direction::Incoming)?.unwrap();
+        let fixed_fees_sat = pair.fees.claim_estimate() + pair.fees.server();
+
+        // Without an amount, only the fixed fees are known upfront
+        let prepare_response = sdk
+            .prepare_receive_onchain(&PrepareReceiveOnchainRequest {
+                payer_amount_sat: None,
+            })
+            .await?;
+        assert_eq!(prepare_response.payer_amount_sat, None);
+        assert_eq!(prepare_response.fees_sat, fixed_fees_sat);
+        assert_eq!(prepare_response.swapper_feerate, pair.fees.percentage);
+
+        let prepare_response_with_amount = sdk
+            .prepare_receive_onchain(&PrepareReceiveOnchainRequest {
+                payer_amount_sat: Some(50_000),
+            })
+            .await?;
+        assert_eq!(
+            prepare_response_with_amount.fees_sat,
+            pair.fees.boltz(50_000) + fixed_fees_sat
+        );
+
+        // The amountless swap is created without amounts, which are set once the fees for the
+        // paid amount are accepted
+        assert!(matches!(
+            sdk.create_chain_swap(None, fixed_fees_sat + 1).await,
+            Err(PaymentError::InvalidOrExpiredFees)
+        ));
+        let swap = sdk
+            .create_chain_swap(prepare_response.payer_amount_sat, prepare_response.fees_sat)
+            .await?;
+        assert_eq!(swap.payer_amount_sat, 0);
+        assert_eq!(swap.receiver_amount_sat, 0);
+        assert_eq!(swap.claim_fees_sat, pair.fees.claim_estimate());
+        assert!(!swap.accept_zero_conf);
+        assert_eq!(swap.state, PaymentState::Created);
+        assert_eq!(swap.get_boltz_create_response()?.lockup_details.amount, 0);
+        let persisted_swap = persister.fetch_chain_swap_by_id(&swap.id)?.unwrap();
+        assert_eq!(persisted_swap.payer_amount_sat, 0);
+        assert_eq!(persisted_swap.state, PaymentState::Created);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_watch_only_rejects_signing_calls() -> Result<()> {
+        let (_tmp_dir, persister) = new_persister()?;
+        let sdk = new_liquid_sdk_with_wallet(
+            Arc::new(persister),
+            Arc::new(MockSwapper::default()),
+            Arc::new(MockStatusStream::new()),
+            Arc::new(MockWallet::new_watch_only()),
+        )?;
+
+        macro_rules! assert_watch_only {
+            ($res:expr) => {
+                assert!(matches!($res, Err(PaymentError::WatchOnly)))
+            };
+        }
+
+        assert_watch_only!(
+            sdk.prepare_send_payment(&PrepareSendRequest {
+                invoice: new_invoice(Some(50_000_000)).to_string(),
+                amount_sat: None,
+                idempotency_key: None,
+                payer_note: None,
+            })
+            .await
+        );
+        assert_watch_only!(
+            sdk.prepare_send_liquid(&PrepareSendLiquidRequest {
+                destination: "destination".to_string(),
+                amount_sat: Some(1_000),
+                drain: None,
+                asset_id: None,
+            })
+            .await
+        );
+        assert_watch_only!(
+            sdk.prepare_pay_onchain(&PreparePayOnchainRequest {
+                receiver_amount_sat: 50_000,
+                sat_per_vbyte: None,
+                drain: None,
+            })
+            .await
+        );
+        assert_watch_only!(
+            sdk.prepare_receive_payment(&PrepareReceivePaymentRequest {
+                payer_amount_sat: 50_000,
+            })
+            .await
+        );
+        assert_watch_only!(
+            sdk.prepare_receive_onchain(&PrepareReceiveOnchainRequest {
+                payer_amount_sat: Some(50_000),
+            })
+            .await
+        );
+        assert_watch_only!(
+            sdk.prepare_buy_bitcoin(&PrepareBuyBitcoinRequest {
+                provider: BuyBitcoinProvider::Moonpay,
+                amount_sat: 50_000,
+            })
+            .await
+        );
+        assert_watch_only!(
+            sdk.refund(&RefundRequest {
+                swap_address: "swap_address".to_string(),
+                refund_address: "refund_address".to_string(),
+                sat_per_vbyte: 1,
+            })
+            .await
+        );
+        assert_watch_only!(
+            sdk.bump_refund_fee(&BumpRefundFeeRequest {
+                swap_address: "swap_address".to_string(),
+                sat_per_vbyte: 2,
+            })
+            .await
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fetch_bolt12_invoice() -> Result<()> {
+        let (_tmp_dir, persister) = new_persister()?;
+        let sdk = new_liquid_sdk(
+            Arc::new(persister),
+            Arc::new(MockSwapper::default()),
+            Arc::new(MockStatusStream::new()),
+        )?;
+
+        let mut req = PrepareSendRequest {
+            invoice: TEST_BOLT12_OFFER.to_string(),
+            amount_sat: None,
+            idempotency_key: None,
+            payer_note: Some("Thanks".to_string()),
+        };
+        let (invoice, receiver_amount_sat) = sdk.fetch_bolt12_invoice(&req)?;
+        assert_eq!(invoice, TEST_BOLT12_INVOICE);
+        assert_eq!(receiver_amount_sat, 50_000);
+
+        // The invoice has to include the requested payer note
+        req.payer_note = Some("Other note".to_string());
+        assert!(matches!(
+            sdk.fetch_bolt12_invoice(&req),
+            Err(PaymentError::InvalidInvoice { .. })
+        ));
+
+        Ok(())
+    }
 }
@@ -5,18 +5,19 @@ use anyhow::{anyhow, Result};
 use boltz_client::swaps::boltz;
 use boltz_client::swaps::{boltz::CreateSubmarineResponse, boltz::SubSwapStates};
 use boltz_client::util::secrets::Preimage;
-use boltz_client::{Bolt11Invoice, ToHex};
+use boltz_client::ToHex;
 use log::{debug, error, info, warn};
 use lwk_wollet::bitcoin::Witness;
 use lwk_wollet::elements::Transaction;
 use lwk_wollet::hashes::{sha256, Hash};
 use tokio::sync::{broadcast, Mutex};
 
+use crate::bolt12;
 use crate::chain::liquid::LiquidChainService;
 use crate::model::PaymentState::{
-    Complete, Created, Failed, Pending, RefundPending, Refundable, TimedOut,
+    Complete, Created, Failed, Pending, RefundPending, Refundable, TimedOut, WaitingFeeAcceptance,
 };
-use crate::model::{Config, SendSwap};
+use crate::model::{Config, SendSwap, Utxo};
 use crate::swapper::Swapper;
 use crate::wallet::OnchainWallet;
 use crate::{ensure_sdk, utils};
@@ -93,6 +94,7 @@ impl SendSwapStateHandler {
                             fees_sat: lockup_tx_fees_sat,
                             payment_type: PaymentType::Send,
                             is_confirmed: false,
+                            asset_id: None,
                         })?;
 
                         self.update_swap_info(id, Pending, None, Some(&lockup_tx_id), None)
@@ -297,11 +299,32 @@ impl SendSwapStateHandler {
             None,
         )
         .await?;
-        self.swapper
-            .claim_send_swap_cooperative(send_swap, claim_tx_details, &output_address)?;
+        let lockup_utxo = self.fetch_lockup_utxo(send_swap).await?;
+        self.swapper.claim_send_swap_cooperative(
+            send_swap,
+            claim_tx_details,
+            &output_address,
+            lockup_utxo,
+        )?;
         Ok(())
     }
 
+    async fn fetch_lockup_utxo(&self, swap: &SendSwap) -> Result<Utxo, PaymentError> {
+        let script_pubkey = swap
+            .get_swap_script()?
+            .to_address(self.config.network.into())
+            .map_err(|e| anyhow!("Error getting script address: {e:?}"))?
+            .to_unconfidential()
+            .script_pubkey();
+        let lockup_utxo = self
+            .chain_service
+            .lock()
+            .await
+            .get_script_utxo(&script_pubkey)
+            .await?;
+        Ok(Utxo::Liquid(Box::new(lockup_utxo)))
+    }
+
     async fn get_preimage_from_script_path_claim_spend(
         &self,
         swap: &SendSwap,
@@ -385,22 +408,48 @@ impl SendSwapStateHandler {
 
     pub(crate) async fn refund(&self, swap: &SendSwap) -> Result<String, PaymentError> {
         let output_address = self.onchain_wallet.next_unused_address().await?.to_string();
-
-        let cooperative_refund_tx_fees_sat =
-            utils::estimate_refund_fees(swap, &self.config, &output_address, true)?;
-        let refund_res = self.swapper.refund_send_swap_cooperative(
-            swap,
-            &output_address,
-            cooperative_refund_tx_fees_sat,
-        );
+        let lockup_utxo = self.fetch_lockup_utxo(swap).await?;
+
+        let cooperative_refund_tx =
+            utils::estimate_refund_fees(swap, &self.config, &output_address, &lockup_utxo, true)
+                .and_then(|cooperative_refund_tx_fees_sat| {
+                    self.swapper.create_send_swap_refund_cooperative(
+                        swap,
+                        lockup_utxo.clone(),
+                        &output_address,
+                        cooperative_refund_tx_fees_sat,
+                    )
+                });
+        let refund_res = match cooperative_refund_tx {
+            Ok(refund_tx) => self
+                .chain_service
+                .lock()
+                .await
+                .broadcast(&refund_tx, Some(&swap.id))
+                .await
+                .map(|refund_tx_id| refund_tx_id.to_string())
+                .map_err(Into::into),
+            Err(e) => Err(e),
+        };
 
         match refund_res {
-            Ok(res) => Ok(res),
+            Ok(refund_tx_id) => {
+                info!(
+                    "Successfully broadcast cooperative refund for Send Swap {}, tx: {}",
+                    swap.id, refund_tx_id
+                );
+                Ok(refund_tx_id)
+            }
             Err(e) => {
                 warn!("Cooperative refund failed: {:?}", e);
-                let non_cooperative_refund_tx_fees_sat =
-                    utils::estimate_refund_fees(swap, &self.config, &output_address, false)?;
-                self.refund_non_cooperative(swap, non_cooperative_refund_tx_fees_sat)
+                let non_cooperative_refund_tx_fees_sat = utils::estimate_refund_fees(
+                    swap,
+                    &self.config,
+                    &output_address,
+                    &lockup_utxo,
+                    false,
+                )?;
+                self.refund_non_cooperative(swap, lockup_utxo, non_cooperative_refund_tx_fees_sat)
                     .await
             }
         }
@@ -409,6 +458,7 @@ impl SendSwapStateHandler {
     async fn refund_non_cooperative(
         &self,
         swap: &SendSwap,
+        lockup_utxo: Utxo,
         broadcast_fees_sat: u64,
     ) -> Result<String, PaymentError> {
         info!(
@@ -418,12 +468,20 @@ impl SendSwapStateHandler {
 
         let current_height = self.onchain_wallet.tip().await.height();
         let output_address = self.onchain_wallet.next_unused_address().await?.to_string();
-        let refund_tx_id = self.swapper.refund_send_swap_non_cooperative(
+        let refund_tx = self.swapper.create_send_swap_refund_non_cooperative(
             swap,
+            lockup_utxo,
             broadcast_fees_sat,
             &output_address,
             current_height,
         )?;
+        let refund_tx_id = self
+            .chain_service
+            .lock()
+            .await
+            .broadcast(&refund_tx, Some(&swap.id))
+            .await?
+            .to_string();
 
         info!(
             "Successfully broadcast non-cooperative refund for Send Swap {}, tx: {}",
@@ -465,6 +523,10 @@ impl SendSwapStateHandler {
                 err: format!("Cannot transition from {from_state:?} to RefundPending state"),
             }),
 
+            (_, WaitingFeeAcceptance) => Err(PaymentError::Generic {
+                err: format!("Cannot transition from {from_state:?} to WaitingFeeAcceptance state"),
+            }),
+
             (Complete, Failed) => Err(PaymentError::Generic {
                 err: format!("Cannot transition from {from_state:?} to Failed state"),
             }),
@@ -475,13 +537,9 @@ impl SendSwapStateHandler {
     fn verify_payment_hash(preimage: &str, invoice: &str) -> Result<(), PaymentError> {
         let preimage = Preimage::from_str(preimage)?;
         let preimage_hash = preimage.sha256.to_string();
-        let invoice =
-            Bolt11Invoice::from_str(invoice).map_err(|err| PaymentError::InvalidInvoice {
-                err: err.to_string(),
-            })?;
-        let invoice_payment_hash = invoice.payment_hash();
+        let invoice_payment_hash = bolt12::get_payment_hash(invoice)?;
 
-        (invoice_payment_hash.to_string() == preimage_hash)
+        (invoice_payment_hash == preimage_hash)
             .then_some(())
             .ok_or(PaymentError::InvalidPreimage)
     }
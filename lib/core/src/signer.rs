@@ -0,0 +1,176 @@
+use std::str::FromStr;
+
+use lwk_common::Signer as LwkSigner;
+use lwk_signer::SwSigner;
+use lwk_wollet::bitcoin::bip32::{DerivationPath, Xpub};
+use lwk_wollet::elements::pset::PartiallySignedTransaction;
+use sdk_common::bitcoin::hashes::hmac::{Hmac, HmacEngine};
+use sdk_common::bitcoin::hashes::{sha256, Hash, HashEngine};
+use sdk_common::bitcoin::secp256k1::{Message, Secp256k1};
+use sdk_common::bitcoin::util::bip32::{DerivationPath as Bip32DerivationPath, ExtendedPrivKey};
+
+use crate::{error::SignerError, model::LiquidNetwork};
+
+/// The signer of the wallet, which holds its keys.
+///
+/// By default the SDK uses a software signer created from the mnemonic in the
+/// [crate::model::ConnectRequest]. Implement this trait and pass it to
+/// [crate::sdk::LiquidSdk::connect_with_signer] to keep the keys outside of the SDK, for example
+/// in an HSM, a secure enclave or a remote signing service.
+///
+/// Derivation paths are passed in their string form, e.g. `m/84'/1776'/0'`.
+pub trait Signer: Send + Sync {
+    /// The base58 encoded master xpub. Its fingerprint identifies the wallet.
+    fn xpub(&self) -> Result<String, SignerError>;
+
+    /// Derives the base58 encoded xpub at the given derivation path
+    fn derive_xpub(&self, derivation_path: String) -> Result<String, SignerError>;
+
+    /// The hex encoded SLIP-77 master blinding key, used to unblind the wallet txs
+    fn slip77_master_blinding_key(&self) -> Result<String, SignerError>;
+
+    /// Signs the inputs of the base64 encoded PSET that belong to the wallet, returning the signed
+    /// base64 encoded PSET
+    fn sign_pset(&self, pset: String) -> Result<String, SignerError>;
+
+    /// Signs a 32 byte message digest with the key at the given derivation path, returning the
+    /// DER encoded ECDSA signature
+    fn sign_ecdsa(&self, msg: Vec<u8>, derivation_path: String) -> Result<Vec<u8>, SignerError>;
+
+    /// Computes the HMAC-SHA256 of the message, keyed with the private key at the given
+    /// derivation path. Used by LNURL-auth to derive the linking key of a domain.
+    fn hmac_sha256(&self, msg: Vec<u8>, derivation_path: String) -> Result<Vec<u8>, SignerError>;
+}
+
+/// The fingerprint of the signer master key, in hex
+pub(crate) fn get_fingerprint(signer: &dyn Signer) -> Result<String, SignerError> {
+    let xpub = Xpub::from_str(&signer.xpub()?).map_err(|e| SignerError::Generic {
+        err: format!("Invalid xpub: {e}"),
+    })?;
+    Ok(xpub.fingerprint().to_string())
+}
+
+/// The default [Signer], a software signer holding the keys derived from the mnemonic
+pub(crate) struct SdkSigner {
+    lwk_signer: SwSigner,
+    network: LiquidNetwork,
+}
+
+impl SdkSigner {
+    pub(crate) fn new(mnemonic: &str, network: LiquidNetwork) -> Result<Self, SignerError> {
+        let is_mainnet = network == LiquidNetwork::Mainnet;
+        let lwk_signer = SwSigner::new(mnemonic, is_mainnet).map_err(|e| SignerError::Generic {
+            err: format!("Invalid mnemonic: {e:?}"),
+        })?;
+        Ok(Self {
+            lwk_signer,
+            network,
+        })
+    }
+
+    fn derive_bip32_xpriv(&self, derivation_path: &str) -> Result<ExtendedPrivKey, SignerError> {
+        let seed = self.lwk_signer.seed().ok_or(SignerError::Generic {
+            err: "Could not get signer seed".to_string(),
+        })?;
+        let derivation_path = Bip32DerivationPath::from_str(derivation_path)?;
+        Ok(ExtendedPrivKey::new_master(self.network.into(), &seed)?
+            .derive_priv(&Secp256k1::new(), &derivation_path)?)
+    }
+}
+
+impl Signer for SdkSigner {
+    fn xpub(&self) -> Result<String, SignerError> {
+        Ok(self.lwk_signer.xpub().to_string())
+    }
+
+    fn derive_xpub(&self, derivation_path: String) -> Result<String, SignerError> {
+        let derivation_path =
+            DerivationPath::from_str(&derivation_path).map_err(|e| SignerError::Generic {
+                err: format!("Invalid derivation path {derivation_path}: {e}"),
+            })?;
+        Ok(self.lwk_signer.derive_xpub(&derivation_path)?.to_string())
+    }
+
+    fn slip77_master_blinding_key(&self) -> Result<String, SignerError> {
+        Ok(self.lwk_signer.slip77_master_blinding_key()?.to_string())
+    }
+
+    fn sign_pset(&self, pset: String) -> Result<String, SignerError> {
+        let mut pset =
+            PartiallySignedTransaction::from_str(&pset).map_err(|e| SignerError::Generic {
+                err: format!("Invalid PSET: {e:?}"),
+            })?;
+        self.lwk_signer.sign(&mut pset)?;
+        Ok(pset.to_string())
+    }
+
+    fn sign_ecdsa(&self, msg: Vec<u8>, derivation_path: String) -> Result<Vec<u8>, SignerError> {
+        let xpriv = self.derive_bip32_xpriv(&derivation_path)?;
+        let msg = Message::from_slice(&msg).map_err(|e| SignerError::Generic {
+            err: format!("Invalid message digest: {e}"),
+        })?;
+        Ok(Secp256k1::new()
+            .sign_ecdsa(&msg, &xpriv.private_key)
+            .serialize_der()
+            .to_vec())
+    }
+
+    fn hmac_sha256(&self, msg: Vec<u8>, derivation_path: String) -> Result<Vec<u8>, SignerError> {
+        let xpriv = self.derive_bip32_xpriv(&derivation_path)?;
+        let mut engine = HmacEngine::<sha256::Hash>::new(&xpriv.private_key.secret_bytes());
+        engine.input(&msg);
+        Ok(Hmac::<sha256::Hash>::from_engine(engine)
+            .into_inner()
+            .to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use sdk_common::bitcoin::secp256k1::{ecdsa::Signature, Message, Secp256k1};
+    use sdk_common::bitcoin::util::bip32::ExtendedPubKey;
+    use std::str::FromStr;
+
+    use crate::model::LiquidNetwork;
+
+    use super::{get_fingerprint, SdkSigner, Signer};
+
+    const TEST_MNEMONIC: &str =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_sdk_signer() -> Result<()> {
+        let signer = SdkSigner::new(TEST_MNEMONIC, LiquidNetwork::Testnet)?;
+        assert_eq!(get_fingerprint(&signer)?, "73c5da0a");
+
+        // Signatures verify against the key derived at the same path
+        let derivation_path = "m/138'/0".to_string();
+        let msg = [1u8; 32].to_vec();
+        let signature = signer.sign_ecdsa(msg.clone(), derivation_path.clone())?;
+        let xpub = ExtendedPubKey::from_str(&signer.derive_xpub(derivation_path.clone())?)?;
+        Secp256k1::new().verify_ecdsa(
+            &Message::from_slice(&msg)?,
+            &Signature::from_der(&signature)?,
+            &xpub.public_key,
+        )?;
+
+        // The HMAC is keyed with the key at the given path
+        let hmac = signer.hmac_sha256(b"site.com".to_vec(), derivation_path)?;
+        assert_eq!(hmac.len(), 32);
+        assert_eq!(
+            hmac,
+            signer.hmac_sha256(b"site.com".to_vec(), "m/138'/0".to_string())?
+        );
+        assert_ne!(
+            hmac,
+            signer.hmac_sha256(b"site.com".to_vec(), "m/138'/1".to_string())?
+        );
+
+        assert!(signer
+            .sign_ecdsa(vec![1u8; 31], "m/138'/0".to_string())
+            .is_err());
+
+        Ok(())
+    }
+}
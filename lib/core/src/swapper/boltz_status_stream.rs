@@ -9,34 +9,53 @@ use log::{debug, error, info, warn};
 use tokio::net::TcpStream;
 use tokio::sync::{broadcast, watch};
 use tokio::time::MissedTickBehavior;
+use tokio_socks::tcp::Socks5Stream;
 use tokio_tungstenite::tungstenite::Message;
-use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+use tokio_tungstenite::{client_async_tls, connect_async, MaybeTlsStream, WebSocketStream};
 use url::Url;
 
 use super::{ReconnectHandler, SwapperStatusStream};
+use crate::model::Config;
 
 pub(crate) struct BoltzStatusStream {
     url: String,
+    config: Config,
     subscription_notifier: broadcast::Sender<String>,
     update_notifier: broadcast::Sender<boltz::Update>,
 }
 
 impl BoltzStatusStream {
-    pub(crate) fn new(url: &str) -> Self {
+    pub(crate) fn new(url: &str, config: Config) -> Self {
         let (subscription_notifier, _) = broadcast::channel::<String>(30);
         let (update_notifier, _) = broadcast::channel::<boltz::Update>(30);
 
         Self {
             url: url.replace("http", "ws") + "/ws",
+            config,
             subscription_notifier,
             update_notifier,
         }
     }
 
     async fn connect(&self) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>> {
-        let (socket, _) = connect_async(Url::parse(&self.url)?)
-            .await
-            .map_err(|e| anyhow!("Failed to connect to websocket: {e:?}"))?;
+        let url = Url::parse(&self.url)?;
+        let (socket, _) = match self.config.socks5_proxy_addr()? {
+            Some(proxy_addr) => {
+                let host = url
+                    .host_str()
+                    .ok_or(anyhow!("Missing host in websocket URL {url}"))?;
+                let port = url
+                    .port_or_known_default()
+                    .ok_or(anyhow!("Missing port in websocket URL {url}"))?;
+                // The proxy resolves the host, so that no DNS request leaks outside of it
+                let stream = Socks5Stream::connect(proxy_addr.as_str(), (host, port))
+                    .await
+                    .map_err(|e| anyhow!("Failed to connect to proxy: {e:?}"))?;
+                client_async_tls(url, stream.into_inner()).await
+            }
+            None => connect_async(url).await,
+        }
+        .map_err(|e| anyhow!("Failed to connect to websocket: {e:?}"))?;
         Ok(socket)
     }
 
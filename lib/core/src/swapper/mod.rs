@@ -1,4 +1,5 @@
 mod boltz_status_stream;
+pub(crate) mod relay;
 
 use std::str::FromStr;
 use std::sync::Arc;
@@ -6,27 +7,26 @@ use std::sync::Arc;
 use anyhow::Result;
 use async_trait::async_trait;
 use boltz_client::elements::secp256k1_zkp::{MusigPartialSignature, MusigPubNonce};
-use boltz_client::error::Error;
-use boltz_client::network::electrum::ElectrumConfig;
 use boltz_client::network::Chain;
 use boltz_client::swaps::boltz::{
     self, BoltzApiClientV2, ChainPair, Cooperative, CreateChainRequest, CreateChainResponse,
     CreateReverseRequest, CreateReverseResponse, CreateSubmarineRequest, CreateSubmarineResponse,
-    ReversePair, SubmarineClaimTxResponse, SubmarinePair, BOLTZ_MAINNET_URL_V2,
-    BOLTZ_TESTNET_URL_V2,
+    GetBolt12FetchRequest, ReversePair, SubmarineClaimTxResponse, SubmarinePair, SwapTxKind,
 };
 use boltz_client::util::secrets::Preimage;
-use boltz_client::{Amount, Bolt11Invoice, BtcSwapTx, Keypair, LBtcSwapTx, LockTime};
+use boltz_client::{Amount, BtcSwapScript, Keypair, LockTime};
 use boltz_status_stream::BoltzStatusStream;
 use log::{debug, info};
-use lwk_wollet::elements;
+use lwk_wollet::elements::{self, Transaction};
 use serde_json::Value;
 use tokio::sync::{broadcast, watch};
 use url::Url;
 
+use crate::bolt12;
 use crate::error::{PaymentError, SdkError};
 use crate::model::{
-    ChainSwap, Config, Direction, LiquidNetwork, ReceiveSwap, SendSwap, SwapScriptV2, SwapTxV2,
+    ChainSwap, Config, Direction, LiquidNetwork, ReceiveSwap, SendSwap, SwapScriptV2,
+    SwapTransaction, SwapTxV2, Utxo,
 };
 use crate::utils;
 
@@ -65,50 +65,66 @@ pub trait Swapper: Send + Sync {
     /// Get the current rate, limits and fees for both swap directions
     fn get_chain_pairs(&self) -> Result<(Option<ChainPair>, Option<ChainPair>), PaymentError>;
 
+    /// Get the new quote of a chain swap whose user lockup amount differs from the agreed one,
+    /// i.e. the amount the swapper would lock up for it
+    fn get_chain_swap_quote(&self, swap_id: &str) -> Result<u64, PaymentError>;
+
+    /// Accept the new quote of a chain swap, so that the swapper locks up the given amount
+    fn accept_chain_swap_quote(
+        &self,
+        swap_id: &str,
+        server_lockup_amount_sat: u64,
+    ) -> Result<(), PaymentError>;
+
     /// Get a submarine pair information
     fn get_submarine_pairs(&self) -> Result<Option<SubmarinePair>, PaymentError>;
 
-    /// Prepare the chain swap refund
+    /// Prepare the chain swap refund spending the given lockup output
     fn prepare_chain_swap_refund(
         &self,
         swap: &ChainSwap,
         output_address: &str,
         sat_per_vbyte: f32,
+        lockup_utxo: &Utxo,
     ) -> Result<(u32, u64), SdkError>;
 
-    /// Refund a cooperatively chain swap  
-    fn refund_chain_swap_cooperative(
+    /// Create a cooperative refund tx of a chain swap, spending the given lockup output
+    fn create_chain_swap_refund_cooperative(
         &self,
         swap: &ChainSwap,
+        lockup_utxo: Utxo,
         output_address: &str,
         broadcast_fees_sat: u64,
-    ) -> Result<String, PaymentError>;
+    ) -> Result<SwapTransaction, PaymentError>;
 
-    /// Refund a cooperatively send swap
-    fn refund_send_swap_cooperative(
+    /// Create a cooperative refund tx of a send swap, spending the given lockup output
+    fn create_send_swap_refund_cooperative(
         &self,
         swap: &SendSwap,
+        lockup_utxo: Utxo,
         output_address: &str,
         broadcast_fees_sat: u64,
-    ) -> Result<String, PaymentError>;
+    ) -> Result<Transaction, PaymentError>;
 
-    /// Refund non-cooperatively chain swap
-    fn refund_chain_swap_non_cooperative(
+    /// Create a non-cooperative refund tx of a chain swap, spending the given lockup output
+    fn create_chain_swap_refund_non_cooperative(
         &self,
         swap: &ChainSwap,
+        lockup_utxo: Utxo,
         broadcast_fees_sat: u64,
         output_address: &str,
         current_height: u32,
-    ) -> Result<String, PaymentError>;
+    ) -> Result<SwapTransaction, PaymentError>;
 
-    /// Refund non-cooperatively send swap
-    fn refund_send_swap_non_cooperative(
+    /// Create a non-cooperative refund tx of a send swap, spending the given lockup output
+    fn create_send_swap_refund_non_cooperative(
         &self,
         swap: &SendSwap,
+        lockup_utxo: Utxo,
         broadcast_fees_sat: u64,
         output_address: &str,
         current_height: u32,
-    ) -> Result<String, PaymentError>;
+    ) -> Result<Transaction, PaymentError>;
 
     /// Get send swap claim tx details which includes the preimage as a proof of payment.
     /// It is used to validate the preimage before claiming which is the reason why we need to separate
@@ -118,8 +134,14 @@ pub trait Swapper: Send + Sync {
         swap: &SendSwap,
     ) -> Result<SubmarineClaimTxResponse, PaymentError>;
 
-    /// Claim chain swap.
-    fn claim_chain_swap(&self, swap: &ChainSwap) -> Result<String, PaymentError>;
+    /// Create the claim tx of a chain swap, spending the given server lockup output. The user
+    /// lockup output is needed to sign the swapper's claim of it in exchange.
+    fn create_chain_swap_claim(
+        &self,
+        swap: &ChainSwap,
+        claim_utxo: Utxo,
+        user_lockup_utxo: Utxo,
+    ) -> Result<SwapTransaction, PaymentError>;
 
     /// Claim send swap cooperatively. Here the remote swapper is the one that claims.
     /// We are helping to use key spend path for cheaper fees.
@@ -128,6 +150,7 @@ pub trait Swapper: Send + Sync {
         swap: &SendSwap,
         claim_tx_response: SubmarineClaimTxResponse,
         refund_address: &str,
+        lockup_utxo: Utxo,
     ) -> Result<(), PaymentError>;
 
     /// Create a new receive swap
@@ -139,18 +162,28 @@ pub trait Swapper: Send + Sync {
     /// Get a reverse pair information
     fn get_reverse_swap_pairs(&self) -> Result<Option<ReversePair>, PaymentError>;
 
-    /// Claim receive swap. Here the local swapper is the one that claims.
-    fn claim_receive_swap(
+    /// Create the claim tx of a receive swap, spending the given lockup output. Here the local
+    /// swapper is the one that claims.
+    fn create_receive_swap_claim(
         &self,
         swap: &ReceiveSwap,
         claim_address: String,
-    ) -> Result<String, PaymentError>;
+        lockup_utxo: Utxo,
+    ) -> Result<Transaction, PaymentError>;
 
     /// Chain broadcast
     fn broadcast_tx(&self, chain: Chain, tx_hex: &str) -> Result<Value, PaymentError>;
 
     fn create_status_stream(&self) -> Box<dyn SwapperStatusStream>;
 
+    /// Fetch a bolt12 invoice for the given amount from the offer, with an optional payer note
+    fn get_bolt12_invoice(
+        &self,
+        offer: &str,
+        amount_sat: u64,
+        payer_note: Option<String>,
+    ) -> Result<String, PaymentError>;
+
     /// Look for a valid Magic Routing Hint. If found, validate it and extract the BIP21 info (amount, address).
     fn check_for_mrh(&self, invoice: &str) -> Result<Option<(String, f64)>, PaymentError>;
 }
@@ -160,15 +193,13 @@ pub struct BoltzSwapper {
     boltz_url: String,
     referral_id: Option<String>,
     config: Config,
-    liquid_electrum_config: ElectrumConfig,
-    bitcoin_electrum_config: ElectrumConfig,
 }
 
 impl BoltzSwapper {
-    pub fn new(config: Config, swapper_proxy_url: Option<String>) -> BoltzSwapper {
-        let (boltz_api_base_url, referral_id) = match &config.network {
-            LiquidNetwork::Testnet => (None, None),
-            LiquidNetwork::Mainnet => match &swapper_proxy_url {
+    pub fn new(config: Config, swapper_proxy_url: Option<String>) -> Result<BoltzSwapper> {
+        let (boltz_api_base_url, referral_id) = match (&config.boltz_url, &config.network) {
+            (Some(_), _) | (None, LiquidNetwork::Testnet | LiquidNetwork::Regtest) => (None, None),
+            (None, LiquidNetwork::Mainnet) => match &swapper_proxy_url {
                 Some(swapper_proxy_url) => Url::parse(swapper_proxy_url)
                     .map(|url| match url.query() {
                         None => (None, None),
@@ -185,57 +216,51 @@ impl BoltzSwapper {
             },
         };
 
-        let boltz_url = boltz_api_base_url.unwrap_or(
-            match config.network {
-                LiquidNetwork::Mainnet => BOLTZ_MAINNET_URL_V2,
-                LiquidNetwork::Testnet => BOLTZ_TESTNET_URL_V2,
-            }
-            .to_string(),
-        );
+        let boltz_url = boltz_api_base_url.unwrap_or(config.get_boltz_url());
+        let referral_id = config.referral_id.clone().or(referral_id);
 
-        BoltzSwapper {
-            client: BoltzApiClientV2::new(&boltz_url),
+        Ok(BoltzSwapper {
+            client: BoltzApiClientV2::new(&relay::boltz_api_url(&config, &boltz_url)?),
             boltz_url,
             referral_id,
-            config: config.clone(),
-            liquid_electrum_config: ElectrumConfig::new(
-                config.network.into(),
-                &config.liquid_electrum_url,
-                true,
-                true,
-                100,
-            ),
-            bitcoin_electrum_config: ElectrumConfig::new(
-                config.network.as_bitcoin_chain(),
-                &config.bitcoin_electrum_url,
-                true,
-                true,
-                100,
-            ),
-        }
+            config,
+        })
     }
 
     fn new_refund_tx(
         &self,
-        swap_id: String,
         swap_script: SwapScriptV2,
-        refund_address: &String,
+        lockup_utxo: Utxo,
+        refund_address: &str,
     ) -> Result<SwapTxV2, SdkError> {
-        let swap_tx = match swap_script {
-            SwapScriptV2::Bitcoin(swap_script) => SwapTxV2::Bitcoin(BtcSwapTx::new_refund(
-                swap_script.clone(),
-                refund_address,
-                &self.bitcoin_electrum_config,
-            )?),
-            SwapScriptV2::Liquid(swap_script) => SwapTxV2::Liquid(LBtcSwapTx::new_refund(
-                swap_script.clone(),
-                refund_address,
-                &self.liquid_electrum_config,
-                self.boltz_url.clone(),
-                swap_id,
-            )?),
-        };
-        Ok(swap_tx)
+        Ok(SwapTxV2::new(
+            SwapTxKind::Refund,
+            swap_script,
+            refund_address,
+            lockup_utxo,
+            self.config.network,
+        )?)
+    }
+
+    fn ensure_locktime_elapsed(
+        script: &BtcSwapScript,
+        current_height: u32,
+    ) -> Result<(), PaymentError> {
+        let locktime_from_height =
+            LockTime::from_height(current_height).map_err(|e| PaymentError::Generic {
+                err: format!("Error getting locktime from height {current_height:?}: {e}",),
+            })?;
+
+        info!("locktime info: locktime_from_height = {locktime_from_height:?},  swap_script.locktime = {:?}", script.locktime);
+        if !script.locktime.is_implied_by(locktime_from_height) {
+            return Err(PaymentError::Generic {
+                err: format!(
+                    "Cannot refund non-cooperatively. Lock time not elapsed yet. Current tip: {:?}. Script lock time: {:?}",
+                    locktime_from_height, script.locktime
+                )
+            });
+        }
+        Ok(())
     }
 
     fn validate_send_swap_preimage(
@@ -252,11 +277,9 @@ impl BoltzSwapper {
     fn verify_payment_hash(preimage: &str, invoice: &str) -> Result<(), PaymentError> {
         let preimage = Preimage::from_str(preimage)?;
         let preimage_hash = preimage.sha256.to_string();
-        let invoice =
-            Bolt11Invoice::from_str(invoice).map_err(|e| Error::Generic(e.to_string()))?;
-        let invoice_payment_hash = invoice.payment_hash();
+        let invoice_payment_hash = bolt12::get_payment_hash(invoice)?;
 
-        (invoice_payment_hash.to_string() == preimage_hash)
+        (invoice_payment_hash == preimage_hash)
             .then_some(())
             .ok_or(PaymentError::InvalidPreimage)
     }
@@ -264,6 +287,7 @@ impl BoltzSwapper {
     fn get_claim_partial_sig(
         &self,
         swap: &ChainSwap,
+        user_lockup_utxo: Utxo,
     ) -> Result<(MusigPartialSignature, MusigPubNonce), PaymentError> {
         let refund_keypair = swap.get_refund_keypair()?;
         let lockup_swap_script = swap.get_lockup_swap_script()?;
@@ -272,7 +296,7 @@ impl BoltzSwapper {
         // We need it to calculate the musig partial sig for the claim tx from the other chain
         let lockup_address = &swap.lockup_address;
         let refund_tx_wrapper =
-            self.new_refund_tx(swap.id.clone(), lockup_swap_script, lockup_address)?;
+            self.new_refund_tx(lockup_swap_script, user_lockup_utxo, lockup_address)?;
 
         let claim_tx_details = self.client.get_chain_claim_tx_details(&swap.id)?;
         match swap.direction {
@@ -290,77 +314,15 @@ impl BoltzSwapper {
         .map_err(Into::into)
     }
 
-    fn claim_outgoing_chain_swap(&self, swap: &ChainSwap) -> Result<String, PaymentError> {
-        let claim_keypair = swap.get_claim_keypair()?;
-        let claim_swap_script = swap.get_claim_swap_script()?.as_bitcoin_script()?;
-        let claim_tx_wrapper = BtcSwapTx::new_claim(
-            claim_swap_script,
-            swap.claim_address.clone(),
-            &self.bitcoin_electrum_config,
-        )?;
-
-        let (partial_sig, pub_nonce) = self.get_claim_partial_sig(swap)?;
-
-        let claim_tx = claim_tx_wrapper.sign_claim(
-            &claim_keypair,
-            &Preimage::from_str(&swap.preimage)?,
-            swap.claim_fees_sat,
-            Some(Cooperative {
-                boltz_api: &self.client,
-                swap_id: swap.id.clone(),
-                pub_nonce: Some(pub_nonce),
-                partial_sig: Some(partial_sig),
-            }),
-        )?;
-        debug!("Claim Tx {:?}", claim_tx);
-
-        let claim_tx_id = claim_tx_wrapper
-            .broadcast(&claim_tx, &self.bitcoin_electrum_config)?
-            .to_string();
-        Ok(claim_tx_id)
-    }
-
-    fn claim_incoming_chain_swap(&self, swap: &ChainSwap) -> Result<String, PaymentError> {
-        let claim_keypair = swap.get_claim_keypair()?;
-        let swap_script = swap.get_claim_swap_script()?.as_liquid_script()?;
-        let claim_tx_wrapper = LBtcSwapTx::new_claim(
-            swap_script,
-            swap.claim_address.clone(),
-            &self.liquid_electrum_config,
-            self.boltz_url.clone(),
-            swap.id.clone(),
-        )?;
-
-        let (partial_sig, pub_nonce) = self.get_claim_partial_sig(swap)?;
-
-        let claim_tx = claim_tx_wrapper.sign_claim(
-            &claim_keypair,
-            &Preimage::from_str(&swap.preimage)?,
-            Amount::from_sat(swap.claim_fees_sat),
-            Some(Cooperative {
-                boltz_api: &self.client,
-                swap_id: swap.id.clone(),
-                pub_nonce: Some(pub_nonce),
-                partial_sig: Some(partial_sig),
-            }),
-        )?;
-        debug!("Claim Tx {:?}", claim_tx);
-        let claim_tx_id = claim_tx_wrapper.broadcast(
-            &claim_tx,
-            &self.liquid_electrum_config,
-            Some((&self.client, self.config.network.into())),
-        )?;
-        Ok(claim_tx_id)
-    }
-
-    fn refund_swap_cooperative(
+    fn create_refund_cooperative(
         &self,
         swap_id: String,
         swap_script: SwapScriptV2,
+        lockup_utxo: Utxo,
         refund_keypair: &Keypair,
         refund_address: &str,
         broadcast_fees_sat: u64,
-    ) -> Result<String, PaymentError> {
+    ) -> Result<SwapTransaction, PaymentError> {
         info!("Initiating cooperative refund for Swap {}", &swap_id);
         let is_cooperative = Some(Cooperative {
             boltz_api: &self.client,
@@ -368,71 +330,38 @@ impl BoltzSwapper {
             pub_nonce: None,
             partial_sig: None,
         });
-        let refund_tx_id = match swap_script.clone() {
-            SwapScriptV2::Bitcoin(_) => {
-                let refund_tx = self
-                    .new_refund_tx(swap_id.clone(), swap_script, &refund_address.into())?
-                    .as_bitcoin_tx()?;
-                let signed_tx =
-                    refund_tx.sign_refund(refund_keypair, broadcast_fees_sat, is_cooperative)?;
-                refund_tx
-                    .broadcast(&signed_tx, &self.bitcoin_electrum_config)?
-                    .to_string()
-            }
-            SwapScriptV2::Liquid(_) => {
-                let refund_tx = self
-                    .new_refund_tx(swap_id.clone(), swap_script, &refund_address.into())?
-                    .as_liquid_tx()?;
-                let signed_tx = refund_tx.sign_refund(
-                    refund_keypair,
-                    Amount::from_sat(broadcast_fees_sat),
-                    is_cooperative,
-                )?;
-                // We attempt lowball broadcast when constructing the tx cooperatively
-                let lowball = Some((&self.client, self.config.network.into()));
-                refund_tx.broadcast(&signed_tx, &self.liquid_electrum_config, lowball)?
-            }
+        let refund_tx = match self.new_refund_tx(swap_script, lockup_utxo, refund_address)? {
+            SwapTxV2::Bitcoin(refund_tx) => SwapTransaction::Bitcoin(refund_tx.sign_refund(
+                refund_keypair,
+                broadcast_fees_sat,
+                is_cooperative,
+            )?),
+            SwapTxV2::Liquid(refund_tx) => SwapTransaction::Liquid(refund_tx.sign_refund(
+                refund_keypair,
+                Amount::from_sat(broadcast_fees_sat),
+                is_cooperative,
+            )?),
         };
         info!(
-            "Successfully broadcast cooperative refund for Swap {}",
+            "Successfully signed cooperative refund for Swap {}",
             &swap_id
         );
-        Ok(refund_tx_id)
+        Ok(refund_tx)
     }
 
-    fn refund_swap_non_cooperative(
+    fn create_refund_non_cooperative(
         &self,
         swap_id: String,
         swap_script: SwapScriptV2,
+        lockup_utxo: Utxo,
         refund_keypair: &Keypair,
         broadcast_fees_sat: u64,
         refund_address: &str,
         current_height: u32,
-    ) -> Result<String, PaymentError> {
-        let refund_tx_id = match swap_script.clone() {
+    ) -> Result<SwapTransaction, PaymentError> {
+        match &swap_script {
             SwapScriptV2::Bitcoin(script) => {
-                let locktime_from_height =
-                    LockTime::from_height(current_height).map_err(|e| PaymentError::Generic {
-                        err: format!("Error getting locktime from height {current_height:?}: {e}",),
-                    })?;
-
-                info!("locktime info: locktime_from_height = {locktime_from_height:?},  swap_script.locktime = {:?}", script.locktime);
-                if !script.locktime.is_implied_by(locktime_from_height) {
-                    return Err(PaymentError::Generic {
-                        err: format!(
-                            "Cannot refund non-cooperatively. Lock time not elapsed yet. Current tip: {:?}. Script lock time: {:?}",
-                            locktime_from_height, script.locktime
-                        )
-                    });
-                }
-
-                let refund_tx = self
-                    .new_refund_tx(swap_id.clone(), swap_script, &refund_address.into())?
-                    .as_bitcoin_tx()?;
-                let signed_tx = refund_tx.sign_refund(refund_keypair, broadcast_fees_sat, None)?;
-                refund_tx
-                    .broadcast(&signed_tx, &self.bitcoin_electrum_config)?
-                    .to_string()
+                Self::ensure_locktime_elapsed(script, current_height)?;
             }
             SwapScriptV2::Liquid(script) => {
                 let locktime_from_height = elements::LockTime::from_height(current_height)
@@ -449,24 +378,23 @@ impl BoltzSwapper {
                         )
                     });
                 }
-
-                let refund_tx = self
-                    .new_refund_tx(swap_id.clone(), swap_script, &refund_address.into())?
-                    .as_liquid_tx()?;
-                let signed_tx = refund_tx.sign_refund(
-                    refund_keypair,
-                    Amount::from_sat(broadcast_fees_sat),
-                    None,
-                )?;
-                // We cannot broadcast lowball when constructing a non-cooperative tx
-                refund_tx.broadcast(&signed_tx, &self.liquid_electrum_config, None)?
             }
+        }
+
+        let refund_tx = match self.new_refund_tx(swap_script, lockup_utxo, refund_address)? {
+            SwapTxV2::Bitcoin(refund_tx) => SwapTransaction::Bitcoin(refund_tx.sign_refund(
+                refund_keypair,
+                broadcast_fees_sat,
+                None,
+            )?),
+            SwapTxV2::Liquid(refund_tx) => SwapTransaction::Liquid(refund_tx.sign_refund(
+                refund_keypair,
+                Amount::from_sat(broadcast_fees_sat),
+                None,
+            )?),
         };
-        info!(
-            "Successfully broadcast non-cooperative refund for Swap {}",
-            swap_id
-        );
-        Ok(refund_tx_id)
+        info!("Successfully signed non-cooperative refund for Swap {swap_id}");
+        Ok(refund_tx)
     }
 }
 
@@ -511,94 +439,108 @@ impl Swapper for BoltzSwapper {
         Ok((pair_outgoing, pair_incoming))
     }
 
+    fn get_chain_swap_quote(&self, swap_id: &str) -> Result<u64, PaymentError> {
+        Ok(self.client.get_quote(swap_id)?.amount)
+    }
+
+    fn accept_chain_swap_quote(
+        &self,
+        swap_id: &str,
+        server_lockup_amount_sat: u64,
+    ) -> Result<(), PaymentError> {
+        self.client
+            .accept_quote(swap_id, server_lockup_amount_sat)?;
+        Ok(())
+    }
+
     /// Get a submarine pair information
     fn get_submarine_pairs(&self) -> Result<Option<SubmarinePair>, PaymentError> {
         Ok(self.client.get_submarine_pairs()?.get_lbtc_to_btc_pair())
     }
 
-    /// Prepare the chain swap refund
+    /// Prepare the chain swap refund spending the given lockup output
     fn prepare_chain_swap_refund(
         &self,
         swap: &ChainSwap,
         output_address: &str,
         sat_per_vbyte: f32,
+        lockup_utxo: &Utxo,
     ) -> Result<(u32, u64), SdkError> {
         let refund_keypair = swap.get_refund_keypair()?;
         let preimage = Preimage::from_str(&swap.preimage)?;
         let swap_script = swap.get_lockup_swap_script()?;
-        let refund_tx_vsize = match swap.direction {
-            Direction::Incoming => {
-                let refund_tx = self
-                    .new_refund_tx(swap.id.clone(), swap_script, &output_address.into())?
-                    .as_bitcoin_tx()?;
-                refund_tx.size(&refund_keypair, &preimage)? as u32
-            }
-            Direction::Outgoing => {
-                let refund_tx = self
-                    .new_refund_tx(swap.id.clone(), swap_script, &output_address.into())?
-                    .as_liquid_tx()?;
-                refund_tx.size(&refund_keypair, &preimage)? as u32
-            }
-        };
+        let refund_tx_vsize =
+            match self.new_refund_tx(swap_script, lockup_utxo.clone(), output_address)? {
+                SwapTxV2::Bitcoin(refund_tx) => refund_tx.size(&refund_keypair, &preimage)? as u32,
+                SwapTxV2::Liquid(refund_tx) => refund_tx.size(&refund_keypair, &preimage)? as u32,
+            };
         let refund_tx_fee_sat = (refund_tx_vsize as f32 * sat_per_vbyte).ceil() as u64;
         Ok((refund_tx_vsize, refund_tx_fee_sat))
     }
 
-    /// Refund a cooperatively chain swap  
-    fn refund_chain_swap_cooperative(
+    /// Create a cooperative refund tx of a chain swap, spending the given lockup output
+    fn create_chain_swap_refund_cooperative(
         &self,
         swap: &ChainSwap,
+        lockup_utxo: Utxo,
         output_address: &str,
         broadcast_fees_sat: u64,
-    ) -> Result<String, PaymentError> {
+    ) -> Result<SwapTransaction, PaymentError> {
         let refund_keypair = swap.get_refund_keypair()?;
         let swap_script = swap.get_lockup_swap_script()?;
         info!("Initiating cooperative refund for Chain Swap {}", &swap.id);
-        self.refund_swap_cooperative(
+        self.create_refund_cooperative(
             swap.id.clone(),
             swap_script,
+            lockup_utxo,
             &refund_keypair,
             output_address,
             broadcast_fees_sat,
         )
     }
 
-    /// Refund a cooperatively send swap
-    fn refund_send_swap_cooperative(
+    /// Create a cooperative refund tx of a send swap, spending the given lockup output
+    fn create_send_swap_refund_cooperative(
         &self,
         swap: &SendSwap,
+        lockup_utxo: Utxo,
         output_address: &str,
         broadcast_fees_sat: u64,
-    ) -> Result<String, PaymentError> {
+    ) -> Result<Transaction, PaymentError> {
         info!("Initiating cooperative refund for Send Swap {}", &swap.id);
         let swap_script = SwapScriptV2::Liquid(swap.get_swap_script()?);
         let refund_keypair = swap.get_refund_keypair()?;
-        self.refund_swap_cooperative(
-            swap.id.clone(),
-            swap_script,
-            &refund_keypair,
-            output_address,
-            broadcast_fees_sat,
-        )
+        Ok(self
+            .create_refund_cooperative(
+                swap.id.clone(),
+                swap_script,
+                lockup_utxo,
+                &refund_keypair,
+                output_address,
+                broadcast_fees_sat,
+            )?
+            .as_liquid()?)
     }
 
-    /// Refund non-cooperatively chain swap
-    fn refund_chain_swap_non_cooperative(
+    /// Create a non-cooperative refund tx of a chain swap, spending the given lockup output
+    fn create_chain_swap_refund_non_cooperative(
         &self,
         swap: &ChainSwap,
+        lockup_utxo: Utxo,
         broadcast_fees_sat: u64,
         output_address: &str,
         current_height: u32,
-    ) -> Result<String, PaymentError> {
+    ) -> Result<SwapTransaction, PaymentError> {
         info!(
             "Initiating non cooperative refund for Chain Swap {}",
             &swap.id
         );
         let refund_keypair = swap.get_refund_keypair()?;
         let swap_script = swap.get_lockup_swap_script()?;
-        self.refund_swap_non_cooperative(
+        self.create_refund_non_cooperative(
             swap.id.clone(),
             swap_script,
+            lockup_utxo,
             &refund_keypair,
             broadcast_fees_sat,
             output_address,
@@ -606,24 +548,28 @@ impl Swapper for BoltzSwapper {
         )
     }
 
-    /// Refund non-cooperatively send swap
-    fn refund_send_swap_non_cooperative(
+    /// Create a non-cooperative refund tx of a send swap, spending the given lockup output
+    fn create_send_swap_refund_non_cooperative(
         &self,
         swap: &SendSwap,
+        lockup_utxo: Utxo,
         broadcast_fees_sat: u64,
         output_address: &str,
         current_height: u32,
-    ) -> Result<String, PaymentError> {
+    ) -> Result<Transaction, PaymentError> {
         let swap_script = SwapScriptV2::Liquid(swap.get_swap_script()?);
         let refund_keypair = swap.get_refund_keypair()?;
-        self.refund_swap_non_cooperative(
-            swap.id.clone(),
-            swap_script,
-            &refund_keypair,
-            broadcast_fees_sat,
-            output_address,
-            current_height,
-        )
+        Ok(self
+            .create_refund_non_cooperative(
+                swap.id.clone(),
+                swap_script,
+                lockup_utxo,
+                &refund_keypair,
+                broadcast_fees_sat,
+                output_address,
+                current_height,
+            )?
+            .as_liquid()?)
     }
 
     /// Get claim tx details which includes the preimage as a proof of payment.
@@ -640,17 +586,52 @@ impl Swapper for BoltzSwapper {
         Ok(claim_tx_response)
     }
 
-    /// Claim chain swap.
-    fn claim_chain_swap(&self, swap: &ChainSwap) -> Result<String, PaymentError> {
-        let claim_tx_id = match swap.direction {
-            Direction::Incoming => self.claim_incoming_chain_swap(swap),
-            Direction::Outgoing => self.claim_outgoing_chain_swap(swap),
-        }?;
-        info!(
-            "Successfully broadcast claim tx {claim_tx_id} for Chain Swap {}",
-            swap.id
-        );
-        Ok(claim_tx_id)
+    /// Create the claim tx of a chain swap, spending the given server lockup output. The user
+    /// lockup output is needed to sign the swapper's claim of it in exchange.
+    fn create_chain_swap_claim(
+        &self,
+        swap: &ChainSwap,
+        claim_utxo: Utxo,
+        user_lockup_utxo: Utxo,
+    ) -> Result<SwapTransaction, PaymentError> {
+        let claim_keypair = swap.get_claim_keypair()?;
+        let preimage = Preimage::from_str(&swap.preimage)?;
+        let claim_tx_wrapper = SwapTxV2::new(
+            SwapTxKind::Claim,
+            swap.get_claim_swap_script()?,
+            &swap.claim_address,
+            claim_utxo,
+            self.config.network,
+        )?;
+
+        let (partial_sig, pub_nonce) = self.get_claim_partial_sig(swap, user_lockup_utxo)?;
+        let is_cooperative = Some(Cooperative {
+            boltz_api: &self.client,
+            swap_id: swap.id.clone(),
+            pub_nonce: Some(pub_nonce),
+            partial_sig: Some(partial_sig),
+        });
+
+        let claim_tx = match claim_tx_wrapper {
+            SwapTxV2::Bitcoin(claim_tx_wrapper) => {
+                SwapTransaction::Bitcoin(claim_tx_wrapper.sign_claim(
+                    &claim_keypair,
+                    &preimage,
+                    swap.claim_fees_sat,
+                    is_cooperative,
+                )?)
+            }
+            SwapTxV2::Liquid(claim_tx_wrapper) => {
+                SwapTransaction::Liquid(claim_tx_wrapper.sign_claim(
+                    &claim_keypair,
+                    &preimage,
+                    Amount::from_sat(swap.claim_fees_sat),
+                    is_cooperative,
+                )?)
+            }
+        };
+        debug!("Claim Tx {:?}", claim_tx);
+        Ok(claim_tx)
     }
 
     /// Claim send swap cooperatively. Here the remote swapper is the one that claims.
@@ -660,12 +641,13 @@ impl Swapper for BoltzSwapper {
         swap: &SendSwap,
         claim_tx_response: SubmarineClaimTxResponse,
         refund_address: &str,
+        lockup_utxo: Utxo,
     ) -> Result<(), PaymentError> {
         let swap_id = &swap.id;
         let keypair = swap.get_refund_keypair()?;
         let swap_script = SwapScriptV2::Liquid(swap.get_swap_script()?);
         let refund_tx = self
-            .new_refund_tx(swap.id.clone(), swap_script, &refund_address.into())?
+            .new_refund_tx(swap_script, lockup_utxo, refund_address)?
             .as_liquid_tx()?;
 
         self.validate_send_swap_preimage(swap_id, &swap.invoice, &claim_tx_response.preimage)?;
@@ -702,21 +684,23 @@ impl Swapper for BoltzSwapper {
         Ok(self.client.get_reverse_pairs()?.get_btc_to_lbtc_pair())
     }
 
-    /// Claim receive swap. Here the local swapper is the one that claims.
-    fn claim_receive_swap(
+    /// Create the claim tx of a receive swap, spending the given lockup output. Here the local
+    /// swapper is the one that claims.
+    fn create_receive_swap_claim(
         &self,
         swap: &ReceiveSwap,
         claim_address: String,
-    ) -> Result<String, PaymentError> {
-        let swap_script = swap.get_swap_script()?;
-        let swap_id = &swap.id;
-        let claim_tx_wrapper = LBtcSwapTx::new_claim(
+        lockup_utxo: Utxo,
+    ) -> Result<Transaction, PaymentError> {
+        let swap_script = SwapScriptV2::Liquid(swap.get_swap_script()?);
+        let claim_tx_wrapper = SwapTxV2::new(
+            SwapTxKind::Claim,
             swap_script,
-            claim_address,
-            &self.liquid_electrum_config,
-            self.boltz_url.clone(),
-            swap.id.clone(),
-        )?;
+            &claim_address,
+            lockup_utxo,
+            self.config.network,
+        )?
+        .as_liquid_tx()?;
 
         let is_cooperative = Some(Cooperative {
             boltz_api: &self.client,
@@ -730,15 +714,8 @@ impl Swapper for BoltzSwapper {
             Amount::from_sat(swap.claim_fees_sat),
             is_cooperative,
         )?;
-
-        let claim_tx_id = claim_tx_wrapper.broadcast(
-            &claim_tx,
-            &self.liquid_electrum_config,
-            Some((&self.client, self.config.network.into())),
-        )?;
-        info!("Successfully broadcast claim tx {claim_tx_id} for Receive Swap {swap_id}");
         debug!("Claim Tx {:?}", claim_tx);
-        Ok(claim_tx_id)
+        Ok(claim_tx)
     }
 
     fn broadcast_tx(&self, chain: Chain, tx_hex: &str) -> Result<Value, PaymentError> {
@@ -746,7 +723,21 @@ impl Swapper for BoltzSwapper {
     }
 
     fn create_status_stream(&self) -> Box<dyn SwapperStatusStream> {
-        Box::new(BoltzStatusStream::new(&self.boltz_url))
+        Box::new(BoltzStatusStream::new(&self.boltz_url, self.config.clone()))
+    }
+
+    fn get_bolt12_invoice(
+        &self,
+        offer: &str,
+        amount_sat: u64,
+        payer_note: Option<String>,
+    ) -> Result<String, PaymentError> {
+        let res = self.client.get_bolt12_invoice(GetBolt12FetchRequest {
+            offer: offer.to_string(),
+            amount: amount_sat,
+            note: payer_note,
+        })?;
+        Ok(res.invoice)
     }
 
     fn check_for_mrh(&self, invoice: &str) -> Result<Option<(String, f64)>, PaymentError> {
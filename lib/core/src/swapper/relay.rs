@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{mpsc, Mutex, OnceLock};
+use std::thread;
+
+use anyhow::{anyhow, Result};
+use log::{info, warn};
+use reqwest::{Client, Method, StatusCode};
+use tokio::runtime::{Builder, Runtime};
+use url::Url;
+
+use crate::chain::http_client;
+use crate::model::Config;
+
+/// The local URLs of the running relays, by proxy URL and Boltz API URL
+static RELAYS: OnceLock<Mutex<HashMap<(String, String), String>>> = OnceLock::new();
+
+/// The URL to build the Boltz API client with. The client cannot be configured with a proxy, so
+/// if a [Config::proxy_url] is set, it is pointed at a local relay which forwards its requests
+/// to `boltz_url` through the proxy.
+pub(crate) fn boltz_api_url(config: &Config, boltz_url: &str) -> Result<String> {
+    let Some(proxy_url) = &config.proxy_url else {
+        return Ok(boltz_url.to_string());
+    };
+
+    let mut relays = RELAYS
+        .get_or_init(Default::default)
+        .lock()
+        .map_err(|_| anyhow!("Failed to lock the Boltz API relays"))?;
+    let key = (proxy_url.clone(), boltz_url.to_string());
+    if let Some(relay_url) = relays.get(&key) {
+        return Ok(relay_url.clone());
+    }
+    let relay_url = start_relay(config, boltz_url)?;
+    relays.insert(key, relay_url.clone());
+    Ok(relay_url)
+}
+
+/// Starts relaying the plain HTTP requests made to a local port to the origin of `boltz_url`,
+/// returning the URL of `boltz_url` on that port
+fn start_relay(config: &Config, boltz_url: &str) -> Result<String> {
+    let url = Url::parse(boltz_url)?;
+    let origin = url.origin().ascii_serialization();
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let relay_url = format!(
+        "http://{}{}",
+        listener.local_addr()?,
+        url.path().trim_end_matches('/')
+    );
+
+    // The relay gets its own runtime, as the Boltz API client blocks the thread it is called on
+    let config = config.clone();
+    let (ready_tx, ready_rx) = mpsc::channel();
+    thread::spawn(move || {
+        let setup = http_client(&config).and_then(|client| {
+            let runtime = Builder::new_current_thread().enable_all().build()?;
+            Ok((client, runtime))
+        });
+        let (client, runtime) = match setup {
+            Ok(setup) => {
+                let _ = ready_tx.send(Ok(()));
+                setup
+            }
+            Err(e) => {
+                let _ = ready_tx.send(Err(e));
+                return;
+            }
+        };
+
+        for stream in listener.incoming() {
+            let res = stream
+                .map_err(Into::into)
+                .and_then(|stream| relay(&runtime, &client, &origin, stream));
+            if let Err(e) = res {
+                warn!("Failed to relay Boltz API request: {e:?}");
+            }
+        }
+    });
+    ready_rx
+        .recv()
+        .map_err(|_| anyhow!("The Boltz API relay stopped unexpectedly"))??;
+
+    info!("Relaying the Boltz API requests from {relay_url} to {boltz_url} through the proxy");
+    Ok(relay_url)
+}
+
+/// Forwards a single request to `origin`, writing back its response
+fn relay(runtime: &Runtime, client: &Client, origin: &str, mut stream: TcpStream) -> Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Err(anyhow!("Invalid request line: {request_line}"));
+    };
+    let method = Method::from_bytes(method.as_bytes())?;
+
+    let mut headers = vec![];
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        let Some((name, value)) = line.split_once(':') else {
+            return Err(anyhow!("Invalid header: {line}"));
+        };
+        let (name, value) = (name.trim().to_lowercase(), value.trim().to_string());
+        match name.as_str() {
+            "content-length" => content_length = value.parse()?,
+            // Set for the connection to the Boltz API instead. The response body is relayed as
+            // is, so it must not be encoded.
+            "host" | "connection" | "accept-encoding" => {}
+            _ => headers.push((name, value)),
+        }
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    let response = runtime.block_on(async {
+        let mut request = client.request(method, format!("{origin}{path}"));
+        for (name, value) in headers {
+            request = request.header(name, value);
+        }
+        let response = request.body(body).send().await?;
+        let status = response.status();
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(ToString::to_string);
+        let bytes = response.bytes().await?;
+        anyhow::Ok((status, content_type, bytes))
+    });
+
+    match response {
+        Ok((status, content_type, bytes)) => write_response(
+            &mut stream,
+            status,
+            content_type
+                .as_deref()
+                .unwrap_or("application/octet-stream"),
+            &bytes,
+        ),
+        Err(e) => write_response(
+            &mut stream,
+            StatusCode::BAD_GATEWAY,
+            "text/plain",
+            format!("Failed to reach the Boltz API through the proxy: {e}").as_bytes(),
+        ),
+    }
+}
+
+fn write_response(
+    stream: &mut TcpStream,
+    status: StatusCode,
+    content_type: &str,
+    body: &[u8],
+) -> Result<()> {
+    let head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status.as_u16(),
+        status.canonical_reason().unwrap_or_default(),
+        body.len()
+    );
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(body)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::boltz_api_url;
+    use crate::model::Config;
+
+    #[test]
+    fn test_boltz_api_url_without_proxy() -> Result<()> {
+        let config = Config::testnet();
+        let boltz_url = config.get_boltz_url();
+        assert_eq!(boltz_api_url(&config, &boltz_url)?, boltz_url);
+        Ok(())
+    }
+
+    #[test]
+    fn test_boltz_api_url_with_proxy() -> Result<()> {
+        let config = Config {
+            proxy_url: Some("socks5h://127.0.0.1:9050".to_string()),
+            ..Config::testnet()
+        };
+        let boltz_url = config.get_boltz_url();
+        let relay_url = boltz_api_url(&config, &boltz_url)?;
+        assert!(relay_url.starts_with("http://127.0.0.1:"));
+        assert!(relay_url.ends_with("/v2"));
+
+        // The relay is reused for the same proxy and Boltz API
+        assert_eq!(boltz_api_url(&config, &boltz_url)?, relay_url);
+        Ok(())
+    }
+}
@@ -1,22 +1,52 @@
 #![cfg(test)]
 
+use std::sync::Mutex;
+
 use anyhow::Result;
 use async_trait::async_trait;
-use lwk_wollet::{bitcoin::consensus::deserialize, elements::hex::FromHex};
+use lwk_wollet::{
+    bitcoin::{self, consensus::deserialize},
+    elements::{self, hex::FromHex, Transaction},
+    History,
+};
 
 use crate::{
-    chain::{bitcoin::BitcoinChainService, liquid::LiquidChainService},
+    chain::{
+        bitcoin::BitcoinChainService,
+        liquid::{ChainChanges, LiquidChainService},
+    },
     prelude::RecommendedFees,
     utils,
 };
 
 #[derive(Default)]
-pub(crate) struct MockLiquidChainService {}
+pub(crate) struct MockLiquidChainService {
+    chain_changes: Option<ChainChanges>,
+    history: Vec<History>,
+    txs: Vec<Transaction>,
+}
 
 impl MockLiquidChainService {
     pub(crate) fn new() -> Self {
         MockLiquidChainService::default()
     }
+
+    /// Sets the changes returned by the next [LiquidChainService::poll_changes]
+    pub(crate) fn set_chain_changes(&mut self, chain_changes: ChainChanges) -> &mut Self {
+        self.chain_changes = Some(chain_changes);
+        self
+    }
+
+    /// Sets the history returned for any script
+    pub(crate) fn set_history(&mut self, history: Vec<History>) -> &mut Self {
+        self.history = history;
+        self
+    }
+
+    pub(crate) fn set_transactions(&mut self, txs: Vec<Transaction>) -> &mut Self {
+        self.txs = txs;
+        self
+    }
 }
 
 #[async_trait]
@@ -35,16 +65,34 @@ impl LiquidChainService for MockLiquidChainService {
 
     async fn get_transactions(
         &self,
-        _txids: &[lwk_wollet::elements::Txid],
+        txids: &[lwk_wollet::elements::Txid],
     ) -> Result<Vec<lwk_wollet::elements::Transaction>> {
-        unimplemented!()
+        Ok(self
+            .txs
+            .iter()
+            .filter(|tx| txids.contains(&tx.txid()))
+            .cloned()
+            .collect())
     }
 
     async fn get_script_history(
         &self,
         _scripts: &lwk_wollet::elements::Script,
     ) -> Result<Vec<lwk_wollet::History>> {
-        unimplemented!()
+        Ok(self.history.clone())
+    }
+
+    async fn get_script_utxo(
+        &self,
+        script: &elements::Script,
+    ) -> Result<(elements::OutPoint, elements::TxOut)> {
+        Ok((
+            elements::OutPoint::default(),
+            elements::TxOut {
+                script_pubkey: script.clone(),
+                ..Default::default()
+            },
+        ))
     }
 
     async fn verify_tx(
@@ -56,48 +104,112 @@ impl LiquidChainService for MockLiquidChainService {
     ) -> Result<lwk_wollet::elements::Transaction> {
         utils::deserialize_tx_hex(tx_hex)
     }
+
+    async fn poll_changes(
+        &mut self,
+        _scripts: &[lwk_wollet::elements::Script],
+    ) -> Result<ChainChanges> {
+        Ok(self.chain_changes.take().unwrap_or_default())
+    }
 }
 
-pub(crate) struct MockBitcoinChainService {}
+#[derive(Default)]
+pub(crate) struct MockBitcoinChainService {
+    txs: Vec<boltz_client::bitcoin::Transaction>,
+    recommended_fees: Option<RecommendedFees>,
+    script_balance: Option<(u64, i64)>,
+    history: Vec<History>,
+    pub(crate) broadcast_txs: Mutex<Vec<boltz_client::bitcoin::Transaction>>,
+}
 
 impl MockBitcoinChainService {
     pub(crate) fn new() -> Self {
-        MockBitcoinChainService {}
+        MockBitcoinChainService::default()
+    }
+
+    pub(crate) fn set_transactions(
+        &mut self,
+        txs: Vec<boltz_client::bitcoin::Transaction>,
+    ) -> &mut Self {
+        self.txs = txs;
+        self
+    }
+
+    /// Sets the history of every script
+    pub(crate) fn set_history(&mut self, history: Vec<History>) -> &mut Self {
+        self.history = history;
+        self
+    }
+
+    /// Sets the confirmed and unconfirmed balance of every script
+    pub(crate) fn set_script_balance(&mut self, confirmed: u64, unconfirmed: i64) -> &mut Self {
+        self.script_balance = Some((confirmed, unconfirmed));
+        self
+    }
+
+    pub(crate) fn set_recommended_fees(&mut self, recommended_fees: RecommendedFees) -> &mut Self {
+        self.recommended_fees = Some(recommended_fees);
+        self
     }
 }
 
 #[async_trait]
 impl BitcoinChainService for MockBitcoinChainService {
-    fn tip(&mut self) -> Result<electrum_client::HeaderNotification> {
+    async fn tip(&mut self) -> Result<electrum_client::HeaderNotification> {
         unimplemented!()
     }
 
-    fn broadcast(
+    async fn broadcast(
         &self,
-        _tx: &boltz_client::bitcoin::Transaction,
+        tx: &boltz_client::bitcoin::Transaction,
     ) -> Result<boltz_client::bitcoin::Txid, anyhow::Error> {
-        unimplemented!()
+        self.broadcast_txs.lock().unwrap().push(tx.clone());
+        Ok(tx.txid())
     }
 
-    fn get_transactions(
+    async fn get_transactions(
         &self,
-        _txids: &[boltz_client::bitcoin::Txid],
+        txids: &[boltz_client::bitcoin::Txid],
     ) -> Result<Vec<boltz_client::bitcoin::Transaction>> {
-        unimplemented!()
+        Ok(self
+            .txs
+            .iter()
+            .filter(|tx| txids.contains(&tx.txid()))
+            .cloned()
+            .collect())
     }
 
-    fn get_script_history(
+    async fn get_script_history(
         &self,
         _script: &boltz_client::bitcoin::Script,
     ) -> Result<Vec<lwk_wollet::History>> {
-        unimplemented!()
+        Ok(self.history.clone())
     }
 
-    fn script_get_balance(
+    async fn script_get_balance(
         &self,
         _script: &boltz_client::bitcoin::Script,
     ) -> Result<electrum_client::GetBalanceRes> {
-        unimplemented!()
+        let (confirmed, unconfirmed) = self
+            .script_balance
+            .ok_or(anyhow::anyhow!("No script balance set"))?;
+        Ok(electrum_client::GetBalanceRes {
+            confirmed,
+            unconfirmed,
+        })
+    }
+
+    async fn get_script_utxo(
+        &self,
+        script: &boltz_client::bitcoin::Script,
+    ) -> Result<(bitcoin::OutPoint, bitcoin::TxOut)> {
+        Ok((
+            bitcoin::OutPoint::null(),
+            bitcoin::TxOut {
+                value: bitcoin::Amount::ZERO,
+                script_pubkey: script.to_owned(),
+            },
+        ))
     }
 
     async fn verify_tx(
@@ -113,6 +225,8 @@ impl BitcoinChainService for MockBitcoinChainService {
     }
 
     async fn recommended_fees(&self) -> Result<RecommendedFees> {
-        unimplemented!()
+        self.recommended_fees
+            .clone()
+            .ok_or(anyhow::anyhow!("No recommended fees set"))
     }
 }
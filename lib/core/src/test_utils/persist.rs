@@ -8,7 +8,7 @@ use sdk_common::{
         secp256k1::{Secp256k1, SecretKey},
     },
     lightning::ln::PaymentSecret,
-    lightning_invoice::{Currency, InvoiceBuilder},
+    lightning_invoice::{Bolt11Invoice, Currency, InvoiceBuilder},
 };
 use tempdir::TempDir;
 
@@ -26,17 +26,26 @@ fn new_secret_key() -> SecretKey {
     SecretKey::from_slice(&buf).expect("Expected valid secret key")
 }
 
-pub(crate) fn new_send_swap(payment_state: Option<PaymentState>) -> SendSwap {
+pub(crate) fn new_invoice(amount_msat: Option<u64>) -> Bolt11Invoice {
     let private_key = new_secret_key();
 
-    let invoice = InvoiceBuilder::new(Currency::BitcoinTestnet)
+    let mut builder = InvoiceBuilder::new(Currency::BitcoinTestnet)
         .description("Test invoice".into())
         .payment_hash(sha256::Hash::from_slice(&[0; 32][..]).expect("Expecting valid hash"))
         .payment_secret(PaymentSecret([42u8; 32]))
         .current_timestamp()
-        .min_final_cltv_expiry_delta(144)
+        .min_final_cltv_expiry_delta(144);
+    if let Some(amount_msat) = amount_msat {
+        builder = builder.amount_milli_satoshis(amount_msat);
+    }
+
+    builder
         .build_signed(|hash| Secp256k1::new().sign_ecdsa_recoverable(hash, &private_key))
-        .expect("Expected valid invoice");
+        .expect("Expected valid invoice")
+}
+
+pub(crate) fn new_send_swap(payment_state: Option<PaymentState>) -> SendSwap {
+    let invoice = new_invoice(None);
 
     SendSwap {
         id: generate_random_string(4),
@@ -69,6 +78,9 @@ pub(crate) fn new_send_swap(payment_state: Option<PaymentState>) -> SendSwap {
         created_at: utils::now(),
         state: payment_state.unwrap_or(PaymentState::Created),
         refund_private_key: "945affeef55f12227f1d4a3f80a17062a05b229ddc5a01591eb5ddf882df92e3".to_string(),
+        idempotency_key: None,
+        bolt12_offer: None,
+        payer_note: None,
     }
 }
 
@@ -126,5 +138,6 @@ pub(crate) fn new_payment_tx_data(payment_type: PaymentType) -> PaymentTxData {
         fees_sat: 0,
         payment_type,
         is_confirmed: false,
+        asset_id: None,
     }
 }
@@ -23,6 +23,38 @@ pub(crate) fn new_liquid_sdk(
     persister: Arc<Persister>,
     swapper: Arc<MockSwapper>,
     status_stream: Arc<MockStatusStream>,
+) -> Result<LiquidSdk> {
+    new_liquid_sdk_with_wallet(
+        persister,
+        swapper,
+        status_stream,
+        Arc::new(MockWallet::new()),
+    )
+}
+
+pub(crate) fn new_liquid_sdk_with_wallet(
+    persister: Arc<Persister>,
+    swapper: Arc<MockSwapper>,
+    status_stream: Arc<MockStatusStream>,
+    onchain_wallet: Arc<MockWallet>,
+) -> Result<LiquidSdk> {
+    new_liquid_sdk_with_chain_services(
+        persister,
+        swapper,
+        status_stream,
+        Arc::new(Mutex::new(MockLiquidChainService::new())),
+        Arc::new(Mutex::new(MockBitcoinChainService::new())),
+        onchain_wallet,
+    )
+}
+
+pub(crate) fn new_liquid_sdk_with_chain_services(
+    persister: Arc<Persister>,
+    swapper: Arc<MockSwapper>,
+    status_stream: Arc<MockStatusStream>,
+    liquid_chain_service: Arc<Mutex<MockLiquidChainService>>,
+    bitcoin_chain_service: Arc<Mutex<MockBitcoinChainService>>,
+    onchain_wallet: Arc<MockWallet>,
 ) -> Result<LiquidSdk> {
     let mut config = Config::testnet();
     config.working_dir = persister
@@ -31,11 +63,6 @@ pub(crate) fn new_liquid_sdk(
         .ok_or(anyhow!("An invalid SDK directory was specified"))?
         .to_string();
 
-    let onchain_wallet = Arc::new(MockWallet::new());
-
-    let liquid_chain_service = Arc::new(Mutex::new(MockLiquidChainService::new()));
-    let bitcoin_chain_service = Arc::new(Mutex::new(MockBitcoinChainService::new()));
-
     let send_swap_state_handler = SendSwapStateHandler::new(
         config.clone(),
         onchain_wallet.clone(),
@@ -10,11 +10,13 @@ use boltz_client::{
     util::secrets::Preimage,
     PublicKey,
 };
+use lwk_wollet::{bitcoin, elements};
 use sdk_common::invoice::parse_invoice;
+use std::{collections::HashSet, sync::Mutex};
 
 use crate::{
     error::{PaymentError, SdkError},
-    model::{ChainSwap, Direction, ReceiveSwap, SendSwap},
+    model::{ChainSwap, Direction, ReceiveSwap, SendSwap, SwapTransaction, Utxo},
     swapper::Swapper,
     test_utils::generate_random_string,
     utils,
@@ -22,14 +24,69 @@ use crate::{
 
 use super::status_stream::MockStatusStream;
 
+/// A testnet offer of 50,000 sat
+pub(crate) const TEST_BOLT12_OFFER: &str = "lno1qgsyxjtl6luzd9t3pr62xr7eemp6awnejusgf6gw45q75vcfqqqqqqqgqsp04uyqpgg5ymmvwscnygr5v4ehggr0venx2uskyyp8ty42kh2rvxxa5ylm5u0rny7dw5t6wyknmfykvnqxacda850hptc";
+/// An invoice for [TEST_BOLT12_OFFER], with the payer note "Thanks"
+pub(crate) const TEST_BOLT12_INVOICE: &str = "lni1qqsyysjzgfpyysjzgfpyysjzgfpyysjzgfpyysjzgfpyysjzgfpyysszypp5jl7hlqnf2ugg7j3slkwwcwht57vhyzzwjr4dq84rxzgqqqqqqzqyqta0pqq2z9px7mr5xyezqar9wd6zqmmxvejhy93pqf6e92446smp3hdp87a8rcue8nt4z7n39576f9nycphwr0farac27kppqtjhgrnrhtfgpq0d0nm9fhtvrypfegpnst7qt26lthdgruk9twz9kkgx235xzmntwwsx5qhvd4ye4m74gr5sx4l3qp9pxczf68ma7kken3zvgm376stfus9vkcp8z4gwdjp6jwqlxhzk35dgpcgl50swljtal58q796f9ghdkexr02gpqgzcf7x6sjqqmytg9u3f6d6dkn8kwkahwgggmwfqphmx04420en4wqqyqypqxp9zrsqqqqqpqqqqqeqqjqqqqqqqqqqq86qqqqqqy4qtusqqqq9yq3j48ugq5cz0lllll75zpkj3wj07x8raanq3h2ulgs5ny7a8e55kwets5c3kaw3plyqz79aj4gzq97hsszczzqn4j24tt4pkrrw6z0a6w83ej0xh29a8ztfa5jtxfsrwux7n68ms4lcyphl9dq3gqmqzqmlnr0h02mzdq5wvua5fyusk9vc4gcm05ztnm7cpvltu8g3sqjkx9tpn8dkcm0quzk3mx049ck8545cspq26kzmq8q2q";
+
 #[derive(Default)]
-pub struct MockSwapper {}
+pub struct MockSwapper {
+    failing_refund_swap_ids: Mutex<HashSet<String>>,
+    chain_swap_quote: Mutex<Option<u64>>,
+    chain_pair_limits: Mutex<Option<(u64, u64)>>,
+    pub(crate) accepted_chain_swap_quotes: Mutex<Vec<(String, u64)>>,
+}
 
 impl MockSwapper {
     pub(crate) fn new() -> Self {
         MockSwapper::default()
     }
 
+    /// Sets the server lockup amount quoted for Chain swaps whose lockup failed. Until set, no
+    /// quote is available.
+    pub(crate) fn set_chain_swap_quote(&self, server_lockup_amount_sat: u64) {
+        *self.chain_swap_quote.lock().unwrap() = Some(server_lockup_amount_sat);
+    }
+
+    /// Sets the minimal and maximal amounts of the Chain pair, which are unlimited by default
+    pub(crate) fn set_chain_pair_limits(&self, minimal: u64, maximal: u64) {
+        *self.chain_pair_limits.lock().unwrap() = Some((minimal, maximal));
+    }
+
+    /// Makes the refunds of the given Chain swaps fail, as when their lock time has not elapsed
+    pub(crate) fn set_failing_refunds(&self, swap_ids: &[&str]) {
+        *self.failing_refund_swap_ids.lock().unwrap() =
+            swap_ids.iter().map(|id| id.to_string()).collect();
+    }
+
+    /// A tx spending the utxo, standing in for a signed claim or refund tx. Bitcoin txs pay the
+    /// given fees, so that a replacement has a different txid than the replaced tx.
+    fn mock_swap_tx(utxo: Utxo, fees_sat: u64) -> SwapTransaction {
+        match utxo {
+            Utxo::Bitcoin((outpoint, txout)) => SwapTransaction::Bitcoin(bitcoin::Transaction {
+                version: bitcoin::transaction::Version::TWO,
+                lock_time: bitcoin::absolute::LockTime::ZERO,
+                input: vec![bitcoin::TxIn {
+                    previous_output: outpoint,
+                    ..Default::default()
+                }],
+                output: vec![bitcoin::TxOut {
+                    value: bitcoin::Amount::from_sat(txout.value.to_sat().saturating_sub(fees_sat)),
+                    script_pubkey: bitcoin::ScriptBuf::new(),
+                }],
+            }),
+            Utxo::Liquid(utxo) => SwapTransaction::Liquid(elements::Transaction {
+                version: 2,
+                lock_time: elements::LockTime::ZERO,
+                input: vec![elements::TxIn {
+                    previous_output: utxo.0,
+                    ..Default::default()
+                }],
+                output: vec![],
+            }),
+        }
+    }
+
     fn mock_swap_tree() -> SwapTree {
         SwapTree {
             claim_leaf: Leaf {
@@ -105,12 +162,17 @@ impl Swapper for MockSwapper {
         &self,
         _direction: Direction,
     ) -> anyhow::Result<Option<ChainPair>, PaymentError> {
+        let (minimal, maximal) = self
+            .chain_pair_limits
+            .lock()
+            .unwrap()
+            .unwrap_or((0, std::u64::MAX));
         Ok(Some(ChainPair {
             hash: generate_random_string(10),
             rate: 0.0,
             limits: PairLimits {
-                maximal: std::u64::MAX,
-                minimal: 0,
+                maximal,
+                minimal,
                 maximal_zero_conf: 100_000,
             },
             fees: ChainFees {
@@ -165,51 +227,87 @@ impl Swapper for MockSwapper {
         }))
     }
 
+    fn get_chain_swap_quote(&self, _swap_id: &str) -> Result<u64, PaymentError> {
+        self.chain_swap_quote
+            .lock()
+            .unwrap()
+            .ok_or(PaymentError::Generic {
+                err: "No quote available".to_string(),
+            })
+    }
+
+    fn accept_chain_swap_quote(
+        &self,
+        swap_id: &str,
+        server_lockup_amount_sat: u64,
+    ) -> Result<(), PaymentError> {
+        self.accepted_chain_swap_quotes
+            .lock()
+            .unwrap()
+            .push((swap_id.to_string(), server_lockup_amount_sat));
+        Ok(())
+    }
+
     fn prepare_chain_swap_refund(
         &self,
-        _swap: &ChainSwap,
+        swap: &ChainSwap,
         _output_address: &str,
         _sat_per_vbyte: f32,
+        _lockup_utxo: &Utxo,
     ) -> Result<(u32, u64), SdkError> {
+        if self
+            .failing_refund_swap_ids
+            .lock()
+            .unwrap()
+            .contains(&swap.id)
+        {
+            return Err(SdkError::Generic {
+                err: "Swap lock time has not elapsed".to_string(),
+            });
+        }
         Ok((0, 0))
     }
 
-    fn refund_chain_swap_cooperative(
+    fn create_chain_swap_refund_cooperative(
         &self,
         _swap: &ChainSwap,
+        lockup_utxo: Utxo,
         _output_address: &str,
-        _broadcast_fees_sat: u64,
-    ) -> Result<String, PaymentError> {
-        Ok("refund-tx-id".to_string())
+        broadcast_fees_sat: u64,
+    ) -> Result<SwapTransaction, PaymentError> {
+        Ok(Self::mock_swap_tx(lockup_utxo, broadcast_fees_sat))
     }
 
-    fn refund_send_swap_cooperative(
+    fn create_send_swap_refund_cooperative(
         &self,
         _swap: &SendSwap,
+        lockup_utxo: Utxo,
         _output_address: &str,
-        _broadcast_fees_sat: u64,
-    ) -> Result<String, PaymentError> {
-        Ok("refund-tx-id".to_string())
+        broadcast_fees_sat: u64,
+    ) -> Result<elements::Transaction, PaymentError> {
+        Ok(Self::mock_swap_tx(lockup_utxo, broadcast_fees_sat).as_liquid()?)
     }
 
-    fn refund_chain_swap_non_cooperative(
+    fn create_chain_swap_refund_non_cooperative(
         &self,
         _swap: &ChainSwap,
-        _broadcast_fees_sat: u64,
+        lockup_utxo: Utxo,
+        broadcast_fees_sat: u64,
         _output_address: &str,
         _current_height: u32,
-    ) -> Result<String, PaymentError> {
-        Ok("refund-tx-id".to_string())
+    ) -> Result<SwapTransaction, PaymentError> {
+        Ok(Self::mock_swap_tx(lockup_utxo, broadcast_fees_sat))
     }
 
-    fn refund_send_swap_non_cooperative(
+    fn create_send_swap_refund_non_cooperative(
         &self,
         _swap: &SendSwap,
-        _broadcast_fees_sat: u64,
+        lockup_utxo: Utxo,
+        broadcast_fees_sat: u64,
         _output_address: &str,
         _current_height: u32,
-    ) -> Result<String, PaymentError> {
-        Ok("refund-tx-id".to_string())
+    ) -> Result<elements::Transaction, PaymentError> {
+        Ok(Self::mock_swap_tx(lockup_utxo, broadcast_fees_sat).as_liquid()?)
     }
 
     fn get_send_claim_tx_details(
@@ -226,8 +324,13 @@ impl Swapper for MockSwapper {
         })
     }
 
-    fn claim_chain_swap(&self, _swap: &ChainSwap) -> Result<String, PaymentError> {
-        Ok("chain-swap-claim-txid".to_string())
+    fn create_chain_swap_claim(
+        &self,
+        swap: &ChainSwap,
+        claim_utxo: Utxo,
+        _user_lockup_utxo: Utxo,
+    ) -> Result<SwapTransaction, PaymentError> {
+        Ok(Self::mock_swap_tx(claim_utxo, swap.claim_fees_sat))
     }
 
     fn claim_send_swap_cooperative(
@@ -235,6 +338,7 @@ impl Swapper for MockSwapper {
         _swap: &SendSwap,
         _claim_tx_response: boltz_client::swaps::boltz::SubmarineClaimTxResponse,
         _refund_address: &str,
+        _lockup_utxo: Utxo,
     ) -> Result<(), PaymentError> {
         Ok(())
     }
@@ -273,12 +377,13 @@ impl Swapper for MockSwapper {
         }))
     }
 
-    fn claim_receive_swap(
+    fn create_receive_swap_claim(
         &self,
-        _swap: &ReceiveSwap,
+        swap: &ReceiveSwap,
         _claim_address: String,
-    ) -> Result<String, PaymentError> {
-        Ok("mock-tx-id".to_string())
+        lockup_utxo: Utxo,
+    ) -> Result<elements::Transaction, PaymentError> {
+        Ok(Self::mock_swap_tx(lockup_utxo, swap.claim_fees_sat).as_liquid()?)
     }
 
     fn broadcast_tx(
@@ -297,6 +402,15 @@ impl Swapper for MockSwapper {
         Box::new(MockStatusStream::new())
     }
 
+    fn get_bolt12_invoice(
+        &self,
+        _offer: &str,
+        _amount_sat: u64,
+        _payer_note: Option<String>,
+    ) -> Result<String, PaymentError> {
+        Ok(TEST_BOLT12_INVOICE.to_string())
+    }
+
     fn check_for_mrh(&self, _invoice: &str) -> Result<Option<(String, f64)>, PaymentError> {
         // Ok(Some(("".to_string(), 0.0)))
         unimplemented!()
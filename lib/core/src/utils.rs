@@ -2,19 +2,19 @@ use std::str::FromStr;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::error::{PaymentError, SdkResult};
+use crate::model::{SwapScriptV2, SwapTxV2, Utxo};
 use crate::prelude::{
     Config, LiquidNetwork, SendSwap, LOWBALL_FEE_RATE_SAT_PER_VBYTE,
     STANDARD_FEE_RATE_SAT_PER_VBYTE,
 };
+use crate::swapper::relay::boltz_api_url;
 use anyhow::{anyhow, Result};
-use boltz_client::boltz::{
-    BoltzApiClientV2, Cooperative, BOLTZ_MAINNET_URL_V2, BOLTZ_TESTNET_URL_V2,
-};
-use boltz_client::network::electrum::ElectrumConfig;
+use boltz_client::boltz::{BoltzApiClientV2, Cooperative, SwapTxKind};
 use boltz_client::Amount;
 use lwk_wollet::elements::encode::deserialize;
 use lwk_wollet::elements::hex::FromHex;
 use lwk_wollet::elements::{
+    BlockHash,
     LockTime::{self, *},
     Transaction,
 };
@@ -59,33 +59,41 @@ pub(crate) fn deserialize_tx_hex(tx_hex: &str) -> Result<Transaction> {
     )?)?)
 }
 
+/// The hash of the genesis block of the Liquid chain, which the signatures of its txs commit to
+pub(crate) fn liquid_genesis_hash(network: LiquidNetwork) -> Result<BlockHash> {
+    let genesis_hash = match network {
+        LiquidNetwork::Mainnet => {
+            "1466275836220db2944ca059a3a10ef6fd2ea684b0688d2c379296888a206003"
+        }
+        LiquidNetwork::Testnet => {
+            "a771da8e52ee6ad581ed1e9a99825e5b3b7992225534eaa2ae23244fe26ab1c1"
+        }
+        LiquidNetwork::Regtest => {
+            "00902a6b70c2ca83b5d9c815d96a0e2f4202179316970d14ea1847dae5b1ca21"
+        }
+    };
+    Ok(BlockHash::from_str(genesis_hash)?)
+}
+
 pub(crate) fn estimate_refund_fees(
     swap: &SendSwap,
     config: &Config,
     output_address: &str,
+    lockup_utxo: &Utxo,
     is_cooperative: bool,
 ) -> Result<u64, PaymentError> {
-    let swap_script = swap.get_swap_script()?;
-    let electrum_config = ElectrumConfig::new(
-        config.network.into(),
-        &config.liquid_electrum_url,
-        true,
-        true,
-        100,
-    );
-    let swap_tx = boltz_client::LBtcSwapTx::new_refund(
+    let swap_script = SwapScriptV2::Liquid(swap.get_swap_script()?);
+    let swap_tx = SwapTxV2::new(
+        SwapTxKind::Refund,
         swap_script,
-        &output_address.to_string(),
-        &electrum_config,
-        config.liquid_electrum_url.clone(),
-        swap.id.clone(),
-    )?;
+        output_address,
+        lockup_utxo.clone(),
+        config.network,
+    )?
+    .as_liquid_tx()?;
     let dummy_fees = Amount::from_sat(100);
 
-    let boltz_api = &BoltzApiClientV2::new(match config.network {
-        LiquidNetwork::Mainnet => BOLTZ_MAINNET_URL_V2,
-        LiquidNetwork::Testnet => BOLTZ_TESTNET_URL_V2,
-    });
+    let boltz_api = &BoltzApiClientV2::new(&boltz_api_url(config, &config.get_boltz_url())?);
 
     let (fee_rate, cooperative) = match (config.network, is_cooperative) {
         (LiquidNetwork::Mainnet, true) => (
@@ -97,7 +105,7 @@ pub(crate) fn estimate_refund_fees(
                 partial_sig: None,
             }),
         ),
-        (LiquidNetwork::Testnet, true) => (
+        (LiquidNetwork::Testnet | LiquidNetwork::Regtest, true) => (
             STANDARD_FEE_RATE_SAT_PER_VBYTE,
             Some(Cooperative {
                 boltz_api,
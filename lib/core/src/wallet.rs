@@ -1,25 +1,29 @@
-use std::{str::FromStr, sync::Arc};
+use std::{collections::HashSet, str::FromStr, sync::Arc};
 
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use boltz_client::ElementsAddress;
-use lwk_common::Signer;
-use lwk_common::{singlesig_desc, Singlesig};
-use lwk_signer::{AnySigner, SwSigner};
 use lwk_wollet::{
-    elements::{Address, Transaction},
-    ElectrumClient, ElectrumUrl, ElementsNetwork, FsPersister, Tip, WalletTx, Wollet,
-    WolletDescriptor,
+    bitcoin::bip32::Xpub,
+    elements::{pset::PartiallySignedTransaction, Address, AssetId, Script, Transaction},
+    hashes::{sha256, Hash},
+    BlockchainBackend, ElectrumClient, ElectrumUrl, ElementsNetwork, EsploraClient, FsPersister,
+    Tip, WalletTx, Wollet, WolletDescriptor,
 };
-use sdk_common::bitcoin::secp256k1::Secp256k1;
-use sdk_common::bitcoin::util::bip32::{ChildNumber, ExtendedPrivKey};
+use sdk_common::bitcoin::secp256k1::{ecdsa::Signature, Message};
+use sdk_common::bitcoin::util::bip32::{ChildNumber, DerivationPath, ExtendedPubKey};
 use tokio::sync::Mutex;
 
 use crate::{
+    chain::liquid::ElectrumBlockchainBackend,
     error::PaymentError,
-    model::{Config, LiquidNetwork},
+    model::{ChainBackend, Config, LiquidNetwork},
+    signer::{get_fingerprint, Signer},
 };
 
+/// The number of consecutive unused addresses after which the LWK wallet scan stops
+const SCAN_GAP_LIMIT: u32 = 20;
+
 #[async_trait]
 pub trait OnchainWallet: Send + Sync {
     /// List all transactions in the wallet
@@ -33,59 +37,165 @@ pub trait OnchainWallet: Send + Sync {
         amount_sat: u64,
     ) -> Result<Transaction, PaymentError>;
 
+    /// Build a transaction to send a Liquid asset other than L-BTC to a recipient. The fees are
+    /// paid in L-BTC.
+    async fn build_asset_tx(
+        &self,
+        fee_rate: Option<f32>,
+        recipient_address: &str,
+        amount: u64,
+        asset_id: &str,
+    ) -> Result<Transaction, PaymentError>;
+
+    /// Build a transaction to send all L-BTC funds to a recipient, leaving no change.
+    ///
+    /// If `enforce_amount_sat` is set, the tx is only returned if the recipient receives exactly
+    /// this amount after fees.
+    async fn build_drain_tx(
+        &self,
+        fee_rate: Option<f32>,
+        recipient_address: &str,
+        enforce_amount_sat: Option<u64>,
+    ) -> Result<Transaction, PaymentError>;
+
+    /// Get the L-BTC balance of the wallet UTXOs, which is what a drain tx spends
+    async fn balance_sat(&self) -> Result<u64, PaymentError>;
+
     /// Get the next unused address in the wallet
     async fn next_unused_address(&self) -> Result<Address, PaymentError>;
 
+    /// Get an unused address in the wallet at the given derivation index or after it, along with
+    /// its index. The index is kept within the gap limit of the wallet scan, so that the payments
+    /// to the address are found, and wraps around to the next unused address beyond it.
+    async fn next_unused_address_from(&self, index: u32) -> Result<(Address, u32), PaymentError>;
+
+    /// Get the scripts to watch for wallet changes: those of the wallet outputs and the one of
+    /// the next unused address
+    async fn scripts(&self) -> Result<Vec<Script>, PaymentError>;
+
     /// Get the current tip of the blockchain the wallet is aware of
     async fn tip(&self) -> Tip;
 
-    /// Get the public key of the wallet
+    /// Get the public key of the wallet. In watch-only mode, this is the wallet id, as the
+    /// descriptor contains the master blinding key.
     fn pubkey(&self) -> String;
 
-    fn derive_bip32_key(&self, path: Vec<ChildNumber>) -> Result<ExtendedPrivKey, PaymentError>;
+    /// Whether the wallet was created from a descriptor only, without any signer
+    fn is_watch_only(&self) -> bool;
+
+    /// Derives the xpub at the given path
+    fn derive_bip32_pub_key(&self, path: Vec<ChildNumber>) -> Result<ExtendedPubKey, PaymentError>;
+
+    /// Signs the message digest with the key at the given path
+    fn sign_ecdsa(&self, msg: &Message, path: Vec<ChildNumber>) -> Result<Signature, PaymentError>;
+
+    /// Computes the HMAC-SHA256 of the message, keyed with the private key at the given path
+    fn hmac_sha256(&self, msg: &[u8], path: Vec<ChildNumber>) -> Result<Vec<u8>, PaymentError>;
 
     /// Perform a full scan of the wallet
     async fn full_scan(&self) -> Result<(), PaymentError>;
+
+    /// Apply a tx found for one of the wallet scripts, without waiting for the next full scan
+    async fn apply_tx(&self, tx: Transaction) -> Result<(), PaymentError>;
 }
 
 pub(crate) struct LiquidOnchainWallet {
     wallet: Arc<Mutex<Wollet>>,
     config: Config,
-    pub(crate) lwk_signer: SwSigner,
+    /// The wallet signer, not set in watch-only mode
+    signer: Option<Arc<dyn Signer>>,
+    /// Identifies the wallet data in the working dir
+    pub(crate) wallet_id: String,
+    /// The master public key, or the wallet id in watch-only mode
+    pubkey: String,
 }
 
 impl LiquidOnchainWallet {
-    pub(crate) fn new(mnemonic: String, config: Config) -> Result<Self> {
-        let is_mainnet = config.network == LiquidNetwork::Mainnet;
-        let lwk_signer = SwSigner::new(&mnemonic, is_mainnet)?;
-        let descriptor = LiquidOnchainWallet::get_descriptor(&lwk_signer, config.network)?;
-        let elements_network: ElementsNetwork = config.network.into();
+    pub(crate) fn new(signer: Arc<dyn Signer>, config: Config) -> Result<Self> {
+        let descriptor = LiquidOnchainWallet::get_descriptor(signer.as_ref(), config.network)?;
+        let wallet_id = get_fingerprint(signer.as_ref())?;
+        let pubkey = Xpub::from_str(&signer.xpub()?)?.public_key.to_string();
+        let wollet = Self::create_wollet(&config, &wallet_id, descriptor)?;
+        Ok(Self {
+            wallet: Arc::new(Mutex::new(wollet)),
+            signer: Some(signer),
+            wallet_id,
+            pubkey,
+            config,
+        })
+    }
 
+    /// Creates a read-only wallet from a CT descriptor. The wallet can be synced and list its
+    /// transactions, but cannot sign.
+    pub(crate) fn new_watch_only(descriptor: String, config: Config) -> Result<Self> {
+        let descriptor = descriptor.trim().to_string();
+        let wollet_descriptor: WolletDescriptor = descriptor
+            .parse()
+            .map_err(|e| anyhow!("Invalid watch-only descriptor: {e:?}"))?;
+        let descriptor_hash = sha256::Hash::hash(descriptor.as_bytes()).to_string();
+        let wallet_id = format!("watch-only-{}", &descriptor_hash[..8]);
+        let wollet = Self::create_wollet(&config, &wallet_id, wollet_descriptor)?;
+        Ok(Self {
+            wallet: Arc::new(Mutex::new(wollet)),
+            signer: None,
+            pubkey: wallet_id.clone(),
+            wallet_id,
+            config,
+        })
+    }
+
+    fn create_wollet(
+        config: &Config,
+        wallet_id: &str,
+        descriptor: WolletDescriptor,
+    ) -> Result<Wollet> {
+        let elements_network: ElementsNetwork = config.network.into();
         let lwk_persister = FsPersister::new(
-            config.get_wallet_working_dir(&lwk_signer)?,
+            config.get_wallet_working_dir(wallet_id)?,
             elements_network,
             &descriptor,
         )?;
-        let wollet = Wollet::new(elements_network, lwk_persister, descriptor)?;
-        Ok(Self {
-            wallet: Arc::new(Mutex::new(wollet)),
-            lwk_signer,
-            config,
+        Ok(Wollet::new(elements_network, lwk_persister, descriptor)?)
+    }
+
+    fn get_signer(&self) -> Result<&Arc<dyn Signer>, PaymentError> {
+        self.signer.as_ref().ok_or(PaymentError::WatchOnly)
+    }
+
+    fn sign_pset(
+        &self,
+        pset: PartiallySignedTransaction,
+    ) -> Result<PartiallySignedTransaction, PaymentError> {
+        let signed_pset = self.get_signer()?.sign_pset(pset.to_string())?;
+        PartiallySignedTransaction::from_str(&signed_pset).map_err(|e| PaymentError::SignerError {
+            err: format!("Signer returned an invalid PSET: {e:?}"),
         })
     }
 
+    fn lbtc_balance_sat(&self, lwk_wollet: &Wollet) -> Result<u64, PaymentError> {
+        let policy_asset = ElementsNetwork::from(self.config.network).policy_asset();
+        Ok(lwk_wollet
+            .balance()?
+            .get(&policy_asset)
+            .copied()
+            .unwrap_or_default())
+    }
+
+    /// Builds the single-sig P2WPKH descriptor with SLIP-77 blinding, as used by Liquid wallets
     fn get_descriptor(
-        signer: &SwSigner,
+        signer: &dyn Signer,
         network: LiquidNetwork,
     ) -> Result<WolletDescriptor, PaymentError> {
-        let is_mainnet = network == LiquidNetwork::Mainnet;
-        let descriptor_str = singlesig_desc(
-            signer,
-            Singlesig::Wpkh,
-            lwk_common::DescriptorBlindingKey::Slip77,
-            is_mainnet,
-        )
-        .map_err(|e| anyhow!("Invalid descriptor: {e}"))?;
+        let coin_type = match network {
+            LiquidNetwork::Mainnet => 1776,
+            LiquidNetwork::Testnet | LiquidNetwork::Regtest => 1,
+        };
+        let fingerprint = get_fingerprint(signer)?;
+        let xpub = signer.derive_xpub(format!("m/84h/{coin_type}h/0h"))?;
+        let blinding_key = signer.slip77_master_blinding_key()?;
+        let descriptor_str = format!(
+            "ct(slip77({blinding_key}),elwpkh([{fingerprint}/84h/{coin_type}h/0h]{xpub}/<0;1>/*))"
+        );
         Ok(descriptor_str.parse()?)
     }
 }
@@ -108,7 +218,7 @@ impl OnchainWallet for LiquidOnchainWallet {
         amount_sat: u64,
     ) -> Result<Transaction, PaymentError> {
         let lwk_wollet = self.wallet.lock().await;
-        let mut pset = lwk_wollet::TxBuilder::new(self.config.network.into())
+        let pset = lwk_wollet::TxBuilder::new(self.config.network.into())
             .add_lbtc_recipient(
                 &ElementsAddress::from_str(recipient_address).map_err(|e| {
                     PaymentError::Generic {
@@ -121,45 +231,185 @@ impl OnchainWallet for LiquidOnchainWallet {
             )?
             .fee_rate(fee_rate)
             .finish(&lwk_wollet)?;
-        let signer = AnySigner::Software(self.lwk_signer.clone());
-        signer.sign(&mut pset)?;
+        let mut pset = self.sign_pset(pset)?;
+        Ok(lwk_wollet.finalize(&mut pset)?)
+    }
+
+    /// Build a transaction to send a Liquid asset other than L-BTC to a recipient
+    async fn build_asset_tx(
+        &self,
+        fee_rate: Option<f32>,
+        recipient_address: &str,
+        amount: u64,
+        asset_id: &str,
+    ) -> Result<Transaction, PaymentError> {
+        let asset_id = AssetId::from_str(asset_id).map_err(|e| PaymentError::Generic {
+            err: format!("Asset id {asset_id} is not valid: {e:?}"),
+        })?;
+        let lwk_wollet = self.wallet.lock().await;
+        let pset = lwk_wollet::TxBuilder::new(self.config.network.into())
+            .add_recipient(
+                &ElementsAddress::from_str(recipient_address).map_err(|e| {
+                    PaymentError::Generic {
+                        err: format!(
+                            "Recipient address {recipient_address} is not a valid ElementsAddress: {e:?}"
+                        ),
+                    }
+                })?,
+                amount,
+                asset_id,
+            )?
+            .fee_rate(fee_rate)
+            .finish(&lwk_wollet)?;
+        let mut pset = self.sign_pset(pset)?;
         Ok(lwk_wollet.finalize(&mut pset)?)
     }
 
+    /// Build a transaction to send all L-BTC funds to a recipient, leaving no change
+    async fn build_drain_tx(
+        &self,
+        fee_rate: Option<f32>,
+        recipient_address: &str,
+        enforce_amount_sat: Option<u64>,
+    ) -> Result<Transaction, PaymentError> {
+        let lwk_wollet = self.wallet.lock().await;
+        let pset = lwk_wollet::TxBuilder::new(self.config.network.into())
+            .drain_lbtc_wallet()
+            .drain_lbtc_to(ElementsAddress::from_str(recipient_address).map_err(|e| {
+                PaymentError::Generic {
+                    err: format!(
+                        "Recipient address {recipient_address} is not a valid ElementsAddress: {e:?}"
+                    ),
+                }
+            })?)
+            .fee_rate(fee_rate)
+            .finish(&lwk_wollet)?;
+        let mut pset = self.sign_pset(pset)?;
+        let tx = lwk_wollet.finalize(&mut pset)?;
+
+        if let Some(enforce_amount_sat) = enforce_amount_sat {
+            let balance_sat = self.lbtc_balance_sat(&lwk_wollet)?;
+            let fees_sat: u64 = tx.all_fees().values().sum();
+            let drain_amount_sat = balance_sat.saturating_sub(fees_sat);
+            if drain_amount_sat != enforce_amount_sat {
+                return Err(PaymentError::Generic {
+                    err: format!(
+                        "Drain amount {drain_amount_sat} sat does not match the expected amount {enforce_amount_sat} sat"
+                    ),
+                });
+            }
+        }
+
+        Ok(tx)
+    }
+
+    /// Get the L-BTC balance of the wallet UTXOs, which is what a drain tx spends
+    async fn balance_sat(&self) -> Result<u64, PaymentError> {
+        self.lbtc_balance_sat(&*self.wallet.lock().await)
+    }
+
     /// Get the next unused address in the wallet
     async fn next_unused_address(&self) -> Result<Address, PaymentError> {
         Ok(self.wallet.lock().await.address(None)?.address().clone())
     }
 
+    /// Get an unused address in the wallet at the given derivation index or after it, along with
+    /// its index
+    async fn next_unused_address_from(&self, index: u32) -> Result<(Address, u32), PaymentError> {
+        let wallet = self.wallet.lock().await;
+        let next_unused = wallet.address(None)?;
+        let gap_end = next_unused.index() + SCAN_GAP_LIMIT;
+        let address = match index > next_unused.index() && index < gap_end {
+            true => wallet.address(Some(index))?,
+            // Either used already or beyond the gap limit
+            false => next_unused,
+        };
+        Ok((address.address().clone(), address.index()))
+    }
+
+    /// Get the scripts to watch for wallet changes: those of the wallet outputs and the one of
+    /// the next unused address
+    async fn scripts(&self) -> Result<Vec<Script>, PaymentError> {
+        let wallet = self.wallet.lock().await;
+        let mut scripts: HashSet<Script> = wallet
+            .transactions()?
+            .into_iter()
+            .flat_map(|tx| tx.outputs.into_iter().flatten())
+            .map(|output| output.script_pubkey)
+            .collect();
+        scripts.insert(wallet.address(None)?.address().script_pubkey());
+        Ok(scripts.into_iter().collect())
+    }
+
     /// Get the current tip of the blockchain the wallet is aware of
     async fn tip(&self) -> Tip {
         self.wallet.lock().await.tip()
     }
 
-    /// Get the public key of the wallet
+    /// Get the public key of the wallet. In watch-only mode, this is the wallet id.
     fn pubkey(&self) -> String {
-        self.lwk_signer.xpub().public_key.to_string()
+        self.pubkey.clone()
+    }
+
+    fn is_watch_only(&self) -> bool {
+        self.signer.is_none()
     }
 
     /// Perform a full scan of the wallet
     async fn full_scan(&self) -> Result<(), PaymentError> {
         let mut wallet = self.wallet.lock().await;
-        let mut electrum_client = ElectrumClient::new(&ElectrumUrl::new(
-            &self.config.liquid_electrum_url,
-            true,
-            true,
-        ))?;
-        lwk_wollet::full_scan_with_electrum_client(&mut wallet, &mut electrum_client)?;
+        match (&self.config.proxy_url, self.config.chain_backend) {
+            // The LWK clients cannot connect through a proxy, so the wallet is then scanned over
+            // the proxied Electrum client, whatever the chain backend
+            (Some(_), _) => {
+                let mut electrum_backend = ElectrumBlockchainBackend::new(&self.config)?;
+                if let Some(update) = electrum_backend.full_scan(&wallet)? {
+                    wallet.apply_update(update)?;
+                }
+            }
+            (None, ChainBackend::Electrum) => {
+                let mut electrum_client = ElectrumClient::new(&ElectrumUrl::new(
+                    &self.config.liquid_electrum_url,
+                    self.config.electrum_tls(),
+                    self.config.electrum_tls(),
+                ))?;
+                lwk_wollet::full_scan_with_electrum_client(&mut wallet, &mut electrum_client)?;
+            }
+            (None, ChainBackend::Esplora) => {
+                let mut esplora_client = EsploraClient::new(&self.config.liquid_esplora_url);
+                if let Some(update) = esplora_client.full_scan(&wallet)? {
+                    wallet.apply_update(update)?;
+                }
+            }
+        }
         Ok(())
     }
 
-    fn derive_bip32_key(&self, path: Vec<ChildNumber>) -> Result<ExtendedPrivKey, PaymentError> {
-        let seed = self.lwk_signer.seed().ok_or(PaymentError::SignerError {
-            err: "Could not get signer seed".to_string(),
-        })?;
+    async fn apply_tx(&self, tx: Transaction) -> Result<(), PaymentError> {
+        self.wallet.lock().await.apply_transaction(tx)?;
+        Ok(())
+    }
+
+    fn derive_bip32_pub_key(&self, path: Vec<ChildNumber>) -> Result<ExtendedPubKey, PaymentError> {
+        let xpub = self
+            .get_signer()?
+            .derive_xpub(DerivationPath::from(path).to_string())?;
+        Ok(ExtendedPubKey::from_str(&xpub)?)
+    }
+
+    fn sign_ecdsa(&self, msg: &Message, path: Vec<ChildNumber>) -> Result<Signature, PaymentError> {
+        let signature = self.get_signer()?.sign_ecdsa(
+            msg.as_ref().to_vec(),
+            DerivationPath::from(path).to_string(),
+        )?;
+        Signature::from_der(&signature).map_err(|e| PaymentError::SignerError {
+            err: format!("Invalid signature: {e}"),
+        })
+    }
 
-        let bip32_xpriv = ExtendedPrivKey::new_master(self.config.network.into(), &seed)?
-            .derive_priv(&Secp256k1::new(), &path)?;
-        Ok(bip32_xpriv)
+    fn hmac_sha256(&self, msg: &[u8], path: Vec<ChildNumber>) -> Result<Vec<u8>, PaymentError> {
+        Ok(self
+            .get_signer()?
+            .hmac_sha256(msg.to_vec(), DerivationPath::from(path).to_string())?)
     }
 }
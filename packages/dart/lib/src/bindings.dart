@@ -21,6 +21,21 @@ Future<BindingLiquidSdk> connect({required ConnectRequest req}) =>
 /// If used, this must be called before `connect`. It can only be called once.
 Stream<LogEntry> breezLogStream() => RustLib.instance.api.crateBindingsBreezLogStream();
 
+/// Connects with a signer implemented by the app, see [LiquidSdk::connect_with_signer].
+///
+/// A Dart object cannot implement the Rust signer trait, so unlike in the Uniffi bindings the
+/// signer is not passed as an argument. Instead, the app answers the requests of the
+/// [signer_request_stream] with [respond_to_signer_request], which has to be set up first.
+Future<BindingLiquidSdk> connectWithSigner({required ConnectRequest req}) =>
+    RustLib.instance.api.crateBindingsConnectWithSigner(req: req);
+
+/// The requests to the signer of `connect_with_signer`. If used, this must be called before
+/// `connect_with_signer`. It can only be called once.
+Stream<SignerRequest> signerRequestStream() => RustLib.instance.api.crateBindingsSignerRequestStream();
+
+void respondToSignerRequest({required int id, required SignerResult result}) =>
+    RustLib.instance.api.crateBindingsRespondToSignerRequest(id: id, result: result);
+
 Config defaultConfig({required LiquidNetwork network}) =>
     RustLib.instance.api.crateBindingsDefaultConfig(network: network);
 
@@ -29,30 +44,49 @@ Future<InputType> parse({required String input}) => RustLib.instance.api.crateBi
 LNInvoice parseInvoice({required String input}) =>
     RustLib.instance.api.crateBindingsParseInvoice(input: input);
 
+LNOffer parseOffer({required String input}) => RustLib.instance.api.crateBindingsParseOffer(input: input);
+
 // Rust type: RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>
 abstract class BindingLiquidSdk implements RustOpaqueInterface {
+  Future<void> acceptPaymentProposedFees({required AcceptPaymentProposedFeesRequest req});
+
   Stream<SdkEvent> addEventListener();
 
   void backup({required BackupRequest req});
 
+  Future<BumpRefundFeeResponse> bumpRefundFee({required BumpRefundFeeRequest req});
+
   Future<String> buyBitcoin({required BuyBitcoinRequest req});
 
+  Future<void> deletePaymentMetadata({required DeletePaymentMetadataRequest req});
+
   Future<void> disconnect();
 
   void emptyWalletCache();
 
+  Future<void> exportPayments({required ExportPaymentsRequest req});
+
   Future<List<Rate>> fetchFiatRates();
 
   Future<LightningPaymentLimitsResponse> fetchLightningLimits();
 
   Future<OnchainPaymentLimitsResponse> fetchOnchainLimits();
 
+  Future<FetchPaymentProposedFeesResponse> fetchPaymentProposedFees(
+      {required FetchPaymentProposedFeesRequest req});
+
   Future<GetInfoResponse> getInfo();
 
+  Future<Payment?> getPayment({required PaymentLookup req});
+
+  Future<List<PaymentMetadata>> getPaymentMetadata({required String paymentId});
+
   Future<List<FiatCurrency>> listFiatCurrencies();
 
   Future<List<Payment>> listPayments({required ListPaymentsRequest req});
 
+  Future<ListPaymentsResponse> listPaymentsPage({required ListPaymentsRequest req});
+
   Future<List<RefundableSwap>> listRefundables();
 
   Future<LnUrlCallbackStatus> lnurlAuth({required LnUrlAuthRequestData reqData});
@@ -73,8 +107,12 @@ abstract class BindingLiquidSdk implements RustOpaqueInterface {
 
   Future<PrepareRefundResponse> prepareRefund({required PrepareRefundRequest req});
 
+  Future<PrepareSendLiquidResponse> prepareSendLiquid({required PrepareSendLiquidRequest req});
+
   Future<PrepareSendResponse> prepareSendPayment({required PrepareSendRequest req});
 
+  Future<ReceiveLiquidResponse> receiveLiquid({required ReceiveLiquidRequest req});
+
   Future<ReceiveOnchainResponse> receiveOnchain({required PrepareReceiveOnchainResponse req});
 
   Future<ReceivePaymentResponse> receivePayment({required ReceivePaymentRequest req});
@@ -87,8 +125,12 @@ abstract class BindingLiquidSdk implements RustOpaqueInterface {
 
   void restore({required RestoreRequest req});
 
+  Future<SendPaymentResponse> sendLiquid({required PrepareSendLiquidResponse req});
+
   Future<SendPaymentResponse> sendPayment({required PrepareSendResponse req});
 
+  Future<void> setPaymentMetadata({required SetPaymentMetadataRequest req});
+
   Future<void> sync();
 }
 
@@ -16,6 +16,9 @@ sealed class PaymentError with _$PaymentError implements FrbException {
   const factory PaymentError.alreadyPaid() = PaymentError_AlreadyPaid;
   const factory PaymentError.paymentInProgress() = PaymentError_PaymentInProgress;
   const factory PaymentError.amountOutOfRange() = PaymentError_AmountOutOfRange;
+  const factory PaymentError.amountMissing({
+    required String err,
+  }) = PaymentError_AmountMissing;
   const factory PaymentError.generic({
     required String err,
   }) = PaymentError_Generic;
@@ -24,6 +27,9 @@ sealed class PaymentError with _$PaymentError implements FrbException {
   const factory PaymentError.invalidInvoice({
     required String err,
   }) = PaymentError_InvalidInvoice;
+  const factory PaymentError.invalidDestination({
+    required String err,
+  }) = PaymentError_InvalidDestination;
   const factory PaymentError.invalidPreimage() = PaymentError_InvalidPreimage;
   const factory PaymentError.lwkError({
     required String err,
@@ -45,6 +51,7 @@ sealed class PaymentError with _$PaymentError implements FrbException {
   const factory PaymentError.signerError({
     required String err,
   }) = PaymentError_SignerError;
+  const factory PaymentError.watchOnly() = PaymentError_WatchOnly;
 }
 
 @freezed
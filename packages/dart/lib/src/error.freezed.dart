@@ -212,6 +212,85 @@ abstract class PaymentError_AmountOutOfRange extends PaymentError {
   const PaymentError_AmountOutOfRange._() : super._();
 }
 
+/// @nodoc
+abstract class _$$PaymentError_AmountMissingImplCopyWith<$Res> {
+  factory _$$PaymentError_AmountMissingImplCopyWith(
+          _$PaymentError_AmountMissingImpl value, $Res Function(_$PaymentError_AmountMissingImpl) then) =
+      __$$PaymentError_AmountMissingImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String err});
+}
+
+/// @nodoc
+class __$$PaymentError_AmountMissingImplCopyWithImpl<$Res>
+    extends _$PaymentErrorCopyWithImpl<$Res, _$PaymentError_AmountMissingImpl>
+    implements _$$PaymentError_AmountMissingImplCopyWith<$Res> {
+  __$$PaymentError_AmountMissingImplCopyWithImpl(
+      _$PaymentError_AmountMissingImpl _value, $Res Function(_$PaymentError_AmountMissingImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PaymentError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? err = null,
+  }) {
+    return _then(_$PaymentError_AmountMissingImpl(
+      err: null == err
+          ? _value.err
+          : err // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PaymentError_AmountMissingImpl extends PaymentError_AmountMissing {
+  const _$PaymentError_AmountMissingImpl({required this.err}) : super._();
+
+  @override
+  final String err;
+
+  @override
+  String toString() {
+    return 'PaymentError.amountMissing(err: $err)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PaymentError_AmountMissingImpl &&
+            (identical(other.err, err) || other.err == err));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, err);
+
+  /// Create a copy of PaymentError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PaymentError_AmountMissingImplCopyWith<_$PaymentError_AmountMissingImpl> get copyWith =>
+      __$$PaymentError_AmountMissingImplCopyWithImpl<_$PaymentError_AmountMissingImpl>(this, _$identity);
+}
+
+abstract class PaymentError_AmountMissing extends PaymentError {
+  const factory PaymentError_AmountMissing({required final String err}) = _$PaymentError_AmountMissingImpl;
+  const PaymentError_AmountMissing._() : super._();
+
+  String get err;
+
+  /// Create a copy of PaymentError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PaymentError_AmountMissingImplCopyWith<_$PaymentError_AmountMissingImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
 /// @nodoc
 abstract class _$$PaymentError_GenericImplCopyWith<$Res> {
   factory _$$PaymentError_GenericImplCopyWith(
@@ -458,6 +537,87 @@ abstract class PaymentError_InvalidInvoice extends PaymentError {
       throw _privateConstructorUsedError;
 }
 
+/// @nodoc
+abstract class _$$PaymentError_InvalidDestinationImplCopyWith<$Res> {
+  factory _$$PaymentError_InvalidDestinationImplCopyWith(_$PaymentError_InvalidDestinationImpl value,
+          $Res Function(_$PaymentError_InvalidDestinationImpl) then) =
+      __$$PaymentError_InvalidDestinationImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String err});
+}
+
+/// @nodoc
+class __$$PaymentError_InvalidDestinationImplCopyWithImpl<$Res>
+    extends _$PaymentErrorCopyWithImpl<$Res, _$PaymentError_InvalidDestinationImpl>
+    implements _$$PaymentError_InvalidDestinationImplCopyWith<$Res> {
+  __$$PaymentError_InvalidDestinationImplCopyWithImpl(_$PaymentError_InvalidDestinationImpl _value,
+      $Res Function(_$PaymentError_InvalidDestinationImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PaymentError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? err = null,
+  }) {
+    return _then(_$PaymentError_InvalidDestinationImpl(
+      err: null == err
+          ? _value.err
+          : err // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PaymentError_InvalidDestinationImpl extends PaymentError_InvalidDestination {
+  const _$PaymentError_InvalidDestinationImpl({required this.err}) : super._();
+
+  @override
+  final String err;
+
+  @override
+  String toString() {
+    return 'PaymentError.invalidDestination(err: $err)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PaymentError_InvalidDestinationImpl &&
+            (identical(other.err, err) || other.err == err));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, err);
+
+  /// Create a copy of PaymentError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PaymentError_InvalidDestinationImplCopyWith<_$PaymentError_InvalidDestinationImpl> get copyWith =>
+      __$$PaymentError_InvalidDestinationImplCopyWithImpl<_$PaymentError_InvalidDestinationImpl>(
+          this, _$identity);
+}
+
+abstract class PaymentError_InvalidDestination extends PaymentError {
+  const factory PaymentError_InvalidDestination({required final String err}) =
+      _$PaymentError_InvalidDestinationImpl;
+  const PaymentError_InvalidDestination._() : super._();
+
+  String get err;
+
+  /// Create a copy of PaymentError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$PaymentError_InvalidDestinationImplCopyWith<_$PaymentError_InvalidDestinationImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
 /// @nodoc
 abstract class _$$PaymentError_InvalidPreimageImplCopyWith<$Res> {
   factory _$$PaymentError_InvalidPreimageImplCopyWith(
@@ -1085,6 +1245,50 @@ abstract class PaymentError_SignerError extends PaymentError {
       throw _privateConstructorUsedError;
 }
 
+/// @nodoc
+abstract class _$$PaymentError_WatchOnlyImplCopyWith<$Res> {
+  factory _$$PaymentError_WatchOnlyImplCopyWith(
+          _$PaymentError_WatchOnlyImpl value, $Res Function(_$PaymentError_WatchOnlyImpl) then) =
+      __$$PaymentError_WatchOnlyImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$PaymentError_WatchOnlyImplCopyWithImpl<$Res>
+    extends _$PaymentErrorCopyWithImpl<$Res, _$PaymentError_WatchOnlyImpl>
+    implements _$$PaymentError_WatchOnlyImplCopyWith<$Res> {
+  __$$PaymentError_WatchOnlyImplCopyWithImpl(
+      _$PaymentError_WatchOnlyImpl _value, $Res Function(_$PaymentError_WatchOnlyImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of PaymentError
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$PaymentError_WatchOnlyImpl extends PaymentError_WatchOnly {
+  const _$PaymentError_WatchOnlyImpl() : super._();
+
+  @override
+  String toString() {
+    return 'PaymentError.watchOnly()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$PaymentError_WatchOnlyImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+}
+
+abstract class PaymentError_WatchOnly extends PaymentError {
+  const factory PaymentError_WatchOnly() = _$PaymentError_WatchOnlyImpl;
+  const PaymentError_WatchOnly._() : super._();
+}
+
 /// @nodoc
 mixin _$SdkError {}
 
@@ -55,7 +55,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.0.0';
 
   @override
-  int get rustContentHash => -119028624;
+  int get rustContentHash => -601581800;
 
   static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
     stem: 'breez_sdk_liquid',
@@ -65,17 +65,29 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<void> crateBindingsBindingLiquidSdkAcceptPaymentProposedFees(
+      {required BindingLiquidSdk that, required AcceptPaymentProposedFeesRequest req});
+
   Stream<SdkEvent> crateBindingsBindingLiquidSdkAddEventListener({required BindingLiquidSdk that});
 
   void crateBindingsBindingLiquidSdkBackup({required BindingLiquidSdk that, required BackupRequest req});
 
+  Future<BumpRefundFeeResponse> crateBindingsBindingLiquidSdkBumpRefundFee(
+      {required BindingLiquidSdk that, required BumpRefundFeeRequest req});
+
   Future<String> crateBindingsBindingLiquidSdkBuyBitcoin(
       {required BindingLiquidSdk that, required BuyBitcoinRequest req});
 
+  Future<void> crateBindingsBindingLiquidSdkDeletePaymentMetadata(
+      {required BindingLiquidSdk that, required DeletePaymentMetadataRequest req});
+
   Future<void> crateBindingsBindingLiquidSdkDisconnect({required BindingLiquidSdk that});
 
   void crateBindingsBindingLiquidSdkEmptyWalletCache({required BindingLiquidSdk that});
 
+  Future<void> crateBindingsBindingLiquidSdkExportPayments(
+      {required BindingLiquidSdk that, required ExportPaymentsRequest req});
+
   Future<List<Rate>> crateBindingsBindingLiquidSdkFetchFiatRates({required BindingLiquidSdk that});
 
   Future<LightningPaymentLimitsResponse> crateBindingsBindingLiquidSdkFetchLightningLimits(
@@ -84,14 +96,26 @@ abstract class RustLibApi extends BaseApi {
   Future<OnchainPaymentLimitsResponse> crateBindingsBindingLiquidSdkFetchOnchainLimits(
       {required BindingLiquidSdk that});
 
+  Future<FetchPaymentProposedFeesResponse> crateBindingsBindingLiquidSdkFetchPaymentProposedFees(
+      {required BindingLiquidSdk that, required FetchPaymentProposedFeesRequest req});
+
   Future<GetInfoResponse> crateBindingsBindingLiquidSdkGetInfo({required BindingLiquidSdk that});
 
+  Future<Payment?> crateBindingsBindingLiquidSdkGetPayment(
+      {required BindingLiquidSdk that, required PaymentLookup req});
+
+  Future<List<PaymentMetadata>> crateBindingsBindingLiquidSdkGetPaymentMetadata(
+      {required BindingLiquidSdk that, required String paymentId});
+
   Future<List<FiatCurrency>> crateBindingsBindingLiquidSdkListFiatCurrencies(
       {required BindingLiquidSdk that});
 
   Future<List<Payment>> crateBindingsBindingLiquidSdkListPayments(
       {required BindingLiquidSdk that, required ListPaymentsRequest req});
 
+  Future<ListPaymentsResponse> crateBindingsBindingLiquidSdkListPaymentsPage(
+      {required BindingLiquidSdk that, required ListPaymentsRequest req});
+
   Future<List<RefundableSwap>> crateBindingsBindingLiquidSdkListRefundables({required BindingLiquidSdk that});
 
   Future<LnUrlCallbackStatus> crateBindingsBindingLiquidSdkLnurlAuth(
@@ -121,9 +145,15 @@ abstract class RustLibApi extends BaseApi {
   Future<PrepareRefundResponse> crateBindingsBindingLiquidSdkPrepareRefund(
       {required BindingLiquidSdk that, required PrepareRefundRequest req});
 
+  Future<PrepareSendLiquidResponse> crateBindingsBindingLiquidSdkPrepareSendLiquid(
+      {required BindingLiquidSdk that, required PrepareSendLiquidRequest req});
+
   Future<PrepareSendResponse> crateBindingsBindingLiquidSdkPrepareSendPayment(
       {required BindingLiquidSdk that, required PrepareSendRequest req});
 
+  Future<ReceiveLiquidResponse> crateBindingsBindingLiquidSdkReceiveLiquid(
+      {required BindingLiquidSdk that, required ReceiveLiquidRequest req});
+
   Future<ReceiveOnchainResponse> crateBindingsBindingLiquidSdkReceiveOnchain(
       {required BindingLiquidSdk that, required PrepareReceiveOnchainResponse req});
 
@@ -139,9 +169,15 @@ abstract class RustLibApi extends BaseApi {
 
   void crateBindingsBindingLiquidSdkRestore({required BindingLiquidSdk that, required RestoreRequest req});
 
+  Future<SendPaymentResponse> crateBindingsBindingLiquidSdkSendLiquid(
+      {required BindingLiquidSdk that, required PrepareSendLiquidResponse req});
+
   Future<SendPaymentResponse> crateBindingsBindingLiquidSdkSendPayment(
       {required BindingLiquidSdk that, required PrepareSendResponse req});
 
+  Future<void> crateBindingsBindingLiquidSdkSetPaymentMetadata(
+      {required BindingLiquidSdk that, required SetPaymentMetadataRequest req});
+
   Future<void> crateBindingsBindingLiquidSdkSync({required BindingLiquidSdk that});
 
   Future<void> crateBindingsBindingEventListenerOnEvent(
@@ -151,12 +187,20 @@ abstract class RustLibApi extends BaseApi {
 
   Future<BindingLiquidSdk> crateBindingsConnect({required ConnectRequest req});
 
+  Future<BindingLiquidSdk> crateBindingsConnectWithSigner({required ConnectRequest req});
+
   Config crateBindingsDefaultConfig({required LiquidNetwork network});
 
   Future<InputType> crateBindingsParse({required String input});
 
   LNInvoice crateBindingsParseInvoice({required String input});
 
+  LNOffer crateBindingsParseOffer({required String input});
+
+  void crateBindingsRespondToSignerRequest({required int id, required SignerResult result});
+
+  Stream<SignerRequest> crateBindingsSignerRequestStream();
+
   RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_BindingLiquidSdk;
 
   RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_BindingLiquidSdk;
@@ -172,6 +216,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     required super.portManager,
   });
 
+  @override
+  Future<void> crateBindingsBindingLiquidSdkAcceptPaymentProposedFees(
+      {required BindingLiquidSdk that, required AcceptPaymentProposedFeesRequest req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_accept_payment_proposed_fees_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_accept_payment_proposed_fees(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkAcceptPaymentProposedFeesConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkAcceptPaymentProposedFeesConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_accept_payment_proposed_fees",
+        argNames: ["that", "req"],
+      );
+
   @override
   Stream<SdkEvent> crateBindingsBindingLiquidSdkAddEventListener({required BindingLiquidSdk that}) {
     final listener = RustStreamSink<SdkEvent>();
@@ -224,6 +294,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "req"],
       );
 
+  @override
+  Future<BumpRefundFeeResponse> crateBindingsBindingLiquidSdkBumpRefundFee(
+      {required BindingLiquidSdk that, required BumpRefundFeeRequest req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_bump_refund_fee_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_bump_refund_fee(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_bump_refund_fee_response,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkBumpRefundFeeConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkBumpRefundFeeConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_bump_refund_fee",
+        argNames: ["that", "req"],
+      );
+
   @override
   Future<String> crateBindingsBindingLiquidSdkBuyBitcoin(
       {required BindingLiquidSdk that, required BuyBitcoinRequest req}) {
@@ -250,6 +346,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "req"],
       );
 
+  @override
+  Future<void> crateBindingsBindingLiquidSdkDeletePaymentMetadata(
+      {required BindingLiquidSdk that, required DeletePaymentMetadataRequest req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_delete_payment_metadata_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_delete_payment_metadata(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkDeletePaymentMetadataConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkDeletePaymentMetadataConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_delete_payment_metadata",
+        argNames: ["that", "req"],
+      );
+
   @override
   Future<void> crateBindingsBindingLiquidSdkDisconnect({required BindingLiquidSdk that}) {
     return handler.executeNormal(NormalTask(
@@ -298,6 +420,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that"],
       );
 
+  @override
+  Future<void> crateBindingsBindingLiquidSdkExportPayments(
+      {required BindingLiquidSdk that, required ExportPaymentsRequest req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_export_payments_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_export_payments(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkExportPaymentsConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkExportPaymentsConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_export_payments",
+        argNames: ["that", "req"],
+      );
+
   @override
   Future<List<Rate>> crateBindingsBindingLiquidSdkFetchFiatRates({required BindingLiquidSdk that}) {
     return handler.executeNormal(NormalTask(
@@ -372,6 +520,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that"],
       );
 
+  @override
+  Future<FetchPaymentProposedFeesResponse> crateBindingsBindingLiquidSdkFetchPaymentProposedFees(
+      {required BindingLiquidSdk that, required FetchPaymentProposedFeesRequest req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_fetch_payment_proposed_fees_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_fetch_payment_proposed_fees(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_fetch_payment_proposed_fees_response,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkFetchPaymentProposedFeesConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkFetchPaymentProposedFeesConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_fetch_payment_proposed_fees",
+        argNames: ["that", "req"],
+      );
+
   @override
   Future<GetInfoResponse> crateBindingsBindingLiquidSdkGetInfo({required BindingLiquidSdk that}) {
     return handler.executeNormal(NormalTask(
@@ -396,6 +570,58 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that"],
       );
 
+  @override
+  Future<Payment?> crateBindingsBindingLiquidSdkGetPayment(
+      {required BindingLiquidSdk that, required PaymentLookup req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_payment_lookup(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_get_payment(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_opt_box_autoadd_payment,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkGetPaymentConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkGetPaymentConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_get_payment",
+        argNames: ["that", "req"],
+      );
+
+  @override
+  Future<List<PaymentMetadata>> crateBindingsBindingLiquidSdkGetPaymentMetadata(
+      {required BindingLiquidSdk that, required String paymentId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_String(paymentId);
+        return wire.wire__crate__bindings__BindingLiquidSdk_get_payment_metadata(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_payment_metadata,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkGetPaymentMetadataConstMeta,
+      argValues: [that, paymentId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkGetPaymentMetadataConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_get_payment_metadata",
+        argNames: ["that", "paymentId"],
+      );
+
   @override
   Future<List<FiatCurrency>> crateBindingsBindingLiquidSdkListFiatCurrencies(
       {required BindingLiquidSdk that}) {
@@ -447,6 +673,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "req"],
       );
 
+  @override
+  Future<ListPaymentsResponse> crateBindingsBindingLiquidSdkListPaymentsPage(
+      {required BindingLiquidSdk that, required ListPaymentsRequest req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_list_payments_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_list_payments_page(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_list_payments_response,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkListPaymentsPageConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkListPaymentsPageConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_list_payments_page",
+        argNames: ["that", "req"],
+      );
+
   @override
   Future<List<RefundableSwap>> crateBindingsBindingLiquidSdkListRefundables(
       {required BindingLiquidSdk that}) {
@@ -706,6 +958,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "req"],
       );
 
+  @override
+  Future<PrepareSendLiquidResponse> crateBindingsBindingLiquidSdkPrepareSendLiquid(
+      {required BindingLiquidSdk that, required PrepareSendLiquidRequest req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_prepare_send_liquid_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_prepare_send_liquid(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_prepare_send_liquid_response,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkPrepareSendLiquidConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkPrepareSendLiquidConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_prepare_send_liquid",
+        argNames: ["that", "req"],
+      );
+
   @override
   Future<PrepareSendResponse> crateBindingsBindingLiquidSdkPrepareSendPayment(
       {required BindingLiquidSdk that, required PrepareSendRequest req}) {
@@ -732,6 +1010,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "req"],
       );
 
+  @override
+  Future<ReceiveLiquidResponse> crateBindingsBindingLiquidSdkReceiveLiquid(
+      {required BindingLiquidSdk that, required ReceiveLiquidRequest req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_receive_liquid_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_receive_liquid(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_receive_liquid_response,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkReceiveLiquidConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkReceiveLiquidConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_receive_liquid",
+        argNames: ["that", "req"],
+      );
+
   @override
   Future<ReceiveOnchainResponse> crateBindingsBindingLiquidSdkReceiveOnchain(
       {required BindingLiquidSdk that, required PrepareReceiveOnchainResponse req}) {
@@ -883,6 +1187,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "req"],
       );
 
+  @override
+  Future<SendPaymentResponse> crateBindingsBindingLiquidSdkSendLiquid(
+      {required BindingLiquidSdk that, required PrepareSendLiquidResponse req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_prepare_send_liquid_response(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_send_liquid(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_send_payment_response,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkSendLiquidConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkSendLiquidConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_send_liquid",
+        argNames: ["that", "req"],
+      );
+
   @override
   Future<SendPaymentResponse> crateBindingsBindingLiquidSdkSendPayment(
       {required BindingLiquidSdk that, required PrepareSendResponse req}) {
@@ -909,6 +1239,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "req"],
       );
 
+  @override
+  Future<void> crateBindingsBindingLiquidSdkSetPaymentMetadata(
+      {required BindingLiquidSdk that, required SetPaymentMetadataRequest req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_set_payment_metadata_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_set_payment_metadata(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkSetPaymentMetadataConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkSetPaymentMetadataConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_set_payment_metadata",
+        argNames: ["that", "req"],
+      );
+
   @override
   Future<void> crateBindingsBindingLiquidSdkSync({required BindingLiquidSdk that}) {
     return handler.executeNormal(NormalTask(
@@ -1004,6 +1360,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["req"],
       );
 
+  @override
+  Future<BindingLiquidSdk> crateBindingsConnectWithSigner({required ConnectRequest req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_box_autoadd_connect_request(req);
+        return wire.wire__crate__bindings__connect_with_signer(port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_binding_liquid_sdk,
+        decodeErrorData: dco_decode_sdk_error,
+      ),
+      constMeta: kCrateBindingsConnectWithSignerConstMeta,
+      argValues: [req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsConnectWithSignerConstMeta => const TaskConstMeta(
+        debugName: "connect_with_signer",
+        argNames: ["req"],
+      );
+
   @override
   Config crateBindingsDefaultConfig({required LiquidNetwork network}) {
     return handler.executeSync(SyncTask(
@@ -1070,8 +1448,77 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["input"],
       );
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_BindingLiquidSdk => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk;
+  @override
+  LNOffer crateBindingsParseOffer({required String input}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_String(input);
+        return wire.wire__crate__bindings__parse_offer(arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_ln_offer,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsParseOfferConstMeta,
+      argValues: [input],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsParseOfferConstMeta => const TaskConstMeta(
+        debugName: "parse_offer",
+        argNames: ["input"],
+      );
+
+  @override
+  void crateBindingsRespondToSignerRequest({required int id, required SignerResult result}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_u_32(id);
+        var arg1 = cst_encode_box_autoadd_signer_result(result);
+        return wire.wire__crate__bindings__respond_to_signer_request(arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: dco_decode_sdk_error,
+      ),
+      constMeta: kCrateBindingsRespondToSignerRequestConstMeta,
+      argValues: [id, result],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsRespondToSignerRequestConstMeta => const TaskConstMeta(
+        debugName: "respond_to_signer_request",
+        argNames: ["id", "result"],
+      );
+
+  @override
+  Stream<SignerRequest> crateBindingsSignerRequestStream() {
+    final s = RustStreamSink<SignerRequest>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_StreamSink_signer_request_Dco(s);
+        return wire.wire__crate__bindings__signer_request_stream(port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: dco_decode_AnyhowException,
+      ),
+      constMeta: kCrateBindingsSignerRequestStreamConstMeta,
+      argValues: [s],
+      apiImpl: this,
+    )));
+    return s.stream;
+  }
+
+  TaskConstMeta get kCrateBindingsSignerRequestStreamConstMeta => const TaskConstMeta(
+        debugName: "signer_request_stream",
+        argNames: ["s"],
+      );
+
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_BindingLiquidSdk => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk;
 
   RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_BindingLiquidSdk => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk;
@@ -1117,12 +1564,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError();
   }
 
+  @protected
+  RustStreamSink<SignerRequest> dco_decode_StreamSink_signer_request_Dco(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   String dco_decode_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as String;
   }
 
+  @protected
+  AcceptPaymentProposedFeesRequest dco_decode_accept_payment_proposed_fees_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return AcceptPaymentProposedFeesRequest(
+      response: dco_decode_fetch_payment_proposed_fees_response(arr[0]),
+    );
+  }
+
   @protected
   AesSuccessActionDataDecrypted dco_decode_aes_success_action_data_decrypted(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1151,6 +1614,56 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  AssetBalance dco_decode_asset_balance(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return AssetBalance(
+      assetId: dco_decode_String(arr[0]),
+      balance: dco_decode_u_64(arr[1]),
+      pendingSend: dco_decode_u_64(arr[2]),
+      pendingReceive: dco_decode_u_64(arr[3]),
+      assetMetadata: dco_decode_opt_box_autoadd_asset_metadata(arr[4]),
+    );
+  }
+
+  @protected
+  AssetMetadata dco_decode_asset_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return AssetMetadata(
+      assetId: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      ticker: dco_decode_String(arr[2]),
+      precision: dco_decode_u_8(arr[3]),
+    );
+  }
+
+  @protected
+  AutoRefundDetails dco_decode_auto_refund_details(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AutoRefundDetails(
+      swapAddress: dco_decode_String(arr[0]),
+      refundAddress: dco_decode_String(arr[1]),
+      refundTxId: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  AutoRefundPolicy dco_decode_auto_refund_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AutoRefundPolicy(
+      destination: dco_decode_refund_destination(arr[0]),
+      feeRate: dco_decode_refund_fee_rate(arr[1]),
+    );
+  }
+
   @protected
   BackupRequest dco_decode_backup_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1161,6 +1674,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  BalanceDetails dco_decode_balance_details(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return BalanceDetails(
+      confirmedSat: dco_decode_u_64(arr[0]),
+      unconfirmedReceiveSat: dco_decode_u_64(arr[1]),
+      unconfirmedSendSat: dco_decode_u_64(arr[2]),
+      pendingSendSwapsSat: dco_decode_u_64(arr[3]),
+      pendingReceiveSwapsSat: dco_decode_u_64(arr[4]),
+      pendingRefundSat: dco_decode_u_64(arr[5]),
+      refundableBtcSat: dco_decode_u_64(arr[6]),
+      pendingBtcRefundSat: dco_decode_u_64(arr[7]),
+    );
+  }
+
   @protected
   BindingEventListener dco_decode_binding_event_listener(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1191,6 +1721,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as bool;
   }
 
+  @protected
+  AcceptPaymentProposedFeesRequest dco_decode_box_autoadd_accept_payment_proposed_fees_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_accept_payment_proposed_fees_request(raw);
+  }
+
   @protected
   AesSuccessActionDataDecrypted dco_decode_box_autoadd_aes_success_action_data_decrypted(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1203,6 +1739,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_aes_success_action_data_result(raw);
   }
 
+  @protected
+  AssetMetadata dco_decode_box_autoadd_asset_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_asset_metadata(raw);
+  }
+
+  @protected
+  AutoRefundDetails dco_decode_box_autoadd_auto_refund_details(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_auto_refund_details(raw);
+  }
+
+  @protected
+  AutoRefundPolicy dco_decode_box_autoadd_auto_refund_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_auto_refund_policy(raw);
+  }
+
   @protected
   BackupRequest dco_decode_box_autoadd_backup_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1227,6 +1781,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as bool;
   }
 
+  @protected
+  BumpRefundFeeRequest dco_decode_box_autoadd_bump_refund_fee_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_bump_refund_fee_request(raw);
+  }
+
   @protected
   BuyBitcoinRequest dco_decode_box_autoadd_buy_bitcoin_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1239,6 +1799,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_connect_request(raw);
   }
 
+  @protected
+  DeletePaymentMetadataRequest dco_decode_box_autoadd_delete_payment_metadata_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_delete_payment_metadata_request(raw);
+  }
+
+  @protected
+  ExportPaymentsRequest dco_decode_box_autoadd_export_payments_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_export_payments_request(raw);
+  }
+
+  @protected
+  FetchPaymentProposedFeesRequest dco_decode_box_autoadd_fetch_payment_proposed_fees_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_fetch_payment_proposed_fees_request(raw);
+  }
+
   @protected
   PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1329,6 +1907,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_payment(raw);
   }
 
+  @protected
+  PaymentLookup dco_decode_box_autoadd_payment_lookup(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_payment_lookup(raw);
+  }
+
   @protected
   PrepareBuyBitcoinRequest dco_decode_box_autoadd_prepare_buy_bitcoin_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1365,6 +1949,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_prepare_refund_request(raw);
   }
 
+  @protected
+  PrepareSendLiquidRequest dco_decode_box_autoadd_prepare_send_liquid_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_prepare_send_liquid_request(raw);
+  }
+
+  @protected
+  PrepareSendLiquidResponse dco_decode_box_autoadd_prepare_send_liquid_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_prepare_send_liquid_response(raw);
+  }
+
   @protected
   PrepareSendRequest dco_decode_box_autoadd_prepare_send_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1377,6 +1973,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_prepare_send_response(raw);
   }
 
+  @protected
+  ReceiveLiquidRequest dco_decode_box_autoadd_receive_liquid_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_receive_liquid_request(raw);
+  }
+
   @protected
   ReceivePaymentRequest dco_decode_box_autoadd_receive_payment_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1401,6 +2003,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_sdk_event(raw);
   }
 
+  @protected
+  SetPaymentMetadataRequest dco_decode_box_autoadd_set_payment_metadata_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_set_payment_metadata_request(raw);
+  }
+
+  @protected
+  SignerResult dco_decode_box_autoadd_signer_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_signer_result(raw);
+  }
+
   @protected
   SuccessActionProcessed dco_decode_box_autoadd_success_action_processed(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1431,6 +2045,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_url_success_action_data(raw);
   }
 
+  @protected
+  BumpRefundFeeRequest dco_decode_bump_refund_fee_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BumpRefundFeeRequest(
+      swapAddress: dco_decode_String(arr[0]),
+      satPerVbyte: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  BumpRefundFeeResponse dco_decode_bump_refund_fee_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return BumpRefundFeeResponse(
+      refundTxId: dco_decode_String(arr[0]),
+      replacedTxId: dco_decode_String(arr[1]),
+      txFeeSat: dco_decode_u_64(arr[2]),
+      feeDeltaSat: dco_decode_u_64(arr[3]),
+    );
+  }
+
   @protected
   BuyBitcoinProvider dco_decode_buy_bitcoin_provider(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1448,20 +2086,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ChainBackend dco_decode_chain_backend(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ChainBackend.values[raw as int];
+  }
+
   @protected
   Config dco_decode_config(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    if (arr.length != 19) throw Exception('unexpected arr length: expect 19 but see ${arr.length}');
     return Config(
       liquidElectrumUrl: dco_decode_String(arr[0]),
       bitcoinElectrumUrl: dco_decode_String(arr[1]),
       mempoolspaceUrl: dco_decode_String(arr[2]),
-      workingDir: dco_decode_String(arr[3]),
-      network: dco_decode_liquid_network(arr[4]),
-      paymentTimeoutSec: dco_decode_u_64(arr[5]),
-      zeroConfMinFeeRateMsat: dco_decode_u_32(arr[6]),
-      zeroConfMaxAmountSat: dco_decode_opt_box_autoadd_u_64(arr[7]),
+      liquidEsploraUrl: dco_decode_String(arr[3]),
+      bitcoinEsploraUrl: dco_decode_String(arr[4]),
+      chainBackend: dco_decode_chain_backend(arr[5]),
+      breezServerUrl: dco_decode_String(arr[6]),
+      boltzUrl: dco_decode_opt_String(arr[7]),
+      referralId: dco_decode_opt_String(arr[8]),
+      workingDir: dco_decode_String(arr[9]),
+      network: dco_decode_liquid_network(arr[10]),
+      paymentTimeoutSec: dco_decode_u_64(arr[11]),
+      zeroConfMinFeeRateMsat: dco_decode_u_32(arr[12]),
+      zeroConfMaxAmountSat: dco_decode_opt_box_autoadd_u_64(arr[13]),
+      assetMetadata: dco_decode_opt_list_asset_metadata(arr[14]),
+      fiatCurrencies: dco_decode_opt_list_String(arr[15]),
+      proxyUrl: dco_decode_opt_String(arr[16]),
+      autoRefund: dco_decode_opt_box_autoadd_auto_refund_policy(arr[17]),
+      amountlessFeeToleranceSat: dco_decode_opt_box_autoadd_u_64(arr[18]),
     );
   }
 
@@ -1469,10 +2124,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   ConnectRequest dco_decode_connect_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
     return ConnectRequest(
-      mnemonic: dco_decode_String(arr[0]),
+      mnemonic: dco_decode_opt_String(arr[0]),
       config: dco_decode_config(arr[1]),
+      watchOnlyDescriptor: dco_decode_opt_String(arr[2]),
     );
   }
 
@@ -1492,12 +2148,66 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  DeletePaymentMetadataRequest dco_decode_delete_payment_metadata_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DeletePaymentMetadataRequest(
+      paymentId: dco_decode_String(arr[0]),
+      key: dco_decode_opt_String(arr[1]),
+    );
+  }
+
+  @protected
+  ExportFormat dco_decode_export_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ExportFormat.values[raw as int];
+  }
+
+  @protected
+  ExportPaymentsRequest dco_decode_export_payments_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ExportPaymentsRequest(
+      exportPath: dco_decode_String(arr[0]),
+      format: dco_decode_export_format(arr[1]),
+      fiatCurrency: dco_decode_opt_String(arr[2]),
+      fromTimestamp: dco_decode_opt_box_autoadd_i_64(arr[3]),
+      toTimestamp: dco_decode_opt_box_autoadd_i_64(arr[4]),
+    );
+  }
+
   @protected
   double dco_decode_f_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as double;
   }
 
+  @protected
+  FetchPaymentProposedFeesRequest dco_decode_fetch_payment_proposed_fees_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return FetchPaymentProposedFeesRequest(
+      swapId: dco_decode_String(arr[0]),
+    );
+  }
+
+  @protected
+  FetchPaymentProposedFeesResponse dco_decode_fetch_payment_proposed_fees_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return FetchPaymentProposedFeesResponse(
+      swapId: dco_decode_String(arr[0]),
+      feesSat: dco_decode_u_64(arr[1]),
+      payerAmountSat: dco_decode_u_64(arr[2]),
+      receiverAmountSat: dco_decode_u_64(arr[3]),
+    );
+  }
+
   @protected
   FiatCurrency dco_decode_fiat_currency(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1513,12 +2223,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   GetInfoResponse dco_decode_get_info_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
     return GetInfoResponse(
       balanceSat: dco_decode_u_64(arr[0]),
       pendingSendSat: dco_decode_u_64(arr[1]),
       pendingReceiveSat: dco_decode_u_64(arr[2]),
       pubkey: dco_decode_String(arr[3]),
+      assetBalances: dco_decode_list_asset_balance(arr[4]),
+      balanceDetails: dco_decode_balance_details(arr[5]),
     );
   }
 
@@ -1604,6 +2316,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return LiquidNetwork.values[raw as int];
   }
 
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<AssetBalance> dco_decode_list_asset_balance(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_asset_balance).toList();
+  }
+
+  @protected
+  List<AssetMetadata> dco_decode_list_asset_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_asset_metadata).toList();
+  }
+
   @protected
   List<FiatCurrency> dco_decode_list_fiat_currency(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1628,6 +2358,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_payment).toList();
   }
 
+  @protected
+  List<PaymentFiatRate> dco_decode_list_payment_fiat_rate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_payment_fiat_rate).toList();
+  }
+
+  @protected
+  List<PaymentKind> dco_decode_list_payment_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_payment_kind).toList();
+  }
+
+  @protected
+  List<PaymentMetadata> dco_decode_list_payment_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_payment_metadata).toList();
+  }
+
+  @protected
+  List<PaymentMetadataFilter> dco_decode_list_payment_metadata_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_payment_metadata_filter).toList();
+  }
+
+  @protected
+  List<PaymentState> dco_decode_list_payment_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_payment_state).toList();
+  }
+
   @protected
   List<PaymentType> dco_decode_list_payment_type(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1638,13 +2398,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   ListPaymentsRequest dco_decode_list_payments_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    if (arr.length != 13) throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
     return ListPaymentsRequest(
       filters: dco_decode_opt_list_payment_type(arr[0]),
       fromTimestamp: dco_decode_opt_box_autoadd_i_64(arr[1]),
       toTimestamp: dco_decode_opt_box_autoadd_i_64(arr[2]),
       offset: dco_decode_opt_box_autoadd_u_32(arr[3]),
       limit: dco_decode_opt_box_autoadd_u_32(arr[4]),
+      metadataFilters: dco_decode_opt_list_payment_metadata_filter(arr[5]),
+      states: dco_decode_opt_list_payment_state(arr[6]),
+      kinds: dco_decode_opt_list_payment_kind(arr[7]),
+      fromAmountSat: dco_decode_opt_box_autoadd_u_64(arr[8]),
+      toAmountSat: dco_decode_opt_box_autoadd_u_64(arr[9]),
+      assetId: dco_decode_opt_String(arr[10]),
+      search: dco_decode_opt_String(arr[11]),
+      cursor: dco_decode_opt_String(arr[12]),
+    );
+  }
+
+  @protected
+  ListPaymentsResponse dco_decode_list_payments_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ListPaymentsResponse(
+      payments: dco_decode_list_payment(arr[0]),
+      nextCursor: dco_decode_opt_String(arr[1]),
     );
   }
 
@@ -1699,6 +2478,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  LNOffer dco_decode_ln_offer(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return LNOffer(
+      offer: dco_decode_String(arr[0]),
+      chains: dco_decode_list_String(arr[1]),
+      amountSat: dco_decode_opt_box_autoadd_u_64(arr[2]),
+      description: dco_decode_opt_String(arr[3]),
+      absoluteExpiry: dco_decode_opt_box_autoadd_u_64(arr[4]),
+      issuer: dco_decode_opt_String(arr[5]),
+    );
+  }
+
   @protected
   LnUrlAuthError dco_decode_ln_url_auth_error(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2045,6 +2839,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_String(raw);
   }
 
+  @protected
+  AssetMetadata? dco_decode_opt_box_autoadd_asset_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_asset_metadata(raw);
+  }
+
+  @protected
+  AutoRefundPolicy? dco_decode_opt_box_autoadd_auto_refund_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_auto_refund_policy(raw);
+  }
+
   @protected
   bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2057,6 +2863,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
   }
 
+  @protected
+  Payment? dco_decode_opt_box_autoadd_payment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_payment(raw);
+  }
+
   @protected
   SuccessActionProcessed? dco_decode_opt_box_autoadd_success_action_processed(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2081,6 +2893,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
   }
 
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_String(raw);
+  }
+
+  @protected
+  List<AssetMetadata>? dco_decode_opt_list_asset_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_asset_metadata(raw);
+  }
+
+  @protected
+  List<PaymentKind>? dco_decode_opt_list_payment_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_payment_kind(raw);
+  }
+
+  @protected
+  List<PaymentMetadataFilter>? dco_decode_opt_list_payment_metadata_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_payment_metadata_filter(raw);
+  }
+
+  @protected
+  List<PaymentState>? dco_decode_opt_list_payment_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_payment_state(raw);
+  }
+
   @protected
   List<PaymentType>? dco_decode_opt_list_payment_type(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2091,10 +2933,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   PayOnchainRequest dco_decode_pay_onchain_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
     return PayOnchainRequest(
       address: dco_decode_String(arr[0]),
       prepareRes: dco_decode_prepare_pay_onchain_response(arr[1]),
+      idempotencyKey: dco_decode_opt_String(arr[2]),
     );
   }
 
@@ -2102,7 +2945,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   Payment dco_decode_payment(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    if (arr.length != 16) throw Exception('unexpected arr length: expect 16 but see ${arr.length}');
     return Payment(
       txId: dco_decode_opt_String(arr[0]),
       swapId: dco_decode_opt_String(arr[1]),
@@ -2112,10 +2955,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       preimage: dco_decode_opt_String(arr[5]),
       bolt11: dco_decode_opt_String(arr[6]),
       description: dco_decode_String(arr[7]),
-      refundTxId: dco_decode_opt_String(arr[8]),
-      refundTxAmountSat: dco_decode_opt_box_autoadd_u_64(arr[9]),
-      paymentType: dco_decode_payment_type(arr[10]),
-      status: dco_decode_payment_state(arr[11]),
+      destination: dco_decode_opt_String(arr[8]),
+      assetId: dco_decode_opt_String(arr[9]),
+      refundTxId: dco_decode_opt_String(arr[10]),
+      refundTxAmountSat: dco_decode_opt_box_autoadd_u_64(arr[11]),
+      paymentType: dco_decode_payment_type(arr[12]),
+      status: dco_decode_payment_state(arr[13]),
+      metadata: dco_decode_list_payment_metadata(arr[14]),
+      fiatRates: dco_decode_list_payment_fiat_rate(arr[15]),
     );
   }
 
@@ -2132,53 +2979,132 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case 3:
         return PaymentError_AmountOutOfRange();
       case 4:
-        return PaymentError_Generic(
+        return PaymentError_AmountMissing(
           err: dco_decode_String(raw[1]),
         );
       case 5:
-        return PaymentError_InvalidOrExpiredFees();
+        return PaymentError_Generic(
+          err: dco_decode_String(raw[1]),
+        );
       case 6:
-        return PaymentError_InsufficientFunds();
+        return PaymentError_InvalidOrExpiredFees();
       case 7:
+        return PaymentError_InsufficientFunds();
+      case 8:
         return PaymentError_InvalidInvoice(
           err: dco_decode_String(raw[1]),
         );
-      case 8:
-        return PaymentError_InvalidPreimage();
       case 9:
-        return PaymentError_LwkError(
+        return PaymentError_InvalidDestination(
           err: dco_decode_String(raw[1]),
         );
       case 10:
-        return PaymentError_PairsNotFound();
+        return PaymentError_InvalidPreimage();
       case 11:
-        return PaymentError_PaymentTimeout();
+        return PaymentError_LwkError(
+          err: dco_decode_String(raw[1]),
+        );
       case 12:
-        return PaymentError_PersistError();
+        return PaymentError_PairsNotFound();
       case 13:
+        return PaymentError_PaymentTimeout();
+      case 14:
+        return PaymentError_PersistError();
+      case 15:
         return PaymentError_ReceiveError(
           err: dco_decode_String(raw[1]),
         );
-      case 14:
+      case 16:
         return PaymentError_Refunded(
           err: dco_decode_String(raw[1]),
           refundTxId: dco_decode_String(raw[2]),
         );
-      case 15:
+      case 17:
         return PaymentError_SelfTransferNotSupported();
-      case 16:
+      case 18:
         return PaymentError_SendError(
           err: dco_decode_String(raw[1]),
         );
-      case 17:
+      case 19:
         return PaymentError_SignerError(
           err: dco_decode_String(raw[1]),
         );
+      case 20:
+        return PaymentError_WatchOnly();
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  PaymentFiatRate dco_decode_payment_fiat_rate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PaymentFiatRate(
+      coin: dco_decode_String(arr[0]),
+      value: dco_decode_f_64(arr[1]),
+      createdAt: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  PaymentKind dco_decode_payment_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PaymentKind.values[raw as int];
+  }
+
+  @protected
+  PaymentLookup dco_decode_payment_lookup(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return PaymentLookup_SwapId(
+          swapId: dco_decode_String(raw[1]),
+        );
+      case 1:
+        return PaymentLookup_Invoice(
+          invoice: dco_decode_String(raw[1]),
+        );
+      case 2:
+        return PaymentLookup_PaymentHash(
+          paymentHash: dco_decode_String(raw[1]),
+        );
+      case 3:
+        return PaymentLookup_TxId(
+          txId: dco_decode_String(raw[1]),
+        );
+      case 4:
+        return PaymentLookup_Destination(
+          address: dco_decode_String(raw[1]),
+        );
       default:
         throw Exception("unreachable");
     }
   }
 
+  @protected
+  PaymentMetadata dco_decode_payment_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PaymentMetadata(
+      key: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  PaymentMetadataFilter dco_decode_payment_metadata_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PaymentMetadataFilter(
+      key: dco_decode_String(arr[0]),
+      value: dco_decode_opt_String(arr[1]),
+    );
+  }
+
   @protected
   PaymentState dco_decode_payment_state(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2218,10 +3144,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   PreparePayOnchainRequest dco_decode_prepare_pay_onchain_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
     return PreparePayOnchainRequest(
       receiverAmountSat: dco_decode_u_64(arr[0]),
       satPerVbyte: dco_decode_opt_box_autoadd_u_32(arr[1]),
+      drain: dco_decode_opt_box_autoadd_bool(arr[2]),
     );
   }
 
@@ -2229,11 +3156,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   PreparePayOnchainResponse dco_decode_prepare_pay_onchain_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
     return PreparePayOnchainResponse(
       receiverAmountSat: dco_decode_u_64(arr[0]),
       claimFeesSat: dco_decode_u_64(arr[1]),
       totalFeesSat: dco_decode_u_64(arr[2]),
+      drain: dco_decode_bool(arr[3]),
     );
   }
 
@@ -2243,7 +3171,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     final arr = raw as List<dynamic>;
     if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
     return PrepareReceiveOnchainRequest(
-      payerAmountSat: dco_decode_u_64(arr[0]),
+      payerAmountSat: dco_decode_opt_box_autoadd_u_64(arr[0]),
     );
   }
 
@@ -2251,10 +3179,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   PrepareReceiveOnchainResponse dco_decode_prepare_receive_onchain_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
     return PrepareReceiveOnchainResponse(
-      payerAmountSat: dco_decode_u_64(arr[0]),
+      payerAmountSat: dco_decode_opt_box_autoadd_u_64(arr[0]),
       feesSat: dco_decode_u_64(arr[1]),
+      swapperFeerate: dco_decode_f_64(arr[2]),
     );
   }
 
@@ -2303,13 +3232,44 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  PrepareSendLiquidRequest dco_decode_prepare_send_liquid_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PrepareSendLiquidRequest(
+      destination: dco_decode_String(arr[0]),
+      amountSat: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      drain: dco_decode_opt_box_autoadd_bool(arr[2]),
+      assetId: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  PrepareSendLiquidResponse dco_decode_prepare_send_liquid_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return PrepareSendLiquidResponse(
+      address: dco_decode_String(arr[0]),
+      receiverAmountSat: dco_decode_u_64(arr[1]),
+      feesSat: dco_decode_u_64(arr[2]),
+      description: dco_decode_opt_String(arr[3]),
+      drain: dco_decode_bool(arr[4]),
+      assetId: dco_decode_opt_String(arr[5]),
+    );
+  }
+
   @protected
   PrepareSendRequest dco_decode_prepare_send_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
     return PrepareSendRequest(
       invoice: dco_decode_String(arr[0]),
+      amountSat: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      idempotencyKey: dco_decode_opt_String(arr[2]),
+      payerNote: dco_decode_opt_String(arr[3]),
     );
   }
 
@@ -2317,10 +3277,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   PrepareSendResponse dco_decode_prepare_send_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
     return PrepareSendResponse(
       invoice: dco_decode_String(arr[0]),
-      feesSat: dco_decode_u_64(arr[1]),
+      offer: dco_decode_opt_String(arr[1]),
+      receiverAmountSat: dco_decode_u_64(arr[2]),
+      feesSat: dco_decode_u_64(arr[3]),
+      idempotencyKey: dco_decode_opt_String(arr[4]),
     );
   }
 
@@ -2335,6 +3298,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ReceiveLiquidRequest dco_decode_receive_liquid_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ReceiveLiquidRequest(
+      amountSat: dco_decode_opt_box_autoadd_u_64(arr[0]),
+      description: dco_decode_opt_String(arr[1]),
+      assetId: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  ReceiveLiquidResponse dco_decode_receive_liquid_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ReceiveLiquidResponse(
+      address: dco_decode_String(arr[0]),
+      bip21: dco_decode_String(arr[1]),
+    );
+  }
+
   @protected
   ReceiveOnchainResponse dco_decode_receive_onchain_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2382,6 +3368,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  RefundDestination dco_decode_refund_destination(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return RefundDestination_Address(
+          address: dco_decode_String(raw[1]),
+        );
+      case 1:
+        return RefundDestination_Xpub(
+          xpub: dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  RefundFeeRate dco_decode_refund_fee_rate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RefundFeeRate.values[raw as int];
+  }
+
   @protected
   RefundRequest dco_decode_refund_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2502,6 +3511,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           details: dco_decode_box_autoadd_payment(raw[1]),
         );
       case 6:
+        return SdkEvent_SwapAutoRefunded(
+          details: dco_decode_box_autoadd_auto_refund_details(raw[1]),
+        );
+      case 7:
+        return SdkEvent_SwapWaitingFeeAcceptance(
+          swapId: dco_decode_String(raw[1]),
+        );
+      case 8:
         return SdkEvent_Synced();
       default:
         throw Exception("unreachable");
@@ -2518,6 +3535,81 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  SetPaymentMetadataRequest dco_decode_set_payment_metadata_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SetPaymentMetadataRequest(
+      paymentId: dco_decode_String(arr[0]),
+      key: dco_decode_String(arr[1]),
+      value: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  SignerMethod dco_decode_signer_method(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return SignerMethod_Xpub();
+      case 1:
+        return SignerMethod_DeriveXpub(
+          derivationPath: dco_decode_String(raw[1]),
+        );
+      case 2:
+        return SignerMethod_Slip77MasterBlindingKey();
+      case 3:
+        return SignerMethod_SignPset(
+          pset: dco_decode_String(raw[1]),
+        );
+      case 4:
+        return SignerMethod_SignEcdsa(
+          msg: dco_decode_list_prim_u_8_strict(raw[1]),
+          derivationPath: dco_decode_String(raw[2]),
+        );
+      case 5:
+        return SignerMethod_HmacSha256(
+          msg: dco_decode_list_prim_u_8_strict(raw[1]),
+          derivationPath: dco_decode_String(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  SignerRequest dco_decode_signer_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SignerRequest(
+      id: dco_decode_u_32(arr[0]),
+      method: dco_decode_signer_method(arr[1]),
+    );
+  }
+
+  @protected
+  SignerResult dco_decode_signer_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return SignerResult_String(
+          value: dco_decode_String(raw[1]),
+        );
+      case 1:
+        return SignerResult_Bytes(
+          value: dco_decode_list_prim_u_8_strict(raw[1]),
+        );
+      case 2:
+        return SignerResult_Error(
+          err: dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
   @protected
   SuccessActionProcessed dco_decode_success_action_processed(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2645,6 +3737,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError('Unreachable ()');
   }
 
+  @protected
+  RustStreamSink<SignerRequest> sse_decode_StreamSink_signer_request_Dco(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   String sse_decode_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2652,6 +3750,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return utf8.decoder.convert(inner);
   }
 
+  @protected
+  AcceptPaymentProposedFeesRequest sse_decode_accept_payment_proposed_fees_request(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_response = sse_decode_fetch_payment_proposed_fees_response(deserializer);
+    return AcceptPaymentProposedFeesRequest(response: var_response);
+  }
+
   @protected
   AesSuccessActionDataDecrypted sse_decode_aes_success_action_data_decrypted(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2677,6 +3783,50 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  AssetBalance sse_decode_asset_balance(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_assetId = sse_decode_String(deserializer);
+    var var_balance = sse_decode_u_64(deserializer);
+    var var_pendingSend = sse_decode_u_64(deserializer);
+    var var_pendingReceive = sse_decode_u_64(deserializer);
+    var var_assetMetadata = sse_decode_opt_box_autoadd_asset_metadata(deserializer);
+    return AssetBalance(
+        assetId: var_assetId,
+        balance: var_balance,
+        pendingSend: var_pendingSend,
+        pendingReceive: var_pendingReceive,
+        assetMetadata: var_assetMetadata);
+  }
+
+  @protected
+  AssetMetadata sse_decode_asset_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_assetId = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_ticker = sse_decode_String(deserializer);
+    var var_precision = sse_decode_u_8(deserializer);
+    return AssetMetadata(assetId: var_assetId, name: var_name, ticker: var_ticker, precision: var_precision);
+  }
+
+  @protected
+  AutoRefundDetails sse_decode_auto_refund_details(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_swapAddress = sse_decode_String(deserializer);
+    var var_refundAddress = sse_decode_String(deserializer);
+    var var_refundTxId = sse_decode_String(deserializer);
+    return AutoRefundDetails(
+        swapAddress: var_swapAddress, refundAddress: var_refundAddress, refundTxId: var_refundTxId);
+  }
+
+  @protected
+  AutoRefundPolicy sse_decode_auto_refund_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_destination = sse_decode_refund_destination(deserializer);
+    var var_feeRate = sse_decode_refund_fee_rate(deserializer);
+    return AutoRefundPolicy(destination: var_destination, feeRate: var_feeRate);
+  }
+
   @protected
   BackupRequest sse_decode_backup_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2684,6 +3834,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return BackupRequest(backupPath: var_backupPath);
   }
 
+  @protected
+  BalanceDetails sse_decode_balance_details(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_confirmedSat = sse_decode_u_64(deserializer);
+    var var_unconfirmedReceiveSat = sse_decode_u_64(deserializer);
+    var var_unconfirmedSendSat = sse_decode_u_64(deserializer);
+    var var_pendingSendSwapsSat = sse_decode_u_64(deserializer);
+    var var_pendingReceiveSwapsSat = sse_decode_u_64(deserializer);
+    var var_pendingRefundSat = sse_decode_u_64(deserializer);
+    var var_refundableBtcSat = sse_decode_u_64(deserializer);
+    var var_pendingBtcRefundSat = sse_decode_u_64(deserializer);
+    return BalanceDetails(
+        confirmedSat: var_confirmedSat,
+        unconfirmedReceiveSat: var_unconfirmedReceiveSat,
+        unconfirmedSendSat: var_unconfirmedSendSat,
+        pendingSendSwapsSat: var_pendingSendSwapsSat,
+        pendingReceiveSwapsSat: var_pendingReceiveSwapsSat,
+        pendingRefundSat: var_pendingRefundSat,
+        refundableBtcSat: var_refundableBtcSat,
+        pendingBtcRefundSat: var_pendingBtcRefundSat);
+  }
+
   @protected
   BindingEventListener sse_decode_binding_event_listener(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2713,6 +3885,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getUint8() != 0;
   }
 
+  @protected
+  AcceptPaymentProposedFeesRequest sse_decode_box_autoadd_accept_payment_proposed_fees_request(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_accept_payment_proposed_fees_request(deserializer));
+  }
+
   @protected
   AesSuccessActionDataDecrypted sse_decode_box_autoadd_aes_success_action_data_decrypted(
       SseDeserializer deserializer) {
@@ -2727,6 +3906,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_aes_success_action_data_result(deserializer));
   }
 
+  @protected
+  AssetMetadata sse_decode_box_autoadd_asset_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_asset_metadata(deserializer));
+  }
+
+  @protected
+  AutoRefundDetails sse_decode_box_autoadd_auto_refund_details(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_auto_refund_details(deserializer));
+  }
+
+  @protected
+  AutoRefundPolicy sse_decode_box_autoadd_auto_refund_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_auto_refund_policy(deserializer));
+  }
+
   @protected
   BackupRequest sse_decode_box_autoadd_backup_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2751,6 +3948,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_bool(deserializer));
   }
 
+  @protected
+  BumpRefundFeeRequest sse_decode_box_autoadd_bump_refund_fee_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bump_refund_fee_request(deserializer));
+  }
+
   @protected
   BuyBitcoinRequest sse_decode_box_autoadd_buy_bitcoin_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2763,6 +3966,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_connect_request(deserializer));
   }
 
+  @protected
+  DeletePaymentMetadataRequest sse_decode_box_autoadd_delete_payment_metadata_request(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_delete_payment_metadata_request(deserializer));
+  }
+
+  @protected
+  ExportPaymentsRequest sse_decode_box_autoadd_export_payments_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_export_payments_request(deserializer));
+  }
+
+  @protected
+  FetchPaymentProposedFeesRequest sse_decode_box_autoadd_fetch_payment_proposed_fees_request(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_fetch_payment_proposed_fees_request(deserializer));
+  }
+
   @protected
   PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2853,6 +4076,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_payment(deserializer));
   }
 
+  @protected
+  PaymentLookup sse_decode_box_autoadd_payment_lookup(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_payment_lookup(deserializer));
+  }
+
   @protected
   PrepareBuyBitcoinRequest sse_decode_box_autoadd_prepare_buy_bitcoin_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2892,6 +4121,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_prepare_refund_request(deserializer));
   }
 
+  @protected
+  PrepareSendLiquidRequest sse_decode_box_autoadd_prepare_send_liquid_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_prepare_send_liquid_request(deserializer));
+  }
+
+  @protected
+  PrepareSendLiquidResponse sse_decode_box_autoadd_prepare_send_liquid_response(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_prepare_send_liquid_response(deserializer));
+  }
+
   @protected
   PrepareSendRequest sse_decode_box_autoadd_prepare_send_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2904,6 +4146,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_prepare_send_response(deserializer));
   }
 
+  @protected
+  ReceiveLiquidRequest sse_decode_box_autoadd_receive_liquid_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_receive_liquid_request(deserializer));
+  }
+
   @protected
   ReceivePaymentRequest sse_decode_box_autoadd_receive_payment_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2928,6 +4176,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_sdk_event(deserializer));
   }
 
+  @protected
+  SetPaymentMetadataRequest sse_decode_box_autoadd_set_payment_metadata_request(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_set_payment_metadata_request(deserializer));
+  }
+
+  @protected
+  SignerResult sse_decode_box_autoadd_signer_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_signer_result(deserializer));
+  }
+
   @protected
   SuccessActionProcessed sse_decode_box_autoadd_success_action_processed(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2958,6 +4219,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_url_success_action_data(deserializer));
   }
 
+  @protected
+  BumpRefundFeeRequest sse_decode_bump_refund_fee_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_swapAddress = sse_decode_String(deserializer);
+    var var_satPerVbyte = sse_decode_u_32(deserializer);
+    return BumpRefundFeeRequest(swapAddress: var_swapAddress, satPerVbyte: var_satPerVbyte);
+  }
+
+  @protected
+  BumpRefundFeeResponse sse_decode_bump_refund_fee_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_refundTxId = sse_decode_String(deserializer);
+    var var_replacedTxId = sse_decode_String(deserializer);
+    var var_txFeeSat = sse_decode_u_64(deserializer);
+    var var_feeDeltaSat = sse_decode_u_64(deserializer);
+    return BumpRefundFeeResponse(
+        refundTxId: var_refundTxId,
+        replacedTxId: var_replacedTxId,
+        txFeeSat: var_txFeeSat,
+        feeDeltaSat: var_feeDeltaSat);
+  }
+
   @protected
   BuyBitcoinProvider sse_decode_buy_bitcoin_provider(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2973,34 +4256,65 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return BuyBitcoinRequest(prepareRes: var_prepareRes, redirectUrl: var_redirectUrl);
   }
 
+  @protected
+  ChainBackend sse_decode_chain_backend(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ChainBackend.values[inner];
+  }
+
   @protected
   Config sse_decode_config(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_liquidElectrumUrl = sse_decode_String(deserializer);
     var var_bitcoinElectrumUrl = sse_decode_String(deserializer);
     var var_mempoolspaceUrl = sse_decode_String(deserializer);
+    var var_liquidEsploraUrl = sse_decode_String(deserializer);
+    var var_bitcoinEsploraUrl = sse_decode_String(deserializer);
+    var var_chainBackend = sse_decode_chain_backend(deserializer);
+    var var_breezServerUrl = sse_decode_String(deserializer);
+    var var_boltzUrl = sse_decode_opt_String(deserializer);
+    var var_referralId = sse_decode_opt_String(deserializer);
     var var_workingDir = sse_decode_String(deserializer);
     var var_network = sse_decode_liquid_network(deserializer);
     var var_paymentTimeoutSec = sse_decode_u_64(deserializer);
     var var_zeroConfMinFeeRateMsat = sse_decode_u_32(deserializer);
     var var_zeroConfMaxAmountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_assetMetadata = sse_decode_opt_list_asset_metadata(deserializer);
+    var var_fiatCurrencies = sse_decode_opt_list_String(deserializer);
+    var var_proxyUrl = sse_decode_opt_String(deserializer);
+    var var_autoRefund = sse_decode_opt_box_autoadd_auto_refund_policy(deserializer);
+    var var_amountlessFeeToleranceSat = sse_decode_opt_box_autoadd_u_64(deserializer);
     return Config(
         liquidElectrumUrl: var_liquidElectrumUrl,
         bitcoinElectrumUrl: var_bitcoinElectrumUrl,
         mempoolspaceUrl: var_mempoolspaceUrl,
+        liquidEsploraUrl: var_liquidEsploraUrl,
+        bitcoinEsploraUrl: var_bitcoinEsploraUrl,
+        chainBackend: var_chainBackend,
+        breezServerUrl: var_breezServerUrl,
+        boltzUrl: var_boltzUrl,
+        referralId: var_referralId,
         workingDir: var_workingDir,
         network: var_network,
         paymentTimeoutSec: var_paymentTimeoutSec,
         zeroConfMinFeeRateMsat: var_zeroConfMinFeeRateMsat,
-        zeroConfMaxAmountSat: var_zeroConfMaxAmountSat);
+        zeroConfMaxAmountSat: var_zeroConfMaxAmountSat,
+        assetMetadata: var_assetMetadata,
+        fiatCurrencies: var_fiatCurrencies,
+        proxyUrl: var_proxyUrl,
+        autoRefund: var_autoRefund,
+        amountlessFeeToleranceSat: var_amountlessFeeToleranceSat);
   }
 
   @protected
   ConnectRequest sse_decode_connect_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_mnemonic = sse_decode_String(deserializer);
+    var var_mnemonic = sse_decode_opt_String(deserializer);
     var var_config = sse_decode_config(deserializer);
-    return ConnectRequest(mnemonic: var_mnemonic, config: var_config);
+    var var_watchOnlyDescriptor = sse_decode_opt_String(deserializer);
+    return ConnectRequest(
+        mnemonic: var_mnemonic, config: var_config, watchOnlyDescriptor: var_watchOnlyDescriptor);
   }
 
   @protected
@@ -3023,12 +4337,66 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         localeOverrides: var_localeOverrides);
   }
 
+  @protected
+  DeletePaymentMetadataRequest sse_decode_delete_payment_metadata_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_paymentId = sse_decode_String(deserializer);
+    var var_key = sse_decode_opt_String(deserializer);
+    return DeletePaymentMetadataRequest(paymentId: var_paymentId, key: var_key);
+  }
+
+  @protected
+  ExportFormat sse_decode_export_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ExportFormat.values[inner];
+  }
+
+  @protected
+  ExportPaymentsRequest sse_decode_export_payments_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_exportPath = sse_decode_String(deserializer);
+    var var_format = sse_decode_export_format(deserializer);
+    var var_fiatCurrency = sse_decode_opt_String(deserializer);
+    var var_fromTimestamp = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_toTimestamp = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return ExportPaymentsRequest(
+        exportPath: var_exportPath,
+        format: var_format,
+        fiatCurrency: var_fiatCurrency,
+        fromTimestamp: var_fromTimestamp,
+        toTimestamp: var_toTimestamp);
+  }
+
   @protected
   double sse_decode_f_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return deserializer.buffer.getFloat64();
   }
 
+  @protected
+  FetchPaymentProposedFeesRequest sse_decode_fetch_payment_proposed_fees_request(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_swapId = sse_decode_String(deserializer);
+    return FetchPaymentProposedFeesRequest(swapId: var_swapId);
+  }
+
+  @protected
+  FetchPaymentProposedFeesResponse sse_decode_fetch_payment_proposed_fees_response(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_swapId = sse_decode_String(deserializer);
+    var var_feesSat = sse_decode_u_64(deserializer);
+    var var_payerAmountSat = sse_decode_u_64(deserializer);
+    var var_receiverAmountSat = sse_decode_u_64(deserializer);
+    return FetchPaymentProposedFeesResponse(
+        swapId: var_swapId,
+        feesSat: var_feesSat,
+        payerAmountSat: var_payerAmountSat,
+        receiverAmountSat: var_receiverAmountSat);
+  }
+
   @protected
   FiatCurrency sse_decode_fiat_currency(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3044,11 +4412,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_pendingSendSat = sse_decode_u_64(deserializer);
     var var_pendingReceiveSat = sse_decode_u_64(deserializer);
     var var_pubkey = sse_decode_String(deserializer);
+    var var_assetBalances = sse_decode_list_asset_balance(deserializer);
+    var var_balanceDetails = sse_decode_balance_details(deserializer);
     return GetInfoResponse(
         balanceSat: var_balanceSat,
         pendingSendSat: var_pendingSendSat,
         pendingReceiveSat: var_pendingReceiveSat,
-        pubkey: var_pubkey);
+        pubkey: var_pubkey,
+        assetBalances: var_assetBalances,
+        balanceDetails: var_balanceDetails);
   }
 
   @protected
@@ -3116,10 +4488,46 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  LiquidNetwork sse_decode_liquid_network(SseDeserializer deserializer) {
+  LiquidNetwork sse_decode_liquid_network(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return LiquidNetwork.values[inner];
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AssetBalance> sse_decode_list_asset_balance(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AssetBalance>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_asset_balance(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AssetMetadata> sse_decode_list_asset_metadata(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return LiquidNetwork.values[inner];
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AssetMetadata>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_asset_metadata(deserializer));
+    }
+    return ans_;
   }
 
   @protected
@@ -3170,6 +4578,66 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<PaymentFiatRate> sse_decode_list_payment_fiat_rate(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PaymentFiatRate>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_payment_fiat_rate(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PaymentKind> sse_decode_list_payment_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PaymentKind>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_payment_kind(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PaymentMetadata> sse_decode_list_payment_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PaymentMetadata>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_payment_metadata(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PaymentMetadataFilter> sse_decode_list_payment_metadata_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PaymentMetadataFilter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_payment_metadata_filter(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PaymentState> sse_decode_list_payment_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PaymentState>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_payment_state(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<PaymentType> sse_decode_list_payment_type(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3190,12 +4658,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_toTimestamp = sse_decode_opt_box_autoadd_i_64(deserializer);
     var var_offset = sse_decode_opt_box_autoadd_u_32(deserializer);
     var var_limit = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_metadataFilters = sse_decode_opt_list_payment_metadata_filter(deserializer);
+    var var_states = sse_decode_opt_list_payment_state(deserializer);
+    var var_kinds = sse_decode_opt_list_payment_kind(deserializer);
+    var var_fromAmountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_toAmountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_assetId = sse_decode_opt_String(deserializer);
+    var var_search = sse_decode_opt_String(deserializer);
+    var var_cursor = sse_decode_opt_String(deserializer);
     return ListPaymentsRequest(
         filters: var_filters,
         fromTimestamp: var_fromTimestamp,
         toTimestamp: var_toTimestamp,
         offset: var_offset,
-        limit: var_limit);
+        limit: var_limit,
+        metadataFilters: var_metadataFilters,
+        states: var_states,
+        kinds: var_kinds,
+        fromAmountSat: var_fromAmountSat,
+        toAmountSat: var_toAmountSat,
+        assetId: var_assetId,
+        search: var_search,
+        cursor: var_cursor);
+  }
+
+  @protected
+  ListPaymentsResponse sse_decode_list_payments_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_payments = sse_decode_list_payment(deserializer);
+    var var_nextCursor = sse_decode_opt_String(deserializer);
+    return ListPaymentsResponse(payments: var_payments, nextCursor: var_nextCursor);
   }
 
   @protected
@@ -3283,6 +4775,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         minFinalCltvExpiryDelta: var_minFinalCltvExpiryDelta);
   }
 
+  @protected
+  LNOffer sse_decode_ln_offer(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_offer = sse_decode_String(deserializer);
+    var var_chains = sse_decode_list_String(deserializer);
+    var var_amountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_absoluteExpiry = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_issuer = sse_decode_opt_String(deserializer);
+    return LNOffer(
+        offer: var_offer,
+        chains: var_chains,
+        amountSat: var_amountSat,
+        description: var_description,
+        absoluteExpiry: var_absoluteExpiry,
+        issuer: var_issuer);
+  }
+
   @protected
   LnUrlAuthError sse_decode_ln_url_auth_error(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3597,6 +5107,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  AssetMetadata? sse_decode_opt_box_autoadd_asset_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_asset_metadata(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AutoRefundPolicy? sse_decode_opt_box_autoadd_auto_refund_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_auto_refund_policy(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3619,6 +5151,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  Payment? sse_decode_opt_box_autoadd_payment(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_payment(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   SuccessActionProcessed? sse_decode_opt_box_autoadd_success_action_processed(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3663,6 +5206,61 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<AssetMetadata>? sse_decode_opt_list_asset_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_asset_metadata(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<PaymentKind>? sse_decode_opt_list_payment_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_payment_kind(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<PaymentMetadataFilter>? sse_decode_opt_list_payment_metadata_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_payment_metadata_filter(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<PaymentState>? sse_decode_opt_list_payment_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_payment_state(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   List<PaymentType>? sse_decode_opt_list_payment_type(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3679,7 +5277,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_address = sse_decode_String(deserializer);
     var var_prepareRes = sse_decode_prepare_pay_onchain_response(deserializer);
-    return PayOnchainRequest(address: var_address, prepareRes: var_prepareRes);
+    var var_idempotencyKey = sse_decode_opt_String(deserializer);
+    return PayOnchainRequest(
+        address: var_address, prepareRes: var_prepareRes, idempotencyKey: var_idempotencyKey);
   }
 
   @protected
@@ -3693,10 +5293,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_preimage = sse_decode_opt_String(deserializer);
     var var_bolt11 = sse_decode_opt_String(deserializer);
     var var_description = sse_decode_String(deserializer);
+    var var_destination = sse_decode_opt_String(deserializer);
+    var var_assetId = sse_decode_opt_String(deserializer);
     var var_refundTxId = sse_decode_opt_String(deserializer);
     var var_refundTxAmountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
     var var_paymentType = sse_decode_payment_type(deserializer);
     var var_status = sse_decode_payment_state(deserializer);
+    var var_metadata = sse_decode_list_payment_metadata(deserializer);
+    var var_fiatRates = sse_decode_list_payment_fiat_rate(deserializer);
     return Payment(
         txId: var_txId,
         swapId: var_swapId,
@@ -3706,10 +5310,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         preimage: var_preimage,
         bolt11: var_bolt11,
         description: var_description,
+        destination: var_destination,
+        assetId: var_assetId,
         refundTxId: var_refundTxId,
         refundTxAmountSat: var_refundTxAmountSat,
         paymentType: var_paymentType,
-        status: var_status);
+        status: var_status,
+        metadata: var_metadata,
+        fiatRates: var_fiatRates);
   }
 
   @protected
@@ -3728,45 +5336,111 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         return PaymentError_AmountOutOfRange();
       case 4:
         var var_err = sse_decode_String(deserializer);
-        return PaymentError_Generic(err: var_err);
+        return PaymentError_AmountMissing(err: var_err);
       case 5:
-        return PaymentError_InvalidOrExpiredFees();
+        var var_err = sse_decode_String(deserializer);
+        return PaymentError_Generic(err: var_err);
       case 6:
-        return PaymentError_InsufficientFunds();
+        return PaymentError_InvalidOrExpiredFees();
       case 7:
+        return PaymentError_InsufficientFunds();
+      case 8:
         var var_err = sse_decode_String(deserializer);
         return PaymentError_InvalidInvoice(err: var_err);
-      case 8:
-        return PaymentError_InvalidPreimage();
       case 9:
         var var_err = sse_decode_String(deserializer);
-        return PaymentError_LwkError(err: var_err);
+        return PaymentError_InvalidDestination(err: var_err);
       case 10:
-        return PaymentError_PairsNotFound();
+        return PaymentError_InvalidPreimage();
       case 11:
-        return PaymentError_PaymentTimeout();
+        var var_err = sse_decode_String(deserializer);
+        return PaymentError_LwkError(err: var_err);
       case 12:
-        return PaymentError_PersistError();
+        return PaymentError_PairsNotFound();
       case 13:
+        return PaymentError_PaymentTimeout();
+      case 14:
+        return PaymentError_PersistError();
+      case 15:
         var var_err = sse_decode_String(deserializer);
         return PaymentError_ReceiveError(err: var_err);
-      case 14:
+      case 16:
         var var_err = sse_decode_String(deserializer);
         var var_refundTxId = sse_decode_String(deserializer);
         return PaymentError_Refunded(err: var_err, refundTxId: var_refundTxId);
-      case 15:
+      case 17:
         return PaymentError_SelfTransferNotSupported();
-      case 16:
+      case 18:
         var var_err = sse_decode_String(deserializer);
         return PaymentError_SendError(err: var_err);
-      case 17:
+      case 19:
         var var_err = sse_decode_String(deserializer);
         return PaymentError_SignerError(err: var_err);
+      case 20:
+        return PaymentError_WatchOnly();
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  PaymentFiatRate sse_decode_payment_fiat_rate(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_coin = sse_decode_String(deserializer);
+    var var_value = sse_decode_f_64(deserializer);
+    var var_createdAt = sse_decode_u_32(deserializer);
+    return PaymentFiatRate(coin: var_coin, value: var_value, createdAt: var_createdAt);
+  }
+
+  @protected
+  PaymentKind sse_decode_payment_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PaymentKind.values[inner];
+  }
+
+  @protected
+  PaymentLookup sse_decode_payment_lookup(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_swapId = sse_decode_String(deserializer);
+        return PaymentLookup_SwapId(swapId: var_swapId);
+      case 1:
+        var var_invoice = sse_decode_String(deserializer);
+        return PaymentLookup_Invoice(invoice: var_invoice);
+      case 2:
+        var var_paymentHash = sse_decode_String(deserializer);
+        return PaymentLookup_PaymentHash(paymentHash: var_paymentHash);
+      case 3:
+        var var_txId = sse_decode_String(deserializer);
+        return PaymentLookup_TxId(txId: var_txId);
+      case 4:
+        var var_address = sse_decode_String(deserializer);
+        return PaymentLookup_Destination(address: var_address);
       default:
         throw UnimplementedError('');
     }
   }
 
+  @protected
+  PaymentMetadata sse_decode_payment_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return PaymentMetadata(key: var_key, value: var_value);
+  }
+
+  @protected
+  PaymentMetadataFilter sse_decode_payment_metadata_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_value = sse_decode_opt_String(deserializer);
+    return PaymentMetadataFilter(key: var_key, value: var_value);
+  }
+
   @protected
   PaymentState sse_decode_payment_state(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3803,7 +5477,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_receiverAmountSat = sse_decode_u_64(deserializer);
     var var_satPerVbyte = sse_decode_opt_box_autoadd_u_32(deserializer);
-    return PreparePayOnchainRequest(receiverAmountSat: var_receiverAmountSat, satPerVbyte: var_satPerVbyte);
+    var var_drain = sse_decode_opt_box_autoadd_bool(deserializer);
+    return PreparePayOnchainRequest(
+        receiverAmountSat: var_receiverAmountSat, satPerVbyte: var_satPerVbyte, drain: var_drain);
   }
 
   @protected
@@ -3812,25 +5488,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_receiverAmountSat = sse_decode_u_64(deserializer);
     var var_claimFeesSat = sse_decode_u_64(deserializer);
     var var_totalFeesSat = sse_decode_u_64(deserializer);
+    var var_drain = sse_decode_bool(deserializer);
     return PreparePayOnchainResponse(
         receiverAmountSat: var_receiverAmountSat,
         claimFeesSat: var_claimFeesSat,
-        totalFeesSat: var_totalFeesSat);
+        totalFeesSat: var_totalFeesSat,
+        drain: var_drain);
   }
 
   @protected
   PrepareReceiveOnchainRequest sse_decode_prepare_receive_onchain_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_payerAmountSat = sse_decode_u_64(deserializer);
+    var var_payerAmountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
     return PrepareReceiveOnchainRequest(payerAmountSat: var_payerAmountSat);
   }
 
   @protected
   PrepareReceiveOnchainResponse sse_decode_prepare_receive_onchain_response(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_payerAmountSat = sse_decode_u_64(deserializer);
+    var var_payerAmountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
     var var_feesSat = sse_decode_u_64(deserializer);
-    return PrepareReceiveOnchainResponse(payerAmountSat: var_payerAmountSat, feesSat: var_feesSat);
+    var var_swapperFeerate = sse_decode_f_64(deserializer);
+    return PrepareReceiveOnchainResponse(
+        payerAmountSat: var_payerAmountSat, feesSat: var_feesSat, swapperFeerate: var_swapperFeerate);
   }
 
   @protected
@@ -3867,19 +5547,63 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return PrepareRefundResponse(txVsize: var_txVsize, txFeeSat: var_txFeeSat, refundTxId: var_refundTxId);
   }
 
+  @protected
+  PrepareSendLiquidRequest sse_decode_prepare_send_liquid_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_destination = sse_decode_String(deserializer);
+    var var_amountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_drain = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_assetId = sse_decode_opt_String(deserializer);
+    return PrepareSendLiquidRequest(
+        destination: var_destination, amountSat: var_amountSat, drain: var_drain, assetId: var_assetId);
+  }
+
+  @protected
+  PrepareSendLiquidResponse sse_decode_prepare_send_liquid_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_address = sse_decode_String(deserializer);
+    var var_receiverAmountSat = sse_decode_u_64(deserializer);
+    var var_feesSat = sse_decode_u_64(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_drain = sse_decode_bool(deserializer);
+    var var_assetId = sse_decode_opt_String(deserializer);
+    return PrepareSendLiquidResponse(
+        address: var_address,
+        receiverAmountSat: var_receiverAmountSat,
+        feesSat: var_feesSat,
+        description: var_description,
+        drain: var_drain,
+        assetId: var_assetId);
+  }
+
   @protected
   PrepareSendRequest sse_decode_prepare_send_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_invoice = sse_decode_String(deserializer);
-    return PrepareSendRequest(invoice: var_invoice);
+    var var_amountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_idempotencyKey = sse_decode_opt_String(deserializer);
+    var var_payerNote = sse_decode_opt_String(deserializer);
+    return PrepareSendRequest(
+        invoice: var_invoice,
+        amountSat: var_amountSat,
+        idempotencyKey: var_idempotencyKey,
+        payerNote: var_payerNote);
   }
 
   @protected
   PrepareSendResponse sse_decode_prepare_send_response(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_invoice = sse_decode_String(deserializer);
+    var var_offer = sse_decode_opt_String(deserializer);
+    var var_receiverAmountSat = sse_decode_u_64(deserializer);
     var var_feesSat = sse_decode_u_64(deserializer);
-    return PrepareSendResponse(invoice: var_invoice, feesSat: var_feesSat);
+    var var_idempotencyKey = sse_decode_opt_String(deserializer);
+    return PrepareSendResponse(
+        invoice: var_invoice,
+        offer: var_offer,
+        receiverAmountSat: var_receiverAmountSat,
+        feesSat: var_feesSat,
+        idempotencyKey: var_idempotencyKey);
   }
 
   @protected
@@ -3890,6 +5614,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return Rate(coin: var_coin, value: var_value);
   }
 
+  @protected
+  ReceiveLiquidRequest sse_decode_receive_liquid_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_amountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_assetId = sse_decode_opt_String(deserializer);
+    return ReceiveLiquidRequest(amountSat: var_amountSat, description: var_description, assetId: var_assetId);
+  }
+
+  @protected
+  ReceiveLiquidResponse sse_decode_receive_liquid_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_address = sse_decode_String(deserializer);
+    var var_bip21 = sse_decode_String(deserializer);
+    return ReceiveLiquidResponse(address: var_address, bip21: var_bip21);
+  }
+
   @protected
   ReceiveOnchainResponse sse_decode_receive_onchain_response(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3930,6 +5671,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         minimumFee: var_minimumFee);
   }
 
+  @protected
+  RefundDestination sse_decode_refund_destination(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_address = sse_decode_String(deserializer);
+        return RefundDestination_Address(address: var_address);
+      case 1:
+        var var_xpub = sse_decode_String(deserializer);
+        return RefundDestination_Xpub(xpub: var_xpub);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  RefundFeeRate sse_decode_refund_fee_rate(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return RefundFeeRate.values[inner];
+  }
+
   @protected
   RefundRequest sse_decode_refund_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4036,6 +5801,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         var var_details = sse_decode_box_autoadd_payment(deserializer);
         return SdkEvent_PaymentWaitingConfirmation(details: var_details);
       case 6:
+        var var_details = sse_decode_box_autoadd_auto_refund_details(deserializer);
+        return SdkEvent_SwapAutoRefunded(details: var_details);
+      case 7:
+        var var_swapId = sse_decode_String(deserializer);
+        return SdkEvent_SwapWaitingFeeAcceptance(swapId: var_swapId);
+      case 8:
         return SdkEvent_Synced();
       default:
         throw UnimplementedError('');
@@ -4049,6 +5820,72 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return SendPaymentResponse(payment: var_payment);
   }
 
+  @protected
+  SetPaymentMetadataRequest sse_decode_set_payment_metadata_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_paymentId = sse_decode_String(deserializer);
+    var var_key = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return SetPaymentMetadataRequest(paymentId: var_paymentId, key: var_key, value: var_value);
+  }
+
+  @protected
+  SignerMethod sse_decode_signer_method(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return SignerMethod_Xpub();
+      case 1:
+        var var_derivationPath = sse_decode_String(deserializer);
+        return SignerMethod_DeriveXpub(derivationPath: var_derivationPath);
+      case 2:
+        return SignerMethod_Slip77MasterBlindingKey();
+      case 3:
+        var var_pset = sse_decode_String(deserializer);
+        return SignerMethod_SignPset(pset: var_pset);
+      case 4:
+        var var_msg = sse_decode_list_prim_u_8_strict(deserializer);
+        var var_derivationPath = sse_decode_String(deserializer);
+        return SignerMethod_SignEcdsa(msg: var_msg, derivationPath: var_derivationPath);
+      case 5:
+        var var_msg = sse_decode_list_prim_u_8_strict(deserializer);
+        var var_derivationPath = sse_decode_String(deserializer);
+        return SignerMethod_HmacSha256(msg: var_msg, derivationPath: var_derivationPath);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  SignerRequest sse_decode_signer_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_u_32(deserializer);
+    var var_method = sse_decode_signer_method(deserializer);
+    return SignerRequest(id: var_id, method: var_method);
+  }
+
+  @protected
+  SignerResult sse_decode_signer_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_value = sse_decode_String(deserializer);
+        return SignerResult_String(value: var_value);
+      case 1:
+        var var_value = sse_decode_list_prim_u_8_strict(deserializer);
+        return SignerResult_Bytes(value: var_value);
+      case 2:
+        var var_err = sse_decode_String(deserializer);
+        return SignerResult_Error(err: var_err);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
   @protected
   SuccessActionProcessed sse_decode_success_action_processed(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4160,6 +5997,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return cst_encode_i_32(raw.index);
   }
 
+  @protected
+  int cst_encode_chain_backend(ChainBackend raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
+  @protected
+  int cst_encode_export_format(ExportFormat raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
   @protected
   double cst_encode_f_64(double raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -4184,6 +6033,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return cst_encode_i_32(raw.index);
   }
 
+  @protected
+  int cst_encode_payment_kind(PaymentKind raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
   @protected
   int cst_encode_payment_state(PaymentState raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -4196,6 +6051,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return cst_encode_i_32(raw.index);
   }
 
+  @protected
+  int cst_encode_refund_fee_rate(RefundFeeRate raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
   @protected
   int cst_encode_u_16(int raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -4271,12 +6132,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         serializer);
   }
 
+  @protected
+  void sse_encode_StreamSink_signer_request_Dco(RustStreamSink<SignerRequest> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: DcoCodec(
+          decodeSuccessData: dco_decode_signer_request,
+          decodeErrorData: dco_decode_AnyhowException,
+        )),
+        serializer);
+  }
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
+  @protected
+  void sse_encode_accept_payment_proposed_fees_request(
+      AcceptPaymentProposedFeesRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_fetch_payment_proposed_fees_response(self.response, serializer);
+  }
+
   @protected
   void sse_encode_aes_success_action_data_decrypted(
       AesSuccessActionDataDecrypted self, SseSerializer serializer) {
@@ -4300,12 +6180,59 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_asset_balance(AssetBalance self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.assetId, serializer);
+    sse_encode_u_64(self.balance, serializer);
+    sse_encode_u_64(self.pendingSend, serializer);
+    sse_encode_u_64(self.pendingReceive, serializer);
+    sse_encode_opt_box_autoadd_asset_metadata(self.assetMetadata, serializer);
+  }
+
+  @protected
+  void sse_encode_asset_metadata(AssetMetadata self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.assetId, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.ticker, serializer);
+    sse_encode_u_8(self.precision, serializer);
+  }
+
+  @protected
+  void sse_encode_auto_refund_details(AutoRefundDetails self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.swapAddress, serializer);
+    sse_encode_String(self.refundAddress, serializer);
+    sse_encode_String(self.refundTxId, serializer);
+  }
+
+  @protected
+  void sse_encode_auto_refund_policy(AutoRefundPolicy self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_refund_destination(self.destination, serializer);
+    sse_encode_refund_fee_rate(self.feeRate, serializer);
+  }
+
   @protected
   void sse_encode_backup_request(BackupRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_opt_String(self.backupPath, serializer);
   }
 
+  @protected
+  void sse_encode_balance_details(BalanceDetails self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.confirmedSat, serializer);
+    sse_encode_u_64(self.unconfirmedReceiveSat, serializer);
+    sse_encode_u_64(self.unconfirmedSendSat, serializer);
+    sse_encode_u_64(self.pendingSendSwapsSat, serializer);
+    sse_encode_u_64(self.pendingReceiveSwapsSat, serializer);
+    sse_encode_u_64(self.pendingRefundSat, serializer);
+    sse_encode_u_64(self.refundableBtcSat, serializer);
+    sse_encode_u_64(self.pendingBtcRefundSat, serializer);
+  }
+
   @protected
   void sse_encode_binding_event_listener(BindingEventListener self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4328,6 +6255,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint8(self ? 1 : 0);
   }
 
+  @protected
+  void sse_encode_box_autoadd_accept_payment_proposed_fees_request(
+      AcceptPaymentProposedFeesRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_accept_payment_proposed_fees_request(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_aes_success_action_data_decrypted(
       AesSuccessActionDataDecrypted self, SseSerializer serializer) {
@@ -4342,6 +6276,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_aes_success_action_data_result(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_asset_metadata(AssetMetadata self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_asset_metadata(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_auto_refund_details(AutoRefundDetails self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_auto_refund_details(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_auto_refund_policy(AutoRefundPolicy self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_auto_refund_policy(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_backup_request(BackupRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4366,6 +6318,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_bool(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_bump_refund_fee_request(BumpRefundFeeRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bump_refund_fee_request(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_buy_bitcoin_request(BuyBitcoinRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4378,6 +6336,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_connect_request(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_delete_payment_metadata_request(
+      DeletePaymentMetadataRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_delete_payment_metadata_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_export_payments_request(ExportPaymentsRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_export_payments_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_fetch_payment_proposed_fees_request(
+      FetchPaymentProposedFeesRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_fetch_payment_proposed_fees_request(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4471,6 +6449,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_payment(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_payment_lookup(PaymentLookup self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_payment_lookup(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_prepare_buy_bitcoin_request(
       PrepareBuyBitcoinRequest self, SseSerializer serializer) {
@@ -4512,6 +6496,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_prepare_refund_request(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_prepare_send_liquid_request(
+      PrepareSendLiquidRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_prepare_send_liquid_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_prepare_send_liquid_response(
+      PrepareSendLiquidResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_prepare_send_liquid_response(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_prepare_send_request(PrepareSendRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4524,6 +6522,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_prepare_send_response(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_receive_liquid_request(ReceiveLiquidRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_receive_liquid_request(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_receive_payment_request(ReceivePaymentRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4548,6 +6552,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_sdk_event(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_set_payment_metadata_request(
+      SetPaymentMetadataRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_set_payment_metadata_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_signer_result(SignerResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_signer_result(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_success_action_processed(
       SuccessActionProcessed self, SseSerializer serializer) {
@@ -4579,6 +6596,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_url_success_action_data(self, serializer);
   }
 
+  @protected
+  void sse_encode_bump_refund_fee_request(BumpRefundFeeRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.swapAddress, serializer);
+    sse_encode_u_32(self.satPerVbyte, serializer);
+  }
+
+  @protected
+  void sse_encode_bump_refund_fee_response(BumpRefundFeeResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.refundTxId, serializer);
+    sse_encode_String(self.replacedTxId, serializer);
+    sse_encode_u_64(self.txFeeSat, serializer);
+    sse_encode_u_64(self.feeDeltaSat, serializer);
+  }
+
   @protected
   void sse_encode_buy_bitcoin_provider(BuyBitcoinProvider self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4592,24 +6625,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.redirectUrl, serializer);
   }
 
+  @protected
+  void sse_encode_chain_backend(ChainBackend self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_config(Config self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.liquidElectrumUrl, serializer);
     sse_encode_String(self.bitcoinElectrumUrl, serializer);
     sse_encode_String(self.mempoolspaceUrl, serializer);
+    sse_encode_String(self.liquidEsploraUrl, serializer);
+    sse_encode_String(self.bitcoinEsploraUrl, serializer);
+    sse_encode_chain_backend(self.chainBackend, serializer);
+    sse_encode_String(self.breezServerUrl, serializer);
+    sse_encode_opt_String(self.boltzUrl, serializer);
+    sse_encode_opt_String(self.referralId, serializer);
     sse_encode_String(self.workingDir, serializer);
     sse_encode_liquid_network(self.network, serializer);
     sse_encode_u_64(self.paymentTimeoutSec, serializer);
     sse_encode_u_32(self.zeroConfMinFeeRateMsat, serializer);
     sse_encode_opt_box_autoadd_u_64(self.zeroConfMaxAmountSat, serializer);
+    sse_encode_opt_list_asset_metadata(self.assetMetadata, serializer);
+    sse_encode_opt_list_String(self.fiatCurrencies, serializer);
+    sse_encode_opt_String(self.proxyUrl, serializer);
+    sse_encode_opt_box_autoadd_auto_refund_policy(self.autoRefund, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.amountlessFeeToleranceSat, serializer);
   }
 
   @protected
   void sse_encode_connect_request(ConnectRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.mnemonic, serializer);
+    sse_encode_opt_String(self.mnemonic, serializer);
     sse_encode_config(self.config, serializer);
+    sse_encode_opt_String(self.watchOnlyDescriptor, serializer);
   }
 
   @protected
@@ -4624,12 +6675,53 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_locale_overrides(self.localeOverrides, serializer);
   }
 
+  @protected
+  void sse_encode_delete_payment_metadata_request(
+      DeletePaymentMetadataRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.paymentId, serializer);
+    sse_encode_opt_String(self.key, serializer);
+  }
+
+  @protected
+  void sse_encode_export_format(ExportFormat self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_export_payments_request(ExportPaymentsRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.exportPath, serializer);
+    sse_encode_export_format(self.format, serializer);
+    sse_encode_opt_String(self.fiatCurrency, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.fromTimestamp, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.toTimestamp, serializer);
+  }
+
   @protected
   void sse_encode_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putFloat64(self);
   }
 
+  @protected
+  void sse_encode_fetch_payment_proposed_fees_request(
+      FetchPaymentProposedFeesRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.swapId, serializer);
+  }
+
+  @protected
+  void sse_encode_fetch_payment_proposed_fees_response(
+      FetchPaymentProposedFeesResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.swapId, serializer);
+    sse_encode_u_64(self.feesSat, serializer);
+    sse_encode_u_64(self.payerAmountSat, serializer);
+    sse_encode_u_64(self.receiverAmountSat, serializer);
+  }
+
   @protected
   void sse_encode_fiat_currency(FiatCurrency self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4644,6 +6736,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_64(self.pendingSendSat, serializer);
     sse_encode_u_64(self.pendingReceiveSat, serializer);
     sse_encode_String(self.pubkey, serializer);
+    sse_encode_list_asset_balance(self.assetBalances, serializer);
+    sse_encode_balance_details(self.balanceDetails, serializer);
   }
 
   @protected
@@ -4700,52 +6794,124 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_limits(Limits self, SseSerializer serializer) {
+  void sse_encode_limits(Limits self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.minSat, serializer);
+    sse_encode_u_64(self.maxSat, serializer);
+    sse_encode_u_64(self.maxZeroConfSat, serializer);
+  }
+
+  @protected
+  void sse_encode_liquid_network(LiquidNetwork self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_asset_balance(List<AssetBalance> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_asset_balance(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_asset_metadata(List<AssetMetadata> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_asset_metadata(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_fiat_currency(List<FiatCurrency> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_fiat_currency(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_locale_overrides(List<LocaleOverrides> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_locale_overrides(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_localized_name(List<LocalizedName> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_localized_name(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_payment(List<Payment> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self.minSat, serializer);
-    sse_encode_u_64(self.maxSat, serializer);
-    sse_encode_u_64(self.maxZeroConfSat, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_payment(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_liquid_network(LiquidNetwork self, SseSerializer serializer) {
+  void sse_encode_list_payment_fiat_rate(List<PaymentFiatRate> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_payment_fiat_rate(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_fiat_currency(List<FiatCurrency> self, SseSerializer serializer) {
+  void sse_encode_list_payment_kind(List<PaymentKind> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_fiat_currency(item, serializer);
+      sse_encode_payment_kind(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_locale_overrides(List<LocaleOverrides> self, SseSerializer serializer) {
+  void sse_encode_list_payment_metadata(List<PaymentMetadata> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_locale_overrides(item, serializer);
+      sse_encode_payment_metadata(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_localized_name(List<LocalizedName> self, SseSerializer serializer) {
+  void sse_encode_list_payment_metadata_filter(List<PaymentMetadataFilter> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_localized_name(item, serializer);
+      sse_encode_payment_metadata_filter(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_payment(List<Payment> self, SseSerializer serializer) {
+  void sse_encode_list_payment_state(List<PaymentState> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_payment(item, serializer);
+      sse_encode_payment_state(item, serializer);
     }
   }
 
@@ -4766,6 +6932,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_i_64(self.toTimestamp, serializer);
     sse_encode_opt_box_autoadd_u_32(self.offset, serializer);
     sse_encode_opt_box_autoadd_u_32(self.limit, serializer);
+    sse_encode_opt_list_payment_metadata_filter(self.metadataFilters, serializer);
+    sse_encode_opt_list_payment_state(self.states, serializer);
+    sse_encode_opt_list_payment_kind(self.kinds, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.fromAmountSat, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.toAmountSat, serializer);
+    sse_encode_opt_String(self.assetId, serializer);
+    sse_encode_opt_String(self.search, serializer);
+    sse_encode_opt_String(self.cursor, serializer);
+  }
+
+  @protected
+  void sse_encode_list_payments_response(ListPaymentsResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_payment(self.payments, serializer);
+    sse_encode_opt_String(self.nextCursor, serializer);
   }
 
   @protected
@@ -4828,6 +7009,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_64(self.minFinalCltvExpiryDelta, serializer);
   }
 
+  @protected
+  void sse_encode_ln_offer(LNOffer self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.offer, serializer);
+    sse_encode_list_String(self.chains, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.amountSat, serializer);
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.absoluteExpiry, serializer);
+    sse_encode_opt_String(self.issuer, serializer);
+  }
+
   @protected
   void sse_encode_ln_url_auth_error(LnUrlAuthError self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5096,6 +7288,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_asset_metadata(AssetMetadata? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_asset_metadata(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_auto_refund_policy(AutoRefundPolicy? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_auto_refund_policy(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5116,6 +7328,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_payment(Payment? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_payment(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_success_action_processed(
       SuccessActionProcessed? self, SseSerializer serializer) {
@@ -5157,6 +7379,57 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_String(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_list_asset_metadata(List<AssetMetadata>? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_asset_metadata(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_list_payment_kind(List<PaymentKind>? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_payment_kind(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_list_payment_metadata_filter(
+      List<PaymentMetadataFilter>? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_payment_metadata_filter(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_list_payment_state(List<PaymentState>? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_payment_state(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_list_payment_type(List<PaymentType>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5172,6 +7445,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.address, serializer);
     sse_encode_prepare_pay_onchain_response(self.prepareRes, serializer);
+    sse_encode_opt_String(self.idempotencyKey, serializer);
   }
 
   @protected
@@ -5185,10 +7459,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.preimage, serializer);
     sse_encode_opt_String(self.bolt11, serializer);
     sse_encode_String(self.description, serializer);
+    sse_encode_opt_String(self.destination, serializer);
+    sse_encode_opt_String(self.assetId, serializer);
     sse_encode_opt_String(self.refundTxId, serializer);
     sse_encode_opt_box_autoadd_u_64(self.refundTxAmountSat, serializer);
     sse_encode_payment_type(self.paymentType, serializer);
     sse_encode_payment_state(self.status, serializer);
+    sse_encode_list_payment_metadata(self.metadata, serializer);
+    sse_encode_list_payment_fiat_rate(self.fiatRates, serializer);
   }
 
   @protected
@@ -5203,47 +7481,107 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_i_32(2, serializer);
       case PaymentError_AmountOutOfRange():
         sse_encode_i_32(3, serializer);
-      case PaymentError_Generic(err: final err):
+      case PaymentError_AmountMissing(err: final err):
         sse_encode_i_32(4, serializer);
         sse_encode_String(err, serializer);
-      case PaymentError_InvalidOrExpiredFees():
+      case PaymentError_Generic(err: final err):
         sse_encode_i_32(5, serializer);
-      case PaymentError_InsufficientFunds():
+        sse_encode_String(err, serializer);
+      case PaymentError_InvalidOrExpiredFees():
         sse_encode_i_32(6, serializer);
-      case PaymentError_InvalidInvoice(err: final err):
+      case PaymentError_InsufficientFunds():
         sse_encode_i_32(7, serializer);
+      case PaymentError_InvalidInvoice(err: final err):
+        sse_encode_i_32(8, serializer);
+        sse_encode_String(err, serializer);
+      case PaymentError_InvalidDestination(err: final err):
+        sse_encode_i_32(9, serializer);
         sse_encode_String(err, serializer);
       case PaymentError_InvalidPreimage():
-        sse_encode_i_32(8, serializer);
+        sse_encode_i_32(10, serializer);
       case PaymentError_LwkError(err: final err):
-        sse_encode_i_32(9, serializer);
+        sse_encode_i_32(11, serializer);
         sse_encode_String(err, serializer);
       case PaymentError_PairsNotFound():
-        sse_encode_i_32(10, serializer);
+        sse_encode_i_32(12, serializer);
       case PaymentError_PaymentTimeout():
-        sse_encode_i_32(11, serializer);
+        sse_encode_i_32(13, serializer);
       case PaymentError_PersistError():
-        sse_encode_i_32(12, serializer);
+        sse_encode_i_32(14, serializer);
       case PaymentError_ReceiveError(err: final err):
-        sse_encode_i_32(13, serializer);
+        sse_encode_i_32(15, serializer);
         sse_encode_String(err, serializer);
       case PaymentError_Refunded(err: final err, refundTxId: final refundTxId):
-        sse_encode_i_32(14, serializer);
+        sse_encode_i_32(16, serializer);
         sse_encode_String(err, serializer);
         sse_encode_String(refundTxId, serializer);
       case PaymentError_SelfTransferNotSupported():
-        sse_encode_i_32(15, serializer);
+        sse_encode_i_32(17, serializer);
       case PaymentError_SendError(err: final err):
-        sse_encode_i_32(16, serializer);
+        sse_encode_i_32(18, serializer);
         sse_encode_String(err, serializer);
       case PaymentError_SignerError(err: final err):
-        sse_encode_i_32(17, serializer);
+        sse_encode_i_32(19, serializer);
         sse_encode_String(err, serializer);
+      case PaymentError_WatchOnly():
+        sse_encode_i_32(20, serializer);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  void sse_encode_payment_fiat_rate(PaymentFiatRate self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.coin, serializer);
+    sse_encode_f_64(self.value, serializer);
+    sse_encode_u_32(self.createdAt, serializer);
+  }
+
+  @protected
+  void sse_encode_payment_kind(PaymentKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_payment_lookup(PaymentLookup self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case PaymentLookup_SwapId(swapId: final swapId):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(swapId, serializer);
+      case PaymentLookup_Invoice(invoice: final invoice):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(invoice, serializer);
+      case PaymentLookup_PaymentHash(paymentHash: final paymentHash):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(paymentHash, serializer);
+      case PaymentLookup_TxId(txId: final txId):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(txId, serializer);
+      case PaymentLookup_Destination(address: final address):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(address, serializer);
       default:
         throw UnimplementedError('');
     }
   }
 
+  @protected
+  void sse_encode_payment_metadata(PaymentMetadata self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.key, serializer);
+    sse_encode_String(self.value, serializer);
+  }
+
+  @protected
+  void sse_encode_payment_metadata_filter(PaymentMetadataFilter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.key, serializer);
+    sse_encode_opt_String(self.value, serializer);
+  }
+
   @protected
   void sse_encode_payment_state(PaymentState self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5276,6 +7614,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_u_64(self.receiverAmountSat, serializer);
     sse_encode_opt_box_autoadd_u_32(self.satPerVbyte, serializer);
+    sse_encode_opt_box_autoadd_bool(self.drain, serializer);
   }
 
   @protected
@@ -5284,21 +7623,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_64(self.receiverAmountSat, serializer);
     sse_encode_u_64(self.claimFeesSat, serializer);
     sse_encode_u_64(self.totalFeesSat, serializer);
+    sse_encode_bool(self.drain, serializer);
   }
 
   @protected
   void sse_encode_prepare_receive_onchain_request(
       PrepareReceiveOnchainRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self.payerAmountSat, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.payerAmountSat, serializer);
   }
 
   @protected
   void sse_encode_prepare_receive_onchain_response(
       PrepareReceiveOnchainResponse self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self.payerAmountSat, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.payerAmountSat, serializer);
     sse_encode_u_64(self.feesSat, serializer);
+    sse_encode_f_64(self.swapperFeerate, serializer);
   }
 
   @protected
@@ -5332,17 +7673,43 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.refundTxId, serializer);
   }
 
+  @protected
+  void sse_encode_prepare_send_liquid_request(PrepareSendLiquidRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.destination, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.amountSat, serializer);
+    sse_encode_opt_box_autoadd_bool(self.drain, serializer);
+    sse_encode_opt_String(self.assetId, serializer);
+  }
+
+  @protected
+  void sse_encode_prepare_send_liquid_response(PrepareSendLiquidResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.address, serializer);
+    sse_encode_u_64(self.receiverAmountSat, serializer);
+    sse_encode_u_64(self.feesSat, serializer);
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_bool(self.drain, serializer);
+    sse_encode_opt_String(self.assetId, serializer);
+  }
+
   @protected
   void sse_encode_prepare_send_request(PrepareSendRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.invoice, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.amountSat, serializer);
+    sse_encode_opt_String(self.idempotencyKey, serializer);
+    sse_encode_opt_String(self.payerNote, serializer);
   }
 
   @protected
   void sse_encode_prepare_send_response(PrepareSendResponse self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.invoice, serializer);
+    sse_encode_opt_String(self.offer, serializer);
+    sse_encode_u_64(self.receiverAmountSat, serializer);
     sse_encode_u_64(self.feesSat, serializer);
+    sse_encode_opt_String(self.idempotencyKey, serializer);
   }
 
   @protected
@@ -5352,6 +7719,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_f_64(self.value, serializer);
   }
 
+  @protected
+  void sse_encode_receive_liquid_request(ReceiveLiquidRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_u_64(self.amountSat, serializer);
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_opt_String(self.assetId, serializer);
+  }
+
+  @protected
+  void sse_encode_receive_liquid_response(ReceiveLiquidResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.address, serializer);
+    sse_encode_String(self.bip21, serializer);
+  }
+
   @protected
   void sse_encode_receive_onchain_response(ReceiveOnchainResponse self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5383,6 +7765,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_64(self.minimumFee, serializer);
   }
 
+  @protected
+  void sse_encode_refund_destination(RefundDestination self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case RefundDestination_Address(address: final address):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(address, serializer);
+      case RefundDestination_Xpub(xpub: final xpub):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(xpub, serializer);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  void sse_encode_refund_fee_rate(RefundFeeRate self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_refund_request(RefundRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5470,8 +7873,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case SdkEvent_PaymentWaitingConfirmation(details: final details):
         sse_encode_i_32(5, serializer);
         sse_encode_box_autoadd_payment(details, serializer);
-      case SdkEvent_Synced():
+      case SdkEvent_SwapAutoRefunded(details: final details):
         sse_encode_i_32(6, serializer);
+        sse_encode_box_autoadd_auto_refund_details(details, serializer);
+      case SdkEvent_SwapWaitingFeeAcceptance(swapId: final swapId):
+        sse_encode_i_32(7, serializer);
+        sse_encode_String(swapId, serializer);
+      case SdkEvent_Synced():
+        sse_encode_i_32(8, serializer);
       default:
         throw UnimplementedError('');
     }
@@ -5483,6 +7892,66 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_payment(self.payment, serializer);
   }
 
+  @protected
+  void sse_encode_set_payment_metadata_request(SetPaymentMetadataRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.paymentId, serializer);
+    sse_encode_String(self.key, serializer);
+    sse_encode_String(self.value, serializer);
+  }
+
+  @protected
+  void sse_encode_signer_method(SignerMethod self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case SignerMethod_Xpub():
+        sse_encode_i_32(0, serializer);
+      case SignerMethod_DeriveXpub(derivationPath: final derivationPath):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(derivationPath, serializer);
+      case SignerMethod_Slip77MasterBlindingKey():
+        sse_encode_i_32(2, serializer);
+      case SignerMethod_SignPset(pset: final pset):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(pset, serializer);
+      case SignerMethod_SignEcdsa(msg: final msg, derivationPath: final derivationPath):
+        sse_encode_i_32(4, serializer);
+        sse_encode_list_prim_u_8_strict(msg, serializer);
+        sse_encode_String(derivationPath, serializer);
+      case SignerMethod_HmacSha256(msg: final msg, derivationPath: final derivationPath):
+        sse_encode_i_32(5, serializer);
+        sse_encode_list_prim_u_8_strict(msg, serializer);
+        sse_encode_String(derivationPath, serializer);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  void sse_encode_signer_request(SignerRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.id, serializer);
+    sse_encode_signer_method(self.method, serializer);
+  }
+
+  @protected
+  void sse_encode_signer_result(SignerResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case SignerResult_String(value: final value):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(value, serializer);
+      case SignerResult_Bytes(value: final value):
+        sse_encode_i_32(1, serializer);
+        sse_encode_list_prim_u_8_strict(value, serializer);
+      case SignerResult_Error(err: final err):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(err, serializer);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
   @protected
   void sse_encode_success_action_processed(SuccessActionProcessed self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5570,6 +8039,9 @@ class BindingLiquidSdkImpl extends RustOpaque implements BindingLiquidSdk {
     rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_BindingLiquidSdkPtr,
   );
 
+  Future<void> acceptPaymentProposedFees({required AcceptPaymentProposedFeesRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkAcceptPaymentProposedFees(that: this, req: req);
+
   Stream<SdkEvent> addEventListener() => RustLib.instance.api.crateBindingsBindingLiquidSdkAddEventListener(
         that: this,
       );
@@ -5577,9 +8049,15 @@ class BindingLiquidSdkImpl extends RustOpaque implements BindingLiquidSdk {
   void backup({required BackupRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkBackup(that: this, req: req);
 
+  Future<BumpRefundFeeResponse> bumpRefundFee({required BumpRefundFeeRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkBumpRefundFee(that: this, req: req);
+
   Future<String> buyBitcoin({required BuyBitcoinRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkBuyBitcoin(that: this, req: req);
 
+  Future<void> deletePaymentMetadata({required DeletePaymentMetadataRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkDeletePaymentMetadata(that: this, req: req);
+
   Future<void> disconnect() => RustLib.instance.api.crateBindingsBindingLiquidSdkDisconnect(
         that: this,
       );
@@ -5588,6 +8066,9 @@ class BindingLiquidSdkImpl extends RustOpaque implements BindingLiquidSdk {
         that: this,
       );
 
+  Future<void> exportPayments({required ExportPaymentsRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkExportPayments(that: this, req: req);
+
   Future<List<Rate>> fetchFiatRates() => RustLib.instance.api.crateBindingsBindingLiquidSdkFetchFiatRates(
         that: this,
       );
@@ -5602,10 +8083,20 @@ class BindingLiquidSdkImpl extends RustOpaque implements BindingLiquidSdk {
         that: this,
       );
 
+  Future<FetchPaymentProposedFeesResponse> fetchPaymentProposedFees(
+          {required FetchPaymentProposedFeesRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkFetchPaymentProposedFees(that: this, req: req);
+
   Future<GetInfoResponse> getInfo() => RustLib.instance.api.crateBindingsBindingLiquidSdkGetInfo(
         that: this,
       );
 
+  Future<Payment?> getPayment({required PaymentLookup req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkGetPayment(that: this, req: req);
+
+  Future<List<PaymentMetadata>> getPaymentMetadata({required String paymentId}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkGetPaymentMetadata(that: this, paymentId: paymentId);
+
   Future<List<FiatCurrency>> listFiatCurrencies() =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkListFiatCurrencies(
         that: this,
@@ -5614,6 +8105,9 @@ class BindingLiquidSdkImpl extends RustOpaque implements BindingLiquidSdk {
   Future<List<Payment>> listPayments({required ListPaymentsRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkListPayments(that: this, req: req);
 
+  Future<ListPaymentsResponse> listPaymentsPage({required ListPaymentsRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkListPaymentsPage(that: this, req: req);
+
   Future<List<RefundableSwap>> listRefundables() =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkListRefundables(
         that: this,
@@ -5646,9 +8140,15 @@ class BindingLiquidSdkImpl extends RustOpaque implements BindingLiquidSdk {
   Future<PrepareRefundResponse> prepareRefund({required PrepareRefundRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkPrepareRefund(that: this, req: req);
 
+  Future<PrepareSendLiquidResponse> prepareSendLiquid({required PrepareSendLiquidRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkPrepareSendLiquid(that: this, req: req);
+
   Future<PrepareSendResponse> prepareSendPayment({required PrepareSendRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkPrepareSendPayment(that: this, req: req);
 
+  Future<ReceiveLiquidResponse> receiveLiquid({required ReceiveLiquidRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkReceiveLiquid(that: this, req: req);
+
   Future<ReceiveOnchainResponse> receiveOnchain({required PrepareReceiveOnchainResponse req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkReceiveOnchain(that: this, req: req);
 
@@ -5670,9 +8170,15 @@ class BindingLiquidSdkImpl extends RustOpaque implements BindingLiquidSdk {
   void restore({required RestoreRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkRestore(that: this, req: req);
 
+  Future<SendPaymentResponse> sendLiquid({required PrepareSendLiquidResponse req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkSendLiquid(that: this, req: req);
+
   Future<SendPaymentResponse> sendPayment({required PrepareSendResponse req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkSendPayment(that: this, req: req);
 
+  Future<void> setPaymentMetadata({required SetPaymentMetadataRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkSetPaymentMetadata(that: this, req: req);
+
   Future<void> sync() => RustLib.instance.api.crateBindingsBindingLiquidSdkSync(
         that: this,
       );
@@ -47,18 +47,39 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RustStreamSink<SdkEvent> dco_decode_StreamSink_sdk_event_Dco(dynamic raw);
 
+  @protected
+  RustStreamSink<SignerRequest> dco_decode_StreamSink_signer_request_Dco(dynamic raw);
+
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  AcceptPaymentProposedFeesRequest dco_decode_accept_payment_proposed_fees_request(dynamic raw);
+
   @protected
   AesSuccessActionDataDecrypted dco_decode_aes_success_action_data_decrypted(dynamic raw);
 
   @protected
   AesSuccessActionDataResult dco_decode_aes_success_action_data_result(dynamic raw);
 
+  @protected
+  AssetBalance dco_decode_asset_balance(dynamic raw);
+
+  @protected
+  AssetMetadata dco_decode_asset_metadata(dynamic raw);
+
+  @protected
+  AutoRefundDetails dco_decode_auto_refund_details(dynamic raw);
+
+  @protected
+  AutoRefundPolicy dco_decode_auto_refund_policy(dynamic raw);
+
   @protected
   BackupRequest dco_decode_backup_request(dynamic raw);
 
+  @protected
+  BalanceDetails dco_decode_balance_details(dynamic raw);
+
   @protected
   BindingEventListener dco_decode_binding_event_listener(dynamic raw);
 
@@ -68,12 +89,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  AcceptPaymentProposedFeesRequest dco_decode_box_autoadd_accept_payment_proposed_fees_request(dynamic raw);
+
   @protected
   AesSuccessActionDataDecrypted dco_decode_box_autoadd_aes_success_action_data_decrypted(dynamic raw);
 
   @protected
   AesSuccessActionDataResult dco_decode_box_autoadd_aes_success_action_data_result(dynamic raw);
 
+  @protected
+  AssetMetadata dco_decode_box_autoadd_asset_metadata(dynamic raw);
+
+  @protected
+  AutoRefundDetails dco_decode_box_autoadd_auto_refund_details(dynamic raw);
+
+  @protected
+  AutoRefundPolicy dco_decode_box_autoadd_auto_refund_policy(dynamic raw);
+
   @protected
   BackupRequest dco_decode_box_autoadd_backup_request(dynamic raw);
 
@@ -86,12 +119,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool dco_decode_box_autoadd_bool(dynamic raw);
 
+  @protected
+  BumpRefundFeeRequest dco_decode_box_autoadd_bump_refund_fee_request(dynamic raw);
+
   @protected
   BuyBitcoinRequest dco_decode_box_autoadd_buy_bitcoin_request(dynamic raw);
 
   @protected
   ConnectRequest dco_decode_box_autoadd_connect_request(dynamic raw);
 
+  @protected
+  DeletePaymentMetadataRequest dco_decode_box_autoadd_delete_payment_metadata_request(dynamic raw);
+
+  @protected
+  ExportPaymentsRequest dco_decode_box_autoadd_export_payments_request(dynamic raw);
+
+  @protected
+  FetchPaymentProposedFeesRequest dco_decode_box_autoadd_fetch_payment_proposed_fees_request(dynamic raw);
+
   @protected
   PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
 
@@ -137,6 +182,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Payment dco_decode_box_autoadd_payment(dynamic raw);
 
+  @protected
+  PaymentLookup dco_decode_box_autoadd_payment_lookup(dynamic raw);
+
   @protected
   PrepareBuyBitcoinRequest dco_decode_box_autoadd_prepare_buy_bitcoin_request(dynamic raw);
 
@@ -155,12 +203,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PrepareRefundRequest dco_decode_box_autoadd_prepare_refund_request(dynamic raw);
 
+  @protected
+  PrepareSendLiquidRequest dco_decode_box_autoadd_prepare_send_liquid_request(dynamic raw);
+
+  @protected
+  PrepareSendLiquidResponse dco_decode_box_autoadd_prepare_send_liquid_response(dynamic raw);
+
   @protected
   PrepareSendRequest dco_decode_box_autoadd_prepare_send_request(dynamic raw);
 
   @protected
   PrepareSendResponse dco_decode_box_autoadd_prepare_send_response(dynamic raw);
 
+  @protected
+  ReceiveLiquidRequest dco_decode_box_autoadd_receive_liquid_request(dynamic raw);
+
   @protected
   ReceivePaymentRequest dco_decode_box_autoadd_receive_payment_request(dynamic raw);
 
@@ -173,6 +230,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   SdkEvent dco_decode_box_autoadd_sdk_event(dynamic raw);
 
+  @protected
+  SetPaymentMetadataRequest dco_decode_box_autoadd_set_payment_metadata_request(dynamic raw);
+
+  @protected
+  SignerResult dco_decode_box_autoadd_signer_result(dynamic raw);
+
   @protected
   SuccessActionProcessed dco_decode_box_autoadd_success_action_processed(dynamic raw);
 
@@ -188,12 +251,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   UrlSuccessActionData dco_decode_box_autoadd_url_success_action_data(dynamic raw);
 
+  @protected
+  BumpRefundFeeRequest dco_decode_bump_refund_fee_request(dynamic raw);
+
+  @protected
+  BumpRefundFeeResponse dco_decode_bump_refund_fee_response(dynamic raw);
+
   @protected
   BuyBitcoinProvider dco_decode_buy_bitcoin_provider(dynamic raw);
 
   @protected
   BuyBitcoinRequest dco_decode_buy_bitcoin_request(dynamic raw);
 
+  @protected
+  ChainBackend dco_decode_chain_backend(dynamic raw);
+
   @protected
   Config dco_decode_config(dynamic raw);
 
@@ -203,9 +275,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   CurrencyInfo dco_decode_currency_info(dynamic raw);
 
+  @protected
+  DeletePaymentMetadataRequest dco_decode_delete_payment_metadata_request(dynamic raw);
+
+  @protected
+  ExportFormat dco_decode_export_format(dynamic raw);
+
+  @protected
+  ExportPaymentsRequest dco_decode_export_payments_request(dynamic raw);
+
   @protected
   double dco_decode_f_64(dynamic raw);
 
+  @protected
+  FetchPaymentProposedFeesRequest dco_decode_fetch_payment_proposed_fees_request(dynamic raw);
+
+  @protected
+  FetchPaymentProposedFeesResponse dco_decode_fetch_payment_proposed_fees_response(dynamic raw);
+
   @protected
   FiatCurrency dco_decode_fiat_currency(dynamic raw);
 
@@ -230,6 +317,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   LiquidNetwork dco_decode_liquid_network(dynamic raw);
 
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<AssetBalance> dco_decode_list_asset_balance(dynamic raw);
+
+  @protected
+  List<AssetMetadata> dco_decode_list_asset_metadata(dynamic raw);
+
   @protected
   List<FiatCurrency> dco_decode_list_fiat_currency(dynamic raw);
 
@@ -242,12 +338,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<Payment> dco_decode_list_payment(dynamic raw);
 
+  @protected
+  List<PaymentFiatRate> dco_decode_list_payment_fiat_rate(dynamic raw);
+
+  @protected
+  List<PaymentKind> dco_decode_list_payment_kind(dynamic raw);
+
+  @protected
+  List<PaymentMetadata> dco_decode_list_payment_metadata(dynamic raw);
+
+  @protected
+  List<PaymentMetadataFilter> dco_decode_list_payment_metadata_filter(dynamic raw);
+
+  @protected
+  List<PaymentState> dco_decode_list_payment_state(dynamic raw);
+
   @protected
   List<PaymentType> dco_decode_list_payment_type(dynamic raw);
 
   @protected
   ListPaymentsRequest dco_decode_list_payments_request(dynamic raw);
 
+  @protected
+  ListPaymentsResponse dco_decode_list_payments_response(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
@@ -266,6 +380,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   LNInvoice dco_decode_ln_invoice(dynamic raw);
 
+  @protected
+  LNOffer dco_decode_ln_offer(dynamic raw);
+
   @protected
   LnUrlAuthError dco_decode_ln_url_auth_error(dynamic raw);
 
@@ -332,12 +449,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
+  @protected
+  AssetMetadata? dco_decode_opt_box_autoadd_asset_metadata(dynamic raw);
+
+  @protected
+  AutoRefundPolicy? dco_decode_opt_box_autoadd_auto_refund_policy(dynamic raw);
+
   @protected
   bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
 
   @protected
   PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
 
+  @protected
+  Payment? dco_decode_opt_box_autoadd_payment(dynamic raw);
+
   @protected
   SuccessActionProcessed? dco_decode_opt_box_autoadd_success_action_processed(dynamic raw);
 
@@ -350,6 +476,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw);
+
+  @protected
+  List<AssetMetadata>? dco_decode_opt_list_asset_metadata(dynamic raw);
+
+  @protected
+  List<PaymentKind>? dco_decode_opt_list_payment_kind(dynamic raw);
+
+  @protected
+  List<PaymentMetadataFilter>? dco_decode_opt_list_payment_metadata_filter(dynamic raw);
+
+  @protected
+  List<PaymentState>? dco_decode_opt_list_payment_state(dynamic raw);
+
   @protected
   List<PaymentType>? dco_decode_opt_list_payment_type(dynamic raw);
 
@@ -362,6 +503,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PaymentError dco_decode_payment_error(dynamic raw);
 
+  @protected
+  PaymentFiatRate dco_decode_payment_fiat_rate(dynamic raw);
+
+  @protected
+  PaymentKind dco_decode_payment_kind(dynamic raw);
+
+  @protected
+  PaymentLookup dco_decode_payment_lookup(dynamic raw);
+
+  @protected
+  PaymentMetadata dco_decode_payment_metadata(dynamic raw);
+
+  @protected
+  PaymentMetadataFilter dco_decode_payment_metadata_filter(dynamic raw);
+
   @protected
   PaymentState dco_decode_payment_state(dynamic raw);
 
@@ -398,6 +554,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PrepareRefundResponse dco_decode_prepare_refund_response(dynamic raw);
 
+  @protected
+  PrepareSendLiquidRequest dco_decode_prepare_send_liquid_request(dynamic raw);
+
+  @protected
+  PrepareSendLiquidResponse dco_decode_prepare_send_liquid_response(dynamic raw);
+
   @protected
   PrepareSendRequest dco_decode_prepare_send_request(dynamic raw);
 
@@ -407,6 +569,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Rate dco_decode_rate(dynamic raw);
 
+  @protected
+  ReceiveLiquidRequest dco_decode_receive_liquid_request(dynamic raw);
+
+  @protected
+  ReceiveLiquidResponse dco_decode_receive_liquid_response(dynamic raw);
+
   @protected
   ReceiveOnchainResponse dco_decode_receive_onchain_response(dynamic raw);
 
@@ -419,6 +587,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RecommendedFees dco_decode_recommended_fees(dynamic raw);
 
+  @protected
+  RefundDestination dco_decode_refund_destination(dynamic raw);
+
+  @protected
+  RefundFeeRate dco_decode_refund_fee_rate(dynamic raw);
+
   @protected
   RefundRequest dco_decode_refund_request(dynamic raw);
 
@@ -446,6 +620,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   SendPaymentResponse dco_decode_send_payment_response(dynamic raw);
 
+  @protected
+  SetPaymentMetadataRequest dco_decode_set_payment_metadata_request(dynamic raw);
+
+  @protected
+  SignerMethod dco_decode_signer_method(dynamic raw);
+
+  @protected
+  SignerRequest dco_decode_signer_request(dynamic raw);
+
+  @protected
+  SignerResult dco_decode_signer_result(dynamic raw);
+
   @protected
   SuccessActionProcessed dco_decode_success_action_processed(dynamic raw);
 
@@ -496,18 +682,40 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RustStreamSink<SdkEvent> sse_decode_StreamSink_sdk_event_Dco(SseDeserializer deserializer);
 
+  @protected
+  RustStreamSink<SignerRequest> sse_decode_StreamSink_signer_request_Dco(SseDeserializer deserializer);
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  AcceptPaymentProposedFeesRequest sse_decode_accept_payment_proposed_fees_request(
+      SseDeserializer deserializer);
+
   @protected
   AesSuccessActionDataDecrypted sse_decode_aes_success_action_data_decrypted(SseDeserializer deserializer);
 
   @protected
   AesSuccessActionDataResult sse_decode_aes_success_action_data_result(SseDeserializer deserializer);
 
+  @protected
+  AssetBalance sse_decode_asset_balance(SseDeserializer deserializer);
+
+  @protected
+  AssetMetadata sse_decode_asset_metadata(SseDeserializer deserializer);
+
+  @protected
+  AutoRefundDetails sse_decode_auto_refund_details(SseDeserializer deserializer);
+
+  @protected
+  AutoRefundPolicy sse_decode_auto_refund_policy(SseDeserializer deserializer);
+
   @protected
   BackupRequest sse_decode_backup_request(SseDeserializer deserializer);
 
+  @protected
+  BalanceDetails sse_decode_balance_details(SseDeserializer deserializer);
+
   @protected
   BindingEventListener sse_decode_binding_event_listener(SseDeserializer deserializer);
 
@@ -517,6 +725,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  AcceptPaymentProposedFeesRequest sse_decode_box_autoadd_accept_payment_proposed_fees_request(
+      SseDeserializer deserializer);
+
   @protected
   AesSuccessActionDataDecrypted sse_decode_box_autoadd_aes_success_action_data_decrypted(
       SseDeserializer deserializer);
@@ -525,6 +737,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   AesSuccessActionDataResult sse_decode_box_autoadd_aes_success_action_data_result(
       SseDeserializer deserializer);
 
+  @protected
+  AssetMetadata sse_decode_box_autoadd_asset_metadata(SseDeserializer deserializer);
+
+  @protected
+  AutoRefundDetails sse_decode_box_autoadd_auto_refund_details(SseDeserializer deserializer);
+
+  @protected
+  AutoRefundPolicy sse_decode_box_autoadd_auto_refund_policy(SseDeserializer deserializer);
+
   @protected
   BackupRequest sse_decode_box_autoadd_backup_request(SseDeserializer deserializer);
 
@@ -537,12 +758,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
 
+  @protected
+  BumpRefundFeeRequest sse_decode_box_autoadd_bump_refund_fee_request(SseDeserializer deserializer);
+
   @protected
   BuyBitcoinRequest sse_decode_box_autoadd_buy_bitcoin_request(SseDeserializer deserializer);
 
   @protected
   ConnectRequest sse_decode_box_autoadd_connect_request(SseDeserializer deserializer);
 
+  @protected
+  DeletePaymentMetadataRequest sse_decode_box_autoadd_delete_payment_metadata_request(
+      SseDeserializer deserializer);
+
+  @protected
+  ExportPaymentsRequest sse_decode_box_autoadd_export_payments_request(SseDeserializer deserializer);
+
+  @protected
+  FetchPaymentProposedFeesRequest sse_decode_box_autoadd_fetch_payment_proposed_fees_request(
+      SseDeserializer deserializer);
+
   @protected
   PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
 
@@ -588,6 +823,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Payment sse_decode_box_autoadd_payment(SseDeserializer deserializer);
 
+  @protected
+  PaymentLookup sse_decode_box_autoadd_payment_lookup(SseDeserializer deserializer);
+
   @protected
   PrepareBuyBitcoinRequest sse_decode_box_autoadd_prepare_buy_bitcoin_request(SseDeserializer deserializer);
 
@@ -609,12 +847,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PrepareRefundRequest sse_decode_box_autoadd_prepare_refund_request(SseDeserializer deserializer);
 
+  @protected
+  PrepareSendLiquidRequest sse_decode_box_autoadd_prepare_send_liquid_request(SseDeserializer deserializer);
+
+  @protected
+  PrepareSendLiquidResponse sse_decode_box_autoadd_prepare_send_liquid_response(SseDeserializer deserializer);
+
   @protected
   PrepareSendRequest sse_decode_box_autoadd_prepare_send_request(SseDeserializer deserializer);
 
   @protected
   PrepareSendResponse sse_decode_box_autoadd_prepare_send_response(SseDeserializer deserializer);
 
+  @protected
+  ReceiveLiquidRequest sse_decode_box_autoadd_receive_liquid_request(SseDeserializer deserializer);
+
   @protected
   ReceivePaymentRequest sse_decode_box_autoadd_receive_payment_request(SseDeserializer deserializer);
 
@@ -627,6 +874,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   SdkEvent sse_decode_box_autoadd_sdk_event(SseDeserializer deserializer);
 
+  @protected
+  SetPaymentMetadataRequest sse_decode_box_autoadd_set_payment_metadata_request(SseDeserializer deserializer);
+
+  @protected
+  SignerResult sse_decode_box_autoadd_signer_result(SseDeserializer deserializer);
+
   @protected
   SuccessActionProcessed sse_decode_box_autoadd_success_action_processed(SseDeserializer deserializer);
 
@@ -642,12 +895,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   UrlSuccessActionData sse_decode_box_autoadd_url_success_action_data(SseDeserializer deserializer);
 
+  @protected
+  BumpRefundFeeRequest sse_decode_bump_refund_fee_request(SseDeserializer deserializer);
+
+  @protected
+  BumpRefundFeeResponse sse_decode_bump_refund_fee_response(SseDeserializer deserializer);
+
   @protected
   BuyBitcoinProvider sse_decode_buy_bitcoin_provider(SseDeserializer deserializer);
 
   @protected
   BuyBitcoinRequest sse_decode_buy_bitcoin_request(SseDeserializer deserializer);
 
+  @protected
+  ChainBackend sse_decode_chain_backend(SseDeserializer deserializer);
+
   @protected
   Config sse_decode_config(SseDeserializer deserializer);
 
@@ -657,9 +919,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   CurrencyInfo sse_decode_currency_info(SseDeserializer deserializer);
 
+  @protected
+  DeletePaymentMetadataRequest sse_decode_delete_payment_metadata_request(SseDeserializer deserializer);
+
+  @protected
+  ExportFormat sse_decode_export_format(SseDeserializer deserializer);
+
+  @protected
+  ExportPaymentsRequest sse_decode_export_payments_request(SseDeserializer deserializer);
+
   @protected
   double sse_decode_f_64(SseDeserializer deserializer);
 
+  @protected
+  FetchPaymentProposedFeesRequest sse_decode_fetch_payment_proposed_fees_request(
+      SseDeserializer deserializer);
+
+  @protected
+  FetchPaymentProposedFeesResponse sse_decode_fetch_payment_proposed_fees_response(
+      SseDeserializer deserializer);
+
   @protected
   FiatCurrency sse_decode_fiat_currency(SseDeserializer deserializer);
 
@@ -684,6 +963,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   LiquidNetwork sse_decode_liquid_network(SseDeserializer deserializer);
 
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<AssetBalance> sse_decode_list_asset_balance(SseDeserializer deserializer);
+
+  @protected
+  List<AssetMetadata> sse_decode_list_asset_metadata(SseDeserializer deserializer);
+
   @protected
   List<FiatCurrency> sse_decode_list_fiat_currency(SseDeserializer deserializer);
 
@@ -696,12 +984,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<Payment> sse_decode_list_payment(SseDeserializer deserializer);
 
+  @protected
+  List<PaymentFiatRate> sse_decode_list_payment_fiat_rate(SseDeserializer deserializer);
+
+  @protected
+  List<PaymentKind> sse_decode_list_payment_kind(SseDeserializer deserializer);
+
+  @protected
+  List<PaymentMetadata> sse_decode_list_payment_metadata(SseDeserializer deserializer);
+
+  @protected
+  List<PaymentMetadataFilter> sse_decode_list_payment_metadata_filter(SseDeserializer deserializer);
+
+  @protected
+  List<PaymentState> sse_decode_list_payment_state(SseDeserializer deserializer);
+
   @protected
   List<PaymentType> sse_decode_list_payment_type(SseDeserializer deserializer);
 
   @protected
   ListPaymentsRequest sse_decode_list_payments_request(SseDeserializer deserializer);
 
+  @protected
+  ListPaymentsResponse sse_decode_list_payments_response(SseDeserializer deserializer);
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
@@ -720,6 +1026,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   LNInvoice sse_decode_ln_invoice(SseDeserializer deserializer);
 
+  @protected
+  LNOffer sse_decode_ln_offer(SseDeserializer deserializer);
+
   @protected
   LnUrlAuthError sse_decode_ln_url_auth_error(SseDeserializer deserializer);
 
@@ -786,12 +1095,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
+  @protected
+  AssetMetadata? sse_decode_opt_box_autoadd_asset_metadata(SseDeserializer deserializer);
+
+  @protected
+  AutoRefundPolicy? sse_decode_opt_box_autoadd_auto_refund_policy(SseDeserializer deserializer);
+
   @protected
   bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
 
   @protected
   PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
 
+  @protected
+  Payment? sse_decode_opt_box_autoadd_payment(SseDeserializer deserializer);
+
   @protected
   SuccessActionProcessed? sse_decode_opt_box_autoadd_success_action_processed(SseDeserializer deserializer);
 
@@ -804,6 +1122,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
 
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<AssetMetadata>? sse_decode_opt_list_asset_metadata(SseDeserializer deserializer);
+
+  @protected
+  List<PaymentKind>? sse_decode_opt_list_payment_kind(SseDeserializer deserializer);
+
+  @protected
+  List<PaymentMetadataFilter>? sse_decode_opt_list_payment_metadata_filter(SseDeserializer deserializer);
+
+  @protected
+  List<PaymentState>? sse_decode_opt_list_payment_state(SseDeserializer deserializer);
+
   @protected
   List<PaymentType>? sse_decode_opt_list_payment_type(SseDeserializer deserializer);
 
@@ -816,6 +1149,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PaymentError sse_decode_payment_error(SseDeserializer deserializer);
 
+  @protected
+  PaymentFiatRate sse_decode_payment_fiat_rate(SseDeserializer deserializer);
+
+  @protected
+  PaymentKind sse_decode_payment_kind(SseDeserializer deserializer);
+
+  @protected
+  PaymentLookup sse_decode_payment_lookup(SseDeserializer deserializer);
+
+  @protected
+  PaymentMetadata sse_decode_payment_metadata(SseDeserializer deserializer);
+
+  @protected
+  PaymentMetadataFilter sse_decode_payment_metadata_filter(SseDeserializer deserializer);
+
   @protected
   PaymentState sse_decode_payment_state(SseDeserializer deserializer);
 
@@ -852,6 +1200,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PrepareRefundResponse sse_decode_prepare_refund_response(SseDeserializer deserializer);
 
+  @protected
+  PrepareSendLiquidRequest sse_decode_prepare_send_liquid_request(SseDeserializer deserializer);
+
+  @protected
+  PrepareSendLiquidResponse sse_decode_prepare_send_liquid_response(SseDeserializer deserializer);
+
   @protected
   PrepareSendRequest sse_decode_prepare_send_request(SseDeserializer deserializer);
 
@@ -861,6 +1215,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Rate sse_decode_rate(SseDeserializer deserializer);
 
+  @protected
+  ReceiveLiquidRequest sse_decode_receive_liquid_request(SseDeserializer deserializer);
+
+  @protected
+  ReceiveLiquidResponse sse_decode_receive_liquid_response(SseDeserializer deserializer);
+
   @protected
   ReceiveOnchainResponse sse_decode_receive_onchain_response(SseDeserializer deserializer);
 
@@ -873,6 +1233,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RecommendedFees sse_decode_recommended_fees(SseDeserializer deserializer);
 
+  @protected
+  RefundDestination sse_decode_refund_destination(SseDeserializer deserializer);
+
+  @protected
+  RefundFeeRate sse_decode_refund_fee_rate(SseDeserializer deserializer);
+
   @protected
   RefundRequest sse_decode_refund_request(SseDeserializer deserializer);
 
@@ -900,6 +1266,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   SendPaymentResponse sse_decode_send_payment_response(SseDeserializer deserializer);
 
+  @protected
+  SetPaymentMetadataRequest sse_decode_set_payment_metadata_request(SseDeserializer deserializer);
+
+  @protected
+  SignerMethod sse_decode_signer_method(SseDeserializer deserializer);
+
+  @protected
+  SignerRequest sse_decode_signer_request(SseDeserializer deserializer);
+
+  @protected
+  SignerResult sse_decode_signer_result(SseDeserializer deserializer);
+
   @protected
   SuccessActionProcessed sse_decode_success_action_processed(SseDeserializer deserializer);
 
@@ -955,12 +1333,32 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     )));
   }
 
+  @protected
+  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_StreamSink_signer_request_Dco(
+      RustStreamSink<SignerRequest> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(raw.setupAndSerialize(
+        codec: DcoCodec(
+      decodeSuccessData: dco_decode_signer_request,
+      decodeErrorData: dco_decode_AnyhowException,
+    )));
+  }
+
   @protected
   ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_String(String raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     return cst_encode_list_prim_u_8_strict(utf8.encoder.convert(raw));
   }
 
+  @protected
+  ffi.Pointer<wire_cst_accept_payment_proposed_fees_request>
+      cst_encode_box_autoadd_accept_payment_proposed_fees_request(AcceptPaymentProposedFeesRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_accept_payment_proposed_fees_request();
+    cst_api_fill_to_wire_accept_payment_proposed_fees_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_aes_success_action_data_decrypted>
       cst_encode_box_autoadd_aes_success_action_data_decrypted(AesSuccessActionDataDecrypted raw) {
@@ -979,6 +1377,31 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_asset_metadata> cst_encode_box_autoadd_asset_metadata(AssetMetadata raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_asset_metadata();
+    cst_api_fill_to_wire_asset_metadata(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_auto_refund_details> cst_encode_box_autoadd_auto_refund_details(
+      AutoRefundDetails raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_auto_refund_details();
+    cst_api_fill_to_wire_auto_refund_details(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_auto_refund_policy> cst_encode_box_autoadd_auto_refund_policy(AutoRefundPolicy raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_auto_refund_policy();
+    cst_api_fill_to_wire_auto_refund_policy(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_backup_request> cst_encode_box_autoadd_backup_request(BackupRequest raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1011,6 +1434,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return wire.cst_new_box_autoadd_bool(cst_encode_bool(raw));
   }
 
+  @protected
+  ffi.Pointer<wire_cst_bump_refund_fee_request> cst_encode_box_autoadd_bump_refund_fee_request(
+      BumpRefundFeeRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_bump_refund_fee_request();
+    cst_api_fill_to_wire_bump_refund_fee_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_buy_bitcoin_request> cst_encode_box_autoadd_buy_bitcoin_request(
       BuyBitcoinRequest raw) {
@@ -1028,6 +1460,33 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_delete_payment_metadata_request>
+      cst_encode_box_autoadd_delete_payment_metadata_request(DeletePaymentMetadataRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_delete_payment_metadata_request();
+    cst_api_fill_to_wire_delete_payment_metadata_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_export_payments_request> cst_encode_box_autoadd_export_payments_request(
+      ExportPaymentsRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_export_payments_request();
+    cst_api_fill_to_wire_export_payments_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_fetch_payment_proposed_fees_request>
+      cst_encode_box_autoadd_fetch_payment_proposed_fees_request(FetchPaymentProposedFeesRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_fetch_payment_proposed_fees_request();
+    cst_api_fill_to_wire_fetch_payment_proposed_fees_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<ffi.Int64> cst_encode_box_autoadd_i_64(PlatformInt64 raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1156,6 +1615,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_payment_lookup> cst_encode_box_autoadd_payment_lookup(PaymentLookup raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_payment_lookup();
+    cst_api_fill_to_wire_payment_lookup(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_prepare_buy_bitcoin_request> cst_encode_box_autoadd_prepare_buy_bitcoin_request(
       PrepareBuyBitcoinRequest raw) {
@@ -1210,6 +1677,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_prepare_send_liquid_request> cst_encode_box_autoadd_prepare_send_liquid_request(
+      PrepareSendLiquidRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_prepare_send_liquid_request();
+    cst_api_fill_to_wire_prepare_send_liquid_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_prepare_send_liquid_response> cst_encode_box_autoadd_prepare_send_liquid_response(
+      PrepareSendLiquidResponse raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_prepare_send_liquid_response();
+    cst_api_fill_to_wire_prepare_send_liquid_response(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_prepare_send_request> cst_encode_box_autoadd_prepare_send_request(
       PrepareSendRequest raw) {
@@ -1228,6 +1713,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_receive_liquid_request> cst_encode_box_autoadd_receive_liquid_request(
+      ReceiveLiquidRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_receive_liquid_request();
+    cst_api_fill_to_wire_receive_liquid_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_receive_payment_request> cst_encode_box_autoadd_receive_payment_request(
       ReceivePaymentRequest raw) {
@@ -1261,6 +1755,23 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_set_payment_metadata_request> cst_encode_box_autoadd_set_payment_metadata_request(
+      SetPaymentMetadataRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_set_payment_metadata_request();
+    cst_api_fill_to_wire_set_payment_metadata_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_signer_result> cst_encode_box_autoadd_signer_result(SignerResult raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_signer_result();
+    cst_api_fill_to_wire_signer_result(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_success_action_processed> cst_encode_box_autoadd_success_action_processed(
       SuccessActionProcessed raw) {
@@ -1305,6 +1816,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return raw.toInt();
   }
 
+  @protected
+  ffi.Pointer<wire_cst_list_String> cst_encode_list_String(List<String> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_String(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = cst_encode_String(raw[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_asset_balance> cst_encode_list_asset_balance(List<AssetBalance> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_asset_balance(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_asset_balance(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_asset_metadata> cst_encode_list_asset_metadata(List<AssetMetadata> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_asset_metadata(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_asset_metadata(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_cst_list_fiat_currency> cst_encode_list_fiat_currency(List<FiatCurrency> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1345,6 +1886,57 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ans;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_list_payment_fiat_rate> cst_encode_list_payment_fiat_rate(List<PaymentFiatRate> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_payment_fiat_rate(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_payment_fiat_rate(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_payment_kind> cst_encode_list_payment_kind(List<PaymentKind> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_payment_kind(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = cst_encode_payment_kind(raw[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_payment_metadata> cst_encode_list_payment_metadata(List<PaymentMetadata> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_payment_metadata(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_payment_metadata(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_payment_metadata_filter> cst_encode_list_payment_metadata_filter(
+      List<PaymentMetadataFilter> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_payment_metadata_filter(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_payment_metadata_filter(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_payment_state> cst_encode_list_payment_state(List<PaymentState> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_payment_state(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = cst_encode_payment_state(raw[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_cst_list_payment_type> cst_encode_list_payment_type(List<PaymentType> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1409,6 +2001,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return raw == null ? ffi.nullptr : cst_encode_String(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_cst_asset_metadata> cst_encode_opt_box_autoadd_asset_metadata(AssetMetadata? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_asset_metadata(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_auto_refund_policy> cst_encode_opt_box_autoadd_auto_refund_policy(
+      AutoRefundPolicy? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_auto_refund_policy(raw);
+  }
+
   @protected
   ffi.Pointer<ffi.Bool> cst_encode_opt_box_autoadd_bool(bool? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1421,29 +2026,66 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return raw == null ? ffi.nullptr : cst_encode_box_autoadd_i_64(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_cst_payment> cst_encode_opt_box_autoadd_payment(Payment? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_payment(raw);
+  }
+
   @protected
   ffi.Pointer<wire_cst_success_action_processed> cst_encode_opt_box_autoadd_success_action_processed(
       SuccessActionProcessed? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_success_action_processed(raw);
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_success_action_processed(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_symbol> cst_encode_opt_box_autoadd_symbol(Symbol? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_symbol(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint32> cst_encode_opt_box_autoadd_u_32(int? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint64> cst_encode_opt_box_autoadd_u_64(BigInt? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_String> cst_encode_opt_list_String(List<String>? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_list_String(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_asset_metadata> cst_encode_opt_list_asset_metadata(List<AssetMetadata>? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_list_asset_metadata(raw);
   }
 
   @protected
-  ffi.Pointer<wire_cst_symbol> cst_encode_opt_box_autoadd_symbol(Symbol? raw) {
+  ffi.Pointer<wire_cst_list_payment_kind> cst_encode_opt_list_payment_kind(List<PaymentKind>? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_symbol(raw);
+    return raw == null ? ffi.nullptr : cst_encode_list_payment_kind(raw);
   }
 
   @protected
-  ffi.Pointer<ffi.Uint32> cst_encode_opt_box_autoadd_u_32(int? raw) {
+  ffi.Pointer<wire_cst_list_payment_metadata_filter> cst_encode_opt_list_payment_metadata_filter(
+      List<PaymentMetadataFilter>? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_32(raw);
+    return raw == null ? ffi.nullptr : cst_encode_list_payment_metadata_filter(raw);
   }
 
   @protected
-  ffi.Pointer<ffi.Uint64> cst_encode_opt_box_autoadd_u_64(BigInt? raw) {
+  ffi.Pointer<wire_cst_list_payment_state> cst_encode_opt_list_payment_state(List<PaymentState>? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_64(raw);
+    return raw == null ? ffi.nullptr : cst_encode_list_payment_state(raw);
   }
 
   @protected
@@ -1464,6 +2106,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return raw.toSigned(64).toInt();
   }
 
+  @protected
+  void cst_api_fill_to_wire_accept_payment_proposed_fees_request(
+      AcceptPaymentProposedFeesRequest apiObj, wire_cst_accept_payment_proposed_fees_request wireObj) {
+    cst_api_fill_to_wire_fetch_payment_proposed_fees_response(apiObj.response, wireObj.response);
+  }
+
   @protected
   void cst_api_fill_to_wire_aes_success_action_data_decrypted(
       AesSuccessActionDataDecrypted apiObj, wire_cst_aes_success_action_data_decrypted wireObj) {
@@ -1488,11 +2136,54 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     }
   }
 
+  @protected
+  void cst_api_fill_to_wire_asset_balance(AssetBalance apiObj, wire_cst_asset_balance wireObj) {
+    wireObj.asset_id = cst_encode_String(apiObj.assetId);
+    wireObj.balance = cst_encode_u_64(apiObj.balance);
+    wireObj.pending_send = cst_encode_u_64(apiObj.pendingSend);
+    wireObj.pending_receive = cst_encode_u_64(apiObj.pendingReceive);
+    wireObj.asset_metadata = cst_encode_opt_box_autoadd_asset_metadata(apiObj.assetMetadata);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_asset_metadata(AssetMetadata apiObj, wire_cst_asset_metadata wireObj) {
+    wireObj.asset_id = cst_encode_String(apiObj.assetId);
+    wireObj.name = cst_encode_String(apiObj.name);
+    wireObj.ticker = cst_encode_String(apiObj.ticker);
+    wireObj.precision = cst_encode_u_8(apiObj.precision);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_auto_refund_details(
+      AutoRefundDetails apiObj, wire_cst_auto_refund_details wireObj) {
+    wireObj.swap_address = cst_encode_String(apiObj.swapAddress);
+    wireObj.refund_address = cst_encode_String(apiObj.refundAddress);
+    wireObj.refund_tx_id = cst_encode_String(apiObj.refundTxId);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_auto_refund_policy(AutoRefundPolicy apiObj, wire_cst_auto_refund_policy wireObj) {
+    cst_api_fill_to_wire_refund_destination(apiObj.destination, wireObj.destination);
+    wireObj.fee_rate = cst_encode_refund_fee_rate(apiObj.feeRate);
+  }
+
   @protected
   void cst_api_fill_to_wire_backup_request(BackupRequest apiObj, wire_cst_backup_request wireObj) {
     wireObj.backup_path = cst_encode_opt_String(apiObj.backupPath);
   }
 
+  @protected
+  void cst_api_fill_to_wire_balance_details(BalanceDetails apiObj, wire_cst_balance_details wireObj) {
+    wireObj.confirmed_sat = cst_encode_u_64(apiObj.confirmedSat);
+    wireObj.unconfirmed_receive_sat = cst_encode_u_64(apiObj.unconfirmedReceiveSat);
+    wireObj.unconfirmed_send_sat = cst_encode_u_64(apiObj.unconfirmedSendSat);
+    wireObj.pending_send_swaps_sat = cst_encode_u_64(apiObj.pendingSendSwapsSat);
+    wireObj.pending_receive_swaps_sat = cst_encode_u_64(apiObj.pendingReceiveSwapsSat);
+    wireObj.pending_refund_sat = cst_encode_u_64(apiObj.pendingRefundSat);
+    wireObj.refundable_btc_sat = cst_encode_u_64(apiObj.refundableBtcSat);
+    wireObj.pending_btc_refund_sat = cst_encode_u_64(apiObj.pendingBtcRefundSat);
+  }
+
   @protected
   void cst_api_fill_to_wire_binding_event_listener(
       BindingEventListener apiObj, wire_cst_binding_event_listener wireObj) {
@@ -1509,6 +2200,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.message = cst_encode_opt_String(apiObj.message);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_accept_payment_proposed_fees_request(
+      AcceptPaymentProposedFeesRequest apiObj,
+      ffi.Pointer<wire_cst_accept_payment_proposed_fees_request> wireObj) {
+    cst_api_fill_to_wire_accept_payment_proposed_fees_request(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_aes_success_action_data_decrypted(
       AesSuccessActionDataDecrypted apiObj, ffi.Pointer<wire_cst_aes_success_action_data_decrypted> wireObj) {
@@ -1521,6 +2219,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_aes_success_action_data_result(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_asset_metadata(
+      AssetMetadata apiObj, ffi.Pointer<wire_cst_asset_metadata> wireObj) {
+    cst_api_fill_to_wire_asset_metadata(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_auto_refund_details(
+      AutoRefundDetails apiObj, ffi.Pointer<wire_cst_auto_refund_details> wireObj) {
+    cst_api_fill_to_wire_auto_refund_details(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_auto_refund_policy(
+      AutoRefundPolicy apiObj, ffi.Pointer<wire_cst_auto_refund_policy> wireObj) {
+    cst_api_fill_to_wire_auto_refund_policy(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_backup_request(
       BackupRequest apiObj, ffi.Pointer<wire_cst_backup_request> wireObj) {
@@ -1539,6 +2255,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_bitcoin_address_data(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_bump_refund_fee_request(
+      BumpRefundFeeRequest apiObj, ffi.Pointer<wire_cst_bump_refund_fee_request> wireObj) {
+    cst_api_fill_to_wire_bump_refund_fee_request(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_buy_bitcoin_request(
       BuyBitcoinRequest apiObj, ffi.Pointer<wire_cst_buy_bitcoin_request> wireObj) {
@@ -1551,6 +2273,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_connect_request(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_delete_payment_metadata_request(
+      DeletePaymentMetadataRequest apiObj, ffi.Pointer<wire_cst_delete_payment_metadata_request> wireObj) {
+    cst_api_fill_to_wire_delete_payment_metadata_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_export_payments_request(
+      ExportPaymentsRequest apiObj, ffi.Pointer<wire_cst_export_payments_request> wireObj) {
+    cst_api_fill_to_wire_export_payments_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_fetch_payment_proposed_fees_request(
+      FetchPaymentProposedFeesRequest apiObj,
+      ffi.Pointer<wire_cst_fetch_payment_proposed_fees_request> wireObj) {
+    cst_api_fill_to_wire_fetch_payment_proposed_fees_request(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_list_payments_request(
       ListPaymentsRequest apiObj, ffi.Pointer<wire_cst_list_payments_request> wireObj) {
@@ -1634,6 +2375,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_payment(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_payment_lookup(
+      PaymentLookup apiObj, ffi.Pointer<wire_cst_payment_lookup> wireObj) {
+    cst_api_fill_to_wire_payment_lookup(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_prepare_buy_bitcoin_request(
       PrepareBuyBitcoinRequest apiObj, ffi.Pointer<wire_cst_prepare_buy_bitcoin_request> wireObj) {
@@ -1670,6 +2417,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_prepare_refund_request(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_prepare_send_liquid_request(
+      PrepareSendLiquidRequest apiObj, ffi.Pointer<wire_cst_prepare_send_liquid_request> wireObj) {
+    cst_api_fill_to_wire_prepare_send_liquid_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_prepare_send_liquid_response(
+      PrepareSendLiquidResponse apiObj, ffi.Pointer<wire_cst_prepare_send_liquid_response> wireObj) {
+    cst_api_fill_to_wire_prepare_send_liquid_response(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_prepare_send_request(
       PrepareSendRequest apiObj, ffi.Pointer<wire_cst_prepare_send_request> wireObj) {
@@ -1682,6 +2441,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_prepare_send_response(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_receive_liquid_request(
+      ReceiveLiquidRequest apiObj, ffi.Pointer<wire_cst_receive_liquid_request> wireObj) {
+    cst_api_fill_to_wire_receive_liquid_request(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_receive_payment_request(
       ReceivePaymentRequest apiObj, ffi.Pointer<wire_cst_receive_payment_request> wireObj) {
@@ -1705,6 +2470,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_sdk_event(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_set_payment_metadata_request(
+      SetPaymentMetadataRequest apiObj, ffi.Pointer<wire_cst_set_payment_metadata_request> wireObj) {
+    cst_api_fill_to_wire_set_payment_metadata_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_signer_result(
+      SignerResult apiObj, ffi.Pointer<wire_cst_signer_result> wireObj) {
+    cst_api_fill_to_wire_signer_result(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_success_action_processed(
       SuccessActionProcessed apiObj, ffi.Pointer<wire_cst_success_action_processed> wireObj) {
@@ -1722,6 +2499,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_url_success_action_data(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_bump_refund_fee_request(
+      BumpRefundFeeRequest apiObj, wire_cst_bump_refund_fee_request wireObj) {
+    wireObj.swap_address = cst_encode_String(apiObj.swapAddress);
+    wireObj.sat_per_vbyte = cst_encode_u_32(apiObj.satPerVbyte);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_bump_refund_fee_response(
+      BumpRefundFeeResponse apiObj, wire_cst_bump_refund_fee_response wireObj) {
+    wireObj.refund_tx_id = cst_encode_String(apiObj.refundTxId);
+    wireObj.replaced_tx_id = cst_encode_String(apiObj.replacedTxId);
+    wireObj.tx_fee_sat = cst_encode_u_64(apiObj.txFeeSat);
+    wireObj.fee_delta_sat = cst_encode_u_64(apiObj.feeDeltaSat);
+  }
+
   @protected
   void cst_api_fill_to_wire_buy_bitcoin_request(
       BuyBitcoinRequest apiObj, wire_cst_buy_bitcoin_request wireObj) {
@@ -1734,17 +2527,29 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.liquid_electrum_url = cst_encode_String(apiObj.liquidElectrumUrl);
     wireObj.bitcoin_electrum_url = cst_encode_String(apiObj.bitcoinElectrumUrl);
     wireObj.mempoolspace_url = cst_encode_String(apiObj.mempoolspaceUrl);
+    wireObj.liquid_esplora_url = cst_encode_String(apiObj.liquidEsploraUrl);
+    wireObj.bitcoin_esplora_url = cst_encode_String(apiObj.bitcoinEsploraUrl);
+    wireObj.chain_backend = cst_encode_chain_backend(apiObj.chainBackend);
+    wireObj.breez_server_url = cst_encode_String(apiObj.breezServerUrl);
+    wireObj.boltz_url = cst_encode_opt_String(apiObj.boltzUrl);
+    wireObj.referral_id = cst_encode_opt_String(apiObj.referralId);
     wireObj.working_dir = cst_encode_String(apiObj.workingDir);
     wireObj.network = cst_encode_liquid_network(apiObj.network);
     wireObj.payment_timeout_sec = cst_encode_u_64(apiObj.paymentTimeoutSec);
     wireObj.zero_conf_min_fee_rate_msat = cst_encode_u_32(apiObj.zeroConfMinFeeRateMsat);
     wireObj.zero_conf_max_amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.zeroConfMaxAmountSat);
+    wireObj.asset_metadata = cst_encode_opt_list_asset_metadata(apiObj.assetMetadata);
+    wireObj.fiat_currencies = cst_encode_opt_list_String(apiObj.fiatCurrencies);
+    wireObj.proxy_url = cst_encode_opt_String(apiObj.proxyUrl);
+    wireObj.auto_refund = cst_encode_opt_box_autoadd_auto_refund_policy(apiObj.autoRefund);
+    wireObj.amountless_fee_tolerance_sat = cst_encode_opt_box_autoadd_u_64(apiObj.amountlessFeeToleranceSat);
   }
 
   @protected
   void cst_api_fill_to_wire_connect_request(ConnectRequest apiObj, wire_cst_connect_request wireObj) {
-    wireObj.mnemonic = cst_encode_String(apiObj.mnemonic);
+    wireObj.mnemonic = cst_encode_opt_String(apiObj.mnemonic);
     cst_api_fill_to_wire_config(apiObj.config, wireObj.config);
+    wireObj.watch_only_descriptor = cst_encode_opt_String(apiObj.watchOnlyDescriptor);
   }
 
   @protected
@@ -1758,6 +2563,38 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.locale_overrides = cst_encode_list_locale_overrides(apiObj.localeOverrides);
   }
 
+  @protected
+  void cst_api_fill_to_wire_delete_payment_metadata_request(
+      DeletePaymentMetadataRequest apiObj, wire_cst_delete_payment_metadata_request wireObj) {
+    wireObj.payment_id = cst_encode_String(apiObj.paymentId);
+    wireObj.key = cst_encode_opt_String(apiObj.key);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_export_payments_request(
+      ExportPaymentsRequest apiObj, wire_cst_export_payments_request wireObj) {
+    wireObj.export_path = cst_encode_String(apiObj.exportPath);
+    wireObj.format = cst_encode_export_format(apiObj.format);
+    wireObj.fiat_currency = cst_encode_opt_String(apiObj.fiatCurrency);
+    wireObj.from_timestamp = cst_encode_opt_box_autoadd_i_64(apiObj.fromTimestamp);
+    wireObj.to_timestamp = cst_encode_opt_box_autoadd_i_64(apiObj.toTimestamp);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_fetch_payment_proposed_fees_request(
+      FetchPaymentProposedFeesRequest apiObj, wire_cst_fetch_payment_proposed_fees_request wireObj) {
+    wireObj.swap_id = cst_encode_String(apiObj.swapId);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_fetch_payment_proposed_fees_response(
+      FetchPaymentProposedFeesResponse apiObj, wire_cst_fetch_payment_proposed_fees_response wireObj) {
+    wireObj.swap_id = cst_encode_String(apiObj.swapId);
+    wireObj.fees_sat = cst_encode_u_64(apiObj.feesSat);
+    wireObj.payer_amount_sat = cst_encode_u_64(apiObj.payerAmountSat);
+    wireObj.receiver_amount_sat = cst_encode_u_64(apiObj.receiverAmountSat);
+  }
+
   @protected
   void cst_api_fill_to_wire_fiat_currency(FiatCurrency apiObj, wire_cst_fiat_currency wireObj) {
     wireObj.id = cst_encode_String(apiObj.id);
@@ -1770,6 +2607,8 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.pending_send_sat = cst_encode_u_64(apiObj.pendingSendSat);
     wireObj.pending_receive_sat = cst_encode_u_64(apiObj.pendingReceiveSat);
     wireObj.pubkey = cst_encode_String(apiObj.pubkey);
+    wireObj.asset_balances = cst_encode_list_asset_balance(apiObj.assetBalances);
+    cst_api_fill_to_wire_balance_details(apiObj.balanceDetails, wireObj.balance_details);
   }
 
   @protected
@@ -1846,6 +2685,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.to_timestamp = cst_encode_opt_box_autoadd_i_64(apiObj.toTimestamp);
     wireObj.offset = cst_encode_opt_box_autoadd_u_32(apiObj.offset);
     wireObj.limit = cst_encode_opt_box_autoadd_u_32(apiObj.limit);
+    wireObj.metadata_filters = cst_encode_opt_list_payment_metadata_filter(apiObj.metadataFilters);
+    wireObj.states = cst_encode_opt_list_payment_state(apiObj.states);
+    wireObj.kinds = cst_encode_opt_list_payment_kind(apiObj.kinds);
+    wireObj.from_amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.fromAmountSat);
+    wireObj.to_amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.toAmountSat);
+    wireObj.asset_id = cst_encode_opt_String(apiObj.assetId);
+    wireObj.search = cst_encode_opt_String(apiObj.search);
+    wireObj.cursor = cst_encode_opt_String(apiObj.cursor);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_list_payments_response(
+      ListPaymentsResponse apiObj, wire_cst_list_payments_response wireObj) {
+    wireObj.payments = cst_encode_list_payment(apiObj.payments);
+    wireObj.next_cursor = cst_encode_opt_String(apiObj.nextCursor);
   }
 
   @protected
@@ -1864,6 +2718,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.min_final_cltv_expiry_delta = cst_encode_u_64(apiObj.minFinalCltvExpiryDelta);
   }
 
+  @protected
+  void cst_api_fill_to_wire_ln_offer(LNOffer apiObj, wire_cst_ln_offer wireObj) {
+    wireObj.offer = cst_encode_String(apiObj.offer);
+    wireObj.chains = cst_encode_list_String(apiObj.chains);
+    wireObj.amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.amountSat);
+    wireObj.description = cst_encode_opt_String(apiObj.description);
+    wireObj.absolute_expiry = cst_encode_opt_box_autoadd_u_64(apiObj.absoluteExpiry);
+    wireObj.issuer = cst_encode_opt_String(apiObj.issuer);
+  }
+
   @protected
   void cst_api_fill_to_wire_ln_url_auth_error(LnUrlAuthError apiObj, wire_cst_ln_url_auth_error wireObj) {
     if (apiObj is LnUrlAuthError_Generic) {
@@ -2173,6 +3037,7 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       PayOnchainRequest apiObj, wire_cst_pay_onchain_request wireObj) {
     wireObj.address = cst_encode_String(apiObj.address);
     cst_api_fill_to_wire_prepare_pay_onchain_response(apiObj.prepareRes, wireObj.prepare_res);
+    wireObj.idempotency_key = cst_encode_opt_String(apiObj.idempotencyKey);
   }
 
   @protected
@@ -2185,10 +3050,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.preimage = cst_encode_opt_String(apiObj.preimage);
     wireObj.bolt11 = cst_encode_opt_String(apiObj.bolt11);
     wireObj.description = cst_encode_String(apiObj.description);
+    wireObj.destination = cst_encode_opt_String(apiObj.destination);
+    wireObj.asset_id = cst_encode_opt_String(apiObj.assetId);
     wireObj.refund_tx_id = cst_encode_opt_String(apiObj.refundTxId);
     wireObj.refund_tx_amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.refundTxAmountSat);
     wireObj.payment_type = cst_encode_payment_type(apiObj.paymentType);
     wireObj.status = cst_encode_payment_state(apiObj.status);
+    wireObj.metadata = cst_encode_list_payment_metadata(apiObj.metadata);
+    wireObj.fiat_rates = cst_encode_list_payment_fiat_rate(apiObj.fiatRates);
   }
 
   @protected
@@ -2209,78 +3078,148 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       wireObj.tag = 3;
       return;
     }
-    if (apiObj is PaymentError_Generic) {
+    if (apiObj is PaymentError_AmountMissing) {
       var pre_err = cst_encode_String(apiObj.err);
       wireObj.tag = 4;
+      wireObj.kind.AmountMissing.err = pre_err;
+      return;
+    }
+    if (apiObj is PaymentError_Generic) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 5;
       wireObj.kind.Generic.err = pre_err;
       return;
     }
     if (apiObj is PaymentError_InvalidOrExpiredFees) {
-      wireObj.tag = 5;
+      wireObj.tag = 6;
       return;
     }
     if (apiObj is PaymentError_InsufficientFunds) {
-      wireObj.tag = 6;
+      wireObj.tag = 7;
       return;
     }
     if (apiObj is PaymentError_InvalidInvoice) {
       var pre_err = cst_encode_String(apiObj.err);
-      wireObj.tag = 7;
+      wireObj.tag = 8;
       wireObj.kind.InvalidInvoice.err = pre_err;
       return;
     }
+    if (apiObj is PaymentError_InvalidDestination) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 9;
+      wireObj.kind.InvalidDestination.err = pre_err;
+      return;
+    }
     if (apiObj is PaymentError_InvalidPreimage) {
-      wireObj.tag = 8;
+      wireObj.tag = 10;
       return;
     }
     if (apiObj is PaymentError_LwkError) {
       var pre_err = cst_encode_String(apiObj.err);
-      wireObj.tag = 9;
+      wireObj.tag = 11;
       wireObj.kind.LwkError.err = pre_err;
       return;
     }
     if (apiObj is PaymentError_PairsNotFound) {
-      wireObj.tag = 10;
+      wireObj.tag = 12;
       return;
     }
     if (apiObj is PaymentError_PaymentTimeout) {
-      wireObj.tag = 11;
+      wireObj.tag = 13;
       return;
     }
     if (apiObj is PaymentError_PersistError) {
-      wireObj.tag = 12;
+      wireObj.tag = 14;
       return;
     }
     if (apiObj is PaymentError_ReceiveError) {
       var pre_err = cst_encode_String(apiObj.err);
-      wireObj.tag = 13;
+      wireObj.tag = 15;
       wireObj.kind.ReceiveError.err = pre_err;
       return;
     }
     if (apiObj is PaymentError_Refunded) {
       var pre_err = cst_encode_String(apiObj.err);
       var pre_refund_tx_id = cst_encode_String(apiObj.refundTxId);
-      wireObj.tag = 14;
+      wireObj.tag = 16;
       wireObj.kind.Refunded.err = pre_err;
       wireObj.kind.Refunded.refund_tx_id = pre_refund_tx_id;
       return;
     }
     if (apiObj is PaymentError_SelfTransferNotSupported) {
-      wireObj.tag = 15;
+      wireObj.tag = 17;
       return;
     }
     if (apiObj is PaymentError_SendError) {
       var pre_err = cst_encode_String(apiObj.err);
-      wireObj.tag = 16;
+      wireObj.tag = 18;
       wireObj.kind.SendError.err = pre_err;
       return;
     }
     if (apiObj is PaymentError_SignerError) {
       var pre_err = cst_encode_String(apiObj.err);
-      wireObj.tag = 17;
+      wireObj.tag = 19;
       wireObj.kind.SignerError.err = pre_err;
       return;
     }
+    if (apiObj is PaymentError_WatchOnly) {
+      wireObj.tag = 20;
+      return;
+    }
+  }
+
+  @protected
+  void cst_api_fill_to_wire_payment_fiat_rate(PaymentFiatRate apiObj, wire_cst_payment_fiat_rate wireObj) {
+    wireObj.coin = cst_encode_String(apiObj.coin);
+    wireObj.value = cst_encode_f_64(apiObj.value);
+    wireObj.created_at = cst_encode_u_32(apiObj.createdAt);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_payment_lookup(PaymentLookup apiObj, wire_cst_payment_lookup wireObj) {
+    if (apiObj is PaymentLookup_SwapId) {
+      var pre_swap_id = cst_encode_String(apiObj.swapId);
+      wireObj.tag = 0;
+      wireObj.kind.SwapId.swap_id = pre_swap_id;
+      return;
+    }
+    if (apiObj is PaymentLookup_Invoice) {
+      var pre_invoice = cst_encode_String(apiObj.invoice);
+      wireObj.tag = 1;
+      wireObj.kind.Invoice.invoice = pre_invoice;
+      return;
+    }
+    if (apiObj is PaymentLookup_PaymentHash) {
+      var pre_payment_hash = cst_encode_String(apiObj.paymentHash);
+      wireObj.tag = 2;
+      wireObj.kind.PaymentHash.payment_hash = pre_payment_hash;
+      return;
+    }
+    if (apiObj is PaymentLookup_TxId) {
+      var pre_tx_id = cst_encode_String(apiObj.txId);
+      wireObj.tag = 3;
+      wireObj.kind.TxId.tx_id = pre_tx_id;
+      return;
+    }
+    if (apiObj is PaymentLookup_Destination) {
+      var pre_address = cst_encode_String(apiObj.address);
+      wireObj.tag = 4;
+      wireObj.kind.Destination.address = pre_address;
+      return;
+    }
+  }
+
+  @protected
+  void cst_api_fill_to_wire_payment_metadata(PaymentMetadata apiObj, wire_cst_payment_metadata wireObj) {
+    wireObj.key = cst_encode_String(apiObj.key);
+    wireObj.value = cst_encode_String(apiObj.value);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_payment_metadata_filter(
+      PaymentMetadataFilter apiObj, wire_cst_payment_metadata_filter wireObj) {
+    wireObj.key = cst_encode_String(apiObj.key);
+    wireObj.value = cst_encode_opt_String(apiObj.value);
   }
 
   @protected
@@ -2303,6 +3242,7 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       PreparePayOnchainRequest apiObj, wire_cst_prepare_pay_onchain_request wireObj) {
     wireObj.receiver_amount_sat = cst_encode_u_64(apiObj.receiverAmountSat);
     wireObj.sat_per_vbyte = cst_encode_opt_box_autoadd_u_32(apiObj.satPerVbyte);
+    wireObj.drain = cst_encode_opt_box_autoadd_bool(apiObj.drain);
   }
 
   @protected
@@ -2311,19 +3251,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.receiver_amount_sat = cst_encode_u_64(apiObj.receiverAmountSat);
     wireObj.claim_fees_sat = cst_encode_u_64(apiObj.claimFeesSat);
     wireObj.total_fees_sat = cst_encode_u_64(apiObj.totalFeesSat);
+    wireObj.drain = cst_encode_bool(apiObj.drain);
   }
 
   @protected
   void cst_api_fill_to_wire_prepare_receive_onchain_request(
       PrepareReceiveOnchainRequest apiObj, wire_cst_prepare_receive_onchain_request wireObj) {
-    wireObj.payer_amount_sat = cst_encode_u_64(apiObj.payerAmountSat);
+    wireObj.payer_amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.payerAmountSat);
   }
 
   @protected
   void cst_api_fill_to_wire_prepare_receive_onchain_response(
       PrepareReceiveOnchainResponse apiObj, wire_cst_prepare_receive_onchain_response wireObj) {
-    wireObj.payer_amount_sat = cst_encode_u_64(apiObj.payerAmountSat);
+    wireObj.payer_amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.payerAmountSat);
     wireObj.fees_sat = cst_encode_u_64(apiObj.feesSat);
+    wireObj.swapper_feerate = cst_encode_f_64(apiObj.swapperFeerate);
   }
 
   @protected
@@ -2355,17 +3297,43 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.refund_tx_id = cst_encode_opt_String(apiObj.refundTxId);
   }
 
+  @protected
+  void cst_api_fill_to_wire_prepare_send_liquid_request(
+      PrepareSendLiquidRequest apiObj, wire_cst_prepare_send_liquid_request wireObj) {
+    wireObj.destination = cst_encode_String(apiObj.destination);
+    wireObj.amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.amountSat);
+    wireObj.drain = cst_encode_opt_box_autoadd_bool(apiObj.drain);
+    wireObj.asset_id = cst_encode_opt_String(apiObj.assetId);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_prepare_send_liquid_response(
+      PrepareSendLiquidResponse apiObj, wire_cst_prepare_send_liquid_response wireObj) {
+    wireObj.address = cst_encode_String(apiObj.address);
+    wireObj.receiver_amount_sat = cst_encode_u_64(apiObj.receiverAmountSat);
+    wireObj.fees_sat = cst_encode_u_64(apiObj.feesSat);
+    wireObj.description = cst_encode_opt_String(apiObj.description);
+    wireObj.drain = cst_encode_bool(apiObj.drain);
+    wireObj.asset_id = cst_encode_opt_String(apiObj.assetId);
+  }
+
   @protected
   void cst_api_fill_to_wire_prepare_send_request(
       PrepareSendRequest apiObj, wire_cst_prepare_send_request wireObj) {
     wireObj.invoice = cst_encode_String(apiObj.invoice);
+    wireObj.amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.amountSat);
+    wireObj.idempotency_key = cst_encode_opt_String(apiObj.idempotencyKey);
+    wireObj.payer_note = cst_encode_opt_String(apiObj.payerNote);
   }
 
   @protected
   void cst_api_fill_to_wire_prepare_send_response(
       PrepareSendResponse apiObj, wire_cst_prepare_send_response wireObj) {
     wireObj.invoice = cst_encode_String(apiObj.invoice);
+    wireObj.offer = cst_encode_opt_String(apiObj.offer);
+    wireObj.receiver_amount_sat = cst_encode_u_64(apiObj.receiverAmountSat);
     wireObj.fees_sat = cst_encode_u_64(apiObj.feesSat);
+    wireObj.idempotency_key = cst_encode_opt_String(apiObj.idempotencyKey);
   }
 
   @protected
@@ -2374,6 +3342,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.value = cst_encode_f_64(apiObj.value);
   }
 
+  @protected
+  void cst_api_fill_to_wire_receive_liquid_request(
+      ReceiveLiquidRequest apiObj, wire_cst_receive_liquid_request wireObj) {
+    wireObj.amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.amountSat);
+    wireObj.description = cst_encode_opt_String(apiObj.description);
+    wireObj.asset_id = cst_encode_opt_String(apiObj.assetId);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_receive_liquid_response(
+      ReceiveLiquidResponse apiObj, wire_cst_receive_liquid_response wireObj) {
+    wireObj.address = cst_encode_String(apiObj.address);
+    wireObj.bip21 = cst_encode_String(apiObj.bip21);
+  }
+
   @protected
   void cst_api_fill_to_wire_receive_onchain_response(
       ReceiveOnchainResponse apiObj, wire_cst_receive_onchain_response wireObj) {
@@ -2404,6 +3387,23 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.minimum_fee = cst_encode_u_64(apiObj.minimumFee);
   }
 
+  @protected
+  void cst_api_fill_to_wire_refund_destination(
+      RefundDestination apiObj, wire_cst_refund_destination wireObj) {
+    if (apiObj is RefundDestination_Address) {
+      var pre_address = cst_encode_String(apiObj.address);
+      wireObj.tag = 0;
+      wireObj.kind.Address.address = pre_address;
+      return;
+    }
+    if (apiObj is RefundDestination_Xpub) {
+      var pre_xpub = cst_encode_String(apiObj.xpub);
+      wireObj.tag = 1;
+      wireObj.kind.Xpub.xpub = pre_xpub;
+      return;
+    }
+  }
+
   @protected
   void cst_api_fill_to_wire_refund_request(RefundRequest apiObj, wire_cst_refund_request wireObj) {
     wireObj.swap_address = cst_encode_String(apiObj.swapAddress);
@@ -2506,18 +3506,106 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       wireObj.kind.PaymentWaitingConfirmation.details = pre_details;
       return;
     }
-    if (apiObj is SdkEvent_Synced) {
+    if (apiObj is SdkEvent_SwapAutoRefunded) {
+      var pre_details = cst_encode_box_autoadd_auto_refund_details(apiObj.details);
       wireObj.tag = 6;
+      wireObj.kind.SwapAutoRefunded.details = pre_details;
+      return;
+    }
+    if (apiObj is SdkEvent_SwapWaitingFeeAcceptance) {
+      var pre_swap_id = cst_encode_String(apiObj.swapId);
+      wireObj.tag = 7;
+      wireObj.kind.SwapWaitingFeeAcceptance.swap_id = pre_swap_id;
+      return;
+    }
+    if (apiObj is SdkEvent_Synced) {
+      wireObj.tag = 8;
+      return;
+    }
+  }
+
+  @protected
+  void cst_api_fill_to_wire_send_payment_response(
+      SendPaymentResponse apiObj, wire_cst_send_payment_response wireObj) {
+    cst_api_fill_to_wire_payment(apiObj.payment, wireObj.payment);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_set_payment_metadata_request(
+      SetPaymentMetadataRequest apiObj, wire_cst_set_payment_metadata_request wireObj) {
+    wireObj.payment_id = cst_encode_String(apiObj.paymentId);
+    wireObj.key = cst_encode_String(apiObj.key);
+    wireObj.value = cst_encode_String(apiObj.value);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_signer_method(SignerMethod apiObj, wire_cst_signer_method wireObj) {
+    if (apiObj is SignerMethod_Xpub) {
+      wireObj.tag = 0;
+      return;
+    }
+    if (apiObj is SignerMethod_DeriveXpub) {
+      var pre_derivation_path = cst_encode_String(apiObj.derivationPath);
+      wireObj.tag = 1;
+      wireObj.kind.DeriveXpub.derivation_path = pre_derivation_path;
+      return;
+    }
+    if (apiObj is SignerMethod_Slip77MasterBlindingKey) {
+      wireObj.tag = 2;
+      return;
+    }
+    if (apiObj is SignerMethod_SignPset) {
+      var pre_pset = cst_encode_String(apiObj.pset);
+      wireObj.tag = 3;
+      wireObj.kind.SignPset.pset = pre_pset;
+      return;
+    }
+    if (apiObj is SignerMethod_SignEcdsa) {
+      var pre_msg = cst_encode_list_prim_u_8_strict(apiObj.msg);
+      var pre_derivation_path = cst_encode_String(apiObj.derivationPath);
+      wireObj.tag = 4;
+      wireObj.kind.SignEcdsa.msg = pre_msg;
+      wireObj.kind.SignEcdsa.derivation_path = pre_derivation_path;
+      return;
+    }
+    if (apiObj is SignerMethod_HmacSha256) {
+      var pre_msg = cst_encode_list_prim_u_8_strict(apiObj.msg);
+      var pre_derivation_path = cst_encode_String(apiObj.derivationPath);
+      wireObj.tag = 5;
+      wireObj.kind.HmacSha256.msg = pre_msg;
+      wireObj.kind.HmacSha256.derivation_path = pre_derivation_path;
+      return;
+    }
+  }
+
+  @protected
+  void cst_api_fill_to_wire_signer_request(SignerRequest apiObj, wire_cst_signer_request wireObj) {
+    wireObj.id = cst_encode_u_32(apiObj.id);
+    cst_api_fill_to_wire_signer_method(apiObj.method, wireObj.method);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_signer_result(SignerResult apiObj, wire_cst_signer_result wireObj) {
+    if (apiObj is SignerResult_String) {
+      var pre_value = cst_encode_String(apiObj.value);
+      wireObj.tag = 0;
+      wireObj.kind.String.value = pre_value;
+      return;
+    }
+    if (apiObj is SignerResult_Bytes) {
+      var pre_value = cst_encode_list_prim_u_8_strict(apiObj.value);
+      wireObj.tag = 1;
+      wireObj.kind.Bytes.value = pre_value;
+      return;
+    }
+    if (apiObj is SignerResult_Error) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 2;
+      wireObj.kind.Error.err = pre_err;
       return;
     }
   }
 
-  @protected
-  void cst_api_fill_to_wire_send_payment_response(
-      SendPaymentResponse apiObj, wire_cst_send_payment_response wireObj) {
-    cst_api_fill_to_wire_payment(apiObj.payment, wireObj.payment);
-  }
-
   @protected
   void cst_api_fill_to_wire_success_action_processed(
       SuccessActionProcessed apiObj, wire_cst_success_action_processed wireObj) {
@@ -2575,6 +3663,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   int cst_encode_buy_bitcoin_provider(BuyBitcoinProvider raw);
 
+  @protected
+  int cst_encode_chain_backend(ChainBackend raw);
+
+  @protected
+  int cst_encode_export_format(ExportFormat raw);
+
   @protected
   double cst_encode_f_64(double raw);
 
@@ -2587,12 +3681,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   int cst_encode_network(Network raw);
 
+  @protected
+  int cst_encode_payment_kind(PaymentKind raw);
+
   @protected
   int cst_encode_payment_state(PaymentState raw);
 
   @protected
   int cst_encode_payment_type(PaymentType raw);
 
+  @protected
+  int cst_encode_refund_fee_rate(RefundFeeRate raw);
+
   @protected
   int cst_encode_u_16(int raw);
 
@@ -2626,9 +3726,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_StreamSink_sdk_event_Dco(RustStreamSink<SdkEvent> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_StreamSink_signer_request_Dco(RustStreamSink<SignerRequest> self, SseSerializer serializer);
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_accept_payment_proposed_fees_request(
+      AcceptPaymentProposedFeesRequest self, SseSerializer serializer);
+
   @protected
   void sse_encode_aes_success_action_data_decrypted(
       AesSuccessActionDataDecrypted self, SseSerializer serializer);
@@ -2636,9 +3743,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_aes_success_action_data_result(AesSuccessActionDataResult self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_asset_balance(AssetBalance self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_asset_metadata(AssetMetadata self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_auto_refund_details(AutoRefundDetails self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_auto_refund_policy(AutoRefundPolicy self, SseSerializer serializer);
+
   @protected
   void sse_encode_backup_request(BackupRequest self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_balance_details(BalanceDetails self, SseSerializer serializer);
+
   @protected
   void sse_encode_binding_event_listener(BindingEventListener self, SseSerializer serializer);
 
@@ -2648,6 +3770,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_accept_payment_proposed_fees_request(
+      AcceptPaymentProposedFeesRequest self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_aes_success_action_data_decrypted(
       AesSuccessActionDataDecrypted self, SseSerializer serializer);
@@ -2656,6 +3782,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_box_autoadd_aes_success_action_data_result(
       AesSuccessActionDataResult self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_asset_metadata(AssetMetadata self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_auto_refund_details(AutoRefundDetails self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_auto_refund_policy(AutoRefundPolicy self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_backup_request(BackupRequest self, SseSerializer serializer);
 
@@ -2668,12 +3803,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_bump_refund_fee_request(BumpRefundFeeRequest self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_buy_bitcoin_request(BuyBitcoinRequest self, SseSerializer serializer);
 
   @protected
   void sse_encode_box_autoadd_connect_request(ConnectRequest self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_delete_payment_metadata_request(
+      DeletePaymentMetadataRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_export_payments_request(ExportPaymentsRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_fetch_payment_proposed_fees_request(
+      FetchPaymentProposedFeesRequest self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer);
 
@@ -2722,6 +3871,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_payment(Payment self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_payment_lookup(PaymentLookup self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_prepare_buy_bitcoin_request(
       PrepareBuyBitcoinRequest self, SseSerializer serializer);
@@ -2745,12 +3897,23 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_prepare_refund_request(PrepareRefundRequest self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_prepare_send_liquid_request(
+      PrepareSendLiquidRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_prepare_send_liquid_response(
+      PrepareSendLiquidResponse self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_prepare_send_request(PrepareSendRequest self, SseSerializer serializer);
 
   @protected
   void sse_encode_box_autoadd_prepare_send_response(PrepareSendResponse self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_receive_liquid_request(ReceiveLiquidRequest self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_receive_payment_request(ReceivePaymentRequest self, SseSerializer serializer);
 
@@ -2763,6 +3926,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_sdk_event(SdkEvent self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_set_payment_metadata_request(
+      SetPaymentMetadataRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_signer_result(SignerResult self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_success_action_processed(SuccessActionProcessed self, SseSerializer serializer);
 
@@ -2778,12 +3948,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_url_success_action_data(UrlSuccessActionData self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_bump_refund_fee_request(BumpRefundFeeRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bump_refund_fee_response(BumpRefundFeeResponse self, SseSerializer serializer);
+
   @protected
   void sse_encode_buy_bitcoin_provider(BuyBitcoinProvider self, SseSerializer serializer);
 
   @protected
   void sse_encode_buy_bitcoin_request(BuyBitcoinRequest self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_chain_backend(ChainBackend self, SseSerializer serializer);
+
   @protected
   void sse_encode_config(Config self, SseSerializer serializer);
 
@@ -2793,9 +3972,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_currency_info(CurrencyInfo self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_delete_payment_metadata_request(
+      DeletePaymentMetadataRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_export_format(ExportFormat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_export_payments_request(ExportPaymentsRequest self, SseSerializer serializer);
+
   @protected
   void sse_encode_f_64(double self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_fetch_payment_proposed_fees_request(
+      FetchPaymentProposedFeesRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_fetch_payment_proposed_fees_response(
+      FetchPaymentProposedFeesResponse self, SseSerializer serializer);
+
   @protected
   void sse_encode_fiat_currency(FiatCurrency self, SseSerializer serializer);
 
@@ -2821,6 +4018,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_liquid_network(LiquidNetwork self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_asset_balance(List<AssetBalance> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_asset_metadata(List<AssetMetadata> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_fiat_currency(List<FiatCurrency> self, SseSerializer serializer);
 
@@ -2833,12 +4039,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_payment(List<Payment> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_payment_fiat_rate(List<PaymentFiatRate> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_payment_kind(List<PaymentKind> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_payment_metadata(List<PaymentMetadata> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_payment_metadata_filter(List<PaymentMetadataFilter> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_payment_state(List<PaymentState> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_payment_type(List<PaymentType> self, SseSerializer serializer);
 
   @protected
   void sse_encode_list_payments_request(ListPaymentsRequest self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_payments_response(ListPaymentsResponse self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
 
@@ -2857,6 +4081,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_ln_invoice(LNInvoice self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_ln_offer(LNOffer self, SseSerializer serializer);
+
   @protected
   void sse_encode_ln_url_auth_error(LnUrlAuthError self, SseSerializer serializer);
 
@@ -2924,12 +4151,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_asset_metadata(AssetMetadata? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_auto_refund_policy(AutoRefundPolicy? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
 
   @protected
   void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_payment(Payment? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_success_action_processed(
       SuccessActionProcessed? self, SseSerializer serializer);
@@ -2943,6 +4179,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_list_asset_metadata(List<AssetMetadata>? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_list_payment_kind(List<PaymentKind>? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_list_payment_metadata_filter(
+      List<PaymentMetadataFilter>? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_list_payment_state(List<PaymentState>? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_list_payment_type(List<PaymentType>? self, SseSerializer serializer);
 
@@ -2955,6 +4207,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_payment_error(PaymentError self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_payment_fiat_rate(PaymentFiatRate self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_payment_kind(PaymentKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_payment_lookup(PaymentLookup self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_payment_metadata(PaymentMetadata self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_payment_metadata_filter(PaymentMetadataFilter self, SseSerializer serializer);
+
   @protected
   void sse_encode_payment_state(PaymentState self, SseSerializer serializer);
 
@@ -2995,6 +4262,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_prepare_refund_response(PrepareRefundResponse self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_prepare_send_liquid_request(PrepareSendLiquidRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_prepare_send_liquid_response(PrepareSendLiquidResponse self, SseSerializer serializer);
+
   @protected
   void sse_encode_prepare_send_request(PrepareSendRequest self, SseSerializer serializer);
 
@@ -3004,6 +4277,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_rate(Rate self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_receive_liquid_request(ReceiveLiquidRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_receive_liquid_response(ReceiveLiquidResponse self, SseSerializer serializer);
+
   @protected
   void sse_encode_receive_onchain_response(ReceiveOnchainResponse self, SseSerializer serializer);
 
@@ -3016,6 +4295,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_recommended_fees(RecommendedFees self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_refund_destination(RefundDestination self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_refund_fee_rate(RefundFeeRate self, SseSerializer serializer);
+
   @protected
   void sse_encode_refund_request(RefundRequest self, SseSerializer serializer);
 
@@ -3043,6 +4328,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_send_payment_response(SendPaymentResponse self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_set_payment_metadata_request(SetPaymentMetadataRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_signer_method(SignerMethod self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_signer_request(SignerRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_signer_result(SignerResult self, SseSerializer serializer);
+
   @protected
   void sse_encode_success_action_processed(SuccessActionProcessed self, SseSerializer serializer);
 
@@ -3106,6 +4403,27 @@ class RustLibWire implements BaseWire {
   late final _store_dart_post_cobject =
       _store_dart_post_cobjectPtr.asFunction<void Function(DartPostCObjectFnType)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_accept_payment_proposed_fees(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_accept_payment_proposed_fees_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_accept_payment_proposed_fees(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_accept_payment_proposed_feesPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_accept_payment_proposed_fees_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_accept_payment_proposed_fees');
+  late final _wire__crate__bindings__BindingLiquidSdk_accept_payment_proposed_fees =
+      _wire__crate__bindings__BindingLiquidSdk_accept_payment_proposed_feesPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_accept_payment_proposed_fees_request>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_add_event_listener(
     int port_,
     int that,
@@ -3144,6 +4462,26 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_backupPtr
           .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_backup_request>)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_bump_refund_fee(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_bump_refund_fee_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_bump_refund_fee(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_bump_refund_feePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_bump_refund_fee_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_bump_refund_fee');
+  late final _wire__crate__bindings__BindingLiquidSdk_bump_refund_fee =
+      _wire__crate__bindings__BindingLiquidSdk_bump_refund_feePtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_bump_refund_fee_request>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_buy_bitcoin(
     int port_,
     int that,
@@ -3164,6 +4502,27 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_buy_bitcoinPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_buy_bitcoin_request>)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_delete_payment_metadata(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_delete_payment_metadata_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_delete_payment_metadata(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_delete_payment_metadataPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_delete_payment_metadata_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_delete_payment_metadata');
+  late final _wire__crate__bindings__BindingLiquidSdk_delete_payment_metadata =
+      _wire__crate__bindings__BindingLiquidSdk_delete_payment_metadataPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_delete_payment_metadata_request>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_disconnect(
     int port_,
     int that,
@@ -3195,6 +4554,26 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_empty_wallet_cachePtr
           .asFunction<WireSyncRust2DartDco Function(int)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_export_payments(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_export_payments_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_export_payments(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_export_paymentsPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_export_payments_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_payments');
+  late final _wire__crate__bindings__BindingLiquidSdk_export_payments =
+      _wire__crate__bindings__BindingLiquidSdk_export_paymentsPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_export_payments_request>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_fetch_fiat_rates(
     int port_,
     int that,
@@ -3244,6 +4623,27 @@ class RustLibWire implements BaseWire {
   late final _wire__crate__bindings__BindingLiquidSdk_fetch_onchain_limits =
       _wire__crate__bindings__BindingLiquidSdk_fetch_onchain_limitsPtr.asFunction<void Function(int, int)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_fetch_payment_proposed_fees(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_fetch_payment_proposed_fees_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_fetch_payment_proposed_fees(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_fetch_payment_proposed_feesPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_fetch_payment_proposed_fees_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_payment_proposed_fees');
+  late final _wire__crate__bindings__BindingLiquidSdk_fetch_payment_proposed_fees =
+      _wire__crate__bindings__BindingLiquidSdk_fetch_payment_proposed_feesPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_fetch_payment_proposed_fees_request>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_get_info(
     int port_,
     int that,
@@ -3260,6 +4660,46 @@ class RustLibWire implements BaseWire {
   late final _wire__crate__bindings__BindingLiquidSdk_get_info =
       _wire__crate__bindings__BindingLiquidSdk_get_infoPtr.asFunction<void Function(int, int)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_get_payment(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_payment_lookup> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_get_payment(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_get_paymentPtr = _lookup<
+          ffi
+          .NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_payment_lookup>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment');
+  late final _wire__crate__bindings__BindingLiquidSdk_get_payment =
+      _wire__crate__bindings__BindingLiquidSdk_get_paymentPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_payment_lookup>)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_get_payment_metadata(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_id,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_get_payment_metadata(
+      port_,
+      that,
+      payment_id,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_get_payment_metadataPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment_metadata');
+  late final _wire__crate__bindings__BindingLiquidSdk_get_payment_metadata =
+      _wire__crate__bindings__BindingLiquidSdk_get_payment_metadataPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_list_fiat_currencies(
     int port_,
     int that,
@@ -3296,6 +4736,26 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_list_paymentsPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_payments_request>)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_list_payments_page(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_list_payments_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_list_payments_page(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_list_payments_pagePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_payments_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments_page');
+  late final _wire__crate__bindings__BindingLiquidSdk_list_payments_page =
+      _wire__crate__bindings__BindingLiquidSdk_list_payments_pagePtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_payments_request>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_list_refundables(
     int port_,
     int that,
@@ -3474,25 +4934,45 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_prepare_receive_paymentPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_receive_payment_request>)>();
 
-  void wire__crate__bindings__BindingLiquidSdk_prepare_refund(
+  void wire__crate__bindings__BindingLiquidSdk_prepare_refund(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_prepare_refund_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_prepare_refund(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_prepare_refundPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_prepare_refund_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_refund');
+  late final _wire__crate__bindings__BindingLiquidSdk_prepare_refund =
+      _wire__crate__bindings__BindingLiquidSdk_prepare_refundPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_refund_request>)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_prepare_send_liquid(
     int port_,
     int that,
-    ffi.Pointer<wire_cst_prepare_refund_request> req,
+    ffi.Pointer<wire_cst_prepare_send_liquid_request> req,
   ) {
-    return _wire__crate__bindings__BindingLiquidSdk_prepare_refund(
+    return _wire__crate__bindings__BindingLiquidSdk_prepare_send_liquid(
       port_,
       that,
       req,
     );
   }
 
-  late final _wire__crate__bindings__BindingLiquidSdk_prepare_refundPtr = _lookup<
+  late final _wire__crate__bindings__BindingLiquidSdk_prepare_send_liquidPtr = _lookup<
           ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_prepare_refund_request>)>>(
-      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_refund');
-  late final _wire__crate__bindings__BindingLiquidSdk_prepare_refund =
-      _wire__crate__bindings__BindingLiquidSdk_prepare_refundPtr
-          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_refund_request>)>();
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_prepare_send_liquid_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_liquid');
+  late final _wire__crate__bindings__BindingLiquidSdk_prepare_send_liquid =
+      _wire__crate__bindings__BindingLiquidSdk_prepare_send_liquidPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_send_liquid_request>)>();
 
   void wire__crate__bindings__BindingLiquidSdk_prepare_send_payment(
     int port_,
@@ -3514,6 +4994,26 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_prepare_send_paymentPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_send_request>)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_receive_liquid(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_receive_liquid_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_receive_liquid(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_receive_liquidPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_receive_liquid_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_liquid');
+  late final _wire__crate__bindings__BindingLiquidSdk_receive_liquid =
+      _wire__crate__bindings__BindingLiquidSdk_receive_liquidPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_receive_liquid_request>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_receive_onchain(
     int port_,
     int that,
@@ -3625,6 +5125,26 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_restorePtr
           .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_restore_request>)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_send_liquid(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_prepare_send_liquid_response> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_send_liquid(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_send_liquidPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_prepare_send_liquid_response>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_liquid');
+  late final _wire__crate__bindings__BindingLiquidSdk_send_liquid =
+      _wire__crate__bindings__BindingLiquidSdk_send_liquidPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_send_liquid_response>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_send_payment(
     int port_,
     int that,
@@ -3645,6 +5165,26 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_send_paymentPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_send_response>)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_set_payment_metadata(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_set_payment_metadata_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_set_payment_metadata(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_set_payment_metadataPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_set_payment_metadata_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_set_payment_metadata');
+  late final _wire__crate__bindings__BindingLiquidSdk_set_payment_metadata =
+      _wire__crate__bindings__BindingLiquidSdk_set_payment_metadataPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_set_payment_metadata_request>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_sync(
     int port_,
     int that,
@@ -3715,6 +5255,22 @@ class RustLibWire implements BaseWire {
   late final _wire__crate__bindings__connect = _wire__crate__bindings__connectPtr
       .asFunction<void Function(int, ffi.Pointer<wire_cst_connect_request>)>();
 
+  void wire__crate__bindings__connect_with_signer(
+    int port_,
+    ffi.Pointer<wire_cst_connect_request> req,
+  ) {
+    return _wire__crate__bindings__connect_with_signer(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__connect_with_signerPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_connect_request>)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__connect_with_signer');
+  late final _wire__crate__bindings__connect_with_signer = _wire__crate__bindings__connect_with_signerPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_cst_connect_request>)>();
+
   WireSyncRust2DartDco wire__crate__bindings__default_config(
     int network,
   ) {
@@ -3759,6 +5315,54 @@ class RustLibWire implements BaseWire {
   late final _wire__crate__bindings__parse_invoice = _wire__crate__bindings__parse_invoicePtr
       .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
+  WireSyncRust2DartDco wire__crate__bindings__parse_offer(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> input,
+  ) {
+    return _wire__crate__bindings__parse_offer(
+      input,
+    );
+  }
+
+  late final _wire__crate__bindings__parse_offerPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__parse_offer');
+  late final _wire__crate__bindings__parse_offer = _wire__crate__bindings__parse_offerPtr
+      .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__respond_to_signer_request(
+    int id,
+    ffi.Pointer<wire_cst_signer_result> result,
+  ) {
+    return _wire__crate__bindings__respond_to_signer_request(
+      id,
+      result,
+    );
+  }
+
+  late final _wire__crate__bindings__respond_to_signer_requestPtr = _lookup<
+          ffi
+          .NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_signer_result>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__respond_to_signer_request');
+  late final _wire__crate__bindings__respond_to_signer_request =
+      _wire__crate__bindings__respond_to_signer_requestPtr
+          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_signer_result>)>();
+
+  void wire__crate__bindings__signer_request_stream(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> s,
+  ) {
+    return _wire__crate__bindings__signer_request_stream(
+      port_,
+      s,
+    );
+  }
+
+  late final _wire__crate__bindings__signer_request_streamPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__signer_request_stream');
+  late final _wire__crate__bindings__signer_request_stream = _wire__crate__bindings__signer_request_streamPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
   void
       rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
     ffi.Pointer<ffi.Void> ptr,
@@ -3791,6 +5395,18 @@ class RustLibWire implements BaseWire {
       _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdkPtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
+  ffi.Pointer<wire_cst_accept_payment_proposed_fees_request>
+      cst_new_box_autoadd_accept_payment_proposed_fees_request() {
+    return _cst_new_box_autoadd_accept_payment_proposed_fees_request();
+  }
+
+  late final _cst_new_box_autoadd_accept_payment_proposed_fees_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_accept_payment_proposed_fees_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_accept_payment_proposed_fees_request');
+  late final _cst_new_box_autoadd_accept_payment_proposed_fees_request =
+      _cst_new_box_autoadd_accept_payment_proposed_fees_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_accept_payment_proposed_fees_request> Function()>();
+
   ffi.Pointer<wire_cst_aes_success_action_data_decrypted>
       cst_new_box_autoadd_aes_success_action_data_decrypted() {
     return _cst_new_box_autoadd_aes_success_action_data_decrypted();
@@ -3814,6 +5430,36 @@ class RustLibWire implements BaseWire {
       _cst_new_box_autoadd_aes_success_action_data_resultPtr
           .asFunction<ffi.Pointer<wire_cst_aes_success_action_data_result> Function()>();
 
+  ffi.Pointer<wire_cst_asset_metadata> cst_new_box_autoadd_asset_metadata() {
+    return _cst_new_box_autoadd_asset_metadata();
+  }
+
+  late final _cst_new_box_autoadd_asset_metadataPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_asset_metadata> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_asset_metadata');
+  late final _cst_new_box_autoadd_asset_metadata =
+      _cst_new_box_autoadd_asset_metadataPtr.asFunction<ffi.Pointer<wire_cst_asset_metadata> Function()>();
+
+  ffi.Pointer<wire_cst_auto_refund_details> cst_new_box_autoadd_auto_refund_details() {
+    return _cst_new_box_autoadd_auto_refund_details();
+  }
+
+  late final _cst_new_box_autoadd_auto_refund_detailsPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_auto_refund_details> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_auto_refund_details');
+  late final _cst_new_box_autoadd_auto_refund_details = _cst_new_box_autoadd_auto_refund_detailsPtr
+      .asFunction<ffi.Pointer<wire_cst_auto_refund_details> Function()>();
+
+  ffi.Pointer<wire_cst_auto_refund_policy> cst_new_box_autoadd_auto_refund_policy() {
+    return _cst_new_box_autoadd_auto_refund_policy();
+  }
+
+  late final _cst_new_box_autoadd_auto_refund_policyPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_auto_refund_policy> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_auto_refund_policy');
+  late final _cst_new_box_autoadd_auto_refund_policy = _cst_new_box_autoadd_auto_refund_policyPtr
+      .asFunction<ffi.Pointer<wire_cst_auto_refund_policy> Function()>();
+
   ffi.Pointer<wire_cst_backup_request> cst_new_box_autoadd_backup_request() {
     return _cst_new_box_autoadd_backup_request();
   }
@@ -3858,6 +5504,16 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_bool =
       _cst_new_box_autoadd_boolPtr.asFunction<ffi.Pointer<ffi.Bool> Function(bool)>();
 
+  ffi.Pointer<wire_cst_bump_refund_fee_request> cst_new_box_autoadd_bump_refund_fee_request() {
+    return _cst_new_box_autoadd_bump_refund_fee_request();
+  }
+
+  late final _cst_new_box_autoadd_bump_refund_fee_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_bump_refund_fee_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_bump_refund_fee_request');
+  late final _cst_new_box_autoadd_bump_refund_fee_request = _cst_new_box_autoadd_bump_refund_fee_requestPtr
+      .asFunction<ffi.Pointer<wire_cst_bump_refund_fee_request> Function()>();
+
   ffi.Pointer<wire_cst_buy_bitcoin_request> cst_new_box_autoadd_buy_bitcoin_request() {
     return _cst_new_box_autoadd_buy_bitcoin_request();
   }
@@ -3878,6 +5534,40 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_connect_request =
       _cst_new_box_autoadd_connect_requestPtr.asFunction<ffi.Pointer<wire_cst_connect_request> Function()>();
 
+  ffi.Pointer<wire_cst_delete_payment_metadata_request>
+      cst_new_box_autoadd_delete_payment_metadata_request() {
+    return _cst_new_box_autoadd_delete_payment_metadata_request();
+  }
+
+  late final _cst_new_box_autoadd_delete_payment_metadata_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_delete_payment_metadata_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_delete_payment_metadata_request');
+  late final _cst_new_box_autoadd_delete_payment_metadata_request =
+      _cst_new_box_autoadd_delete_payment_metadata_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_delete_payment_metadata_request> Function()>();
+
+  ffi.Pointer<wire_cst_export_payments_request> cst_new_box_autoadd_export_payments_request() {
+    return _cst_new_box_autoadd_export_payments_request();
+  }
+
+  late final _cst_new_box_autoadd_export_payments_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_export_payments_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_export_payments_request');
+  late final _cst_new_box_autoadd_export_payments_request = _cst_new_box_autoadd_export_payments_requestPtr
+      .asFunction<ffi.Pointer<wire_cst_export_payments_request> Function()>();
+
+  ffi.Pointer<wire_cst_fetch_payment_proposed_fees_request>
+      cst_new_box_autoadd_fetch_payment_proposed_fees_request() {
+    return _cst_new_box_autoadd_fetch_payment_proposed_fees_request();
+  }
+
+  late final _cst_new_box_autoadd_fetch_payment_proposed_fees_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_fetch_payment_proposed_fees_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_fetch_payment_proposed_fees_request');
+  late final _cst_new_box_autoadd_fetch_payment_proposed_fees_request =
+      _cst_new_box_autoadd_fetch_payment_proposed_fees_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_fetch_payment_proposed_fees_request> Function()>();
+
   ffi.Pointer<ffi.Int64> cst_new_box_autoadd_i_64(
     int value,
   ) {
@@ -4035,6 +5725,16 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_payment =
       _cst_new_box_autoadd_paymentPtr.asFunction<ffi.Pointer<wire_cst_payment> Function()>();
 
+  ffi.Pointer<wire_cst_payment_lookup> cst_new_box_autoadd_payment_lookup() {
+    return _cst_new_box_autoadd_payment_lookup();
+  }
+
+  late final _cst_new_box_autoadd_payment_lookupPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_payment_lookup> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_payment_lookup');
+  late final _cst_new_box_autoadd_payment_lookup =
+      _cst_new_box_autoadd_payment_lookupPtr.asFunction<ffi.Pointer<wire_cst_payment_lookup> Function()>();
+
   ffi.Pointer<wire_cst_prepare_buy_bitcoin_request> cst_new_box_autoadd_prepare_buy_bitcoin_request() {
     return _cst_new_box_autoadd_prepare_buy_bitcoin_request();
   }
@@ -4103,6 +5803,28 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_prepare_refund_request = _cst_new_box_autoadd_prepare_refund_requestPtr
       .asFunction<ffi.Pointer<wire_cst_prepare_refund_request> Function()>();
 
+  ffi.Pointer<wire_cst_prepare_send_liquid_request> cst_new_box_autoadd_prepare_send_liquid_request() {
+    return _cst_new_box_autoadd_prepare_send_liquid_request();
+  }
+
+  late final _cst_new_box_autoadd_prepare_send_liquid_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_prepare_send_liquid_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_liquid_request');
+  late final _cst_new_box_autoadd_prepare_send_liquid_request =
+      _cst_new_box_autoadd_prepare_send_liquid_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_prepare_send_liquid_request> Function()>();
+
+  ffi.Pointer<wire_cst_prepare_send_liquid_response> cst_new_box_autoadd_prepare_send_liquid_response() {
+    return _cst_new_box_autoadd_prepare_send_liquid_response();
+  }
+
+  late final _cst_new_box_autoadd_prepare_send_liquid_responsePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_prepare_send_liquid_response> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_liquid_response');
+  late final _cst_new_box_autoadd_prepare_send_liquid_response =
+      _cst_new_box_autoadd_prepare_send_liquid_responsePtr
+          .asFunction<ffi.Pointer<wire_cst_prepare_send_liquid_response> Function()>();
+
   ffi.Pointer<wire_cst_prepare_send_request> cst_new_box_autoadd_prepare_send_request() {
     return _cst_new_box_autoadd_prepare_send_request();
   }
@@ -4123,6 +5845,16 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_prepare_send_response = _cst_new_box_autoadd_prepare_send_responsePtr
       .asFunction<ffi.Pointer<wire_cst_prepare_send_response> Function()>();
 
+  ffi.Pointer<wire_cst_receive_liquid_request> cst_new_box_autoadd_receive_liquid_request() {
+    return _cst_new_box_autoadd_receive_liquid_request();
+  }
+
+  late final _cst_new_box_autoadd_receive_liquid_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_receive_liquid_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_receive_liquid_request');
+  late final _cst_new_box_autoadd_receive_liquid_request = _cst_new_box_autoadd_receive_liquid_requestPtr
+      .asFunction<ffi.Pointer<wire_cst_receive_liquid_request> Function()>();
+
   ffi.Pointer<wire_cst_receive_payment_request> cst_new_box_autoadd_receive_payment_request() {
     return _cst_new_box_autoadd_receive_payment_request();
   }
@@ -4163,6 +5895,27 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_sdk_event =
       _cst_new_box_autoadd_sdk_eventPtr.asFunction<ffi.Pointer<wire_cst_sdk_event> Function()>();
 
+  ffi.Pointer<wire_cst_set_payment_metadata_request> cst_new_box_autoadd_set_payment_metadata_request() {
+    return _cst_new_box_autoadd_set_payment_metadata_request();
+  }
+
+  late final _cst_new_box_autoadd_set_payment_metadata_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_set_payment_metadata_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_set_payment_metadata_request');
+  late final _cst_new_box_autoadd_set_payment_metadata_request =
+      _cst_new_box_autoadd_set_payment_metadata_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_set_payment_metadata_request> Function()>();
+
+  ffi.Pointer<wire_cst_signer_result> cst_new_box_autoadd_signer_result() {
+    return _cst_new_box_autoadd_signer_result();
+  }
+
+  late final _cst_new_box_autoadd_signer_resultPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_signer_result> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_signer_result');
+  late final _cst_new_box_autoadd_signer_result =
+      _cst_new_box_autoadd_signer_resultPtr.asFunction<ffi.Pointer<wire_cst_signer_result> Function()>();
+
   ffi.Pointer<wire_cst_success_action_processed> cst_new_box_autoadd_success_action_processed() {
     return _cst_new_box_autoadd_success_action_processed();
   }
@@ -4221,6 +5974,48 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_url_success_action_data = _cst_new_box_autoadd_url_success_action_dataPtr
       .asFunction<ffi.Pointer<wire_cst_url_success_action_data> Function()>();
 
+  ffi.Pointer<wire_cst_list_String> cst_new_list_String(
+    int len,
+  ) {
+    return _cst_new_list_String(
+      len,
+    );
+  }
+
+  late final _cst_new_list_StringPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_String> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_String');
+  late final _cst_new_list_String =
+      _cst_new_list_StringPtr.asFunction<ffi.Pointer<wire_cst_list_String> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_asset_balance> cst_new_list_asset_balance(
+    int len,
+  ) {
+    return _cst_new_list_asset_balance(
+      len,
+    );
+  }
+
+  late final _cst_new_list_asset_balancePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_asset_balance> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_asset_balance');
+  late final _cst_new_list_asset_balance =
+      _cst_new_list_asset_balancePtr.asFunction<ffi.Pointer<wire_cst_list_asset_balance> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_asset_metadata> cst_new_list_asset_metadata(
+    int len,
+  ) {
+    return _cst_new_list_asset_metadata(
+      len,
+    );
+  }
+
+  late final _cst_new_list_asset_metadataPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_asset_metadata> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_asset_metadata');
+  late final _cst_new_list_asset_metadata =
+      _cst_new_list_asset_metadataPtr.asFunction<ffi.Pointer<wire_cst_list_asset_metadata> Function(int)>();
+
   ffi.Pointer<wire_cst_list_fiat_currency> cst_new_list_fiat_currency(
     int len,
   ) {
@@ -4277,6 +6072,76 @@ class RustLibWire implements BaseWire {
   late final _cst_new_list_payment =
       _cst_new_list_paymentPtr.asFunction<ffi.Pointer<wire_cst_list_payment> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_payment_fiat_rate> cst_new_list_payment_fiat_rate(
+    int len,
+  ) {
+    return _cst_new_list_payment_fiat_rate(
+      len,
+    );
+  }
+
+  late final _cst_new_list_payment_fiat_ratePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_payment_fiat_rate> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_payment_fiat_rate');
+  late final _cst_new_list_payment_fiat_rate = _cst_new_list_payment_fiat_ratePtr
+      .asFunction<ffi.Pointer<wire_cst_list_payment_fiat_rate> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_payment_kind> cst_new_list_payment_kind(
+    int len,
+  ) {
+    return _cst_new_list_payment_kind(
+      len,
+    );
+  }
+
+  late final _cst_new_list_payment_kindPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_payment_kind> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_payment_kind');
+  late final _cst_new_list_payment_kind =
+      _cst_new_list_payment_kindPtr.asFunction<ffi.Pointer<wire_cst_list_payment_kind> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_payment_metadata> cst_new_list_payment_metadata(
+    int len,
+  ) {
+    return _cst_new_list_payment_metadata(
+      len,
+    );
+  }
+
+  late final _cst_new_list_payment_metadataPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_payment_metadata> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_payment_metadata');
+  late final _cst_new_list_payment_metadata = _cst_new_list_payment_metadataPtr
+      .asFunction<ffi.Pointer<wire_cst_list_payment_metadata> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_payment_metadata_filter> cst_new_list_payment_metadata_filter(
+    int len,
+  ) {
+    return _cst_new_list_payment_metadata_filter(
+      len,
+    );
+  }
+
+  late final _cst_new_list_payment_metadata_filterPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_payment_metadata_filter> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_payment_metadata_filter');
+  late final _cst_new_list_payment_metadata_filter = _cst_new_list_payment_metadata_filterPtr
+      .asFunction<ffi.Pointer<wire_cst_list_payment_metadata_filter> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_payment_state> cst_new_list_payment_state(
+    int len,
+  ) {
+    return _cst_new_list_payment_state(
+      len,
+    );
+  }
+
+  late final _cst_new_list_payment_statePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_payment_state> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_payment_state');
+  late final _cst_new_list_payment_state =
+      _cst_new_list_payment_statePtr.asFunction<ffi.Pointer<wire_cst_list_payment_state> Function(int)>();
+
   ffi.Pointer<wire_cst_list_payment_type> cst_new_list_payment_type(
     int len,
   ) {
@@ -4385,10 +6250,34 @@ final class wire_cst_list_prim_u_8_strict extends ffi.Struct {
   external int len;
 }
 
+final class wire_cst_fetch_payment_proposed_fees_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+
+  @ffi.Uint64()
+  external int fees_sat;
+
+  @ffi.Uint64()
+  external int payer_amount_sat;
+
+  @ffi.Uint64()
+  external int receiver_amount_sat;
+}
+
+final class wire_cst_accept_payment_proposed_fees_request extends ffi.Struct {
+  external wire_cst_fetch_payment_proposed_fees_response response;
+}
+
 final class wire_cst_backup_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> backup_path;
 }
 
+final class wire_cst_bump_refund_fee_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_address;
+
+  @ffi.Uint32()
+  external int sat_per_vbyte;
+}
+
 final class wire_cst_prepare_buy_bitcoin_response extends ffi.Struct {
   @ffi.Int32()
   external int provider;
@@ -4403,10 +6292,99 @@ final class wire_cst_prepare_buy_bitcoin_response extends ffi.Struct {
 final class wire_cst_buy_bitcoin_request extends ffi.Struct {
   external wire_cst_prepare_buy_bitcoin_response prepare_res;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> redirect_url;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> redirect_url;
+}
+
+final class wire_cst_delete_payment_metadata_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key;
+}
+
+final class wire_cst_export_payments_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> export_path;
+
+  @ffi.Int32()
+  external int format;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> fiat_currency;
+
+  external ffi.Pointer<ffi.Int64> from_timestamp;
+
+  external ffi.Pointer<ffi.Int64> to_timestamp;
+}
+
+final class wire_cst_fetch_payment_proposed_fees_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+}
+
+final class wire_cst_PaymentLookup_SwapId extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+}
+
+final class wire_cst_PaymentLookup_Invoice extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> invoice;
+}
+
+final class wire_cst_PaymentLookup_PaymentHash extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_hash;
+}
+
+final class wire_cst_PaymentLookup_TxId extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> tx_id;
+}
+
+final class wire_cst_PaymentLookup_Destination extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+}
+
+final class PaymentLookupKind extends ffi.Union {
+  external wire_cst_PaymentLookup_SwapId SwapId;
+
+  external wire_cst_PaymentLookup_Invoice Invoice;
+
+  external wire_cst_PaymentLookup_PaymentHash PaymentHash;
+
+  external wire_cst_PaymentLookup_TxId TxId;
+
+  external wire_cst_PaymentLookup_Destination Destination;
+}
+
+final class wire_cst_payment_lookup extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external PaymentLookupKind kind;
+}
+
+final class wire_cst_list_payment_type extends ffi.Struct {
+  external ffi.Pointer<ffi.Int32> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_payment_metadata_filter extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value;
+}
+
+final class wire_cst_list_payment_metadata_filter extends ffi.Struct {
+  external ffi.Pointer<wire_cst_payment_metadata_filter> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_payment_state extends ffi.Struct {
+  external ffi.Pointer<ffi.Int32> ptr;
+
+  @ffi.Int32()
+  external int len;
 }
 
-final class wire_cst_list_payment_type extends ffi.Struct {
+final class wire_cst_list_payment_kind extends ffi.Struct {
   external ffi.Pointer<ffi.Int32> ptr;
 
   @ffi.Int32()
@@ -4423,6 +6401,22 @@ final class wire_cst_list_payments_request extends ffi.Struct {
   external ffi.Pointer<ffi.Uint32> offset;
 
   external ffi.Pointer<ffi.Uint32> limit;
+
+  external ffi.Pointer<wire_cst_list_payment_metadata_filter> metadata_filters;
+
+  external ffi.Pointer<wire_cst_list_payment_state> states;
+
+  external ffi.Pointer<wire_cst_list_payment_kind> kinds;
+
+  external ffi.Pointer<ffi.Uint64> from_amount_sat;
+
+  external ffi.Pointer<ffi.Uint64> to_amount_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> search;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> cursor;
 }
 
 final class wire_cst_ln_url_auth_request_data extends ffi.Struct {
@@ -4504,12 +6498,17 @@ final class wire_cst_prepare_pay_onchain_response extends ffi.Struct {
 
   @ffi.Uint64()
   external int total_fees_sat;
+
+  @ffi.Bool()
+  external bool drain;
 }
 
 final class wire_cst_pay_onchain_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
 
   external wire_cst_prepare_pay_onchain_response prepare_res;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> idempotency_key;
 }
 
 final class wire_cst_prepare_buy_bitcoin_request extends ffi.Struct {
@@ -4525,11 +6524,12 @@ final class wire_cst_prepare_pay_onchain_request extends ffi.Struct {
   external int receiver_amount_sat;
 
   external ffi.Pointer<ffi.Uint32> sat_per_vbyte;
+
+  external ffi.Pointer<ffi.Bool> drain;
 }
 
 final class wire_cst_prepare_receive_onchain_request extends ffi.Struct {
-  @ffi.Uint64()
-  external int payer_amount_sat;
+  external ffi.Pointer<ffi.Uint64> payer_amount_sat;
 }
 
 final class wire_cst_prepare_receive_payment_request extends ffi.Struct {
@@ -4546,16 +6546,42 @@ final class wire_cst_prepare_refund_request extends ffi.Struct {
   external int sat_per_vbyte;
 }
 
+final class wire_cst_prepare_send_liquid_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> destination;
+
+  external ffi.Pointer<ffi.Uint64> amount_sat;
+
+  external ffi.Pointer<ffi.Bool> drain;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+}
+
 final class wire_cst_prepare_send_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> invoice;
+
+  external ffi.Pointer<ffi.Uint64> amount_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> idempotency_key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payer_note;
+}
+
+final class wire_cst_receive_liquid_request extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint64> amount_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
 }
 
 final class wire_cst_prepare_receive_onchain_response extends ffi.Struct {
-  @ffi.Uint64()
-  external int payer_amount_sat;
+  external ffi.Pointer<ffi.Uint64> payer_amount_sat;
 
   @ffi.Uint64()
   external int fees_sat;
+
+  @ffi.Double()
+  external double swapper_feerate;
 }
 
 final class wire_cst_prepare_receive_payment_response extends ffi.Struct {
@@ -4585,17 +6611,79 @@ final class wire_cst_restore_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> backup_path;
 }
 
+final class wire_cst_prepare_send_liquid_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+
+  @ffi.Uint64()
+  external int receiver_amount_sat;
+
+  @ffi.Uint64()
+  external int fees_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  @ffi.Bool()
+  external bool drain;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+}
+
 final class wire_cst_prepare_send_response extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> invoice;
 
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> offer;
+
+  @ffi.Uint64()
+  external int receiver_amount_sat;
+
   @ffi.Uint64()
   external int fees_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> idempotency_key;
+}
+
+final class wire_cst_set_payment_metadata_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value;
 }
 
 final class wire_cst_binding_event_listener extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> stream;
 }
 
+final class wire_cst_payment_metadata extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value;
+}
+
+final class wire_cst_list_payment_metadata extends ffi.Struct {
+  external ffi.Pointer<wire_cst_payment_metadata> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_payment_fiat_rate extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> coin;
+
+  @ffi.Double()
+  external double value;
+
+  @ffi.Uint32()
+  external int created_at;
+}
+
+final class wire_cst_list_payment_fiat_rate extends ffi.Struct {
+  external ffi.Pointer<wire_cst_payment_fiat_rate> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
 final class wire_cst_payment extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> tx_id;
 
@@ -4616,6 +6704,10 @@ final class wire_cst_payment extends ffi.Struct {
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
 
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> destination;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
 
   external ffi.Pointer<ffi.Uint64> refund_tx_amount_sat;
@@ -4625,6 +6717,10 @@ final class wire_cst_payment extends ffi.Struct {
 
   @ffi.Int32()
   external int status;
+
+  external ffi.Pointer<wire_cst_list_payment_metadata> metadata;
+
+  external ffi.Pointer<wire_cst_list_payment_fiat_rate> fiat_rates;
 }
 
 final class wire_cst_SdkEvent_PaymentFailed extends ffi.Struct {
@@ -4651,6 +6747,22 @@ final class wire_cst_SdkEvent_PaymentWaitingConfirmation extends ffi.Struct {
   external ffi.Pointer<wire_cst_payment> details;
 }
 
+final class wire_cst_auto_refund_details extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_address;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_address;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
+}
+
+final class wire_cst_SdkEvent_SwapAutoRefunded extends ffi.Struct {
+  external ffi.Pointer<wire_cst_auto_refund_details> details;
+}
+
+final class wire_cst_SdkEvent_SwapWaitingFeeAcceptance extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+}
+
 final class SdkEventKind extends ffi.Union {
   external wire_cst_SdkEvent_PaymentFailed PaymentFailed;
 
@@ -4663,6 +6775,10 @@ final class SdkEventKind extends ffi.Union {
   external wire_cst_SdkEvent_PaymentSucceeded PaymentSucceeded;
 
   external wire_cst_SdkEvent_PaymentWaitingConfirmation PaymentWaitingConfirmation;
+
+  external wire_cst_SdkEvent_SwapAutoRefunded SwapAutoRefunded;
+
+  external wire_cst_SdkEvent_SwapWaitingFeeAcceptance SwapWaitingFeeAcceptance;
 }
 
 final class wire_cst_sdk_event extends ffi.Struct {
@@ -4672,6 +6788,59 @@ final class wire_cst_sdk_event extends ffi.Struct {
   external SdkEventKind kind;
 }
 
+final class wire_cst_asset_metadata extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> ticker;
+
+  @ffi.Uint8()
+  external int precision;
+}
+
+final class wire_cst_list_asset_metadata extends ffi.Struct {
+  external ffi.Pointer<wire_cst_asset_metadata> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_String extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<wire_cst_list_prim_u_8_strict>> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_RefundDestination_Address extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+}
+
+final class wire_cst_RefundDestination_Xpub extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> xpub;
+}
+
+final class RefundDestinationKind extends ffi.Union {
+  external wire_cst_RefundDestination_Address Address;
+
+  external wire_cst_RefundDestination_Xpub Xpub;
+}
+
+final class wire_cst_refund_destination extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external RefundDestinationKind kind;
+}
+
+final class wire_cst_auto_refund_policy extends ffi.Struct {
+  external wire_cst_refund_destination destination;
+
+  @ffi.Int32()
+  external int fee_rate;
+}
+
 final class wire_cst_config extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> liquid_electrum_url;
 
@@ -4679,6 +6848,19 @@ final class wire_cst_config extends ffi.Struct {
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> mempoolspace_url;
 
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> liquid_esplora_url;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bitcoin_esplora_url;
+
+  @ffi.Int32()
+  external int chain_backend;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> breez_server_url;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> boltz_url;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> referral_id;
+
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> working_dir;
 
   @ffi.Int32()
@@ -4691,12 +6873,51 @@ final class wire_cst_config extends ffi.Struct {
   external int zero_conf_min_fee_rate_msat;
 
   external ffi.Pointer<ffi.Uint64> zero_conf_max_amount_sat;
+
+  external ffi.Pointer<wire_cst_list_asset_metadata> asset_metadata;
+
+  external ffi.Pointer<wire_cst_list_String> fiat_currencies;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> proxy_url;
+
+  external ffi.Pointer<wire_cst_auto_refund_policy> auto_refund;
+
+  external ffi.Pointer<ffi.Uint64> amountless_fee_tolerance_sat;
 }
 
 final class wire_cst_connect_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> mnemonic;
 
   external wire_cst_config config;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> watch_only_descriptor;
+}
+
+final class wire_cst_SignerResult_String extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value;
+}
+
+final class wire_cst_SignerResult_Bytes extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value;
+}
+
+final class wire_cst_SignerResult_Error extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class SignerResultKind extends ffi.Union {
+  external wire_cst_SignerResult_String String;
+
+  external wire_cst_SignerResult_Bytes Bytes;
+
+  external wire_cst_SignerResult_Error Error;
+}
+
+final class wire_cst_signer_result extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external SignerResultKind kind;
 }
 
 final class wire_cst_aes_success_action_data_decrypted extends ffi.Struct {
@@ -4877,6 +7098,28 @@ final class wire_cst_symbol extends ffi.Struct {
   external ffi.Pointer<ffi.Uint32> position;
 }
 
+final class wire_cst_asset_balance extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
+  @ffi.Uint64()
+  external int balance;
+
+  @ffi.Uint64()
+  external int pending_send;
+
+  @ffi.Uint64()
+  external int pending_receive;
+
+  external ffi.Pointer<wire_cst_asset_metadata> asset_metadata;
+}
+
+final class wire_cst_list_asset_balance extends ffi.Struct {
+  external ffi.Pointer<wire_cst_asset_balance> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
 final class wire_cst_localized_name extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> locale;
 
@@ -4973,6 +7216,44 @@ final class wire_cst_list_refundable_swap extends ffi.Struct {
   external int len;
 }
 
+final class wire_cst_balance_details extends ffi.Struct {
+  @ffi.Uint64()
+  external int confirmed_sat;
+
+  @ffi.Uint64()
+  external int unconfirmed_receive_sat;
+
+  @ffi.Uint64()
+  external int unconfirmed_send_sat;
+
+  @ffi.Uint64()
+  external int pending_send_swaps_sat;
+
+  @ffi.Uint64()
+  external int pending_receive_swaps_sat;
+
+  @ffi.Uint64()
+  external int pending_refund_sat;
+
+  @ffi.Uint64()
+  external int refundable_btc_sat;
+
+  @ffi.Uint64()
+  external int pending_btc_refund_sat;
+}
+
+final class wire_cst_bump_refund_fee_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> replaced_tx_id;
+
+  @ffi.Uint64()
+  external int tx_fee_sat;
+
+  @ffi.Uint64()
+  external int fee_delta_sat;
+}
+
 final class wire_cst_get_info_response extends ffi.Struct {
   @ffi.Uint64()
   external int balance_sat;
@@ -4984,6 +7265,10 @@ final class wire_cst_get_info_response extends ffi.Struct {
   external int pending_receive_sat;
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> pubkey;
+
+  external ffi.Pointer<wire_cst_list_asset_balance> asset_balances;
+
+  external wire_cst_balance_details balance_details;
 }
 
 final class wire_cst_InputType_BitcoinAddress extends ffi.Struct {
@@ -5060,6 +7345,26 @@ final class wire_cst_lightning_payment_limits_response extends ffi.Struct {
   external wire_cst_limits receive;
 }
 
+final class wire_cst_list_payments_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_payment> payments;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> next_cursor;
+}
+
+final class wire_cst_ln_offer extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> offer;
+
+  external ffi.Pointer<wire_cst_list_String> chains;
+
+  external ffi.Pointer<ffi.Uint64> amount_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  external ffi.Pointer<ffi.Uint64> absolute_expiry;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> issuer;
+}
+
 final class wire_cst_LnUrlAuthError_Generic extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
 }
@@ -5288,6 +7593,10 @@ final class wire_cst_onchain_payment_limits_response extends ffi.Struct {
   external wire_cst_limits receive;
 }
 
+final class wire_cst_PaymentError_AmountMissing extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
 final class wire_cst_PaymentError_Generic extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
 }
@@ -5296,6 +7605,10 @@ final class wire_cst_PaymentError_InvalidInvoice extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
 }
 
+final class wire_cst_PaymentError_InvalidDestination extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
 final class wire_cst_PaymentError_LwkError extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
 }
@@ -5319,10 +7632,14 @@ final class wire_cst_PaymentError_SignerError extends ffi.Struct {
 }
 
 final class PaymentErrorKind extends ffi.Union {
+  external wire_cst_PaymentError_AmountMissing AmountMissing;
+
   external wire_cst_PaymentError_Generic Generic;
 
   external wire_cst_PaymentError_InvalidInvoice InvalidInvoice;
 
+  external wire_cst_PaymentError_InvalidDestination InvalidDestination;
+
   external wire_cst_PaymentError_LwkError LwkError;
 
   external wire_cst_PaymentError_ReceiveError ReceiveError;
@@ -5351,6 +7668,12 @@ final class wire_cst_prepare_refund_response extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
 }
 
+final class wire_cst_receive_liquid_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bip21;
+}
+
 final class wire_cst_receive_onchain_response extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
 
@@ -5409,8 +7732,54 @@ final class wire_cst_send_payment_response extends ffi.Struct {
   external wire_cst_payment payment;
 }
 
+final class wire_cst_SignerMethod_DeriveXpub extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> derivation_path;
+}
+
+final class wire_cst_SignerMethod_SignPset extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> pset;
+}
+
+final class wire_cst_SignerMethod_SignEcdsa extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> msg;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> derivation_path;
+}
+
+final class wire_cst_SignerMethod_HmacSha256 extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> msg;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> derivation_path;
+}
+
+final class SignerMethodKind extends ffi.Union {
+  external wire_cst_SignerMethod_DeriveXpub DeriveXpub;
+
+  external wire_cst_SignerMethod_SignPset SignPset;
+
+  external wire_cst_SignerMethod_SignEcdsa SignEcdsa;
+
+  external wire_cst_SignerMethod_HmacSha256 HmacSha256;
+}
+
+final class wire_cst_signer_method extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external SignerMethodKind kind;
+}
+
+final class wire_cst_signer_request extends ffi.Struct {
+  @ffi.Uint32()
+  external int id;
+
+  external wire_cst_signer_method method;
+}
+
 const int ESTIMATED_BTC_CLAIM_TX_VSIZE = 111;
 
+const int DEFAULT_AMOUNTLESS_FEE_TOLERANCE_SAT = 0;
+
 const double STANDARD_FEE_RATE_SAT_PER_VBYTE = 0.1;
 
 const double LOWBALL_FEE_RATE_SAT_PER_VBYTE = 0.01;
@@ -9,6 +9,154 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'model.freezed.dart';
 
+/// An argument when calling [crate::sdk::LiquidSdk::accept_payment_proposed_fees].
+class AcceptPaymentProposedFeesRequest {
+  final FetchPaymentProposedFeesResponse response;
+
+  const AcceptPaymentProposedFeesRequest({
+    required this.response,
+  });
+
+  @override
+  int get hashCode => response.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AcceptPaymentProposedFeesRequest &&
+          runtimeType == other.runtimeType &&
+          response == other.response;
+}
+
+/// The balance of a Liquid asset other than L-BTC, returned as part of [GetInfoResponse]
+class AssetBalance {
+  final String assetId;
+
+  /// Usable balance in the asset base units. This is the confirmed balance minus pending sends.
+  final BigInt balance;
+
+  /// Outgoing amount in the asset base units that is not yet confirmed
+  final BigInt pendingSend;
+
+  /// Incoming amount in the asset base units that is not yet confirmed
+  final BigInt pendingReceive;
+
+  /// The asset metadata, if the asset is known. See [Config::asset_metadata]
+  final AssetMetadata? assetMetadata;
+
+  const AssetBalance({
+    required this.assetId,
+    required this.balance,
+    required this.pendingSend,
+    required this.pendingReceive,
+    this.assetMetadata,
+  });
+
+  @override
+  int get hashCode =>
+      assetId.hashCode ^
+      balance.hashCode ^
+      pendingSend.hashCode ^
+      pendingReceive.hashCode ^
+      assetMetadata.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AssetBalance &&
+          runtimeType == other.runtimeType &&
+          assetId == other.assetId &&
+          balance == other.balance &&
+          pendingSend == other.pendingSend &&
+          pendingReceive == other.pendingReceive &&
+          assetMetadata == other.assetMetadata;
+}
+
+/// Metadata of a Liquid asset, used to identify and display asset amounts
+class AssetMetadata {
+  /// The asset id in hex format
+  final String assetId;
+  final String name;
+  final String ticker;
+
+  /// The number of decimal places of the asset. Amounts are always expressed in base units,
+  /// so for example an amount of 150 of an asset with precision 2 is displayed as 1.50
+  final int precision;
+
+  const AssetMetadata({
+    required this.assetId,
+    required this.name,
+    required this.ticker,
+    required this.precision,
+  });
+
+  @override
+  int get hashCode => assetId.hashCode ^ name.hashCode ^ ticker.hashCode ^ precision.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AssetMetadata &&
+          runtimeType == other.runtimeType &&
+          assetId == other.assetId &&
+          name == other.name &&
+          ticker == other.ticker &&
+          precision == other.precision;
+}
+
+/// The details of a refund broadcast by an [AutoRefundPolicy], see [SdkEvent::SwapAutoRefunded]
+class AutoRefundDetails {
+  /// The address where the swap funds were locked up
+  final String swapAddress;
+
+  /// The address the swap funds were refunded to
+  final String refundAddress;
+  final String refundTxId;
+
+  const AutoRefundDetails({
+    required this.swapAddress,
+    required this.refundAddress,
+    required this.refundTxId,
+  });
+
+  @override
+  int get hashCode => swapAddress.hashCode ^ refundAddress.hashCode ^ refundTxId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AutoRefundDetails &&
+          runtimeType == other.runtimeType &&
+          swapAddress == other.swapAddress &&
+          refundAddress == other.refundAddress &&
+          refundTxId == other.refundTxId;
+}
+
+/// The policy to automatically refund failed incoming chain swaps, see [Config::auto_refund]
+class AutoRefundPolicy {
+  /// Where to send the refunds to
+  final RefundDestination destination;
+
+  /// The fee rate at which to broadcast the refunds
+  final RefundFeeRate feeRate;
+
+  const AutoRefundPolicy({
+    required this.destination,
+    required this.feeRate,
+  });
+
+  @override
+  int get hashCode => destination.hashCode ^ feeRate.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AutoRefundPolicy &&
+          runtimeType == other.runtimeType &&
+          destination == other.destination &&
+          feeRate == other.feeRate;
+}
+
 /// An argument when calling [crate::sdk::LiquidSdk::backup].
 class BackupRequest {
   /// Path to the backup.
@@ -30,6 +178,130 @@ class BackupRequest {
       other is BackupRequest && runtimeType == other.runtimeType && backupPath == other.backupPath;
 }
 
+/// A breakdown of the wallet balance, returned as part of [GetInfoResponse]
+class BalanceDetails {
+  /// Confirmed L-BTC balance, before subtracting the pending sends
+  final BigInt confirmedSat;
+
+  /// L-BTC received in direct Liquid txs that are not yet confirmed
+  final BigInt unconfirmedReceiveSat;
+
+  /// L-BTC sent in direct Liquid txs that are not yet confirmed
+  final BigInt unconfirmedSendSat;
+
+  /// L-BTC locked up in ongoing Send swaps and outgoing chain swaps
+  final BigInt pendingSendSwapsSat;
+
+  /// L-BTC incoming from ongoing Receive swaps and incoming chain swaps, not yet confirmed
+  final BigInt pendingReceiveSwapsSat;
+
+  /// L-BTC being refunded from failed Send swaps and outgoing chain swaps
+  final BigInt pendingRefundSat;
+
+  /// BTC locked up in failed incoming chain swaps, which has to be refunded manually.
+  /// See [crate::sdk::LiquidSdk::list_refundables]
+  final BigInt refundableBtcSat;
+
+  /// BTC being refunded from failed incoming chain swaps
+  final BigInt pendingBtcRefundSat;
+
+  const BalanceDetails({
+    required this.confirmedSat,
+    required this.unconfirmedReceiveSat,
+    required this.unconfirmedSendSat,
+    required this.pendingSendSwapsSat,
+    required this.pendingReceiveSwapsSat,
+    required this.pendingRefundSat,
+    required this.refundableBtcSat,
+    required this.pendingBtcRefundSat,
+  });
+
+  @override
+  int get hashCode =>
+      confirmedSat.hashCode ^
+      unconfirmedReceiveSat.hashCode ^
+      unconfirmedSendSat.hashCode ^
+      pendingSendSwapsSat.hashCode ^
+      pendingReceiveSwapsSat.hashCode ^
+      pendingRefundSat.hashCode ^
+      refundableBtcSat.hashCode ^
+      pendingBtcRefundSat.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BalanceDetails &&
+          runtimeType == other.runtimeType &&
+          confirmedSat == other.confirmedSat &&
+          unconfirmedReceiveSat == other.unconfirmedReceiveSat &&
+          unconfirmedSendSat == other.unconfirmedSendSat &&
+          pendingSendSwapsSat == other.pendingSendSwapsSat &&
+          pendingReceiveSwapsSat == other.pendingReceiveSwapsSat &&
+          pendingRefundSat == other.pendingRefundSat &&
+          refundableBtcSat == other.refundableBtcSat &&
+          pendingBtcRefundSat == other.pendingBtcRefundSat;
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::bump_refund_fee].
+class BumpRefundFeeRequest {
+  /// The address where the swap funds are locked up
+  final String swapAddress;
+
+  /// The new fee rate in sat/vB for the refund transaction
+  final int satPerVbyte;
+
+  const BumpRefundFeeRequest({
+    required this.swapAddress,
+    required this.satPerVbyte,
+  });
+
+  @override
+  int get hashCode => swapAddress.hashCode ^ satPerVbyte.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BumpRefundFeeRequest &&
+          runtimeType == other.runtimeType &&
+          swapAddress == other.swapAddress &&
+          satPerVbyte == other.satPerVbyte;
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::bump_refund_fee].
+class BumpRefundFeeResponse {
+  /// The id of the replacement refund transaction
+  final String refundTxId;
+
+  /// The id of the refund transaction that was replaced
+  final String replacedTxId;
+
+  /// The absolute fee of the replacement refund transaction
+  final BigInt txFeeSat;
+
+  /// How much more the replacement refund transaction pays in fees
+  final BigInt feeDeltaSat;
+
+  const BumpRefundFeeResponse({
+    required this.refundTxId,
+    required this.replacedTxId,
+    required this.txFeeSat,
+    required this.feeDeltaSat,
+  });
+
+  @override
+  int get hashCode => refundTxId.hashCode ^ replacedTxId.hashCode ^ txFeeSat.hashCode ^ feeDeltaSat.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BumpRefundFeeResponse &&
+          runtimeType == other.runtimeType &&
+          refundTxId == other.refundTxId &&
+          replacedTxId == other.replacedTxId &&
+          txFeeSat == other.txFeeSat &&
+          feeDeltaSat == other.feeDeltaSat;
+}
+
 /// An argument of [PrepareBuyBitcoinRequest] when calling [crate::sdk::LiquidSdk::prepare_buy_bitcoin].
 enum BuyBitcoinProvider {
   moonpay,
@@ -62,6 +334,17 @@ class BuyBitcoinRequest {
           redirectUrl == other.redirectUrl;
 }
 
+/// The source of the chain data of the Liquid SDK
+enum ChainBackend {
+  /// The Electrum servers, see [Config::liquid_electrum_url] and [Config::bitcoin_electrum_url]
+  electrum,
+
+  /// The Esplora REST APIs, see [Config::liquid_esplora_url] and [Config::bitcoin_esplora_url].
+  /// Only needs outbound HTTPS.
+  esplora,
+  ;
+}
+
 /// Configuration for the Liquid SDK
 class Config {
   final String liquidElectrumUrl;
@@ -70,6 +353,28 @@ class Config {
   /// The mempool.space API URL, has to be in the format: `https://mempool.space/api`
   final String mempoolspaceUrl;
 
+  /// The Liquid Esplora API URL, used on mainnet to broadcast txs and fetch script histories.
+  /// Has to be in the format: `https://blockstream.info/liquid/api`
+  final String liquidEsploraUrl;
+
+  /// The Bitcoin Esplora API URL, used with [ChainBackend::Esplora].
+  /// Has to be in the format: `https://blockstream.info/api`
+  final String bitcoinEsploraUrl;
+
+  /// Whether the chain data is fetched from the Electrum or the Esplora servers
+  final ChainBackend chainBackend;
+
+  /// The Breez server URL, used to look up the swapper proxy and to buy Bitcoin
+  final String breezServerUrl;
+
+  /// The optional Boltz API URL, e.g. of a self-hosted instance. If not set, the Boltz swapper
+  /// proxy from the Breez server is used on mainnet, or else the public Boltz instance.
+  final String? boltzUrl;
+
+  /// The optional referral id sent to Boltz when creating swaps. If not set, the one of the
+  /// swapper proxy is used, if any.
+  final String? referralId;
+
   /// Directory in which all SDK files (DB, log, cache) are stored.
   ///
   /// Prefix can be a relative or absolute path to this directory.
@@ -86,15 +391,49 @@ class Config {
   /// Defaults to [crate::receive_swap::DEFAULT_ZERO_CONF_MAX_SAT]
   final BigInt? zeroConfMaxAmountSat;
 
+  /// Metadata of additional Liquid assets, on top of the built-in registry of well-known
+  /// assets. See [AssetMetadata::registry]
+  final List<AssetMetadata>? assetMetadata;
+
+  /// The fiat currencies, e.g. `USD`, to record the exchange rates of whenever a payment is
+  /// created or first seen. See [Payment::fiat_rates]
+  final List<String>? fiatCurrencies;
+
+  /// The optional SOCKS5 proxy URL, e.g. `socks5h://127.0.0.1:9050` for a local Tor daemon.
+  /// All the chain, Boltz and fee estimate requests go through it. The only client without a
+  /// proxy hook is the Breez server gRPC client, so the fiat rates, buying Bitcoin and the
+  /// swapper proxy lookup fail instead of connecting directly.
+  final String? proxyUrl;
+
+  /// The optional policy to refund failed incoming chain swaps automatically. If not set, they
+  /// stay refundable until [crate::sdk::LiquidSdk::refund] is called.
+  final AutoRefundPolicy? autoRefund;
+
+  /// The most, in satoshi, by which the fees proposed for an amountless incoming chain swap may
+  /// exceed the current fees of the swapper before they are no longer accepted automatically.
+  /// Defaults to [crate::chain_swap::DEFAULT_AMOUNTLESS_FEE_TOLERANCE_SAT]
+  final BigInt? amountlessFeeToleranceSat;
+
   const Config({
     required this.liquidElectrumUrl,
     required this.bitcoinElectrumUrl,
     required this.mempoolspaceUrl,
+    required this.liquidEsploraUrl,
+    required this.bitcoinEsploraUrl,
+    required this.chainBackend,
+    required this.breezServerUrl,
+    this.boltzUrl,
+    this.referralId,
     required this.workingDir,
     required this.network,
     required this.paymentTimeoutSec,
     required this.zeroConfMinFeeRateMsat,
     this.zeroConfMaxAmountSat,
+    this.assetMetadata,
+    this.fiatCurrencies,
+    this.proxyUrl,
+    this.autoRefund,
+    this.amountlessFeeToleranceSat,
   });
 
   @override
@@ -102,11 +441,22 @@ class Config {
       liquidElectrumUrl.hashCode ^
       bitcoinElectrumUrl.hashCode ^
       mempoolspaceUrl.hashCode ^
+      liquidEsploraUrl.hashCode ^
+      bitcoinEsploraUrl.hashCode ^
+      chainBackend.hashCode ^
+      breezServerUrl.hashCode ^
+      boltzUrl.hashCode ^
+      referralId.hashCode ^
       workingDir.hashCode ^
       network.hashCode ^
       paymentTimeoutSec.hashCode ^
       zeroConfMinFeeRateMsat.hashCode ^
-      zeroConfMaxAmountSat.hashCode;
+      zeroConfMaxAmountSat.hashCode ^
+      assetMetadata.hashCode ^
+      fiatCurrencies.hashCode ^
+      proxyUrl.hashCode ^
+      autoRefund.hashCode ^
+      amountlessFeeToleranceSat.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -116,25 +466,43 @@ class Config {
           liquidElectrumUrl == other.liquidElectrumUrl &&
           bitcoinElectrumUrl == other.bitcoinElectrumUrl &&
           mempoolspaceUrl == other.mempoolspaceUrl &&
+          liquidEsploraUrl == other.liquidEsploraUrl &&
+          bitcoinEsploraUrl == other.bitcoinEsploraUrl &&
+          chainBackend == other.chainBackend &&
+          breezServerUrl == other.breezServerUrl &&
+          boltzUrl == other.boltzUrl &&
+          referralId == other.referralId &&
           workingDir == other.workingDir &&
           network == other.network &&
           paymentTimeoutSec == other.paymentTimeoutSec &&
           zeroConfMinFeeRateMsat == other.zeroConfMinFeeRateMsat &&
-          zeroConfMaxAmountSat == other.zeroConfMaxAmountSat;
+          zeroConfMaxAmountSat == other.zeroConfMaxAmountSat &&
+          assetMetadata == other.assetMetadata &&
+          fiatCurrencies == other.fiatCurrencies &&
+          proxyUrl == other.proxyUrl &&
+          autoRefund == other.autoRefund &&
+          amountlessFeeToleranceSat == other.amountlessFeeToleranceSat;
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::connect].
 class ConnectRequest {
-  final String mnemonic;
+  /// The wallet mnemonic. Required unless `watch_only_descriptor` is set.
+  final String? mnemonic;
   final Config config;
 
+  /// A CT descriptor of the wallet, as parsed by [lwk_wollet::WolletDescriptor]. If set instead
+  /// of the mnemonic, the SDK connects in watch-only mode: payments, balances and sync work,
+  /// while any call that needs to sign or create a swap fails with [PaymentError::WatchOnly].
+  final String? watchOnlyDescriptor;
+
   const ConnectRequest({
-    required this.mnemonic,
+    this.mnemonic,
     required this.config,
+    this.watchOnlyDescriptor,
   });
 
   @override
-  int get hashCode => mnemonic.hashCode ^ config.hashCode;
+  int get hashCode => mnemonic.hashCode ^ config.hashCode ^ watchOnlyDescriptor.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -142,7 +510,136 @@ class ConnectRequest {
       other is ConnectRequest &&
           runtimeType == other.runtimeType &&
           mnemonic == other.mnemonic &&
-          config == other.config;
+          config == other.config &&
+          watchOnlyDescriptor == other.watchOnlyDescriptor;
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::delete_payment_metadata].
+class DeletePaymentMetadataRequest {
+  /// The tx id or swap id of the payment
+  final String paymentId;
+
+  /// The key to delete. If not set, all the metadata of the payment is deleted.
+  final String? key;
+
+  const DeletePaymentMetadataRequest({
+    required this.paymentId,
+    this.key,
+  });
+
+  @override
+  int get hashCode => paymentId.hashCode ^ key.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DeletePaymentMetadataRequest &&
+          runtimeType == other.runtimeType &&
+          paymentId == other.paymentId &&
+          key == other.key;
+}
+
+/// The file format of [ExportPaymentsRequest]
+enum ExportFormat {
+  csv,
+  json,
+  ;
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::export_payments].
+class ExportPaymentsRequest {
+  /// Path of the file to export the payments to. Any existing file is replaced.
+  final String exportPath;
+  final ExportFormat format;
+
+  /// The optional code of the fiat currency to value the payments in, e.g. `USD`
+  final String? fiatCurrency;
+
+  /// Epoch time, in seconds
+  final PlatformInt64? fromTimestamp;
+
+  /// Epoch time, in seconds
+  final PlatformInt64? toTimestamp;
+
+  const ExportPaymentsRequest({
+    required this.exportPath,
+    required this.format,
+    this.fiatCurrency,
+    this.fromTimestamp,
+    this.toTimestamp,
+  });
+
+  @override
+  int get hashCode =>
+      exportPath.hashCode ^
+      format.hashCode ^
+      fiatCurrency.hashCode ^
+      fromTimestamp.hashCode ^
+      toTimestamp.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ExportPaymentsRequest &&
+          runtimeType == other.runtimeType &&
+          exportPath == other.exportPath &&
+          format == other.format &&
+          fiatCurrency == other.fiatCurrency &&
+          fromTimestamp == other.fromTimestamp &&
+          toTimestamp == other.toTimestamp;
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::fetch_payment_proposed_fees].
+class FetchPaymentProposedFeesRequest {
+  /// The id of the swap, as emitted in [SdkEvent::SwapWaitingFeeAcceptance]
+  final String swapId;
+
+  const FetchPaymentProposedFeesRequest({
+    required this.swapId,
+  });
+
+  @override
+  int get hashCode => swapId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FetchPaymentProposedFeesRequest && runtimeType == other.runtimeType && swapId == other.swapId;
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::fetch_payment_proposed_fees].
+class FetchPaymentProposedFeesResponse {
+  final String swapId;
+
+  /// The fees of the swap for the amount actually sent by the payer
+  final BigInt feesSat;
+
+  /// The amount actually sent by the payer
+  final BigInt payerAmountSat;
+
+  /// The amount that will be received if the fees are accepted
+  final BigInt receiverAmountSat;
+
+  const FetchPaymentProposedFeesResponse({
+    required this.swapId,
+    required this.feesSat,
+    required this.payerAmountSat,
+    required this.receiverAmountSat,
+  });
+
+  @override
+  int get hashCode =>
+      swapId.hashCode ^ feesSat.hashCode ^ payerAmountSat.hashCode ^ receiverAmountSat.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FetchPaymentProposedFeesResponse &&
+          runtimeType == other.runtimeType &&
+          swapId == other.swapId &&
+          feesSat == other.feesSat &&
+          payerAmountSat == other.payerAmountSat &&
+          receiverAmountSat == other.receiverAmountSat;
 }
 
 /// Returned when calling [crate::sdk::LiquidSdk::get_info].
@@ -157,16 +654,29 @@ class GetInfoResponse {
   final BigInt pendingReceiveSat;
   final String pubkey;
 
+  /// Balances of the Liquid assets other than L-BTC held by the wallet
+  final List<AssetBalance> assetBalances;
+
+  /// A breakdown of the balance, explaining why `balance_sat` may be lower than expected
+  final BalanceDetails balanceDetails;
+
   const GetInfoResponse({
     required this.balanceSat,
     required this.pendingSendSat,
     required this.pendingReceiveSat,
     required this.pubkey,
+    required this.assetBalances,
+    required this.balanceDetails,
   });
 
   @override
   int get hashCode =>
-      balanceSat.hashCode ^ pendingSendSat.hashCode ^ pendingReceiveSat.hashCode ^ pubkey.hashCode;
+      balanceSat.hashCode ^
+      pendingSendSat.hashCode ^
+      pendingReceiveSat.hashCode ^
+      pubkey.hashCode ^
+      assetBalances.hashCode ^
+      balanceDetails.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -176,7 +686,56 @@ class GetInfoResponse {
           balanceSat == other.balanceSat &&
           pendingSendSat == other.pendingSendSat &&
           pendingReceiveSat == other.pendingReceiveSat &&
-          pubkey == other.pubkey;
+          pubkey == other.pubkey &&
+          assetBalances == other.assetBalances &&
+          balanceDetails == other.balanceDetails;
+}
+
+/// A parsed bolt12 offer. See [crate::sdk::LiquidSdk::parse_offer]
+class LNOffer {
+  /// The offer as a bech32 string
+  final String offer;
+
+  /// The hashes of the chains the offer can be paid on
+  final List<String> chains;
+
+  /// The amount to pay, in satoshi. If not set, the payer chooses the amount.
+  final BigInt? amountSat;
+  final String? description;
+
+  /// The UNIX timestamp in seconds after which the offer can no longer be paid
+  final BigInt? absoluteExpiry;
+  final String? issuer;
+
+  const LNOffer({
+    required this.offer,
+    required this.chains,
+    this.amountSat,
+    this.description,
+    this.absoluteExpiry,
+    this.issuer,
+  });
+
+  @override
+  int get hashCode =>
+      offer.hashCode ^
+      chains.hashCode ^
+      amountSat.hashCode ^
+      description.hashCode ^
+      absoluteExpiry.hashCode ^
+      issuer.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LNOffer &&
+          runtimeType == other.runtimeType &&
+          offer == other.offer &&
+          chains == other.chains &&
+          amountSat == other.amountSat &&
+          description == other.description &&
+          absoluteExpiry == other.absoluteExpiry &&
+          issuer == other.issuer;
 }
 
 /// Returned when calling [crate::sdk::LiquidSdk::fetch_lightning_limits].
@@ -237,6 +796,9 @@ enum LiquidNetwork {
 
   /// Testnet Bitcoin and Liquid chains
   testnet,
+
+  /// Local regtest Bitcoin and Liquid chains, e.g. for integration tests. See [Config::regtest]
+  regtest,
   ;
 }
 
@@ -252,17 +814,65 @@ class ListPaymentsRequest {
   final int? offset;
   final int? limit;
 
+  /// Only payments matching all of these metadata filters are listed
+  final List<PaymentMetadataFilter>? metadataFilters;
+
+  /// Only payments in any of these states are listed
+  final List<PaymentState>? states;
+
+  /// Only payments of any of these kinds are listed
+  final List<PaymentKind>? kinds;
+
+  /// The minimum payment amount, inclusive. See [Payment::amount_sat]
+  final BigInt? fromAmountSat;
+
+  /// The maximum payment amount, inclusive. See [Payment::amount_sat]
+  final BigInt? toAmountSat;
+
+  /// Only payments of this Liquid asset are listed. Set it to the L-BTC asset id to exclude
+  /// the payments of other assets.
+  final String? assetId;
+
+  /// Only payments whose description or bolt11 invoice contain this text are listed. The search
+  /// is case-insensitive.
+  final String? search;
+
+  /// Only payments after this cursor are listed. Set it to the [ListPaymentsResponse::next_cursor]
+  /// of the previous page to get the next one. Cannot be combined with `offset`, and only used
+  /// by [crate::sdk::LiquidSdk::list_payments_page].
+  final String? cursor;
+
   const ListPaymentsRequest({
     this.filters,
     this.fromTimestamp,
     this.toTimestamp,
     this.offset,
     this.limit,
+    this.metadataFilters,
+    this.states,
+    this.kinds,
+    this.fromAmountSat,
+    this.toAmountSat,
+    this.assetId,
+    this.search,
+    this.cursor,
   });
 
   @override
   int get hashCode =>
-      filters.hashCode ^ fromTimestamp.hashCode ^ toTimestamp.hashCode ^ offset.hashCode ^ limit.hashCode;
+      filters.hashCode ^
+      fromTimestamp.hashCode ^
+      toTimestamp.hashCode ^
+      offset.hashCode ^
+      limit.hashCode ^
+      metadataFilters.hashCode ^
+      states.hashCode ^
+      kinds.hashCode ^
+      fromAmountSat.hashCode ^
+      toAmountSat.hashCode ^
+      assetId.hashCode ^
+      search.hashCode ^
+      cursor.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -273,7 +883,40 @@ class ListPaymentsRequest {
           fromTimestamp == other.fromTimestamp &&
           toTimestamp == other.toTimestamp &&
           offset == other.offset &&
-          limit == other.limit;
+          limit == other.limit &&
+          metadataFilters == other.metadataFilters &&
+          states == other.states &&
+          kinds == other.kinds &&
+          fromAmountSat == other.fromAmountSat &&
+          toAmountSat == other.toAmountSat &&
+          assetId == other.assetId &&
+          search == other.search &&
+          cursor == other.cursor;
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::list_payments_page].
+class ListPaymentsResponse {
+  final List<Payment> payments;
+
+  /// The opaque cursor of the next page, set only if this page is full. See
+  /// [ListPaymentsRequest::cursor]
+  final String? nextCursor;
+
+  const ListPaymentsResponse({
+    required this.payments,
+    this.nextCursor,
+  });
+
+  @override
+  int get hashCode => payments.hashCode ^ nextCursor.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ListPaymentsResponse &&
+          runtimeType == other.runtimeType &&
+          payments == other.payments &&
+          nextCursor == other.nextCursor;
 }
 
 @freezed
@@ -361,13 +1004,18 @@ class PayOnchainRequest {
   final String address;
   final PreparePayOnchainResponse prepareRes;
 
+  /// If set, calling [crate::sdk::LiquidSdk::pay_onchain] again with the same key returns the
+  /// payment started by the first call, instead of paying again
+  final String? idempotencyKey;
+
   const PayOnchainRequest({
     required this.address,
     required this.prepareRes,
+    this.idempotencyKey,
   });
 
   @override
-  int get hashCode => address.hashCode ^ prepareRes.hashCode;
+  int get hashCode => address.hashCode ^ prepareRes.hashCode ^ idempotencyKey.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -375,7 +1023,8 @@ class PayOnchainRequest {
       other is PayOnchainRequest &&
           runtimeType == other.runtimeType &&
           address == other.address &&
-          prepareRes == other.prepareRes;
+          prepareRes == other.prepareRes &&
+          idempotencyKey == other.idempotencyKey;
 }
 
 /// Represents an SDK payment.
@@ -397,6 +1046,7 @@ class Payment {
   /// The payment amount, which corresponds to the onchain tx amount.
   ///
   /// In case of an outbound payment (Send), this is the payer amount. Otherwise it's the receiver amount.
+  /// For asset payments, this is the asset amount in base units.
   final BigInt amountSat;
 
   /// Represents the fees paid by this wallet for this payment.
@@ -412,6 +1062,8 @@ class Payment {
   /// If no swap is associated with this payment:
   /// - for Send payments, this is the onchain tx fee
   /// - for Receive payments, this is zero
+  ///
+  /// Fees are always paid in L-BTC, also for asset payments.
   final BigInt feesSat;
 
   /// In case of a Send swap, this is the preimage of the paid invoice (proof of payment).
@@ -425,6 +1077,12 @@ class Payment {
   /// Represents the invoice description
   final String description;
 
+  /// For a direct Liquid payment, this is the Liquid address the funds were sent to
+  final String? destination;
+
+  /// The id of the Liquid asset transferred, if other than L-BTC. Swaps are always in L-BTC.
+  final String? assetId;
+
   /// For a Send swap which was refunded, this is the refund tx id
   final String? refundTxId;
 
@@ -441,6 +1099,13 @@ class Payment {
   /// If the tx has an associated swap, this is determined by the swap status (pending or complete).
   final PaymentState status;
 
+  /// The user-defined metadata of the payment, see [crate::sdk::LiquidSdk::set_payment_metadata]
+  final List<PaymentMetadata> metadata;
+
+  /// The exchange rates of the [Config::fiat_currencies] at the time the payment was created
+  /// or first seen, sorted by currency
+  final List<PaymentFiatRate> fiatRates;
+
   const Payment({
     this.txId,
     this.swapId,
@@ -450,10 +1115,14 @@ class Payment {
     this.preimage,
     this.bolt11,
     required this.description,
+    this.destination,
+    this.assetId,
     this.refundTxId,
     this.refundTxAmountSat,
     required this.paymentType,
     required this.status,
+    required this.metadata,
+    required this.fiatRates,
   });
 
   @override
@@ -466,10 +1135,14 @@ class Payment {
       preimage.hashCode ^
       bolt11.hashCode ^
       description.hashCode ^
+      destination.hashCode ^
+      assetId.hashCode ^
       refundTxId.hashCode ^
       refundTxAmountSat.hashCode ^
       paymentType.hashCode ^
-      status.hashCode;
+      status.hashCode ^
+      metadata.hashCode ^
+      fiatRates.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -484,10 +1157,137 @@ class Payment {
           preimage == other.preimage &&
           bolt11 == other.bolt11 &&
           description == other.description &&
+          destination == other.destination &&
+          assetId == other.assetId &&
           refundTxId == other.refundTxId &&
           refundTxAmountSat == other.refundTxAmountSat &&
           paymentType == other.paymentType &&
-          status == other.status;
+          status == other.status &&
+          metadata == other.metadata &&
+          fiatRates == other.fiatRates;
+}
+
+/// A snapshot of the exchange rate of a fiat currency, recorded for a [Payment]
+class PaymentFiatRate {
+  /// The fiat currency code, e.g. `USD`
+  final String coin;
+
+  /// The BTC price in the fiat currency
+  final double value;
+
+  /// Epoch time, in seconds, when the rate was recorded
+  final int createdAt;
+
+  const PaymentFiatRate({
+    required this.coin,
+    required this.value,
+    required this.createdAt,
+  });
+
+  @override
+  int get hashCode => coin.hashCode ^ value.hashCode ^ createdAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PaymentFiatRate &&
+          runtimeType == other.runtimeType &&
+          coin == other.coin &&
+          value == other.value &&
+          createdAt == other.createdAt;
+}
+
+/// How a [Payment] is made, used to filter [ListPaymentsRequest]
+enum PaymentKind {
+  /// A Lightning payment sent via a submarine swap
+  submarine,
+
+  /// A Lightning payment received via a reverse swap
+  reverse,
+
+  /// A Bitcoin payment sent or received via a chain swap
+  chain,
+
+  /// A Liquid payment without any swap
+  direct,
+  ;
+}
+
+@freezed
+sealed class PaymentLookup with _$PaymentLookup {
+  const PaymentLookup._();
+
+  /// The id of the swap of a Lightning or onchain payment
+  const factory PaymentLookup.swapId({
+    required String swapId,
+  }) = PaymentLookup_SwapId;
+
+  /// The bolt11 invoice paid or received via a swap
+  const factory PaymentLookup.invoice({
+    required String invoice,
+  }) = PaymentLookup_Invoice;
+
+  /// The payment hash of the bolt11 invoice paid or received via a swap
+  const factory PaymentLookup.paymentHash({
+    required String paymentHash,
+  }) = PaymentLookup_PaymentHash;
+
+  /// Any tx of the payment, e.g. the lockup, claim or refund tx of a swap
+  const factory PaymentLookup.txId({
+    required String txId,
+  }) = PaymentLookup_TxId;
+
+  /// The Liquid address a direct Liquid payment was sent to or received on
+  const factory PaymentLookup.destination({
+    required String address,
+  }) = PaymentLookup_Destination;
+}
+
+/// A user-defined key-value pair attached to a [Payment], such as a note, a label or an
+/// external order id
+class PaymentMetadata {
+  final String key;
+  final String value;
+
+  const PaymentMetadata({
+    required this.key,
+    required this.value,
+  });
+
+  @override
+  int get hashCode => key.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PaymentMetadata &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          value == other.value;
+}
+
+/// A filter on the [Payment::metadata], used in [ListPaymentsRequest]
+class PaymentMetadataFilter {
+  final String key;
+
+  /// If set, the metadata value has to match it. Otherwise the key only has to be present.
+  final String? value;
+
+  const PaymentMetadataFilter({
+    required this.key,
+    this.value,
+  });
+
+  @override
+  int get hashCode => key.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PaymentMetadataFilter &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          value == other.value;
 }
 
 /// The payment state of an individual payment.
@@ -555,6 +1355,15 @@ enum PaymentState {
   ///
   /// When the refund tx is broadcast, `refund_tx_id` is set in the swap.
   refundPending,
+
+  /// ## Incoming Chain Swaps
+  ///
+  /// This is the status when the user lockup amount differs from the agreed one and the swapper
+  /// proposed new fees for it. They can be accepted by calling
+  /// [crate::sdk::LiquidSdk::fetch_payment_proposed_fees] then
+  /// [crate::sdk::LiquidSdk::accept_payment_proposed_fees], otherwise the swap becomes
+  /// refundable once it expires.
+  waitingFeeAcceptance,
   ;
 }
 
@@ -613,16 +1422,21 @@ class PrepareBuyBitcoinResponse {
 
 /// An argument when calling [crate::sdk::LiquidSdk::prepare_pay_onchain].
 class PreparePayOnchainRequest {
+  /// The amount to be received. Ignored if `drain` is set.
   final BigInt receiverAmountSat;
   final int? satPerVbyte;
 
+  /// If set, the whole wallet balance is sent and the receiver amount is the balance minus fees
+  final bool? drain;
+
   const PreparePayOnchainRequest({
     required this.receiverAmountSat,
     this.satPerVbyte,
+    this.drain,
   });
 
   @override
-  int get hashCode => receiverAmountSat.hashCode ^ satPerVbyte.hashCode;
+  int get hashCode => receiverAmountSat.hashCode ^ satPerVbyte.hashCode ^ drain.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -630,7 +1444,8 @@ class PreparePayOnchainRequest {
       other is PreparePayOnchainRequest &&
           runtimeType == other.runtimeType &&
           receiverAmountSat == other.receiverAmountSat &&
-          satPerVbyte == other.satPerVbyte;
+          satPerVbyte == other.satPerVbyte &&
+          drain == other.drain;
 }
 
 /// Returned when calling [crate::sdk::LiquidSdk::prepare_pay_onchain].
@@ -639,14 +1454,19 @@ class PreparePayOnchainResponse {
   final BigInt claimFeesSat;
   final BigInt totalFeesSat;
 
+  /// Whether the whole wallet balance is sent
+  final bool drain;
+
   const PreparePayOnchainResponse({
     required this.receiverAmountSat,
     required this.claimFeesSat,
     required this.totalFeesSat,
+    required this.drain,
   });
 
   @override
-  int get hashCode => receiverAmountSat.hashCode ^ claimFeesSat.hashCode ^ totalFeesSat.hashCode;
+  int get hashCode =>
+      receiverAmountSat.hashCode ^ claimFeesSat.hashCode ^ totalFeesSat.hashCode ^ drain.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -655,15 +1475,18 @@ class PreparePayOnchainResponse {
           runtimeType == other.runtimeType &&
           receiverAmountSat == other.receiverAmountSat &&
           claimFeesSat == other.claimFeesSat &&
-          totalFeesSat == other.totalFeesSat;
+          totalFeesSat == other.totalFeesSat &&
+          drain == other.drain;
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::prepare_receive_onchain].
 class PrepareReceiveOnchainRequest {
-  final BigInt payerAmountSat;
+  /// The amount to be paid by the payer. If not set, any amount within the swapper limits can
+  /// be paid, and the swap continues once the fees for the actual amount are accepted.
+  final BigInt? payerAmountSat;
 
   const PrepareReceiveOnchainRequest({
-    required this.payerAmountSat,
+    this.payerAmountSat,
   });
 
   @override
@@ -679,16 +1502,23 @@ class PrepareReceiveOnchainRequest {
 
 /// Returned when calling [crate::sdk::LiquidSdk::prepare_receive_onchain].
 class PrepareReceiveOnchainResponse {
-  final BigInt payerAmountSat;
+  final BigInt? payerAmountSat;
+
+  /// The total fees. If no amount is set, only the fixed part of them, on top of which the
+  /// swapper takes `swapper_feerate` percent of the amount paid.
   final BigInt feesSat;
 
+  /// The percentage of the amount paid taken by the swapper
+  final double swapperFeerate;
+
   const PrepareReceiveOnchainResponse({
-    required this.payerAmountSat,
+    this.payerAmountSat,
     required this.feesSat,
+    required this.swapperFeerate,
   });
 
   @override
-  int get hashCode => payerAmountSat.hashCode ^ feesSat.hashCode;
+  int get hashCode => payerAmountSat.hashCode ^ feesSat.hashCode ^ swapperFeerate.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -696,7 +1526,8 @@ class PrepareReceiveOnchainResponse {
       other is PrepareReceiveOnchainResponse &&
           runtimeType == other.runtimeType &&
           payerAmountSat == other.payerAmountSat &&
-          feesSat == other.feesSat;
+          feesSat == other.feesSat &&
+          swapperFeerate == other.swapperFeerate;
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::prepare_receive_payment].
@@ -795,35 +1626,161 @@ class PrepareRefundResponse {
           refundTxId == other.refundTxId;
 }
 
+/// An argument when calling [crate::sdk::LiquidSdk::prepare_send_liquid].
+class PrepareSendLiquidRequest {
+  /// A confidential Liquid address or a `liquidnetwork:` / `liquidtestnet:` BIP21 URI
+  final String destination;
+
+  /// The amount to send, in satoshi. Required if the destination has no amount and `drain`
+  /// is not set, otherwise it must either be omitted or match the destination amount.
+  final BigInt? amountSat;
+
+  /// If set, the whole wallet balance is sent and the receiver amount is the balance minus fees
+  final bool? drain;
+
+  /// The id of the Liquid asset to send. Defaults to the asset of the destination, or L-BTC.
+  /// If set, `amount_sat` is in the asset base units and `drain` is not supported.
+  final String? assetId;
+
+  const PrepareSendLiquidRequest({
+    required this.destination,
+    this.amountSat,
+    this.drain,
+    this.assetId,
+  });
+
+  @override
+  int get hashCode => destination.hashCode ^ amountSat.hashCode ^ drain.hashCode ^ assetId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrepareSendLiquidRequest &&
+          runtimeType == other.runtimeType &&
+          destination == other.destination &&
+          amountSat == other.amountSat &&
+          drain == other.drain &&
+          assetId == other.assetId;
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::prepare_send_liquid].
+class PrepareSendLiquidResponse {
+  /// The Liquid address the funds will be sent to
+  final String address;
+
+  /// The amount received, in the asset base units if `asset_id` is set
+  final BigInt receiverAmountSat;
+
+  /// The onchain fees, always paid in L-BTC
+  final BigInt feesSat;
+
+  /// The description of the payment, taken from the BIP21 message or label
+  final String? description;
+
+  /// Whether the whole wallet balance is sent
+  final bool drain;
+
+  /// The id of the Liquid asset sent, if other than L-BTC
+  final String? assetId;
+
+  const PrepareSendLiquidResponse({
+    required this.address,
+    required this.receiverAmountSat,
+    required this.feesSat,
+    this.description,
+    required this.drain,
+    this.assetId,
+  });
+
+  @override
+  int get hashCode =>
+      address.hashCode ^
+      receiverAmountSat.hashCode ^
+      feesSat.hashCode ^
+      description.hashCode ^
+      drain.hashCode ^
+      assetId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrepareSendLiquidResponse &&
+          runtimeType == other.runtimeType &&
+          address == other.address &&
+          receiverAmountSat == other.receiverAmountSat &&
+          feesSat == other.feesSat &&
+          description == other.description &&
+          drain == other.drain &&
+          assetId == other.assetId;
+}
+
 /// An argument when calling [crate::sdk::LiquidSdk::prepare_send_payment].
 class PrepareSendRequest {
+  /// The bolt11 invoice or bolt12 offer to pay
   final String invoice;
 
+  /// The amount to pay, in satoshi. Required if the invoice or offer has no amount, otherwise
+  /// it must either be omitted or match its amount.
+  final BigInt? amountSat;
+
+  /// An optional key identifying the payment. See [PrepareSendResponse::idempotency_key]
+  final String? idempotencyKey;
+
+  /// An optional note to the recipient, only supported when paying a bolt12 offer
+  final String? payerNote;
+
   const PrepareSendRequest({
     required this.invoice,
+    this.amountSat,
+    this.idempotencyKey,
+    this.payerNote,
   });
 
   @override
-  int get hashCode => invoice.hashCode;
+  int get hashCode => invoice.hashCode ^ amountSat.hashCode ^ idempotencyKey.hashCode ^ payerNote.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is PrepareSendRequest && runtimeType == other.runtimeType && invoice == other.invoice;
+      other is PrepareSendRequest &&
+          runtimeType == other.runtimeType &&
+          invoice == other.invoice &&
+          amountSat == other.amountSat &&
+          idempotencyKey == other.idempotencyKey &&
+          payerNote == other.payerNote;
 }
 
 /// Returned when calling [crate::sdk::LiquidSdk::prepare_send_payment].
 class PrepareSendResponse {
+  /// The invoice to pay. When paying a bolt12 offer, the bolt12 invoice fetched for it.
   final String invoice;
+
+  /// The bolt12 offer being paid, if any
+  final String? offer;
+
+  /// The amount the recipient will receive, in satoshi
+  final BigInt receiverAmountSat;
   final BigInt feesSat;
 
+  /// If set, calling [crate::sdk::LiquidSdk::send_payment] again with the same key returns the
+  /// payment started by the first call, instead of paying again
+  final String? idempotencyKey;
+
   const PrepareSendResponse({
     required this.invoice,
+    this.offer,
+    required this.receiverAmountSat,
     required this.feesSat,
+    this.idempotencyKey,
   });
 
   @override
-  int get hashCode => invoice.hashCode ^ feesSat.hashCode;
+  int get hashCode =>
+      invoice.hashCode ^
+      offer.hashCode ^
+      receiverAmountSat.hashCode ^
+      feesSat.hashCode ^
+      idempotencyKey.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -831,7 +1788,64 @@ class PrepareSendResponse {
       other is PrepareSendResponse &&
           runtimeType == other.runtimeType &&
           invoice == other.invoice &&
-          feesSat == other.feesSat;
+          offer == other.offer &&
+          receiverAmountSat == other.receiverAmountSat &&
+          feesSat == other.feesSat &&
+          idempotencyKey == other.idempotencyKey;
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::receive_liquid].
+class ReceiveLiquidRequest {
+  /// The optional amount to request, in satoshi
+  final BigInt? amountSat;
+
+  /// The optional description, shown to the payer and kept on the resulting payment
+  final String? description;
+
+  /// The id of the Liquid asset to request. If set, `amount_sat` is in the asset base units.
+  final String? assetId;
+
+  const ReceiveLiquidRequest({
+    this.amountSat,
+    this.description,
+    this.assetId,
+  });
+
+  @override
+  int get hashCode => amountSat.hashCode ^ description.hashCode ^ assetId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReceiveLiquidRequest &&
+          runtimeType == other.runtimeType &&
+          amountSat == other.amountSat &&
+          description == other.description &&
+          assetId == other.assetId;
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::receive_liquid].
+class ReceiveLiquidResponse {
+  final String address;
+
+  /// A `liquidnetwork:` / `liquidtestnet:` BIP21 URI with the requested amount and description
+  final String bip21;
+
+  const ReceiveLiquidResponse({
+    required this.address,
+    required this.bip21,
+  });
+
+  @override
+  int get hashCode => address.hashCode ^ bip21.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReceiveLiquidResponse &&
+          runtimeType == other.runtimeType &&
+          address == other.address &&
+          bip21 == other.bip21;
 }
 
 /// Returned when calling [crate::sdk::LiquidSdk::receive_onchain].
@@ -936,6 +1950,32 @@ class RecommendedFees {
           minimumFee == other.minimumFee;
 }
 
+@freezed
+sealed class RefundDestination with _$RefundDestination {
+  const RefundDestination._();
+
+  /// A fixed Bitcoin address
+  const factory RefundDestination.address({
+    required String address,
+  }) = RefundDestination_Address;
+
+  /// A Bitcoin account xpub. Each refund is sent to the next P2WPKH address of its external
+  /// chain, i.e. `xpub/0/i`.
+  const factory RefundDestination.xpub({
+    required String xpub,
+  }) = RefundDestination_Xpub;
+}
+
+/// The [RecommendedFees] fee rate an [AutoRefundPolicy] refunds at
+enum RefundFeeRate {
+  fastest,
+  halfHour,
+  hour,
+  economy,
+  minimum,
+  ;
+}
+
 /// An argument when calling [crate::sdk::LiquidSdk::refund].
 class RefundRequest {
   /// The address where the swap funds are locked up
@@ -1047,10 +2087,16 @@ sealed class SdkEvent with _$SdkEvent {
   const factory SdkEvent.paymentWaitingConfirmation({
     required Payment details,
   }) = SdkEvent_PaymentWaitingConfirmation;
+  const factory SdkEvent.swapAutoRefunded({
+    required AutoRefundDetails details,
+  }) = SdkEvent_SwapAutoRefunded;
+  const factory SdkEvent.swapWaitingFeeAcceptance({
+    required String swapId,
+  }) = SdkEvent_SwapWaitingFeeAcceptance;
   const factory SdkEvent.synced() = SdkEvent_Synced;
 }
 
-/// Returned when calling [crate::sdk::LiquidSdk::send_payment].
+/// Returned when calling [crate::sdk::LiquidSdk::send_payment] or [crate::sdk::LiquidSdk::send_liquid].
 class SendPaymentResponse {
   final Payment payment;
 
@@ -1066,3 +2112,86 @@ class SendPaymentResponse {
       identical(this, other) ||
       other is SendPaymentResponse && runtimeType == other.runtimeType && payment == other.payment;
 }
+
+/// An argument when calling [crate::sdk::LiquidSdk::set_payment_metadata].
+class SetPaymentMetadataRequest {
+  /// The tx id or swap id of the payment
+  final String paymentId;
+  final String key;
+  final String value;
+
+  const SetPaymentMetadataRequest({
+    required this.paymentId,
+    required this.key,
+    required this.value,
+  });
+
+  @override
+  int get hashCode => paymentId.hashCode ^ key.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SetPaymentMetadataRequest &&
+          runtimeType == other.runtimeType &&
+          paymentId == other.paymentId &&
+          key == other.key &&
+          value == other.value;
+}
+
+@freezed
+sealed class SignerMethod with _$SignerMethod {
+  const SignerMethod._();
+
+  const factory SignerMethod.xpub() = SignerMethod_Xpub;
+  const factory SignerMethod.deriveXpub({
+    required String derivationPath,
+  }) = SignerMethod_DeriveXpub;
+  const factory SignerMethod.slip77MasterBlindingKey() = SignerMethod_Slip77MasterBlindingKey;
+  const factory SignerMethod.signPset({
+    required String pset,
+  }) = SignerMethod_SignPset;
+  const factory SignerMethod.signEcdsa({
+    required Uint8List msg,
+    required String derivationPath,
+  }) = SignerMethod_SignEcdsa;
+  const factory SignerMethod.hmacSha256({
+    required Uint8List msg,
+    required String derivationPath,
+  }) = SignerMethod_HmacSha256;
+}
+
+/// A call to the external signer of the Dart bindings, which is implemented by the app. It is
+/// answered by passing the same `id` to `respond_to_signer_request`, see `connect_with_signer`.
+class SignerRequest {
+  final int id;
+  final SignerMethod method;
+
+  const SignerRequest({
+    required this.id,
+    required this.method,
+  });
+
+  @override
+  int get hashCode => id.hashCode ^ method.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SignerRequest && runtimeType == other.runtimeType && id == other.id && method == other.method;
+}
+
+@freezed
+sealed class SignerResult with _$SignerResult {
+  const SignerResult._();
+
+  const factory SignerResult.string({
+    required String value,
+  }) = SignerResult_String;
+  const factory SignerResult.bytes({
+    required Uint8List value,
+  }) = SignerResult_Bytes;
+  const factory SignerResult.error({
+    required String err,
+  }) = SignerResult_Error;
+}
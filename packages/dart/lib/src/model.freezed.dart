@@ -284,544 +284,2011 @@ abstract class LnUrlPayResult_PayError extends LnUrlPayResult {
 }
 
 /// @nodoc
-mixin _$SdkEvent {}
+mixin _$PaymentLookup {}
 
 /// @nodoc
-abstract class $SdkEventCopyWith<$Res> {
-  factory $SdkEventCopyWith(SdkEvent value, $Res Function(SdkEvent) then) =
-      _$SdkEventCopyWithImpl<$Res, SdkEvent>;
+abstract class $PaymentLookupCopyWith<$Res> {
+  factory $PaymentLookupCopyWith(PaymentLookup value, $Res Function(PaymentLookup) then) =
+      _$PaymentLookupCopyWithImpl<$Res, PaymentLookup>;
 }
 
 /// @nodoc
-class _$SdkEventCopyWithImpl<$Res, $Val extends SdkEvent> implements $SdkEventCopyWith<$Res> {
-  _$SdkEventCopyWithImpl(this._value, this._then);
+class _$PaymentLookupCopyWithImpl<$Res, $Val extends PaymentLookup> implements $PaymentLookupCopyWith<$Res> {
+  _$PaymentLookupCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
   // ignore: unused_field
   final $Res Function($Val) _then;
 
-  /// Create a copy of SdkEvent
+  /// Create a copy of PaymentLookup
   /// with the given fields replaced by the non-null parameter values.
 }
 
 /// @nodoc
-abstract class _$$SdkEvent_PaymentFailedImplCopyWith<$Res> {
-  factory _$$SdkEvent_PaymentFailedImplCopyWith(
-          _$SdkEvent_PaymentFailedImpl value, $Res Function(_$SdkEvent_PaymentFailedImpl) then) =
-      __$$SdkEvent_PaymentFailedImplCopyWithImpl<$Res>;
+abstract class _$$PaymentLookup_SwapIdImplCopyWith<$Res> {
+  factory _$$PaymentLookup_SwapIdImplCopyWith(
+          _$PaymentLookup_SwapIdImpl value, $Res Function(_$PaymentLookup_SwapIdImpl) then) =
+      __$$PaymentLookup_SwapIdImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({Payment details});
+  $Res call({String swapId});
 }
 
 /// @nodoc
-class __$$SdkEvent_PaymentFailedImplCopyWithImpl<$Res>
-    extends _$SdkEventCopyWithImpl<$Res, _$SdkEvent_PaymentFailedImpl>
-    implements _$$SdkEvent_PaymentFailedImplCopyWith<$Res> {
-  __$$SdkEvent_PaymentFailedImplCopyWithImpl(
-      _$SdkEvent_PaymentFailedImpl _value, $Res Function(_$SdkEvent_PaymentFailedImpl) _then)
+class __$$PaymentLookup_SwapIdImplCopyWithImpl<$Res>
+    extends _$PaymentLookupCopyWithImpl<$Res, _$PaymentLookup_SwapIdImpl>
+    implements _$$PaymentLookup_SwapIdImplCopyWith<$Res> {
+  __$$PaymentLookup_SwapIdImplCopyWithImpl(
+      _$PaymentLookup_SwapIdImpl _value, $Res Function(_$PaymentLookup_SwapIdImpl) _then)
       : super(_value, _then);
 
-  /// Create a copy of SdkEvent
+  /// Create a copy of PaymentLookup
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? details = null,
+    Object? swapId = null,
   }) {
-    return _then(_$SdkEvent_PaymentFailedImpl(
-      details: null == details
-          ? _value.details
-          : details // ignore: cast_nullable_to_non_nullable
-              as Payment,
+    return _then(_$PaymentLookup_SwapIdImpl(
+      swapId: null == swapId
+          ? _value.swapId
+          : swapId // ignore: cast_nullable_to_non_nullable
+              as String,
     ));
   }
 }
 
 /// @nodoc
 
-class _$SdkEvent_PaymentFailedImpl extends SdkEvent_PaymentFailed {
-  const _$SdkEvent_PaymentFailedImpl({required this.details}) : super._();
+class _$PaymentLookup_SwapIdImpl extends PaymentLookup_SwapId {
+  const _$PaymentLookup_SwapIdImpl({required this.swapId}) : super._();
 
   @override
-  final Payment details;
+  final String swapId;
 
   @override
   String toString() {
-    return 'SdkEvent.paymentFailed(details: $details)';
+    return 'PaymentLookup.swapId(swapId: $swapId)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$SdkEvent_PaymentFailedImpl &&
-            (identical(other.details, details) || other.details == details));
+            other is _$PaymentLookup_SwapIdImpl &&
+            (identical(other.swapId, swapId) || other.swapId == swapId));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, details);
+  int get hashCode => Object.hash(runtimeType, swapId);
 
-  /// Create a copy of SdkEvent
+  /// Create a copy of PaymentLookup
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   @pragma('vm:prefer-inline')
-  _$$SdkEvent_PaymentFailedImplCopyWith<_$SdkEvent_PaymentFailedImpl> get copyWith =>
-      __$$SdkEvent_PaymentFailedImplCopyWithImpl<_$SdkEvent_PaymentFailedImpl>(this, _$identity);
+  _$$PaymentLookup_SwapIdImplCopyWith<_$PaymentLookup_SwapIdImpl> get copyWith =>
+      __$$PaymentLookup_SwapIdImplCopyWithImpl<_$PaymentLookup_SwapIdImpl>(this, _$identity);
 }
 
-abstract class SdkEvent_PaymentFailed extends SdkEvent {
-  const factory SdkEvent_PaymentFailed({required final Payment details}) = _$SdkEvent_PaymentFailedImpl;
-  const SdkEvent_PaymentFailed._() : super._();
+abstract class PaymentLookup_SwapId extends PaymentLookup {
+  const factory PaymentLookup_SwapId({required final String swapId}) = _$PaymentLookup_SwapIdImpl;
+  const PaymentLookup_SwapId._() : super._();
 
-  Payment get details;
+  String get swapId;
 
-  /// Create a copy of SdkEvent
+  /// Create a copy of PaymentLookup
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
-  _$$SdkEvent_PaymentFailedImplCopyWith<_$SdkEvent_PaymentFailedImpl> get copyWith =>
+  _$$PaymentLookup_SwapIdImplCopyWith<_$PaymentLookup_SwapIdImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$SdkEvent_PaymentPendingImplCopyWith<$Res> {
-  factory _$$SdkEvent_PaymentPendingImplCopyWith(
-          _$SdkEvent_PaymentPendingImpl value, $Res Function(_$SdkEvent_PaymentPendingImpl) then) =
-      __$$SdkEvent_PaymentPendingImplCopyWithImpl<$Res>;
+abstract class _$$PaymentLookup_InvoiceImplCopyWith<$Res> {
+  factory _$$PaymentLookup_InvoiceImplCopyWith(
+          _$PaymentLookup_InvoiceImpl value, $Res Function(_$PaymentLookup_InvoiceImpl) then) =
+      __$$PaymentLookup_InvoiceImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({Payment details});
+  $Res call({String invoice});
 }
 
 /// @nodoc
-class __$$SdkEvent_PaymentPendingImplCopyWithImpl<$Res>
-    extends _$SdkEventCopyWithImpl<$Res, _$SdkEvent_PaymentPendingImpl>
-    implements _$$SdkEvent_PaymentPendingImplCopyWith<$Res> {
-  __$$SdkEvent_PaymentPendingImplCopyWithImpl(
-      _$SdkEvent_PaymentPendingImpl _value, $Res Function(_$SdkEvent_PaymentPendingImpl) _then)
+class __$$PaymentLookup_InvoiceImplCopyWithImpl<$Res>
+    extends _$PaymentLookupCopyWithImpl<$Res, _$PaymentLookup_InvoiceImpl>
+    implements _$$PaymentLookup_InvoiceImplCopyWith<$Res> {
+  __$$PaymentLookup_InvoiceImplCopyWithImpl(
+      _$PaymentLookup_InvoiceImpl _value, $Res Function(_$PaymentLookup_InvoiceImpl) _then)
       : super(_value, _then);
 
-  /// Create a copy of SdkEvent
+  /// Create a copy of PaymentLookup
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? details = null,
+    Object? invoice = null,
   }) {
-    return _then(_$SdkEvent_PaymentPendingImpl(
-      details: null == details
-          ? _value.details
-          : details // ignore: cast_nullable_to_non_nullable
-              as Payment,
+    return _then(_$PaymentLookup_InvoiceImpl(
+      invoice: null == invoice
+          ? _value.invoice
+          : invoice // ignore: cast_nullable_to_non_nullable
+              as String,
     ));
   }
 }
 
 /// @nodoc
 
-class _$SdkEvent_PaymentPendingImpl extends SdkEvent_PaymentPending {
-  const _$SdkEvent_PaymentPendingImpl({required this.details}) : super._();
+class _$PaymentLookup_InvoiceImpl extends PaymentLookup_Invoice {
+  const _$PaymentLookup_InvoiceImpl({required this.invoice}) : super._();
 
   @override
-  final Payment details;
+  final String invoice;
 
   @override
   String toString() {
-    return 'SdkEvent.paymentPending(details: $details)';
+    return 'PaymentLookup.invoice(invoice: $invoice)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$SdkEvent_PaymentPendingImpl &&
-            (identical(other.details, details) || other.details == details));
+            other is _$PaymentLookup_InvoiceImpl &&
+            (identical(other.invoice, invoice) || other.invoice == invoice));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, details);
+  int get hashCode => Object.hash(runtimeType, invoice);
 
-  /// Create a copy of SdkEvent
+  /// Create a copy of PaymentLookup
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   @pragma('vm:prefer-inline')
-  _$$SdkEvent_PaymentPendingImplCopyWith<_$SdkEvent_PaymentPendingImpl> get copyWith =>
-      __$$SdkEvent_PaymentPendingImplCopyWithImpl<_$SdkEvent_PaymentPendingImpl>(this, _$identity);
+  _$$PaymentLookup_InvoiceImplCopyWith<_$PaymentLookup_InvoiceImpl> get copyWith =>
+      __$$PaymentLookup_InvoiceImplCopyWithImpl<_$PaymentLookup_InvoiceImpl>(this, _$identity);
 }
 
-abstract class SdkEvent_PaymentPending extends SdkEvent {
-  const factory SdkEvent_PaymentPending({required final Payment details}) = _$SdkEvent_PaymentPendingImpl;
-  const SdkEvent_PaymentPending._() : super._();
+abstract class PaymentLookup_Invoice extends PaymentLookup {
+  const factory PaymentLookup_Invoice({required final String invoice}) = _$PaymentLookup_InvoiceImpl;
+  const PaymentLookup_Invoice._() : super._();
 
-  Payment get details;
+  String get invoice;
 
-  /// Create a copy of SdkEvent
+  /// Create a copy of PaymentLookup
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
-  _$$SdkEvent_PaymentPendingImplCopyWith<_$SdkEvent_PaymentPendingImpl> get copyWith =>
+  _$$PaymentLookup_InvoiceImplCopyWith<_$PaymentLookup_InvoiceImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$SdkEvent_PaymentRefundedImplCopyWith<$Res> {
-  factory _$$SdkEvent_PaymentRefundedImplCopyWith(
-          _$SdkEvent_PaymentRefundedImpl value, $Res Function(_$SdkEvent_PaymentRefundedImpl) then) =
-      __$$SdkEvent_PaymentRefundedImplCopyWithImpl<$Res>;
+abstract class _$$PaymentLookup_PaymentHashImplCopyWith<$Res> {
+  factory _$$PaymentLookup_PaymentHashImplCopyWith(
+          _$PaymentLookup_PaymentHashImpl value, $Res Function(_$PaymentLookup_PaymentHashImpl) then) =
+      __$$PaymentLookup_PaymentHashImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({Payment details});
+  $Res call({String paymentHash});
 }
 
 /// @nodoc
-class __$$SdkEvent_PaymentRefundedImplCopyWithImpl<$Res>
-    extends _$SdkEventCopyWithImpl<$Res, _$SdkEvent_PaymentRefundedImpl>
-    implements _$$SdkEvent_PaymentRefundedImplCopyWith<$Res> {
-  __$$SdkEvent_PaymentRefundedImplCopyWithImpl(
-      _$SdkEvent_PaymentRefundedImpl _value, $Res Function(_$SdkEvent_PaymentRefundedImpl) _then)
+class __$$PaymentLookup_PaymentHashImplCopyWithImpl<$Res>
+    extends _$PaymentLookupCopyWithImpl<$Res, _$PaymentLookup_PaymentHashImpl>
+    implements _$$PaymentLookup_PaymentHashImplCopyWith<$Res> {
+  __$$PaymentLookup_PaymentHashImplCopyWithImpl(
+      _$PaymentLookup_PaymentHashImpl _value, $Res Function(_$PaymentLookup_PaymentHashImpl) _then)
       : super(_value, _then);
 
-  /// Create a copy of SdkEvent
+  /// Create a copy of PaymentLookup
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? details = null,
+    Object? paymentHash = null,
   }) {
-    return _then(_$SdkEvent_PaymentRefundedImpl(
-      details: null == details
-          ? _value.details
-          : details // ignore: cast_nullable_to_non_nullable
-              as Payment,
+    return _then(_$PaymentLookup_PaymentHashImpl(
+      paymentHash: null == paymentHash
+          ? _value.paymentHash
+          : paymentHash // ignore: cast_nullable_to_non_nullable
+              as String,
     ));
   }
 }
 
 /// @nodoc
 
-class _$SdkEvent_PaymentRefundedImpl extends SdkEvent_PaymentRefunded {
-  const _$SdkEvent_PaymentRefundedImpl({required this.details}) : super._();
+class _$PaymentLookup_PaymentHashImpl extends PaymentLookup_PaymentHash {
+  const _$PaymentLookup_PaymentHashImpl({required this.paymentHash}) : super._();
 
   @override
-  final Payment details;
+  final String paymentHash;
 
   @override
   String toString() {
-    return 'SdkEvent.paymentRefunded(details: $details)';
+    return 'PaymentLookup.paymentHash(paymentHash: $paymentHash)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$SdkEvent_PaymentRefundedImpl &&
-            (identical(other.details, details) || other.details == details));
+            other is _$PaymentLookup_PaymentHashImpl &&
+            (identical(other.paymentHash, paymentHash) || other.paymentHash == paymentHash));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, details);
+  int get hashCode => Object.hash(runtimeType, paymentHash);
 
-  /// Create a copy of SdkEvent
+  /// Create a copy of PaymentLookup
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   @pragma('vm:prefer-inline')
-  _$$SdkEvent_PaymentRefundedImplCopyWith<_$SdkEvent_PaymentRefundedImpl> get copyWith =>
-      __$$SdkEvent_PaymentRefundedImplCopyWithImpl<_$SdkEvent_PaymentRefundedImpl>(this, _$identity);
+  _$$PaymentLookup_PaymentHashImplCopyWith<_$PaymentLookup_PaymentHashImpl> get copyWith =>
+      __$$PaymentLookup_PaymentHashImplCopyWithImpl<_$PaymentLookup_PaymentHashImpl>(this, _$identity);
 }
 
-abstract class SdkEvent_PaymentRefunded extends SdkEvent {
-  const factory SdkEvent_PaymentRefunded({required final Payment details}) = _$SdkEvent_PaymentRefundedImpl;
-  const SdkEvent_PaymentRefunded._() : super._();
+abstract class PaymentLookup_PaymentHash extends PaymentLookup {
+  const factory PaymentLookup_PaymentHash({required final String paymentHash}) =
+      _$PaymentLookup_PaymentHashImpl;
+  const PaymentLookup_PaymentHash._() : super._();
 
-  Payment get details;
+  String get paymentHash;
 
-  /// Create a copy of SdkEvent
+  /// Create a copy of PaymentLookup
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
-  _$$SdkEvent_PaymentRefundedImplCopyWith<_$SdkEvent_PaymentRefundedImpl> get copyWith =>
+  _$$PaymentLookup_PaymentHashImplCopyWith<_$PaymentLookup_PaymentHashImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$SdkEvent_PaymentRefundPendingImplCopyWith<$Res> {
-  factory _$$SdkEvent_PaymentRefundPendingImplCopyWith(_$SdkEvent_PaymentRefundPendingImpl value,
-          $Res Function(_$SdkEvent_PaymentRefundPendingImpl) then) =
-      __$$SdkEvent_PaymentRefundPendingImplCopyWithImpl<$Res>;
+abstract class _$$PaymentLookup_TxIdImplCopyWith<$Res> {
+  factory _$$PaymentLookup_TxIdImplCopyWith(
+          _$PaymentLookup_TxIdImpl value, $Res Function(_$PaymentLookup_TxIdImpl) then) =
+      __$$PaymentLookup_TxIdImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({Payment details});
+  $Res call({String txId});
 }
 
 /// @nodoc
-class __$$SdkEvent_PaymentRefundPendingImplCopyWithImpl<$Res>
-    extends _$SdkEventCopyWithImpl<$Res, _$SdkEvent_PaymentRefundPendingImpl>
-    implements _$$SdkEvent_PaymentRefundPendingImplCopyWith<$Res> {
-  __$$SdkEvent_PaymentRefundPendingImplCopyWithImpl(
-      _$SdkEvent_PaymentRefundPendingImpl _value, $Res Function(_$SdkEvent_PaymentRefundPendingImpl) _then)
+class __$$PaymentLookup_TxIdImplCopyWithImpl<$Res>
+    extends _$PaymentLookupCopyWithImpl<$Res, _$PaymentLookup_TxIdImpl>
+    implements _$$PaymentLookup_TxIdImplCopyWith<$Res> {
+  __$$PaymentLookup_TxIdImplCopyWithImpl(
+      _$PaymentLookup_TxIdImpl _value, $Res Function(_$PaymentLookup_TxIdImpl) _then)
       : super(_value, _then);
 
-  /// Create a copy of SdkEvent
+  /// Create a copy of PaymentLookup
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? details = null,
+    Object? txId = null,
   }) {
-    return _then(_$SdkEvent_PaymentRefundPendingImpl(
-      details: null == details
-          ? _value.details
-          : details // ignore: cast_nullable_to_non_nullable
-              as Payment,
+    return _then(_$PaymentLookup_TxIdImpl(
+      txId: null == txId
+          ? _value.txId
+          : txId // ignore: cast_nullable_to_non_nullable
+              as String,
     ));
   }
 }
 
 /// @nodoc
 
-class _$SdkEvent_PaymentRefundPendingImpl extends SdkEvent_PaymentRefundPending {
-  const _$SdkEvent_PaymentRefundPendingImpl({required this.details}) : super._();
+class _$PaymentLookup_TxIdImpl extends PaymentLookup_TxId {
+  const _$PaymentLookup_TxIdImpl({required this.txId}) : super._();
 
   @override
-  final Payment details;
+  final String txId;
 
   @override
   String toString() {
-    return 'SdkEvent.paymentRefundPending(details: $details)';
+    return 'PaymentLookup.txId(txId: $txId)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$SdkEvent_PaymentRefundPendingImpl &&
-            (identical(other.details, details) || other.details == details));
+            other is _$PaymentLookup_TxIdImpl &&
+            (identical(other.txId, txId) || other.txId == txId));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, details);
+  int get hashCode => Object.hash(runtimeType, txId);
 
-  /// Create a copy of SdkEvent
+  /// Create a copy of PaymentLookup
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   @pragma('vm:prefer-inline')
-  _$$SdkEvent_PaymentRefundPendingImplCopyWith<_$SdkEvent_PaymentRefundPendingImpl> get copyWith =>
-      __$$SdkEvent_PaymentRefundPendingImplCopyWithImpl<_$SdkEvent_PaymentRefundPendingImpl>(
-          this, _$identity);
+  _$$PaymentLookup_TxIdImplCopyWith<_$PaymentLookup_TxIdImpl> get copyWith =>
+      __$$PaymentLookup_TxIdImplCopyWithImpl<_$PaymentLookup_TxIdImpl>(this, _$identity);
 }
 
-abstract class SdkEvent_PaymentRefundPending extends SdkEvent {
-  const factory SdkEvent_PaymentRefundPending({required final Payment details}) =
-      _$SdkEvent_PaymentRefundPendingImpl;
-  const SdkEvent_PaymentRefundPending._() : super._();
+abstract class PaymentLookup_TxId extends PaymentLookup {
+  const factory PaymentLookup_TxId({required final String txId}) = _$PaymentLookup_TxIdImpl;
+  const PaymentLookup_TxId._() : super._();
 
-  Payment get details;
+  String get txId;
 
-  /// Create a copy of SdkEvent
+  /// Create a copy of PaymentLookup
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
-  _$$SdkEvent_PaymentRefundPendingImplCopyWith<_$SdkEvent_PaymentRefundPendingImpl> get copyWith =>
+  _$$PaymentLookup_TxIdImplCopyWith<_$PaymentLookup_TxIdImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$SdkEvent_PaymentSucceededImplCopyWith<$Res> {
-  factory _$$SdkEvent_PaymentSucceededImplCopyWith(
-          _$SdkEvent_PaymentSucceededImpl value, $Res Function(_$SdkEvent_PaymentSucceededImpl) then) =
-      __$$SdkEvent_PaymentSucceededImplCopyWithImpl<$Res>;
+abstract class _$$PaymentLookup_DestinationImplCopyWith<$Res> {
+  factory _$$PaymentLookup_DestinationImplCopyWith(
+          _$PaymentLookup_DestinationImpl value, $Res Function(_$PaymentLookup_DestinationImpl) then) =
+      __$$PaymentLookup_DestinationImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({Payment details});
+  $Res call({String address});
 }
 
 /// @nodoc
-class __$$SdkEvent_PaymentSucceededImplCopyWithImpl<$Res>
-    extends _$SdkEventCopyWithImpl<$Res, _$SdkEvent_PaymentSucceededImpl>
-    implements _$$SdkEvent_PaymentSucceededImplCopyWith<$Res> {
-  __$$SdkEvent_PaymentSucceededImplCopyWithImpl(
-      _$SdkEvent_PaymentSucceededImpl _value, $Res Function(_$SdkEvent_PaymentSucceededImpl) _then)
+class __$$PaymentLookup_DestinationImplCopyWithImpl<$Res>
+    extends _$PaymentLookupCopyWithImpl<$Res, _$PaymentLookup_DestinationImpl>
+    implements _$$PaymentLookup_DestinationImplCopyWith<$Res> {
+  __$$PaymentLookup_DestinationImplCopyWithImpl(
+      _$PaymentLookup_DestinationImpl _value, $Res Function(_$PaymentLookup_DestinationImpl) _then)
       : super(_value, _then);
 
-  /// Create a copy of SdkEvent
+  /// Create a copy of PaymentLookup
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? details = null,
+    Object? address = null,
   }) {
-    return _then(_$SdkEvent_PaymentSucceededImpl(
-      details: null == details
-          ? _value.details
-          : details // ignore: cast_nullable_to_non_nullable
-              as Payment,
+    return _then(_$PaymentLookup_DestinationImpl(
+      address: null == address
+          ? _value.address
+          : address // ignore: cast_nullable_to_non_nullable
+              as String,
     ));
   }
 }
 
 /// @nodoc
 
-class _$SdkEvent_PaymentSucceededImpl extends SdkEvent_PaymentSucceeded {
-  const _$SdkEvent_PaymentSucceededImpl({required this.details}) : super._();
+class _$PaymentLookup_DestinationImpl extends PaymentLookup_Destination {
+  const _$PaymentLookup_DestinationImpl({required this.address}) : super._();
 
   @override
-  final Payment details;
+  final String address;
 
   @override
   String toString() {
-    return 'SdkEvent.paymentSucceeded(details: $details)';
+    return 'PaymentLookup.destination(address: $address)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$SdkEvent_PaymentSucceededImpl &&
-            (identical(other.details, details) || other.details == details));
+            other is _$PaymentLookup_DestinationImpl &&
+            (identical(other.address, address) || other.address == address));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, details);
+  int get hashCode => Object.hash(runtimeType, address);
 
-  /// Create a copy of SdkEvent
+  /// Create a copy of PaymentLookup
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   @pragma('vm:prefer-inline')
-  _$$SdkEvent_PaymentSucceededImplCopyWith<_$SdkEvent_PaymentSucceededImpl> get copyWith =>
-      __$$SdkEvent_PaymentSucceededImplCopyWithImpl<_$SdkEvent_PaymentSucceededImpl>(this, _$identity);
+  _$$PaymentLookup_DestinationImplCopyWith<_$PaymentLookup_DestinationImpl> get copyWith =>
+      __$$PaymentLookup_DestinationImplCopyWithImpl<_$PaymentLookup_DestinationImpl>(this, _$identity);
 }
 
-abstract class SdkEvent_PaymentSucceeded extends SdkEvent {
-  const factory SdkEvent_PaymentSucceeded({required final Payment details}) = _$SdkEvent_PaymentSucceededImpl;
-  const SdkEvent_PaymentSucceeded._() : super._();
+abstract class PaymentLookup_Destination extends PaymentLookup {
+  const factory PaymentLookup_Destination({required final String address}) = _$PaymentLookup_DestinationImpl;
+  const PaymentLookup_Destination._() : super._();
 
-  Payment get details;
+  String get address;
 
-  /// Create a copy of SdkEvent
+  /// Create a copy of PaymentLookup
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
-  _$$SdkEvent_PaymentSucceededImplCopyWith<_$SdkEvent_PaymentSucceededImpl> get copyWith =>
+  _$$PaymentLookup_DestinationImplCopyWith<_$PaymentLookup_DestinationImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$SdkEvent_PaymentWaitingConfirmationImplCopyWith<$Res> {
-  factory _$$SdkEvent_PaymentWaitingConfirmationImplCopyWith(_$SdkEvent_PaymentWaitingConfirmationImpl value,
-          $Res Function(_$SdkEvent_PaymentWaitingConfirmationImpl) then) =
-      __$$SdkEvent_PaymentWaitingConfirmationImplCopyWithImpl<$Res>;
+mixin _$RefundDestination {}
+
+/// @nodoc
+abstract class $RefundDestinationCopyWith<$Res> {
+  factory $RefundDestinationCopyWith(RefundDestination value, $Res Function(RefundDestination) then) =
+      _$RefundDestinationCopyWithImpl<$Res, RefundDestination>;
+}
+
+/// @nodoc
+class _$RefundDestinationCopyWithImpl<$Res, $Val extends RefundDestination>
+    implements $RefundDestinationCopyWith<$Res> {
+  _$RefundDestinationCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of RefundDestination
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$RefundDestination_AddressImplCopyWith<$Res> {
+  factory _$$RefundDestination_AddressImplCopyWith(
+          _$RefundDestination_AddressImpl value, $Res Function(_$RefundDestination_AddressImpl) then) =
+      __$$RefundDestination_AddressImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({Payment details});
+  $Res call({String address});
 }
 
 /// @nodoc
-class __$$SdkEvent_PaymentWaitingConfirmationImplCopyWithImpl<$Res>
-    extends _$SdkEventCopyWithImpl<$Res, _$SdkEvent_PaymentWaitingConfirmationImpl>
-    implements _$$SdkEvent_PaymentWaitingConfirmationImplCopyWith<$Res> {
-  __$$SdkEvent_PaymentWaitingConfirmationImplCopyWithImpl(_$SdkEvent_PaymentWaitingConfirmationImpl _value,
-      $Res Function(_$SdkEvent_PaymentWaitingConfirmationImpl) _then)
+class __$$RefundDestination_AddressImplCopyWithImpl<$Res>
+    extends _$RefundDestinationCopyWithImpl<$Res, _$RefundDestination_AddressImpl>
+    implements _$$RefundDestination_AddressImplCopyWith<$Res> {
+  __$$RefundDestination_AddressImplCopyWithImpl(
+      _$RefundDestination_AddressImpl _value, $Res Function(_$RefundDestination_AddressImpl) _then)
       : super(_value, _then);
 
-  /// Create a copy of SdkEvent
+  /// Create a copy of RefundDestination
   /// with the given fields replaced by the non-null parameter values.
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? details = null,
+    Object? address = null,
   }) {
-    return _then(_$SdkEvent_PaymentWaitingConfirmationImpl(
-      details: null == details
-          ? _value.details
-          : details // ignore: cast_nullable_to_non_nullable
-              as Payment,
+    return _then(_$RefundDestination_AddressImpl(
+      address: null == address
+          ? _value.address
+          : address // ignore: cast_nullable_to_non_nullable
+              as String,
     ));
   }
 }
 
 /// @nodoc
 
-class _$SdkEvent_PaymentWaitingConfirmationImpl extends SdkEvent_PaymentWaitingConfirmation {
-  const _$SdkEvent_PaymentWaitingConfirmationImpl({required this.details}) : super._();
+class _$RefundDestination_AddressImpl extends RefundDestination_Address {
+  const _$RefundDestination_AddressImpl({required this.address}) : super._();
 
   @override
-  final Payment details;
+  final String address;
 
   @override
   String toString() {
-    return 'SdkEvent.paymentWaitingConfirmation(details: $details)';
+    return 'RefundDestination.address(address: $address)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$SdkEvent_PaymentWaitingConfirmationImpl &&
-            (identical(other.details, details) || other.details == details));
+            other is _$RefundDestination_AddressImpl &&
+            (identical(other.address, address) || other.address == address));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, details);
+  int get hashCode => Object.hash(runtimeType, address);
 
-  /// Create a copy of SdkEvent
+  /// Create a copy of RefundDestination
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   @pragma('vm:prefer-inline')
-  _$$SdkEvent_PaymentWaitingConfirmationImplCopyWith<_$SdkEvent_PaymentWaitingConfirmationImpl>
-      get copyWith =>
-          __$$SdkEvent_PaymentWaitingConfirmationImplCopyWithImpl<_$SdkEvent_PaymentWaitingConfirmationImpl>(
-              this, _$identity);
+  _$$RefundDestination_AddressImplCopyWith<_$RefundDestination_AddressImpl> get copyWith =>
+      __$$RefundDestination_AddressImplCopyWithImpl<_$RefundDestination_AddressImpl>(this, _$identity);
 }
 
-abstract class SdkEvent_PaymentWaitingConfirmation extends SdkEvent {
-  const factory SdkEvent_PaymentWaitingConfirmation({required final Payment details}) =
-      _$SdkEvent_PaymentWaitingConfirmationImpl;
-  const SdkEvent_PaymentWaitingConfirmation._() : super._();
+abstract class RefundDestination_Address extends RefundDestination {
+  const factory RefundDestination_Address({required final String address}) = _$RefundDestination_AddressImpl;
+  const RefundDestination_Address._() : super._();
 
-  Payment get details;
+  String get address;
 
-  /// Create a copy of SdkEvent
+  /// Create a copy of RefundDestination
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
-  _$$SdkEvent_PaymentWaitingConfirmationImplCopyWith<_$SdkEvent_PaymentWaitingConfirmationImpl>
-      get copyWith => throw _privateConstructorUsedError;
+  _$$RefundDestination_AddressImplCopyWith<_$RefundDestination_AddressImpl> get copyWith =>
+      throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$SdkEvent_SyncedImplCopyWith<$Res> {
-  factory _$$SdkEvent_SyncedImplCopyWith(
-          _$SdkEvent_SyncedImpl value, $Res Function(_$SdkEvent_SyncedImpl) then) =
-      __$$SdkEvent_SyncedImplCopyWithImpl<$Res>;
+abstract class _$$RefundDestination_XpubImplCopyWith<$Res> {
+  factory _$$RefundDestination_XpubImplCopyWith(
+          _$RefundDestination_XpubImpl value, $Res Function(_$RefundDestination_XpubImpl) then) =
+      __$$RefundDestination_XpubImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String xpub});
 }
 
 /// @nodoc
-class __$$SdkEvent_SyncedImplCopyWithImpl<$Res> extends _$SdkEventCopyWithImpl<$Res, _$SdkEvent_SyncedImpl>
-    implements _$$SdkEvent_SyncedImplCopyWith<$Res> {
-  __$$SdkEvent_SyncedImplCopyWithImpl(
-      _$SdkEvent_SyncedImpl _value, $Res Function(_$SdkEvent_SyncedImpl) _then)
+class __$$RefundDestination_XpubImplCopyWithImpl<$Res>
+    extends _$RefundDestinationCopyWithImpl<$Res, _$RefundDestination_XpubImpl>
+    implements _$$RefundDestination_XpubImplCopyWith<$Res> {
+  __$$RefundDestination_XpubImplCopyWithImpl(
+      _$RefundDestination_XpubImpl _value, $Res Function(_$RefundDestination_XpubImpl) _then)
       : super(_value, _then);
 
-  /// Create a copy of SdkEvent
+  /// Create a copy of RefundDestination
   /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? xpub = null,
+  }) {
+    return _then(_$RefundDestination_XpubImpl(
+      xpub: null == xpub
+          ? _value.xpub
+          : xpub // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
 }
 
 /// @nodoc
 
-class _$SdkEvent_SyncedImpl extends SdkEvent_Synced {
-  const _$SdkEvent_SyncedImpl() : super._();
+class _$RefundDestination_XpubImpl extends RefundDestination_Xpub {
+  const _$RefundDestination_XpubImpl({required this.xpub}) : super._();
+
+  @override
+  final String xpub;
 
   @override
   String toString() {
-    return 'SdkEvent.synced()';
+    return 'RefundDestination.xpub(xpub: $xpub)';
   }
 
   @override
   bool operator ==(Object other) {
-    return identical(this, other) || (other.runtimeType == runtimeType && other is _$SdkEvent_SyncedImpl);
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$RefundDestination_XpubImpl &&
+            (identical(other.xpub, xpub) || other.xpub == xpub));
   }
 
   @override
-  int get hashCode => runtimeType.hashCode;
-}
+  int get hashCode => Object.hash(runtimeType, xpub);
 
-abstract class SdkEvent_Synced extends SdkEvent {
-  const factory SdkEvent_Synced() = _$SdkEvent_SyncedImpl;
-  const SdkEvent_Synced._() : super._();
+  /// Create a copy of RefundDestination
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$RefundDestination_XpubImplCopyWith<_$RefundDestination_XpubImpl> get copyWith =>
+      __$$RefundDestination_XpubImplCopyWithImpl<_$RefundDestination_XpubImpl>(this, _$identity);
+}
+
+abstract class RefundDestination_Xpub extends RefundDestination {
+  const factory RefundDestination_Xpub({required final String xpub}) = _$RefundDestination_XpubImpl;
+  const RefundDestination_Xpub._() : super._();
+
+  String get xpub;
+
+  /// Create a copy of RefundDestination
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$RefundDestination_XpubImplCopyWith<_$RefundDestination_XpubImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$SdkEvent {}
+
+/// @nodoc
+abstract class $SdkEventCopyWith<$Res> {
+  factory $SdkEventCopyWith(SdkEvent value, $Res Function(SdkEvent) then) =
+      _$SdkEventCopyWithImpl<$Res, SdkEvent>;
+}
+
+/// @nodoc
+class _$SdkEventCopyWithImpl<$Res, $Val extends SdkEvent> implements $SdkEventCopyWith<$Res> {
+  _$SdkEventCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$SdkEvent_PaymentFailedImplCopyWith<$Res> {
+  factory _$$SdkEvent_PaymentFailedImplCopyWith(
+          _$SdkEvent_PaymentFailedImpl value, $Res Function(_$SdkEvent_PaymentFailedImpl) then) =
+      __$$SdkEvent_PaymentFailedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Payment details});
+}
+
+/// @nodoc
+class __$$SdkEvent_PaymentFailedImplCopyWithImpl<$Res>
+    extends _$SdkEventCopyWithImpl<$Res, _$SdkEvent_PaymentFailedImpl>
+    implements _$$SdkEvent_PaymentFailedImplCopyWith<$Res> {
+  __$$SdkEvent_PaymentFailedImplCopyWithImpl(
+      _$SdkEvent_PaymentFailedImpl _value, $Res Function(_$SdkEvent_PaymentFailedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$SdkEvent_PaymentFailedImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as Payment,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SdkEvent_PaymentFailedImpl extends SdkEvent_PaymentFailed {
+  const _$SdkEvent_PaymentFailedImpl({required this.details}) : super._();
+
+  @override
+  final Payment details;
+
+  @override
+  String toString() {
+    return 'SdkEvent.paymentFailed(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SdkEvent_PaymentFailedImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SdkEvent_PaymentFailedImplCopyWith<_$SdkEvent_PaymentFailedImpl> get copyWith =>
+      __$$SdkEvent_PaymentFailedImplCopyWithImpl<_$SdkEvent_PaymentFailedImpl>(this, _$identity);
+}
+
+abstract class SdkEvent_PaymentFailed extends SdkEvent {
+  const factory SdkEvent_PaymentFailed({required final Payment details}) = _$SdkEvent_PaymentFailedImpl;
+  const SdkEvent_PaymentFailed._() : super._();
+
+  Payment get details;
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SdkEvent_PaymentFailedImplCopyWith<_$SdkEvent_PaymentFailedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SdkEvent_PaymentPendingImplCopyWith<$Res> {
+  factory _$$SdkEvent_PaymentPendingImplCopyWith(
+          _$SdkEvent_PaymentPendingImpl value, $Res Function(_$SdkEvent_PaymentPendingImpl) then) =
+      __$$SdkEvent_PaymentPendingImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Payment details});
+}
+
+/// @nodoc
+class __$$SdkEvent_PaymentPendingImplCopyWithImpl<$Res>
+    extends _$SdkEventCopyWithImpl<$Res, _$SdkEvent_PaymentPendingImpl>
+    implements _$$SdkEvent_PaymentPendingImplCopyWith<$Res> {
+  __$$SdkEvent_PaymentPendingImplCopyWithImpl(
+      _$SdkEvent_PaymentPendingImpl _value, $Res Function(_$SdkEvent_PaymentPendingImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$SdkEvent_PaymentPendingImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as Payment,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SdkEvent_PaymentPendingImpl extends SdkEvent_PaymentPending {
+  const _$SdkEvent_PaymentPendingImpl({required this.details}) : super._();
+
+  @override
+  final Payment details;
+
+  @override
+  String toString() {
+    return 'SdkEvent.paymentPending(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SdkEvent_PaymentPendingImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SdkEvent_PaymentPendingImplCopyWith<_$SdkEvent_PaymentPendingImpl> get copyWith =>
+      __$$SdkEvent_PaymentPendingImplCopyWithImpl<_$SdkEvent_PaymentPendingImpl>(this, _$identity);
+}
+
+abstract class SdkEvent_PaymentPending extends SdkEvent {
+  const factory SdkEvent_PaymentPending({required final Payment details}) = _$SdkEvent_PaymentPendingImpl;
+  const SdkEvent_PaymentPending._() : super._();
+
+  Payment get details;
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SdkEvent_PaymentPendingImplCopyWith<_$SdkEvent_PaymentPendingImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SdkEvent_PaymentRefundedImplCopyWith<$Res> {
+  factory _$$SdkEvent_PaymentRefundedImplCopyWith(
+          _$SdkEvent_PaymentRefundedImpl value, $Res Function(_$SdkEvent_PaymentRefundedImpl) then) =
+      __$$SdkEvent_PaymentRefundedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Payment details});
+}
+
+/// @nodoc
+class __$$SdkEvent_PaymentRefundedImplCopyWithImpl<$Res>
+    extends _$SdkEventCopyWithImpl<$Res, _$SdkEvent_PaymentRefundedImpl>
+    implements _$$SdkEvent_PaymentRefundedImplCopyWith<$Res> {
+  __$$SdkEvent_PaymentRefundedImplCopyWithImpl(
+      _$SdkEvent_PaymentRefundedImpl _value, $Res Function(_$SdkEvent_PaymentRefundedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$SdkEvent_PaymentRefundedImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as Payment,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SdkEvent_PaymentRefundedImpl extends SdkEvent_PaymentRefunded {
+  const _$SdkEvent_PaymentRefundedImpl({required this.details}) : super._();
+
+  @override
+  final Payment details;
+
+  @override
+  String toString() {
+    return 'SdkEvent.paymentRefunded(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SdkEvent_PaymentRefundedImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SdkEvent_PaymentRefundedImplCopyWith<_$SdkEvent_PaymentRefundedImpl> get copyWith =>
+      __$$SdkEvent_PaymentRefundedImplCopyWithImpl<_$SdkEvent_PaymentRefundedImpl>(this, _$identity);
+}
+
+abstract class SdkEvent_PaymentRefunded extends SdkEvent {
+  const factory SdkEvent_PaymentRefunded({required final Payment details}) = _$SdkEvent_PaymentRefundedImpl;
+  const SdkEvent_PaymentRefunded._() : super._();
+
+  Payment get details;
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SdkEvent_PaymentRefundedImplCopyWith<_$SdkEvent_PaymentRefundedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SdkEvent_PaymentRefundPendingImplCopyWith<$Res> {
+  factory _$$SdkEvent_PaymentRefundPendingImplCopyWith(_$SdkEvent_PaymentRefundPendingImpl value,
+          $Res Function(_$SdkEvent_PaymentRefundPendingImpl) then) =
+      __$$SdkEvent_PaymentRefundPendingImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Payment details});
+}
+
+/// @nodoc
+class __$$SdkEvent_PaymentRefundPendingImplCopyWithImpl<$Res>
+    extends _$SdkEventCopyWithImpl<$Res, _$SdkEvent_PaymentRefundPendingImpl>
+    implements _$$SdkEvent_PaymentRefundPendingImplCopyWith<$Res> {
+  __$$SdkEvent_PaymentRefundPendingImplCopyWithImpl(
+      _$SdkEvent_PaymentRefundPendingImpl _value, $Res Function(_$SdkEvent_PaymentRefundPendingImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$SdkEvent_PaymentRefundPendingImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as Payment,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SdkEvent_PaymentRefundPendingImpl extends SdkEvent_PaymentRefundPending {
+  const _$SdkEvent_PaymentRefundPendingImpl({required this.details}) : super._();
+
+  @override
+  final Payment details;
+
+  @override
+  String toString() {
+    return 'SdkEvent.paymentRefundPending(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SdkEvent_PaymentRefundPendingImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SdkEvent_PaymentRefundPendingImplCopyWith<_$SdkEvent_PaymentRefundPendingImpl> get copyWith =>
+      __$$SdkEvent_PaymentRefundPendingImplCopyWithImpl<_$SdkEvent_PaymentRefundPendingImpl>(
+          this, _$identity);
+}
+
+abstract class SdkEvent_PaymentRefundPending extends SdkEvent {
+  const factory SdkEvent_PaymentRefundPending({required final Payment details}) =
+      _$SdkEvent_PaymentRefundPendingImpl;
+  const SdkEvent_PaymentRefundPending._() : super._();
+
+  Payment get details;
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SdkEvent_PaymentRefundPendingImplCopyWith<_$SdkEvent_PaymentRefundPendingImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SdkEvent_PaymentSucceededImplCopyWith<$Res> {
+  factory _$$SdkEvent_PaymentSucceededImplCopyWith(
+          _$SdkEvent_PaymentSucceededImpl value, $Res Function(_$SdkEvent_PaymentSucceededImpl) then) =
+      __$$SdkEvent_PaymentSucceededImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Payment details});
+}
+
+/// @nodoc
+class __$$SdkEvent_PaymentSucceededImplCopyWithImpl<$Res>
+    extends _$SdkEventCopyWithImpl<$Res, _$SdkEvent_PaymentSucceededImpl>
+    implements _$$SdkEvent_PaymentSucceededImplCopyWith<$Res> {
+  __$$SdkEvent_PaymentSucceededImplCopyWithImpl(
+      _$SdkEvent_PaymentSucceededImpl _value, $Res Function(_$SdkEvent_PaymentSucceededImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$SdkEvent_PaymentSucceededImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as Payment,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SdkEvent_PaymentSucceededImpl extends SdkEvent_PaymentSucceeded {
+  const _$SdkEvent_PaymentSucceededImpl({required this.details}) : super._();
+
+  @override
+  final Payment details;
+
+  @override
+  String toString() {
+    return 'SdkEvent.paymentSucceeded(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SdkEvent_PaymentSucceededImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SdkEvent_PaymentSucceededImplCopyWith<_$SdkEvent_PaymentSucceededImpl> get copyWith =>
+      __$$SdkEvent_PaymentSucceededImplCopyWithImpl<_$SdkEvent_PaymentSucceededImpl>(this, _$identity);
+}
+
+abstract class SdkEvent_PaymentSucceeded extends SdkEvent {
+  const factory SdkEvent_PaymentSucceeded({required final Payment details}) = _$SdkEvent_PaymentSucceededImpl;
+  const SdkEvent_PaymentSucceeded._() : super._();
+
+  Payment get details;
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SdkEvent_PaymentSucceededImplCopyWith<_$SdkEvent_PaymentSucceededImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SdkEvent_PaymentWaitingConfirmationImplCopyWith<$Res> {
+  factory _$$SdkEvent_PaymentWaitingConfirmationImplCopyWith(_$SdkEvent_PaymentWaitingConfirmationImpl value,
+          $Res Function(_$SdkEvent_PaymentWaitingConfirmationImpl) then) =
+      __$$SdkEvent_PaymentWaitingConfirmationImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Payment details});
+}
+
+/// @nodoc
+class __$$SdkEvent_PaymentWaitingConfirmationImplCopyWithImpl<$Res>
+    extends _$SdkEventCopyWithImpl<$Res, _$SdkEvent_PaymentWaitingConfirmationImpl>
+    implements _$$SdkEvent_PaymentWaitingConfirmationImplCopyWith<$Res> {
+  __$$SdkEvent_PaymentWaitingConfirmationImplCopyWithImpl(_$SdkEvent_PaymentWaitingConfirmationImpl _value,
+      $Res Function(_$SdkEvent_PaymentWaitingConfirmationImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$SdkEvent_PaymentWaitingConfirmationImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as Payment,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SdkEvent_PaymentWaitingConfirmationImpl extends SdkEvent_PaymentWaitingConfirmation {
+  const _$SdkEvent_PaymentWaitingConfirmationImpl({required this.details}) : super._();
+
+  @override
+  final Payment details;
+
+  @override
+  String toString() {
+    return 'SdkEvent.paymentWaitingConfirmation(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SdkEvent_PaymentWaitingConfirmationImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SdkEvent_PaymentWaitingConfirmationImplCopyWith<_$SdkEvent_PaymentWaitingConfirmationImpl>
+      get copyWith =>
+          __$$SdkEvent_PaymentWaitingConfirmationImplCopyWithImpl<_$SdkEvent_PaymentWaitingConfirmationImpl>(
+              this, _$identity);
+}
+
+abstract class SdkEvent_PaymentWaitingConfirmation extends SdkEvent {
+  const factory SdkEvent_PaymentWaitingConfirmation({required final Payment details}) =
+      _$SdkEvent_PaymentWaitingConfirmationImpl;
+  const SdkEvent_PaymentWaitingConfirmation._() : super._();
+
+  Payment get details;
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SdkEvent_PaymentWaitingConfirmationImplCopyWith<_$SdkEvent_PaymentWaitingConfirmationImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SdkEvent_SwapAutoRefundedImplCopyWith<$Res> {
+  factory _$$SdkEvent_SwapAutoRefundedImplCopyWith(
+          _$SdkEvent_SwapAutoRefundedImpl value, $Res Function(_$SdkEvent_SwapAutoRefundedImpl) then) =
+      __$$SdkEvent_SwapAutoRefundedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({AutoRefundDetails details});
+}
+
+/// @nodoc
+class __$$SdkEvent_SwapAutoRefundedImplCopyWithImpl<$Res>
+    extends _$SdkEventCopyWithImpl<$Res, _$SdkEvent_SwapAutoRefundedImpl>
+    implements _$$SdkEvent_SwapAutoRefundedImplCopyWith<$Res> {
+  __$$SdkEvent_SwapAutoRefundedImplCopyWithImpl(
+      _$SdkEvent_SwapAutoRefundedImpl _value, $Res Function(_$SdkEvent_SwapAutoRefundedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$SdkEvent_SwapAutoRefundedImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as AutoRefundDetails,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SdkEvent_SwapAutoRefundedImpl extends SdkEvent_SwapAutoRefunded {
+  const _$SdkEvent_SwapAutoRefundedImpl({required this.details}) : super._();
+
+  @override
+  final AutoRefundDetails details;
+
+  @override
+  String toString() {
+    return 'SdkEvent.swapAutoRefunded(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SdkEvent_SwapAutoRefundedImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SdkEvent_SwapAutoRefundedImplCopyWith<_$SdkEvent_SwapAutoRefundedImpl> get copyWith =>
+      __$$SdkEvent_SwapAutoRefundedImplCopyWithImpl<_$SdkEvent_SwapAutoRefundedImpl>(this, _$identity);
+}
+
+abstract class SdkEvent_SwapAutoRefunded extends SdkEvent {
+  const factory SdkEvent_SwapAutoRefunded({required final AutoRefundDetails details}) =
+      _$SdkEvent_SwapAutoRefundedImpl;
+  const SdkEvent_SwapAutoRefunded._() : super._();
+
+  AutoRefundDetails get details;
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SdkEvent_SwapAutoRefundedImplCopyWith<_$SdkEvent_SwapAutoRefundedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SdkEvent_SwapWaitingFeeAcceptanceImplCopyWith<$Res> {
+  factory _$$SdkEvent_SwapWaitingFeeAcceptanceImplCopyWith(_$SdkEvent_SwapWaitingFeeAcceptanceImpl value,
+          $Res Function(_$SdkEvent_SwapWaitingFeeAcceptanceImpl) then) =
+      __$$SdkEvent_SwapWaitingFeeAcceptanceImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String swapId});
+}
+
+/// @nodoc
+class __$$SdkEvent_SwapWaitingFeeAcceptanceImplCopyWithImpl<$Res>
+    extends _$SdkEventCopyWithImpl<$Res, _$SdkEvent_SwapWaitingFeeAcceptanceImpl>
+    implements _$$SdkEvent_SwapWaitingFeeAcceptanceImplCopyWith<$Res> {
+  __$$SdkEvent_SwapWaitingFeeAcceptanceImplCopyWithImpl(_$SdkEvent_SwapWaitingFeeAcceptanceImpl _value,
+      $Res Function(_$SdkEvent_SwapWaitingFeeAcceptanceImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? swapId = null,
+  }) {
+    return _then(_$SdkEvent_SwapWaitingFeeAcceptanceImpl(
+      swapId: null == swapId
+          ? _value.swapId
+          : swapId // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SdkEvent_SwapWaitingFeeAcceptanceImpl extends SdkEvent_SwapWaitingFeeAcceptance {
+  const _$SdkEvent_SwapWaitingFeeAcceptanceImpl({required this.swapId}) : super._();
+
+  @override
+  final String swapId;
+
+  @override
+  String toString() {
+    return 'SdkEvent.swapWaitingFeeAcceptance(swapId: $swapId)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SdkEvent_SwapWaitingFeeAcceptanceImpl &&
+            (identical(other.swapId, swapId) || other.swapId == swapId));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, swapId);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SdkEvent_SwapWaitingFeeAcceptanceImplCopyWith<_$SdkEvent_SwapWaitingFeeAcceptanceImpl> get copyWith =>
+      __$$SdkEvent_SwapWaitingFeeAcceptanceImplCopyWithImpl<_$SdkEvent_SwapWaitingFeeAcceptanceImpl>(
+          this, _$identity);
+}
+
+abstract class SdkEvent_SwapWaitingFeeAcceptance extends SdkEvent {
+  const factory SdkEvent_SwapWaitingFeeAcceptance({required final String swapId}) =
+      _$SdkEvent_SwapWaitingFeeAcceptanceImpl;
+  const SdkEvent_SwapWaitingFeeAcceptance._() : super._();
+
+  String get swapId;
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SdkEvent_SwapWaitingFeeAcceptanceImplCopyWith<_$SdkEvent_SwapWaitingFeeAcceptanceImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SdkEvent_SyncedImplCopyWith<$Res> {
+  factory _$$SdkEvent_SyncedImplCopyWith(
+          _$SdkEvent_SyncedImpl value, $Res Function(_$SdkEvent_SyncedImpl) then) =
+      __$$SdkEvent_SyncedImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$SdkEvent_SyncedImplCopyWithImpl<$Res> extends _$SdkEventCopyWithImpl<$Res, _$SdkEvent_SyncedImpl>
+    implements _$$SdkEvent_SyncedImplCopyWith<$Res> {
+  __$$SdkEvent_SyncedImplCopyWithImpl(
+      _$SdkEvent_SyncedImpl _value, $Res Function(_$SdkEvent_SyncedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SdkEvent
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$SdkEvent_SyncedImpl extends SdkEvent_Synced {
+  const _$SdkEvent_SyncedImpl() : super._();
+
+  @override
+  String toString() {
+    return 'SdkEvent.synced()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$SdkEvent_SyncedImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+}
+
+abstract class SdkEvent_Synced extends SdkEvent {
+  const factory SdkEvent_Synced() = _$SdkEvent_SyncedImpl;
+  const SdkEvent_Synced._() : super._();
+}
+
+/// @nodoc
+mixin _$SignerMethod {}
+
+/// @nodoc
+abstract class $SignerMethodCopyWith<$Res> {
+  factory $SignerMethodCopyWith(SignerMethod value, $Res Function(SignerMethod) then) =
+      _$SignerMethodCopyWithImpl<$Res, SignerMethod>;
+}
+
+/// @nodoc
+class _$SignerMethodCopyWithImpl<$Res, $Val extends SignerMethod> implements $SignerMethodCopyWith<$Res> {
+  _$SignerMethodCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of SignerMethod
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$SignerMethod_XpubImplCopyWith<$Res> {
+  factory _$$SignerMethod_XpubImplCopyWith(
+          _$SignerMethod_XpubImpl value, $Res Function(_$SignerMethod_XpubImpl) then) =
+      __$$SignerMethod_XpubImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$SignerMethod_XpubImplCopyWithImpl<$Res>
+    extends _$SignerMethodCopyWithImpl<$Res, _$SignerMethod_XpubImpl>
+    implements _$$SignerMethod_XpubImplCopyWith<$Res> {
+  __$$SignerMethod_XpubImplCopyWithImpl(
+      _$SignerMethod_XpubImpl _value, $Res Function(_$SignerMethod_XpubImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SignerMethod
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$SignerMethod_XpubImpl extends SignerMethod_Xpub {
+  const _$SignerMethod_XpubImpl() : super._();
+
+  @override
+  String toString() {
+    return 'SignerMethod.xpub()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$SignerMethod_XpubImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+}
+
+abstract class SignerMethod_Xpub extends SignerMethod {
+  const factory SignerMethod_Xpub() = _$SignerMethod_XpubImpl;
+  const SignerMethod_Xpub._() : super._();
+}
+
+/// @nodoc
+abstract class _$$SignerMethod_DeriveXpubImplCopyWith<$Res> {
+  factory _$$SignerMethod_DeriveXpubImplCopyWith(
+          _$SignerMethod_DeriveXpubImpl value, $Res Function(_$SignerMethod_DeriveXpubImpl) then) =
+      __$$SignerMethod_DeriveXpubImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String derivationPath});
+}
+
+/// @nodoc
+class __$$SignerMethod_DeriveXpubImplCopyWithImpl<$Res>
+    extends _$SignerMethodCopyWithImpl<$Res, _$SignerMethod_DeriveXpubImpl>
+    implements _$$SignerMethod_DeriveXpubImplCopyWith<$Res> {
+  __$$SignerMethod_DeriveXpubImplCopyWithImpl(
+      _$SignerMethod_DeriveXpubImpl _value, $Res Function(_$SignerMethod_DeriveXpubImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SignerMethod
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? derivationPath = null,
+  }) {
+    return _then(_$SignerMethod_DeriveXpubImpl(
+      derivationPath: null == derivationPath
+          ? _value.derivationPath
+          : derivationPath // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SignerMethod_DeriveXpubImpl extends SignerMethod_DeriveXpub {
+  const _$SignerMethod_DeriveXpubImpl({required this.derivationPath}) : super._();
+
+  @override
+  final String derivationPath;
+
+  @override
+  String toString() {
+    return 'SignerMethod.deriveXpub(derivationPath: $derivationPath)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SignerMethod_DeriveXpubImpl &&
+            (identical(other.derivationPath, derivationPath) || other.derivationPath == derivationPath));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, derivationPath);
+
+  /// Create a copy of SignerMethod
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SignerMethod_DeriveXpubImplCopyWith<_$SignerMethod_DeriveXpubImpl> get copyWith =>
+      __$$SignerMethod_DeriveXpubImplCopyWithImpl<_$SignerMethod_DeriveXpubImpl>(this, _$identity);
+}
+
+abstract class SignerMethod_DeriveXpub extends SignerMethod {
+  const factory SignerMethod_DeriveXpub({required final String derivationPath}) =
+      _$SignerMethod_DeriveXpubImpl;
+  const SignerMethod_DeriveXpub._() : super._();
+
+  String get derivationPath;
+
+  /// Create a copy of SignerMethod
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SignerMethod_DeriveXpubImplCopyWith<_$SignerMethod_DeriveXpubImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SignerMethod_Slip77MasterBlindingKeyImplCopyWith<$Res> {
+  factory _$$SignerMethod_Slip77MasterBlindingKeyImplCopyWith(
+          _$SignerMethod_Slip77MasterBlindingKeyImpl value,
+          $Res Function(_$SignerMethod_Slip77MasterBlindingKeyImpl) then) =
+      __$$SignerMethod_Slip77MasterBlindingKeyImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$SignerMethod_Slip77MasterBlindingKeyImplCopyWithImpl<$Res>
+    extends _$SignerMethodCopyWithImpl<$Res, _$SignerMethod_Slip77MasterBlindingKeyImpl>
+    implements _$$SignerMethod_Slip77MasterBlindingKeyImplCopyWith<$Res> {
+  __$$SignerMethod_Slip77MasterBlindingKeyImplCopyWithImpl(_$SignerMethod_Slip77MasterBlindingKeyImpl _value,
+      $Res Function(_$SignerMethod_Slip77MasterBlindingKeyImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SignerMethod
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$SignerMethod_Slip77MasterBlindingKeyImpl extends SignerMethod_Slip77MasterBlindingKey {
+  const _$SignerMethod_Slip77MasterBlindingKeyImpl() : super._();
+
+  @override
+  String toString() {
+    return 'SignerMethod.slip77MasterBlindingKey()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$SignerMethod_Slip77MasterBlindingKeyImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+}
+
+abstract class SignerMethod_Slip77MasterBlindingKey extends SignerMethod {
+  const factory SignerMethod_Slip77MasterBlindingKey() = _$SignerMethod_Slip77MasterBlindingKeyImpl;
+  const SignerMethod_Slip77MasterBlindingKey._() : super._();
+}
+
+/// @nodoc
+abstract class _$$SignerMethod_SignPsetImplCopyWith<$Res> {
+  factory _$$SignerMethod_SignPsetImplCopyWith(
+          _$SignerMethod_SignPsetImpl value, $Res Function(_$SignerMethod_SignPsetImpl) then) =
+      __$$SignerMethod_SignPsetImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String pset});
+}
+
+/// @nodoc
+class __$$SignerMethod_SignPsetImplCopyWithImpl<$Res>
+    extends _$SignerMethodCopyWithImpl<$Res, _$SignerMethod_SignPsetImpl>
+    implements _$$SignerMethod_SignPsetImplCopyWith<$Res> {
+  __$$SignerMethod_SignPsetImplCopyWithImpl(
+      _$SignerMethod_SignPsetImpl _value, $Res Function(_$SignerMethod_SignPsetImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SignerMethod
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? pset = null,
+  }) {
+    return _then(_$SignerMethod_SignPsetImpl(
+      pset: null == pset
+          ? _value.pset
+          : pset // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SignerMethod_SignPsetImpl extends SignerMethod_SignPset {
+  const _$SignerMethod_SignPsetImpl({required this.pset}) : super._();
+
+  @override
+  final String pset;
+
+  @override
+  String toString() {
+    return 'SignerMethod.signPset(pset: $pset)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SignerMethod_SignPsetImpl &&
+            (identical(other.pset, pset) || other.pset == pset));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, pset);
+
+  /// Create a copy of SignerMethod
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SignerMethod_SignPsetImplCopyWith<_$SignerMethod_SignPsetImpl> get copyWith =>
+      __$$SignerMethod_SignPsetImplCopyWithImpl<_$SignerMethod_SignPsetImpl>(this, _$identity);
+}
+
+abstract class SignerMethod_SignPset extends SignerMethod {
+  const factory SignerMethod_SignPset({required final String pset}) = _$SignerMethod_SignPsetImpl;
+  const SignerMethod_SignPset._() : super._();
+
+  String get pset;
+
+  /// Create a copy of SignerMethod
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SignerMethod_SignPsetImplCopyWith<_$SignerMethod_SignPsetImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SignerMethod_SignEcdsaImplCopyWith<$Res> {
+  factory _$$SignerMethod_SignEcdsaImplCopyWith(
+          _$SignerMethod_SignEcdsaImpl value, $Res Function(_$SignerMethod_SignEcdsaImpl) then) =
+      __$$SignerMethod_SignEcdsaImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Uint8List msg, String derivationPath});
+}
+
+/// @nodoc
+class __$$SignerMethod_SignEcdsaImplCopyWithImpl<$Res>
+    extends _$SignerMethodCopyWithImpl<$Res, _$SignerMethod_SignEcdsaImpl>
+    implements _$$SignerMethod_SignEcdsaImplCopyWith<$Res> {
+  __$$SignerMethod_SignEcdsaImplCopyWithImpl(
+      _$SignerMethod_SignEcdsaImpl _value, $Res Function(_$SignerMethod_SignEcdsaImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SignerMethod
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? msg = null,
+    Object? derivationPath = null,
+  }) {
+    return _then(_$SignerMethod_SignEcdsaImpl(
+      msg: null == msg
+          ? _value.msg
+          : msg // ignore: cast_nullable_to_non_nullable
+              as Uint8List,
+      derivationPath: null == derivationPath
+          ? _value.derivationPath
+          : derivationPath // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SignerMethod_SignEcdsaImpl extends SignerMethod_SignEcdsa {
+  const _$SignerMethod_SignEcdsaImpl({required this.msg, required this.derivationPath}) : super._();
+
+  @override
+  final Uint8List msg;
+  @override
+  final String derivationPath;
+
+  @override
+  String toString() {
+    return 'SignerMethod.signEcdsa(msg: $msg, derivationPath: $derivationPath)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SignerMethod_SignEcdsaImpl &&
+            (identical(other.msg, msg) || other.msg == msg) &&
+            (identical(other.derivationPath, derivationPath) || other.derivationPath == derivationPath));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, msg, derivationPath);
+
+  /// Create a copy of SignerMethod
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SignerMethod_SignEcdsaImplCopyWith<_$SignerMethod_SignEcdsaImpl> get copyWith =>
+      __$$SignerMethod_SignEcdsaImplCopyWithImpl<_$SignerMethod_SignEcdsaImpl>(this, _$identity);
+}
+
+abstract class SignerMethod_SignEcdsa extends SignerMethod {
+  const factory SignerMethod_SignEcdsa({required final Uint8List msg, required final String derivationPath}) =
+      _$SignerMethod_SignEcdsaImpl;
+  const SignerMethod_SignEcdsa._() : super._();
+
+  Uint8List get msg;
+  String get derivationPath;
+
+  /// Create a copy of SignerMethod
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SignerMethod_SignEcdsaImplCopyWith<_$SignerMethod_SignEcdsaImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SignerMethod_HmacSha256ImplCopyWith<$Res> {
+  factory _$$SignerMethod_HmacSha256ImplCopyWith(
+          _$SignerMethod_HmacSha256Impl value, $Res Function(_$SignerMethod_HmacSha256Impl) then) =
+      __$$SignerMethod_HmacSha256ImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Uint8List msg, String derivationPath});
+}
+
+/// @nodoc
+class __$$SignerMethod_HmacSha256ImplCopyWithImpl<$Res>
+    extends _$SignerMethodCopyWithImpl<$Res, _$SignerMethod_HmacSha256Impl>
+    implements _$$SignerMethod_HmacSha256ImplCopyWith<$Res> {
+  __$$SignerMethod_HmacSha256ImplCopyWithImpl(
+      _$SignerMethod_HmacSha256Impl _value, $Res Function(_$SignerMethod_HmacSha256Impl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SignerMethod
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? msg = null,
+    Object? derivationPath = null,
+  }) {
+    return _then(_$SignerMethod_HmacSha256Impl(
+      msg: null == msg
+          ? _value.msg
+          : msg // ignore: cast_nullable_to_non_nullable
+              as Uint8List,
+      derivationPath: null == derivationPath
+          ? _value.derivationPath
+          : derivationPath // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SignerMethod_HmacSha256Impl extends SignerMethod_HmacSha256 {
+  const _$SignerMethod_HmacSha256Impl({required this.msg, required this.derivationPath}) : super._();
+
+  @override
+  final Uint8List msg;
+  @override
+  final String derivationPath;
+
+  @override
+  String toString() {
+    return 'SignerMethod.hmacSha256(msg: $msg, derivationPath: $derivationPath)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SignerMethod_HmacSha256Impl &&
+            (identical(other.msg, msg) || other.msg == msg) &&
+            (identical(other.derivationPath, derivationPath) || other.derivationPath == derivationPath));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, msg, derivationPath);
+
+  /// Create a copy of SignerMethod
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SignerMethod_HmacSha256ImplCopyWith<_$SignerMethod_HmacSha256Impl> get copyWith =>
+      __$$SignerMethod_HmacSha256ImplCopyWithImpl<_$SignerMethod_HmacSha256Impl>(this, _$identity);
+}
+
+abstract class SignerMethod_HmacSha256 extends SignerMethod {
+  const factory SignerMethod_HmacSha256(
+          {required final Uint8List msg, required final String derivationPath}) =
+      _$SignerMethod_HmacSha256Impl;
+  const SignerMethod_HmacSha256._() : super._();
+
+  Uint8List get msg;
+  String get derivationPath;
+
+  /// Create a copy of SignerMethod
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SignerMethod_HmacSha256ImplCopyWith<_$SignerMethod_HmacSha256Impl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$SignerResult {}
+
+/// @nodoc
+abstract class $SignerResultCopyWith<$Res> {
+  factory $SignerResultCopyWith(SignerResult value, $Res Function(SignerResult) then) =
+      _$SignerResultCopyWithImpl<$Res, SignerResult>;
+}
+
+/// @nodoc
+class _$SignerResultCopyWithImpl<$Res, $Val extends SignerResult> implements $SignerResultCopyWith<$Res> {
+  _$SignerResultCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of SignerResult
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$SignerResult_StringImplCopyWith<$Res> {
+  factory _$$SignerResult_StringImplCopyWith(
+          _$SignerResult_StringImpl value, $Res Function(_$SignerResult_StringImpl) then) =
+      __$$SignerResult_StringImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String value});
+}
+
+/// @nodoc
+class __$$SignerResult_StringImplCopyWithImpl<$Res>
+    extends _$SignerResultCopyWithImpl<$Res, _$SignerResult_StringImpl>
+    implements _$$SignerResult_StringImplCopyWith<$Res> {
+  __$$SignerResult_StringImplCopyWithImpl(
+      _$SignerResult_StringImpl _value, $Res Function(_$SignerResult_StringImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SignerResult
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? value = null,
+  }) {
+    return _then(_$SignerResult_StringImpl(
+      value: null == value
+          ? _value.value
+          : value // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SignerResult_StringImpl extends SignerResult_String {
+  const _$SignerResult_StringImpl({required this.value}) : super._();
+
+  @override
+  final String value;
+
+  @override
+  String toString() {
+    return 'SignerResult.string(value: $value)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SignerResult_StringImpl &&
+            (identical(other.value, value) || other.value == value));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, value);
+
+  /// Create a copy of SignerResult
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SignerResult_StringImplCopyWith<_$SignerResult_StringImpl> get copyWith =>
+      __$$SignerResult_StringImplCopyWithImpl<_$SignerResult_StringImpl>(this, _$identity);
+}
+
+abstract class SignerResult_String extends SignerResult {
+  const factory SignerResult_String({required final String value}) = _$SignerResult_StringImpl;
+  const SignerResult_String._() : super._();
+
+  String get value;
+
+  /// Create a copy of SignerResult
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SignerResult_StringImplCopyWith<_$SignerResult_StringImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SignerResult_BytesImplCopyWith<$Res> {
+  factory _$$SignerResult_BytesImplCopyWith(
+          _$SignerResult_BytesImpl value, $Res Function(_$SignerResult_BytesImpl) then) =
+      __$$SignerResult_BytesImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Uint8List value});
+}
+
+/// @nodoc
+class __$$SignerResult_BytesImplCopyWithImpl<$Res>
+    extends _$SignerResultCopyWithImpl<$Res, _$SignerResult_BytesImpl>
+    implements _$$SignerResult_BytesImplCopyWith<$Res> {
+  __$$SignerResult_BytesImplCopyWithImpl(
+      _$SignerResult_BytesImpl _value, $Res Function(_$SignerResult_BytesImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SignerResult
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? value = null,
+  }) {
+    return _then(_$SignerResult_BytesImpl(
+      value: null == value
+          ? _value.value
+          : value // ignore: cast_nullable_to_non_nullable
+              as Uint8List,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SignerResult_BytesImpl extends SignerResult_Bytes {
+  const _$SignerResult_BytesImpl({required this.value}) : super._();
+
+  @override
+  final Uint8List value;
+
+  @override
+  String toString() {
+    return 'SignerResult.bytes(value: $value)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SignerResult_BytesImpl &&
+            (identical(other.value, value) || other.value == value));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, value);
+
+  /// Create a copy of SignerResult
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SignerResult_BytesImplCopyWith<_$SignerResult_BytesImpl> get copyWith =>
+      __$$SignerResult_BytesImplCopyWithImpl<_$SignerResult_BytesImpl>(this, _$identity);
+}
+
+abstract class SignerResult_Bytes extends SignerResult {
+  const factory SignerResult_Bytes({required final Uint8List value}) = _$SignerResult_BytesImpl;
+  const SignerResult_Bytes._() : super._();
+
+  Uint8List get value;
+
+  /// Create a copy of SignerResult
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SignerResult_BytesImplCopyWith<_$SignerResult_BytesImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SignerResult_ErrorImplCopyWith<$Res> {
+  factory _$$SignerResult_ErrorImplCopyWith(
+          _$SignerResult_ErrorImpl value, $Res Function(_$SignerResult_ErrorImpl) then) =
+      __$$SignerResult_ErrorImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String err});
+}
+
+/// @nodoc
+class __$$SignerResult_ErrorImplCopyWithImpl<$Res>
+    extends _$SignerResultCopyWithImpl<$Res, _$SignerResult_ErrorImpl>
+    implements _$$SignerResult_ErrorImplCopyWith<$Res> {
+  __$$SignerResult_ErrorImplCopyWithImpl(
+      _$SignerResult_ErrorImpl _value, $Res Function(_$SignerResult_ErrorImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of SignerResult
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? err = null,
+  }) {
+    return _then(_$SignerResult_ErrorImpl(
+      err: null == err
+          ? _value.err
+          : err // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SignerResult_ErrorImpl extends SignerResult_Error {
+  const _$SignerResult_ErrorImpl({required this.err}) : super._();
+
+  @override
+  final String err;
+
+  @override
+  String toString() {
+    return 'SignerResult.error(err: $err)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SignerResult_ErrorImpl &&
+            (identical(other.err, err) || other.err == err));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, err);
+
+  /// Create a copy of SignerResult
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SignerResult_ErrorImplCopyWith<_$SignerResult_ErrorImpl> get copyWith =>
+      __$$SignerResult_ErrorImplCopyWithImpl<_$SignerResult_ErrorImpl>(this, _$identity);
+}
+
+abstract class SignerResult_Error extends SignerResult {
+  const factory SignerResult_Error({required final String err}) = _$SignerResult_ErrorImpl;
+  const SignerResult_Error._() : super._();
+
+  String get err;
+
+  /// Create a copy of SignerResult
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$SignerResult_ErrorImplCopyWith<_$SignerResult_ErrorImpl> get copyWith =>
+      throw _privateConstructorUsedError;
 }
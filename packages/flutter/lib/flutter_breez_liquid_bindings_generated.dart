@@ -37,6 +37,28 @@ class FlutterBreezLiquidBindings {
   late final _store_dart_post_cobject =
       _store_dart_post_cobjectPtr.asFunction<void Function(DartPostCObjectFnType)>();
 
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_accept_payment_proposed_fees(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_accept_payment_proposed_fees_request> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_accept_payment_proposed_fees(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_accept_payment_proposed_feesPtr =
+      _lookup<
+              ffi.NativeFunction<
+                  ffi.Void Function(
+                      ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_accept_payment_proposed_fees_request>)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_accept_payment_proposed_fees');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_accept_payment_proposed_fees =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_accept_payment_proposed_feesPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_accept_payment_proposed_fees_request>)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_add_event_listener(
     int port_,
     int that,
@@ -75,6 +97,26 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_backupPtr
           .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_backup_request>)>();
 
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_bump_refund_fee(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_bump_refund_fee_request> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_bump_refund_fee(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_bump_refund_feePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_bump_refund_fee_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_bump_refund_fee');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_bump_refund_fee =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_bump_refund_feePtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_bump_refund_fee_request>)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_buy_bitcoin(
     int port_,
     int that,
@@ -95,6 +137,28 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_buy_bitcoinPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_buy_bitcoin_request>)>();
 
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_delete_payment_metadata(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_delete_payment_metadata_request> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_delete_payment_metadata(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_delete_payment_metadataPtr =
+      _lookup<
+              ffi.NativeFunction<
+                  ffi.Void Function(
+                      ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_delete_payment_metadata_request>)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_delete_payment_metadata');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_delete_payment_metadata =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_delete_payment_metadataPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_delete_payment_metadata_request>)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_disconnect(
     int port_,
     int that,
@@ -127,6 +191,26 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_empty_wallet_cachePtr
           .asFunction<WireSyncRust2DartDco Function(int)>();
 
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_payments(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_export_payments_request> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_payments(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_paymentsPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_export_payments_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_payments');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_payments =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_paymentsPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_export_payments_request>)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_fiat_rates(
     int port_,
     int that,
@@ -178,6 +262,28 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_onchain_limitsPtr
           .asFunction<void Function(int, int)>();
 
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_payment_proposed_fees(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_fetch_payment_proposed_fees_request> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_payment_proposed_fees(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_payment_proposed_feesPtr =
+      _lookup<
+              ffi.NativeFunction<
+                  ffi.Void Function(
+                      ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_fetch_payment_proposed_fees_request>)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_payment_proposed_fees');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_payment_proposed_fees =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_payment_proposed_feesPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_fetch_payment_proposed_fees_request>)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_info(
     int port_,
     int that,
@@ -195,6 +301,46 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_infoPtr
           .asFunction<void Function(int, int)>();
 
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_payment_lookup> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_paymentPtr = _lookup<
+          ffi
+          .NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_payment_lookup>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_paymentPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_payment_lookup>)>();
+
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment_metadata(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_id,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment_metadata(
+      port_,
+      that,
+      payment_id,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment_metadataPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment_metadata');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment_metadata =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment_metadataPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_fiat_currencies(
     int port_,
     int that,
@@ -232,6 +378,26 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_paymentsPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_payments_request>)>();
 
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments_page(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_list_payments_request> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments_page(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments_pagePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_payments_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments_page');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments_page =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments_pagePtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_payments_request>)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_refundables(
     int port_,
     int that,
@@ -433,6 +599,26 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_refundPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_refund_request>)>();
 
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_liquid(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_prepare_send_liquid_request> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_liquid(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_liquidPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_prepare_send_liquid_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_liquid');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_liquid =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_liquidPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_send_liquid_request>)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_payment(
     int port_,
     int that,
@@ -453,6 +639,26 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_paymentPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_send_request>)>();
 
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_liquid(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_receive_liquid_request> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_liquid(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_liquidPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_receive_liquid_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_liquid');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_liquid =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_liquidPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_receive_liquid_request>)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_onchain(
     int port_,
     int that,
@@ -566,6 +772,26 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_restorePtr
           .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_restore_request>)>();
 
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_liquid(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_prepare_send_liquid_response> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_liquid(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_liquidPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_prepare_send_liquid_response>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_liquid');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_liquid =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_liquidPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_send_liquid_response>)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_payment(
     int port_,
     int that,
@@ -586,6 +812,26 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_paymentPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_send_response>)>();
 
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_set_payment_metadata(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_set_payment_metadata_request> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_set_payment_metadata(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_set_payment_metadataPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_set_payment_metadata_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_set_payment_metadata');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_set_payment_metadata =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_set_payment_metadataPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_set_payment_metadata_request>)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sync(
     int port_,
     int that,
@@ -659,6 +905,23 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__connectPtr
           .asFunction<void Function(int, ffi.Pointer<wire_cst_connect_request>)>();
 
+  void frbgen_breez_liquid_wire__crate__bindings__connect_with_signer(
+    int port_,
+    ffi.Pointer<wire_cst_connect_request> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__connect_with_signer(
+      port_,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__connect_with_signerPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_connect_request>)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__connect_with_signer');
+  late final _frbgen_breez_liquid_wire__crate__bindings__connect_with_signer =
+      _frbgen_breez_liquid_wire__crate__bindings__connect_with_signerPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_cst_connect_request>)>();
+
   WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__default_config(
     int network,
   ) {
@@ -706,6 +969,56 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__parse_invoicePtr
           .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
+  WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__parse_offer(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> input,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__parse_offer(
+      input,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__parse_offerPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__parse_offer');
+  late final _frbgen_breez_liquid_wire__crate__bindings__parse_offer =
+      _frbgen_breez_liquid_wire__crate__bindings__parse_offerPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__respond_to_signer_request(
+    int id,
+    ffi.Pointer<wire_cst_signer_result> result,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__respond_to_signer_request(
+      id,
+      result,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__respond_to_signer_requestPtr = _lookup<
+          ffi
+          .NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_signer_result>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__respond_to_signer_request');
+  late final _frbgen_breez_liquid_wire__crate__bindings__respond_to_signer_request =
+      _frbgen_breez_liquid_wire__crate__bindings__respond_to_signer_requestPtr
+          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_signer_result>)>();
+
+  void frbgen_breez_liquid_wire__crate__bindings__signer_request_stream(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> s,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__signer_request_stream(
+      port_,
+      s,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__signer_request_streamPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__signer_request_stream');
+  late final _frbgen_breez_liquid_wire__crate__bindings__signer_request_stream =
+      _frbgen_breez_liquid_wire__crate__bindings__signer_request_streamPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
   void
       frbgen_breez_liquid_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
     ffi.Pointer<ffi.Void> ptr,
@@ -738,6 +1051,18 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdkPtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
+  ffi.Pointer<wire_cst_accept_payment_proposed_fees_request>
+      frbgen_breez_liquid_cst_new_box_autoadd_accept_payment_proposed_fees_request() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_accept_payment_proposed_fees_request();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_accept_payment_proposed_fees_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_accept_payment_proposed_fees_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_accept_payment_proposed_fees_request');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_accept_payment_proposed_fees_request =
+      _frbgen_breez_liquid_cst_new_box_autoadd_accept_payment_proposed_fees_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_accept_payment_proposed_fees_request> Function()>();
+
   ffi.Pointer<wire_cst_aes_success_action_data_decrypted>
       frbgen_breez_liquid_cst_new_box_autoadd_aes_success_action_data_decrypted() {
     return _frbgen_breez_liquid_cst_new_box_autoadd_aes_success_action_data_decrypted();
@@ -762,6 +1087,39 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_cst_new_box_autoadd_aes_success_action_data_resultPtr
           .asFunction<ffi.Pointer<wire_cst_aes_success_action_data_result> Function()>();
 
+  ffi.Pointer<wire_cst_asset_metadata> frbgen_breez_liquid_cst_new_box_autoadd_asset_metadata() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_asset_metadata();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_asset_metadataPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_asset_metadata> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_asset_metadata');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_asset_metadata =
+      _frbgen_breez_liquid_cst_new_box_autoadd_asset_metadataPtr
+          .asFunction<ffi.Pointer<wire_cst_asset_metadata> Function()>();
+
+  ffi.Pointer<wire_cst_auto_refund_details> frbgen_breez_liquid_cst_new_box_autoadd_auto_refund_details() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_auto_refund_details();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_auto_refund_detailsPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_auto_refund_details> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_auto_refund_details');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_auto_refund_details =
+      _frbgen_breez_liquid_cst_new_box_autoadd_auto_refund_detailsPtr
+          .asFunction<ffi.Pointer<wire_cst_auto_refund_details> Function()>();
+
+  ffi.Pointer<wire_cst_auto_refund_policy> frbgen_breez_liquid_cst_new_box_autoadd_auto_refund_policy() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_auto_refund_policy();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_auto_refund_policyPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_auto_refund_policy> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_auto_refund_policy');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_auto_refund_policy =
+      _frbgen_breez_liquid_cst_new_box_autoadd_auto_refund_policyPtr
+          .asFunction<ffi.Pointer<wire_cst_auto_refund_policy> Function()>();
+
   ffi.Pointer<wire_cst_backup_request> frbgen_breez_liquid_cst_new_box_autoadd_backup_request() {
     return _frbgen_breez_liquid_cst_new_box_autoadd_backup_request();
   }
@@ -810,6 +1168,18 @@ class FlutterBreezLiquidBindings {
   late final _frbgen_breez_liquid_cst_new_box_autoadd_bool =
       _frbgen_breez_liquid_cst_new_box_autoadd_boolPtr.asFunction<ffi.Pointer<ffi.Bool> Function(bool)>();
 
+  ffi.Pointer<wire_cst_bump_refund_fee_request>
+      frbgen_breez_liquid_cst_new_box_autoadd_bump_refund_fee_request() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_bump_refund_fee_request();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_bump_refund_fee_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_bump_refund_fee_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_bump_refund_fee_request');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_bump_refund_fee_request =
+      _frbgen_breez_liquid_cst_new_box_autoadd_bump_refund_fee_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_bump_refund_fee_request> Function()>();
+
   ffi.Pointer<wire_cst_buy_bitcoin_request> frbgen_breez_liquid_cst_new_box_autoadd_buy_bitcoin_request() {
     return _frbgen_breez_liquid_cst_new_box_autoadd_buy_bitcoin_request();
   }
@@ -832,6 +1202,42 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_cst_new_box_autoadd_connect_requestPtr
           .asFunction<ffi.Pointer<wire_cst_connect_request> Function()>();
 
+  ffi.Pointer<wire_cst_delete_payment_metadata_request>
+      frbgen_breez_liquid_cst_new_box_autoadd_delete_payment_metadata_request() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_delete_payment_metadata_request();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_delete_payment_metadata_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_delete_payment_metadata_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_delete_payment_metadata_request');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_delete_payment_metadata_request =
+      _frbgen_breez_liquid_cst_new_box_autoadd_delete_payment_metadata_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_delete_payment_metadata_request> Function()>();
+
+  ffi.Pointer<wire_cst_export_payments_request>
+      frbgen_breez_liquid_cst_new_box_autoadd_export_payments_request() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_export_payments_request();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_export_payments_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_export_payments_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_export_payments_request');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_export_payments_request =
+      _frbgen_breez_liquid_cst_new_box_autoadd_export_payments_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_export_payments_request> Function()>();
+
+  ffi.Pointer<wire_cst_fetch_payment_proposed_fees_request>
+      frbgen_breez_liquid_cst_new_box_autoadd_fetch_payment_proposed_fees_request() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_fetch_payment_proposed_fees_request();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_fetch_payment_proposed_fees_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_fetch_payment_proposed_fees_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_fetch_payment_proposed_fees_request');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_fetch_payment_proposed_fees_request =
+      _frbgen_breez_liquid_cst_new_box_autoadd_fetch_payment_proposed_fees_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_fetch_payment_proposed_fees_request> Function()>();
+
   ffi.Pointer<ffi.Int64> frbgen_breez_liquid_cst_new_box_autoadd_i_64(
     int value,
   ) {
@@ -1009,6 +1415,17 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_cst_new_box_autoadd_paymentPtr
           .asFunction<ffi.Pointer<wire_cst_payment> Function()>();
 
+  ffi.Pointer<wire_cst_payment_lookup> frbgen_breez_liquid_cst_new_box_autoadd_payment_lookup() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_payment_lookup();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_payment_lookupPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_payment_lookup> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_payment_lookup');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_payment_lookup =
+      _frbgen_breez_liquid_cst_new_box_autoadd_payment_lookupPtr
+          .asFunction<ffi.Pointer<wire_cst_payment_lookup> Function()>();
+
   ffi.Pointer<wire_cst_prepare_buy_bitcoin_request>
       frbgen_breez_liquid_cst_new_box_autoadd_prepare_buy_bitcoin_request() {
     return _frbgen_breez_liquid_cst_new_box_autoadd_prepare_buy_bitcoin_request();
@@ -1081,6 +1498,30 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_cst_new_box_autoadd_prepare_refund_requestPtr
           .asFunction<ffi.Pointer<wire_cst_prepare_refund_request> Function()>();
 
+  ffi.Pointer<wire_cst_prepare_send_liquid_request>
+      frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_liquid_request() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_liquid_request();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_liquid_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_prepare_send_liquid_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_liquid_request');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_liquid_request =
+      _frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_liquid_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_prepare_send_liquid_request> Function()>();
+
+  ffi.Pointer<wire_cst_prepare_send_liquid_response>
+      frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_liquid_response() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_liquid_response();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_liquid_responsePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_prepare_send_liquid_response> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_liquid_response');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_liquid_response =
+      _frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_liquid_responsePtr
+          .asFunction<ffi.Pointer<wire_cst_prepare_send_liquid_response> Function()>();
+
   ffi.Pointer<wire_cst_prepare_send_request> frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_request() {
     return _frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_request();
   }
@@ -1104,6 +1545,18 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_responsePtr
           .asFunction<ffi.Pointer<wire_cst_prepare_send_response> Function()>();
 
+  ffi.Pointer<wire_cst_receive_liquid_request>
+      frbgen_breez_liquid_cst_new_box_autoadd_receive_liquid_request() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_receive_liquid_request();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_receive_liquid_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_receive_liquid_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_receive_liquid_request');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_receive_liquid_request =
+      _frbgen_breez_liquid_cst_new_box_autoadd_receive_liquid_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_receive_liquid_request> Function()>();
+
   ffi.Pointer<wire_cst_receive_payment_request>
       frbgen_breez_liquid_cst_new_box_autoadd_receive_payment_request() {
     return _frbgen_breez_liquid_cst_new_box_autoadd_receive_payment_request();
@@ -1149,6 +1602,29 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_cst_new_box_autoadd_sdk_eventPtr
           .asFunction<ffi.Pointer<wire_cst_sdk_event> Function()>();
 
+  ffi.Pointer<wire_cst_set_payment_metadata_request>
+      frbgen_breez_liquid_cst_new_box_autoadd_set_payment_metadata_request() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_set_payment_metadata_request();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_set_payment_metadata_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_set_payment_metadata_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_set_payment_metadata_request');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_set_payment_metadata_request =
+      _frbgen_breez_liquid_cst_new_box_autoadd_set_payment_metadata_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_set_payment_metadata_request> Function()>();
+
+  ffi.Pointer<wire_cst_signer_result> frbgen_breez_liquid_cst_new_box_autoadd_signer_result() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_signer_result();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_signer_resultPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_signer_result> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_signer_result');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_signer_result =
+      _frbgen_breez_liquid_cst_new_box_autoadd_signer_resultPtr
+          .asFunction<ffi.Pointer<wire_cst_signer_result> Function()>();
+
   ffi.Pointer<wire_cst_success_action_processed>
       frbgen_breez_liquid_cst_new_box_autoadd_success_action_processed() {
     return _frbgen_breez_liquid_cst_new_box_autoadd_success_action_processed();
@@ -1212,6 +1688,50 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_cst_new_box_autoadd_url_success_action_dataPtr
           .asFunction<ffi.Pointer<wire_cst_url_success_action_data> Function()>();
 
+  ffi.Pointer<wire_cst_list_String> frbgen_breez_liquid_cst_new_list_String(
+    int len,
+  ) {
+    return _frbgen_breez_liquid_cst_new_list_String(
+      len,
+    );
+  }
+
+  late final _frbgen_breez_liquid_cst_new_list_StringPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_String> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_String');
+  late final _frbgen_breez_liquid_cst_new_list_String = _frbgen_breez_liquid_cst_new_list_StringPtr
+      .asFunction<ffi.Pointer<wire_cst_list_String> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_asset_balance> frbgen_breez_liquid_cst_new_list_asset_balance(
+    int len,
+  ) {
+    return _frbgen_breez_liquid_cst_new_list_asset_balance(
+      len,
+    );
+  }
+
+  late final _frbgen_breez_liquid_cst_new_list_asset_balancePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_asset_balance> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_asset_balance');
+  late final _frbgen_breez_liquid_cst_new_list_asset_balance =
+      _frbgen_breez_liquid_cst_new_list_asset_balancePtr
+          .asFunction<ffi.Pointer<wire_cst_list_asset_balance> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_asset_metadata> frbgen_breez_liquid_cst_new_list_asset_metadata(
+    int len,
+  ) {
+    return _frbgen_breez_liquid_cst_new_list_asset_metadata(
+      len,
+    );
+  }
+
+  late final _frbgen_breez_liquid_cst_new_list_asset_metadataPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_asset_metadata> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_asset_metadata');
+  late final _frbgen_breez_liquid_cst_new_list_asset_metadata =
+      _frbgen_breez_liquid_cst_new_list_asset_metadataPtr
+          .asFunction<ffi.Pointer<wire_cst_list_asset_metadata> Function(int)>();
+
   ffi.Pointer<wire_cst_list_fiat_currency> frbgen_breez_liquid_cst_new_list_fiat_currency(
     int len,
   ) {
@@ -1271,33 +1791,108 @@ class FlutterBreezLiquidBindings {
   late final _frbgen_breez_liquid_cst_new_list_payment = _frbgen_breez_liquid_cst_new_list_paymentPtr
       .asFunction<ffi.Pointer<wire_cst_list_payment> Function(int)>();
 
-  ffi.Pointer<wire_cst_list_payment_type> frbgen_breez_liquid_cst_new_list_payment_type(
+  ffi.Pointer<wire_cst_list_payment_fiat_rate> frbgen_breez_liquid_cst_new_list_payment_fiat_rate(
     int len,
   ) {
-    return _frbgen_breez_liquid_cst_new_list_payment_type(
+    return _frbgen_breez_liquid_cst_new_list_payment_fiat_rate(
       len,
     );
   }
 
-  late final _frbgen_breez_liquid_cst_new_list_payment_typePtr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_payment_type> Function(ffi.Int32)>>(
-          'frbgen_breez_liquid_cst_new_list_payment_type');
-  late final _frbgen_breez_liquid_cst_new_list_payment_type =
-      _frbgen_breez_liquid_cst_new_list_payment_typePtr
-          .asFunction<ffi.Pointer<wire_cst_list_payment_type> Function(int)>();
+  late final _frbgen_breez_liquid_cst_new_list_payment_fiat_ratePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_payment_fiat_rate> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_payment_fiat_rate');
+  late final _frbgen_breez_liquid_cst_new_list_payment_fiat_rate =
+      _frbgen_breez_liquid_cst_new_list_payment_fiat_ratePtr
+          .asFunction<ffi.Pointer<wire_cst_list_payment_fiat_rate> Function(int)>();
 
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> frbgen_breez_liquid_cst_new_list_prim_u_8_strict(
+  ffi.Pointer<wire_cst_list_payment_kind> frbgen_breez_liquid_cst_new_list_payment_kind(
     int len,
   ) {
-    return _frbgen_breez_liquid_cst_new_list_prim_u_8_strict(
+    return _frbgen_breez_liquid_cst_new_list_payment_kind(
       len,
     );
   }
 
-  late final _frbgen_breez_liquid_cst_new_list_prim_u_8_strictPtr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_prim_u_8_strict> Function(ffi.Int32)>>(
-          'frbgen_breez_liquid_cst_new_list_prim_u_8_strict');
-  late final _frbgen_breez_liquid_cst_new_list_prim_u_8_strict =
+  late final _frbgen_breez_liquid_cst_new_list_payment_kindPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_payment_kind> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_payment_kind');
+  late final _frbgen_breez_liquid_cst_new_list_payment_kind =
+      _frbgen_breez_liquid_cst_new_list_payment_kindPtr
+          .asFunction<ffi.Pointer<wire_cst_list_payment_kind> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_payment_metadata> frbgen_breez_liquid_cst_new_list_payment_metadata(
+    int len,
+  ) {
+    return _frbgen_breez_liquid_cst_new_list_payment_metadata(
+      len,
+    );
+  }
+
+  late final _frbgen_breez_liquid_cst_new_list_payment_metadataPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_payment_metadata> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_payment_metadata');
+  late final _frbgen_breez_liquid_cst_new_list_payment_metadata =
+      _frbgen_breez_liquid_cst_new_list_payment_metadataPtr
+          .asFunction<ffi.Pointer<wire_cst_list_payment_metadata> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_payment_metadata_filter> frbgen_breez_liquid_cst_new_list_payment_metadata_filter(
+    int len,
+  ) {
+    return _frbgen_breez_liquid_cst_new_list_payment_metadata_filter(
+      len,
+    );
+  }
+
+  late final _frbgen_breez_liquid_cst_new_list_payment_metadata_filterPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_payment_metadata_filter> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_payment_metadata_filter');
+  late final _frbgen_breez_liquid_cst_new_list_payment_metadata_filter =
+      _frbgen_breez_liquid_cst_new_list_payment_metadata_filterPtr
+          .asFunction<ffi.Pointer<wire_cst_list_payment_metadata_filter> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_payment_state> frbgen_breez_liquid_cst_new_list_payment_state(
+    int len,
+  ) {
+    return _frbgen_breez_liquid_cst_new_list_payment_state(
+      len,
+    );
+  }
+
+  late final _frbgen_breez_liquid_cst_new_list_payment_statePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_payment_state> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_payment_state');
+  late final _frbgen_breez_liquid_cst_new_list_payment_state =
+      _frbgen_breez_liquid_cst_new_list_payment_statePtr
+          .asFunction<ffi.Pointer<wire_cst_list_payment_state> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_payment_type> frbgen_breez_liquid_cst_new_list_payment_type(
+    int len,
+  ) {
+    return _frbgen_breez_liquid_cst_new_list_payment_type(
+      len,
+    );
+  }
+
+  late final _frbgen_breez_liquid_cst_new_list_payment_typePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_payment_type> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_payment_type');
+  late final _frbgen_breez_liquid_cst_new_list_payment_type =
+      _frbgen_breez_liquid_cst_new_list_payment_typePtr
+          .asFunction<ffi.Pointer<wire_cst_list_payment_type> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_prim_u_8_strict> frbgen_breez_liquid_cst_new_list_prim_u_8_strict(
+    int len,
+  ) {
+    return _frbgen_breez_liquid_cst_new_list_prim_u_8_strict(
+      len,
+    );
+  }
+
+  late final _frbgen_breez_liquid_cst_new_list_prim_u_8_strictPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_prim_u_8_strict> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_prim_u_8_strict');
+  late final _frbgen_breez_liquid_cst_new_list_prim_u_8_strict =
       _frbgen_breez_liquid_cst_new_list_prim_u_8_strictPtr
           .asFunction<ffi.Pointer<wire_cst_list_prim_u_8_strict> Function(int)>();
 
@@ -1394,10 +1989,34 @@ final class wire_cst_list_prim_u_8_strict extends ffi.Struct {
   external int len;
 }
 
+final class wire_cst_fetch_payment_proposed_fees_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+
+  @ffi.Uint64()
+  external int fees_sat;
+
+  @ffi.Uint64()
+  external int payer_amount_sat;
+
+  @ffi.Uint64()
+  external int receiver_amount_sat;
+}
+
+final class wire_cst_accept_payment_proposed_fees_request extends ffi.Struct {
+  external wire_cst_fetch_payment_proposed_fees_response response;
+}
+
 final class wire_cst_backup_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> backup_path;
 }
 
+final class wire_cst_bump_refund_fee_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_address;
+
+  @ffi.Uint32()
+  external int sat_per_vbyte;
+}
+
 final class wire_cst_prepare_buy_bitcoin_response extends ffi.Struct {
   @ffi.Int32()
   external int provider;
@@ -1415,6 +2034,68 @@ final class wire_cst_buy_bitcoin_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> redirect_url;
 }
 
+final class wire_cst_delete_payment_metadata_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key;
+}
+
+final class wire_cst_export_payments_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> export_path;
+
+  @ffi.Int32()
+  external int format;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> fiat_currency;
+
+  external ffi.Pointer<ffi.Int64> from_timestamp;
+
+  external ffi.Pointer<ffi.Int64> to_timestamp;
+}
+
+final class wire_cst_fetch_payment_proposed_fees_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+}
+
+final class wire_cst_PaymentLookup_SwapId extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+}
+
+final class wire_cst_PaymentLookup_Invoice extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> invoice;
+}
+
+final class wire_cst_PaymentLookup_PaymentHash extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_hash;
+}
+
+final class wire_cst_PaymentLookup_TxId extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> tx_id;
+}
+
+final class wire_cst_PaymentLookup_Destination extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+}
+
+final class PaymentLookupKind extends ffi.Union {
+  external wire_cst_PaymentLookup_SwapId SwapId;
+
+  external wire_cst_PaymentLookup_Invoice Invoice;
+
+  external wire_cst_PaymentLookup_PaymentHash PaymentHash;
+
+  external wire_cst_PaymentLookup_TxId TxId;
+
+  external wire_cst_PaymentLookup_Destination Destination;
+}
+
+final class wire_cst_payment_lookup extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external PaymentLookupKind kind;
+}
+
 final class wire_cst_list_payment_type extends ffi.Struct {
   external ffi.Pointer<ffi.Int32> ptr;
 
@@ -1422,6 +2103,33 @@ final class wire_cst_list_payment_type extends ffi.Struct {
   external int len;
 }
 
+final class wire_cst_payment_metadata_filter extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value;
+}
+
+final class wire_cst_list_payment_metadata_filter extends ffi.Struct {
+  external ffi.Pointer<wire_cst_payment_metadata_filter> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_payment_state extends ffi.Struct {
+  external ffi.Pointer<ffi.Int32> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_payment_kind extends ffi.Struct {
+  external ffi.Pointer<ffi.Int32> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
 final class wire_cst_list_payments_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_payment_type> filters;
 
@@ -1432,6 +2140,22 @@ final class wire_cst_list_payments_request extends ffi.Struct {
   external ffi.Pointer<ffi.Uint32> offset;
 
   external ffi.Pointer<ffi.Uint32> limit;
+
+  external ffi.Pointer<wire_cst_list_payment_metadata_filter> metadata_filters;
+
+  external ffi.Pointer<wire_cst_list_payment_state> states;
+
+  external ffi.Pointer<wire_cst_list_payment_kind> kinds;
+
+  external ffi.Pointer<ffi.Uint64> from_amount_sat;
+
+  external ffi.Pointer<ffi.Uint64> to_amount_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> search;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> cursor;
 }
 
 final class wire_cst_ln_url_auth_request_data extends ffi.Struct {
@@ -1513,12 +2237,17 @@ final class wire_cst_prepare_pay_onchain_response extends ffi.Struct {
 
   @ffi.Uint64()
   external int total_fees_sat;
+
+  @ffi.Bool()
+  external bool drain;
 }
 
 final class wire_cst_pay_onchain_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
 
   external wire_cst_prepare_pay_onchain_response prepare_res;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> idempotency_key;
 }
 
 final class wire_cst_prepare_buy_bitcoin_request extends ffi.Struct {
@@ -1534,11 +2263,12 @@ final class wire_cst_prepare_pay_onchain_request extends ffi.Struct {
   external int receiver_amount_sat;
 
   external ffi.Pointer<ffi.Uint32> sat_per_vbyte;
+
+  external ffi.Pointer<ffi.Bool> drain;
 }
 
 final class wire_cst_prepare_receive_onchain_request extends ffi.Struct {
-  @ffi.Uint64()
-  external int payer_amount_sat;
+  external ffi.Pointer<ffi.Uint64> payer_amount_sat;
 }
 
 final class wire_cst_prepare_receive_payment_request extends ffi.Struct {
@@ -1555,16 +2285,42 @@ final class wire_cst_prepare_refund_request extends ffi.Struct {
   external int sat_per_vbyte;
 }
 
+final class wire_cst_prepare_send_liquid_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> destination;
+
+  external ffi.Pointer<ffi.Uint64> amount_sat;
+
+  external ffi.Pointer<ffi.Bool> drain;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+}
+
 final class wire_cst_prepare_send_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> invoice;
+
+  external ffi.Pointer<ffi.Uint64> amount_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> idempotency_key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payer_note;
+}
+
+final class wire_cst_receive_liquid_request extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint64> amount_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
 }
 
 final class wire_cst_prepare_receive_onchain_response extends ffi.Struct {
-  @ffi.Uint64()
-  external int payer_amount_sat;
+  external ffi.Pointer<ffi.Uint64> payer_amount_sat;
 
   @ffi.Uint64()
   external int fees_sat;
+
+  @ffi.Double()
+  external double swapper_feerate;
 }
 
 final class wire_cst_prepare_receive_payment_response extends ffi.Struct {
@@ -1594,17 +2350,79 @@ final class wire_cst_restore_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> backup_path;
 }
 
+final class wire_cst_prepare_send_liquid_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+
+  @ffi.Uint64()
+  external int receiver_amount_sat;
+
+  @ffi.Uint64()
+  external int fees_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  @ffi.Bool()
+  external bool drain;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+}
+
 final class wire_cst_prepare_send_response extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> invoice;
 
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> offer;
+
+  @ffi.Uint64()
+  external int receiver_amount_sat;
+
   @ffi.Uint64()
   external int fees_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> idempotency_key;
+}
+
+final class wire_cst_set_payment_metadata_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value;
 }
 
 final class wire_cst_binding_event_listener extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> stream;
 }
 
+final class wire_cst_payment_metadata extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value;
+}
+
+final class wire_cst_list_payment_metadata extends ffi.Struct {
+  external ffi.Pointer<wire_cst_payment_metadata> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_payment_fiat_rate extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> coin;
+
+  @ffi.Double()
+  external double value;
+
+  @ffi.Uint32()
+  external int created_at;
+}
+
+final class wire_cst_list_payment_fiat_rate extends ffi.Struct {
+  external ffi.Pointer<wire_cst_payment_fiat_rate> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
 final class wire_cst_payment extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> tx_id;
 
@@ -1625,6 +2443,10 @@ final class wire_cst_payment extends ffi.Struct {
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
 
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> destination;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
 
   external ffi.Pointer<ffi.Uint64> refund_tx_amount_sat;
@@ -1634,6 +2456,10 @@ final class wire_cst_payment extends ffi.Struct {
 
   @ffi.Int32()
   external int status;
+
+  external ffi.Pointer<wire_cst_list_payment_metadata> metadata;
+
+  external ffi.Pointer<wire_cst_list_payment_fiat_rate> fiat_rates;
 }
 
 final class wire_cst_SdkEvent_PaymentFailed extends ffi.Struct {
@@ -1660,6 +2486,22 @@ final class wire_cst_SdkEvent_PaymentWaitingConfirmation extends ffi.Struct {
   external ffi.Pointer<wire_cst_payment> details;
 }
 
+final class wire_cst_auto_refund_details extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_address;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_address;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
+}
+
+final class wire_cst_SdkEvent_SwapAutoRefunded extends ffi.Struct {
+  external ffi.Pointer<wire_cst_auto_refund_details> details;
+}
+
+final class wire_cst_SdkEvent_SwapWaitingFeeAcceptance extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+}
+
 final class SdkEventKind extends ffi.Union {
   external wire_cst_SdkEvent_PaymentFailed PaymentFailed;
 
@@ -1672,6 +2514,10 @@ final class SdkEventKind extends ffi.Union {
   external wire_cst_SdkEvent_PaymentSucceeded PaymentSucceeded;
 
   external wire_cst_SdkEvent_PaymentWaitingConfirmation PaymentWaitingConfirmation;
+
+  external wire_cst_SdkEvent_SwapAutoRefunded SwapAutoRefunded;
+
+  external wire_cst_SdkEvent_SwapWaitingFeeAcceptance SwapWaitingFeeAcceptance;
 }
 
 final class wire_cst_sdk_event extends ffi.Struct {
@@ -1681,6 +2527,59 @@ final class wire_cst_sdk_event extends ffi.Struct {
   external SdkEventKind kind;
 }
 
+final class wire_cst_asset_metadata extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> ticker;
+
+  @ffi.Uint8()
+  external int precision;
+}
+
+final class wire_cst_list_asset_metadata extends ffi.Struct {
+  external ffi.Pointer<wire_cst_asset_metadata> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_String extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<wire_cst_list_prim_u_8_strict>> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_RefundDestination_Address extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+}
+
+final class wire_cst_RefundDestination_Xpub extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> xpub;
+}
+
+final class RefundDestinationKind extends ffi.Union {
+  external wire_cst_RefundDestination_Address Address;
+
+  external wire_cst_RefundDestination_Xpub Xpub;
+}
+
+final class wire_cst_refund_destination extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external RefundDestinationKind kind;
+}
+
+final class wire_cst_auto_refund_policy extends ffi.Struct {
+  external wire_cst_refund_destination destination;
+
+  @ffi.Int32()
+  external int fee_rate;
+}
+
 final class wire_cst_config extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> liquid_electrum_url;
 
@@ -1688,6 +2587,19 @@ final class wire_cst_config extends ffi.Struct {
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> mempoolspace_url;
 
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> liquid_esplora_url;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bitcoin_esplora_url;
+
+  @ffi.Int32()
+  external int chain_backend;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> breez_server_url;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> boltz_url;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> referral_id;
+
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> working_dir;
 
   @ffi.Int32()
@@ -1700,12 +2612,51 @@ final class wire_cst_config extends ffi.Struct {
   external int zero_conf_min_fee_rate_msat;
 
   external ffi.Pointer<ffi.Uint64> zero_conf_max_amount_sat;
+
+  external ffi.Pointer<wire_cst_list_asset_metadata> asset_metadata;
+
+  external ffi.Pointer<wire_cst_list_String> fiat_currencies;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> proxy_url;
+
+  external ffi.Pointer<wire_cst_auto_refund_policy> auto_refund;
+
+  external ffi.Pointer<ffi.Uint64> amountless_fee_tolerance_sat;
 }
 
 final class wire_cst_connect_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> mnemonic;
 
   external wire_cst_config config;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> watch_only_descriptor;
+}
+
+final class wire_cst_SignerResult_String extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value;
+}
+
+final class wire_cst_SignerResult_Bytes extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value;
+}
+
+final class wire_cst_SignerResult_Error extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class SignerResultKind extends ffi.Union {
+  external wire_cst_SignerResult_String String;
+
+  external wire_cst_SignerResult_Bytes Bytes;
+
+  external wire_cst_SignerResult_Error Error;
+}
+
+final class wire_cst_signer_result extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external SignerResultKind kind;
 }
 
 final class wire_cst_aes_success_action_data_decrypted extends ffi.Struct {
@@ -1886,6 +2837,28 @@ final class wire_cst_symbol extends ffi.Struct {
   external ffi.Pointer<ffi.Uint32> position;
 }
 
+final class wire_cst_asset_balance extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
+  @ffi.Uint64()
+  external int balance;
+
+  @ffi.Uint64()
+  external int pending_send;
+
+  @ffi.Uint64()
+  external int pending_receive;
+
+  external ffi.Pointer<wire_cst_asset_metadata> asset_metadata;
+}
+
+final class wire_cst_list_asset_balance extends ffi.Struct {
+  external ffi.Pointer<wire_cst_asset_balance> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
 final class wire_cst_localized_name extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> locale;
 
@@ -1982,6 +2955,44 @@ final class wire_cst_list_refundable_swap extends ffi.Struct {
   external int len;
 }
 
+final class wire_cst_balance_details extends ffi.Struct {
+  @ffi.Uint64()
+  external int confirmed_sat;
+
+  @ffi.Uint64()
+  external int unconfirmed_receive_sat;
+
+  @ffi.Uint64()
+  external int unconfirmed_send_sat;
+
+  @ffi.Uint64()
+  external int pending_send_swaps_sat;
+
+  @ffi.Uint64()
+  external int pending_receive_swaps_sat;
+
+  @ffi.Uint64()
+  external int pending_refund_sat;
+
+  @ffi.Uint64()
+  external int refundable_btc_sat;
+
+  @ffi.Uint64()
+  external int pending_btc_refund_sat;
+}
+
+final class wire_cst_bump_refund_fee_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> replaced_tx_id;
+
+  @ffi.Uint64()
+  external int tx_fee_sat;
+
+  @ffi.Uint64()
+  external int fee_delta_sat;
+}
+
 final class wire_cst_get_info_response extends ffi.Struct {
   @ffi.Uint64()
   external int balance_sat;
@@ -1993,6 +3004,10 @@ final class wire_cst_get_info_response extends ffi.Struct {
   external int pending_receive_sat;
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> pubkey;
+
+  external ffi.Pointer<wire_cst_list_asset_balance> asset_balances;
+
+  external wire_cst_balance_details balance_details;
 }
 
 final class wire_cst_InputType_BitcoinAddress extends ffi.Struct {
@@ -2069,6 +3084,26 @@ final class wire_cst_lightning_payment_limits_response extends ffi.Struct {
   external wire_cst_limits receive;
 }
 
+final class wire_cst_list_payments_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_payment> payments;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> next_cursor;
+}
+
+final class wire_cst_ln_offer extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> offer;
+
+  external ffi.Pointer<wire_cst_list_String> chains;
+
+  external ffi.Pointer<ffi.Uint64> amount_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  external ffi.Pointer<ffi.Uint64> absolute_expiry;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> issuer;
+}
+
 final class wire_cst_LnUrlAuthError_Generic extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
 }
@@ -2297,6 +3332,10 @@ final class wire_cst_onchain_payment_limits_response extends ffi.Struct {
   external wire_cst_limits receive;
 }
 
+final class wire_cst_PaymentError_AmountMissing extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
 final class wire_cst_PaymentError_Generic extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
 }
@@ -2305,6 +3344,10 @@ final class wire_cst_PaymentError_InvalidInvoice extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
 }
 
+final class wire_cst_PaymentError_InvalidDestination extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
 final class wire_cst_PaymentError_LwkError extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
 }
@@ -2328,10 +3371,14 @@ final class wire_cst_PaymentError_SignerError extends ffi.Struct {
 }
 
 final class PaymentErrorKind extends ffi.Union {
+  external wire_cst_PaymentError_AmountMissing AmountMissing;
+
   external wire_cst_PaymentError_Generic Generic;
 
   external wire_cst_PaymentError_InvalidInvoice InvalidInvoice;
 
+  external wire_cst_PaymentError_InvalidDestination InvalidDestination;
+
   external wire_cst_PaymentError_LwkError LwkError;
 
   external wire_cst_PaymentError_ReceiveError ReceiveError;
@@ -2360,6 +3407,12 @@ final class wire_cst_prepare_refund_response extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
 }
 
+final class wire_cst_receive_liquid_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bip21;
+}
+
 final class wire_cst_receive_onchain_response extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
 
@@ -2418,11 +3471,57 @@ final class wire_cst_send_payment_response extends ffi.Struct {
   external wire_cst_payment payment;
 }
 
+final class wire_cst_SignerMethod_DeriveXpub extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> derivation_path;
+}
+
+final class wire_cst_SignerMethod_SignPset extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> pset;
+}
+
+final class wire_cst_SignerMethod_SignEcdsa extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> msg;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> derivation_path;
+}
+
+final class wire_cst_SignerMethod_HmacSha256 extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> msg;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> derivation_path;
+}
+
+final class SignerMethodKind extends ffi.Union {
+  external wire_cst_SignerMethod_DeriveXpub DeriveXpub;
+
+  external wire_cst_SignerMethod_SignPset SignPset;
+
+  external wire_cst_SignerMethod_SignEcdsa SignEcdsa;
+
+  external wire_cst_SignerMethod_HmacSha256 HmacSha256;
+}
+
+final class wire_cst_signer_method extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external SignerMethodKind kind;
+}
+
+final class wire_cst_signer_request extends ffi.Struct {
+  @ffi.Uint32()
+  external int id;
+
+  external wire_cst_signer_method method;
+}
+
 /// EXTRA BEGIN
 typedef WireSyncRust2DartDco = ffi.Pointer<DartCObject>;
 
 const int ESTIMATED_BTC_CLAIM_TX_VSIZE = 111;
 
+const int DEFAULT_AMOUNTLESS_FEE_TOLERANCE_SAT = 0;
+
 const double STANDARD_FEE_RATE_SAT_PER_VBYTE = 0.1;
 
 const double LOWBALL_FEE_RATE_SAT_PER_VBYTE = 0.01;
@@ -13,6 +13,7 @@ class BreezSDKLiquidModule(
 ) : ReactContextBaseJavaModule(reactContext) {
     private lateinit var executor: ExecutorService
     private var bindingLiquidSdk: BindingLiquidSdk? = null
+    private var signer: BreezSDKLiquidSigner? = null
 
     companion object {
         const val TAG = "RNBreezSDKLiquid"
@@ -143,6 +144,50 @@ class BreezSDKLiquidModule(
         }
     }
 
+    @ReactMethod
+    fun connectWithSigner(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        if (bindingLiquidSdk != null) {
+            promise.reject("Generic", "Already initialized")
+            return
+        }
+
+        executor.execute {
+            try {
+                var connectRequest =
+                    asConnectRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "ConnectRequest")) }
+
+                ensureWorkingDir(connectRequest.config.workingDir)
+
+                val emitter = reactApplicationContext.getJSModule(RCTDeviceEventEmitter::class.java)
+                val signer = BreezSDKLiquidSigner(emitter)
+                this.signer = signer
+                bindingLiquidSdk = connectWithSigner(connectRequest, signer)
+                promise.resolve(readableMapOf("status" to "ok"))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    // Not run by the executor, as its threads may all be blocked waiting for signer responses
+    @ReactMethod
+    fun respondToSignerRequest(
+        id: Int,
+        result: ReadableMap,
+        promise: Promise,
+    ) {
+        try {
+            val signer = signer ?: run { throw SdkException.Generic("Not connected with a signer") }
+            signer.respond(id, result)
+            promise.resolve(readableMapOf("status" to "ok"))
+        } catch (e: Exception) {
+            promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+        }
+    }
+
     @ReactMethod
     fun addEventListener(promise: Promise) {
         executor.execute {
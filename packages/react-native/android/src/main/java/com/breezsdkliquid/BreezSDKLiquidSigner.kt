@@ -0,0 +1,76 @@
+package com.breezsdkliquid
+
+import breez_sdk_liquid.SdkException
+import breez_sdk_liquid.Signer
+import breez_sdk_liquid.SignerException
+import com.facebook.react.bridge.ReadableMap
+import com.facebook.react.modules.core.DeviceEventManagerModule.RCTDeviceEventEmitter
+import java.util.concurrent.CompletableFuture
+import java.util.concurrent.ConcurrentHashMap
+import java.util.concurrent.atomic.AtomicInteger
+
+class BreezSDKLiquidSigner(
+    private val emitter: RCTDeviceEventEmitter,
+) : Signer {
+    companion object {
+        var emitterName = "breezSdkLiquidSignerRequest"
+    }
+
+    private val nextId = AtomicInteger(0)
+    private val pending = ConcurrentHashMap<Int, CompletableFuture<ReadableMap>>()
+
+    fun respond(
+        id: Int,
+        result: ReadableMap,
+    ) {
+        pending.remove(id)?.complete(result) ?: throw SdkException.Generic("No pending signer request $id")
+    }
+
+    private fun request(
+        method: String,
+        vararg args: Pair<String, *>,
+    ): ReadableMap {
+        val id = nextId.getAndIncrement()
+        val future = CompletableFuture<ReadableMap>()
+        pending[id] = future
+
+        emitter.emit(emitterName, readableMapOf("id" to id, "method" to method, *args))
+        val result = future.get()
+        if (result.hasKey("error")) {
+            throw SignerException.Generic(result.getString("error") ?: "Signer request $method failed")
+        }
+        return result
+    }
+
+    private fun requestString(
+        method: String,
+        vararg args: Pair<String, *>,
+    ): String =
+        request(method, *args).getString("value")
+            ?: throw SignerException.Generic("Missing value of signer request $method")
+
+    private fun requestBytes(
+        method: String,
+        vararg args: Pair<String, *>,
+    ): List<UByte> =
+        request(method, *args).getArray("value")?.let { asUByteList(it) }
+            ?: throw SignerException.Generic("Missing value of signer request $method")
+
+    override fun xpub(): String = requestString("xpub")
+
+    override fun deriveXpub(derivationPath: String): String = requestString("deriveXpub", "derivationPath" to derivationPath)
+
+    override fun slip77MasterBlindingKey(): String = requestString("slip77MasterBlindingKey")
+
+    override fun signPset(pset: String): String = requestString("signPset", "pset" to pset)
+
+    override fun signEcdsa(
+        msg: List<UByte>,
+        derivationPath: String,
+    ): List<UByte> = requestBytes("signEcdsa", "msg" to msg, "derivationPath" to derivationPath)
+
+    override fun hmacSha256(
+        msg: List<UByte>,
+        derivationPath: String,
+    ): List<UByte> = requestBytes("hmacSha256", "msg" to msg, "derivationPath" to derivationPath)
+}
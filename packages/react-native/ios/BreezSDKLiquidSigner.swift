@@ -0,0 +1,87 @@
+import BreezSDKLiquid
+import Foundation
+
+class BreezSDKLiquidSigner: Signer {
+    static let emitterName: String = "breezSdkLiquidSignerRequest"
+
+    private let lock = NSLock()
+    private var nextId: Int = 0
+    private var pending: [Int: DispatchSemaphore] = [:]
+    private var results: [Int: [String: Any]] = [:]
+
+    func respond(id: Int, result: [String: Any]) throws {
+        lock.lock()
+        defer { lock.unlock() }
+
+        guard let semaphore = pending.removeValue(forKey: id) else {
+            throw SdkError.Generic(message: "No pending signer request \(id)")
+        }
+        results[id] = result
+        semaphore.signal()
+    }
+
+    private func request(method: String, args: [String: Any] = [:]) throws -> [String: Any] {
+        if !RNBreezSDKLiquid.hasListeners {
+            throw SignerError.Generic(err: "No signer request listener")
+        }
+
+        let semaphore = DispatchSemaphore(value: 0)
+        lock.lock()
+        let id = nextId
+        nextId += 1
+        pending[id] = semaphore
+        lock.unlock()
+
+        var body = args
+        body["id"] = id
+        body["method"] = method
+        RNBreezSDKLiquid.emitter.sendEvent(withName: BreezSDKLiquidSigner.emitterName, body: body)
+        semaphore.wait()
+
+        lock.lock()
+        let result = results.removeValue(forKey: id) ?? [:]
+        lock.unlock()
+        if let err = result["error"] as? String {
+            throw SignerError.Generic(err: err)
+        }
+        return result
+    }
+
+    private func requestString(method: String, args: [String: Any] = [:]) throws -> String {
+        guard let value = try request(method: method, args: args)["value"] as? String else {
+            throw SignerError.Generic(err: "Missing value of signer request \(method)")
+        }
+        return value
+    }
+
+    private func requestBytes(method: String, args: [String: Any] = [:]) throws -> [UInt8] {
+        guard let value = try request(method: method, args: args)["value"] as? [UInt8] else {
+            throw SignerError.Generic(err: "Missing value of signer request \(method)")
+        }
+        return value
+    }
+
+    func xpub() throws -> String {
+        return try requestString(method: "xpub")
+    }
+
+    func deriveXpub(derivationPath: String) throws -> String {
+        return try requestString(method: "deriveXpub", args: ["derivationPath": derivationPath])
+    }
+
+    func slip77MasterBlindingKey() throws -> String {
+        return try requestString(method: "slip77MasterBlindingKey")
+    }
+
+    func signPset(pset: String) throws -> String {
+        return try requestString(method: "signPset", args: ["pset": pset])
+    }
+
+    func signEcdsa(msg: [UInt8], derivationPath: String) throws -> [UInt8] {
+        return try requestBytes(method: "signEcdsa", args: ["msg": msg, "derivationPath": derivationPath])
+    }
+
+    func hmacSha256(msg: [UInt8], derivationPath: String) throws -> [UInt8] {
+        return try requestBytes(method: "hmacSha256", args: ["msg": msg, "derivationPath": derivationPath])
+    }
+}